@@ -96,12 +96,7 @@ async fn delete_event(id: String, secret: String) {
 
 async fn change_event_state(id: String, secret: String, state: u8) {
     let res = reqwest::Client::new()
-        .post(format!(
-            "{}/api/mod/event/{}/{}",
-            server_rest(),
-            id,
-            secret
-        ))
+        .post(format!("{}/api/mod/event/{}/{}", server_rest(), id, secret))
         .json(&json!({
             "state": {
                 "state": state
@@ -141,7 +136,11 @@ async fn add_question(event: String) -> shared::QuestionItem {
 }
 
 async fn like_question(event: String, question_id: i64, like: bool) -> shared::QuestionItem {
-    let body = shared::EditLike { question_id, like };
+    let body = shared::EditLike {
+        question_id,
+        like,
+        participant: None,
+    };
     let res = reqwest::Client::new()
         .post(format!("{}/api/event/editlike/{}", server_rest(), event))
         .json(&body)
@@ -166,6 +165,8 @@ async fn hide_question(event: String, secret: String, question_id: i64) {
         answered: false,
         hide: true,
         screened: false,
+        pinned: false,
+        shadow_ban: None,
     };
 
     let res = reqwest::Client::new()
@@ -189,9 +190,32 @@ mod test {
     use super::*;
     use pretty_assertions::assert_eq;
     use reqwest::StatusCode;
-    use shared::TEST_EVENT_NAME;
+    use shared::{EventDelta, EventState, States, WsMessage, TEST_EVENT_NAME};
     use tungstenite::connect;
 
+    /// reads the next text message, skipping protocol frames like heartbeat pings
+    fn read_text<S: std::io::Read + std::io::Write>(
+        socket: &mut tungstenite::WebSocket<S>,
+    ) -> String {
+        loop {
+            let msg = socket.read().unwrap();
+            if msg.is_text() {
+                return msg.into_text().unwrap();
+            }
+        }
+    }
+
+    fn read_delta<S: std::io::Read + std::io::Write>(
+        socket: &mut tungstenite::WebSocket<S>,
+    ) -> EventDelta {
+        let delta = match WsMessage::decode(&read_text(socket)) {
+            Some(WsMessage::Delta(delta)) => Some(delta),
+            _ => None,
+        };
+
+        delta.unwrap()
+    }
+
     #[tokio::test]
     #[tracing_test::traced_test]
     async fn test_status() {
@@ -296,21 +320,31 @@ mod test {
 
         assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
 
-        let msg = socket.read().expect("Error reading message");
-        assert_eq!(msg.into_text().unwrap(), "v:1".to_string());
+        assert_eq!(read_text(&mut socket), WsMessage::Viewers(1).encode());
 
         let question = add_question(event.clone()).await;
 
-        let msg = socket.read().expect("Error reading message");
-        assert_eq!(msg.into_text().unwrap(), format!("q:{}", question.id));
+        assert_eq!(
+            read_delta(&mut socket),
+            EventDelta::QuestionAdded(question.clone())
+        );
 
-        like_question(event.clone(), question.id, true).await;
+        let liked = like_question(event.clone(), question.id, true).await;
 
-        let msg = socket.read().expect("Error reading message");
-        assert_eq!(msg.into_text().unwrap(), format!("q:{}", question.id));
+        assert_eq!(
+            read_delta(&mut socket),
+            EventDelta::QuestionLiked {
+                id: question.id,
+                likes: liked.likes,
+            }
+        );
 
         change_event_state(event, secret, 1).await;
-        let msg = socket.read().expect("Error reading message");
-        assert_eq!(msg.into_text().unwrap(), "e");
+        assert_eq!(
+            read_delta(&mut socket),
+            EventDelta::EventStateChanged(EventState {
+                state: States::VotingOnly,
+            })
+        );
     }
 }
@@ -1,59 +1,226 @@
 use async_trait::async_trait;
-use axum::extract::ws::{close_code::RESTART, CloseFrame, Message, WebSocket};
+use axum::{
+    extract::ws::{close_code::RESTART, CloseFrame, Message, WebSocket},
+    http::StatusCode,
+};
 use shared::{
-    AddEvent, ContextValidation, EventInfo, EventResponseFlags, EventState, EventTags, EventTokens,
-    EventUpgrade, GetEventResponse, ModEvent, ModInfo, ModQuestion, PasswordValidation,
-    PaymentCapture, QuestionItem, States, TagValidation,
+    AddEvent, AddOrg, AddOrgMember, AddPoll, AddSession, AddSurveyResponse, AddWord, AddWordCloud,
+    AdminDashboard, AdminDayStats, AttachmentRequest, AttachmentUpload, BlockEntry, BlockedTerms,
+    BlockedTermsAction, Blocklist, BulkAction, ContextValidation, EditSeries, EditSlug,
+    Entitlements, EventBranding, EventData, EventDelta, EventInfo, EventResponseFlags, EventSeries,
+    EventState, EventStats, EventTags, EventTheme, EventTimer, EventTokens, EventUpdates,
+    EventUpgrade, FlaggedLikes, GetEventResponse, LanguageAction, LikeTimeline, ModEvent, ModInfo,
+    ModMergeQuestions, ModPoll, ModQuestion, ModQuestionNote, ModQuestionsBulk, ModSession,
+    ModSpeaker, ModTimer, ModWordCloud, NameValidation, NotificationSettings, OrgCheckout,
+    OrgEvent, OrgMember, OrgMembership, OrgRole, OrgWorkspace, PasswordValidation, PaymentCapture,
+    Poll, PollOption, PollVote, PromoCode, PushParams, QuestionCluster, QuestionItem,
+    QuestionLanguages, QuestionLength, QuestionLimits, QuestionNames, QuestionSentiment,
+    QuestionSummary, QuestionTranslation, QuestionsPage, QuestionsParams, RaiseHand, RaisedHand,
+    Reactions, ReceiptDetails, SeriesCurrent, SeriesOccurrence, Session, SlugTarget,
+    SocketEncoding, Speaker, SpeakerQueue, States, StatsBucket, SubscriptionState, SurveyResponse,
+    SurveyResults, TagValidation, WebPushNotification, WebPushSubscription, WebPushUnsubscribe,
+    WordCloud, WordCount, WsClientMessage, WsMessage, ADMIN_DASHBOARD_DAYS, BLOCKLIST_MAX_ENTRIES,
+    EVENT_STATS_BUCKET_SECS, EVENT_STATS_MAX_BUCKETS, ORG_MAX_EVENTS, ORG_MAX_MEMBERS,
+    POLL_MAX_PER_EVENT, PREMIUM_TRIAL_SECS, QUESTION_COOLDOWN_MAX_MINS,
+    QUESTION_SUMMARY_MAX_LENGTH, QUESTION_SUMMARY_MAX_QUESTIONS, SERIES_MAX_OCCURRENCES,
+    SESSION_MAX_PER_EVENT, SPEAKER_QUEUE_MAX, SURVEY_MAX_RESPONSES, VIEWER_CAPACITY_MAX,
+    WEB_PUSH_MAX_SUBSCRIPTIONS, WORD_CLOUD_MAX_ANSWERS, WORD_CLOUD_MAX_ENTRIES,
+    WORD_CLOUD_MAX_PER_EVENT,
 };
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
+    net::IpAddr,
     sync::{
-        atomic::{AtomicBool, AtomicUsize, Ordering},
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
         Arc,
     },
     time::{Duration, Instant},
 };
 use tinyurl_rs::{CreateRequest, TinyUrlAPI, TinyUrlOpenAPI};
 use tokio::{
-    sync::{mpsc, RwLock},
+    sync::{mpsc, Notify, RwLock},
     time::sleep,
 };
 use tracing::instrument;
 use ulid::Ulid;
 
 use crate::{
-    bail, env,
+    attachments::Attachments,
+    bail, clusters,
+    counters::Counters,
+    env,
     error::{InternalError, Result},
-    eventsdb::{ApiEventInfo, EventEntry, EventsDB, PremiumOrder},
-    mail::MailConfig,
-    payment::Payment,
+    eventsdb::{
+        self, ApiEventInfo, EventEntry, EventsDB, OrgEntry, OrgMemberEntry, Organization,
+        PremiumOrder, QueuedSpeaker, SpeakerQueueState, StatCounter, Subscription,
+    },
+    export,
+    like_timeline::LikeTimelines,
+    mail::{MailConfig, Receipt},
+    payment::{Payment, PaymentReceipt},
+    poll_voters::PollVoters,
     pubsub::{PubSubPublish, PubSubReceiver},
+    schedule::Schedule,
+    sentiment,
+    summary::Summarizer,
+    survey_respondents::SurveyRespondents,
     tracking::{EditEvent, Tracking},
-    utils::timestamp_now,
+    translate::Translator,
+    typing::Typing,
+    utils::{day_string, timestamp_now},
     viewers::Viewers,
+    webpush::{self, WebPush, WebPushError},
 };
 
 pub type SharedApp = Arc<App>;
 
-enum Notification {
-    Event,
-    Question(i64),
-    Viewers(i64),
+/// interval of websocket pings sent to every client
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+/// clients not responding for this many heartbeats are considered dead and get dropped
+const HEARTBEAT_MAX_MISSED: u32 = 3;
+
+/// max time a long poll is kept open without changes
+const LONG_POLL_TIMEOUT: Duration = Duration::from_secs(25);
+
+/// series share the schedule with the open/close transitions of events
+const SERIES_SCHEDULE_PREFIX: &str = "series/";
+const SERIES_UPDATE_RETRIES: usize = 3;
+const ORG_UPDATE_RETRIES: usize = 3;
+
+/// push services limit the payload, questions are cut to fit
+const PUSH_BODY_MAX_CHARS: usize = 120;
+
+/// word cloud answers of a participant are counted this long, outlasting any event
+const WORD_CLOUD_ANSWERS_TTL_SECS: usize = 30 * 24 * 60 * 60;
+
+/// likes of a browser or address are counted within windows of this length
+const LIKE_BURST_WINDOW_SECS: usize = 10;
+/// likes of one browser within a window before further ones are discounted
+const LIKE_BURST_MAX_PARTICIPANT: u64 = 30;
+/// likes of one address within a window, a venue wifi puts hundreds of participants behind one
+const LIKE_BURST_MAX_IP: u64 = 1000;
+
+/// other instances pick up blocklist changes once their copy is this old
+const BLOCKLIST_CACHE_SECS: i64 = 60;
+
+/// copy of the blocklist, spares a db read per created event and asked question
+#[derive(Default)]
+struct CachedBlocklist {
+    fetched_unix: i64,
+    blocklist: Blocklist,
+}
+
+/// other instances pick up viewer capacity changes once their copy is this old
+const VIEWER_CAPACITY_CACHE_SECS: i64 = 30;
+
+/// viewer capacity of an event (0 if unlimited), spares a db read per heartbeat of waiting sockets
+struct CachedCapacity {
+    fetched_unix: i64,
+    capacity: u32,
+}
+
+/// notification for the moderators of an event, sent once the change is stored
+struct ModeratorNotification {
+    /// empty if push notifications are turned off
+    subscriptions: Vec<WebPushSubscription>,
+    email: Option<String>,
+    notification: WebPushNotification,
+}
+
+fn series_schedule_key(id: &str) -> String {
+    format!("{SERIES_SCHEDULE_PREFIX}{id}")
+}
+
+/// typing signals of a socket arriving faster than this are dropped,
+/// the client sends one every 3 seconds at most
+const TYPING_SIGNAL_MIN_INTERVAL: Duration = Duration::from_secs(2);
+/// first retry of a failed scheduled transition, doubling with every further failure
+const SCHEDULE_RETRY_BASE_SECS: i64 = 10;
+const SCHEDULE_RETRY_MAX_SECS: i64 = 60 * 60;
+
+/// delay before retrying a scheduled transition that failed `failures` times in a row
+fn schedule_retry_secs(failures: u32) -> i64 {
+    SCHEDULE_RETRY_BASE_SECS
+        .saturating_mul(1_i64 << failures.saturating_sub(1).min(16))
+        .min(SCHEDULE_RETRY_MAX_SECS)
 }
 
 #[derive(Clone)]
 pub struct App {
     eventsdb: Arc<dyn EventsDB>,
     //TODO: order subscriber based on topic name into Concurrent Hashmap
-    channels: Arc<RwLock<HashMap<usize, (String, OutBoundChannel)>>>,
+    /// per socket
+    channels: Arc<RwLock<HashMap<usize, Subscriber>>>,
+    /// per event: wakes up pending long polls on changes
+    long_polls: Arc<RwLock<HashMap<String, Arc<Notify>>>>,
+    typing: Arc<dyn Typing>,
+    /// upcoming scheduled open/close transitions of events
+    schedule: Arc<dyn Schedule>,
+    /// failed attempts per schedule entry, only tracked by this instance
+    schedule_failures: Arc<RwLock<HashMap<String, u32>>>,
+    /// distinguishes socket user ids of this server from those of other instances
+    instance_id: String,
     shutdown: Arc<AtomicBool>,
     pubsub_publish: Arc<dyn PubSubPublish>,
     viewers: Arc<dyn Viewers>,
+    like_timelines: Arc<dyn LikeTimelines>,
+    poll_voters: Arc<dyn PollVoters>,
+    survey_respondents: Arc<dyn SurveyRespondents>,
+    counters: Arc<dyn Counters>,
     payment: Arc<Payment>,
     tracking: Tracking,
     base_url: String,
     tiny_url_token: Option<String>,
     mail_config: MailConfig,
+    attachments: Attachments,
+    web_push: WebPush,
+    summarizer: Summarizer,
+    /// classify the tone of incoming questions, see `ENV_SENTIMENT`
+    sentiment: bool,
+    translator: Translator,
+    request_stats: Arc<RequestStats>,
+    blocklist: Arc<RwLock<CachedBlocklist>>,
+    /// per event: discounted likes of questions that are flagged already,
+    /// stored with the next counted like, only tracked by this instance
+    discounted_likes: Arc<RwLock<HashMap<String, Vec<FlaggedLikes>>>>,
+    /// per event, only tracked by this instance
+    viewer_capacities: Arc<RwLock<HashMap<String, CachedCapacity>>>,
+}
+
+/// answered requests of this server instance, see `App::track_response`
+#[derive(Default)]
+struct RequestStats {
+    total: AtomicU64,
+    server_errors: AtomicU64,
+}
+
+/// state shared by all server instances, backed by redis outside of tests
+pub struct Stores {
+    pub viewers: Arc<dyn Viewers>,
+    pub typing: Arc<dyn Typing>,
+    pub schedule: Arc<dyn Schedule>,
+    pub like_timelines: Arc<dyn LikeTimelines>,
+    pub poll_voters: Arc<dyn PollVoters>,
+    pub survey_respondents: Arc<dyn SurveyRespondents>,
+    pub counters: Arc<dyn Counters>,
+}
+
+#[cfg(test)]
+impl Stores {
+    /// in memory stores, nobody is watching any event
+    pub fn in_memory() -> Self {
+        Self {
+            viewers: Arc::new(crate::viewers::MockViewers::idle()),
+            typing: Arc::new(crate::typing::InMemoryTyping::default()),
+            schedule: Arc::new(crate::schedule::InMemorySchedule::default()),
+            like_timelines: Arc::new(crate::like_timeline::InMemoryLikeTimelines::default()),
+            poll_voters: Arc::new(crate::poll_voters::InMemoryPollVoters::default()),
+            survey_respondents: Arc::new(
+                crate::survey_respondents::InMemorySurveyRespondents::default(),
+            ),
+            counters: Arc::new(crate::counters::InMemoryCounters::default()),
+        }
+    }
 }
 
 static NEXT_USER_ID: AtomicUsize = AtomicUsize::new(1);
@@ -61,31 +228,84 @@ static NEXT_USER_ID: AtomicUsize = AtomicUsize::new(1);
 type OutBoundChannel =
     mpsc::UnboundedSender<std::result::Result<axum::extract::ws::Message, axum::Error>>;
 
+/// socket receiving the updates of an event
+#[derive(Clone)]
+struct Subscriber {
+    event: String,
+    encoding: SocketEncoding,
+    channel: OutBoundChannel,
+    /// moderators also receive the updates meant for moderators only
+    moderator: bool,
+}
+
 impl App {
     pub fn new(
         eventsdb: Arc<dyn EventsDB>,
         pubsub_publish: Arc<dyn PubSubPublish>,
-        viewers: Arc<dyn Viewers>,
+        stores: Stores,
         payment: Arc<Payment>,
         tracking: Tracking,
         base_url: String,
     ) -> Self {
+        let Stores {
+            viewers,
+            typing,
+            schedule,
+            like_timelines,
+            poll_voters,
+            survey_respondents,
+            counters,
+        } = stores;
+
         let tiny_url_token = Self::tinyurl_token();
 
         let mail_config = MailConfig::new();
+        let attachments = Attachments::new();
+        let web_push = WebPush::new();
+        let summarizer = Summarizer::new();
+        let sentiment = Self::sentiment_enabled();
+        let translator = Translator::new();
 
         Self {
             eventsdb,
             pubsub_publish,
             channels: Arc::default(),
+            typing,
+            schedule,
+            schedule_failures: Arc::default(),
+            instance_id: Ulid::new().to_string(),
+            long_polls: Arc::default(),
             base_url,
             tiny_url_token,
             mail_config,
+            attachments,
+            web_push,
+            summarizer,
+            sentiment,
+            translator,
             payment,
             viewers,
+            like_timelines,
+            poll_voters,
+            survey_respondents,
+            counters,
             tracking,
             shutdown: Arc::new(AtomicBool::new(false)),
+            request_stats: Arc::default(),
+            blocklist: Arc::default(),
+            discounted_likes: Arc::default(),
+            viewer_capacities: Arc::default(),
+        }
+    }
+
+    fn sentiment_enabled() -> bool {
+        let enabled = std::env::var(env::ENV_SENTIMENT).is_ok_and(|var| var == "1");
+
+        if enabled {
+            tracing::info!("question sentiment enabled");
         }
+
+        enabled
     }
 
     fn tinyurl_token() -> Option<String> {
@@ -158,7 +378,12 @@ impl App {
     }
 
     #[instrument(skip(self, request))]
-    pub async fn create_event(&self, request: AddEvent) -> Result<EventInfo> {
+    pub async fn create_event(&self, mut request: AddEvent) -> Result<EventInfo> {
+        request.data.name = shared::strip_html(&request.data.name);
+        request.data.description = shared::strip_html(&request.data.description);
+        //Note: branding can only be set once the event is premium
+        request.data.branding = None;
+
         let validation = shared::CreateEventValidation::default().check(
             &request.data.name,
             &request.data.description,
@@ -172,6 +397,19 @@ impl App {
             }));
         }
 
+        if !request.data.schedule_valid() {
+            bail!("event end must be after its start");
+        }
+
+        if request
+            .data
+            .timezone
+            .as_ref()
+            .is_some_and(|tz| tz.len() > shared::EVENT_TIMEZONE_MAX_LENGTH)
+        {
+            bail!("timezone too long");
+        }
+
         let now = timestamp_now();
 
         let request_mod_mail = request.moderator_email.clone();
@@ -188,8 +426,13 @@ impl App {
             password: shared::EventPassword::Disabled,
             questions: Vec::new(),
             do_screening: false,
+            //Note: events with a scheduled start get opened by the scheduler
             state: EventState {
-                state: States::Open,
+                state: if request.data.not_started(now) {
+                    States::Closed
+                } else {
+                    States::Open
+                },
             },
             data: request.data,
             tokens: EventTokens {
@@ -198,8 +441,42 @@ impl App {
             },
             context: Vec::new(),
             tags: EventTags::default(),
+            question_names: QuestionNames::default(),
+            question_length: QuestionLength::default(),
+            downvotes: false,
+            polls: Vec::new(),
+            word_clouds: Vec::new(),
+            sessions: Vec::new(),
+            survey: false,
+            survey_responses: Vec::new(),
+            series: None,
+            slug: None,
+            theme: EventTheme::default(),
+            hide_branding: false,
+            receipt: None,
+            trial_end_unix: None,
+            peak_viewers: 0,
+            push_subscriptions: Vec::new(),
+            notifications: NotificationSettings::default(),
+            speaker_queue: SpeakerQueueState::default(),
+            timer: None,
+            question_cooldown_mins: 0,
+            last_questions: HashMap::new(),
+            question_limits: QuestionLimits::default(),
+            viewer_capacity: 0,
+            flagged_likes: Vec::new(),
+            participant_questions: HashMap::new(),
+            shadow_banned: Vec::new(),
+            blocked_terms: BlockedTerms::default(),
+            question_languages: QuestionLanguages::default(),
+            question_summary: None,
+            sentiments: Vec::new(),
         };
 
+        if let Some(account) = &request.subscription {
+            e.premium_id = self.subscription_order(account).await;
+        }
+
         let url = format!("{}/event/{}", self.base_url, e.tokens.public_token);
 
         //Note: only use shortener outside of e2e tests
@@ -214,6 +491,10 @@ impl App {
             .put(EventEntry::new(e, request.test.then_some(now + 60)))
             .await?;
 
+        if let Some(at) = result.data.next_transition(now) {
+            self.schedule.add(&public_token, at).await;
+        }
+
         if let Some(mail) = request_mod_mail.as_ref() {
             self.send_mail(
                 public_token,
@@ -225,6 +506,8 @@ impl App {
         }
 
         if !request.test {
+            self.count_stat(StatCounter::EventCreated).await;
+
             self.tracking
                 .track_event_create(
                     result.tokens.public_token.clone(),
@@ -237,6 +520,89 @@ impl App {
         Ok(result.into())
     }
 
+    /// applies all due scheduled transitions, called periodically on every server instance
+    #[instrument(skip(self))]
+    pub async fn run_schedule(&self) {
+        let now = timestamp_now();
+
+        for id in self.schedule.take_due(now).await {
+            let res = match id.strip_prefix(SERIES_SCHEDULE_PREFIX) {
+                Some(series) => self.apply_series(series, now).await,
+                None => self.apply_schedule(&id, now).await,
+            };
+
+            match res {
+                Ok(()) => {
+                    self.schedule_failures.write().await.remove(&id);
+                }
+                Err(InternalError::EventsDB(eventsdb::Error::ItemNotFound)) => {
+                    tracing::warn!("scheduled event {id} not found");
+                    self.schedule_failures.write().await.remove(&id);
+                }
+                //Note: `take_due` removed the entry already, it would be lost without re-adding it
+                Err(e) => {
+                    let failures = {
+                        let mut failures = self.schedule_failures.write().await;
+                        let count = failures.entry(id.clone()).or_default();
+                        *count = count.saturating_add(1);
+                        *count
+                    };
+                    let delay = schedule_retry_secs(failures);
+
+                    tracing::error!(
+                        "scheduled state change of {id} failed ({failures}x), retry in {delay}s: {e}"
+                    );
+
+                    self.schedule.add(&id, now + delay).await;
+                }
+            }
+        }
+    }
+
+    /// opens the event once its start passed and closes it after its end
+    async fn apply_schedule(&self, id: &str, now: i64) -> Result<()> {
+        let mut entry = self.eventsdb.get(id).await?;
+
+        let e = &mut entry.event;
+
+        //Note: archiving is final, the schedule must not reopen the event
+        if e.deleted || e.is_timed_out_and_free() || e.state.is_archived() {
+            return Ok(());
+        }
+
+        if let Some(at) = e.data.next_transition(now) {
+            self.schedule.add(id, at).await;
+        }
+
+        let state = if e.data.ended(now) {
+            States::Closed
+        } else if e.data.not_started(now) {
+            return Ok(());
+        } else {
+            States::Open
+        };
+
+        if e.state.state == state {
+            return Ok(());
+        }
+
+        tracing::info!("scheduled state change of {id}: {state:?}");
+
+        e.state = EventState { state };
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(
+            id,
+            WsMessage::Delta(EventDelta::EventStateChanged(EventState { state })),
+        )
+        .await;
+
+        Ok(())
+    }
+
     fn mod_link(&self, tokens: &EventTokens) -> String {
         let mod_token = tokens
             .moderator_token
@@ -275,10 +641,13 @@ impl App {
         secret: Option<String>,
         admin: bool,
         password: Option<String>,
+        participant: Option<String>,
     ) -> Result<GetEventResponse> {
         tracing::info!("get_event");
 
-        let mut e = self.eventsdb.get(&id).await?.event;
+        let entry = self.eventsdb.get(&id).await?;
+        let version = entry.version;
+        let mut e = entry.event;
 
         if let Some(secret) = &secret {
             if e.tokens
@@ -299,17 +668,23 @@ impl App {
         let mod_info = is_mod.then(|| ModInfo {
             pwd: e.password.clone(),
             private_token: e.tokens.moderator_token.clone().unwrap_or_default(),
+            notifications: e.notifications.clone(),
+            flagged_likes: e.flagged_likes.clone(),
+            blocked_terms: e.blocked_terms.clone(),
+            summaries: self.summarizer.is_configured(),
+            question_summary: e.question_summary.clone(),
+            sentiments: if self.sentiment {
+                e.sentiments.clone()
+            } else {
+                Vec::new()
+            },
         });
 
         if !is_mod && !admin {
             //TODO: can be NONE?
             e.tokens.moderator_token = Some(String::new());
 
-            e.questions = e
-                .questions
-                .into_iter()
-                .filter(|q| !q.hidden && !q.screening)
-                .collect::<Vec<_>>();
+            e.retain_visible_questions(participant.as_deref());
         }
 
         let time_out_masked = if admin { false } else { e.adapt_if_timedout() };
@@ -324,11 +699,9 @@ impl App {
         };
 
         let timed_out = e.is_timed_out_and_free();
-        let viewers = if admin || e.premium_id.is_some() {
-            self.viewers.count(&id).await
-        } else {
-            0
-        };
+        let translations =
+            self.translator.is_configured() && e.is_entitled(Entitlements::MODERATION);
+        let viewers = self.viewers.count(&id).await;
 
         let masked = time_out_masked || pwd_masked;
 
@@ -336,6 +709,7 @@ impl App {
 
         flags.set(EventResponseFlags::TIMED_OUT, timed_out);
         flags.set(EventResponseFlags::WRONG_PASSWORD, pwd_masked);
+        flags.set(EventResponseFlags::TRANSLATIONS, translations);
 
         Ok(GetEventResponse {
             info: e.into(),
@@ -344,9 +718,88 @@ impl App {
             flags,
             masked,
             mod_info,
+            version,
+            questions_total: None,
         })
     }
 
+    /// one page of the questions viewers see, masked like [`Self::get_event`] masks them
+    pub async fn get_questions(
+        &self,
+        id: String,
+        params: QuestionsParams,
+        password: Option<String>,
+        participant: Option<String>,
+    ) -> Result<QuestionsPage> {
+        tracing::info!("get_questions");
+
+        let entry = self.eventsdb.get(&id).await?;
+        let version = entry.version;
+        let mut e = entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        e.retain_visible_questions(participant.as_deref());
+
+        if !e.adapt_if_timedout() && e.password.is_enabled() && !e.password.matches(&password) {
+            e.mask_data();
+        }
+
+        Ok(QuestionsPage::new(e.questions, &params, version))
+    }
+
+    /// static html of the public event page, masked like [`Self::get_event`] masks it
+    pub async fn event_preview(&self, id: String) -> Result<String> {
+        let e = self.get_event(id.clone(), None, false, None, None).await?;
+
+        if e.info.is_deleted() {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        let url = format!("{}/event/{}", self.base_url, e.info.tokens.public_token);
+
+        export::event_preview(&e, url).map_err(|e| InternalError::General(e.to_string()))
+    }
+
+    /// long polling fallback for clients that cannot use websockets:
+    /// waits for the event version to be newer than `since` (or a timeout) and returns the current one
+    pub async fn event_updates(&self, id: String, since: usize) -> Result<EventUpdates> {
+        let notify = Arc::clone(self.long_polls.write().await.entry(id.clone()).or_default());
+
+        let version = self.wait_for_update(&notify, &id, since).await;
+
+        //Note: nobody else waits if only the map and we hold it
+        {
+            let mut long_polls = self.long_polls.write().await;
+            if Arc::strong_count(&notify) <= 2 {
+                long_polls.remove(&id);
+            }
+        }
+
+        Ok(EventUpdates { version: version? })
+    }
+
+    async fn wait_for_update(&self, notify: &Notify, id: &str, since: usize) -> Result<usize> {
+        //Note: registers for notifications right away so nothing gets lost until we wait
+        let notified = notify.notified();
+
+        let version = self.eventsdb.get(id).await?.version;
+        if version > since {
+            return Ok(version);
+        }
+
+        if tokio::time::timeout(LONG_POLL_TIMEOUT, notified)
+            .await
+            .is_err()
+        {
+            tracing::debug!("long poll timeout: {id}");
+        }
+
+        Ok(self.eventsdb.get(id).await?.version)
+    }
+
     pub async fn get_question(
         &self,
         id: String,
@@ -364,22 +817,82 @@ impl App {
             .moderator_token
             .clone()
             .zip(secret.clone())
-            .is_some_and(|tokens| tokens.0 != tokens.1);
+            .is_some_and(|tokens| tokens.0 == tokens.1);
 
-        let q = e
+        let mut q = e
             .questions
             .iter()
             .find(|q| q.id == question_id)
             .ok_or_else(|| InternalError::General("q not found".into()))?
             .clone();
 
-        if (q.screening || q.hidden) && !is_mod {
-            bail!("q not found")
+        if !is_mod {
+            if q.screening || q.hidden || q.shadowed {
+                bail!("q not found")
+            }
+
+            q.note = None;
         }
 
         Ok(q)
     }
 
+    /// text of a question in `language`, moderators can translate questions not public yet
+    pub async fn translate_question(
+        &self,
+        id: String,
+        secret: Option<String>,
+        password: Option<String>,
+        question_id: i64,
+        language: String,
+    ) -> Result<QuestionTranslation> {
+        if shared::language_name(&language).is_none() {
+            bail!("unknown language");
+        }
+
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if !e.is_entitled(Entitlements::MODERATION) {
+            return Err(InternalError::PremiumOnlyFeature(id));
+        }
+
+        let is_mod = e
+            .tokens
+            .moderator_token
+            .as_ref()
+            .zip(secret.as_ref())
+            .is_some_and(|tokens| tokens.0 == tokens.1);
+
+        //Note: the text of a question is only translated for those allowed to read it
+        if !is_mod && e.password.is_enabled() && !e.password.matches(&password) {
+            bail!("wrong password")
+        }
+
+        let Some(q) = e.questions.iter().find(|q| q.id == question_id) else {
+            bail!("q not found")
+        };
+
+        if !is_mod && (q.screening || q.hidden || q.shadowed) {
+            bail!("q not found")
+        }
+
+        let text = self.translator.translate(&q.text, &language).await?;
+
+        Ok(QuestionTranslation {
+            question_id,
+            language,
+            text,
+        })
+    }
+
     pub async fn mod_edit_question(
         &self,
         id: String,
@@ -417,6 +930,8 @@ impl App {
 
             q.hidden = state.hide;
             q.answered = state.answered;
+            //Note: only open questions stay pinned
+            q.pinned = state.pinned && !state.hide && !state.answered;
 
             if q.screening && state.screened {
                 q.screening = false;
@@ -427,107 +942,144 @@ impl App {
             }
         }
 
+        let shadowed = state
+            .shadow_ban
+            .is_some_and(|ban| Self::shadow_ban(&mut entry.event, question_id, ban));
+
         entry.bump();
 
         let e = entry.event.clone();
 
         self.eventsdb.put(entry).await?;
 
-        self.notify_subscribers(&id, Notification::Question(question_id))
-            .await;
+        //Note: shadowing changes which questions viewers see, they have to refetch
+        let msg = e
+            .questions
+            .iter()
+            .find(|q| q.id == question_id)
+            .filter(|_| !shadowed)
+            .map_or(WsMessage::Event, |q| {
+                WsMessage::Delta(EventDelta::QuestionStateChanged {
+                    id: q.id,
+                    hidden: q.hidden,
+                    answered: q.answered,
+                    screening: q.screening,
+                    pinned: q.pinned,
+                })
+            });
+
+        self.notify_subscribers(&id, msg).await;
 
         Ok(e.into())
     }
 
-    pub async fn mod_edit_event(
+    /// applies `bulk` to all its questions in a single write, fails if any of them is unknown
+    pub async fn mod_edit_questions(
         &self,
         id: String,
         secret: String,
-        changes: ModEvent,
+        bulk: ModQuestionsBulk,
     ) -> Result<EventInfo> {
-        let mut entry = self.eventsdb.get(&id).await?.clone();
-
-        let e = &mut entry.event;
-
-        if e.deleted {
-            return Err(InternalError::AccessingDeletedEvent(id));
-        }
+        tracing::info!("mod_edit_questions: {:?}", bulk);
 
-        if e.is_timed_out_and_free() {
-            return Err(InternalError::TimedOutFreeEvent(id));
+        if !bulk.is_valid() {
+            bail!("invalid bulk moderation");
         }
 
-        if e.tokens
-            .moderator_token
-            .as_ref()
-            .is_some_and(|mod_token| mod_token != &secret)
+        let mut entry = self.eventsdb.get(&id).await?;
         {
-            return Err(InternalError::WrongModeratorToken(id));
-        }
+            let e = &mut entry.event;
 
-        if let Some(state) = changes.state {
-            e.state = state;
-        }
-        if let Some(screening) = changes.screening {
-            e.do_screening = screening;
-        }
-        if let Some(password) = changes.password {
-            self.mod_edit_password(e, password).await?;
-        }
-        if let Some(current_tag) = &changes.current_tag {
-            self.mod_edit_tag(e, current_tag).await?;
-        }
-        if let Some(context_link) = &changes.context {
-            self.mod_context(e, context_link).await?;
-        }
-        if let Some(meta) = &changes.meta {
-            self.mod_meta(e, meta).await?;
-        }
+            if e.deleted {
+                return Err(InternalError::AccessingDeletedEvent(id));
+            }
 
-        let result = e.clone();
+            if e.is_timed_out_and_free() {
+                return Err(InternalError::TimedOutFreeEvent(id));
+            }
 
-        entry.bump();
+            if e.tokens
+                .moderator_token
+                .as_ref()
+                .is_some_and(|mod_token| mod_token != &secret)
+            {
+                return Err(InternalError::WrongModeratorToken(id));
+            }
 
-        self.eventsdb.put(entry).await?;
+            if bulk
+                .questions
+                .iter()
+                .any(|question_id| !e.questions.iter().any(|q| q.id == *question_id))
+            {
+                bail!("q not found");
+            }
 
-        self.notify_subscribers(&id, Notification::Event).await;
+            let tag = match &bulk.action {
+                BulkAction::Tag(Some(tag)) => {
+                    if !e.is_entitled(Entitlements::MODERATION) {
+                        return Err(InternalError::PremiumOnlyFeature(id));
+                    }
 
-        Ok(result.into())
-    }
+                    let mut validation = TagValidation::default();
+                    validation.check(tag);
 
-    pub async fn delete_event(&self, id: String, secret: String) -> Result<()> {
-        let mut entry = self.eventsdb.get(&id).await?;
+                    if validation.has_any() {
+                        return Err(InternalError::TagValidation(validation));
+                    }
 
-        let e = &mut entry.event;
+                    let Some(tag) = e.tags.get_or_add_tag(tag) else {
+                        bail!("max tags reached");
+                    };
 
-        if e.tokens
-            .moderator_token
-            .as_ref()
-            .is_some_and(|mod_token| mod_token != &secret)
-        {
-            return Err(InternalError::WrongModeratorToken(id));
+                    Some(tag)
+                }
+                _ => None,
+            };
+
+            for q in e
+                .questions
+                .iter_mut()
+                .filter(|q| bulk.questions.contains(&q.id))
+            {
+                match bulk.action {
+                    BulkAction::Hide => {
+                        q.hidden = true;
+                        q.pinned = false;
+                        //hiding an unscreened question equals a dis-approval
+                        q.screening = false;
+                    }
+                    BulkAction::Answer => {
+                        q.answered = true;
+                        q.pinned = false;
+                    }
+                    BulkAction::Tag(_) => q.tag = tag,
+                }
+            }
         }
 
-        e.deleted = true;
-        e.delete_time_unix = timestamp_now();
-
         entry.bump();
 
+        let e = entry.event.clone();
+
         self.eventsdb.put(entry).await?;
 
-        self.notify_subscribers(&id, Notification::Event).await;
+        self.notify_subscribers(&id, WsMessage::Event).await;
 
-        Ok(())
+        Ok(e.into())
     }
 
-    pub async fn request_premium_upgrade(
+    /// groups of open questions that look like they ask the same
+    #[instrument(skip(self, secret))]
+    pub async fn mod_question_clusters(
         &self,
         id: String,
         secret: String,
-    ) -> Result<EventUpgrade> {
-        let mut entry = self.eventsdb.get(&id).await?;
+    ) -> Result<Vec<QuestionCluster>> {
+        let e = self.eventsdb.get(&id).await?.event;
 
-        let e = &mut entry.event;
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
 
         if e.tokens
             .moderator_token
@@ -537,1117 +1089,9143 @@ impl App {
             return Err(InternalError::WrongModeratorToken(id));
         }
 
-        if e.deleted {
-            return Err(InternalError::AccessingDeletedEvent(id));
-        }
-
-        let mod_url = self.mod_link(&e.tokens);
-        let approve_url = self
-            .payment
-            .create_order(
-                &e.tokens.public_token,
-                &mod_url,
-                &format!("{mod_url}?payment=true&token={{CHECKOUT_SESSION_ID}}"),
-            )
-            .await?;
+        let open: Vec<&QuestionItem> = e
+            .questions
+            .iter()
+            .filter(|q| !q.hidden && !q.answered && !q.screening)
+            .collect();
 
-        Ok(EventUpgrade { url: approve_url })
+        Ok(clusters::cluster_questions(&open))
     }
 
-    #[instrument(skip(self))]
-    pub async fn premium_capture(
+    /// hides the questions as duplicates of the target, which takes over their likes
+    pub async fn mod_merge_questions(
         &self,
         id: String,
-        stripe_order_id: String,
-    ) -> Result<PaymentCapture> {
-        tracing::info!("premium_capture");
-
-        let (event_id, complete) = self
-            .payment
-            .retrieve_event_state(stripe_order_id.clone())
-            .await?;
+        secret: String,
+        merge: ModMergeQuestions,
+    ) -> Result<EventInfo> {
+        tracing::info!("mod_merge_questions: {:?}", merge);
 
-        if event_id != id {
-            return Err(InternalError::General("invalid parameter".into()));
+        if !merge.is_valid() {
+            bail!("invalid merge");
         }
 
-        let order_captured = complete;
+        let mut entry = self.eventsdb.get(&id).await?;
+        {
+            let e = &mut entry.event;
 
-        if order_captured {
-            self.capture_payment_and_upgrade(id, stripe_order_id)
-                .await?;
-        }
+            if e.deleted {
+                return Err(InternalError::AccessingDeletedEvent(id));
+            }
 
-        Ok(PaymentCapture { order_captured })
-    }
+            if e.is_timed_out_and_free() {
+                return Err(InternalError::TimedOutFreeEvent(id));
+            }
 
-    #[instrument(skip(self))]
-    pub async fn payment_webhook(&self, stripe_session_id: String, event_id: String) -> Result<()> {
-        tracing::info!("order processing");
+            if e.tokens
+                .moderator_token
+                .as_ref()
+                .is_some_and(|mod_token| mod_token != &secret)
+            {
+                return Err(InternalError::WrongModeratorToken(id));
+            }
 
-        if !self
-            .capture_payment_and_upgrade(event_id, stripe_session_id)
-            .await?
-        {
-            tracing::warn!("webhook failed");
-        }
+            if !e
+                .questions
+                .iter()
+                .any(|q| q.id == merge.target && !q.hidden)
+            {
+                bail!("merge target not found");
+            }
 
-        Ok(())
-    }
+            if merge.questions.iter().any(|question_id| {
+                !e.questions
+                    .iter()
+                    .any(|q| q.id == *question_id && q.merged_into.is_none())
+            }) {
+                bail!("q not found");
+            }
 
-    async fn capture_payment_and_upgrade(
-        &self,
-        event: String,
-        stripe_session_id: String,
-    ) -> Result<bool> {
-        let mut entry = self.eventsdb.get(&event).await?;
+            let mut likes = 0_i32;
 
-        if entry.event.premium_id.is_some() {
-            tracing::info!("event already premium");
-            return Ok(true);
-        }
+            for q in e
+                .questions
+                .iter_mut()
+                .filter(|q| merge.questions.contains(&q.id))
+            {
+                likes = likes.saturating_add(q.likes);
+                q.merged_into = Some(merge.target);
+                q.hidden = true;
+                q.pinned = false;
+                q.screening = false;
+            }
 
-        entry.event.premium_id = Some(PremiumOrder::StripeSessionId(stripe_session_id));
+            if let Some(target) = e.questions.iter_mut().find(|q| q.id == merge.target) {
+                target.likes = target.likes.saturating_add(likes);
+            }
+        }
 
         entry.bump();
 
-        let (name, long_url, age) = (
-            entry.event.data.name.clone(),
-            entry.event.data.long_url.clone().unwrap_or_default(),
-            entry.event.age_in_seconds(),
-        );
+        let e = entry.event.clone();
 
         self.eventsdb.put(entry).await?;
 
-        self.notify_subscribers(&event, Notification::Event).await;
-
-        self.tracking
-            .track_event_upgrade(event.clone(), name, long_url, age)
-            .await?;
+        self.notify_subscribers(&id, WsMessage::Event).await;
 
-        Ok(true)
+        Ok(e.into())
     }
 
-    //TODO: fix clippy-allow
-    #[allow(clippy::cast_possible_wrap)]
-    pub async fn add_question(
+    /// sets the private moderator note of a question, returns the question including it
+    pub async fn mod_question_note(
         &self,
         id: String,
-        question: shared::AddQuestion,
+        secret: String,
+        question_id: i64,
+        note: ModQuestionNote,
     ) -> Result<QuestionItem> {
-        let trimmed_question = question.text.trim().to_string();
-
-        let mut validation = shared::AddQuestionValidation::default();
-
-        validation.check(&trimmed_question);
-
-        if validation.has_any() {
-            return Err(InternalError::AddQuestionValidation(validation));
+        if !note.is_valid() {
+            bail!("invalid question note");
         }
 
         let mut entry = self.eventsdb.get(&id).await?;
 
         let e = &mut entry.event;
 
-        if e.is_timed_out_and_free() {
-            return Err(InternalError::TimedOutFreeEvent(id));
-        }
-
-        if e.questions.len() > 500 {
-            bail!("max number of questions reached");
-        }
-
-        if !matches!(e.state.state, States::Open) {
-            bail!("event not open");
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
         }
 
-        if e.questions
-            .iter()
-            .any(|q| q.text.trim() == trimmed_question)
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
         {
-            return Err(InternalError::DuplicateQuestion);
+            return Err(InternalError::WrongModeratorToken(id));
         }
 
-        let question_id = e.questions.len() as i64;
+        let q = e
+            .questions
+            .iter_mut()
+            .find(|q| q.id == question_id)
+            .ok_or_else(|| InternalError::General("q not found".into()))?;
 
-        let question = shared::QuestionItem {
-            text: trimmed_question,
-            answered: false,
-            create_time_unix: timestamp_now(),
-            hidden: false,
-            screening: e.do_screening,
-            id: question_id,
-            likes: 1,
-            tag: e.tags.current_tag,
-        };
+        let note = note.note.trim();
+        q.note = (!note.is_empty()).then(|| note.to_string());
 
-        e.questions.push(question.clone());
+        let question = q.clone();
 
         entry.bump();
 
         self.eventsdb.put(entry).await?;
 
-        self.notify_subscribers(&id, Notification::Question(question_id))
+        self.notify_subscribers(&id, WsMessage::Question(question_id))
             .await;
 
         Ok(question)
     }
 
-    pub async fn edit_like(&self, id: String, edit: shared::EditLike) -> Result<QuestionItem> {
+    /// (un)shadows the question and every other one of its author, whose future questions
+    /// get shadowed as well, returns `true` if any question changed
+    fn shadow_ban(e: &mut ApiEventInfo, question_id: i64, ban: bool) -> bool {
+        let author = e
+            .participant_questions
+            .iter()
+            .find(|(_, questions)| questions.contains(&question_id))
+            .map(|(participant, questions)| (participant.clone(), questions.clone()));
+
+        let questions = match author {
+            Some((participant, questions)) => {
+                e.shadow_banned.retain(|banned| banned != &participant);
+                if ban {
+                    e.shadow_banned.push(participant);
+                }
+                questions
+            }
+            None => vec![question_id],
+        };
+
+        let mut changed = false;
+
+        for q in e.questions.iter_mut().filter(|q| questions.contains(&q.id)) {
+            changed |= q.shadowed != ban;
+            q.shadowed = ban;
+        }
+
+        changed
+    }
+
+    pub async fn mod_edit_event(
+        &self,
+        id: String,
+        secret: String,
+        changes: ModEvent,
+    ) -> Result<EventInfo> {
         let mut entry = self.eventsdb.get(&id).await?.clone();
 
         let e = &mut entry.event;
 
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
         if e.is_timed_out_and_free() {
             return Err(InternalError::TimedOutFreeEvent(id));
         }
 
-        if matches!(e.state.state, States::Closed) {
-            bail!("event closed");
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
         }
 
-        if let Some(f) = e.questions.iter_mut().find(|e| e.id == edit.question_id) {
-            f.likes = if edit.like {
-                f.likes.saturating_add(1)
-            } else {
-                f.likes.saturating_sub(1)
-            };
+        //Note: plain state changes can be sent as a delta, anything else makes clients refetch
+        let state_only = changes.state.filter(|state| {
+            changes
+                == ModEvent {
+                    state: Some(*state),
+                    ..Default::default()
+                }
+        });
 
-            let res = f.clone();
+        if let Some(state) = changes.state {
+            e.state = state;
+        }
+        if let Some(screening) = changes.screening {
+            e.do_screening = screening;
+        }
+        if let Some(question_names) = changes.question_names {
+            e.question_names = question_names;
+        }
+        if let Some(downvotes) = changes.downvotes {
+            e.downvotes = downvotes;
+        }
+        if let Some(survey) = changes.survey {
+            e.survey = survey;
+        }
+        if let Some(question_length) = changes.question_length {
+            if !question_length.is_valid() {
+                bail!("invalid question length");
+            }
+            e.question_length = question_length;
+        }
+        if let Some(cooldown) = changes.question_cooldown_mins {
+            if cooldown > QUESTION_COOLDOWN_MAX_MINS {
+                bail!("invalid question cooldown");
+            }
+            e.question_cooldown_mins = cooldown;
+            if cooldown == 0 {
+                e.last_questions.clear();
+            }
+        }
+        if let Some(limits) = changes.question_limits {
+            if !limits.is_valid() {
+                bail!("invalid question limits");
+            }
+            e.question_limits = limits;
+        }
+        if let Some(blocked_terms) = changes.blocked_terms {
+            if !blocked_terms.is_valid() {
+                bail!("invalid blocked terms");
+            }
+            e.blocked_terms = blocked_terms.normalized();
+        }
+        if let Some(languages) = changes.question_languages {
+            if !languages.is_valid() {
+                bail!("invalid question languages");
+            }
+            e.question_languages = languages;
+        }
+        if let Some(capacity) = changes.viewer_capacity {
+            if capacity > 0 && !e.is_entitled(Entitlements::MODERATION) {
+                return Err(InternalError::PremiumOnlyFeature(id));
+            }
+            if capacity > VIEWER_CAPACITY_MAX {
+                bail!("invalid viewer capacity");
+            }
+            e.viewer_capacity = capacity;
+        }
+        if let Some(password) = changes.password {
+            self.mod_edit_password(e, password).await?;
+        }
+        if let Some(current_tag) = &changes.current_tag {
+            self.mod_edit_tag(e, current_tag).await?;
+        }
+        if let Some(context_link) = &changes.context {
+            self.mod_context(e, context_link).await?;
+        }
+        if let Some(meta) = &changes.meta {
+            self.mod_meta(e, meta).await?;
+        }
+        if let Some(branding) = &changes.branding {
+            Self::mod_branding(e, branding)?;
+        }
+        if let Some(theme) = changes.theme {
+            if matches!(theme, EventTheme::Custom) && !e.is_entitled(Entitlements::BRANDING) {
+                return Err(InternalError::PremiumOnlyFeature(id));
+            }
+            e.theme = theme;
+        }
+        if let Some(hide_branding) = changes.hide_branding {
+            if hide_branding && !e.is_entitled(Entitlements::BRANDING) {
+                return Err(InternalError::PremiumOnlyFeature(id));
+            }
+            e.hide_branding = hide_branding;
+        }
+        if let Some(notifications) = changes.notifications {
+            Self::mod_notifications(e, notifications)?;
+        }
 
-            entry.bump();
+        let result = e.clone();
 
-            self.eventsdb.put(entry).await?;
+        entry.bump();
 
-            self.notify_subscribers(&id, Notification::Question(edit.question_id))
-                .await;
+        self.eventsdb.put(entry).await?;
 
-            Ok(res)
-        } else {
-            bail!("question not found")
+        if let Some(capacity) = changes.viewer_capacity {
+            self.viewer_capacities.write().await.insert(
+                id.clone(),
+                CachedCapacity {
+                    fetched_unix: timestamp_now(),
+                    capacity,
+                },
+            );
         }
-    }
-
-    pub async fn push_subscriber(&self, ws: WebSocket, id: String) {
-        use futures_util::StreamExt;
-
-        let (ws_sender, mut ws_receiver) = ws.split();
-
-        let user_id = NEXT_USER_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
-        let send_channel = Self::create_send_channel(ws_sender);
+        let msg = state_only.map_or(WsMessage::Event, |state| {
+            WsMessage::Delta(EventDelta::EventStateChanged(state))
+        });
 
-        self.channels
-            .write()
-            .await
-            .insert(user_id, (id.clone(), send_channel.clone()));
+        self.notify_subscribers(&id, msg).await;
 
-        self.viewers.add(&id).await;
+        Ok(result.into())
+    }
 
-        self.notify_viewer_count_change(&id);
+    #[instrument(skip(self, secret, subscription))]
+    pub async fn mod_push_subscribe(
+        &self,
+        id: String,
+        secret: String,
+        subscription: WebPushSubscription,
+    ) -> Result<()> {
+        let mut entry = self.eventsdb.get(&id).await?;
 
-        tracing::info!(
-            "user connected: {} ({} total)",
-            user_id,
-            self.channels.read().await.len()
-        );
+        let e = &mut entry.event;
 
-        while let Some(result) = ws_receiver.next().await {
-            let msg = match result {
-                Ok(msg) => msg,
-                Err(e) => {
-                    tracing::info!("websocket receive err (id={}): '{}'", user_id, e);
-                    break;
-                }
-            };
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
 
-            //allow receiving `p` for app based pings
-            if !matches!(&msg, Message::Text(text) if text=="p") {
-                match &msg {
-                    //TODO: do we need to respond manually?
-                    Message::Ping(_) => tracing::info!("received msg:ping"),
-                    Message::Pong(_) => tracing::warn!("received msg:pong"),
-                    Message::Text(txt) => tracing::warn!("received msg:text: '{txt}'"),
-                    Message::Binary(bin) => tracing::warn!("received msg:binary: {}b", bin.len()),
-                    Message::Close(frame) => tracing::info!("received msg:close: {frame:?}"),
-                }
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
 
-                let (disconnect, sent_data) = match msg {
-                    Message::Ping(_) | Message::Pong(_) => (false, false),
-                    Message::Close(_) => (true, false),
-                    _ => (true, true),
-                };
+        if !webpush::is_push_service(&subscription.endpoint)
+            || subscription.keys.p256dh.is_empty()
+            || subscription.keys.auth.is_empty()
+        {
+            bail!("invalid push subscription");
+        }
 
-                if disconnect {
-                    if sent_data {
-                        tracing::warn!("user:{} sent data, disconnecting", user_id);
-                    }
-                    break;
-                }
-            }
+        e.push_subscriptions
+            .retain(|existing| existing.endpoint != subscription.endpoint);
+        e.push_subscriptions.push(subscription);
 
-            if self.is_shutting_down() {
-                tracing::info!("shutdown: close client connection [{user_id}]");
+        if e.push_subscriptions.len() > WEB_PUSH_MAX_SUBSCRIPTIONS {
+            e.push_subscriptions.remove(0);
+        }
 
-                if let Err(e) = send_channel.send(Ok(Message::Close(Some(CloseFrame {
-                    code: RESTART,
-                    reason: "server shutdown".into(),
-                })))) {
-                    tracing::error!("shutdown error: {e}");
-                }
+        entry.bump();
 
-                break;
-            }
-        }
+        self.eventsdb.put(entry).await?;
 
-        tracing::info!(
-            "user disconnected: {} ({} remain)",
-            user_id,
-            self.channels.read().await.len().saturating_sub(1)
-        );
+        Ok(())
+    }
 
-        self.viewers.remove(&id).await;
+    #[instrument(skip(self, secret, unsubscribe))]
+    pub async fn mod_push_unsubscribe(
+        &self,
+        id: String,
+        secret: String,
+        unsubscribe: WebPushUnsubscribe,
+    ) -> Result<()> {
+        let mut entry = self.eventsdb.get(&id).await?;
 
-        //Note: lets not spam everyone if its a shutdown
-        if !self.is_shutting_down() {
-            self.notify_viewer_count_change(&id);
+        let e = &mut entry.event;
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
         }
 
-        self.channels.write().await.remove(&user_id);
-    }
+        let count = e.push_subscriptions.len();
+        e.push_subscriptions
+            .retain(|existing| existing.endpoint != unsubscribe.endpoint);
 
-    fn is_shutting_down(&self) -> bool {
-        self.shutdown.load(Ordering::Relaxed)
-    }
+        if e.push_subscriptions.len() == count {
+            return Ok(());
+        }
 
-    fn notify_viewer_count_change(&self, event: &str) {
-        let event = event.to_string();
-        let app = self.clone();
+        entry.bump();
 
-        tokio::spawn(async move {
-            let count = app.viewers.count(&event).await;
+        self.eventsdb.put(entry).await?;
 
-            tracing::info!("notify viewer count: {count}");
+        Ok(())
+    }
 
-            app.notify_subscribers(&event, Notification::Viewers(count))
-                .await;
-        });
+    pub fn web_push_key(&self) -> Result<String> {
+        Ok(self.web_push.public_key()?)
     }
 
-    fn create_send_channel(
-        ws_sender: futures_util::stream::SplitSink<WebSocket, axum::extract::ws::Message>,
-    ) -> OutBoundChannel {
-        use futures_util::FutureExt;
-        use futures_util::StreamExt;
-        use tokio_stream::wrappers::UnboundedReceiverStream;
+    /// generates a new summary of the most liked questions and keeps it for the moderators
+    #[instrument(skip(self, secret))]
+    pub async fn mod_question_summary(
+        &self,
+        id: String,
+        secret: String,
+    ) -> Result<QuestionSummary> {
+        let entry = self.eventsdb.get(&id).await?;
 
-        let (sender, receiver) = mpsc::unbounded_channel();
-        let rx = UnboundedReceiverStream::new(receiver);
+        let e = &entry.event;
 
-        tokio::task::spawn(rx.forward(ws_sender).map(|result| {
-            if let Err(e) = result {
-                let error_string_lowcase = e.to_string().to_lowercase();
-                let well_known = error_string_lowcase.starts_with("connection closed normally")
-                    || error_string_lowcase.starts_with("trying to work with closed connection");
-                if !well_known {
-                    tracing::warn!("websocket send error: {}", e);
-                }
-            }
-        }));
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
 
-        sender
-    }
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
 
-    async fn notify_subscribers(&self, event_id: &str, n: Notification) {
-        let msg = match n {
-            Notification::Event => "e".to_string(),
-            Notification::Question(id) => format!("q:{id}"),
-            Notification::Viewers(count) => format!("v:{count}"),
-        };
+        if !e.is_entitled(Entitlements::MODERATION) {
+            return Err(InternalError::PremiumOnlyFeature(id));
+        }
 
-        self.pubsub_publish.publish(event_id, &msg).await;
-    }
+        let mut questions: Vec<&QuestionItem> = e
+            .questions
+            .iter()
+            .filter(|q| !q.hidden && !q.screening && !q.shadowed)
+            .collect();
 
-    fn send_mail(
-        &self,
-        event_id: String,
-        receiver: String,
-        event_name: String,
-        public_link: String,
-        mod_link: String,
-    ) {
-        if receiver.trim().is_empty() {
-            tracing::debug!("mail not sent, no receiver specified");
-            return;
+        if questions.is_empty() {
+            bail!("no questions to summarize");
         }
 
-        let mail = self.mail_config.clone();
+        questions.sort_by_key(|q| std::cmp::Reverse(q.likes));
 
-        tokio::spawn(async move {
-            if let Err(e) = mail
-                .send_mail(
-                    event_id,
-                    receiver.clone(),
-                    event_name,
-                    public_link,
-                    mod_link,
-                )
-                .await
-            {
-                tracing::error!("mail send error: {e}");
-            }
-        });
-    }
+        let texts: Vec<String> = questions
+            .into_iter()
+            .take(QUESTION_SUMMARY_MAX_QUESTIONS)
+            .map(|q| q.text.clone())
+            .collect();
 
-    async fn mod_edit_password(
-        &self,
-        e: &mut ApiEventInfo,
-        password: shared::EventPassword,
-    ) -> Result<()> {
-        let edit_type = match (e.password.is_enabled(), password.is_enabled()) {
-            (false, true) => Some(EditEvent::Enabled),
-            (true, false) => Some(EditEvent::Disabled),
-            (true, true) => Some(EditEvent::Changed),
-            _ => None,
+        //Note: the endpoint may take a while, the event is read again to store the result
+        let text = self.summarizer.summarize(&texts).await?;
+
+        let summary = QuestionSummary {
+            text: text.chars().take(QUESTION_SUMMARY_MAX_LENGTH).collect(),
+            questions: texts.len(),
+            create_time_unix: timestamp_now(),
         };
 
-        if let Some(edit_type) = edit_type {
-            self.tracking
-                .track_event_password_set(e.tokens.public_token.clone(), edit_type)
-                .await?;
-        }
+        let mut entry = self.eventsdb.get(&id).await?;
 
-        e.password = password;
+        entry.event.question_summary = Some(summary.clone());
 
-        Ok(())
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        Ok(summary)
     }
 
-    async fn mod_edit_tag(
+    /// `None` if the moderators turned off or have no way to receive notifications
+    fn moderator_notification(
         &self,
-        e: &mut ApiEventInfo,
-        current_tag: &shared::CurrentTag,
-    ) -> Result<()> {
-        if e.premium_id.is_none() {
-            return Err(InternalError::PremiumOnlyFeature(
-                e.tokens.public_token.clone(),
-            ));
-        }
-
-        let edit_type = match (e.tags.current_tag.is_some(), current_tag.is_enabled()) {
-            (false, true) => Some(EditEvent::Enabled),
-            (true, false) => Some(EditEvent::Disabled),
-            (true, true) => Some(EditEvent::Changed),
-            _ => None,
+        e: &ApiEventInfo,
+        text: &str,
+    ) -> Option<ModeratorNotification> {
+        let subscriptions = if e.notifications.push && self.web_push.is_configured() {
+            e.push_subscriptions.clone()
+        } else {
+            Vec::new()
         };
+        let email = e.notifications.email.clone();
 
-        if let Some(edit_type) = edit_type {
-            self.tracking
-                .track_event_tag_set(e.tokens.public_token.clone(), edit_type, e.age_in_seconds())
-                .await?;
+        if subscriptions.is_empty() && email.is_none() {
+            return None;
         }
 
-        if let shared::CurrentTag::Enabled(tag) = &current_tag {
-            let mut validation = TagValidation::default();
-            validation.check(tag);
-
-            if validation.has_any() {
-                return Err(InternalError::TagValidation(validation));
-            }
-
-            if !e.tags.set_or_add_tag(tag) {
-                bail!("max tags reached");
-            }
+        let body = if text.chars().count() > PUSH_BODY_MAX_CHARS {
+            let cut: String = text.chars().take(PUSH_BODY_MAX_CHARS).collect();
+            format!("{}...", cut.trim_end())
         } else {
-            e.tags.current_tag = None;
-        }
+            text.to_string()
+        };
 
-        Ok(())
+        Some(ModeratorNotification {
+            subscriptions,
+            email,
+            notification: WebPushNotification {
+                title: e.data.name.clone(),
+                body,
+                url: self.mod_link(&e.tokens),
+            },
+        })
     }
 
-    async fn mod_context(
-        &self,
-        e: &mut ApiEventInfo,
-        context_link: &shared::EditContextLink,
-    ) -> Result<()> {
-        if e.premium_id.is_none() {
-            return Err(InternalError::PremiumOnlyFeature(
-                e.tokens.public_token.clone(),
-            ));
-        }
+    /// push subscriptions the push service reports as gone are removed from the event
+    fn send_notification(&self, id: String, notification: ModeratorNotification) {
+        let web_push = self.web_push.clone();
+        let mail = self.mail_config.clone();
+        let eventsdb = Arc::clone(&self.eventsdb);
 
-        match context_link {
-            shared::EditContextLink::Disabled => e.context = vec![],
-            shared::EditContextLink::Enabled(item) => {
-                let mut validation = ContextValidation::default();
+        tokio::spawn(async move {
+            let ModeratorNotification {
+                subscriptions,
+                email,
+                notification,
+            } = notification;
+
+            if let Some(receiver) = email {
+                if let Err(e) = mail.send_notification(receiver, &notification).await {
+                    tracing::error!("notification mail error: {e}");
+                }
+            }
 
-                validation.check(&item.label, &item.url);
-                if validation.has_any() {
-                    return Err(InternalError::ContextValidation(validation));
+            let mut gone = Vec::new();
+
+            for subscription in subscriptions {
+                match web_push.send(&subscription, &notification).await {
+                    Ok(()) => {}
+                    Err(WebPushError::Gone) => gone.push(subscription.endpoint),
+                    Err(e) => tracing::warn!("web push error: {e}"),
                 }
+            }
 
-                e.context = vec![item.clone()];
+            if gone.is_empty() {
+                return;
+            }
 
-                self.tracking
-                    .track_event_context_set(e.tokens.public_token.clone(), &item.label, &item.url)
-                    .await?;
+            //Note: a concurrent write only delays the cleanup until the next notification
+            let result = async {
+                let mut entry = eventsdb.get(&id).await?;
+                entry
+                    .event
+                    .push_subscriptions
+                    .retain(|subscription| !gone.contains(&subscription.endpoint));
+                entry.bump();
+                eventsdb.put(entry).await
             }
-        }
+            .await;
 
-        Ok(())
+            if let Err(e) = result {
+                tracing::warn!("web push cleanup error: {e}");
+            }
+        });
     }
 
-    async fn mod_meta(&self, e: &mut ApiEventInfo, edit: &shared::EditMetaData) -> Result<()> {
-        if !shared::EventInfo::during_first_day(e.create_time_unix) {
-            bail!("event meta can only be changed during first 24h")
-        }
+    #[instrument(skip(self, secret))]
+    pub async fn mod_like_timeline(&self, id: String, secret: String) -> Result<LikeTimeline> {
+        let e = self.eventsdb.get(&id).await?.event;
 
-        let validation =
-            shared::CreateEventValidation::default().check(&edit.title, &edit.description, "");
-        if validation.has_any() {
-            return Err(InternalError::MetaValidation(edit.clone()));
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
         }
 
-        e.data.name = edit.title.clone();
-        e.data.description = edit.description.clone();
-
-        self.tracking
-            .track_event_meta_change(e.tokens.public_token.clone(), edit)
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        Ok(self.like_timelines.get(&id).await)
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_event_stats(&self, id: String, secret: String) -> Result<EventStats> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if !e.is_entitled(Entitlements::ANALYTICS) {
+            return Err(InternalError::PremiumOnlyFeature(id));
+        }
+
+        let visible = e
+            .questions
+            .iter()
+            .filter(|q| !q.hidden && !q.screening && !q.shadowed);
+
+        let timeline = self.like_timelines.get(&id).await;
+
+        let likes = timeline
+            .questions
+            .iter()
+            .flat_map(|q| q.buckets.iter())
+            .map(|bucket| (bucket.minute, i64::from(bucket.likes)));
+
+        Ok(EventStats {
+            questions: stats_buckets(visible.clone().map(|q| (q.create_time_unix, 1_i64))),
+            likes: stats_buckets(likes),
+            peak_viewers: e.peak_viewers,
+            total: visible.clone().count(),
+            answered: visible.filter(|q| q.answered).count(),
+        })
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_export_csv(&self, id: String, secret: String) -> Result<String> {
+        let e = self.export_event(id, &secret).await?;
+
+        Ok(export::questions_csv(&e))
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_export_pdf(&self, id: String, secret: String) -> Result<Vec<u8>> {
+        let e = self.export_event(id, &secret).await?;
+
+        let share_url = if e.data.short_url.is_empty() {
+            e.data.long_url.clone().unwrap_or_default()
+        } else {
+            e.data.short_url.clone()
+        };
+
+        Ok(export::event_pdf(&e, &share_url))
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_export_markdown(&self, id: String, secret: String) -> Result<String> {
+        let e = self.export_event(id, &secret).await?;
+
+        Ok(export::event_markdown(&e))
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_export_slides(&self, id: String, secret: String) -> Result<String> {
+        let e = self.export_event(id, &secret).await?;
+
+        export::event_slides(&e).map_err(|e| InternalError::General(e.to_string()))
+    }
+
+    /// event to export for its moderator, exports are a premium feature
+    async fn export_event(&self, id: String, secret: &str) -> Result<ApiEventInfo> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if !e.is_entitled(Entitlements::EXPORT) {
+            return Err(InternalError::PremiumOnlyFeature(id));
+        }
+
+        Ok(e)
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_survey_results(&self, id: String, secret: String) -> Result<SurveyResults> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        Ok(SurveyResults::new(&e.survey_responses))
+    }
+
+    pub async fn delete_event(&self, id: String, secret: String) -> Result<()> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        e.deleted = true;
+        e.delete_time_unix = timestamp_now();
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, WsMessage::Event).await;
+
+        Ok(())
+    }
+
+    /// creates a fresh event with the description and settings of `id` but none of its questions
+    #[instrument(skip(self))]
+    pub async fn mod_duplicate_event(&self, id: String, secret: String) -> Result<EventInfo> {
+        let source = self.eventsdb.get(&id).await?.event;
+
+        if source.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if source
+            .tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        //Note: the schedule is not copied, it would usually lie in the past already
+        let data = EventData {
+            name: source.data.name.clone(),
+            description: source.data.description.clone(),
+            timezone: source.data.timezone.clone(),
+            ..Default::default()
+        };
+
+        Ok(self.copy_event(&source, data, None).await?.into())
+    }
+
+    /// creates a new event with `data` and the settings of `source`, questions and polls are not copied
+    async fn copy_event(
+        &self,
+        source: &ApiEventInfo,
+        data: EventData,
+        series: Option<EventSeries>,
+    ) -> Result<ApiEventInfo> {
+        let created = self
+            .create_event(AddEvent {
+                data,
+                moderator_email: None,
+                subscription: None,
+                test: false,
+            })
             .await?;
 
-        Ok(())
+        let id = created.tokens.public_token;
+
+        match self.copy_settings(source, &id, series).await {
+            Ok(copy) => Ok(copy),
+            Err(e) => {
+                self.discard_event(&id).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn copy_settings(
+        &self,
+        source: &ApiEventInfo,
+        id: &str,
+        series: Option<EventSeries>,
+    ) -> Result<ApiEventInfo> {
+        let mut entry = self.eventsdb.get(id).await?;
+
+        let e = &mut entry.event;
+
+        e.do_screening = source.do_screening;
+        e.question_names = source.question_names;
+        e.question_length = source.question_length;
+        e.question_cooldown_mins = source.question_cooldown_mins;
+        e.question_limits = source.question_limits;
+        e.viewer_capacity = source.viewer_capacity;
+        e.blocked_terms = source.blocked_terms.clone();
+        e.question_languages = source.question_languages.clone();
+        e.downvotes = source.downvotes;
+        e.survey = source.survey;
+        e.password = source.password.clone();
+        e.context = source.context.clone();
+        //Note: the custom theme relies on the branding, which only a series keeps
+        if !matches!(source.theme, EventTheme::Custom) {
+            e.theme = source.theme;
+        }
+        e.sessions = source
+            .sessions
+            .iter()
+            .map(|session| Session {
+                state: EventState::default(),
+                ..session.clone()
+            })
+            .collect();
+
+        //Note: all occurrences of a series share moderator token and premium status
+        if series.is_some() {
+            e.tokens.moderator_token = source.tokens.moderator_token.clone();
+            e.premium_id = source.premium_id.clone();
+            e.data.branding = source.data.branding.clone();
+            e.theme = source.theme;
+            e.hide_branding = source.hide_branding;
+            e.series = series;
+        }
+
+        let result = e.clone();
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        Ok(result)
+    }
+
+    /// deletes an event created as part of an operation that failed halfway, so it is not left behind
+    async fn discard_event(&self, id: &str) {
+        let res = async {
+            let mut entry = self.eventsdb.get(id).await?;
+
+            entry.event.deleted = true;
+            entry.event.delete_time_unix = timestamp_now();
+            entry.bump();
+
+            self.eventsdb.put(entry).await
+        }
+        .await;
+
+        if let Err(e) = res {
+            tracing::error!("failed to discard event {id}: {e}");
+        }
+    }
+
+    /// starts a recurring series with the event as its first occurrence, changes its interval or ends it
+    #[instrument(skip(self))]
+    pub async fn mod_edit_series(
+        &self,
+        id: String,
+        secret: String,
+        edit: EditSeries,
+    ) -> Result<EventSeries> {
+        let entry = self.eventsdb.get(&id).await?;
+
+        let e = &entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if !e.is_entitled(Entitlements::MODERATION) {
+            return Err(InternalError::PremiumOnlyFeature(id));
+        }
+
+        if !edit.is_valid() {
+            bail!("invalid series interval");
+        }
+
+        //Note: the series lives on its first event, any occurrence can edit it
+        let root = e
+            .series
+            .as_ref()
+            .map(|series| series.id.clone())
+            .filter(|root| root != &id);
+
+        let mut entry = match root {
+            Some(root) => self.eventsdb.get(&root).await?,
+            None => entry,
+        };
+
+        let e = &mut entry.event;
+        let root = e.tokens.public_token.clone();
+        let start = e.data.start_unix.unwrap_or(e.create_time_unix);
+
+        let series = e.series.get_or_insert_with(|| EventSeries {
+            id: root.clone(),
+            interval_days: 0,
+            occurrences: vec![SeriesOccurrence {
+                public_token: root.clone(),
+                start_unix: start,
+            }],
+        });
+
+        series.interval_days = edit.interval_days;
+
+        let result = series.clone();
+
+        if let Some(current) = result.current().filter(|_| result.is_active()) {
+            self.schedule
+                .add(
+                    &series_schedule_key(&root),
+                    current.start_unix + result.interval_secs(),
+                )
+                .await;
+        }
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, WsMessage::Event).await;
+
+        Ok(result)
+    }
+
+    /// all occurrences of the series `id` is part of, only for the moderator
+    #[instrument(skip(self))]
+    pub async fn mod_series_archive(&self, id: String, secret: String) -> Result<EventSeries> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        let Some(series) = e.series else {
+            bail!("event is not part of a series");
+        };
+
+        Ok(self
+            .eventsdb
+            .get(&series.id)
+            .await?
+            .event
+            .series
+            .unwrap_or(series))
+    }
+
+    /// latest occurrence of a series, the target of the stable series url
+    #[instrument(skip(self))]
+    pub async fn series_current(&self, id: String) -> Result<SeriesCurrent> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        let Some(current) = e.series.as_ref().and_then(EventSeries::current) else {
+            bail!("series not found");
+        };
+
+        Ok(SeriesCurrent {
+            public_token: current.public_token.clone(),
+        })
+    }
+
+    /// claims a custom slug for a premium event, its short url becomes `/e/<slug>`
+    #[instrument(skip(self))]
+    pub async fn mod_edit_slug(
+        &self,
+        id: String,
+        secret: String,
+        edit: EditSlug,
+    ) -> Result<EventInfo> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if !e.is_entitled(Entitlements::BRANDING) {
+            return Err(InternalError::PremiumOnlyFeature(id));
+        }
+
+        if !edit.is_valid() {
+            bail!("invalid slug");
+        }
+
+        //Note: previous slugs are not released and keep leading to the event
+        match self.eventsdb.claim_slug(&edit.slug, &id).await {
+            Err(eventsdb::Error::Concurrency) => return Err(InternalError::SlugTaken(edit.slug)),
+            res => res?,
+        }
+
+        e.data.short_url = format!("{}/e/{}", self.base_url, edit.slug);
+        e.slug = Some(edit.slug);
+
+        let result = e.clone();
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, WsMessage::Event).await;
+
+        Ok(result.into())
+    }
+
+    /// event a custom slug leads to
+    #[instrument(skip(self))]
+    pub async fn slug_target(&self, slug: String) -> Result<SlugTarget> {
+        Ok(SlugTarget {
+            public_token: self.eventsdb.get_slug(&slug).await?,
+        })
+    }
+
+    /// creates the next occurrence of a series once it is due
+    async fn apply_series(&self, id: &str, now: i64) -> Result<()> {
+        let root = self.eventsdb.get(id).await?.event;
+
+        let Some(series) = root
+            .series
+            .clone()
+            .filter(|s| s.is_active() && !root.deleted)
+        else {
+            return Ok(());
+        };
+        let Some(current) = series.current().cloned() else {
+            return Ok(());
+        };
+
+        let interval = series.interval_secs();
+        let due = current.start_unix + interval;
+
+        if due > now {
+            self.schedule.add(&series_schedule_key(id), due).await;
+            return Ok(());
+        }
+
+        //Note: occurrences missed while no server was running are skipped
+        let start = due + (now - due).div_euclid(interval) * interval;
+
+        let latest = if current.public_token == id {
+            root
+        } else {
+            self.eventsdb.get(&current.public_token).await?.event
+        };
+
+        let duration = latest
+            .data
+            .start_unix
+            .zip(latest.data.end_unix)
+            .map(|(start, end)| end - start);
+
+        let data = EventData {
+            name: latest.data.name.clone(),
+            description: latest.data.description.clone(),
+            timezone: latest.data.timezone.clone(),
+            start_unix: Some(start),
+            end_unix: duration.map(|duration| start + duration),
+            ..Default::default()
+        };
+
+        let occurrence = self
+            .copy_event(
+                &latest,
+                data,
+                Some(EventSeries {
+                    occurrences: Vec::new(),
+                    ..series
+                }),
+            )
+            .await?;
+
+        let occurrence = occurrence.tokens.public_token;
+
+        //Note: an unlinked occurrence would be orphaned, the retry creates it again
+        if let Err(e) = self
+            .add_series_occurrence(
+                id,
+                SeriesOccurrence {
+                    public_token: occurrence.clone(),
+                    start_unix: start,
+                },
+            )
+            .await
+        {
+            self.discard_event(&occurrence).await;
+            return Err(e);
+        }
+
+        self.schedule
+            .add(&series_schedule_key(id), start + interval)
+            .await;
+
+        Ok(())
+    }
+
+    /// retries on conflicts, giving up would create the occurrence again on the next run
+    async fn add_series_occurrence(&self, id: &str, occurrence: SeriesOccurrence) -> Result<()> {
+        for _ in 0..SERIES_UPDATE_RETRIES {
+            let mut entry = self.eventsdb.get(id).await?;
+
+            if let Some(series) = &mut entry.event.series {
+                series.occurrences.push(occurrence.clone());
+
+                if series.occurrences.len() > SERIES_MAX_OCCURRENCES {
+                    series.occurrences.remove(0);
+                }
+            }
+
+            entry.bump();
+
+            match self.eventsdb.put(entry).await {
+                Ok(()) => return Ok(()),
+                Err(eventsdb::Error::Concurrency) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Err(InternalError::General(format!(
+            "failed to add occurrence to series {id}"
+        )))
+    }
+
+    pub async fn request_premium_upgrade(
+        &self,
+        id: String,
+        secret: String,
+        promo: Option<String>,
+    ) -> Result<EventUpgrade> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        let promo = match promo {
+            Some(code) => Some(self.valid_promo(&code).await?.code),
+            None => None,
+        };
+
+        let mod_url = self.mod_link(&e.tokens);
+        let approve_url = self
+            .payment
+            .create_order(
+                &e.tokens.public_token,
+                &mod_url,
+                &format!("{mod_url}?payment=true&token={{CHECKOUT_SESSION_ID}}"),
+                promo.as_deref(),
+            )
+            .await?;
+
+        Ok(EventUpgrade { url: approve_url })
+    }
+
+    /// unlocks the premium features for `PREMIUM_TRIAL_SECS`, only once per event
+    #[instrument(skip(self))]
+    pub async fn mod_start_trial(&self, id: String, secret: String) -> Result<EventInfo> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if e.premium_id.is_some() {
+            bail!("event is premium already");
+        }
+
+        if e.trial_end_unix.is_some() {
+            bail!("trial used already");
+        }
+
+        e.trial_end_unix = Some(timestamp_now() + PREMIUM_TRIAL_SECS);
+
+        let result = e.clone();
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, WsMessage::Event).await;
+
+        Ok(result.into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn premium_capture(
+        &self,
+        id: String,
+        stripe_order_id: String,
+    ) -> Result<PaymentCapture> {
+        tracing::info!("premium_capture");
+
+        let (event_id, complete) = self
+            .payment
+            .retrieve_event_state(stripe_order_id.clone())
+            .await?;
+
+        if event_id != id {
+            return Err(InternalError::General("invalid parameter".into()));
+        }
+
+        let order_captured = complete;
+
+        if order_captured {
+            self.capture_payment_and_upgrade(id, stripe_order_id)
+                .await?;
+        }
+
+        Ok(PaymentCapture { order_captured })
+    }
+
+    /// starts the checkout of a subscription, its account token is part of the return url
+    #[instrument(skip(self))]
+    pub async fn request_subscription(&self) -> Result<EventUpgrade> {
+        let account = Ulid::new().to_string();
+
+        let url = self
+            .payment
+            .create_subscription(
+                &account,
+                None,
+                &format!("{}/newevent", self.base_url),
+                &format!("{}/subscription/{account}", self.base_url),
+            )
+            .await?;
+
+        Ok(EventUpgrade { url })
+    }
+
+    #[instrument(skip(self))]
+    pub async fn subscription_state(&self, account: String) -> Result<SubscriptionState> {
+        let active = match self.eventsdb.get_subscription(&account).await {
+            Ok(subscription) => subscription.active,
+            //Note: the webhook might not have arrived yet
+            Err(eventsdb::Error::ItemNotFound) => false,
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(SubscriptionState { active })
+    }
+
+    /// called by the stripe webhooks whenever a subscription starts, renews or ends
+    #[instrument(skip(self))]
+    pub async fn subscription_webhook(
+        &self,
+        account: String,
+        stripe_id: String,
+        active: bool,
+        event_unix: i64,
+    ) -> Result<()> {
+        tracing::info!("subscription update");
+
+        //Note: stripe does not guarantee the delivery order, an older event must not undo a newer one
+        if let Ok(current) = self.eventsdb.get_subscription(&account).await {
+            if current.updated_unix > event_unix {
+                tracing::info!("stale subscription event ignored");
+                return Ok(());
+            }
+        }
+
+        self.eventsdb
+            .put_subscription(
+                &account,
+                Subscription {
+                    stripe_id,
+                    active,
+                    updated_unix: event_unix,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// premium order for an event created with `account`, only while its subscription is active
+    async fn subscription_order(&self, account: &str) -> Option<PremiumOrder> {
+        match self.eventsdb.get_subscription(account).await {
+            Ok(subscription) if subscription.active => {
+                Some(PremiumOrder::StripeSubscriptionId(subscription.stripe_id))
+            }
+            Ok(_) => {
+                tracing::info!("subscription inactive");
+                None
+            }
+            Err(e) => {
+                tracing::warn!("subscription lookup failed: {e}");
+                None
+            }
+        }
+    }
+
+    /// the creator becomes the owner, the returned membership is the only way back in
+    #[instrument(skip(self))]
+    pub async fn create_org(&self, request: AddOrg) -> Result<OrgMembership> {
+        if !request.is_valid() {
+            bail!("invalid organization");
+        }
+
+        let token = Ulid::new().to_string();
+
+        let org = Organization {
+            id: Ulid::new().to_string(),
+            name: shared::strip_html(request.name.trim()),
+            members: vec![OrgMemberEntry {
+                name: shared::strip_html(request.owner.trim()),
+                role: OrgRole::Owner,
+                token: token.clone(),
+            }],
+            events: Vec::new(),
+            account: None,
+        };
+
+        let membership = OrgMembership {
+            org: org.id.clone(),
+            token,
+        };
+
+        self.eventsdb.put_org(OrgEntry { org, version: 0 }).await?;
+
+        Ok(membership)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn org_workspace(&self, id: String, token: String) -> Result<OrgWorkspace> {
+        let (entry, role) = self.org_member(&id, &token).await?;
+        let org = entry.org;
+
+        let members = org
+            .members
+            .into_iter()
+            .map(|member| OrgMember {
+                name: member.name,
+                role: member.role,
+                token: role.is_owner().then_some(member.token),
+            })
+            .collect();
+
+        let mut events = Vec::with_capacity(org.events.len());
+        for event in org.events.iter().rev() {
+            match self.eventsdb.get(event).await {
+                Ok(entry) => {
+                    let e = entry.event;
+                    events.push(OrgEvent {
+                        premium: e.is_premium(),
+                        name: e.data.name,
+                        public_token: e.tokens.public_token,
+                        moderator_token: e.tokens.moderator_token.unwrap_or_default(),
+                        create_time_unix: e.create_time_unix,
+                        deleted: e.deleted,
+                    });
+                }
+                Err(e) => tracing::warn!("org event {event} failed to load: {e}"),
+            }
+        }
+
+        let mut subscribed = false;
+        if let Some(account) = org.account {
+            subscribed = self.subscription_state(account).await?.active;
+        }
+
+        Ok(OrgWorkspace {
+            id: org.id,
+            name: org.name,
+            role,
+            members,
+            events,
+            subscribed,
+        })
+    }
+
+    /// owners only, the new member logs in with the returned membership
+    #[instrument(skip(self))]
+    pub async fn org_add_member(
+        &self,
+        id: String,
+        token: String,
+        member: AddOrgMember,
+    ) -> Result<OrgMembership> {
+        if !member.is_valid() {
+            bail!("invalid member");
+        }
+
+        let (mut entry, role) = self.org_member(&id, &token).await?;
+
+        if !role.is_owner() {
+            return Err(InternalError::OrgOwnerOnly(id));
+        }
+
+        if entry.org.members.len() >= ORG_MAX_MEMBERS {
+            bail!("org member limit reached");
+        }
+
+        let membership = OrgMembership {
+            org: id,
+            token: Ulid::new().to_string(),
+        };
+
+        entry.org.members.push(OrgMemberEntry {
+            name: shared::strip_html(member.name.trim()),
+            role: member.role,
+            token: membership.token.clone(),
+        });
+
+        entry.bump();
+        self.eventsdb.put_org(entry).await?;
+
+        Ok(membership)
+    }
+
+    /// owners only, revokes the login of `member`, the last owner cannot be removed
+    #[instrument(skip(self))]
+    pub async fn org_remove_member(&self, id: String, token: String, member: String) -> Result<()> {
+        let (mut entry, role) = self.org_member(&id, &token).await?;
+
+        if !role.is_owner() {
+            return Err(InternalError::OrgOwnerOnly(id));
+        }
+
+        let count = entry.org.members.len();
+        entry.org.members.retain(|m| m.token != member);
+
+        if entry.org.members.len() == count {
+            bail!("org member not found");
+        }
+
+        if !entry.org.members.iter().any(|m| m.role.is_owner()) {
+            bail!("org needs an owner");
+        }
+
+        entry.bump();
+        self.eventsdb.put_org(entry).await?;
+
+        Ok(())
+    }
+
+    /// creates an event listed in the workspace, premium while the team subscription is active
+    #[instrument(skip(self))]
+    pub async fn org_create_event(
+        &self,
+        id: String,
+        token: String,
+        mut request: AddEvent,
+    ) -> Result<EventInfo> {
+        let (entry, _) = self.org_member(&id, &token).await?;
+
+        if entry.org.events.len() >= ORG_MAX_EVENTS {
+            bail!("org event limit reached");
+        }
+
+        if entry.org.account.is_some() {
+            request.subscription = entry.org.account;
+        }
+
+        let event = self.create_event(request).await?;
+
+        for _ in 0..ORG_UPDATE_RETRIES {
+            let mut entry = self.eventsdb.get_org(&id).await?;
+
+            entry.org.events.push(event.tokens.public_token.clone());
+            entry.bump();
+
+            match self.eventsdb.put_org(entry).await {
+                Ok(()) => return Ok(event),
+                Err(eventsdb::Error::Concurrency) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Err(InternalError::General(format!(
+            "failed to add event to org {id}"
+        )))
+    }
+
+    /// owners only, starts the checkout of the team subscription
+    #[instrument(skip(self))]
+    pub async fn org_request_subscription(
+        &self,
+        id: String,
+        token: String,
+    ) -> Result<EventUpgrade> {
+        let (mut entry, role) = self.org_member(&id, &token).await?;
+
+        if !role.is_owner() {
+            return Err(InternalError::OrgOwnerOnly(id));
+        }
+
+        let account = entry
+            .org
+            .account
+            .clone()
+            .unwrap_or_else(|| Ulid::new().to_string());
+
+        if entry.org.account.is_none() {
+            entry.org.account = Some(account.clone());
+            entry.bump();
+            self.eventsdb.put_org(entry).await?;
+        }
+
+        //Note: the member token stays out of the urls handed to stripe
+        let url = self
+            .payment
+            .create_subscription(
+                &account,
+                Some(&id),
+                &format!("{}/orgcheckout", self.base_url),
+                &format!("{}/orgcheckout/{{CHECKOUT_SESSION_ID}}", self.base_url),
+            )
+            .await?;
+
+        Ok(EventUpgrade { url })
+    }
+
+    /// organization a subscription checkout returning to the workspace was started for
+    #[instrument(skip(self))]
+    pub async fn org_checkout(&self, session: String) -> Result<OrgCheckout> {
+        let org = self
+            .payment
+            .retrieve_org(&session)
+            .await?
+            .ok_or_else(|| InternalError::General("checkout without org".into()))?;
+
+        Ok(OrgCheckout { org })
+    }
+
+    /// org along with the role of the member holding `token`
+    async fn org_member(&self, id: &str, token: &str) -> Result<(OrgEntry, OrgRole)> {
+        let entry = self.eventsdb.get_org(id).await?;
+
+        let role = entry
+            .org
+            .member(token)
+            .map(|member| member.role)
+            .ok_or_else(|| InternalError::WrongOrgToken(id.to_string()))?;
+
+        Ok((entry, role))
+    }
+
+    /// stores the raw payload of a verified webhook delivery for reconciliation,
+    /// `false` if the delivery was seen before (providers retry until they get an answer)
+    #[instrument(skip(self, payload))]
+    pub async fn webhook_received(&self, id: &str, payload: String) -> Result<bool> {
+        match self
+            .eventsdb
+            .put_webhook(id, payload, timestamp_now())
+            .await
+        {
+            Err(eventsdb::Error::Concurrency) => return Ok(false),
+            res => res?,
+        }
+
+        Ok(true)
+    }
+
+    /// forgets a delivery whose processing failed, so the retry of the provider is not dropped as duplicate
+    #[instrument(skip(self))]
+    pub async fn webhook_failed(&self, id: &str) {
+        if let Err(e) = self.eventsdb.delete_webhook(id).await {
+            tracing::error!("failed to forget webhook delivery {id}: {e}");
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn payment_webhook(&self, stripe_session_id: String, event_id: String) -> Result<()> {
+        tracing::info!("order processing");
+
+        if !self
+            .capture_payment_and_upgrade(event_id, stripe_session_id)
+            .await?
+        {
+            tracing::warn!("webhook failed");
+        }
+
+        Ok(())
+    }
+
+    async fn capture_payment_and_upgrade(
+        &self,
+        event: String,
+        stripe_session_id: String,
+    ) -> Result<bool> {
+        let mut entry = self.eventsdb.get(&event).await?;
+
+        if entry.event.premium_id.is_some() {
+            tracing::info!("event already premium");
+            return Ok(true);
+        }
+
+        entry.event.premium_id = Some(PremiumOrder::StripeSessionId(stripe_session_id.clone()));
+
+        entry.bump();
+
+        let (name, long_url, age) = (
+            entry.event.data.name.clone(),
+            entry.event.data.long_url.clone().unwrap_or_default(),
+            entry.event.age_in_seconds(),
+        );
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&event, WsMessage::Event).await;
+
+        self.redeem_promo(&stripe_session_id).await;
+
+        self.count_stat(StatCounter::PremiumUpgrade).await;
+
+        self.send_receipt(name.clone(), stripe_session_id);
+
+        self.tracking
+            .track_event_upgrade(event.clone(), name, long_url, age)
+            .await?;
+
+        Ok(true)
+    }
+
+    /// daily counter of the admin dashboard, failures only get logged
+    async fn count_stat(&self, counter: StatCounter) {
+        if let Err(e) = self.eventsdb.count_stat(&day_string(0), counter).await {
+            tracing::warn!("stat count failed: {counter:?} ({e})");
+        }
+    }
+
+    /// counts every answered request for the error rate of the admin dashboard
+    pub fn track_response(&self, status: StatusCode) {
+        self.request_stats.total.fetch_add(1, Ordering::Relaxed);
+
+        if status.is_server_error() {
+            self.request_stats
+                .server_errors
+                .fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn admin_dashboard(&self) -> Result<AdminDashboard> {
+        let mut days = Vec::new();
+
+        for days_ago in 0..ADMIN_DASHBOARD_DAYS {
+            let day = day_string(days_ago);
+
+            days.push(match self.eventsdb.get_stats(&day).await {
+                Ok(stats) => stats,
+                Err(eventsdb::Error::ItemNotFound) => AdminDayStats {
+                    day,
+                    ..Default::default()
+                },
+                Err(e) => return Err(e.into()),
+            });
+        }
+
+        Ok(AdminDashboard {
+            days,
+            instance: self.instance_id.clone(),
+            connections: self.channels.read().await.len(),
+            requests: self.request_stats.total.load(Ordering::Relaxed),
+            server_errors: self.request_stats.server_errors.load(Ordering::Relaxed),
+        })
+    }
+
+    /// full event including its moderator token, for support requests
+    #[instrument(skip(self))]
+    pub async fn admin_event(&self, id: String) -> Result<EventInfo> {
+        Ok(self.eventsdb.get(id.trim()).await?.event.into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn admin_blocklist(&self) -> Result<Blocklist> {
+        self.load_blocklist().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn admin_block(&self, entry: BlockEntry) -> Result<Blocklist> {
+        let entry = BlockEntry {
+            value: entry.value.trim().to_string(),
+            note: entry.note.trim().to_string(),
+            create_time_unix: timestamp_now(),
+            ..entry
+        };
+
+        if !entry.is_valid() {
+            bail!("invalid block entry");
+        }
+
+        let mut blocklist = self.load_blocklist().await?;
+
+        if blocklist.entries.iter().any(|e| e.same_target(&entry)) {
+            bail!("blocked already");
+        }
+
+        if blocklist.entries.len() >= BLOCKLIST_MAX_ENTRIES {
+            bail!("blocklist full");
+        }
+
+        blocklist.entries.push(entry);
+
+        self.store_blocklist(blocklist).await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn admin_unblock(&self, entry: BlockEntry) -> Result<Blocklist> {
+        let mut blocklist = self.load_blocklist().await?;
+
+        let count = blocklist.entries.len();
+        blocklist.entries.retain(|e| !e.same_target(&entry));
+
+        if blocklist.entries.len() == count {
+            bail!("not blocked");
+        }
+
+        self.store_blocklist(blocklist).await
+    }
+
+    /// checked before an event gets created or a question gets asked
+    pub async fn is_blocked(&self, ip: Option<IpAddr>, fingerprint: Option<&str>) -> bool {
+        let now = timestamp_now();
+
+        {
+            let cached = self.blocklist.read().await;
+            if now - cached.fetched_unix < BLOCKLIST_CACHE_SECS {
+                return cached.blocklist.is_blocked(ip, fingerprint);
+            }
+        }
+
+        let loaded = self.load_blocklist().await;
+
+        let mut cached = self.blocklist.write().await;
+
+        match loaded {
+            Ok(blocklist) => cached.blocklist = blocklist,
+            //Note: the last known list is kept, an unreachable db must not stop events from being created
+            Err(e) => tracing::error!("blocklist load failed: {e}"),
+        }
+        cached.fetched_unix = now;
+
+        cached.blocklist.is_blocked(ip, fingerprint)
+    }
+
+    /// straight from the db, edits must not start from a stale copy
+    async fn load_blocklist(&self) -> Result<Blocklist> {
+        match self.eventsdb.get_blocklist().await {
+            Ok(blocklist) => Ok(blocklist),
+            Err(eventsdb::Error::ItemNotFound) => Ok(Blocklist::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn store_blocklist(&self, blocklist: Blocklist) -> Result<Blocklist> {
+        self.eventsdb.put_blocklist(blocklist.clone()).await?;
+
+        *self.blocklist.write().await = CachedBlocklist {
+            fetched_unix: timestamp_now(),
+            blocklist: blocklist.clone(),
+        };
+
+        Ok(blocklist)
+    }
+
+    /// counts the purchase against the promo code of the checkout for display, failures only get logged.
+    /// the limit itself is enforced by stripe, concurrent checkouts could exceed this counter
+    async fn redeem_promo(&self, stripe_session_id: &str) {
+        match self.payment.retrieve_promo(stripe_session_id).await {
+            Ok(Some(code)) => {
+                if let Err(e) = self.eventsdb.redeem_promo(&code).await {
+                    tracing::warn!("promo redeem failed: {code} ({e})");
+                }
+            }
+            Ok(None) => {}
+            Err(e) => tracing::error!("promo lookup failed: {e}"),
+        }
+    }
+
+    /// promo code that can still be used, `code` is matched case insensitive
+    async fn valid_promo(&self, code: &str) -> Result<PromoCode> {
+        let code = code.trim().to_uppercase();
+
+        let promo = match self.eventsdb.get_promo(&code).await {
+            Err(eventsdb::Error::ItemNotFound) => {
+                return Err(InternalError::InvalidPromoCode(code))
+            }
+            res => res?,
+        };
+
+        if promo.is_used_up() {
+            return Err(InternalError::InvalidPromoCode(code));
+        }
+
+        Ok(promo)
+    }
+
+    /// creates a promo code along with the stripe coupon granting its discount
+    #[instrument(skip(self))]
+    pub async fn admin_add_promo(&self, promo: PromoCode) -> Result<PromoCode> {
+        let promo = PromoCode {
+            code: promo.code.trim().to_uppercase(),
+            uses: 0,
+            ..promo
+        };
+
+        if !promo.is_valid() {
+            bail!("invalid promo code");
+        }
+
+        match self.eventsdb.get_promo(&promo.code).await {
+            Err(eventsdb::Error::ItemNotFound) => {}
+            Ok(_) => bail!("promo code exists already"),
+            Err(e) => return Err(e.into()),
+        }
+
+        self.payment
+            .create_coupon(&promo.code, promo.percent_off, promo.max_uses)
+            .await?;
+
+        self.eventsdb.add_promo(promo.clone()).await?;
+
+        Ok(promo)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn admin_get_promo(&self, code: String) -> Result<PromoCode> {
+        Ok(self.eventsdb.get_promo(&code.trim().to_uppercase()).await?)
+    }
+
+    /// buyer details printed on the receipt, e.g. to claim the VAT
+    #[instrument(skip(self))]
+    pub async fn mod_edit_receipt(
+        &self,
+        id: String,
+        secret: String,
+        details: ReceiptDetails,
+    ) -> Result<ReceiptDetails> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if e.premium_id.is_none() {
+            return Err(InternalError::PremiumOnlyFeature(id));
+        }
+
+        let clean = |field: Option<String>| {
+            field
+                .map(|field| shared::strip_html(&field).trim().to_string())
+                .filter(|field| !field.is_empty())
+        };
+
+        let details = ReceiptDetails {
+            name: clean(details.name),
+            address: clean(details.address),
+            vat_id: clean(details.vat_id),
+        };
+
+        if !details.is_valid() {
+            bail!("invalid receipt details");
+        }
+
+        e.receipt = Some(details.clone());
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        Ok(details)
+    }
+
+    /// html receipt of the premium purchase of an event
+    #[instrument(skip(self))]
+    pub async fn mod_receipt(&self, id: String, secret: String) -> Result<String> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        //Note: events upgraded via paypal or a subscription have no checkout of their own
+        let Some(PremiumOrder::StripeSessionId(session)) = e.premium_id else {
+            bail!("no receipt available");
+        };
+
+        let paid = self.payment.retrieve_receipt(&session).await?;
+
+        let receipt = Self::receipt(e.data.name, session, &paid, e.receipt.unwrap_or_default());
+
+        MailConfig::create_receipt(&receipt).map_err(|e| InternalError::General(e.to_string()))
+    }
+
+    /// mails the receipt to the address used at checkout
+    fn send_receipt(&self, event_name: String, session: String) {
+        let payment = Arc::clone(&self.payment);
+        let mail = self.mail_config.clone();
+
+        tokio::spawn(async move {
+            let paid = match payment.retrieve_receipt(&session).await {
+                Ok(paid) => paid,
+                Err(e) => {
+                    tracing::error!("receipt payment error: {e}");
+                    return;
+                }
+            };
+
+            let Some(receiver) = paid.email.clone() else {
+                tracing::info!("receipt not sent, no email at checkout");
+                return;
+            };
+
+            let receipt = Self::receipt(event_name, session, &paid, ReceiptDetails::default());
+
+            if let Err(e) = mail.send_receipt(receiver, receipt).await {
+                tracing::error!("receipt mail error: {e}");
+            }
+        });
+    }
+
+    fn receipt(
+        event_name: String,
+        order: String,
+        paid: &PaymentReceipt,
+        details: ReceiptDetails,
+    ) -> Receipt {
+        Receipt {
+            event_name,
+            order,
+            date: shared::EventInfo::timestamp_to_datetime(paid.created)
+                .map(|date| date.date_naive().to_string())
+                .unwrap_or_default(),
+            amount: format!(
+                "{}.{:02} {}",
+                paid.amount.div_euclid(100),
+                paid.amount.rem_euclid(100),
+                paid.currency.to_uppercase()
+            ),
+            name: details.name,
+            address: details.address,
+            vat_id: details.vat_id,
+        }
+    }
+
+    //TODO: fix clippy-allow
+    #[allow(clippy::cast_possible_wrap)]
+    pub async fn add_question(
+        &self,
+        id: String,
+        question: shared::AddQuestion,
+    ) -> Result<QuestionItem> {
+        let trimmed_question = shared::strip_html(&question.text).trim().to_string();
+
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        let mut validation = shared::AddQuestionValidation::default();
+
+        validation.check(&trimmed_question, e.question_length);
+
+        if validation.has_any() {
+            return Err(InternalError::AddQuestionValidation(validation));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if e.questions.len() >= e.question_limits.max_questions() {
+            return Err(InternalError::QuestionLimitReached(id));
+        }
+
+        if !matches!(e.state.state, States::Open) {
+            bail!("event not open");
+        }
+
+        let now = timestamp_now();
+
+        if e.data.not_started(now) {
+            bail!("event not started yet");
+        }
+
+        if e.data.ended(now) {
+            bail!("event ended");
+        }
+
+        if e.question_limits.per_minute > 0 {
+            //Note: questions are appended in order, the latest minute is at the end and
+            // asking is possible again once the oldest counted question left it
+            if let Some(oldest) = e
+                .questions
+                .iter()
+                .rev()
+                .take_while(|q| now - q.create_time_unix < 60)
+                .nth(e.question_limits.per_minute - 1)
+            {
+                return Err(InternalError::QuestionRateLimited(
+                    id,
+                    60 - (now - oldest.create_time_unix),
+                ));
+            }
+        }
+
+        if let Some(session) = question.session {
+            let Some(session) = e.sessions.iter().find(|s| s.id == session) else {
+                bail!("session not found")
+            };
+
+            if !session.state.is_open() {
+                bail!("session not open");
+            }
+        }
+
+        if e.questions
+            .iter()
+            .any(|q| q.text.trim() == trimmed_question)
+        {
+            return Err(InternalError::DuplicateQuestion);
+        }
+
+        let foreign_language = !e.question_languages.allows(&trimmed_question);
+
+        if foreign_language && e.question_languages.action == LanguageAction::Reject {
+            return Err(InternalError::QuestionLanguage(id));
+        }
+
+        if e.question_cooldown_mins > 0 {
+            let Some(participant) = question.participant.as_deref() else {
+                bail!("participant token missing")
+            };
+
+            let cooldown = i64::from(e.question_cooldown_mins) * 60;
+
+            if e.last_questions
+                .get(participant)
+                .is_some_and(|last| now - last < cooldown)
+            {
+                return Err(InternalError::QuestionCooldown(id));
+            }
+
+            //Note: pruned on every question so the map only holds participants still cooling down
+            e.last_questions.retain(|_, last| now - *last < cooldown);
+            e.last_questions.insert(participant.to_string(), now);
+        }
+
+        let name = Self::question_name(e.question_names, question.name.as_deref())?;
+
+        if let Some(attachment) = &question.attachment {
+            if !e.is_entitled(Entitlements::MODERATION) {
+                return Err(InternalError::PremiumOnlyFeature(id));
+            }
+
+            if shared::attachment_file(&id, attachment).is_none() {
+                bail!("invalid attachment key");
+            }
+        }
+
+        let question_id = e.questions.len() as i64;
+
+        let shadowed = question
+            .participant
+            .as_ref()
+            .is_some_and(|participant| e.shadow_banned.contains(participant));
+
+        if let Some(participant) = question.participant {
+            e.participant_questions
+                .entry(participant)
+                .or_default()
+                .push(question_id);
+        }
+
+        let blocked = e.blocked_terms.matches(&trimmed_question);
+
+        let question = shared::QuestionItem {
+            hidden: blocked && e.blocked_terms.action == BlockedTermsAction::Hide,
+            screening: e.do_screening
+                || foreign_language
+                || (blocked && e.blocked_terms.action == BlockedTermsAction::Review),
+            text: trimmed_question,
+            answered: false,
+            create_time_unix: now,
+            id: question_id,
+            likes: 1,
+            tag: e.tags.current_tag,
+            name,
+            attachment: question.attachment,
+            reactions: Reactions::default(),
+            downvotes: 0,
+            session: question.session,
+            pinned: false,
+            shadowed,
+            note: None,
+            merged_into: None,
+        };
+
+        e.questions.push(question.clone());
+
+        if self.sentiment {
+            e.sentiments.push(QuestionSentiment {
+                question_id,
+                sentiment: sentiment::classify(&question.text),
+            });
+        }
+
+        //Note: the question text must not reach viewers that are not supposed to see it yet
+        let msg = if question.screening || question.hidden || e.password.is_enabled() {
+            WsMessage::Question(question_id)
+        } else {
+            WsMessage::Delta(EventDelta::QuestionAdded(question.clone()))
+        };
+
+        let notification = if question.shadowed {
+            None
+        } else {
+            self.moderator_notification(e, &format!("New question: {}", question.text))
+        };
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        //Note: a shadowed one only makes moderators refetch, nobody else must notice it
+        if question.shadowed {
+            self.notify_moderators(&id, WsMessage::Event).await;
+        } else {
+            self.notify_subscribers(&id, msg).await;
+        }
+
+        if let Some(notification) = notification {
+            self.send_notification(id, notification);
+        }
+
+        //Note: the author must not notice the shadow ban
+        Ok(QuestionItem {
+            shadowed: false,
+            ..question
+        })
+    }
+
+    fn question_name(mode: QuestionNames, name: Option<&str>) -> Result<Option<String>> {
+        if !mode.allowed() {
+            return Ok(None);
+        }
+
+        let name = name.unwrap_or_default().trim();
+
+        let mut validation = NameValidation::default();
+        validation.check(name, mode.required());
+        if validation.has_any() {
+            return Err(InternalError::NameValidation(validation));
+        }
+
+        Ok((!name.is_empty()).then(|| name.to_string()))
+    }
+
+    #[instrument(skip(self))]
+    pub async fn request_attachment(
+        &self,
+        id: String,
+        request: AttachmentRequest,
+    ) -> Result<AttachmentUpload> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if !e.is_entitled(Entitlements::MODERATION) {
+            return Err(InternalError::PremiumOnlyFeature(id));
+        }
+
+        if !matches!(e.state.state, States::Open) {
+            bail!("event not open");
+        }
+
+        Ok(self.attachments.upload_url(&id, &request).await?)
+    }
+
+    /// moderators pass their `secret` to open attachments of questions that are not public yet
+    #[instrument(skip(self))]
+    pub async fn attachment_url(
+        &self,
+        id: String,
+        secret: Option<String>,
+        file: String,
+    ) -> Result<String> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if let Some(secret) = &secret {
+            if e.tokens
+                .moderator_token
+                .as_ref()
+                .is_some_and(|mod_token| mod_token != secret)
+            {
+                return Err(InternalError::WrongModeratorToken(id));
+            }
+        }
+
+        let key = shared::attachment_key(&id, &file);
+
+        if !e.questions.iter().any(|q| {
+            q.attachment.as_ref() == Some(&key) && (secret.is_some() || (!q.hidden && !q.screening))
+        }) {
+            bail!("attachment not found");
+        }
+
+        Ok(self.attachments.download_url(&id, &file).await?)
+    }
+
+    /// presigned upload of a logo, its key is then set via `EventBranding::logo`
+    #[instrument(skip(self))]
+    pub async fn mod_request_logo(
+        &self,
+        id: String,
+        secret: String,
+        request: AttachmentRequest,
+    ) -> Result<AttachmentUpload> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if !e.is_entitled(Entitlements::BRANDING) {
+            return Err(InternalError::PremiumOnlyFeature(id));
+        }
+
+        Ok(self.attachments.logo_upload_url(&id, &request).await?)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn logo_url(&self, id: String, file: String) -> Result<String> {
+        let e = self.eventsdb.get(&id).await?.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        let key = shared::logo_key(&id, &file);
+
+        if !e
+            .data
+            .branding
+            .as_ref()
+            .is_some_and(|branding| branding.logo.as_ref() == Some(&key))
+        {
+            bail!("logo not found");
+        }
+
+        Ok(self.attachments.logo_download_url(&id, &file).await?)
+    }
+
+    pub async fn edit_like(
+        &self,
+        id: String,
+        edit: shared::EditLike,
+        ip: Option<String>,
+    ) -> Result<QuestionItem> {
+        let mut entry = self.eventsdb.get(&id).await?.clone();
+
+        let e = &mut entry.event;
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if matches!(e.state.state, States::Closed | States::Archived) {
+            bail!("event closed");
+        }
+
+        if Self::question_session_closed(e, edit.question_id) {
+            bail!("session closed");
+        }
+
+        //Note: unlikes count as well, a script could otherwise take back the likes of others
+        let implausible = self
+            .like_burst(&id, edit.participant.as_deref(), ip.as_deref())
+            .await;
+
+        if let Some(f) = e.questions.iter_mut().find(|e| e.id == edit.question_id) {
+            //Note: answered like any other like, so scripts do not notice they are discounted
+            if implausible {
+                tracing::warn!(
+                    "like discounted: {}/{} ({})",
+                    edit.question_id,
+                    id,
+                    edit.like
+                );
+
+                let res = f.clone();
+
+                if !edit.like {
+                    return Ok(res);
+                }
+
+                //Note: once flagged a script must not cause a write per like
+                if e.flagged_likes
+                    .iter()
+                    .any(|flagged| flagged.question_id == edit.question_id)
+                {
+                    let mut discounted = self.discounted_likes.write().await;
+                    Self::flag_likes(discounted.entry(id).or_default(), edit.question_id, 1);
+
+                    return Ok(res);
+                }
+
+                Self::flag_likes(&mut e.flagged_likes, edit.question_id, 1);
+
+                entry.bump();
+
+                self.eventsdb.put(entry).await?;
+
+                //Note: makes moderators refetch and see the flag
+                self.notify_subscribers(&id, WsMessage::Question(res.id))
+                    .await;
+
+                return Ok(res);
+            }
+
+            f.likes = if edit.like {
+                f.likes.saturating_add(1)
+            } else {
+                f.likes.saturating_sub(1).max(0)
+            };
+
+            let res = f.clone();
+
+            let notification = if edit.like && e.notifications.is_popular(res.likes) {
+                self.moderator_notification(e, &format!("{} likes: {}", res.likes, res.text))
+            } else {
+                None
+            };
+
+            if let Some(discounted) = self.discounted_likes.write().await.remove(&id) {
+                for flagged in discounted {
+                    Self::flag_likes(
+                        &mut e.flagged_likes,
+                        flagged.question_id,
+                        flagged.discounted,
+                    );
+                }
+            }
+
+            entry.bump();
+
+            self.eventsdb.put(entry).await?;
+
+            self.like_timelines
+                .record(
+                    &id,
+                    edit.question_id,
+                    timestamp_now(),
+                    if edit.like { 1 } else { -1 },
+                )
+                .await;
+
+            self.notify_subscribers(
+                &id,
+                WsMessage::Delta(EventDelta::QuestionLiked {
+                    id: res.id,
+                    likes: res.likes,
+                }),
+            )
+            .await;
+
+            if let Some(notification) = notification {
+                self.send_notification(id, notification);
+            }
+
+            Ok(res)
+        } else {
+            bail!("question not found")
+        }
+    }
+
+    /// counts a like of the browser and the address,
+    /// returns `true` if either of them likes implausibly fast
+    async fn like_burst(&self, event: &str, participant: Option<&str>, ip: Option<&str>) -> bool {
+        let sources = [
+            participant.map(|participant| {
+                (
+                    format!("likes/{event}/p/{participant}"),
+                    LIKE_BURST_MAX_PARTICIPANT,
+                )
+            }),
+            ip.map(|ip| (format!("likes/{event}/ip/{ip}"), LIKE_BURST_MAX_IP)),
+        ];
+
+        let mut implausible = false;
+
+        for (source, max) in sources.into_iter().flatten() {
+            implausible |= self
+                .counters
+                .increment(&source, LIKE_BURST_WINDOW_SECS)
+                .await
+                > max;
+        }
+
+        implausible
+    }
+
+    fn flag_likes(flagged: &mut Vec<FlaggedLikes>, question_id: i64, count: u32) {
+        if let Some(f) = flagged.iter_mut().find(|f| f.question_id == question_id) {
+            f.discounted = f.discounted.saturating_add(count);
+            return;
+        }
+
+        flagged.push(FlaggedLikes {
+            question_id,
+            discounted: count,
+        });
+    }
+
+    pub async fn edit_downvote(
+        &self,
+        id: String,
+        edit: shared::EditDownvote,
+    ) -> Result<QuestionItem> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if matches!(e.state.state, States::Closed | States::Archived) {
+            bail!("event closed");
+        }
+
+        if !e.downvotes {
+            bail!("downvotes disabled");
+        }
+
+        if Self::question_session_closed(e, edit.question_id) {
+            bail!("session closed");
+        }
+
+        let Some(q) = e.questions.iter_mut().find(|q| q.id == edit.question_id) else {
+            bail!("question not found")
+        };
+
+        q.downvotes = if edit.downvote {
+            q.downvotes.saturating_add(1)
+        } else {
+            q.downvotes.saturating_sub(1).max(0)
+        };
+
+        let res = q.clone();
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, WsMessage::Question(edit.question_id))
+            .await;
+
+        Ok(res)
+    }
+
+    pub async fn edit_reaction(
+        &self,
+        id: String,
+        edit: shared::EditReaction,
+    ) -> Result<QuestionItem> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if matches!(e.state.state, States::Closed | States::Archived) {
+            bail!("event closed");
+        }
+
+        if Self::question_session_closed(e, edit.question_id) {
+            bail!("session closed");
+        }
+
+        let Some(q) = e.questions.iter_mut().find(|q| q.id == edit.question_id) else {
+            bail!("question not found")
+        };
+
+        q.reactions.edit(edit.reaction, edit.add);
+
+        let res = q.clone();
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, WsMessage::Question(edit.question_id))
+            .await;
+
+        Ok(res)
+    }
+
+    /// questions of a closed session cannot be voted on anymore
+    fn question_session_closed(e: &ApiEventInfo, question_id: i64) -> bool {
+        e.questions
+            .iter()
+            .find(|q| q.id == question_id)
+            .and_then(|q| q.session)
+            .and_then(|session| e.sessions.iter().find(|s| s.id == session))
+            .is_some_and(|s| s.state.is_closed())
+    }
+
+    #[instrument(skip(self, secret))]
+    #[allow(clippy::cast_possible_wrap)]
+    pub async fn mod_add_session(
+        &self,
+        id: String,
+        secret: String,
+        session: AddSession,
+    ) -> Result<Session> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if !session.is_valid() {
+            bail!("invalid session");
+        }
+
+        if e.sessions.len() >= SESSION_MAX_PER_EVENT {
+            bail!("max number of sessions reached");
+        }
+
+        let session = Session {
+            id: e.sessions.len() as i64,
+            name: shared::strip_html(session.name.trim()),
+            state: EventState::default(),
+        };
+
+        e.sessions.push(session.clone());
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, WsMessage::Event).await;
+
+        Ok(session)
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_edit_session(
+        &self,
+        id: String,
+        secret: String,
+        session_id: i64,
+        edit: ModSession,
+    ) -> Result<Session> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        let Some(session) = e.sessions.iter_mut().find(|s| s.id == session_id) else {
+            bail!("session not found")
+        };
+
+        session.state = edit.state;
+
+        let session = session.clone();
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, WsMessage::Event).await;
+
+        Ok(session)
+    }
+
+    #[instrument(skip(self, secret))]
+    #[allow(clippy::cast_possible_wrap)]
+    pub async fn mod_add_poll(&self, id: String, secret: String, poll: AddPoll) -> Result<Poll> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if !poll.is_valid() {
+            bail!("invalid poll");
+        }
+
+        if e.polls.len() >= POLL_MAX_PER_EVENT {
+            bail!("max number of polls reached");
+        }
+
+        let poll = Poll {
+            id: e.polls.len() as i64,
+            question: shared::strip_html(poll.question.trim()),
+            options: poll
+                .options
+                .iter()
+                .map(|text| PollOption {
+                    text: shared::strip_html(text.trim()),
+                    votes: 0,
+                })
+                .collect(),
+            closed: false,
+            create_time_unix: timestamp_now(),
+        };
+
+        e.polls.push(poll.clone());
+
+        let msg = Self::poll_changed_msg(e, &poll);
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, msg).await;
+
+        Ok(poll)
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_edit_poll(
+        &self,
+        id: String,
+        secret: String,
+        poll_id: i64,
+        edit: ModPoll,
+    ) -> Result<Poll> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        let Some(poll) = e.polls.iter_mut().find(|p| p.id == poll_id) else {
+            bail!("poll not found")
+        };
+
+        poll.closed = edit.closed;
+
+        let poll = poll.clone();
+
+        let msg = Self::poll_changed_msg(e, &poll);
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, msg).await;
+
+        Ok(poll)
+    }
+
+    pub async fn vote_poll(&self, id: String, poll_id: i64, vote: PollVote) -> Result<Poll> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if e.state.is_archived() {
+            bail!("event archived");
+        }
+
+        if vote.participant.trim().is_empty() {
+            bail!("participant missing");
+        }
+
+        let Some(poll) = e.polls.iter_mut().find(|p| p.id == poll_id) else {
+            bail!("poll not found")
+        };
+
+        if poll.closed {
+            bail!("poll closed");
+        }
+
+        let Some(option) = poll.options.get_mut(vote.option) else {
+            bail!("poll option not found")
+        };
+
+        let participant = vote.participant.trim();
+
+        if !self.poll_voters.add(&id, poll_id, participant).await {
+            bail!("voted already");
+        }
+
+        option.votes = option.votes.saturating_add(1);
+
+        let res = poll.clone();
+
+        entry.bump();
+
+        if let Err(e) = self.eventsdb.put(entry).await {
+            self.poll_voters.remove(&id, poll_id, participant).await;
+            return Err(e.into());
+        }
+
+        self.notify_subscribers(
+            &id,
+            WsMessage::Delta(EventDelta::PollVoted {
+                id: res.id,
+                votes: res.votes(),
+            }),
+        )
+        .await;
+
+        Ok(res)
+    }
+
+    //Note: poll texts must not reach viewers of password protected events
+    fn poll_changed_msg(e: &ApiEventInfo, poll: &Poll) -> WsMessage {
+        if e.password.is_enabled() {
+            WsMessage::Event
+        } else {
+            WsMessage::Delta(EventDelta::PollChanged(poll.clone()))
+        }
+    }
+
+    #[instrument(skip(self, secret))]
+    #[allow(clippy::cast_possible_wrap)]
+    pub async fn mod_add_word_cloud(
+        &self,
+        id: String,
+        secret: String,
+        cloud: AddWordCloud,
+    ) -> Result<WordCloud> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if !cloud.is_valid() {
+            bail!("invalid word cloud");
+        }
+
+        if e.word_clouds.len() >= WORD_CLOUD_MAX_PER_EVENT {
+            bail!("max number of word clouds reached");
+        }
+
+        let cloud = WordCloud {
+            id: e.word_clouds.len() as i64,
+            prompt: shared::strip_html(cloud.prompt.trim()),
+            words: Vec::new(),
+            closed: false,
+            create_time_unix: timestamp_now(),
+        };
+
+        e.word_clouds.push(cloud.clone());
+
+        let msg = Self::word_cloud_changed_msg(e, &cloud);
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, msg).await;
+
+        Ok(cloud)
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_edit_word_cloud(
+        &self,
+        id: String,
+        secret: String,
+        cloud_id: i64,
+        edit: ModWordCloud,
+    ) -> Result<WordCloud> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        let Some(cloud) = e.word_clouds.iter_mut().find(|c| c.id == cloud_id) else {
+            bail!("word cloud not found")
+        };
+
+        cloud.closed = edit.closed;
+
+        let cloud = cloud.clone();
+
+        let msg = Self::word_cloud_changed_msg(e, &cloud);
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, msg).await;
+
+        Ok(cloud)
+    }
+
+    pub async fn add_word(&self, id: String, cloud_id: i64, word: AddWord) -> Result<WordCount> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if e.state.is_archived() {
+            bail!("event archived");
+        }
+
+        let password = e.password.is_enabled();
+
+        let Some(cloud) = e.word_clouds.iter_mut().find(|c| c.id == cloud_id) else {
+            bail!("word cloud not found")
+        };
+
+        if cloud.closed {
+            bail!("word cloud closed");
+        }
+
+        let Some(text) = shared::normalize_word(&word.text) else {
+            bail!("invalid word")
+        };
+
+        if word.participant.trim().is_empty() {
+            bail!("participant missing");
+        }
+
+        let answers = format!("wordcloud/{id}/{cloud_id}/{}", word.participant.trim());
+
+        if self
+            .counters
+            .increment(&answers, WORD_CLOUD_ANSWERS_TTL_SECS)
+            .await
+            > WORD_CLOUD_MAX_ANSWERS
+        {
+            bail!("answered already");
+        }
+
+        let res = if let Some(existing) = cloud.words.iter_mut().find(|w| w.text == text) {
+            existing.count = existing.count.saturating_add(1);
+            existing.clone()
+        } else {
+            if cloud.words.len() >= WORD_CLOUD_MAX_ENTRIES {
+                bail!("max number of words reached");
+            }
+
+            let new = WordCount { text, count: 1 };
+            cloud.words.push(new.clone());
+            new
+        };
+
+        entry.bump();
+
+        if let Err(e) = self.eventsdb.put(entry).await {
+            self.counters.decrement(&answers).await;
+            return Err(e.into());
+        }
+
+        let msg = if password {
+            WsMessage::Event
+        } else {
+            WsMessage::Delta(EventDelta::WordCloudWord {
+                id: cloud_id,
+                text: res.text.clone(),
+                count: res.count,
+            })
+        };
+
+        self.notify_subscribers(&id, msg).await;
+
+        Ok(res)
+    }
+
+    //Note: word cloud texts must not reach viewers of password protected events
+    fn word_cloud_changed_msg(e: &ApiEventInfo, cloud: &WordCloud) -> WsMessage {
+        if e.password.is_enabled() {
+            WsMessage::Event
+        } else {
+            WsMessage::Delta(EventDelta::WordCloudChanged(cloud.clone()))
+        }
+    }
+
+    pub async fn raise_hand(&self, id: String, hand: RaiseHand) -> Result<RaisedHand> {
+        let name = shared::strip_html(&hand.name).trim().to_string();
+
+        let mut validation = NameValidation::default();
+        validation.check(&name, true);
+        if validation.has_any() {
+            return Err(InternalError::NameValidation(validation));
+        }
+
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if !matches!(e.state.state, States::Open) {
+            bail!("event not open");
+        }
+
+        let queue = &mut e.speaker_queue;
+
+        if queue.speakers.len() >= SPEAKER_QUEUE_MAX {
+            bail!("speaker queue full");
+        }
+
+        let raised = RaisedHand {
+            id: queue.next_id,
+            token: Ulid::new().to_string(),
+        };
+
+        queue.next_id += 1;
+        queue.speakers.push(QueuedSpeaker {
+            speaker: Speaker {
+                id: raised.id,
+                name,
+                create_time_unix: timestamp_now(),
+            },
+            token: raised.token.clone(),
+        });
+
+        let msg = Self::speakers_changed_msg(e);
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, msg).await;
+
+        Ok(raised)
+    }
+
+    pub async fn lower_hand(&self, id: String, hand: RaisedHand) -> Result<()> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if !e
+            .speaker_queue
+            .speakers
+            .iter()
+            .any(|s| s.speaker.id == hand.id && s.token == hand.token)
+        {
+            bail!("speaker not found");
+        }
+
+        e.speaker_queue.remove(hand.id);
+
+        let msg = Self::speakers_changed_msg(e);
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, msg).await;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_edit_speakers(
+        &self,
+        id: String,
+        secret: String,
+        edit: ModSpeaker,
+    ) -> Result<SpeakerQueue> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        let queue = &mut e.speaker_queue;
+
+        match edit {
+            ModSpeaker::Call(speaker) => {
+                if !queue.speakers.iter().any(|s| s.speaker.id == speaker) {
+                    bail!("speaker not found");
+                }
+
+                queue.current = Some(speaker);
+            }
+            ModSpeaker::Move { id: speaker, index } => {
+                let Some(from) = queue.speakers.iter().position(|s| s.speaker.id == speaker) else {
+                    bail!("speaker not found")
+                };
+
+                let moved = queue.speakers.remove(from);
+                let index = index.min(queue.speakers.len());
+                queue.speakers.insert(index, moved);
+            }
+            ModSpeaker::Remove(speaker) => {
+                if !queue.remove(speaker) {
+                    bail!("speaker not found");
+                }
+            }
+        }
+
+        let res = queue.queue();
+
+        let msg = Self::speakers_changed_msg(e);
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, msg).await;
+
+        Ok(res)
+    }
+
+    //Note: names in the queue must not reach viewers of password protected events
+    fn speakers_changed_msg(e: &ApiEventInfo) -> WsMessage {
+        if e.password.is_enabled() {
+            WsMessage::Event
+        } else {
+            WsMessage::Delta(EventDelta::SpeakersChanged(e.speaker_queue.queue()))
+        }
+    }
+
+    #[instrument(skip(self, secret))]
+    pub async fn mod_edit_timer(
+        &self,
+        id: String,
+        secret: String,
+        edit: ModTimer,
+    ) -> Result<Option<EventTimer>> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if e.tokens
+            .moderator_token
+            .as_ref()
+            .is_some_and(|mod_token| mod_token != &secret)
+        {
+            return Err(InternalError::WrongModeratorToken(id));
+        }
+
+        if !edit.is_valid() {
+            bail!("invalid timer");
+        }
+
+        e.timer = edit.seconds.map(|seconds| EventTimer {
+            end_unix: timestamp_now().saturating_add(seconds),
+            label: shared::strip_html(edit.label.trim()),
+        });
+
+        let res = e.timer.clone();
+
+        //Note: the label must not reach viewers of password protected events
+        let msg = if e.password.is_enabled() {
+            WsMessage::Event
+        } else {
+            WsMessage::Delta(EventDelta::TimerChanged(res.clone()))
+        };
+
+        entry.bump();
+
+        self.eventsdb.put(entry).await?;
+
+        self.notify_subscribers(&id, msg).await;
+
+        Ok(res)
+    }
+
+    //Note: responses are only visible to the moderator, so nobody needs to be notified
+    pub async fn add_survey_response(&self, id: String, response: AddSurveyResponse) -> Result<()> {
+        let mut entry = self.eventsdb.get(&id).await?;
+
+        let e = &mut entry.event;
+
+        if e.deleted {
+            return Err(InternalError::AccessingDeletedEvent(id));
+        }
+
+        if e.is_timed_out_and_free() {
+            return Err(InternalError::TimedOutFreeEvent(id));
+        }
+
+        if !e.survey {
+            bail!("survey disabled");
+        }
+
+        if !e.state.is_closed() {
+            bail!("event not closed");
+        }
+
+        if !response.is_valid() {
+            bail!("invalid survey response");
+        }
+
+        if e.survey_responses.len() >= SURVEY_MAX_RESPONSES {
+            bail!("max number of survey responses reached");
+        }
+
+        if response.participant.trim().is_empty() {
+            bail!("participant missing");
+        }
+
+        let participant = response.participant.trim();
+
+        if !self.survey_respondents.add(&id, participant).await {
+            bail!("responded already");
+        }
+
+        let comment = response
+            .comment
+            .map(|comment| shared::strip_html(&comment).trim().to_string())
+            .filter(|comment| !comment.is_empty());
+
+        e.survey_responses.push(SurveyResponse {
+            rating: response.rating,
+            comment,
+            create_time_unix: timestamp_now(),
+        });
+
+        entry.bump();
+
+        if let Err(e) = self.eventsdb.put(entry).await {
+            self.survey_respondents.remove(&id, participant).await;
+            return Err(e.into());
+        }
+
+        Ok(())
+    }
+
+    pub async fn push_subscriber(&self, ws: WebSocket, id: String, params: PushParams) {
+        use futures_util::StreamExt;
+
+        let (ws_sender, mut ws_receiver) = ws.split();
+
+        let user_id = NEXT_USER_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+        let send_channel = Self::create_send_channel(ws_sender);
+
+        let moderator = match &params.secret {
+            Some(secret) => self.is_moderator(&id, secret).await,
+            None => false,
+        };
+
+        //Note: moderators always get in and are only counted as moderators, so they take no slot
+        let mut waiting = if moderator {
+            false
+        } else {
+            !self.try_add_viewer(&id).await
+        };
+
+        if waiting {
+            send_channel
+                .send(Ok(Message::Text(WsMessage::WaitingRoom.encode())))
+                .ok();
+        } else {
+            self.admit_subscriber(user_id, &id, params.encoding, &send_channel, moderator)
+                .await;
+        }
+
+        tracing::info!(
+            "user connected: {} ({} total, waiting: {})",
+            user_id,
+            self.channels.read().await.len(),
+            waiting
+        );
+
+        let mut last_typing: Option<Instant> = None;
+
+        let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+        let mut last_seen = Instant::now();
+
+        loop {
+            let msg = tokio::select! {
+                result = ws_receiver.next() => match result {
+                    Some(Ok(msg)) => msg,
+                    Some(Err(e)) => {
+                        tracing::info!("websocket receive err (id={}): '{}'", user_id, e);
+                        break;
+                    }
+                    None => break,
+                },
+                _ = heartbeat.tick() => {
+                    if last_seen.elapsed() > HEARTBEAT_INTERVAL * HEARTBEAT_MAX_MISSED {
+                        tracing::info!("user:{} missed heartbeats, disconnecting", user_id);
+                        break;
+                    }
+
+                    if send_channel.send(Ok(Message::Ping(Vec::new()))).is_err() {
+                        break;
+                    }
+
+                    if waiting && self.try_add_viewer(&id).await {
+                        waiting = false;
+
+                        send_channel
+                            .send(Ok(Message::Text(WsMessage::Admitted.encode())))
+                            .ok();
+
+                        self.admit_subscriber(user_id, &id, params.encoding, &send_channel, false)
+                            .await;
+                    }
+
+                    continue;
+                }
+            };
+
+            last_seen = Instant::now();
+
+            let client_msg = match &msg {
+                Message::Text(text) => WsClientMessage::decode(text),
+                _ => None,
+            };
+
+            match client_msg {
+                //answer app based pings so the client can detect dead sockets
+                Some(WsClientMessage::Ping) => {
+                    if send_channel
+                        .send(Ok(Message::Text(WsMessage::Pong.encode())))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+                //Note: sockets in the waiting room do not see the event yet
+                Some(WsClientMessage::Typing) => {
+                    let throttled =
+                        last_typing.is_some_and(|last| last.elapsed() < TYPING_SIGNAL_MIN_INTERVAL);
+
+                    if !waiting && !throttled {
+                        last_typing = Some(Instant::now());
+                        self.typing_signal(&id, user_id).await;
+                    }
+                    continue;
+                }
+                None => {
+                    match &msg {
+                        //TODO: do we need to respond manually?
+                        Message::Ping(_) => tracing::info!("received msg:ping"),
+                        Message::Pong(_) => tracing::trace!("received msg:pong"),
+                        Message::Text(txt) => tracing::warn!("received msg:text: '{txt}'"),
+                        Message::Binary(bin) => {
+                            tracing::warn!("received msg:binary: {}b", bin.len());
+                        }
+                        Message::Close(frame) => tracing::info!("received msg:close: {frame:?}"),
+                    }
+
+                    let (disconnect, sent_data) = match msg {
+                        Message::Ping(_) | Message::Pong(_) => (false, false),
+                        Message::Close(_) => (true, false),
+                        _ => (true, true),
+                    };
+
+                    if disconnect {
+                        if sent_data {
+                            tracing::warn!("user:{} sent data, disconnecting", user_id);
+                        }
+                        break;
+                    }
+                }
+            }
+
+            if self.is_shutting_down() {
+                tracing::info!("shutdown: close client connection [{user_id}]");
+
+                if let Err(e) = send_channel.send(Ok(Message::Close(Some(CloseFrame {
+                    code: RESTART,
+                    reason: "server shutdown".into(),
+                })))) {
+                    tracing::error!("shutdown error: {e}");
+                }
+
+                break;
+            }
+        }
+
+        tracing::info!(
+            "user disconnected: {} ({} remain)",
+            user_id,
+            self.channels.read().await.len().saturating_sub(1)
+        );
+
+        //Note: a socket still in the waiting room was neither counted nor subscribed
+        if waiting {
+            return;
+        }
+
+        if moderator {
+            self.viewers.remove(&moderators_key(&id)).await;
+        } else {
+            self.viewers.remove(&id).await;
+        }
+
+        let was_typing = self.typing_remove(&id, user_id).await;
+
+        //Note: lets not spam everyone if its a shutdown
+        if !self.is_shutting_down() {
+            self.notify_viewer_count_change(&id, false);
+
+            if moderator {
+                self.notify_moderator_presence(&id).await;
+            }
+
+            if let Some(count) = was_typing {
+                self.notify_subscribers(&id, WsMessage::Typing(count)).await;
+            }
+        }
+
+        self.channels.write().await.remove(&user_id);
+    }
+
+    /// subscribes an admitted socket to the event updates
+    async fn admit_subscriber(
+        &self,
+        user_id: usize,
+        id: &str,
+        encoding: SocketEncoding,
+        send_channel: &OutBoundChannel,
+        moderator: bool,
+    ) {
+        self.channels.write().await.insert(
+            user_id,
+            Subscriber {
+                event: id.to_string(),
+                encoding,
+                channel: send_channel.clone(),
+                moderator,
+            },
+        );
+
+        self.notify_viewer_count_change(id, true);
+
+        if moderator {
+            self.viewers.add(&moderators_key(id)).await;
+            self.notify_moderator_presence(id).await;
+        } else if self.moderator_online(id).await {
+            //Note: offline is the default on the client, only tell newcomers if someone is there
+            send_channel
+                .send(Ok(Message::Text(WsMessage::ModeratorOnline(true).encode())))
+                .ok();
+        }
+    }
+
+    /// counts a viewer unless a premium event is at its capacity
+    ///
+    /// Note: this is a soft limit on the websocket only, `GET /api/event/:id`, the paged questions
+    /// and the long poll still answer everyone that knows the link, they just miss the live updates
+    async fn try_add_viewer(&self, event: &str) -> bool {
+        let capacity = self.viewer_capacity(event).await;
+
+        if capacity > 0 {
+            self.viewers.try_add(event, i64::from(capacity)).await
+        } else {
+            self.viewers.add(event).await;
+            true
+        }
+    }
+
+    async fn viewer_capacity(&self, event: &str) -> u32 {
+        let now = timestamp_now();
+
+        if let Some(cached) = self.viewer_capacities.read().await.get(event) {
+            if now - cached.fetched_unix < VIEWER_CAPACITY_CACHE_SECS {
+                return cached.capacity;
+            }
+        }
+
+        let capacity = self
+            .eventsdb
+            .get(event)
+            .await
+            .ok()
+            .filter(|entry| entry.event.is_entitled(Entitlements::MODERATION))
+            .map_or(0, |entry| entry.event.viewer_capacity);
+
+        let mut capacities = self.viewer_capacities.write().await;
+
+        capacities.retain(|_, cached| now - cached.fetched_unix < VIEWER_CAPACITY_CACHE_SECS);
+        capacities.insert(
+            event.to_string(),
+            CachedCapacity {
+                fetched_unix: now,
+                capacity,
+            },
+        );
+
+        capacity
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.shutdown.load(Ordering::Relaxed)
+    }
+
+    async fn typing_signal(&self, event: &str, user_id: usize) {
+        let count = self.typing.signal(event, &self.typing_user(user_id)).await;
+
+        self.notify_subscribers(event, WsMessage::Typing(count))
+            .await;
+    }
+
+    /// removes the user from the typing list, returns the remaining count if it was typing
+    async fn typing_remove(&self, event: &str, user_id: usize) -> Option<usize> {
+        self.typing.remove(event, &self.typing_user(user_id)).await
+    }
+
+    fn typing_user(&self, user_id: usize) -> String {
+        format!("{}/{user_id}", self.instance_id)
+    }
+
+    /// only sockets presenting the right moderator token count as moderator presence
+    async fn is_moderator(&self, event: &str, secret: &str) -> bool {
+        self.eventsdb
+            .get(event)
+            .await
+            .is_ok_and(|entry| entry.event.tokens.moderator_token.as_deref() == Some(secret))
+    }
+
+    async fn moderator_online(&self, event: &str) -> bool {
+        self.viewers.count(&moderators_key(event)).await > 0
+    }
+
+    async fn notify_moderator_presence(&self, event: &str) {
+        let online = self.moderator_online(event).await;
+
+        tracing::info!("notify moderator presence: {online}");
+
+        self.notify_subscribers(event, WsMessage::ModeratorOnline(online))
+            .await;
+    }
+
+    fn notify_viewer_count_change(&self, event: &str, joined: bool) {
+        let event = event.to_string();
+        let app = self.clone();
+
+        tokio::spawn(async move {
+            let count = app.viewers.count(&event).await;
+
+            tracing::info!("notify viewer count: {count}");
+
+            app.notify_subscribers(&event, WsMessage::Viewers(count))
+                .await;
+
+            //Note: only a joining viewer can raise the peak
+            if joined {
+                app.record_peak_viewers(&event, count).await;
+            }
+        });
+    }
+
+    async fn record_peak_viewers(&self, id: &str, count: i64) {
+        let Ok(mut entry) = self.eventsdb.get(id).await else {
+            return;
+        };
+
+        if entry.event.deleted || entry.event.peak_viewers >= count {
+            return;
+        }
+
+        entry.event.peak_viewers = count;
+        entry.bump();
+
+        //Note: a concurrent edit wins, the next joining viewer records the peak again
+        if let Err(e) = self.eventsdb.put(entry).await {
+            tracing::warn!("peak viewers not recorded: {e}");
+        }
+    }
+
+    fn create_send_channel(
+        ws_sender: futures_util::stream::SplitSink<WebSocket, axum::extract::ws::Message>,
+    ) -> OutBoundChannel {
+        use futures_util::FutureExt;
+        use futures_util::StreamExt;
+        use tokio_stream::wrappers::UnboundedReceiverStream;
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let rx = UnboundedReceiverStream::new(receiver);
+
+        tokio::task::spawn(rx.forward(ws_sender).map(|result| {
+            if let Err(e) = result {
+                let error_string_lowcase = e.to_string().to_lowercase();
+                let well_known = error_string_lowcase.starts_with("connection closed normally")
+                    || error_string_lowcase.starts_with("trying to work with closed connection");
+                if !well_known {
+                    tracing::warn!("websocket send error: {}", e);
+                }
+            }
+        }));
+
+        sender
+    }
+
+    async fn notify_subscribers(&self, event_id: &str, msg: WsMessage) {
+        let msg = msg.encode();
+
+        self.pubsub_publish.publish(event_id, &msg).await;
+    }
+
+    /// only reaches the sockets of moderators of the event
+    async fn notify_moderators(&self, event_id: &str, msg: WsMessage) {
+        self.notify_subscribers(&moderators_key(event_id), msg)
+            .await;
+    }
+
+    fn send_mail(
+        &self,
+        event_id: String,
+        receiver: String,
+        event_name: String,
+        public_link: String,
+        mod_link: String,
+    ) {
+        if receiver.trim().is_empty() {
+            tracing::debug!("mail not sent, no receiver specified");
+            return;
+        }
+
+        let mail = self.mail_config.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = mail
+                .send_mail(
+                    event_id,
+                    receiver.clone(),
+                    event_name,
+                    public_link,
+                    mod_link,
+                )
+                .await
+            {
+                tracing::error!("mail send error: {e}");
+            }
+        });
+    }
+
+    async fn mod_edit_password(
+        &self,
+        e: &mut ApiEventInfo,
+        password: shared::EventPassword,
+    ) -> Result<()> {
+        let edit_type = match (e.password.is_enabled(), password.is_enabled()) {
+            (false, true) => Some(EditEvent::Enabled),
+            (true, false) => Some(EditEvent::Disabled),
+            (true, true) => Some(EditEvent::Changed),
+            _ => None,
+        };
+
+        if let Some(edit_type) = edit_type {
+            self.tracking
+                .track_event_password_set(e.tokens.public_token.clone(), edit_type)
+                .await?;
+        }
+
+        e.password = password;
+
+        Ok(())
+    }
+
+    async fn mod_edit_tag(
+        &self,
+        e: &mut ApiEventInfo,
+        current_tag: &shared::CurrentTag,
+    ) -> Result<()> {
+        if !e.is_entitled(Entitlements::MODERATION) {
+            return Err(InternalError::PremiumOnlyFeature(
+                e.tokens.public_token.clone(),
+            ));
+        }
+
+        let edit_type = match (e.tags.current_tag.is_some(), current_tag.is_enabled()) {
+            (false, true) => Some(EditEvent::Enabled),
+            (true, false) => Some(EditEvent::Disabled),
+            (true, true) => Some(EditEvent::Changed),
+            _ => None,
+        };
+
+        if let Some(edit_type) = edit_type {
+            self.tracking
+                .track_event_tag_set(e.tokens.public_token.clone(), edit_type, e.age_in_seconds())
+                .await?;
+        }
+
+        if let shared::CurrentTag::Enabled(tag) = &current_tag {
+            let mut validation = TagValidation::default();
+            validation.check(tag);
+
+            if validation.has_any() {
+                return Err(InternalError::TagValidation(validation));
+            }
+
+            if !e.tags.set_or_add_tag(tag) {
+                bail!("max tags reached");
+            }
+        } else {
+            e.tags.current_tag = None;
+        }
+
+        Ok(())
+    }
+
+    async fn mod_context(
+        &self,
+        e: &mut ApiEventInfo,
+        context_link: &shared::EditContextLink,
+    ) -> Result<()> {
+        if !e.is_entitled(Entitlements::MODERATION) {
+            return Err(InternalError::PremiumOnlyFeature(
+                e.tokens.public_token.clone(),
+            ));
+        }
+
+        match context_link {
+            shared::EditContextLink::Disabled => e.context = vec![],
+            shared::EditContextLink::Enabled(item) => {
+                let mut validation = ContextValidation::default();
+
+                validation.check(&item.label, &item.url);
+                if validation.has_any() {
+                    return Err(InternalError::ContextValidation(validation));
+                }
+
+                e.context = vec![item.clone()];
+
+                self.tracking
+                    .track_event_context_set(e.tokens.public_token.clone(), &item.label, &item.url)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn mod_notifications(e: &mut ApiEventInfo, edit: NotificationSettings) -> Result<()> {
+        let email = edit
+            .email
+            .map(|email| email.trim().to_string())
+            .filter(|email| !email.is_empty());
+
+        let settings = NotificationSettings { email, ..edit };
+
+        if !settings.is_valid() {
+            bail!("invalid notification settings");
+        }
+
+        e.notifications = settings;
+
+        Ok(())
+    }
+
+    fn mod_branding(e: &mut ApiEventInfo, edit: &EventBranding) -> Result<()> {
+        if !e.is_entitled(Entitlements::BRANDING) {
+            return Err(InternalError::PremiumOnlyFeature(
+                e.tokens.public_token.clone(),
+            ));
+        }
+
+        let branding = EventBranding {
+            banner: edit
+                .banner
+                .as_deref()
+                .map(shared::strip_html)
+                .filter(|banner| !banner.trim().is_empty()),
+            ..edit.clone()
+        };
+
+        if !branding.is_valid() {
+            bail!("invalid branding");
+        }
+
+        //Note: occurrences of a series keep the logo uploaded for the first one
+        let current_logo = e.data.branding.as_ref().and_then(|b| b.logo.as_deref());
+        if branding.logo.as_deref().is_some_and(|key| {
+            shared::logo_file(&e.tokens.public_token, key).is_none() && Some(key) != current_logo
+        }) {
+            bail!("invalid logo key");
+        }
+
+        e.data.branding = (!branding.is_empty()).then_some(branding);
+
+        Ok(())
+    }
+
+    async fn mod_meta(&self, e: &mut ApiEventInfo, edit: &shared::EditMetaData) -> Result<()> {
+        if !shared::EventInfo::during_first_day(e.create_time_unix) {
+            bail!("event meta can only be changed during first 24h")
+        }
+
+        let edit = shared::EditMetaData {
+            title: shared::strip_html(&edit.title),
+            description: shared::strip_html(&edit.description),
+        };
+
+        let validation =
+            shared::CreateEventValidation::default().check(&edit.title, &edit.description, "");
+        if validation.has_any() {
+            return Err(InternalError::MetaValidation(edit));
+        }
+
+        e.data.name = edit.title.clone();
+        e.data.description = edit.description.clone();
+
+        self.tracking
+            .track_event_meta_change(e.tokens.public_token.clone(), &edit)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// sums the counts of `points` into `EVENT_STATS_BUCKET_SECS` windows,
+/// windows without any activity in between are filled with zero
+fn stats_buckets(points: impl Iterator<Item = (i64, i64)>) -> Vec<StatsBucket> {
+    let mut sums: BTreeMap<i64, i64> = BTreeMap::new();
+    for (timestamp, count) in points {
+        let start = timestamp - timestamp.rem_euclid(EVENT_STATS_BUCKET_SECS);
+        let sum = sums.entry(start).or_default();
+        *sum = sum.saturating_add(count);
+    }
+
+    let (Some(&first), Some(&last)) = (sums.keys().next(), sums.keys().next_back()) else {
+        return Vec::new();
+    };
+
+    let windows = (last - first).div_euclid(EVENT_STATS_BUCKET_SECS);
+    let skipped = windows.saturating_sub(EVENT_STATS_MAX_BUCKETS - 1).max(0);
+
+    (skipped..=windows)
+        .map(|window| {
+            let start = first + window * EVENT_STATS_BUCKET_SECS;
+            StatsBucket {
+                start,
+                count: sums.get(&start).copied().unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+const MODERATORS_SUFFIX: &str = "/moderators";
+
+/// moderator sockets are counted like viewers, just under their own key,
+/// it is also the pubsub topic of messages only moderators receive
+fn moderators_key(event: &str) -> String {
+    format!("{event}{MODERATORS_SUFFIX}")
+}
+
+#[async_trait]
+impl PubSubReceiver for App {
+    async fn notify(&self, topic: &str, payload: &str) {
+        let (event, moderators_only) = topic
+            .strip_suffix(MODERATORS_SUFFIX)
+            .map_or((topic, false), |event| (event, true));
+
+        //Note: long polls only learn about a new version, that is fine for moderator messages too
+        if let Some(long_poll) = self.long_polls.read().await.get(event) {
+            long_poll.notify_waiters();
+        }
+
+        let event = event.to_string();
+        let msg = Message::Text(payload.to_string());
+
+        //Note: only deltas are worth encoding binary, anything else is a few bytes anyway
+        let binary = match WsMessage::decode(payload) {
+            Some(WsMessage::Delta(delta)) => delta.to_msgpack().ok().map(Message::Binary),
+            _ => None,
+        };
+
+        let channels = Arc::clone(&self.channels);
+
+        if let Err(e) = tokio::spawn(async move {
+            //TODO: lookup subscriber based on topic name
+            let receivers = channels.read().await.clone();
+            for subscriber in receivers
+                .values()
+                .filter(|s| s.event == event && (s.moderator || !moderators_only))
+            {
+                let msg = match (&subscriber.encoding, &binary) {
+                    (SocketEncoding::Msgpack, Some(binary)) => binary.clone(),
+                    _ => msg.clone(),
+                };
+
+                if let Err(e) = subscriber.channel.send(Ok(msg)) {
+                    if let Err(inner_err) = &e.0 {
+                        tracing::error!("pubsub send err: {} ({})", e, inner_err);
+                    } else {
+                        tracing::info!("pubsub not sent: {}", e);
+                    }
+                }
+            }
+        })
+        .await
+        {
+            tracing::error!("pubsub notify error: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        attachments::AttachmentError,
+        eventsdb::{event_key, InMemoryEventsDB, PremiumOrder},
+        pubsub::{PubSubInMemory, PubSubReceiverInMemory},
+        schedule::InMemorySchedule,
+        summary::SummaryError,
+        translate::TranslateError,
+        typing::InMemoryTyping,
+        viewers::MockViewers,
+    };
+    use pretty_assertions::{assert_eq, assert_ne};
+    use shared::{
+        AddQuestion, BlockTarget, CurrentTag, EditDownvote, EditLike, EditReaction, Reaction,
+        Sentiment, TagId, QUESTIONS_MAX, TEST_EVENT_DESC, TEST_EVENT_NAME, TEST_VALID_QUESTION,
+    };
+    use std::sync::Arc;
+
+    /// app on in memory backends only
+    fn test_app() -> App {
+        test_app_with(
+            Arc::new(InMemoryEventsDB::default()),
+            Arc::new(PubSubInMemory::default()),
+        )
+    }
+
+    /// pass `events` to access the stored events and `pubsub` to observe notifications
+    fn test_app_with(events: Arc<InMemoryEventsDB>, pubsub: Arc<dyn PubSubPublish>) -> App {
+        App::new(
+            events,
+            pubsub,
+            Stores::in_memory(),
+            Arc::new(Payment::default()),
+            Tracking::default(),
+            String::new(),
+        )
+    }
+
+    async fn create_test_event(app: &App) -> EventInfo {
+        app.create_event(AddEvent {
+            data: EventData {
+                name: TEST_EVENT_NAME.to_string(),
+                description: TEST_EVENT_DESC.to_string(),
+                short_url: String::new(),
+                long_url: None,
+                start_unix: None,
+                end_unix: None,
+                timezone: None,
+                branding: None,
+            },
+            moderator_email: None,
+            subscription: None,
+            test: false,
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_event_create_fail_validation() {
+        let app = test_app();
+
+        let res = app
+            .create_event(AddEvent {
+                data: EventData {
+                    name: String::from("too short"),
+                    description: String::new(),
+                    short_url: String::new(),
+                    long_url: None,
+                    start_unix: None,
+                    end_unix: None,
+                    timezone: None,
+                    branding: None,
+                },
+                moderator_email: None,
+                subscription: None,
+                test: false,
+            })
+            .await;
+
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_event_create_email_fail_validation() {
+        let app = test_app();
+
+        let res = app
+            .create_event(AddEvent {
+                data: EventData {
+                    name: TEST_EVENT_NAME.to_string(),
+                    description: TEST_EVENT_DESC.to_string(),
+                    short_url: String::new(),
+                    long_url: None,
+                    start_unix: None,
+                    end_unix: None,
+                    timezone: None,
+                    branding: None,
+                },
+                moderator_email: Option::Some("a@a".to_string()),
+                subscription: None,
+                test: false,
+            })
+            .await;
+
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_event_create_email_pass_validation() {
+        let app = test_app();
+
+        let res = app
+            .create_event(AddEvent {
+                data: EventData {
+                    name: TEST_EVENT_NAME.to_string(),
+                    description: TEST_EVENT_DESC.to_string(),
+                    short_url: String::new(),
+                    long_url: None,
+                    start_unix: None,
+                    end_unix: None,
+                    timezone: None,
+                    branding: None,
+                },
+                moderator_email: Option::Some("testuser@live-ask.com".to_string()),
+                subscription: None,
+                test: false,
+            })
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_event_create() {
+        let eventdb = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(eventdb.clone(), Arc::new(PubSubInMemory::default()));
+
+        create_test_event(&app).await;
+
+        assert_eq!(eventdb.db.lock().await.len(), 1);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_event_mod_question_notification() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let app = test_app_with(Arc::new(InMemoryEventsDB::default()), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        let q = app
+            .add_question(
+                res.tokens.public_token.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        app.mod_edit_question(
+            res.tokens.public_token.clone(),
+            res.tokens.moderator_token.unwrap(),
+            q.id,
+            ModQuestion {
+                hide: true,
+                answered: false,
+                screened: true,
+                pinned: false,
+                shadow_ban: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            pubsubreceiver.log.read().await[0].clone(),
+            (
+                res.tokens.public_token.clone(),
+                WsMessage::Delta(EventDelta::QuestionAdded(q.clone())).encode()
+            )
+        );
+
+        assert_eq!(
+            pubsubreceiver.log.read().await[1].clone(),
+            (
+                res.tokens.public_token.clone(),
+                WsMessage::Delta(EventDelta::QuestionStateChanged {
+                    id: q.id,
+                    hidden: true,
+                    answered: false,
+                    screening: false,
+                    pinned: false,
+                })
+                .encode()
+            )
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_delta_notification_fallback() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let app = test_app_with(Arc::new(InMemoryEventsDB::default()), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
+
+        app.mod_edit_event(
+            id.clone(),
+            secret.clone(),
+            ModEvent {
+                screening: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        app.mod_edit_event(
+            id,
+            secret,
+            ModEvent {
+                state: Some(EventState {
+                    state: States::VotingOnly,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let log = pubsubreceiver.log.read().await.clone();
+        assert_eq!(
+            log.into_iter().map(|(_, msg)| msg).collect::<Vec<_>>(),
+            vec![
+                WsMessage::Event.encode(),
+                WsMessage::Question(q.id).encode(),
+                WsMessage::Delta(EventDelta::EventStateChanged(EventState {
+                    state: States::VotingOnly,
+                }))
+                .encode(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_screening_question() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&res.tokens.public_token))
+            .unwrap()
+            .event
+            .do_screening = true;
+
+        let q = app
+            .add_question(
+                res.tokens.public_token.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(q.screening, true);
+
+        let e = app
+            .get_event(res.tokens.public_token.clone(), None, false, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(e.info.questions.len(), 0);
+
+        let e = app
+            .get_event(
+                res.tokens.public_token.clone(),
+                Some(res.tokens.moderator_token.clone().unwrap()),
+                false,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(e.info.questions.len(), 1);
+
+        app.mod_edit_question(
+            res.tokens.public_token.clone(),
+            res.tokens.moderator_token.clone().unwrap(),
+            q.id,
+            ModQuestion {
+                hide: false,
+                answered: false,
+                screened: true,
+                pinned: false,
+                shadow_ban: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let e = app
+            .get_event(res.tokens.public_token.clone(), None, false, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(e.info.questions.len(), 1);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_screening_question_disapprove() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&res.tokens.public_token))
+            .unwrap()
+            .event
+            .do_screening = true;
+
+        let q = app
+            .add_question(
+                res.tokens.public_token.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(q.screening, true);
+
+        let e = app
+            .get_event(res.tokens.public_token.clone(), None, false, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(e.info.questions.len(), 0);
+
+        let e = app
+            .mod_edit_question(
+                res.tokens.public_token.clone(),
+                res.tokens.moderator_token.clone().unwrap(),
+                q.id,
+                ModQuestion {
+                    hide: true,
+                    answered: false,
+                    screened: false,
+                    pinned: false,
+                    shadow_ban: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(e.questions[0].screening, false);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_screening_enable() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&res.tokens.public_token))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        let e = app
+            .mod_edit_event(
+                res.tokens.public_token.clone(),
+                res.tokens.moderator_token.clone().unwrap(),
+                ModEvent {
+                    screening: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(e.is_screening());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_duplicate_question_check() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        app.add_question(
+            res.tokens.public_token.clone(),
+            AddQuestion {
+                text: String::from(TEST_VALID_QUESTION),
+                name: None,
+                attachment: None,
+                session: None,
+                participant: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let request = app
+            .add_question(
+                res.tokens.public_token.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            request.unwrap_err(),
+            InternalError::DuplicateQuestion
+        ))
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_password_protection() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        let event_id = res.tokens.public_token.clone();
+        let mod_token = res.tokens.moderator_token.clone().unwrap();
+
+        let question_text = "very long, sophisticated question you can ask!";
+        app.add_question(
+            event_id.clone(),
+            AddQuestion {
+                text: String::from(question_text),
+                name: None,
+                attachment: None,
+                session: None,
+                participant: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        app.mod_edit_event(
+            event_id.clone(),
+            mod_token.clone(),
+            ModEvent {
+                password: Some(shared::EventPassword::Enabled(String::from("pwd"))),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let e = app
+            .get_event(event_id.clone(), None, false, None, None)
+            .await
+            .unwrap();
+
+        assert_ne!(&e.info.questions[0].text, question_text);
+        assert!(e.flags.contains(EventResponseFlags::WRONG_PASSWORD));
+
+        let e = app
+            .get_event(
+                event_id.clone(),
+                None,
+                false,
+                Some(String::from("pwd")),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(&e.info.questions[0].text, question_text);
+        assert!(!e.flags.contains(EventResponseFlags::WRONG_PASSWORD));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_event_question_tags() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&res.tokens.public_token))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        assert_eq!(
+            app.mod_edit_event(
+                res.tokens.public_token.clone(),
+                res.tokens.moderator_token.clone().unwrap(),
+                ModEvent {
+                    current_tag: Some(CurrentTag::Enabled(String::from("tag1"))),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap()
+            .tags
+            .current_tag
+            .unwrap(),
+            TagId(0)
+        );
+
+        let request = app
+            .add_question(
+                res.tokens.public_token.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.tag.unwrap(), TagId(0))
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_names() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let event_id = res.tokens.public_token.clone();
+
+        let q = app
+            .add_question(
+                event_id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: Some(String::from("alice")),
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(q.name, None);
+
+        app.mod_edit_event(
+            event_id.clone(),
+            res.tokens.moderator_token.clone().unwrap(),
+            ModEvent {
+                question_names: Some(QuestionNames::Required),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let request = app
+            .add_question(
+                event_id.clone(),
+                AddQuestion {
+                    text: String::from("second valid question"),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            request.unwrap_err(),
+            InternalError::NameValidation(_)
+        ));
+
+        let q = app
+            .add_question(
+                event_id.clone(),
+                AddQuestion {
+                    text: String::from("second valid question"),
+                    name: Some(String::from(" alice ")),
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(q.name.unwrap(), "alice");
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_length() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let event_id = res.tokens.public_token.clone();
+        let mod_token = res.tokens.moderator_token.clone().unwrap();
+
+        let request = app
+            .mod_edit_event(
+                event_id.clone(),
+                mod_token.clone(),
+                ModEvent {
+                    question_length: Some(QuestionLength { min: 20, max: 10 }),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        assert!(request.is_err());
+
+        let e = app
+            .mod_edit_event(
+                event_id.clone(),
+                mod_token.clone(),
+                ModEvent {
+                    question_length: Some(QuestionLength { min: 5, max: 12 }),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(e.question_length, QuestionLength { min: 5, max: 12 });
+
+        let request = app
+            .add_question(
+                event_id.clone(),
+                AddQuestion {
+                    text: String::from("question too long"),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            request.unwrap_err(),
+            InternalError::AddQuestionValidation(_)
+        ));
+
+        app.add_question(
+            event_id.clone(),
+            AddQuestion {
+                text: String::from("a b c"),
+                name: None,
+                attachment: None,
+                session: None,
+                participant: None,
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_cooldown() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let event_id = res.tokens.public_token.clone();
+        let mod_token = res.tokens.moderator_token.clone().unwrap();
+
+        assert!(app
+            .mod_edit_event(
+                event_id.clone(),
+                mod_token.clone(),
+                ModEvent {
+                    question_cooldown_mins: Some(QUESTION_COOLDOWN_MAX_MINS + 1),
+                    ..Default::default()
+                },
+            )
+            .await
+            .is_err());
+
+        let e = app
+            .mod_edit_event(
+                event_id.clone(),
+                mod_token.clone(),
+                ModEvent {
+                    question_cooldown_mins: Some(5),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(e.question_cooldown_mins, 5);
+
+        let question = |text: &str, participant: Option<&str>| AddQuestion {
+            text: text.to_string(),
+            name: None,
+            attachment: None,
+            session: None,
+            participant: participant.map(ToString::to_string),
+        };
+
+        assert!(app
+            .add_question(event_id.clone(), question("my first question", None))
+            .await
+            .is_err());
+
+        app.add_question(event_id.clone(), question("my first question", Some("a")))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            app.add_question(event_id.clone(), question("my second question", Some("a")))
+                .await
+                .unwrap_err(),
+            InternalError::QuestionCooldown(_)
+        ));
+
+        app.add_question(event_id.clone(), question("my second question", Some("b")))
+            .await
+            .unwrap();
+
+        app.mod_edit_event(
+            event_id.clone(),
+            mod_token,
+            ModEvent {
+                question_cooldown_mins: Some(0),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        app.add_question(event_id, question("my third question", Some("a")))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_cooldown_pruned() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let event_id = res.tokens.public_token.clone();
+
+        app.mod_edit_event(
+            event_id.clone(),
+            res.tokens.moderator_token.clone().unwrap(),
+            ModEvent {
+                question_cooldown_mins: Some(5),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let mut entry = events.get(&event_id).await.unwrap();
+        entry
+            .event
+            .last_questions
+            .insert(String::from("old"), timestamp_now() - 10 * 60);
+        entry.bump();
+        events.put(entry).await.unwrap();
+
+        app.add_question(
+            event_id.clone(),
+            AddQuestion {
+                text: String::from(TEST_VALID_QUESTION),
+                name: None,
+                attachment: None,
+                session: None,
+                participant: Some(String::from("new")),
+            },
+        )
+        .await
+        .unwrap();
+
+        let e = events.get(&event_id).await.unwrap().event;
+        assert_eq!(e.last_questions.len(), 1);
+        assert!(e.last_questions.contains_key("new"));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_limits() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let event_id = res.tokens.public_token.clone();
+        let mod_token = res.tokens.moderator_token.clone().unwrap();
+
+        assert!(app
+            .mod_edit_event(
+                event_id.clone(),
+                mod_token.clone(),
+                ModEvent {
+                    question_limits: Some(QuestionLimits {
+                        total: QUESTIONS_MAX + 1,
+                        per_minute: 0,
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await
+            .is_err());
+
+        let e = app
+            .mod_edit_event(
+                event_id.clone(),
+                mod_token.clone(),
+                ModEvent {
+                    question_limits: Some(QuestionLimits {
+                        total: 3,
+                        per_minute: 2,
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(e.question_limits.max_questions(), 3);
+
+        let question = |text: &str| AddQuestion {
+            text: text.to_string(),
+            name: None,
+            attachment: None,
+            session: None,
+            participant: None,
+        };
+
+        app.add_question(event_id.clone(), question("my first question"))
+            .await
+            .unwrap();
+        app.add_question(event_id.clone(), question("my second question"))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            app.add_question(event_id.clone(), question("my third question"))
+                .await
+                .unwrap_err(),
+            InternalError::QuestionRateLimited(_, _)
+        ));
+
+        app.mod_edit_event(
+            event_id.clone(),
+            mod_token,
+            ModEvent {
+                question_limits: Some(QuestionLimits {
+                    total: 3,
+                    per_minute: 0,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        app.add_question(event_id.clone(), question("my third question"))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            app.add_question(event_id, question("my fourth question"))
+                .await
+                .unwrap_err(),
+            InternalError::QuestionLimitReached(_)
+        ));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_html_stripped() {
+        let app = test_app();
+
+        let res = app
+            .create_event(AddEvent {
+                data: EventData {
+                    name: String::from("123456789"),
+                    description: String::from("<script>alert(1)</script>**123456789** 123456789 !"),
+                    short_url: String::new(),
+                    long_url: None,
+                    start_unix: None,
+                    end_unix: None,
+                    timezone: None,
+                    branding: None,
+                },
+                moderator_email: None,
+                subscription: None,
+                test: false,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(res.data.description, "alert(1)**123456789** 123456789 !");
+
+        let q = app
+            .add_question(
+                res.tokens.public_token.clone(),
+                AddQuestion {
+                    text: String::from("<b>what</b> is *this* <img src=x onerror=alert(1)>about?"),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(q.text, "what is *this* about?");
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_attachment() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let key = shared::attachment_key(&id, "file");
+
+        let request = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: Some(key.clone()),
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            request.unwrap_err(),
+            InternalError::PremiumOnlyFeature(_)
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        let request = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: Some(shared::attachment_key("other", "file")),
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(request.unwrap_err(), InternalError::General(_)));
+
+        let q = app
+            .add_question(
+                id,
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: Some(key.clone()),
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(q.attachment, Some(key));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .questions[0]
+            .hidden = true;
+
+        let request = app
+            .attachment_url(id.clone(), None, String::from("file"))
+            .await;
+
+        assert!(matches!(request.unwrap_err(), InternalError::General(_)));
+
+        let request = app
+            .attachment_url(
+                id.clone(),
+                Some(String::from("wrong")),
+                String::from("file"),
+            )
+            .await;
+
+        assert!(matches!(
+            request.unwrap_err(),
+            InternalError::WrongModeratorToken(_)
+        ));
+
+        // passes the visibility check, fails only because storage is not configured in tests
+        let request = app
+            .attachment_url(
+                id,
+                Some(res.tokens.moderator_token.unwrap()),
+                String::from("file"),
+            )
+            .await;
+
+        assert!(matches!(
+            request.unwrap_err(),
+            InternalError::Attachment(AttachmentError::NotConfigured)
+        ));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_reactions() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        for (reaction, add) in [
+            (Reaction::Heart, true),
+            (Reaction::Heart, true),
+            (Reaction::Heart, false),
+            (Reaction::Thinking, false),
+        ] {
+            app.edit_reaction(
+                id.clone(),
+                EditReaction {
+                    question_id: q.id,
+                    reaction,
+                    add,
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        let q = app.get_question(id, None, q.id).await.unwrap();
+
+        assert_eq!(q.reactions, Reactions([0, 1, 0, 0]));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_get_question_moderator() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        app.mod_edit_question(
+            id.clone(),
+            secret.clone(),
+            q.id,
+            ModQuestion {
+                hide: true,
+                answered: false,
+                screened: true,
+                pinned: false,
+                shadow_ban: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(app.get_question(id.clone(), None, q.id).await.is_err());
+        assert!(app
+            .get_question(id.clone(), Some(String::from("wrong")), q.id)
+            .await
+            .is_err());
+
+        let q = app.get_question(id, Some(secret), q.id).await.unwrap();
+        assert!(q.hidden);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_downvotes() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let downvote = EditDownvote {
+            question_id: q.id,
+            downvote: true,
+        };
+
+        assert!(matches!(
+            app.edit_downvote(id.clone(), downvote).await.unwrap_err(),
+            InternalError::General(_)
+        ));
+
+        app.mod_edit_event(
+            id.clone(),
+            res.tokens.moderator_token.clone().unwrap(),
+            ModEvent {
+                downvotes: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        for _ in 0..2_u8 {
+            app.edit_downvote(
+                id.clone(),
+                EditDownvote {
+                    question_id: q.id,
+                    downvote: true,
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        let q = app.get_question(id, None, q.id).await.unwrap();
+
+        assert_eq!(q.downvotes, 2);
+        assert_eq!(q.score(), -1);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_unlike_question() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let like = |like| EditLike {
+            question_id: q.id,
+            like,
+            participant: None,
+        };
+
+        assert_eq!(
+            app.edit_like(id.clone(), like(true), None)
+                .await
+                .unwrap()
+                .likes,
+            2
+        );
+        assert_eq!(
+            app.edit_like(id.clone(), like(false), None)
+                .await
+                .unwrap()
+                .likes,
+            1
+        );
+        assert_eq!(
+            app.edit_like(id.clone(), like(false), None)
+                .await
+                .unwrap()
+                .likes,
+            0
+        );
+        assert_eq!(app.edit_like(id, like(false), None).await.unwrap().likes, 0);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_like_burst_discounted() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let like = || EditLike {
+            question_id: q.id,
+            like: true,
+            participant: Some(String::from("script")),
+        };
+
+        for _ in 0..LIKE_BURST_MAX_PARTICIPANT + 5 {
+            app.edit_like(id.clone(), like(), None).await.unwrap();
+        }
+
+        //unlikes within the burst do not take back counted likes
+        let unliked = app
+            .edit_like(
+                id.clone(),
+                EditLike {
+                    like: false,
+                    ..like()
+                },
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            unliked.likes,
+            1 + i32::try_from(LIKE_BURST_MAX_PARTICIPANT).unwrap()
+        );
+
+        //only the first discounted like is stored right away
+        let e = app
+            .get_event(
+                id.clone(),
+                res.tokens.moderator_token.clone(),
+                false,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            e.mod_info.unwrap().flagged_likes,
+            vec![FlaggedLikes {
+                question_id: q.id,
+                discounted: 1,
+            }]
+        );
+
+        //other browsers behind the same address are still counted
+        let liked = app
+            .edit_like(
+                id.clone(),
+                EditLike {
+                    question_id: q.id,
+                    like: true,
+                    participant: Some(String::from("human")),
+                },
+                Some(String::from("10.0.0.1")),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            liked.likes,
+            2 + i32::try_from(LIKE_BURST_MAX_PARTICIPANT).unwrap()
+        );
+
+        let e = app
+            .get_event(id, res.tokens.moderator_token, false, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            e.mod_info.unwrap().flagged_likes,
+            vec![FlaggedLikes {
+                question_id: q.id,
+                discounted: 5,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_pin_question() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let state = |answered, pinned| ModQuestion {
+            hide: false,
+            answered,
+            screened: true,
+            pinned,
+            shadow_ban: None,
+        };
+
+        let e = app
+            .mod_edit_question(id.clone(), secret.clone(), q.id, state(false, true))
+            .await
+            .unwrap();
+        assert!(e.questions[0].pinned);
+
+        let e = app
+            .get_event(id.clone(), None, false, None, None)
+            .await
+            .unwrap();
+        assert!(e.info.questions[0].pinned);
+
+        //Note: answering drops the pin
+        let e = app
+            .mod_edit_question(id, secret, q.id, state(true, true))
+            .await
+            .unwrap();
+        assert!(!e.questions[0].pinned);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_shadow_ban() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let ask = |i: usize| AddQuestion {
+            text: format!("{TEST_VALID_QUESTION} {i}"),
+            name: None,
+            attachment: None,
+            session: None,
+            participant: Some(String::from("troll")),
+        };
+
+        let q = app.add_question(id.clone(), ask(0)).await.unwrap();
+
+        let state = |shadow_ban| ModQuestion {
+            hide: false,
+            answered: false,
+            screened: true,
+            pinned: false,
+            shadow_ban: Some(shadow_ban),
+        };
+
+        app.mod_edit_question(id.clone(), secret.clone(), q.id, state(true))
+            .await
+            .unwrap();
+
+        //Note: the author must not notice
+        let q2 = app.add_question(id.clone(), ask(1)).await.unwrap();
+        assert!(!q2.shadowed);
+
+        let e = app
+            .get_event(id.clone(), None, false, None, None)
+            .await
+            .unwrap();
+        assert!(e.info.questions.is_empty());
+
+        let e = app
+            .get_event(id.clone(), None, false, None, Some(String::from("troll")))
+            .await
+            .unwrap();
+        assert_eq!(e.info.questions.len(), 2);
+        assert!(e.info.questions.iter().all(|q| !q.shadowed));
+
+        let e = app
+            .get_event(id.clone(), Some(secret.clone()), false, None, None)
+            .await
+            .unwrap();
+        assert_eq!(e.info.questions.len(), 2);
+        assert!(e.info.questions.iter().all(|q| q.shadowed));
+
+        app.mod_edit_question(id.clone(), secret, q2.id, state(false))
+            .await
+            .unwrap();
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert_eq!(e.info.questions.len(), 2);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_blocked_terms() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let edit = |terms: &[&str], action| ModEvent {
+            blocked_terms: Some(BlockedTerms {
+                terms: terms.iter().map(ToString::to_string).collect(),
+                action,
+            }),
+            ..Default::default()
+        };
+
+        assert!(app
+            .mod_edit_event(
+                id.clone(),
+                secret.clone(),
+                edit(&["  "], BlockedTermsAction::Review)
+            )
+            .await
+            .is_err());
+
+        app.mod_edit_event(
+            id.clone(),
+            secret.clone(),
+            edit(&["Spam ", "free money", "spam"], BlockedTermsAction::Review),
+        )
+        .await
+        .unwrap();
+
+        let e = app
+            .get_event(id.clone(), Some(secret.clone()), false, None, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            e.mod_info.unwrap().blocked_terms.terms,
+            vec![String::from("spam"), String::from("free money")]
+        );
+
+        let ask = |text: &str| AddQuestion {
+            text: text.to_string(),
+            name: None,
+            attachment: None,
+            session: None,
+            participant: None,
+        };
+
+        let q = app
+            .add_question(id.clone(), ask("get FREE money right now"))
+            .await
+            .unwrap();
+        assert!(q.screening && !q.hidden);
+
+        //Note: only whole words match
+        let q = app
+            .add_question(id.clone(), ask("spammy questions are fine"))
+            .await
+            .unwrap();
+        assert!(!q.screening && !q.hidden);
+
+        app.mod_edit_event(
+            id.clone(),
+            secret.clone(),
+            edit(&["spam"], BlockedTermsAction::Hide),
+        )
+        .await
+        .unwrap();
+
+        let q = app
+            .add_question(id.clone(), ask("this is spam, really"))
+            .await
+            .unwrap();
+        assert!(!q.screening && q.hidden);
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert_eq!(e.info.questions.len(), 1);
+        assert_eq!(e.mod_info, None);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_languages() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let edit = |languages: &[&str], action| ModEvent {
+            question_languages: Some(QuestionLanguages {
+                languages: languages.iter().map(ToString::to_string).collect(),
+                action,
+            }),
+            ..Default::default()
+        };
+
+        assert!(app
+            .mod_edit_event(
+                id.clone(),
+                secret.clone(),
+                edit(&["en", "xx"], LanguageAction::Review)
+            )
+            .await
+            .is_err());
+
+        app.mod_edit_event(
+            id.clone(),
+            secret.clone(),
+            edit(&["en"], LanguageAction::Review),
+        )
+        .await
+        .unwrap();
+
+        let ask = |text: &str| AddQuestion {
+            text: text.to_string(),
+            name: None,
+            attachment: None,
+            session: None,
+            participant: None,
+        };
+
+        let q = app
+            .add_question(id.clone(), ask("what is the plan for the next release?"))
+            .await
+            .unwrap();
+        assert!(!q.screening);
+
+        let q = app
+            .add_question(
+                id.clone(),
+                ask("wie ist der Plan und was kommt als naechstes?"),
+            )
+            .await
+            .unwrap();
+        assert!(q.screening);
+
+        //Note: no frequent words to tell the language by, so it is taken
+        let q = app
+            .add_question(id.clone(), ask("Rust async runtime tips?"))
+            .await
+            .unwrap();
+        assert!(!q.screening);
+
+        app.mod_edit_event(
+            id.clone(),
+            secret.clone(),
+            edit(&["en"], LanguageAction::Reject),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            app.add_question(id.clone(), ask("pourquoi est-ce que la salle est vide?"))
+                .await
+                .unwrap_err(),
+            InternalError::QuestionLanguage(_)
+        ));
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert_eq!(
+            e.info.question_languages.languages,
+            vec![String::from("en")]
+        );
+        assert_eq!(e.info.questions.len(), 2);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_summary() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        assert!(matches!(
+            app.mod_question_summary(id.clone(), secret.clone())
+                .await
+                .unwrap_err(),
+            InternalError::PremiumOnlyFeature(_)
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        assert!(matches!(
+            app.mod_question_summary(id.clone(), String::from("wrong"))
+                .await
+                .unwrap_err(),
+            InternalError::WrongModeratorToken(_)
+        ));
+
+        assert!(matches!(
+            app.mod_question_summary(id.clone(), secret.clone())
+                .await
+                .unwrap_err(),
+            InternalError::General(_)
+        ));
+
+        app.add_question(
+            id.clone(),
+            AddQuestion {
+                text: String::from(TEST_VALID_QUESTION),
+                name: None,
+                attachment: None,
+                session: None,
+                participant: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        //Note: no endpoint is configured in tests
+        assert!(matches!(
+            app.mod_question_summary(id.clone(), secret.clone())
+                .await
+                .unwrap_err(),
+            InternalError::Summary(SummaryError::NotConfigured)
+        ));
+
+        let e = app
+            .get_event(id, Some(secret), false, None, None)
+            .await
+            .unwrap();
+        let mod_info = e.mod_info.unwrap();
+        assert!(!mod_info.summaries);
+        assert_eq!(mod_info.question_summary, None);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_clusters() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        for text in [
+            "When will the new release ship?",
+            "Which database do you use in production?",
+            "When is the new release shipping?",
+            "when will the NEW release ship to customers?",
+        ] {
+            app.add_question(
+                id.clone(),
+                AddQuestion {
+                    text: text.to_string(),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        app.edit_like(
+            id.clone(),
+            EditLike {
+                question_id: 2,
+                like: true,
+                participant: None,
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+        let clusters = app
+            .mod_question_clusters(id.clone(), secret.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            clusters,
+            vec![QuestionCluster {
+                questions: vec![2, 0, 3]
+            }]
+        );
+
+        assert!(app
+            .mod_merge_questions(
+                id.clone(),
+                secret.clone(),
+                ModMergeQuestions {
+                    target: 2,
+                    questions: vec![2, 0],
+                },
+            )
+            .await
+            .is_err());
+
+        let e = app
+            .mod_merge_questions(
+                id.clone(),
+                secret.clone(),
+                ModMergeQuestions {
+                    target: 2,
+                    questions: vec![0, 3],
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(e.questions[2].likes, 4);
+        assert!(e.questions[0].hidden);
+        assert_eq!(e.questions[0].merged_into, Some(2));
+        assert_eq!(e.questions[3].merged_into, Some(2));
+
+        //Note: merged questions cannot be merged again
+        assert!(app
+            .mod_merge_questions(
+                id.clone(),
+                secret.clone(),
+                ModMergeQuestions {
+                    target: 1,
+                    questions: vec![0],
+                },
+            )
+            .await
+            .is_err());
+
+        assert!(app
+            .mod_question_clusters(id, secret)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_sentiment() {
+        let mut app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let add = |text: &str| AddQuestion {
+            text: String::from(text),
+            name: None,
+            attachment: None,
+            session: None,
+            participant: None,
+        };
+
+        app.add_question(id.clone(), add("thanks for the great talk, any book tips?"))
+            .await
+            .unwrap();
+
+        let e = app
+            .get_event(id.clone(), Some(secret.clone()), false, None, None)
+            .await
+            .unwrap();
+        assert!(e.mod_info.unwrap().sentiments.is_empty());
+
+        app.sentiment = true;
+
+        app.add_question(id.clone(), add("why is the roadmap so boring and useless?"))
+            .await
+            .unwrap();
+        app.add_question(id.clone(), add("which release ships the new api?"))
+            .await
+            .unwrap();
+        app.add_question(id.clone(), add("this is not bad at all, when is it out?"))
+            .await
+            .unwrap();
+
+        let e = app
+            .get_event(id.clone(), Some(secret.clone()), false, None, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            e.mod_info.unwrap().sentiments,
+            vec![
+                QuestionSentiment {
+                    question_id: 1,
+                    sentiment: Sentiment::Negative,
+                },
+                QuestionSentiment {
+                    question_id: 2,
+                    sentiment: Sentiment::Neutral,
+                },
+                QuestionSentiment {
+                    question_id: 3,
+                    sentiment: Sentiment::Positive,
+                },
+            ]
+        );
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert!(e.mod_info.is_none());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_translation() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            app.translate_question(id.clone(), None, None, q.id, String::from("de"))
+                .await
+                .unwrap_err(),
+            InternalError::PremiumOnlyFeature(_)
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        assert!(matches!(
+            app.translate_question(id.clone(), None, None, q.id, String::from("xx"))
+                .await
+                .unwrap_err(),
+            InternalError::General(_)
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .questions[0]
+            .screening = true;
+
+        assert!(matches!(
+            app.translate_question(id.clone(), None, None, q.id, String::from("de"))
+                .await
+                .unwrap_err(),
+            InternalError::General(_)
+        ));
+
+        //Note: no endpoint is configured in tests
+        assert!(matches!(
+            app.translate_question(
+                id.clone(),
+                Some(secret.clone()),
+                None,
+                q.id,
+                String::from("de")
+            )
+            .await
+            .unwrap_err(),
+            InternalError::Translate(TranslateError::NotConfigured)
+        ));
+
+        {
+            let mut db = events.db.lock().await;
+            let e = &mut db.get_mut(&event_key(&id)).unwrap().event;
+            e.questions[0].screening = false;
+            e.password = shared::EventPassword::Enabled(String::from("pwd"));
+        }
+
+        for password in [None, Some(String::from("wrong"))] {
+            assert!(matches!(
+                app.translate_question(id.clone(), None, password, q.id, String::from("de"))
+                    .await
+                    .unwrap_err(),
+                InternalError::General(_)
+            ));
+        }
+
+        assert!(matches!(
+            app.translate_question(
+                id.clone(),
+                None,
+                Some(String::from("pwd")),
+                q.id,
+                String::from("de")
+            )
+            .await
+            .unwrap_err(),
+            InternalError::Translate(TranslateError::NotConfigured)
+        ));
+        assert!(matches!(
+            app.translate_question(id.clone(), Some(secret), None, q.id, String::from("de"))
+                .await
+                .unwrap_err(),
+            InternalError::Translate(TranslateError::NotConfigured)
+        ));
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert!(!e.can_translate());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_bulk_moderation() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        for i in 0..3 {
+            app.add_question(
+                id.clone(),
+                AddQuestion {
+                    text: format!("{TEST_VALID_QUESTION} {i}"),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        let bulk = |questions: Vec<i64>, action| ModQuestionsBulk { questions, action };
+
+        //Note: all or nothing
+        assert!(app
+            .mod_edit_questions(
+                id.clone(),
+                secret.clone(),
+                bulk(vec![0, 7], BulkAction::Hide)
+            )
+            .await
+            .is_err());
+
+        let e = app
+            .mod_edit_questions(
+                id.clone(),
+                secret.clone(),
+                bulk(vec![0, 1], BulkAction::Hide),
+            )
+            .await
+            .unwrap();
+        assert!(e.questions[0].hidden && e.questions[1].hidden);
+        assert!(!e.questions[2].hidden);
+
+        let e = app
+            .mod_edit_questions(
+                id.clone(),
+                secret.clone(),
+                bulk(vec![2], BulkAction::Answer),
+            )
+            .await
+            .unwrap();
+        assert!(e.questions[2].answered);
+
+        let tag = || BulkAction::Tag(Some(String::from("Finance")));
+
+        assert!(matches!(
+            app.mod_edit_questions(id.clone(), secret.clone(), bulk(vec![1, 2], tag()))
+                .await,
+            Err(InternalError::PremiumOnlyFeature(_))
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        let e = app
+            .mod_edit_questions(id.clone(), secret.clone(), bulk(vec![1, 2], tag()))
+            .await
+            .unwrap();
+        assert_eq!(e.tags.tags[0].name, "finance");
+        assert_eq!(e.tags.current_tag, None);
+        assert_eq!(e.questions[0].tag, None);
+        assert_eq!(e.questions[1].tag, Some(TagId(0)));
+        assert_eq!(e.questions[2].tag, Some(TagId(0)));
+
+        let e = app
+            .mod_edit_questions(id, secret, bulk(vec![1], BulkAction::Tag(None)))
+            .await
+            .unwrap();
+        assert_eq!(e.questions[1].tag, None);
+        assert_eq!(e.questions[2].tag, Some(TagId(0)));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_question_note() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let note = |note: &str| ModQuestionNote {
+            note: note.to_string(),
+        };
+
+        assert!(app
+            .mod_question_note(id.clone(), secret.clone(), q.id, note(&"x".repeat(501)))
+            .await
+            .is_err());
+
+        let q = app
+            .mod_question_note(id.clone(), secret.clone(), q.id, note(" assign to CFO "))
+            .await
+            .unwrap();
+        assert_eq!(q.note.as_deref(), Some("assign to CFO"));
+
+        let e = app
+            .get_event(id.clone(), Some(secret.clone()), false, None, None)
+            .await
+            .unwrap();
+        assert_eq!(e.info.questions[0].note.as_deref(), Some("assign to CFO"));
+
+        let q2 = app
+            .get_question(id.clone(), Some(secret.clone()), q.id)
+            .await
+            .unwrap();
+        assert_eq!(q2.note, q.note);
+
+        //Note: nobody but the moderators sees it
+        let e = app
+            .get_event(id.clone(), None, false, None, None)
+            .await
+            .unwrap();
+        assert_eq!(e.info.questions[0].note, None);
+
+        let q2 = app.get_question(id.clone(), None, q.id).await.unwrap();
+        assert_eq!(q2.note, None);
+
+        let q = app
+            .mod_question_note(id, secret, q.id, note(""))
+            .await
+            .unwrap();
+        assert_eq!(q.note, None);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_questions_pagination() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let mut ids = Vec::new();
+        for i in 0..5 {
+            let q = app
+                .add_question(
+                    id.clone(),
+                    AddQuestion {
+                        text: format!("{TEST_VALID_QUESTION} {i}"),
+                        name: None,
+                        attachment: None,
+                        session: None,
+                        participant: None,
+                    },
+                )
+                .await
+                .unwrap();
+            ids.push(q.id);
+        }
+
+        app.edit_like(
+            id.clone(),
+            EditLike {
+                question_id: ids[3],
+                like: true,
+                participant: None,
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+        app.mod_edit_question(
+            id.clone(),
+            secret,
+            ids[1],
+            ModQuestion {
+                hide: true,
+                answered: false,
+                screened: true,
+                pinned: false,
+                shadow_ban: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let params = |offset, limit| QuestionsParams {
+            offset,
+            limit: Some(limit),
+            sort: shared::QuestionSort::Score,
+        };
+
+        let page = app
+            .get_questions(id.clone(), params(0, 2), None, None)
+            .await
+            .unwrap();
+
+        //Note: hidden questions are not part of any page
+        assert_eq!(page.total, 4);
+        assert_eq!(page.questions.len(), 2);
+        assert_eq!(page.questions[0].id, ids[3]);
+        assert_eq!(page.next_offset(), Some(2));
+
+        let page = app
+            .get_questions(id, params(2, 2), None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(page.questions.len(), 2);
+        assert_eq!(page.next_offset(), None);
+        assert!(page.questions.iter().all(|q| q.id != ids[1]));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_event_preview() {
+        let app = App {
+            base_url: String::from("https://live-ask.com"),
+            ..test_app()
+        };
+
+        let res = app
+            .create_event(AddEvent {
+                data: EventData {
+                    name: String::from("Tom & Jerry"),
+                    description: String::from("123456789 123456789 123456789 !"),
+                    short_url: String::new(),
+                    long_url: None,
+                    start_unix: None,
+                    end_unix: None,
+                    timezone: None,
+                    branding: None,
+                },
+                moderator_email: None,
+                subscription: None,
+                test: false,
+            })
+            .await
+            .unwrap();
+
+        let id = res.tokens.public_token.clone();
+
+        app.add_question(
+            id.clone(),
+            AddQuestion {
+                text: String::from(TEST_VALID_QUESTION),
+                name: None,
+                attachment: None,
+                session: None,
+                participant: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let html = app.event_preview(id.clone()).await.unwrap();
+
+        assert!(html.contains("<h1>Tom &amp; Jerry</h1>"));
+        assert!(html.contains(TEST_VALID_QUESTION));
+        assert!(html.contains(&format!(
+            r#"<meta property="og:url" content="https://live-ask.com/event/{id}">"#
+        )));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_like_timeline() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let q = app
+            .add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(TEST_VALID_QUESTION),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        for like in [true, true, false] {
+            app.edit_like(
+                id.clone(),
+                EditLike {
+                    question_id: q.id,
+                    like,
+                    participant: None,
+                },
+                None,
+            )
+            .await
+            .unwrap();
+        }
+
+        assert!(matches!(
+            app.mod_like_timeline(id.clone(), String::from("wrong"))
+                .await
+                .unwrap_err(),
+            InternalError::WrongModeratorToken(_)
+        ));
+
+        let timeline = app.mod_like_timeline(id, secret).await.unwrap();
+
+        assert_eq!(timeline.questions.len(), 1);
+        assert_eq!(timeline.questions[0].question_id, q.id);
+        assert_eq!(
+            timeline.questions[0]
+                .buckets
+                .iter()
+                .map(|bucket| bucket.likes)
+                .sum::<i32>(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_event_stats() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let mut questions = Vec::new();
+        for _ in 0..2 {
+            questions.push(
+                app.add_question(
+                    id.clone(),
+                    AddQuestion {
+                        text: String::from(TEST_VALID_QUESTION),
+                        name: None,
+                        attachment: None,
+                        session: None,
+                        participant: None,
+                    },
+                )
+                .await
+                .unwrap(),
+            );
+        }
+
+        app.edit_like(
+            id.clone(),
+            EditLike {
+                question_id: questions[0].id,
+                like: true,
+                participant: None,
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+        app.mod_edit_question(
+            id.clone(),
+            secret.clone(),
+            questions[0].id,
+            ModQuestion {
+                hide: false,
+                answered: true,
+                screened: true,
+                pinned: false,
+                shadow_ban: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        app.record_peak_viewers(&id, 5).await;
+        app.record_peak_viewers(&id, 3).await;
+
+        assert!(matches!(
+            app.mod_event_stats(id.clone(), secret.clone())
+                .await
+                .unwrap_err(),
+            InternalError::PremiumOnlyFeature(_)
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        assert!(matches!(
+            app.mod_event_stats(id.clone(), String::from("wrong"))
+                .await
+                .unwrap_err(),
+            InternalError::WrongModeratorToken(_)
+        ));
+
+        let stats = app.mod_event_stats(id, secret).await.unwrap();
+
+        assert_eq!(stats.peak_viewers, 5);
+        assert_eq!(stats.total, 2);
+        assert_eq!(stats.answered, 1);
+        assert_eq!(stats.questions.iter().map(|b| b.count).sum::<i64>(), 2);
+        assert_eq!(stats.likes.iter().map(|b| b.count).sum::<i64>(), 1);
+    }
+
+    #[test]
+    fn test_stats_buckets() {
+        let buckets =
+            stats_buckets([(0, 1), (10, 2), (3 * EVENT_STATS_BUCKET_SECS + 1, 4)].into_iter());
+
+        assert_eq!(
+            buckets.iter().map(|b| b.count).collect::<Vec<_>>(),
+            vec![3, 0, 0, 4]
+        );
+        assert_eq!(buckets[1].start, EVENT_STATS_BUCKET_SECS);
+
+        let buckets = stats_buckets(
+            [
+                (0, 1),
+                (EVENT_STATS_MAX_BUCKETS * EVENT_STATS_BUCKET_SECS, 1),
+            ]
+            .into_iter(),
+        );
+
+        assert_eq!(buckets.len(), 288);
+        assert_eq!(buckets[0].start, EVENT_STATS_BUCKET_SECS);
+
+        assert!(stats_buckets(std::iter::empty()).is_empty());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_export_csv() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        for text in ["=1+2, is \"this\" safe?", TEST_VALID_QUESTION] {
+            app.add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from(text),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        assert!(matches!(
+            app.mod_export_csv(id.clone(), secret.clone())
+                .await
+                .unwrap_err(),
+            InternalError::PremiumOnlyFeature(_)
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        assert!(matches!(
+            app.mod_export_csv(id.clone(), String::from("wrong"))
+                .await
+                .unwrap_err(),
+            InternalError::WrongModeratorToken(_)
+        ));
+
+        let csv = app.mod_export_csv(id, secret).await.unwrap();
+        let rows: Vec<&str> = csv.split_terminator("\r\n").collect();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], "text,likes,created (utc),answered,hidden,tags");
+        assert!(rows[1].starts_with("\"'=1+2, is \"\"this\"\" safe?\",1,"));
+        assert!(rows[2].ends_with(",false,false,"));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_export_pdf() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        for _ in 0..60 {
+            app.add_question(
+                id.clone(),
+                AddQuestion {
+                    text: String::from("what (about) \\ this?"),
+                    name: None,
+                    attachment: None,
+                    session: None,
+                    participant: None,
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        assert!(matches!(
+            app.mod_export_pdf(id.clone(), secret.clone())
+                .await
+                .unwrap_err(),
+            InternalError::PremiumOnlyFeature(_)
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        assert!(matches!(
+            app.mod_export_pdf(id.clone(), String::from("wrong"))
+                .await
+                .unwrap_err(),
+            InternalError::WrongModeratorToken(_)
+        ));
+
+        let pdf = app.mod_export_pdf(id, secret).await.unwrap();
+        let contains = |needle: &[u8]| pdf.windows(needle.len()).any(|window| window == needle);
+
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        assert!(contains(TEST_EVENT_NAME.as_bytes()));
+        assert!(contains(b"(what \\(about\\) \\\\ this?) Tj"));
+        assert!(contains(b"/Count 4 >>"));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_export_markdown() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let mut questions = Vec::new();
+        for text in [TEST_VALID_QUESTION, "first *question* here"] {
+            questions.push(
+                app.add_question(
+                    id.clone(),
+                    AddQuestion {
+                        text: String::from(text),
+                        name: None,
+                        attachment: None,
+                        session: None,
+                        participant: None,
+                    },
+                )
+                .await
+                .unwrap(),
+            );
+        }
+
+        app.edit_like(
+            id.clone(),
+            EditLike {
+                question_id: questions[1].id,
+                like: true,
+                participant: None,
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+        app.mod_edit_question(
+            id.clone(),
+            secret.clone(),
+            questions[1].id,
+            ModQuestion {
+                hide: false,
+                answered: true,
+                screened: true,
+                pinned: false,
+                shadow_ban: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        assert_eq!(
+            app.mod_export_markdown(id, secret).await.unwrap(),
+            format!(
+                "# {TEST_EVENT_NAME}\n\n{TEST_EVENT_DESC}\n\n\
+                - **2 likes** first \\*question\\* here\n  > answered\n\
+                - **1 like** {TEST_VALID_QUESTION}\n"
+            )
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_export_slides() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        let mut questions = Vec::new();
+        for text in ["what about tom & jerry?", "already answered question"] {
+            questions.push(
+                app.add_question(
+                    id.clone(),
+                    AddQuestion {
+                        text: String::from(text),
+                        name: None,
+                        attachment: None,
+                        session: None,
+                        participant: None,
+                    },
+                )
+                .await
+                .unwrap(),
+            );
+        }
+
+        app.mod_edit_question(
+            id.clone(),
+            secret.clone(),
+            questions[1].id,
+            ModQuestion {
+                hide: false,
+                answered: true,
+                screened: true,
+                pinned: false,
+                shadow_ban: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            app.mod_export_slides(id.clone(), secret.clone())
+                .await
+                .unwrap_err(),
+            InternalError::PremiumOnlyFeature(_)
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        let slides = app.mod_export_slides(id, secret).await.unwrap();
+
+        assert_eq!(slides.matches("<section class=\"slide\">").count(), 1);
+        assert!(slides.contains("what about tom &amp; jerry?"));
+        assert!(!slides.contains("already answered question"));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_typing_notification() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let typing = InMemoryTyping::default();
+        let app = App {
+            typing: Arc::new(typing.clone()),
+            ..test_app_with(Arc::new(InMemoryEventsDB::default()), Arc::new(pubsub))
+        };
+
+        let event = String::from("event");
+
+        app.typing_signal(&event, 1).await;
+        app.typing_signal(&event, 2).await;
+        app.typing_signal(&event, 1).await;
+
+        assert_eq!(app.typing_remove(&event, 1).await, Some(1));
+        assert_eq!(app.typing_remove(&event, 3).await, None);
+        assert_eq!(app.typing_remove(&event, 2).await, Some(0));
+        assert!(typing.users.read().await.is_empty());
+
+        let log = pubsubreceiver.log.read().await.clone();
+        assert_eq!(
+            log.into_iter().map(|(_, msg)| msg).collect::<Vec<_>>(),
+            vec![
+                WsMessage::Typing(1).encode(),
+                WsMessage::Typing(2).encode(),
+                WsMessage::Typing(2).encode()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_binary_delta_encoding() {
+        let app = test_app();
+
+        let (json_sender, mut json_receiver) = mpsc::unbounded_channel();
+        let (binary_sender, mut binary_receiver) = mpsc::unbounded_channel();
+
+        {
+            let mut channels = app.channels.write().await;
+            channels.insert(
+                1,
+                Subscriber {
+                    event: String::from("event"),
+                    encoding: SocketEncoding::Json,
+                    channel: json_sender,
+                    moderator: false,
+                },
+            );
+            channels.insert(
+                2,
+                Subscriber {
+                    event: String::from("event"),
+                    encoding: SocketEncoding::Msgpack,
+                    channel: binary_sender,
+                    moderator: false,
+                },
+            );
+        }
+
+        let delta = EventDelta::QuestionLiked { id: 1, likes: 2 };
+        let payload = WsMessage::Delta(delta.clone()).encode();
+
+        app.notify("event", &payload).await;
+        app.notify("event", &WsMessage::Event.encode()).await;
+
+        assert_eq!(
+            json_receiver.recv().await.unwrap().unwrap(),
+            Message::Text(payload)
+        );
+        assert_eq!(
+            json_receiver.recv().await.unwrap().unwrap(),
+            Message::Text(WsMessage::Event.encode())
+        );
+
+        let binary = delta.to_msgpack().unwrap();
+        assert_eq!(EventDelta::from_msgpack(&binary).unwrap(), delta);
+
+        assert_eq!(
+            binary_receiver.recv().await.unwrap().unwrap(),
+            Message::Binary(binary)
+        );
+        assert_eq!(
+            binary_receiver.recv().await.unwrap().unwrap(),
+            Message::Text(WsMessage::Event.encode())
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_notify_moderators_only() {
+        let app = test_app();
+
+        let (viewer_sender, mut viewer_receiver) = mpsc::unbounded_channel();
+        let (mod_sender, mut mod_receiver) = mpsc::unbounded_channel();
+
+        {
+            let mut channels = app.channels.write().await;
+            channels.insert(
+                1,
+                Subscriber {
+                    event: String::from("event"),
+                    encoding: SocketEncoding::Json,
+                    channel: viewer_sender,
+                    moderator: false,
+                },
+            );
+            channels.insert(
+                2,
+                Subscriber {
+                    event: String::from("event"),
+                    encoding: SocketEncoding::Json,
+                    channel: mod_sender,
+                    moderator: true,
+                },
+            );
+        }
+
+        app.notify(&moderators_key("event"), &WsMessage::Event.encode())
+            .await;
+        app.notify("event", &WsMessage::Question(1).encode()).await;
+
+        assert_eq!(
+            mod_receiver.recv().await.unwrap().unwrap(),
+            Message::Text(WsMessage::Event.encode())
+        );
+        assert_eq!(
+            mod_receiver.recv().await.unwrap().unwrap(),
+            Message::Text(WsMessage::Question(1).encode())
+        );
+
+        assert_eq!(
+            viewer_receiver.recv().await.unwrap().unwrap(),
+            Message::Text(WsMessage::Question(1).encode())
+        );
+        assert!(viewer_receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_long_poll_updates() {
+        let pubsub = Arc::new(PubSubInMemory::default());
+        let app = Arc::new(test_app_with(
+            Arc::new(InMemoryEventsDB::default()),
+            pubsub.clone(),
+        ));
+        pubsub.set_receiver(app.clone()).await;
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+
+        let version = app
+            .get_event(id.clone(), None, false, None, None)
+            .await
+            .unwrap()
+            .version;
+
+        let poll = {
+            let app = app.clone();
+            let id = id.clone();
+            tokio::spawn(async move { app.event_updates(id, version).await })
+        };
+
+        app.add_question(
+            id.clone(),
+            AddQuestion {
+                text: String::from(TEST_VALID_QUESTION),
+                name: None,
+                attachment: None,
+                session: None,
+                participant: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let updates = tokio::time::timeout(Duration::from_secs(5), poll)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert!(updates.version > version);
+
+        //already newer, returns right away
+        assert_eq!(app.event_updates(id, version).await.unwrap(), updates);
+
+        assert!(app.long_polls.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_moderator_presence() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let mut viewers = MockViewers::new();
+        viewers
+            .expect_count()
+            .returning(|key| i64::from(key.ends_with("/moderators")));
+        let app = App {
+            viewers: Arc::new(viewers),
+            ..test_app_with(Arc::new(InMemoryEventsDB::default()), Arc::new(pubsub))
+        };
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        assert!(app.is_moderator(&id, &secret).await);
+        assert!(!app.is_moderator(&id, "wrong").await);
+        assert!(!app.is_moderator("unknown", &secret).await);
+
+        app.notify_moderator_presence(&id).await;
+
+        let log = pubsubreceiver.log.read().await.clone();
+        assert_eq!(
+            log.into_iter().map(|(_, msg)| msg).collect::<Vec<_>>(),
+            vec![WsMessage::ModeratorOnline(true).encode()]
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_viewer_capacity() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let mut viewers = MockViewers::new();
+        viewers.expect_add().times(1).return_const(());
+        viewers
+            .expect_try_add()
+            .returning(|_, max| max > 1)
+            .times(3);
+        let app = App {
+            viewers: Arc::new(viewers),
+            ..test_app_with(events.clone(), Arc::new(PubSubInMemory::default()))
+        };
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let capacity = |capacity: u32| ModEvent {
+            viewer_capacity: Some(capacity),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            app.mod_edit_event(id.clone(), secret.clone(), capacity(1))
+                .await
+                .unwrap_err(),
+            InternalError::PremiumOnlyFeature(_)
+        ));
+
+        //no capacity, counted right away
+        assert!(app.try_add_viewer(&id).await);
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        assert!(app
+            .mod_edit_event(
+                id.clone(),
+                secret.clone(),
+                capacity(VIEWER_CAPACITY_MAX + 1)
+            )
+            .await
+            .is_err());
+
+        let e = app
+            .mod_edit_event(id.clone(), secret.clone(), capacity(1))
+            .await
+            .unwrap();
+        assert_eq!(e.viewer_capacity, 1);
+
+        assert!(!app.try_add_viewer(&id).await);
+
+        //capacity is cached, changes by other instances show up later
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .viewer_capacity = 2;
+
+        assert!(!app.try_add_viewer(&id).await);
+
+        app.mod_edit_event(id.clone(), secret, capacity(2))
+            .await
+            .unwrap();
+
+        assert!(app.try_add_viewer(&id).await);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_polls() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let app = test_app_with(Arc::new(InMemoryEventsDB::default()), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let add = AddPoll {
+            question: String::from(" which one? "),
+            options: vec![String::from("a"), String::from("<b>b</b>")],
+        };
+
+        assert!(app
+            .mod_add_poll(id.clone(), String::from("wrong"), add.clone())
+            .await
+            .is_err());
+        assert!(app
+            .mod_add_poll(
+                id.clone(),
+                secret.clone(),
+                AddPoll {
+                    options: vec![String::from("a")],
+                    ..add.clone()
+                },
+            )
+            .await
+            .is_err());
+
+        let poll = app
+            .mod_add_poll(id.clone(), secret.clone(), add)
+            .await
+            .unwrap();
+
+        assert_eq!(poll.question, "which one?");
+        assert_eq!(poll.options[1].text, "b");
+
+        let vote = |option: usize, participant: &str| PollVote {
+            option,
+            participant: participant.to_string(),
+        };
+
+        let voted = app
+            .vote_poll(id.clone(), poll.id, vote(1, "p1"))
+            .await
+            .unwrap();
+        assert_eq!(voted.votes(), vec![0, 1]);
+
+        assert!(app
+            .vote_poll(id.clone(), poll.id, vote(2, "p2"))
+            .await
+            .is_err());
+
+        //Note: one vote per participant and poll
+        assert!(app
+            .vote_poll(id.clone(), poll.id, vote(0, "p1"))
+            .await
+            .is_err());
+        assert!(app
+            .vote_poll(id.clone(), poll.id, vote(0, ""))
+            .await
+            .is_err());
+
+        let closed = app
+            .mod_edit_poll(id.clone(), secret, poll.id, ModPoll { closed: true })
+            .await
+            .unwrap();
+        assert!(closed.closed);
+
+        assert!(app
+            .vote_poll(id.clone(), poll.id, vote(0, "p3"))
+            .await
+            .is_err());
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert_eq!(e.info.polls, vec![closed.clone()]);
+
+        let log = pubsubreceiver.log.read().await.clone();
+        assert_eq!(
+            log.into_iter().map(|(_, msg)| msg).collect::<Vec<_>>(),
+            vec![
+                WsMessage::Delta(EventDelta::PollChanged(poll.clone())).encode(),
+                WsMessage::Delta(EventDelta::PollVoted {
+                    id: poll.id,
+                    votes: vec![0, 1],
+                })
+                .encode(),
+                WsMessage::Delta(EventDelta::PollChanged(closed)).encode(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_speaker_queue() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let app = test_app_with(Arc::new(InMemoryEventsDB::default()), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        assert!(app
+            .raise_hand(
+                id.clone(),
+                RaiseHand {
+                    name: String::from(" "),
+                },
+            )
+            .await
+            .is_err());
+
+        let ada = app
+            .raise_hand(
+                id.clone(),
+                RaiseHand {
+                    name: String::from(" <b>Ada</b> "),
+                },
+            )
+            .await
+            .unwrap();
+        let bob = app
+            .raise_hand(
+                id.clone(),
+                RaiseHand {
+                    name: String::from("Bob"),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(app
+            .mod_edit_speakers(id.clone(), String::from("wrong"), ModSpeaker::Call(bob.id))
+            .await
+            .is_err());
+
+        let queue = app
+            .mod_edit_speakers(
+                id.clone(),
+                secret.clone(),
+                ModSpeaker::Move {
+                    id: bob.id,
+                    index: 0,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            queue
+                .speakers
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Bob", "Ada"]
+        );
+
+        let queue = app
+            .mod_edit_speakers(id.clone(), secret.clone(), ModSpeaker::Call(bob.id))
+            .await
+            .unwrap();
+        assert_eq!(queue.current, Some(bob.id));
+
+        let queue = app
+            .mod_edit_speakers(id.clone(), secret, ModSpeaker::Remove(bob.id))
+            .await
+            .unwrap();
+        assert_eq!(queue.current, None);
+
+        assert!(app
+            .lower_hand(
+                id.clone(),
+                RaisedHand {
+                    id: ada.id,
+                    token: bob.token,
+                },
+            )
+            .await
+            .is_err());
+
+        app.lower_hand(id.clone(), ada).await.unwrap();
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert!(e.info.speakers.speakers.is_empty());
+
+        let log = pubsubreceiver.log.read().await.clone();
+        assert_eq!(log.len(), 6);
+        assert_eq!(
+            log.last().map(|(_, msg)| msg.clone()),
+            Some(WsMessage::Delta(EventDelta::SpeakersChanged(SpeakerQueue::default())).encode())
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_raise_hand_deleted_event() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        app.delete_event(id.clone(), secret).await.unwrap();
+
+        assert!(matches!(
+            app.raise_hand(
+                id.clone(),
+                RaiseHand {
+                    name: String::from("Ada"),
+                },
+            )
+            .await
+            .unwrap_err(),
+            InternalError::AccessingDeletedEvent(_)
+        ));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_timer() {
+        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
+        let pubsub = PubSubInMemory::default();
+        pubsub.set_receiver(pubsubreceiver.clone()).await;
+        let app = test_app_with(Arc::new(InMemoryEventsDB::default()), Arc::new(pubsub));
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let start = ModTimer {
+            seconds: Some(300),
+            label: String::from(" left for <b>questions</b> "),
+        };
+
+        assert!(app
+            .mod_edit_timer(id.clone(), String::from("wrong"), start.clone())
+            .await
+            .is_err());
+        assert!(app
+            .mod_edit_timer(
+                id.clone(),
+                secret.clone(),
+                ModTimer {
+                    seconds: Some(0),
+                    ..start.clone()
+                },
+            )
+            .await
+            .is_err());
+
+        let timer = app
+            .mod_edit_timer(id.clone(), secret.clone(), start)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(timer.label, "left for questions");
+        assert!(timer.seconds_left(timestamp_now()) > 290);
+
+        let e = app
+            .get_event(id.clone(), None, false, None, None)
+            .await
+            .unwrap();
+        assert_eq!(e.info.timer, Some(timer.clone()));
+
+        let stopped = app
+            .mod_edit_timer(id.clone(), secret, ModTimer::default())
+            .await
+            .unwrap();
+        assert_eq!(stopped, None);
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert_eq!(e.info.timer, None);
+
+        let log = pubsubreceiver.log.read().await.clone();
+        assert_eq!(
+            log.into_iter().map(|(_, msg)| msg).collect::<Vec<_>>(),
+            vec![
+                WsMessage::Delta(EventDelta::TimerChanged(Some(timer))).encode(),
+                WsMessage::Delta(EventDelta::TimerChanged(None)).encode(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_word_cloud() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let cloud = app
+            .mod_add_word_cloud(
+                id.clone(),
+                secret.clone(),
+                AddWordCloud {
+                    prompt: String::from("one word for today"),
+                },
+            )
+            .await
+            .unwrap();
+
+        let word = |text: &str, participant: &str| AddWord {
+            text: text.to_string(),
+            participant: participant.to_string(),
+        };
+
+        app.add_word(id.clone(), cloud.id, word("Rust", "p1"))
+            .await
+            .unwrap();
+        let count = app
+            .add_word(id.clone(), cloud.id, word("  rust ", "p2"))
+            .await
+            .unwrap();
+        assert_eq!(count.count, 2);
+
+        app.add_word(id.clone(), cloud.id, word("fast   code", "p3"))
+            .await
+            .unwrap();
+
+        assert!(app
+            .add_word(id.clone(), cloud.id, word("again", "p1"))
+            .await
+            .is_err());
+        assert!(app
+            .add_word(id.clone(), cloud.id, word("anonymous", " "))
+            .await
+            .is_err());
+
+        assert!(app
+            .add_word(id.clone(), cloud.id, word("too many words", "p4"))
+            .await
+            .is_err());
+        assert!(app
+            .add_word(id.clone(), cloud.id, word(" ", "p4"))
+            .await
+            .is_err());
+
+        app.mod_edit_word_cloud(id.clone(), secret, cloud.id, ModWordCloud { closed: true })
+            .await
+            .unwrap();
+
+        assert!(app
+            .add_word(id.clone(), cloud.id, word("late", "p4"))
+            .await
+            .is_err());
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        let cloud = &e.info.word_clouds[0];
+        assert!(cloud.closed);
+        assert_eq!(
+            cloud.words,
+            vec![
+                WordCount {
+                    text: String::from("rust"),
+                    count: 2,
+                },
+                WordCount {
+                    text: String::from("fast code"),
+                    count: 1,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_word_cloud_deleted_event() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let cloud = app
+            .mod_add_word_cloud(
+                id.clone(),
+                secret.clone(),
+                AddWordCloud {
+                    prompt: String::from("one word for today"),
+                },
+            )
+            .await
+            .unwrap();
+
+        app.delete_event(id.clone(), secret).await.unwrap();
+
+        assert!(matches!(
+            app.add_word(
+                id.clone(),
+                cloud.id,
+                AddWord {
+                    text: String::from("late"),
+                    participant: String::from("p1"),
+                },
+            )
+            .await
+            .unwrap_err(),
+            InternalError::AccessingDeletedEvent(_)
+        ));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_survey() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let response = |participant: &str, rating: u8, comment: Option<&str>| AddSurveyResponse {
+            rating,
+            comment: comment.map(ToString::to_string),
+            participant: participant.to_string(),
+        };
+
+        assert!(app
+            .add_survey_response(id.clone(), response("p1", 5, None))
+            .await
+            .is_err());
+
+        app.mod_edit_event(
+            id.clone(),
+            secret.clone(),
+            ModEvent {
+                survey: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(app
+            .add_survey_response(id.clone(), response("p1", 5, None))
+            .await
+            .is_err());
+
+        app.mod_edit_event(
+            id.clone(),
+            secret.clone(),
+            ModEvent {
+                state: Some(EventState {
+                    state: States::Closed,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        app.add_survey_response(id.clone(), response("p1", 5, None))
+            .await
+            .unwrap();
+        app.add_survey_response(id.clone(), response("p2", 4, Some(" <b>nice</b> ")))
+            .await
+            .unwrap();
+        app.add_survey_response(id.clone(), response("p3", 4, Some("  ")))
+            .await
+            .unwrap();
+
+        assert!(app
+            .add_survey_response(id.clone(), response("p1", 3, None))
+            .await
+            .is_err());
+        assert!(app
+            .add_survey_response(id.clone(), response(" ", 3, None))
+            .await
+            .is_err());
+
+        assert!(app
+            .add_survey_response(id.clone(), response("p4", 0, None))
+            .await
+            .is_err());
+        assert!(app
+            .add_survey_response(id.clone(), response("p4", 6, None))
+            .await
+            .is_err());
+
+        assert!(app
+            .mod_survey_results(id.clone(), String::from("wrong"))
+            .await
+            .is_err());
+
+        let results = app.mod_survey_results(id, secret).await.unwrap();
+        assert_eq!(results.ratings, vec![0, 0, 0, 2, 1]);
+        assert_eq!(results.count(), 3);
+        assert_eq!(results.comments.len(), 1);
+        assert_eq!(results.comments[0].comment.as_deref(), Some("nice"));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_sessions() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let keynote = app
+            .mod_add_session(
+                id.clone(),
+                secret.clone(),
+                AddSession {
+                    name: String::from("Keynote"),
+                },
+            )
+            .await
+            .unwrap();
+        let panel = app
+            .mod_add_session(
+                id.clone(),
+                secret.clone(),
+                AddSession {
+                    name: String::from("Panel 2"),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(app
+            .mod_add_session(
+                id.clone(),
+                secret.clone(),
+                AddSession {
+                    name: String::from(" "),
+                },
+            )
+            .await
+            .is_err());
+
+        let question = |text: &str, session: Option<i64>| AddQuestion {
+            text: format!("{TEST_VALID_QUESTION} {text}"),
+            name: None,
+            attachment: None,
+            session,
+            participant: None,
+        };
+
+        let q = app
+            .add_question(id.clone(), question("keynote", Some(keynote.id)))
+            .await
+            .unwrap();
+        assert_eq!(q.session, Some(keynote.id));
+
+        assert!(app
+            .add_question(id.clone(), question("unknown", Some(42)))
+            .await
+            .is_err());
+
+        app.mod_edit_session(
+            id.clone(),
+            secret,
+            keynote.id,
+            ModSession {
+                state: EventState {
+                    state: States::Closed,
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(app
+            .add_question(id.clone(), question("closed", Some(keynote.id)))
+            .await
+            .is_err());
+        assert!(app
+            .edit_like(
+                id.clone(),
+                EditLike {
+                    question_id: q.id,
+                    like: true,
+                    participant: None,
+                },
+                None,
+            )
+            .await
+            .is_err());
+
+        app.add_question(id.clone(), question("panel", Some(panel.id)))
+            .await
+            .unwrap();
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert_eq!(e.info.sessions.len(), 2);
+        assert!(e.info.sessions[0].state.is_closed());
+        assert!(e.info.sessions[1].state.is_open());
+        assert_eq!(e.info.questions.len(), 2);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_schedule() {
+        let app = test_app();
+
+        let now = timestamp_now();
+
+        let event = |start_unix: Option<i64>, end_unix: Option<i64>| AddEvent {
+            data: EventData {
+                name: TEST_EVENT_NAME.to_string(),
+                description: TEST_EVENT_DESC.to_string(),
+                short_url: String::new(),
+                long_url: None,
+                start_unix,
+                end_unix,
+                timezone: None,
+                branding: None,
+            },
+            moderator_email: None,
+            subscription: None,
+            test: false,
+        };
+
+        let question = || AddQuestion {
+            text: String::from(TEST_VALID_QUESTION),
+            name: None,
+            attachment: None,
+            session: None,
+            participant: None,
+        };
+
+        assert!(app
+            .create_event(event(Some(now + 60), Some(now)))
+            .await
+            .is_err());
+
+        let upcoming = app.create_event(event(Some(now + 60), None)).await.unwrap();
+        assert!(app
+            .add_question(upcoming.tokens.public_token, question())
+            .await
+            .is_err());
+
+        let ended = app.create_event(event(None, Some(now - 60))).await.unwrap();
+        assert!(app
+            .add_question(ended.tokens.public_token, question())
+            .await
+            .is_err());
+
+        let running = app
+            .create_event(event(Some(now - 60), Some(now + 60)))
+            .await
+            .unwrap();
+        assert_eq!(running.data.start_unix, Some(now - 60));
+        app.add_question(running.tokens.public_token, question())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_timezone() {
+        let app = test_app();
+
+        let event = |timezone: &str| AddEvent {
+            data: EventData {
+                name: TEST_EVENT_NAME.to_string(),
+                description: TEST_EVENT_DESC.to_string(),
+                short_url: String::new(),
+                long_url: None,
+                start_unix: None,
+                end_unix: None,
+                timezone: Some(timezone.to_string()),
+                branding: None,
+            },
+            moderator_email: None,
+            subscription: None,
+            test: false,
+        };
+
+        assert!(app
+            .create_event(event(&"x".repeat(shared::EVENT_TIMEZONE_MAX_LENGTH + 1)))
+            .await
+            .is_err());
+
+        let res = app.create_event(event("Europe/Berlin")).await.unwrap();
+        let e = app
+            .get_event(res.tokens.public_token, None, false, None, None)
+            .await
+            .unwrap();
+        assert_eq!(e.info.data.timezone.as_deref(), Some("Europe/Berlin"));
+    }
+
+    #[test]
+    fn test_schedule_retry_backoff() {
+        assert_eq!(schedule_retry_secs(1), SCHEDULE_RETRY_BASE_SECS);
+        assert_eq!(schedule_retry_secs(2), SCHEDULE_RETRY_BASE_SECS * 2);
+        assert_eq!(schedule_retry_secs(4), SCHEDULE_RETRY_BASE_SECS * 8);
+        assert_eq!(schedule_retry_secs(20), SCHEDULE_RETRY_MAX_SECS);
+        assert_eq!(schedule_retry_secs(u32::MAX), SCHEDULE_RETRY_MAX_SECS);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_scheduled_state() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let schedule = InMemorySchedule::default();
+        let app = App {
+            schedule: Arc::new(schedule.clone()),
+            ..test_app_with(events.clone(), Arc::new(PubSubInMemory::default()))
+        };
+
+        let now = timestamp_now();
+
+        let res = app
+            .create_event(AddEvent {
+                data: EventData {
+                    name: TEST_EVENT_NAME.to_string(),
+                    description: TEST_EVENT_DESC.to_string(),
+                    short_url: String::new(),
+                    long_url: None,
+                    start_unix: Some(now + 60),
+                    end_unix: Some(now + 120),
+                    timezone: None,
+                    branding: None,
+                },
+                moderator_email: None,
+                subscription: None,
+                test: false,
+            })
+            .await
+            .unwrap();
+        let id = res.tokens.public_token.clone();
+
+        assert!(res.state.is_closed());
+        assert_eq!(schedule.events.read().await.get(&id), Some(&(now + 60)));
+
+        //Note: nothing due yet
+        app.run_schedule().await;
+        assert_eq!(schedule.events.read().await.get(&id), Some(&(now + 60)));
+
+        let set_schedule = |start: i64, end: i64| {
+            let events = events.clone();
+            let key = event_key(&id);
+            async move {
+                let mut db = events.db.lock().await;
+                let data = &mut db.get_mut(&key).unwrap().event.data;
+                data.start_unix = Some(start);
+                data.end_unix = Some(end);
+            }
+        };
+
+        set_schedule(now - 1, now + 120).await;
+        schedule.add(&id, now).await;
+        app.run_schedule().await;
+
+        let e = app
+            .get_event(id.clone(), None, false, None, None)
+            .await
+            .unwrap();
+        assert!(e.info.state.is_open());
+        assert_eq!(schedule.events.read().await.get(&id), Some(&(now + 120)));
+
+        set_schedule(now - 2, now - 1).await;
+        schedule.add(&id, now).await;
+        app.run_schedule().await;
+
+        let e = app
+            .get_event(id.clone(), None, false, None, None)
+            .await
+            .unwrap();
+        assert!(e.info.state.is_closed());
+        assert!(schedule.events.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_duplicate_event() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+
+        app.mod_edit_event(
+            id.clone(),
+            secret.clone(),
+            ModEvent {
+                downvotes: Some(true),
+                question_names: Some(QuestionNames::Required),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        app.mod_add_session(
+            id.clone(),
+            secret.clone(),
+            AddSession {
+                name: String::from("Keynote"),
+            },
+        )
+        .await
+        .unwrap();
+        app.add_question(
+            id.clone(),
+            AddQuestion {
+                text: String::from(TEST_VALID_QUESTION),
+                name: Some(String::from("alice")),
+                attachment: None,
+                session: None,
+                participant: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(app
+            .mod_duplicate_event(id.clone(), String::from("wrong"))
+            .await
+            .is_err());
+
+        let copy = app.mod_duplicate_event(id.clone(), secret).await.unwrap();
+
+        assert_ne!(copy.tokens, res.tokens);
+        assert_eq!(copy.data.name, TEST_EVENT_NAME);
+        assert_eq!(copy.data.description, TEST_EVENT_DESC);
+        assert!(copy.downvotes_enabled());
+        assert_eq!(copy.question_names, QuestionNames::Required);
+        assert_eq!(copy.sessions.len(), 1);
+        assert!(copy.questions.is_empty());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_series() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let schedule = InMemorySchedule::default();
+        let app = App {
+            schedule: Arc::new(schedule.clone()),
+            ..test_app_with(events.clone(), Arc::new(PubSubInMemory::default()))
+        };
+
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.clone().unwrap();
+        let weekly = EditSeries { interval_days: 7 };
+        let week = 7 * 24 * 60 * 60;
+
+        assert!(app
+            .mod_edit_series(id.clone(), secret.clone(), weekly)
+            .await
+            .is_err());
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        let series = app
+            .mod_edit_series(id.clone(), secret.clone(), weekly)
+            .await
+            .unwrap();
+        assert_eq!(series.id, id);
+        assert_eq!(series.occurrences.len(), 1);
+        assert_eq!(
+            schedule
+                .events
+                .read()
+                .await
+                .get(&series_schedule_key(&id))
+                .copied(),
+            Some(res.create_time_unix + week)
+        );
+
+        //Note: pretend the first occurrence was a week ago
+        let now = timestamp_now();
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .series
+            .as_mut()
+            .unwrap()
+            .occurrences[0]
+            .start_unix = now - week - 10;
+        schedule.add(&series_schedule_key(&id), now).await;
+
+        app.run_schedule().await;
+
+        let current = app.series_current(id.clone()).await.unwrap().public_token;
+        assert_ne!(current, id);
+
+        let archive = app
+            .mod_series_archive(current.clone(), secret.clone())
+            .await
+            .unwrap();
+        assert_eq!(archive.occurrences.len(), 2);
+        assert_eq!(archive.occurrences[1].start_unix, now - 10);
+
+        let e = app
+            .get_event(current.clone(), Some(secret.clone()), false, None, None)
+            .await
+            .unwrap();
+        assert!(e.info.is_premium());
+        assert!(e.info.questions.is_empty());
+        assert_eq!(e.info.series.map(|s| s.id), Some(id.clone()));
+        assert_eq!(
+            schedule
+                .events
+                .read()
+                .await
+                .get(&series_schedule_key(&id))
+                .copied(),
+            Some(now - 10 + week)
+        );
+
+        app.mod_edit_series(current, secret, EditSeries { interval_days: 0 })
+            .await
+            .unwrap();
+        schedule.add(&series_schedule_key(&id), now).await;
+        app.run_schedule().await;
+
+        assert_eq!(
+            app.mod_series_archive(id.clone(), res.tokens.moderator_token.unwrap())
+                .await
+                .unwrap()
+                .occurrences
+                .len(),
+            2
+        );
     }
-}
 
-#[async_trait]
-impl PubSubReceiver for App {
-    async fn notify(&self, topic: &str, payload: &str) {
-        let topic = topic.to_string();
-        let msg = Message::Text(payload.to_string());
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_archived() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let question = || AddQuestion {
+            text: String::from(TEST_VALID_QUESTION),
+            name: None,
+            attachment: None,
+            session: None,
+            participant: None,
+        };
 
-        let channels = Arc::clone(&self.channels);
+        let q = app.add_question(id.clone(), question()).await.unwrap();
 
-        if let Err(e) = tokio::spawn(async move {
-            //TODO: lookup subscriber based on topic name
-            let receivers = channels.read().await.clone();
-            for (_user_id, (_id, c)) in receivers.iter().filter(|(_, (id, _))| id == &topic) {
-                if let Err(e) = c.send(Ok(msg.clone())) {
-                    if let Err(inner_err) = &e.0 {
-                        tracing::error!("pubsub send err: {} ({})", e, inner_err);
-                    } else {
-                        tracing::info!("pubsub not sent: {}", e);
-                    }
-                }
-            }
-        })
+        app.mod_edit_event(
+            id.clone(),
+            secret,
+            ModEvent {
+                state: Some(EventState {
+                    state: States::Archived,
+                }),
+                ..Default::default()
+            },
+        )
         .await
-        {
-            tracing::error!("pubsub notify error: {e}");
-        }
-    }
-}
+        .unwrap();
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::{
-        eventsdb::{event_key, InMemoryEventsDB, PremiumOrder},
-        pubsub::{PubSubInMemory, PubSubReceiverInMemory},
-        viewers::MockViewers,
-    };
-    use pretty_assertions::{assert_eq, assert_ne};
-    use shared::{
-        AddQuestion, CurrentTag, EventData, TagId, TEST_EVENT_DESC, TEST_EVENT_NAME,
-        TEST_VALID_QUESTION,
-    };
-    use std::sync::Arc;
+        assert!(app.add_question(id.clone(), question()).await.is_err());
+        assert!(app
+            .edit_like(
+                id.clone(),
+                EditLike {
+                    question_id: q.id,
+                    like: true,
+                    participant: None,
+                },
+                None,
+            )
+            .await
+            .is_err());
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert!(e.info.state.is_archived());
+        assert_eq!(e.info.questions.len(), 1);
+    }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_event_create_fail_validation() {
-        let app = App::new(
-            Arc::new(InMemoryEventsDB::default()),
-            Arc::new(PubSubInMemory::default()),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
+    async fn test_slug() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = App {
+            base_url: String::from("https://www.live-ask.com"),
+            ..test_app_with(events.clone(), Arc::new(PubSubInMemory::default()))
+        };
+
+        let mut tokens = Vec::new();
+        for _ in 0..2 {
+            let res = create_test_event(&app).await;
+            tokens.push(res.tokens);
+        }
+
+        let slug = |slug: &str| EditSlug {
+            slug: slug.to_string(),
+        };
+        let (a, b) = (&tokens[0], &tokens[1]);
+        let (a_id, a_secret) = (a.public_token.clone(), a.moderator_token.clone().unwrap());
+        let (b_id, b_secret) = (b.public_token.clone(), b.moderator_token.clone().unwrap());
+
+        assert!(app
+            .mod_edit_slug(a_id.clone(), a_secret.clone(), slug("rustconf-keynote"))
+            .await
+            .is_err());
+
+        for id in [&a_id, &b_id] {
+            events
+                .db
+                .lock()
+                .await
+                .get_mut(&event_key(id))
+                .unwrap()
+                .event
+                .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+        }
+
+        assert!(app
+            .mod_edit_slug(a_id.clone(), a_secret.clone(), slug("Rustconf Keynote"))
+            .await
+            .is_err());
+
+        let e = app
+            .mod_edit_slug(a_id.clone(), a_secret.clone(), slug("rustconf-keynote"))
+            .await
+            .unwrap();
+        assert_eq!(
+            e.data.short_url,
+            "https://www.live-ask.com/e/rustconf-keynote"
         );
+        assert_eq!(e.slug.as_deref(), Some("rustconf-keynote"));
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: String::from("too short"),
-                    description: String::new(),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: None,
-                test: false,
-            })
-            .await;
+        assert!(matches!(
+            app.mod_edit_slug(b_id.clone(), b_secret, slug("rustconf-keynote"))
+                .await,
+            Err(InternalError::SlugTaken(_))
+        ));
 
-        assert!(res.is_err());
+        app.mod_edit_slug(a_id.clone(), a_secret, slug("rustconf-keynote"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            app.slug_target(String::from("rustconf-keynote"))
+                .await
+                .unwrap()
+                .public_token,
+            a_id
+        );
+        assert!(app.slug_target(String::from("unknown")).await.is_err());
     }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_event_create_email_fail_validation() {
-        let app = App::new(
-            Arc::new(InMemoryEventsDB::default()),
-            Arc::new(PubSubInMemory::default()),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
-        );
+    async fn test_branding() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: TEST_EVENT_NAME.to_string(),
-                    description: TEST_EVENT_DESC.to_string(),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: Option::Some("a@a".to_string()),
-                test: false,
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let edit = |branding: EventBranding| ModEvent {
+            branding: Some(branding),
+            ..Default::default()
+        };
+        let branding = EventBranding {
+            color: Some(String::from("#123abc")),
+            banner: Some(String::from("<b>Welcome</b>")),
+            logo_url: Some(String::from("https://example.com/logo.png")),
+            logo: None,
+        };
+
+        assert!(app
+            .mod_edit_event(id.clone(), secret.clone(), edit(branding.clone()))
+            .await
+            .is_err());
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        let e = app
+            .mod_edit_event(id.clone(), secret.clone(), edit(branding.clone()))
+            .await
+            .unwrap();
+        assert_eq!(
+            e.data.branding,
+            Some(EventBranding {
+                banner: Some(String::from("Welcome")),
+                ..branding.clone()
             })
-            .await;
+        );
 
-        assert!(res.is_err());
+        assert!(app
+            .mod_edit_event(
+                id.clone(),
+                secret.clone(),
+                edit(EventBranding {
+                    color: Some(String::from("red")),
+                    ..branding
+                })
+            )
+            .await
+            .is_err());
+
+        let e = app
+            .mod_edit_event(id, secret, edit(EventBranding::default()))
+            .await
+            .unwrap();
+        assert_eq!(e.data.branding, None);
     }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_event_create_email_pass_validation() {
-        let app = App::new(
-            Arc::new(InMemoryEventsDB::default()),
-            Arc::new(PubSubInMemory::default()),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
-        );
+    async fn test_theme() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: TEST_EVENT_NAME.to_string(),
-                    description: TEST_EVENT_DESC.to_string(),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: Option::Some("testuser@live-ask.com".to_string()),
-                test: false,
-            })
-            .await;
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
+        assert_eq!(res.theme, EventTheme::Auto);
+
+        let edit = |theme| ModEvent {
+            theme: Some(theme),
+            ..Default::default()
+        };
+
+        let e = app
+            .mod_edit_event(id.clone(), secret.clone(), edit(EventTheme::Dark))
+            .await
+            .unwrap();
+        assert_eq!(e.theme, EventTheme::Dark);
+
+        assert!(app
+            .mod_edit_event(id.clone(), secret.clone(), edit(EventTheme::Custom))
+            .await
+            .is_err());
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        let e = app
+            .mod_edit_event(id, secret, edit(EventTheme::Custom))
+            .await
+            .unwrap();
+        assert_eq!(e.theme, EventTheme::Custom);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_hide_branding() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let edit = ModEvent {
+            hide_branding: Some(true),
+            ..Default::default()
+        };
+
+        assert!(app
+            .mod_edit_event(id.clone(), secret.clone(), edit.clone())
+            .await
+            .is_err());
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        let e = app.mod_edit_event(id.clone(), secret, edit).await.unwrap();
+        assert!(e.branding_hidden());
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = None;
+
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert!(!e.info.branding_hidden());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_webhook_failed() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        assert!(app
+            .webhook_received("evt_1", String::from("{}"))
+            .await
+            .unwrap());
+
+        app.webhook_failed("evt_1").await;
+
+        //Note: the retry of a failed delivery is processed again
+        assert!(app
+            .webhook_received("evt_1", String::from("{}"))
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_logo() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let request = |content_type: &str, size| AttachmentRequest {
+            content_type: content_type.to_string(),
+            size,
+        };
+
+        assert!(matches!(
+            app.mod_request_logo(id.clone(), secret.clone(), request("image/png", 1_000))
+                .await,
+            Err(InternalError::PremiumOnlyFeature(_))
+        ));
+
+        events
+            .db
+            .lock()
+            .await
+            .get_mut(&event_key(&id))
+            .unwrap()
+            .event
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+
+        assert!(matches!(
+            app.mod_request_logo(
+                id.clone(),
+                String::from("wrong"),
+                request("image/png", 1_000)
+            )
+            .await,
+            Err(InternalError::WrongModeratorToken(_))
+        ));
+        assert!(app
+            .mod_request_logo(id.clone(), secret.clone(), request("image/gif", 1_000))
+            .await
+            .is_err());
+        assert!(app
+            .mod_request_logo(
+                id.clone(),
+                secret.clone(),
+                request("image/png", shared::LOGO_MAX_SIZE + 1)
+            )
+            .await
+            .is_err());
+
+        let edit = |logo: String| ModEvent {
+            branding: Some(EventBranding {
+                logo: Some(logo),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert!(app
+            .mod_edit_event(
+                id.clone(),
+                secret.clone(),
+                edit(shared::logo_key("other", "file"))
+            )
+            .await
+            .is_err());
+
+        let e = app
+            .mod_edit_event(id.clone(), secret, edit(shared::logo_key(&id, "file")))
+            .await
+            .unwrap();
+        assert_eq!(
+            e.data.branding.and_then(|branding| branding.logo),
+            Some(shared::logo_key(&id, "file"))
+        );
 
-        assert!(res.is_ok());
+        assert!(app.logo_url(id, String::from("unknown")).await.is_err());
     }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_event_create() {
-        let eventdb = Arc::new(InMemoryEventsDB::default());
-        let app = App::new(
-            eventdb.clone(),
-            Arc::new(PubSubInMemory::default()),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
-        );
+    async fn test_subscription() {
+        let app = test_app();
 
-        app.create_event(AddEvent {
+        let create = |subscription: Option<&str>| AddEvent {
             data: EventData {
-                name: String::from("123456789"),
-                description: String::from("123456789 123456789 123456789 !"),
+                name: TEST_EVENT_NAME.to_string(),
+                description: TEST_EVENT_DESC.to_string(),
                 short_url: String::new(),
                 long_url: None,
+                start_unix: None,
+                end_unix: None,
+                timezone: None,
+                branding: None,
             },
             moderator_email: None,
+            subscription: subscription.map(String::from),
             test: false,
-        })
-        .await
-        .unwrap();
-
-        assert_eq!(eventdb.db.lock().await.len(), 1);
-    }
+        };
 
-    #[tokio::test]
-    #[tracing_test::traced_test]
-    async fn test_event_mod_question_notification() {
-        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
-        let pubsub = PubSubInMemory::default();
-        pubsub.set_receiver(pubsubreceiver.clone()).await;
-        let app = App::new(
-            Arc::new(InMemoryEventsDB::default()),
-            Arc::new(pubsub),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
+        assert!(
+            !app.subscription_state(String::from("account"))
+                .await
+                .unwrap()
+                .active
         );
+        assert!(!app
+            .create_event(create(Some("account")))
+            .await
+            .unwrap()
+            .is_premium());
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: String::from("123456789"),
-                    description: String::from("123456789 123456789 123456789 !"),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: None,
-                test: false,
-            })
+        app.subscription_webhook(String::from("account"), String::from("sub_1"), true, 1)
             .await
             .unwrap();
 
-        let q = app
-            .add_question(
-                res.tokens.public_token.clone(),
-                AddQuestion {
-                    text: String::from(TEST_VALID_QUESTION),
-                },
-            )
+        assert!(
+            app.subscription_state(String::from("account"))
+                .await
+                .unwrap()
+                .active
+        );
+        assert!(app
+            .create_event(create(Some("account")))
+            .await
+            .unwrap()
+            .is_premium());
+        assert!(!app.create_event(create(None)).await.unwrap().is_premium());
+        assert!(!app
+            .create_event(create(Some("other")))
+            .await
+            .unwrap()
+            .is_premium());
+
+        app.subscription_webhook(String::from("account"), String::from("sub_1"), false, 2)
             .await
             .unwrap();
 
-        app.mod_edit_question(
-            res.tokens.public_token.clone(),
-            res.tokens.moderator_token.unwrap(),
-            q.id,
-            ModQuestion {
-                hide: true,
-                answered: false,
-                screened: true,
-            },
-        )
-        .await
-        .unwrap();
+        assert!(!app
+            .create_event(create(Some("account")))
+            .await
+            .unwrap()
+            .is_premium());
 
-        assert_eq!(
-            pubsubreceiver.log.read().await[0].clone(),
-            (res.tokens.public_token.clone(), format!("q:{}", q.id))
-        );
+        //Note: delivered late, older than the deactivation
+        app.subscription_webhook(String::from("account"), String::from("sub_1"), true, 1)
+            .await
+            .unwrap();
 
-        assert_eq!(
-            pubsubreceiver.log.read().await[1].clone(),
-            (res.tokens.public_token.clone(), format!("q:{}", q.id))
+        assert!(
+            !app.subscription_state(String::from("account"))
+                .await
+                .unwrap()
+                .active
         );
     }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_screening_question() {
-        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
-        let pubsub = PubSubInMemory::default();
-        pubsub.set_receiver(pubsubreceiver.clone()).await;
+    async fn test_receipt() {
         let events = Arc::new(InMemoryEventsDB::default());
-        let app = App::new(
-            events.clone(),
-            Arc::new(pubsub),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
-        );
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: String::from("123456789"),
-                    description: String::from("123456789 123456789 123456789 !"),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: None,
-                test: false,
-            })
-            .await
-            .unwrap();
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let details = ReceiptDetails {
+            name: Some(String::from(" Live-Ask GmbH ")),
+            address: Some(String::new()),
+            vat_id: Some(String::from("DE123")),
+        };
+
+        assert!(matches!(
+            app.mod_edit_receipt(id.clone(), secret.clone(), details.clone())
+                .await,
+            Err(InternalError::PremiumOnlyFeature(_))
+        ));
 
         events
             .db
             .lock()
             .await
-            .get_mut(&event_key(&res.tokens.public_token))
+            .get_mut(&event_key(&id))
             .unwrap()
             .event
-            .do_screening = true;
+            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
 
-        let q = app
-            .add_question(
-                res.tokens.public_token.clone(),
-                AddQuestion {
-                    text: String::from(TEST_VALID_QUESTION),
-                },
+        assert_eq!(
+            app.mod_edit_receipt(id.clone(), secret.clone(), details.clone())
+                .await
+                .unwrap(),
+            ReceiptDetails {
+                name: Some(String::from("Live-Ask GmbH")),
+                address: None,
+                vat_id: Some(String::from("DE123")),
+            }
+        );
+
+        assert!(app
+            .mod_edit_receipt(
+                id.clone(),
+                secret.clone(),
+                ReceiptDetails {
+                    name: Some("a".repeat(shared::RECEIPT_FIELD_MAX_LENGTH + 1)),
+                    ..details
+                }
             )
             .await
-            .unwrap();
+            .is_err());
 
-        assert_eq!(q.screening, true);
+        assert!(app.mod_receipt(id, secret).await.is_err());
+    }
 
-        let e = app
-            .get_event(res.tokens.public_token.clone(), None, false, None)
-            .await
-            .unwrap();
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_web_push_subscriptions() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
 
-        assert_eq!(e.info.questions.len(), 0);
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
 
-        let e = app
-            .get_event(
-                res.tokens.public_token.clone(),
-                Some(res.tokens.moderator_token.clone().unwrap()),
-                false,
-                None,
+        let subscription = |n: usize| WebPushSubscription {
+            endpoint: format!("https://fcm.googleapis.com/fcm/send/{n}"),
+            keys: shared::WebPushKeys {
+                p256dh: String::from("p256dh"),
+                auth: String::from("auth"),
+            },
+        };
+
+        assert!(matches!(
+            app.mod_push_subscribe(id.clone(), String::from("wrong"), subscription(0))
+                .await,
+            Err(InternalError::WrongModeratorToken(_))
+        ));
+
+        for endpoint in [
+            "http://fcm.googleapis.com/fcm/send/0",
+            "https://push.example.com/0",
+            "https://169.254.169.254/latest/meta-data",
+            "https://127.0.0.1/0",
+            "https://[::1]/0",
+            "https://fcm.googleapis.com:8443/fcm/send/0",
+            "https://fcm.googleapis.com.example.com/0",
+            "https://evilpush.apple.com/0",
+        ] {
+            assert!(app
+                .mod_push_subscribe(
+                    id.clone(),
+                    secret.clone(),
+                    WebPushSubscription {
+                        endpoint: String::from(endpoint),
+                        ..subscription(0)
+                    }
+                )
+                .await
+                .is_err());
+        }
+
+        for endpoint in [
+            "https://updates.push.services.mozilla.com/wpush/v2/0",
+            "https://web.push.apple.com/0",
+            "https://wns2-par02p.notify.windows.com/w/?token=0",
+        ] {
+            app.mod_push_subscribe(
+                id.clone(),
+                secret.clone(),
+                WebPushSubscription {
+                    endpoint: String::from(endpoint),
+                    ..subscription(0)
+                },
             )
             .await
             .unwrap();
+        }
 
-        assert_eq!(e.info.questions.len(), 1);
+        for n in 0..=WEB_PUSH_MAX_SUBSCRIPTIONS {
+            app.mod_push_subscribe(id.clone(), secret.clone(), subscription(n))
+                .await
+                .unwrap();
+        }
+        app.mod_push_subscribe(id.clone(), secret.clone(), subscription(1))
+            .await
+            .unwrap();
 
-        app.mod_edit_question(
-            res.tokens.public_token.clone(),
-            res.tokens.moderator_token.clone().unwrap(),
-            q.id,
-            ModQuestion {
-                hide: false,
-                answered: false,
-                screened: true,
+        let subscriptions = events
+            .db
+            .lock()
+            .await
+            .get(&event_key(&id))
+            .unwrap()
+            .event
+            .push_subscriptions
+            .clone();
+        assert_eq!(subscriptions.len(), WEB_PUSH_MAX_SUBSCRIPTIONS);
+        assert_eq!(subscriptions[0], subscription(2));
+        assert_eq!(subscriptions.last(), Some(&subscription(1)));
+
+        app.mod_push_unsubscribe(
+            id.clone(),
+            secret,
+            WebPushUnsubscribe {
+                endpoint: subscription(1).endpoint,
             },
         )
         .await
         .unwrap();
 
-        let e = app
-            .get_event(res.tokens.public_token.clone(), None, false, None)
+        let subscriptions = events
+            .db
+            .lock()
             .await
-            .unwrap();
+            .get(&event_key(&id))
+            .unwrap()
+            .event
+            .push_subscriptions
+            .clone();
+        assert_eq!(subscriptions.len(), WEB_PUSH_MAX_SUBSCRIPTIONS - 1);
+        assert!(!subscriptions.contains(&subscription(1)));
 
-        assert_eq!(e.info.questions.len(), 1);
+        assert!(matches!(
+            app.web_push_key(),
+            Err(InternalError::WebPush(WebPushError::NotConfigured))
+        ));
     }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_screening_question_disapprove() {
-        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
-        let pubsub = PubSubInMemory::default();
-        pubsub.set_receiver(pubsubreceiver.clone()).await;
-        let events = Arc::new(InMemoryEventsDB::default());
-        let app = App::new(
-            events.clone(),
-            Arc::new(pubsub),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
-        );
+    async fn test_notification_settings() {
+        let app = test_app();
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: String::from("123456789"),
-                    description: String::from("123456789 123456789 123456789 !"),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: None,
-                test: false,
-            })
-            .await
-            .unwrap();
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
 
-        events
-            .db
-            .lock()
-            .await
-            .get_mut(&event_key(&res.tokens.public_token))
-            .unwrap()
-            .event
-            .do_screening = true;
+        let settings = |notifications: NotificationSettings| ModEvent {
+            notifications: Some(notifications),
+            ..Default::default()
+        };
 
-        let q = app
-            .add_question(
-                res.tokens.public_token.clone(),
-                AddQuestion {
-                    text: String::from(TEST_VALID_QUESTION),
-                },
+        assert!(app
+            .mod_edit_event(
+                id.clone(),
+                secret.clone(),
+                settings(NotificationSettings {
+                    email: Some(String::from("no-mail")),
+                    ..Default::default()
+                })
             )
             .await
-            .unwrap();
-
-        assert_eq!(q.screening, true);
+            .is_err());
 
-        let e = app
-            .get_event(res.tokens.public_token.clone(), None, false, None)
+        assert!(app
+            .mod_edit_event(
+                id.clone(),
+                secret.clone(),
+                settings(NotificationSettings {
+                    likes_threshold: -1,
+                    ..Default::default()
+                })
+            )
             .await
-            .unwrap();
+            .is_err());
 
-        assert_eq!(e.info.questions.len(), 0);
+        app.mod_edit_event(
+            id.clone(),
+            secret.clone(),
+            settings(NotificationSettings {
+                email: Some(String::from(" mod@live-ask.com ")),
+                push: false,
+                likes_threshold: 0,
+            }),
+        )
+        .await
+        .unwrap();
 
         let e = app
-            .mod_edit_question(
-                res.tokens.public_token.clone(),
-                res.tokens.moderator_token.clone().unwrap(),
-                q.id,
-                ModQuestion {
-                    hide: true,
-                    answered: false,
-                    screened: false,
-                },
-            )
+            .get_event(id.clone(), Some(secret), false, None, None)
             .await
             .unwrap();
+        assert_eq!(
+            e.mod_info.unwrap().notifications,
+            NotificationSettings {
+                email: Some(String::from("mod@live-ask.com")),
+                push: false,
+                likes_threshold: 0,
+            }
+        );
 
-        assert_eq!(e.questions[0].screening, false);
+        let e = app.get_event(id, None, false, None, None).await.unwrap();
+        assert!(e.mod_info.is_none());
     }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_screening_enable() {
-        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
-        let pubsub = PubSubInMemory::default();
-        pubsub.set_receiver(pubsubreceiver.clone()).await;
+    async fn test_webhook_received() {
         let events = Arc::new(InMemoryEventsDB::default());
-        let app = App::new(
-            events.clone(),
-            Arc::new(pubsub),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
-        );
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        assert!(app
+            .webhook_received("evt_1", String::from("{}"))
+            .await
+            .unwrap());
+        assert!(!app
+            .webhook_received("evt_1", String::from("{}"))
+            .await
+            .unwrap());
+        assert!(app
+            .webhook_received("evt_2", String::from("{}"))
+            .await
+            .unwrap());
+
+        assert_eq!(events.webhooks.lock().await.len(), 2);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_premium_trial() {
+        let events = Arc::new(InMemoryEventsDB::default());
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: String::from("123456789"),
-                    description: String::from("123456789 123456789 123456789 !"),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: None,
-                test: false,
-            })
+        let res = create_test_event(&app).await;
+        let id = res.tokens.public_token.clone();
+        let secret = res.tokens.moderator_token.unwrap();
+
+        assert!(app
+            .mod_start_trial(id.clone(), String::from("wrong"))
+            .await
+            .is_err());
+
+        let e = app
+            .mod_start_trial(id.clone(), secret.clone())
+            .await
+            .unwrap();
+        assert!(e.is_premium());
+        assert!(e.is_trial());
+        assert!(e.is_entitled(Entitlements::BRANDING));
+        assert!(!e.is_entitled(Entitlements::LONG_RETENTION));
+
+        let edit = ModEvent {
+            hide_branding: Some(true),
+            ..Default::default()
+        };
+        let e = app
+            .mod_edit_event(id.clone(), secret.clone(), edit)
             .await
             .unwrap();
+        assert!(e.branding_hidden());
+
+        assert!(app
+            .mod_start_trial(id.clone(), secret.clone())
+            .await
+            .is_err());
 
         events
             .db
             .lock()
             .await
-            .get_mut(&event_key(&res.tokens.public_token))
+            .get_mut(&event_key(&id))
             .unwrap()
             .event
-            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+            .trial_end_unix = Some(timestamp_now() - 1);
 
         let e = app
-            .mod_edit_event(
-                res.tokens.public_token.clone(),
-                res.tokens.moderator_token.clone().unwrap(),
-                ModEvent {
-                    screening: Some(true),
-                    ..Default::default()
-                },
-            )
+            .get_event(id.clone(), None, false, None, None)
             .await
             .unwrap();
+        assert!(!e.info.is_premium());
+        assert!(!e.info.branding_hidden());
 
-        assert!(e.is_screening());
+        assert!(app.mod_start_trial(id, secret).await.is_err());
     }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_duplicate_question_check() {
-        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
-        let pubsub = PubSubInMemory::default();
-        pubsub.set_receiver(pubsubreceiver.clone()).await;
+    async fn test_promo_code() {
         let events = Arc::new(InMemoryEventsDB::default());
-        let app = App::new(
-            events.clone(),
-            Arc::new(pubsub),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
-        );
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
+
+        assert!(app
+            .admin_add_promo(PromoCode {
+                code: String::from("no spaces"),
+                percent_off: 20,
+                max_uses: 1,
+                uses: 0,
+            })
+            .await
+            .is_err());
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: String::from("123456789"),
-                    description: String::from("123456789 123456789 123456789 !"),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: None,
-                test: false,
+        events
+            .add_promo(PromoCode {
+                code: String::from("RUST-24"),
+                percent_off: 20,
+                max_uses: 1,
+                uses: 0,
             })
             .await
             .unwrap();
 
-        app.add_question(
-            res.tokens.public_token.clone(),
-            AddQuestion {
-                text: String::from(TEST_VALID_QUESTION),
-            },
-        )
-        .await
-        .unwrap();
+        assert_eq!(app.valid_promo(" rust-24").await.unwrap().percent_off, 20);
+        assert!(matches!(
+            app.valid_promo("unknown").await,
+            Err(InternalError::InvalidPromoCode(_))
+        ));
 
-        let request = app
-            .add_question(
+        let res = create_test_event(&app).await;
+
+        assert!(matches!(
+            app.request_premium_upgrade(
                 res.tokens.public_token.clone(),
-                AddQuestion {
-                    text: String::from(TEST_VALID_QUESTION),
-                },
+                res.tokens.moderator_token.unwrap(),
+                Some(String::from("unknown"))
             )
-            .await;
+            .await,
+            Err(InternalError::InvalidPromoCode(_))
+        ));
+
+        events.redeem_promo("RUST-24").await.unwrap();
+        assert!(events.redeem_promo("RUST-24").await.is_err());
 
         assert!(matches!(
-            request.unwrap_err(),
-            InternalError::DuplicateQuestion
-        ))
+            app.valid_promo("RUST-24").await,
+            Err(InternalError::InvalidPromoCode(_))
+        ));
+        assert_eq!(
+            app.admin_get_promo(String::from("rust-24"))
+                .await
+                .unwrap()
+                .uses,
+            1
+        );
     }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_password_protection() {
-        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
-        let pubsub = PubSubInMemory::default();
-        pubsub.set_receiver(pubsubreceiver.clone()).await;
+    async fn test_org() {
         let events = Arc::new(InMemoryEventsDB::default());
-        let app = App::new(
-            events.clone(),
-            Arc::new(pubsub),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
-        );
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: String::from("123456789"),
-                    description: String::from("123456789 123456789 123456789 !"),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: None,
-                test: false,
+        let create = || AddEvent {
+            data: EventData {
+                name: TEST_EVENT_NAME.to_string(),
+                description: TEST_EVENT_DESC.to_string(),
+                short_url: String::new(),
+                long_url: None,
+                start_unix: None,
+                end_unix: None,
+                timezone: None,
+                branding: None,
+            },
+            moderator_email: None,
+            subscription: None,
+            test: false,
+        };
+
+        assert!(app
+            .create_org(AddOrg {
+                name: String::from("a"),
+                owner: String::from("owner"),
             })
             .await
-            .unwrap();
+            .is_err());
 
-        let event_id = res.tokens.public_token.clone();
-        let mod_token = res.tokens.moderator_token.clone().unwrap();
+        let owner = app
+            .create_org(AddOrg {
+                name: String::from("org"),
+                owner: String::from("owner"),
+            })
+            .await
+            .unwrap();
+        let id = owner.org.clone();
+
+        let member = app
+            .org_add_member(
+                id.clone(),
+                owner.token.clone(),
+                AddOrgMember {
+                    name: String::from("member"),
+                    role: OrgRole::Moderator,
+                },
+            )
+            .await
+            .unwrap();
 
-        let question_text = "very long, sophisticated question you can ask!";
-        app.add_question(
-            event_id.clone(),
-            AddQuestion {
-                text: String::from(question_text),
-            },
-        )
-        .await
-        .unwrap();
+        assert!(app
+            .org_add_member(
+                id.clone(),
+                member.token.clone(),
+                AddOrgMember {
+                    name: String::from("other"),
+                    role: OrgRole::Owner,
+                },
+            )
+            .await
+            .is_err());
+        assert!(app
+            .org_workspace(id.clone(), String::from("wrong"))
+            .await
+            .is_err());
 
-        app.mod_edit_event(
-            event_id.clone(),
-            mod_token.clone(),
-            ModEvent {
-                password: Some(shared::EventPassword::Enabled(String::from("pwd"))),
-                ..Default::default()
-            },
-        )
-        .await
-        .unwrap();
+        let workspace = app
+            .org_workspace(id.clone(), owner.token.clone())
+            .await
+            .unwrap();
+        assert_eq!(workspace.role, OrgRole::Owner);
+        assert_eq!(workspace.members.len(), 2);
+        assert!(workspace.members.iter().all(|m| m.token.is_some()));
 
         let e = app
-            .get_event(event_id.clone(), None, false, None)
+            .org_create_event(id.clone(), member.token.clone(), create())
             .await
             .unwrap();
+        assert!(!e.is_premium());
 
-        assert_ne!(&e.info.questions[0].text, question_text);
-        assert!(e.flags.contains(EventResponseFlags::WRONG_PASSWORD));
+        let workspace = app
+            .org_workspace(id.clone(), member.token.clone())
+            .await
+            .unwrap();
+        assert_eq!(workspace.role, OrgRole::Moderator);
+        assert!(workspace.members.iter().all(|m| m.token.is_none()));
+        assert_eq!(workspace.events.len(), 1);
+        assert_eq!(workspace.events[0].public_token, e.tokens.public_token);
+        assert_eq!(
+            Some(workspace.events[0].moderator_token.clone()),
+            e.tokens.moderator_token
+        );
+        assert!(!workspace.subscribed);
+
+        let mut entry = events.get_org(&id).await.unwrap();
+        entry.org.account = Some(String::from("account"));
+        entry.bump();
+        events.put_org(entry).await.unwrap();
+        app.subscription_webhook(String::from("account"), String::from("sub_1"), true, 1)
+            .await
+            .unwrap();
 
         let e = app
-            .get_event(event_id.clone(), None, false, Some(String::from("pwd")))
+            .org_create_event(id.clone(), member.token.clone(), create())
             .await
             .unwrap();
+        assert!(e.is_premium());
 
-        assert_eq!(&e.info.questions[0].text, question_text);
-        assert!(!e.flags.contains(EventResponseFlags::WRONG_PASSWORD));
+        let workspace = app
+            .org_workspace(id.clone(), owner.token.clone())
+            .await
+            .unwrap();
+        assert!(workspace.subscribed);
+        assert_eq!(workspace.events[0].public_token, e.tokens.public_token);
+
+        assert!(app
+            .org_remove_member(id.clone(), owner.token.clone(), owner.token.clone())
+            .await
+            .is_err());
+        app.org_remove_member(id.clone(), owner.token.clone(), member.token.clone())
+            .await
+            .unwrap();
+        assert!(app.org_workspace(id, member.token).await.is_err());
     }
 
     #[tokio::test]
     #[tracing_test::traced_test]
-    async fn test_event_question_tags() {
-        let pubsubreceiver = Arc::new(PubSubReceiverInMemory::default());
-        let pubsub = PubSubInMemory::default();
-        pubsub.set_receiver(pubsubreceiver.clone()).await;
+    async fn test_admin_dashboard() {
+        let app = test_app();
+
+        let create = |test: bool| AddEvent {
+            data: EventData {
+                name: TEST_EVENT_NAME.to_string(),
+                description: TEST_EVENT_DESC.to_string(),
+                short_url: String::new(),
+                long_url: None,
+                start_unix: None,
+                end_unix: None,
+                timezone: None,
+                branding: None,
+            },
+            moderator_email: None,
+            subscription: None,
+            test,
+        };
+
+        let e = app.create_event(create(false)).await.unwrap();
+        app.create_event(create(false)).await.unwrap();
+        app.create_event(create(true)).await.unwrap();
+
+        app.track_response(StatusCode::OK);
+        app.track_response(StatusCode::INTERNAL_SERVER_ERROR);
+
+        let dashboard = app.admin_dashboard().await.unwrap();
+        assert_eq!(dashboard.days.len(), 14);
+        assert_eq!(dashboard.days[0].day, day_string(0));
+        assert_eq!(dashboard.days[0].events, 2);
+        assert_eq!(dashboard.days[1].events, 0);
+        assert_eq!(dashboard.requests, 2);
+        assert_eq!(dashboard.server_errors, 1);
+
+        let found = app
+            .admin_event(e.tokens.public_token.clone())
+            .await
+            .unwrap();
+        assert_eq!(found.tokens.moderator_token, e.tokens.moderator_token);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_blocklist() {
         let events = Arc::new(InMemoryEventsDB::default());
-        let app = App::new(
-            events.clone(),
-            Arc::new(pubsub),
-            Arc::new(MockViewers::new()),
-            Arc::new(Payment::default()),
-            Tracking::default(),
-            String::new(),
-        );
+        let app = test_app_with(events.clone(), Arc::new(PubSubInMemory::default()));
 
-        let res = app
-            .create_event(AddEvent {
-                data: EventData {
-                    name: String::from("123456789"),
-                    description: String::from("123456789 123456789 123456789 !"),
-                    short_url: String::new(),
-                    long_url: None,
-                },
-                moderator_email: None,
-                test: false,
+        let ip = |ip: &str| ip.parse().ok();
+
+        assert!(!app.is_blocked(ip("203.0.113.7"), Some("abc")).await);
+
+        assert!(app
+            .admin_block(BlockEntry {
+                target: BlockTarget::IpRange,
+                value: String::from("203.0.113.0/33"),
+                ..Default::default()
             })
             .await
-            .unwrap();
+            .is_err());
 
-        events
-            .db
-            .lock()
+        app.admin_block(BlockEntry {
+            target: BlockTarget::IpRange,
+            value: String::from(" 203.0.113.0/24"),
+            note: String::from("junk events"),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        let blocklist = app
+            .admin_block(BlockEntry {
+                target: BlockTarget::Fingerprint,
+                value: String::from("abc"),
+                ..Default::default()
+            })
             .await
-            .get_mut(&event_key(&res.tokens.public_token))
-            .unwrap()
-            .event
-            .premium_id = Some(PremiumOrder::PaypalOrderId(String::from("foo")));
+            .unwrap();
 
-        assert_eq!(
-            app.mod_edit_event(
-                res.tokens.public_token.clone(),
-                res.tokens.moderator_token.clone().unwrap(),
-                ModEvent {
-                    current_tag: Some(CurrentTag::Enabled(String::from("tag1"))),
-                    ..Default::default()
+        assert_eq!(blocklist.entries.len(), 2);
+        assert_eq!(blocklist.entries[0].value, "203.0.113.0/24");
+        assert_eq!(events.get_blocklist().await.unwrap(), blocklist);
+
+        assert!(app.is_blocked(ip("203.0.113.7"), None).await);
+        assert!(app.is_blocked(ip("198.51.100.1"), Some("abc")).await);
+        assert!(!app.is_blocked(ip("198.51.100.1"), Some("abcd")).await);
+        assert!(!app.is_blocked(ip("2001:db8::1"), None).await);
+
+        app.admin_unblock(BlockEntry {
+            target: BlockTarget::IpRange,
+            value: String::from("203.0.113.0/24"),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        assert!(!app.is_blocked(ip("203.0.113.7"), None).await);
+        assert_eq!(app.admin_blocklist().await.unwrap().entries.len(), 1);
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_poll_vote_deleted_event() {
+        let app = test_app();
+
+        let res = create_test_event(&app).await;
+
+        let id = res.tokens.public_token;
+        let secret = res.tokens.moderator_token.unwrap();
+
+        let poll = app
+            .mod_add_poll(
+                id.clone(),
+                secret.clone(),
+                AddPoll {
+                    question: String::from("which one?"),
+                    options: vec![String::from("a"), String::from("b")],
                 },
             )
             .await
-            .unwrap()
-            .tags
-            .current_tag
-            .unwrap(),
-            TagId(0)
-        );
+            .unwrap();
 
-        let request = app
-            .add_question(
-                res.tokens.public_token.clone(),
-                AddQuestion {
-                    text: String::from(TEST_VALID_QUESTION),
+        app.delete_event(id.clone(), secret).await.unwrap();
+
+        assert!(matches!(
+            app.vote_poll(
+                id.clone(),
+                poll.id,
+                PollVote {
+                    option: 0,
+                    participant: String::from("p1"),
                 },
             )
             .await
+            .unwrap_err(),
+            InternalError::AccessingDeletedEvent(_)
+        ));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_viewer_count_free_event() {
+        let mut viewers = MockViewers::new();
+        viewers.expect_count().return_const(134_i64);
+        let app = App {
+            viewers: Arc::new(viewers),
+            ..test_app()
+        };
+
+        let res = create_test_event(&app).await;
+
+        let e = app
+            .get_event(res.tokens.public_token, None, false, None, None)
+            .await
             .unwrap();
 
-        assert_eq!(request.tag.unwrap(), TagId(0))
+        assert_eq!(e.viewers, 134);
     }
 }
@@ -0,0 +1,16 @@
+use aws_sdk_s3::presigning::PresigningConfigError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AttachmentError {
+    #[error("Attachments not configured")]
+    NotConfigured,
+    #[error("Invalid Attachment: {0}")]
+    Invalid(String),
+    #[error("Presigning Config Error: {0}")]
+    PresigningConfig(#[from] PresigningConfigError),
+    #[error("S3 Error: {0}")]
+    S3(String),
+}
+
+pub type AttachmentResult<T> = std::result::Result<T, AttachmentError>;
@@ -0,0 +1,123 @@
+mod error;
+
+pub use error::AttachmentError;
+
+use aws_sdk_s3::presigning::PresigningConfig;
+use error::AttachmentResult;
+use shared::{attachment_key, logo_key, AttachmentRequest, AttachmentUpload};
+use std::time::Duration;
+use tracing::instrument;
+use ulid::Ulid;
+
+use crate::{aws_s3_client, env};
+
+const UPLOAD_EXPIRY: Duration = Duration::from_secs(5 * 60);
+const DOWNLOAD_EXPIRY: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Clone, Debug, Default)]
+pub struct Attachments {
+    bucket: Option<String>,
+}
+
+impl Attachments {
+    pub fn new() -> Self {
+        let bucket = std::env::var(env::ENV_ATTACHMENTS_BUCKET)
+            .ok()
+            .filter(|bucket| !bucket.trim().is_empty());
+
+        if let Some(bucket) = &bucket {
+            tracing::info!("attachments bucket: {bucket}");
+        } else {
+            tracing::warn!("no attachments bucket set, use `LA_ATTACHMENTS_BUCKET` to do so");
+        }
+
+        Self { bucket }
+    }
+
+    fn bucket(&self) -> AttachmentResult<&str> {
+        self.bucket.as_deref().ok_or(AttachmentError::NotConfigured)
+    }
+
+    #[instrument(err, skip(self))]
+    pub async fn upload_url(
+        &self,
+        event: &str,
+        request: &AttachmentRequest,
+    ) -> AttachmentResult<AttachmentUpload> {
+        if !request.is_valid() {
+            return Err(AttachmentError::Invalid(format!(
+                "{} ({} bytes)",
+                request.content_type, request.size
+            )));
+        }
+
+        self.presign_upload(attachment_key(event, &Ulid::new().to_string()), request)
+            .await
+    }
+
+    #[instrument(err, skip(self))]
+    pub async fn logo_upload_url(
+        &self,
+        event: &str,
+        request: &AttachmentRequest,
+    ) -> AttachmentResult<AttachmentUpload> {
+        if !request.is_valid_logo() {
+            return Err(AttachmentError::Invalid(format!(
+                "logo {} ({} bytes)",
+                request.content_type, request.size
+            )));
+        }
+
+        self.presign_upload(logo_key(event, &Ulid::new().to_string()), request)
+            .await
+    }
+
+    #[instrument(err, skip(self))]
+    pub async fn download_url(&self, event: &str, file: &str) -> AttachmentResult<String> {
+        self.presign_download(attachment_key(event, file)).await
+    }
+
+    #[instrument(err, skip(self))]
+    pub async fn logo_download_url(&self, event: &str, file: &str) -> AttachmentResult<String> {
+        self.presign_download(logo_key(event, file)).await
+    }
+
+    async fn presign_upload(
+        &self,
+        key: String,
+        request: &AttachmentRequest,
+    ) -> AttachmentResult<AttachmentUpload> {
+        let bucket = self.bucket()?;
+
+        let presigned = aws_s3_client()
+            .await
+            .put_object()
+            .bucket(bucket)
+            .key(&key)
+            .content_type(&request.content_type)
+            .content_length(i64::try_from(request.size).unwrap_or_default())
+            .presigned(PresigningConfig::expires_in(UPLOAD_EXPIRY)?)
+            .await
+            .map_err(|e| AttachmentError::S3(e.to_string()))?;
+
+        Ok(AttachmentUpload {
+            key,
+            upload_url: presigned.uri().to_string(),
+        })
+    }
+
+    async fn presign_download(&self, key: String) -> AttachmentResult<String> {
+        let bucket = self.bucket()?;
+
+        let presigned = aws_s3_client()
+            .await
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .presigned(PresigningConfig::expires_in(DOWNLOAD_EXPIRY)?)
+            .await
+            .map_err(|e| AttachmentError::S3(e.to_string()))?;
+
+        Ok(presigned.uri().to_string())
+    }
+}
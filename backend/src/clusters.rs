@@ -0,0 +1,83 @@
+use shared::{QuestionCluster, QuestionItem};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+/// shorter words carry too little meaning to compare questions by
+const MIN_WORD_LENGTH: usize = 3;
+
+/// groups questions sharing at least half of their words,
+/// the clusters with the most likes come first
+pub fn cluster_questions(questions: &[&QuestionItem]) -> Vec<QuestionCluster> {
+    let words: Vec<HashSet<String>> = questions.iter().map(|q| words(&q.text)).collect();
+
+    //Note: union-find over the indices, similar questions end up with the same root
+    let mut parents: Vec<usize> = (0..questions.len()).collect();
+
+    for (a, words_a) in words.iter().enumerate() {
+        for (b, words_b) in words.iter().enumerate().skip(a + 1) {
+            if similar(words_a, words_b) {
+                let root_a = root(&mut parents, a);
+                let root_b = root(&mut parents, b);
+                parents[root_b] = root_a;
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<&QuestionItem>> = HashMap::new();
+
+    for (index, question) in questions.iter().enumerate() {
+        groups
+            .entry(root(&mut parents, index))
+            .or_default()
+            .push(question);
+    }
+
+    let mut groups: Vec<Vec<&QuestionItem>> = groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect();
+
+    for group in &mut groups {
+        group.sort_by_key(|q| (Reverse(q.likes), q.id));
+    }
+
+    groups.sort_by_key(|group| {
+        (
+            Reverse(group.iter().map(|q| q.likes).sum::<i32>()),
+            group.first().map(|q| q.id),
+        )
+    });
+
+    groups
+        .into_iter()
+        .map(|group| QuestionCluster {
+            questions: group.iter().map(|q| q.id).collect(),
+        })
+        .collect()
+}
+
+fn root(parents: &mut [usize], mut index: usize) -> usize {
+    while parents[index] != index {
+        parents[index] = parents[parents[index]];
+        index = parents[index];
+    }
+
+    index
+}
+
+fn words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= MIN_WORD_LENGTH)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// at least half of the words of both questions together are in each of them
+fn similar(a: &HashSet<String>, b: &HashSet<String>) -> bool {
+    let shared = a.intersection(b).count();
+    let all = a.len() + b.len() - shared;
+
+    shared > 0 && shared * 2 >= all
+}
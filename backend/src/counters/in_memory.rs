@@ -0,0 +1,36 @@
+use super::Counters;
+use crate::utils::timestamp_now;
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemoryCounters {
+    /// count and expiry time per key
+    pub counters: Arc<RwLock<HashMap<String, (u64, i64)>>>,
+}
+
+#[async_trait]
+impl Counters for InMemoryCounters {
+    async fn increment(&self, key: &str, ttl_secs: usize) -> u64 {
+        let now = timestamp_now();
+        let mut counters = self.counters.write().await;
+
+        let counter = counters.entry(key.to_string()).or_insert((0, 0));
+        if counter.1 <= now {
+            *counter = (
+                0,
+                now.saturating_add(i64::try_from(ttl_secs).unwrap_or(i64::MAX)),
+            );
+        }
+
+        counter.0 = counter.0.saturating_add(1);
+        counter.0
+    }
+
+    async fn decrement(&self, key: &str) {
+        if let Some(counter) = self.counters.write().await.get_mut(key) {
+            counter.0 = counter.0.saturating_sub(1);
+        }
+    }
+}
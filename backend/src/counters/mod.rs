@@ -0,0 +1,17 @@
+mod in_memory;
+mod redis;
+
+pub use self::redis::RedisCounters;
+#[cfg(test)]
+pub use in_memory::InMemoryCounters;
+
+use async_trait::async_trait;
+
+/// expiring counters shared across all server instances, e.g. towards per participant limits
+#[async_trait]
+pub trait Counters: Send + Sync {
+    /// adds one to `key` and returns the new count, a new key expires `ttl_secs` later
+    async fn increment(&self, key: &str, ttl_secs: usize) -> u64;
+    /// takes back an increment whose action could not be stored, so it does not count
+    async fn decrement(&self, key: &str);
+}
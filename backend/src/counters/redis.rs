@@ -0,0 +1,58 @@
+use super::Counters;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use tracing::instrument;
+
+/// plain redis integers, expiring a fixed time after their first increment
+pub struct RedisCounters {
+    redis: deadpool_redis::Pool,
+}
+
+impl RedisCounters {
+    pub const fn new(pool: deadpool_redis::Pool) -> Self {
+        Self { redis: pool }
+    }
+}
+
+#[async_trait]
+impl Counters for RedisCounters {
+    #[instrument(skip(self))]
+    async fn increment(&self, key: &str, ttl_secs: usize) -> u64 {
+        //Note: limits are not enforced while redis is unavailable rather than rejecting everyone
+        let Ok(mut db) = self.redis.get().await else {
+            tracing::error!("counters: redis unavailable");
+            return 0;
+        };
+
+        let key = create_key(key);
+
+        let count = match db.incr::<_, _, u64>(&key, 1).await {
+            Ok(count) => count,
+            Err(e) => {
+                tracing::error!("counters incr error: {e}");
+                return 0;
+            }
+        };
+
+        if count == 1 {
+            db.expire::<_, isize>(&key, ttl_secs).await.ok();
+        }
+
+        count
+    }
+
+    #[instrument(skip(self))]
+    async fn decrement(&self, key: &str) {
+        let Ok(mut db) = self.redis.get().await else {
+            return;
+        };
+
+        if let Err(e) = db.decr::<_, _, i64>(create_key(key), 1).await {
+            tracing::error!("counters decr error: {e}");
+        }
+    }
+}
+
+fn create_key(key: &str) -> String {
+    format!("counter/{key}")
+}
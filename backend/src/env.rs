@@ -12,6 +12,15 @@ const ENV_ADMIN_PWD_HASH: &str = "LA_ADMIN_PWD_HASH";
 const ENV_SESSION_SECRET: &str = "LA_SESSION_SECRET";
 pub const ENV_STRIPE_SECRET: &str = "LA_STRIPE_SECRET";
 pub const ENV_STRIPE_HOOK_SECRET: &str = "LA_STRIPE_HOOK_SECRET";
+pub const ENV_ATTACHMENTS_BUCKET: &str = "LA_ATTACHMENTS_BUCKET";
+pub const ENV_VAPID_PRIVATE_KEY: &str = "LA_VAPID_PRIVATE_KEY";
+pub const ENV_VAPID_PUBLIC_KEY: &str = "LA_VAPID_PUBLIC_KEY";
+pub const ENV_LLM_URL: &str = "LA_LLM_URL";
+pub const ENV_LLM_KEY: &str = "LA_LLM_KEY";
+pub const ENV_LLM_MODEL: &str = "LA_LLM_MODEL";
+pub const ENV_SENTIMENT: &str = "LA_SENTIMENT";
+pub const ENV_TRANSLATE_URL: &str = "LA_TRANSLATE_URL";
+pub const ENV_TRANSLATE_KEY: &str = "LA_TRANSLATE_KEY";
 
 pub fn admin_pwd_hash() -> String {
     std::env::var(ENV_ADMIN_PWD_HASH).unwrap_or_default()
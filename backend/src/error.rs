@@ -1,13 +1,20 @@
-use axum::response::{IntoResponse, Response};
+use axum::{
+    http::header,
+    response::{IntoResponse, Response},
+};
 use deadpool_redis::{CreatePoolError, PoolError};
 use redis::RedisError;
 use reqwest::StatusCode;
 use shared::{
-    AddQuestionValidation, ContextValidation, EditMetaData, PasswordValidation, TagValidation,
+    AddQuestionValidation, ContextValidation, EditMetaData, NameValidation, PasswordValidation,
+    TagValidation,
 };
 use thiserror::Error;
 
-use crate::{eventsdb, payment::PaymentError, tracking};
+use crate::{
+    attachments::AttachmentError, eventsdb, payment::PaymentError, summary::SummaryError, tracking,
+    translate::TranslateError, webpush::WebPushError,
+};
 
 #[derive(Error, Debug)]
 pub enum InternalError {
@@ -26,15 +33,45 @@ pub enum InternalError {
     #[error("wrong moderator token: {0}")]
     WrongModeratorToken(String),
 
+    #[error("wrong org token: {0}")]
+    WrongOrgToken(String),
+
+    #[error("Org Owner Only: {0}")]
+    OrgOwnerOnly(String),
+
     #[error("Premium Only Feature: {0}")]
     PremiumOnlyFeature(String),
 
     #[error("Duplicate Question Error")]
     DuplicateQuestion,
 
+    #[error("Question Cooldown: {0}")]
+    QuestionCooldown(String),
+
+    #[error("Question Limit Reached: {0}")]
+    QuestionLimitReached(String),
+
+    #[error("Question Language Not Allowed: {0}")]
+    QuestionLanguage(String),
+
+    #[error("Question Rate Limited: {0} (retry in {1}s)")]
+    QuestionRateLimited(String, i64),
+
+    #[error("Blocked Client: {0}")]
+    BlockedClient(String),
+
+    #[error("Slug Taken: {0}")]
+    SlugTaken(String),
+
+    #[error("Invalid Promo Code: {0}")]
+    InvalidPromoCode(String),
+
     #[error("Add Question Validation")]
     AddQuestionValidation(AddQuestionValidation),
 
+    #[error("Name Validation")]
+    NameValidation(NameValidation),
+
     #[error("Password Validation")]
     PasswordValidation(PasswordValidation),
 
@@ -70,6 +107,18 @@ pub enum InternalError {
 
     #[error("Tracking Error: {0}")]
     Tracking(#[from] tracking::TrackingError),
+
+    #[error("Attachment Error: {0}")]
+    Attachment(#[from] AttachmentError),
+
+    #[error("Web Push Error: {0}")]
+    WebPush(#[from] WebPushError),
+
+    #[error("Summary Error: {0}")]
+    Summary(#[from] SummaryError),
+
+    #[error("Translate Error: {0}")]
+    Translate(#[from] TranslateError),
 }
 
 impl IntoResponse for InternalError {
@@ -101,6 +150,16 @@ impl IntoResponse for InternalError {
                 (StatusCode::BAD_REQUEST, "").into_response()
             }
 
+            Self::WrongOrgToken(id) => {
+                tracing::warn!("wrong org token: {id}");
+                (StatusCode::BAD_REQUEST, "").into_response()
+            }
+
+            Self::OrgOwnerOnly(id) => {
+                tracing::warn!("org owner only: {id}");
+                (StatusCode::FORBIDDEN, "").into_response()
+            }
+
             Self::PremiumOnlyFeature(id) => {
                 tracing::warn!("trying to access premium feature: {id}");
                 (StatusCode::BAD_REQUEST, "").into_response()
@@ -108,11 +167,81 @@ impl IntoResponse for InternalError {
 
             Self::DuplicateQuestion => (StatusCode::BAD_REQUEST, "").into_response(),
 
+            Self::QuestionCooldown(id) => {
+                tracing::info!("question cooldown: {id}");
+                (StatusCode::TOO_MANY_REQUESTS, "").into_response()
+            }
+
+            Self::QuestionLimitReached(id) => {
+                tracing::info!("question limit reached: {id}");
+                (StatusCode::FORBIDDEN, "").into_response()
+            }
+
+            Self::QuestionLanguage(id) => {
+                tracing::info!("question language not allowed: {id}");
+                (StatusCode::UNPROCESSABLE_ENTITY, "").into_response()
+            }
+
+            Self::QuestionRateLimited(id, retry_secs) => {
+                tracing::info!("question rate limited: {id}");
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(header::RETRY_AFTER, retry_secs.to_string())],
+                    "",
+                )
+                    .into_response()
+            }
+
+            Self::BlockedClient(path) => {
+                tracing::warn!("blocked client: {path}");
+                (StatusCode::FORBIDDEN, "").into_response()
+            }
+
+            Self::SlugTaken(slug) => {
+                tracing::info!("slug taken: {slug}");
+                (StatusCode::CONFLICT, "").into_response()
+            }
+
+            Self::InvalidPromoCode(code) => {
+                tracing::info!("invalid promo code: {code}");
+                (StatusCode::BAD_REQUEST, "").into_response()
+            }
+
             Self::Payment(e) => {
                 tracing::error!("payment error: {e}");
                 (StatusCode::BAD_REQUEST, "").into_response()
             }
 
+            Self::Attachment(e) if matches!(e, AttachmentError::Invalid(_)) => {
+                tracing::warn!("invalid attachment: {e}");
+                (StatusCode::BAD_REQUEST, "").into_response()
+            }
+
+            Self::WebPush(e) if matches!(e, WebPushError::NotConfigured) => {
+                tracing::info!("web push not configured");
+                (StatusCode::NOT_FOUND, "").into_response()
+            }
+
+            Self::Summary(e) if matches!(e, SummaryError::NotConfigured) => {
+                tracing::info!("summaries not configured");
+                (StatusCode::NOT_FOUND, "").into_response()
+            }
+
+            Self::Summary(e) => {
+                tracing::warn!("summary failed: {e}");
+                (StatusCode::BAD_GATEWAY, "").into_response()
+            }
+
+            Self::Translate(e) if matches!(e, TranslateError::NotConfigured) => {
+                tracing::info!("translations not configured");
+                (StatusCode::NOT_FOUND, "").into_response()
+            }
+
+            Self::Translate(e) => {
+                tracing::warn!("translation failed: {e}");
+                (StatusCode::BAD_GATEWAY, "").into_response()
+            }
+
             Self::SerdeJson(e) => {
                 tracing::error!("serde error: {e}");
                 (StatusCode::BAD_REQUEST, "").into_response()
@@ -126,6 +255,10 @@ impl IntoResponse for InternalError {
                 tracing::warn!("add question validation: {:?}", e);
                 (StatusCode::BAD_REQUEST, "").into_response()
             }
+            Self::NameValidation(e) => {
+                tracing::warn!("name validation: {:?}", e);
+                (StatusCode::BAD_REQUEST, "").into_response()
+            }
             Self::PasswordValidation(e) => {
                 tracing::warn!("password validation: {:?}", e);
                 (StatusCode::BAD_REQUEST, "").into_response()
@@ -161,6 +294,8 @@ impl IntoResponse for InternalError {
             Self::DeadPoolCreatePool(e) => convert_error(e),
             Self::DeadPoolRedis(e) => convert_error(e),
             Self::Tracking(e) => convert_error(e),
+            Self::Attachment(e) => convert_error(e),
+            Self::WebPush(e) => convert_error(e),
         }
     }
 }
@@ -3,23 +3,48 @@
 use async_trait::async_trait;
 use aws_sdk_dynamodb::{
     error::SdkError,
-    operation::put_item::PutItemError,
+    operation::{put_item::PutItemError, update_item::UpdateItemError},
     types::{
         AttributeDefinition, AttributeValue, KeySchemaElement, KeyType, ProvisionedThroughput,
         ScalarAttributeType,
     },
 };
+use shared::{AdminDayStats, Blocklist, PromoCode};
 use tracing::instrument;
 
-use crate::eventsdb::event_key;
+use crate::eventsdb::{
+    event_key, org_key, promo_key, slug_key, stats_key, subscription_key, webhook_key,
+    BLOCKLIST_KEY,
+};
 
 use super::{
     error::{Error, Result},
     types::{ApiEventInfo, AttributeMap},
-    EventEntry, EventsDB,
+    EventEntry, EventsDB, OrgEntry, StatCounter, Subscription,
 };
 
 const DB_TABLE_NAME: &str = "liveask";
+const ATTR_SLUG_EVENT: &str = "event";
+const ATTR_SUBSCRIPTION_STRIPE_ID: &str = "stripe_id";
+const ATTR_SUBSCRIPTION_ACTIVE: &str = "active";
+const ATTR_SUBSCRIPTION_UPDATED: &str = "updated";
+const ATTR_WEBHOOK_PAYLOAD: &str = "payload";
+const ATTR_WEBHOOK_RECEIVED: &str = "received";
+const ATTR_PROMO_PERCENT_OFF: &str = "percent_off";
+const ATTR_PROMO_MAX_USES: &str = "max_uses";
+const ATTR_PROMO_USES: &str = "uses";
+const ATTR_ORG_VERSION: &str = "v";
+const ATTR_ORG_VALUE: &str = "value";
+const ATTR_STATS_EVENTS: &str = "events";
+const ATTR_STATS_UPGRADES: &str = "upgrades";
+const ATTR_BLOCKLIST_VALUE: &str = "value";
+
+const fn stat_attribute(counter: StatCounter) -> &'static str {
+    match counter {
+        StatCounter::EventCreated => ATTR_STATS_EVENTS,
+        StatCounter::PremiumUpgrade => ATTR_STATS_UPGRADES,
+    }
+}
 
 #[derive(Clone)]
 pub struct DynamoEventsDB {
@@ -105,6 +130,392 @@ impl EventsDB for DynamoEventsDB {
 
         Ok(())
     }
+
+    #[instrument(skip(self), err)]
+    async fn claim_slug(&self, slug: &str, event: &str) -> Result<()> {
+        //Note: claiming the own slug again succeeds, anybody else's fails the condition
+        let res = self
+            .db
+            .put_item()
+            .table_name(&self.table)
+            .item("key", AttributeValue::S(slug_key(slug)))
+            .item(ATTR_SLUG_EVENT, AttributeValue::S(event.to_string()))
+            .condition_expression("attribute_not_exists(#key) OR #event = :event")
+            .expression_attribute_names("#key", "key")
+            .expression_attribute_names("#event", ATTR_SLUG_EVENT)
+            .expression_attribute_values(":event", AttributeValue::S(event.to_string()))
+            .send()
+            .await;
+
+        if let Err(e) = res {
+            if matches!(&e,SdkError::<PutItemError>::ServiceError (err)
+            if matches!(
+                err.err(),PutItemError::ConditionalCheckFailedException(_)
+
+            )) {
+                return Err(Error::Concurrency);
+            }
+
+            return Err(Error::DynamoPut(e));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_slug(&self, slug: &str) -> Result<String> {
+        let res = self
+            .db
+            .get_item()
+            .table_name(&self.table)
+            .key("key", AttributeValue::S(slug_key(slug)))
+            .send()
+            .await?;
+
+        let item = res.item().ok_or(Error::ItemNotFound)?;
+
+        item.get(ATTR_SLUG_EVENT)
+            .and_then(|value| value.as_s().ok())
+            .cloned()
+            .ok_or_else(|| Error::MalformedObject(ATTR_SLUG_EVENT.into()))
+    }
+
+    #[instrument(skip(self), err)]
+    async fn put_subscription(&self, account: &str, subscription: Subscription) -> Result<()> {
+        self.db
+            .put_item()
+            .table_name(&self.table)
+            .item("key", AttributeValue::S(subscription_key(account)))
+            .item(
+                ATTR_SUBSCRIPTION_STRIPE_ID,
+                AttributeValue::S(subscription.stripe_id),
+            )
+            .item(
+                ATTR_SUBSCRIPTION_ACTIVE,
+                AttributeValue::Bool(subscription.active),
+            )
+            .item(
+                ATTR_SUBSCRIPTION_UPDATED,
+                AttributeValue::N(subscription.updated_unix.to_string()),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_subscription(&self, account: &str) -> Result<Subscription> {
+        let res = self
+            .db
+            .get_item()
+            .table_name(&self.table)
+            .key("key", AttributeValue::S(subscription_key(account)))
+            .send()
+            .await?;
+
+        let item = res.item().ok_or(Error::ItemNotFound)?;
+
+        Ok(Subscription {
+            stripe_id: item
+                .get(ATTR_SUBSCRIPTION_STRIPE_ID)
+                .and_then(|value| value.as_s().ok())
+                .cloned()
+                .ok_or_else(|| Error::MalformedObject(ATTR_SUBSCRIPTION_STRIPE_ID.into()))?,
+            active: item
+                .get(ATTR_SUBSCRIPTION_ACTIVE)
+                .and_then(|value| value.as_bool().ok())
+                .copied()
+                .unwrap_or_default(),
+            //Note: missing on subscriptions stored before stale events were detected
+            updated_unix: item
+                .get(ATTR_SUBSCRIPTION_UPDATED)
+                .and_then(|value| value.as_n().ok())
+                .and_then(|updated| updated.parse::<i64>().ok())
+                .unwrap_or_default(),
+        })
+    }
+
+    #[instrument(skip(self, payload), err)]
+    async fn put_webhook(&self, id: &str, payload: String, received_unix: i64) -> Result<()> {
+        let res = self
+            .db
+            .put_item()
+            .table_name(&self.table)
+            .item("key", AttributeValue::S(webhook_key(id)))
+            .item(ATTR_WEBHOOK_PAYLOAD, AttributeValue::S(payload))
+            .item(
+                ATTR_WEBHOOK_RECEIVED,
+                AttributeValue::N(received_unix.to_string()),
+            )
+            .condition_expression("attribute_not_exists(#key)")
+            .expression_attribute_names("#key", "key")
+            .send()
+            .await;
+
+        if let Err(e) = res {
+            if matches!(&e,SdkError::<PutItemError>::ServiceError (err)
+            if matches!(
+                err.err(),PutItemError::ConditionalCheckFailedException(_)
+
+            )) {
+                return Err(Error::Concurrency);
+            }
+
+            return Err(Error::DynamoPut(e));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn add_promo(&self, promo: PromoCode) -> Result<()> {
+        let res = self
+            .db
+            .put_item()
+            .table_name(&self.table)
+            .item("key", AttributeValue::S(promo_key(&promo.code)))
+            .item(
+                ATTR_PROMO_PERCENT_OFF,
+                AttributeValue::N(promo.percent_off.to_string()),
+            )
+            .item(
+                ATTR_PROMO_MAX_USES,
+                AttributeValue::N(promo.max_uses.to_string()),
+            )
+            .item(ATTR_PROMO_USES, AttributeValue::N(promo.uses.to_string()))
+            .condition_expression("attribute_not_exists(#key)")
+            .expression_attribute_names("#key", "key")
+            .send()
+            .await;
+
+        if let Err(e) = res {
+            if matches!(&e,SdkError::<PutItemError>::ServiceError (err)
+            if matches!(
+                err.err(),PutItemError::ConditionalCheckFailedException(_)
+
+            )) {
+                return Err(Error::Concurrency);
+            }
+
+            return Err(Error::DynamoPut(e));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_promo(&self, code: &str) -> Result<PromoCode> {
+        let res = self
+            .db
+            .get_item()
+            .table_name(&self.table)
+            .key("key", AttributeValue::S(promo_key(code)))
+            .send()
+            .await?;
+
+        let item = res.item().ok_or(Error::ItemNotFound)?;
+
+        let number = |attr: &str| -> Result<String> {
+            item.get(attr)
+                .and_then(|value| value.as_n().ok())
+                .cloned()
+                .ok_or_else(|| Error::MalformedObject(attr.into()))
+        };
+
+        Ok(PromoCode {
+            code: code.to_string(),
+            percent_off: number(ATTR_PROMO_PERCENT_OFF)?.parse()?,
+            max_uses: number(ATTR_PROMO_MAX_USES)?.parse()?,
+            uses: number(ATTR_PROMO_USES)?.parse()?,
+        })
+    }
+
+    #[instrument(skip(self), err)]
+    async fn redeem_promo(&self, code: &str) -> Result<()> {
+        let res = self
+            .db
+            .update_item()
+            .table_name(&self.table)
+            .key("key", AttributeValue::S(promo_key(code)))
+            .update_expression("SET #uses = #uses + :one")
+            .condition_expression("#uses < #max")
+            .expression_attribute_names("#uses", ATTR_PROMO_USES)
+            .expression_attribute_names("#max", ATTR_PROMO_MAX_USES)
+            .expression_attribute_values(":one", AttributeValue::N(String::from("1")))
+            .send()
+            .await;
+
+        if let Err(e) = res {
+            if matches!(&e,SdkError::<UpdateItemError>::ServiceError (err)
+            if matches!(
+                err.err(),UpdateItemError::ConditionalCheckFailedException(_)
+
+            )) {
+                return Err(Error::Concurrency);
+            }
+
+            return Err(Error::DynamoUpdate(e));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_org(&self, id: &str) -> Result<OrgEntry> {
+        let res = self
+            .db
+            .get_item()
+            .table_name(&self.table)
+            .key("key", AttributeValue::S(org_key(id)))
+            .send()
+            .await?;
+
+        let item = res.item().ok_or(Error::ItemNotFound)?;
+
+        let version = item
+            .get(ATTR_ORG_VERSION)
+            .and_then(|value| value.as_n().ok())
+            .ok_or_else(|| Error::MalformedObject(ATTR_ORG_VERSION.into()))?
+            .parse::<usize>()?;
+
+        let value = item
+            .get(ATTR_ORG_VALUE)
+            .and_then(|value| value.as_s().ok())
+            .ok_or_else(|| Error::MalformedObject(ATTR_ORG_VALUE.into()))?;
+
+        Ok(OrgEntry {
+            org: serde_json::from_str(value)?,
+            version,
+        })
+    }
+
+    #[instrument(skip(self), err)]
+    async fn put_org(&self, org: OrgEntry) -> Result<()> {
+        let mut request = self
+            .db
+            .put_item()
+            .table_name(&self.table)
+            .item("key", AttributeValue::S(org_key(&org.org.id)))
+            .item(ATTR_ORG_VERSION, AttributeValue::N(org.version.to_string()))
+            .item(
+                ATTR_ORG_VALUE,
+                AttributeValue::S(serde_json::to_string(&org.org)?),
+            );
+
+        if org.version > 0 {
+            request = request
+                .condition_expression("#v = :ver")
+                .expression_attribute_names("#v", ATTR_ORG_VERSION)
+                .expression_attribute_values(
+                    ":ver",
+                    AttributeValue::N(org.version.saturating_sub(1).to_string()),
+                );
+        }
+
+        if let Err(e) = request.send().await {
+            if matches!(&e,SdkError::<PutItemError>::ServiceError (err)
+            if matches!(
+                err.err(),PutItemError::ConditionalCheckFailedException(_)
+
+            )) {
+                return Err(Error::Concurrency);
+            }
+
+            return Err(Error::DynamoPut(e));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn count_stat(&self, day: &str, counter: StatCounter) -> Result<()> {
+        //Note: `ADD` creates the item and the counter on first use
+        self.db
+            .update_item()
+            .table_name(&self.table)
+            .key("key", AttributeValue::S(stats_key(day)))
+            .update_expression("ADD #counter :one")
+            .expression_attribute_names("#counter", stat_attribute(counter))
+            .expression_attribute_values(":one", AttributeValue::N(String::from("1")))
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_stats(&self, day: &str) -> Result<AdminDayStats> {
+        let res = self
+            .db
+            .get_item()
+            .table_name(&self.table)
+            .key("key", AttributeValue::S(stats_key(day)))
+            .send()
+            .await?;
+
+        let item = res.item().ok_or(Error::ItemNotFound)?;
+
+        let number = |attr: &str| -> Result<u64> {
+            item.get(attr)
+                .and_then(|value| value.as_n().ok())
+                .map_or(Ok(0), |value| value.parse().map_err(Error::from))
+        };
+
+        Ok(AdminDayStats {
+            day: day.to_string(),
+            events: number(ATTR_STATS_EVENTS)?,
+            upgrades: number(ATTR_STATS_UPGRADES)?,
+        })
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_blocklist(&self) -> Result<Blocklist> {
+        let res = self
+            .db
+            .get_item()
+            .table_name(&self.table)
+            .key("key", AttributeValue::S(BLOCKLIST_KEY.to_string()))
+            .send()
+            .await?;
+
+        let item = res.item().ok_or(Error::ItemNotFound)?;
+
+        let value = item
+            .get(ATTR_BLOCKLIST_VALUE)
+            .and_then(|value| value.as_s().ok())
+            .ok_or_else(|| Error::MalformedObject(ATTR_BLOCKLIST_VALUE.into()))?;
+
+        Ok(serde_json::from_str(value)?)
+    }
+
+    #[instrument(skip(self, blocklist), err)]
+    async fn put_blocklist(&self, blocklist: Blocklist) -> Result<()> {
+        self.db
+            .put_item()
+            .table_name(&self.table)
+            .item("key", AttributeValue::S(BLOCKLIST_KEY.to_string()))
+            .item(
+                ATTR_BLOCKLIST_VALUE,
+                AttributeValue::S(serde_json::to_string(&blocklist)?),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete_webhook(&self, id: &str) -> Result<()> {
+        self.db
+            .delete_item()
+            .table_name(&self.table)
+            .key("key", AttributeValue::S(webhook_key(id)))
+            .send()
+            .await?;
+
+        Ok(())
+    }
 }
 
 impl DynamoEventsDB {
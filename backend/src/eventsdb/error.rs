@@ -3,8 +3,8 @@ use std::num::ParseIntError;
 use aws_sdk_dynamodb::{
     error::SdkError,
     operation::{
-        create_table::CreateTableError, get_item::GetItemError, list_tables::ListTablesError,
-        put_item::PutItemError,
+        create_table::CreateTableError, delete_item::DeleteItemError, get_item::GetItemError,
+        list_tables::ListTablesError, put_item::PutItemError, update_item::UpdateItemError,
     },
 };
 use thiserror::Error;
@@ -41,6 +41,12 @@ pub enum Error {
     #[error("Dynamo GetItemError: {0}")]
     DynamoGetItem(#[from] SdkError<GetItemError>),
 
+    #[error("Dynamo UpdateItemError: {0}")]
+    DynamoUpdate(#[from] SdkError<UpdateItemError>),
+
+    #[error("Dynamo DeleteItemError: {0}")]
+    DynamoDelete(#[from] SdkError<DeleteItemError>),
+
     #[error("Dynamo BuildError: {0}")]
     DynamoBuild(#[from] aws_sdk_dynamodb::error::BuildError),
 
@@ -2,16 +2,28 @@
 
 use super::{
     error::{Error, Result},
-    event_key, EventEntry, EventsDB,
+    event_key, org_key, promo_key, slug_key, stats_key, subscription_key, webhook_key, EventEntry,
+    EventsDB, OrgEntry, StatCounter, Subscription, BLOCKLIST_KEY,
 };
 use async_trait::async_trait;
-use std::{collections::HashMap, sync::Arc};
+use shared::{AdminDayStats, Blocklist, PromoCode};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::Arc,
+};
 use tokio::sync::Mutex;
 use tracing::instrument;
 
 #[derive(Default)]
 pub struct InMemoryEventsDB {
     pub db: Arc<Mutex<HashMap<String, EventEntry>>>,
+    pub slugs: Arc<Mutex<HashMap<String, String>>>,
+    pub subscriptions: Arc<Mutex<HashMap<String, Subscription>>>,
+    pub webhooks: Arc<Mutex<HashMap<String, String>>>,
+    pub promos: Arc<Mutex<HashMap<String, PromoCode>>>,
+    pub orgs: Arc<Mutex<HashMap<String, OrgEntry>>>,
+    pub stats: Arc<Mutex<HashMap<String, AdminDayStats>>>,
+    pub blocklist: Arc<Mutex<HashMap<String, Blocklist>>>,
 }
 
 #[async_trait]
@@ -45,4 +57,190 @@ impl EventsDB for InMemoryEventsDB {
 
         Ok(())
     }
+
+    #[instrument(skip(self), err)]
+    #[allow(clippy::significant_drop_tightening)]
+    async fn claim_slug(&self, slug: &str, event: &str) -> Result<()> {
+        let mut slugs = self.slugs.lock().await;
+
+        let holder = slugs
+            .entry(slug_key(slug))
+            .or_insert_with(|| event.to_string());
+
+        if holder.as_str() != event {
+            return Err(Error::Concurrency);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_slug(&self, slug: &str) -> Result<String> {
+        let slugs = self.slugs.lock().await;
+
+        slugs
+            .get(&slug_key(slug))
+            .cloned()
+            .ok_or(Error::ItemNotFound)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn put_subscription(&self, account: &str, subscription: Subscription) -> Result<()> {
+        self.subscriptions
+            .lock()
+            .await
+            .insert(subscription_key(account), subscription);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_subscription(&self, account: &str) -> Result<Subscription> {
+        let subscriptions = self.subscriptions.lock().await;
+
+        subscriptions
+            .get(&subscription_key(account))
+            .cloned()
+            .ok_or(Error::ItemNotFound)
+    }
+
+    #[instrument(skip(self, payload), err)]
+    #[allow(clippy::significant_drop_tightening)]
+    async fn put_webhook(&self, id: &str, payload: String, _received_unix: i64) -> Result<()> {
+        let mut webhooks = self.webhooks.lock().await;
+
+        match webhooks.entry(webhook_key(id)) {
+            Entry::Occupied(_) => Err(Error::Concurrency),
+            Entry::Vacant(entry) => {
+                entry.insert(payload);
+                Ok(())
+            }
+        }
+    }
+
+    #[instrument(skip(self), err)]
+    #[allow(clippy::significant_drop_tightening)]
+    async fn add_promo(&self, promo: PromoCode) -> Result<()> {
+        let mut promos = self.promos.lock().await;
+
+        match promos.entry(promo_key(&promo.code)) {
+            Entry::Occupied(_) => Err(Error::Concurrency),
+            Entry::Vacant(entry) => {
+                entry.insert(promo);
+                Ok(())
+            }
+        }
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_promo(&self, code: &str) -> Result<PromoCode> {
+        let promos = self.promos.lock().await;
+
+        promos
+            .get(&promo_key(code))
+            .cloned()
+            .ok_or(Error::ItemNotFound)
+    }
+
+    #[instrument(skip(self), err)]
+    #[allow(clippy::significant_drop_tightening)]
+    async fn redeem_promo(&self, code: &str) -> Result<()> {
+        let mut promos = self.promos.lock().await;
+
+        let promo = promos
+            .get_mut(&promo_key(code))
+            .ok_or(Error::ItemNotFound)?;
+
+        if promo.is_used_up() {
+            return Err(Error::Concurrency);
+        }
+
+        promo.uses = promo.uses.saturating_add(1);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_org(&self, id: &str) -> Result<OrgEntry> {
+        let orgs = self.orgs.lock().await;
+
+        orgs.get(&org_key(id)).cloned().ok_or(Error::ItemNotFound)
+    }
+
+    #[instrument(skip(self), err)]
+    #[allow(clippy::significant_drop_tightening)]
+    async fn put_org(&self, org: OrgEntry) -> Result<()> {
+        let mut orgs = self.orgs.lock().await;
+
+        match orgs.entry(org_key(&org.org.id)) {
+            Entry::Occupied(mut entry) => {
+                if org.version <= entry.get().version {
+                    return Err(Error::Concurrency);
+                }
+                entry.insert(org);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(org);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    #[allow(clippy::significant_drop_tightening)]
+    async fn count_stat(&self, day: &str, counter: StatCounter) -> Result<()> {
+        let mut stats = self.stats.lock().await;
+
+        let day_stats = stats
+            .entry(stats_key(day))
+            .or_insert_with(|| AdminDayStats {
+                day: day.to_string(),
+                ..Default::default()
+            });
+
+        let count = match counter {
+            StatCounter::EventCreated => &mut day_stats.events,
+            StatCounter::PremiumUpgrade => &mut day_stats.upgrades,
+        };
+        *count = count.saturating_add(1);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_stats(&self, day: &str) -> Result<AdminDayStats> {
+        let stats = self.stats.lock().await;
+
+        stats
+            .get(&stats_key(day))
+            .cloned()
+            .ok_or(Error::ItemNotFound)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_blocklist(&self) -> Result<Blocklist> {
+        let blocklist = self.blocklist.lock().await;
+
+        blocklist
+            .get(BLOCKLIST_KEY)
+            .cloned()
+            .ok_or(Error::ItemNotFound)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn put_blocklist(&self, blocklist: Blocklist) -> Result<()> {
+        self.blocklist
+            .lock()
+            .await
+            .insert(BLOCKLIST_KEY.to_string(), blocklist);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete_webhook(&self, id: &str) -> Result<()> {
+        self.webhooks.lock().await.remove(&webhook_key(id));
+        Ok(())
+    }
 }
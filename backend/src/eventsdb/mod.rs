@@ -9,15 +9,70 @@ pub use error::{Error, Result};
 pub use in_memory::InMemoryEventsDB;
 
 use async_trait::async_trait;
+use shared::{AdminDayStats, Blocklist, PromoCode};
 
-pub use self::types::{ApiEventInfo, EventEntry, PremiumOrder};
+pub use self::types::{
+    ApiEventInfo, EventEntry, OrgEntry, OrgMemberEntry, Organization, PremiumOrder, QueuedSpeaker,
+    SpeakerQueueState, StatCounter, Subscription,
+};
 
 pub fn event_key(key: &str) -> String {
     format!("events/ev-{key}.json")
 }
 
+pub fn slug_key(slug: &str) -> String {
+    format!("slugs/{slug}")
+}
+
+pub fn subscription_key(account: &str) -> String {
+    format!("subscriptions/{account}")
+}
+
+pub fn webhook_key(id: &str) -> String {
+    format!("webhooks/{id}")
+}
+
+pub fn promo_key(code: &str) -> String {
+    format!("promos/{code}")
+}
+
+pub fn org_key(id: &str) -> String {
+    format!("orgs/{id}")
+}
+
+pub fn stats_key(day: &str) -> String {
+    format!("stats/{day}")
+}
+
+pub const BLOCKLIST_KEY: &str = "blocklist";
+
 #[async_trait]
 pub trait EventsDB: Send + Sync {
     async fn get(&self, key: &str) -> Result<EventEntry>;
     async fn put(&self, event: EventEntry) -> Result<()>;
+    /// reserves `slug` for `event`, `Error::Concurrency` if another event holds it already
+    async fn claim_slug(&self, slug: &str, event: &str) -> Result<()>;
+    /// public token of the event holding `slug`
+    async fn get_slug(&self, slug: &str) -> Result<String>;
+    async fn put_subscription(&self, account: &str, subscription: Subscription) -> Result<()>;
+    async fn get_subscription(&self, account: &str) -> Result<Subscription>;
+    /// persists the raw payload of webhook delivery `id`, `Error::Concurrency` if it was stored already
+    async fn put_webhook(&self, id: &str, payload: String, received_unix: i64) -> Result<()>;
+    /// stores a new promo code, `Error::Concurrency` if the code exists already
+    async fn add_promo(&self, promo: PromoCode) -> Result<()>;
+    async fn get_promo(&self, code: &str) -> Result<PromoCode>;
+    /// counts a purchase made with `code`, `Error::Concurrency` once it is used up
+    async fn redeem_promo(&self, code: &str) -> Result<()>;
+    async fn get_org(&self, id: &str) -> Result<OrgEntry>;
+    /// `Error::Concurrency` if the org changed since `org.version` was read
+    async fn put_org(&self, org: OrgEntry) -> Result<()>;
+    /// increments `counter` of `day` (utc, `YYYY-MM-DD`)
+    async fn count_stat(&self, day: &str, counter: StatCounter) -> Result<()>;
+    /// `Error::ItemNotFound` if nothing was counted that day
+    async fn get_stats(&self, day: &str) -> Result<AdminDayStats>;
+    /// `Error::ItemNotFound` if nothing was blocked yet
+    async fn get_blocklist(&self) -> Result<Blocklist>;
+    async fn put_blocklist(&self, blocklist: Blocklist) -> Result<()>;
+    /// forgets webhook delivery `id` so a redelivery gets processed again
+    async fn delete_webhook(&self, id: &str) -> Result<()>;
 }
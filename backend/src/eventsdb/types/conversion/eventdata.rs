@@ -1,12 +1,17 @@
 use super::AttributeMap;
 use crate::eventsdb::Error;
 use aws_sdk_dynamodb::types::AttributeValue;
+use serde_dynamo::{from_item, to_item};
 use shared::EventData;
 
 const ATTR_EVENT_DATA_NAME: &str = "name";
 const ATTR_EVENT_DATA_DESC: &str = "desc";
 const ATTR_EVENT_DATA_URL_SHORT: &str = "short_url";
 const ATTR_EVENT_DATA_URL_LONG: &str = "long_url";
+const ATTR_EVENT_DATA_START: &str = "start";
+const ATTR_EVENT_DATA_END: &str = "end";
+const ATTR_EVENT_DATA_TIMEZONE: &str = "timezone";
+const ATTR_EVENT_DATA_BRANDING: &str = "branding";
 
 pub fn eventdata_to_attributes(value: EventData) -> AttributeMap {
     let mut map = AttributeMap::new();
@@ -31,6 +36,31 @@ pub fn eventdata_to_attributes(value: EventData) -> AttributeMap {
         map.insert(ATTR_EVENT_DATA_URL_LONG.into(), AttributeValue::S(long_url));
     }
 
+    if let Some(start) = value.start_unix {
+        map.insert(
+            ATTR_EVENT_DATA_START.into(),
+            AttributeValue::N(start.to_string()),
+        );
+    }
+
+    if let Some(end) = value.end_unix {
+        map.insert(
+            ATTR_EVENT_DATA_END.into(),
+            AttributeValue::N(end.to_string()),
+        );
+    }
+
+    if let Some(timezone) = value.timezone {
+        map.insert(ATTR_EVENT_DATA_TIMEZONE.into(), AttributeValue::S(timezone));
+    }
+
+    if let Some(branding) = value.branding {
+        map.insert(
+            ATTR_EVENT_DATA_BRANDING.into(),
+            AttributeValue::M(to_item(branding).unwrap_or_default()),
+        );
+    }
+
     map
 }
 
@@ -54,10 +84,33 @@ pub fn attributes_to_eventdata(value: &AttributeMap) -> Result<EventData, super:
         .get(ATTR_EVENT_DATA_URL_LONG)
         .and_then(|value| value.as_s().ok().cloned());
 
+    let start_unix = value
+        .get(ATTR_EVENT_DATA_START)
+        .and_then(|value| value.as_n().ok())
+        .and_then(|value| value.parse::<i64>().ok());
+
+    let end_unix = value
+        .get(ATTR_EVENT_DATA_END)
+        .and_then(|value| value.as_n().ok())
+        .and_then(|value| value.parse::<i64>().ok());
+
+    let timezone = value
+        .get(ATTR_EVENT_DATA_TIMEZONE)
+        .and_then(|value| value.as_s().ok().cloned());
+
+    let branding = value
+        .get(ATTR_EVENT_DATA_BRANDING)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok());
+
     Ok(EventData {
         name,
         description,
         short_url,
         long_url,
+        start_unix,
+        end_unix,
+        timezone,
+        branding,
     })
 }
@@ -10,11 +10,14 @@ use self::{
     tokens::{attributes_to_tokens, tokens_to_attributes},
 };
 
-use super::{ApiEventInfo, AttributeMap, PremiumOrder};
+use super::{ApiEventInfo, AttributeMap, PremiumOrder, SpeakerQueueState};
 use crate::eventsdb::Error;
 use aws_sdk_dynamodb::types::AttributeValue;
 use serde_dynamo::{from_item, to_item};
-use shared::{EventPassword, EventState, EventTags, States};
+use shared::{
+    BlockedTerms, EventPassword, EventState, EventTags, EventTheme, NotificationSettings,
+    QuestionLanguages, QuestionLength, QuestionLimits, QuestionNames, States,
+};
 
 const ATTR_EVENT_INFO_LAST_EDIT: &str = "last_edit";
 const ATTR_EVENT_INFO_DELETE_TIME: &str = "delete_time";
@@ -29,6 +32,36 @@ const ATTR_EVENT_INFO_PREMIUM: &str = "premium_id";
 const ATTR_EVENT_INFO_PASSWORD: &str = "password";
 const ATTR_EVENT_INFO_CONTEXT: &str = "ctx";
 const ATTR_EVENT_INFO_TAGS: &str = "tags";
+const ATTR_EVENT_INFO_QUESTION_NAMES: &str = "q_names";
+const ATTR_EVENT_INFO_QUESTION_LENGTH: &str = "q_length";
+const ATTR_EVENT_INFO_DOWNVOTES: &str = "downvotes";
+const ATTR_EVENT_INFO_POLLS: &str = "polls";
+const ATTR_EVENT_INFO_WORD_CLOUDS: &str = "word_clouds";
+const ATTR_EVENT_INFO_SESSIONS: &str = "sessions";
+const ATTR_EVENT_INFO_SURVEY: &str = "survey";
+const ATTR_EVENT_INFO_SURVEY_RESPONSES: &str = "survey_responses";
+const ATTR_EVENT_INFO_SERIES: &str = "series";
+const ATTR_EVENT_INFO_SLUG: &str = "slug";
+const ATTR_EVENT_INFO_THEME: &str = "theme";
+const ATTR_EVENT_INFO_HIDE_BRANDING: &str = "hide_branding";
+const ATTR_EVENT_INFO_RECEIPT: &str = "receipt";
+const ATTR_EVENT_INFO_TRIAL_END: &str = "trial_end_unix";
+const ATTR_EVENT_INFO_PEAK_VIEWERS: &str = "peak_viewers";
+const ATTR_EVENT_INFO_PUSH_SUBSCRIPTIONS: &str = "push_subscriptions";
+const ATTR_EVENT_INFO_NOTIFICATIONS: &str = "notifications";
+const ATTR_EVENT_INFO_SPEAKER_QUEUE: &str = "speaker_queue";
+const ATTR_EVENT_INFO_TIMER: &str = "timer";
+const ATTR_EVENT_INFO_QUESTION_COOLDOWN: &str = "q_cooldown";
+const ATTR_EVENT_INFO_LAST_QUESTIONS: &str = "last_questions";
+const ATTR_EVENT_INFO_QUESTION_LIMITS: &str = "q_limits";
+const ATTR_EVENT_INFO_VIEWER_CAPACITY: &str = "viewer_capacity";
+const ATTR_EVENT_INFO_FLAGGED_LIKES: &str = "flagged_likes";
+const ATTR_EVENT_INFO_PARTICIPANT_QUESTIONS: &str = "participant_questions";
+const ATTR_EVENT_INFO_SHADOW_BANNED: &str = "shadow_banned";
+const ATTR_EVENT_INFO_BLOCKED_TERMS: &str = "blocked_terms";
+const ATTR_EVENT_INFO_QUESTION_LANGUAGES: &str = "question_languages";
+const ATTR_EVENT_INFO_QUESTION_SUMMARY: &str = "question_summary";
+const ATTR_EVENT_INFO_SENTIMENTS: &str = "sentiments";
 
 pub fn event_to_attributes(value: ApiEventInfo) -> AttributeMap {
     let vec = vec![
@@ -56,6 +89,18 @@ pub fn event_to_attributes(value: ApiEventInfo) -> AttributeMap {
             ATTR_EVENT_INFO_DO_SCREENING.into(),
             AttributeValue::Bool(value.do_screening),
         ),
+        (
+            ATTR_EVENT_INFO_DOWNVOTES.into(),
+            AttributeValue::Bool(value.downvotes),
+        ),
+        (
+            ATTR_EVENT_INFO_SURVEY.into(),
+            AttributeValue::Bool(value.survey),
+        ),
+        (
+            ATTR_EVENT_INFO_HIDE_BRANDING.into(),
+            AttributeValue::Bool(value.hide_branding),
+        ),
         (
             ATTR_EVENT_INFO_CREATE_TIME.into(),
             AttributeValue::N(value.create_time_unix.to_string()),
@@ -76,9 +121,263 @@ pub fn event_to_attributes(value: ApiEventInfo) -> AttributeMap {
             ATTR_EVENT_INFO_TAGS.into(),
             AttributeValue::M(to_item(value.tags).unwrap_or_default()),
         ),
+        (
+            ATTR_EVENT_INFO_QUESTION_NAMES.into(),
+            AttributeValue::N(value.question_names.to_value().to_string()),
+        ),
+        (
+            ATTR_EVENT_INFO_THEME.into(),
+            AttributeValue::N(value.theme.to_value().to_string()),
+        ),
+        (
+            ATTR_EVENT_INFO_QUESTION_LENGTH.into(),
+            AttributeValue::M(to_item(value.question_length).unwrap_or_default()),
+        ),
     ];
     let mut map: AttributeMap = vec.into_iter().collect();
 
+    if !value.polls.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_POLLS.into(),
+            AttributeValue::L(
+                value
+                    .polls
+                    .into_iter()
+                    .filter_map(|poll| to_item(poll).ok())
+                    .map(AttributeValue::M)
+                    .collect(),
+            ),
+        );
+    }
+
+    if !value.word_clouds.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_WORD_CLOUDS.into(),
+            AttributeValue::L(
+                value
+                    .word_clouds
+                    .into_iter()
+                    .filter_map(|cloud| to_item(cloud).ok())
+                    .map(AttributeValue::M)
+                    .collect(),
+            ),
+        );
+    }
+
+    if !value.sessions.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_SESSIONS.into(),
+            AttributeValue::L(
+                value
+                    .sessions
+                    .into_iter()
+                    .filter_map(|session| to_item(session).ok())
+                    .map(AttributeValue::M)
+                    .collect(),
+            ),
+        );
+    }
+
+    if !value.survey_responses.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_SURVEY_RESPONSES.into(),
+            AttributeValue::L(
+                value
+                    .survey_responses
+                    .into_iter()
+                    .filter_map(|response| to_item(response).ok())
+                    .map(AttributeValue::M)
+                    .collect(),
+            ),
+        );
+    }
+
+    if let Some(series) = value.series {
+        map.insert(
+            ATTR_EVENT_INFO_SERIES.into(),
+            AttributeValue::M(to_item(series).unwrap_or_default()),
+        );
+    }
+
+    if let Some(slug) = value.slug {
+        map.insert(ATTR_EVENT_INFO_SLUG.into(), AttributeValue::S(slug));
+    }
+
+    if let Some(receipt) = value.receipt {
+        map.insert(
+            ATTR_EVENT_INFO_RECEIPT.into(),
+            AttributeValue::M(to_item(receipt).unwrap_or_default()),
+        );
+    }
+
+    if let Some(trial_end) = value.trial_end_unix {
+        map.insert(
+            ATTR_EVENT_INFO_TRIAL_END.into(),
+            AttributeValue::N(trial_end.to_string()),
+        );
+    }
+
+    if value.peak_viewers > 0 {
+        map.insert(
+            ATTR_EVENT_INFO_PEAK_VIEWERS.into(),
+            AttributeValue::N(value.peak_viewers.to_string()),
+        );
+    }
+
+    if !value.push_subscriptions.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_PUSH_SUBSCRIPTIONS.into(),
+            AttributeValue::L(
+                value
+                    .push_subscriptions
+                    .into_iter()
+                    .filter_map(|subscription| to_item(subscription).ok())
+                    .map(AttributeValue::M)
+                    .collect(),
+            ),
+        );
+    }
+
+    if value.notifications != NotificationSettings::default() {
+        map.insert(
+            ATTR_EVENT_INFO_NOTIFICATIONS.into(),
+            AttributeValue::M(to_item(value.notifications).unwrap_or_default()),
+        );
+    }
+
+    if value.speaker_queue != SpeakerQueueState::default() {
+        map.insert(
+            ATTR_EVENT_INFO_SPEAKER_QUEUE.into(),
+            AttributeValue::M(to_item(value.speaker_queue).unwrap_or_default()),
+        );
+    }
+
+    if let Some(timer) = value.timer {
+        map.insert(
+            ATTR_EVENT_INFO_TIMER.into(),
+            AttributeValue::M(to_item(timer).unwrap_or_default()),
+        );
+    }
+
+    if value.question_cooldown_mins > 0 {
+        map.insert(
+            ATTR_EVENT_INFO_QUESTION_COOLDOWN.into(),
+            AttributeValue::N(value.question_cooldown_mins.to_string()),
+        );
+    }
+
+    if !value.last_questions.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_LAST_QUESTIONS.into(),
+            AttributeValue::M(
+                value
+                    .last_questions
+                    .into_iter()
+                    .map(|(participant, time)| (participant, AttributeValue::N(time.to_string())))
+                    .collect(),
+            ),
+        );
+    }
+
+    if value.question_limits != QuestionLimits::default() {
+        map.insert(
+            ATTR_EVENT_INFO_QUESTION_LIMITS.into(),
+            AttributeValue::M(to_item(value.question_limits).unwrap_or_default()),
+        );
+    }
+
+    if value.blocked_terms != BlockedTerms::default() {
+        map.insert(
+            ATTR_EVENT_INFO_BLOCKED_TERMS.into(),
+            AttributeValue::M(to_item(value.blocked_terms).unwrap_or_default()),
+        );
+    }
+
+    if value.question_languages.is_enabled() {
+        map.insert(
+            ATTR_EVENT_INFO_QUESTION_LANGUAGES.into(),
+            AttributeValue::M(to_item(value.question_languages).unwrap_or_default()),
+        );
+    }
+
+    if let Some(summary) = value.question_summary {
+        map.insert(
+            ATTR_EVENT_INFO_QUESTION_SUMMARY.into(),
+            AttributeValue::M(to_item(summary).unwrap_or_default()),
+        );
+    }
+
+    if value.viewer_capacity > 0 {
+        map.insert(
+            ATTR_EVENT_INFO_VIEWER_CAPACITY.into(),
+            AttributeValue::N(value.viewer_capacity.to_string()),
+        );
+    }
+
+    if !value.flagged_likes.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_FLAGGED_LIKES.into(),
+            AttributeValue::L(
+                value
+                    .flagged_likes
+                    .into_iter()
+                    .filter_map(|flagged| to_item(flagged).ok())
+                    .map(AttributeValue::M)
+                    .collect(),
+            ),
+        );
+    }
+
+    if !value.sentiments.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_SENTIMENTS.into(),
+            AttributeValue::L(
+                value
+                    .sentiments
+                    .into_iter()
+                    .filter_map(|sentiment| to_item(sentiment).ok())
+                    .map(AttributeValue::M)
+                    .collect(),
+            ),
+        );
+    }
+
+    if !value.participant_questions.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_PARTICIPANT_QUESTIONS.into(),
+            AttributeValue::M(
+                value
+                    .participant_questions
+                    .into_iter()
+                    .map(|(participant, questions)| {
+                        (
+                            participant,
+                            AttributeValue::L(
+                                questions
+                                    .into_iter()
+                                    .map(|id| AttributeValue::N(id.to_string()))
+                                    .collect(),
+                            ),
+                        )
+                    })
+                    .collect(),
+            ),
+        );
+    }
+
+    if !value.shadow_banned.is_empty() {
+        map.insert(
+            ATTR_EVENT_INFO_SHADOW_BANNED.into(),
+            AttributeValue::L(
+                value
+                    .shadow_banned
+                    .into_iter()
+                    .map(AttributeValue::S)
+                    .collect(),
+            ),
+        );
+    }
+
     if let Some(premium) = value.premium_id {
         map.insert(
             ATTR_EVENT_INFO_PREMIUM.into(),
@@ -147,6 +446,24 @@ pub fn attributes_to_event(value: &AttributeMap) -> Result<ApiEventInfo, super::
         .copied()
         .unwrap_or_default();
 
+    let downvotes = value
+        .get(ATTR_EVENT_INFO_DOWNVOTES)
+        .and_then(|val| val.as_bool().ok())
+        .copied()
+        .unwrap_or_default();
+
+    let survey = value
+        .get(ATTR_EVENT_INFO_SURVEY)
+        .and_then(|val| val.as_bool().ok())
+        .copied()
+        .unwrap_or_default();
+
+    let hide_branding = value
+        .get(ATTR_EVENT_INFO_HIDE_BRANDING)
+        .and_then(|val| val.as_bool().ok())
+        .copied()
+        .unwrap_or_default();
+
     let premium_receipt: Option<PremiumOrder> = value
         .get(ATTR_EVENT_INFO_PREMIUM)
         .and_then(|value| value.as_m().ok().cloned())
@@ -178,6 +495,236 @@ pub fn attributes_to_event(value: &AttributeMap) -> Result<ApiEventInfo, super::
         EventTags::default()
     };
 
+    let question_names = value
+        .get(ATTR_EVENT_INFO_QUESTION_NAMES)
+        .and_then(|val| val.as_n().ok())
+        .and_then(|val| val.parse::<u8>().ok())
+        .and_then(QuestionNames::from_value)
+        .unwrap_or_default();
+
+    let theme = value
+        .get(ATTR_EVENT_INFO_THEME)
+        .and_then(|val| val.as_n().ok())
+        .and_then(|val| val.parse::<u8>().ok())
+        .and_then(EventTheme::from_value)
+        .unwrap_or_default();
+
+    let question_length = if let Some(attr) = value.get(ATTR_EVENT_INFO_QUESTION_LENGTH) {
+        let map = attr
+            .as_m()
+            .map_err(|_| Error::MalformedObject(ATTR_EVENT_INFO_QUESTION_LENGTH.into()))?
+            .clone();
+
+        from_item(map).unwrap_or_default()
+    } else {
+        QuestionLength::default()
+    };
+
+    let polls = value
+        .get(ATTR_EVENT_INFO_POLLS)
+        .and_then(|value| value.as_l().ok())
+        .map(|polls| {
+            polls
+                .iter()
+                .filter_map(|poll| poll.as_m().ok().cloned())
+                .filter_map(|poll| from_item(poll).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let word_clouds = value
+        .get(ATTR_EVENT_INFO_WORD_CLOUDS)
+        .and_then(|value| value.as_l().ok())
+        .map(|clouds| {
+            clouds
+                .iter()
+                .filter_map(|cloud| cloud.as_m().ok().cloned())
+                .filter_map(|cloud| from_item(cloud).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let sessions = value
+        .get(ATTR_EVENT_INFO_SESSIONS)
+        .and_then(|value| value.as_l().ok())
+        .map(|sessions| {
+            sessions
+                .iter()
+                .filter_map(|session| session.as_m().ok().cloned())
+                .filter_map(|session| from_item(session).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let survey_responses = value
+        .get(ATTR_EVENT_INFO_SURVEY_RESPONSES)
+        .and_then(|value| value.as_l().ok())
+        .map(|responses| {
+            responses
+                .iter()
+                .filter_map(|response| response.as_m().ok().cloned())
+                .filter_map(|response| from_item(response).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let series = value
+        .get(ATTR_EVENT_INFO_SERIES)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok());
+
+    let slug = value
+        .get(ATTR_EVENT_INFO_SLUG)
+        .and_then(|value| value.as_s().ok().cloned());
+
+    let receipt = value
+        .get(ATTR_EVENT_INFO_RECEIPT)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok());
+
+    let trial_end_unix = value
+        .get(ATTR_EVENT_INFO_TRIAL_END)
+        .and_then(|value| value.as_n().ok())
+        .and_then(|value| value.parse::<i64>().ok());
+
+    let peak_viewers = value
+        .get(ATTR_EVENT_INFO_PEAK_VIEWERS)
+        .and_then(|value| value.as_n().ok())
+        .and_then(|value| value.parse::<i64>().ok())
+        .unwrap_or_default();
+
+    let push_subscriptions = value
+        .get(ATTR_EVENT_INFO_PUSH_SUBSCRIPTIONS)
+        .and_then(|value| value.as_l().ok())
+        .map(|subscriptions| {
+            subscriptions
+                .iter()
+                .filter_map(|subscription| subscription.as_m().ok().cloned())
+                .filter_map(|subscription| from_item(subscription).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let notifications = value
+        .get(ATTR_EVENT_INFO_NOTIFICATIONS)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok())
+        .unwrap_or_default();
+
+    let speaker_queue = value
+        .get(ATTR_EVENT_INFO_SPEAKER_QUEUE)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok())
+        .unwrap_or_default();
+
+    let timer = value
+        .get(ATTR_EVENT_INFO_TIMER)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok());
+
+    let question_cooldown_mins = value
+        .get(ATTR_EVENT_INFO_QUESTION_COOLDOWN)
+        .and_then(|value| value.as_n().ok())
+        .and_then(|value| value.parse::<u32>().ok())
+        .unwrap_or_default();
+
+    let last_questions = value
+        .get(ATTR_EVENT_INFO_LAST_QUESTIONS)
+        .and_then(|value| value.as_m().ok())
+        .map(|participants| {
+            participants
+                .iter()
+                .filter_map(|(participant, time)| {
+                    let time = time.as_n().ok()?.parse::<i64>().ok()?;
+                    Some((participant.clone(), time))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let question_limits = value
+        .get(ATTR_EVENT_INFO_QUESTION_LIMITS)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok())
+        .unwrap_or_default();
+
+    let blocked_terms = value
+        .get(ATTR_EVENT_INFO_BLOCKED_TERMS)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok())
+        .unwrap_or_default();
+
+    let question_languages = value
+        .get(ATTR_EVENT_INFO_QUESTION_LANGUAGES)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok())
+        .unwrap_or_default();
+
+    let question_summary = value
+        .get(ATTR_EVENT_INFO_QUESTION_SUMMARY)
+        .and_then(|value| value.as_m().ok().cloned())
+        .and_then(|value| from_item(value).ok());
+
+    let viewer_capacity = value
+        .get(ATTR_EVENT_INFO_VIEWER_CAPACITY)
+        .and_then(|value| value.as_n().ok())
+        .and_then(|value| value.parse::<u32>().ok())
+        .unwrap_or_default();
+
+    let flagged_likes = value
+        .get(ATTR_EVENT_INFO_FLAGGED_LIKES)
+        .and_then(|value| value.as_l().ok())
+        .map(|flagged| {
+            flagged
+                .iter()
+                .filter_map(|flagged| flagged.as_m().ok().cloned())
+                .filter_map(|flagged| from_item(flagged).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let sentiments = value
+        .get(ATTR_EVENT_INFO_SENTIMENTS)
+        .and_then(|value| value.as_l().ok())
+        .map(|sentiments| {
+            sentiments
+                .iter()
+                .filter_map(|sentiment| sentiment.as_m().ok().cloned())
+                .filter_map(|sentiment| from_item(sentiment).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let participant_questions = value
+        .get(ATTR_EVENT_INFO_PARTICIPANT_QUESTIONS)
+        .and_then(|value| value.as_m().ok())
+        .map(|participants| {
+            participants
+                .iter()
+                .filter_map(|(participant, questions)| {
+                    let questions = questions
+                        .as_l()
+                        .ok()?
+                        .iter()
+                        .filter_map(|id| id.as_n().ok()?.parse::<i64>().ok())
+                        .collect();
+                    Some((participant.clone(), questions))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let shadow_banned = value
+        .get(ATTR_EVENT_INFO_SHADOW_BANNED)
+        .and_then(|value| value.as_l().ok())
+        .map(|participants| {
+            participants
+                .iter()
+                .filter_map(|participant| participant.as_s().ok().cloned())
+                .collect()
+        })
+        .unwrap_or_default();
+
     Ok(ApiEventInfo {
         tokens,
         data,
@@ -192,6 +739,36 @@ pub fn attributes_to_event(value: &AttributeMap) -> Result<ApiEventInfo, super::
         premium_id: premium_receipt,
         context,
         tags,
+        question_names,
+        question_length,
+        downvotes,
+        polls,
+        word_clouds,
+        sessions,
+        survey,
+        survey_responses,
+        series,
+        slug,
+        theme,
+        hide_branding,
+        receipt,
+        trial_end_unix,
+        peak_viewers,
+        push_subscriptions,
+        notifications,
+        speaker_queue,
+        timer,
+        question_cooldown_mins,
+        last_questions,
+        question_limits,
+        viewer_capacity,
+        flagged_likes,
+        participant_questions,
+        shadow_banned,
+        blocked_terms,
+        question_languages,
+        question_summary,
+        sentiments,
     })
 }
 
@@ -202,11 +779,16 @@ mod test {
     use pretty_assertions::assert_eq;
     use serde_dynamo::{aws_sdk_dynamodb_1::to_item, from_item};
     use shared::{
-        ContextItem, EventData, EventState, EventTags, EventTokens, QuestionItem, States, Tag,
-        TagId,
+        BlockedTermsAction, ContextItem, EventBranding, EventData, EventSeries, EventState,
+        EventTags, EventTimer, EventTokens, FlaggedLikes, LanguageAction, Poll, PollOption,
+        QuestionItem, QuestionSentiment, QuestionSummary, Reactions, ReceiptDetails, Sentiment,
+        SeriesOccurrence, Session, Speaker, States, SurveyResponse, Tag, TagId, WebPushKeys,
+        WebPushSubscription, WordCloud, WordCount,
     };
 
-    use crate::eventsdb::{types::AttributeMap, ApiEventInfo};
+    use std::collections::HashMap;
+
+    use crate::eventsdb::{types::AttributeMap, ApiEventInfo, QueuedSpeaker};
 
     #[test]
     #[tracing_test::traced_test]
@@ -221,6 +803,15 @@ mod test {
                 description: String::from("desc"),
                 short_url: String::from(""),
                 long_url: Some(String::from("foo")),
+                start_unix: Some(10),
+                end_unix: Some(20),
+                timezone: Some(String::from("Europe/Berlin")),
+                branding: Some(EventBranding {
+                    color: Some(String::from("#ff0000")),
+                    banner: Some(String::from("banner")),
+                    logo_url: None,
+                    logo: Some(String::from("logos/public/logo")),
+                }),
             },
             create_time_unix: 1,
             delete_time_unix: 0,
@@ -237,6 +828,15 @@ mod test {
                 screening: true,
                 create_time_unix: 3,
                 tag: Some(TagId(0)),
+                name: Some(String::from("name")),
+                attachment: Some(String::from("attachments/a/b")),
+                reactions: Reactions([1, 0, 2, 0]),
+                downvotes: 3,
+                session: Some(0),
+                pinned: true,
+                shadowed: true,
+                note: Some(String::from("answer in part 2")),
+                merged_into: Some(1),
             }],
             do_screening: false,
             state: EventState {
@@ -253,6 +853,124 @@ mod test {
                 }],
                 current_tag: Some(TagId(0)),
             },
+            question_names: QuestionNames::Optional,
+            question_length: QuestionLength { min: 20, max: 300 },
+            downvotes: true,
+            polls: vec![Poll {
+                id: 0,
+                question: String::from("poll"),
+                options: vec![
+                    PollOption {
+                        text: String::from("a"),
+                        votes: 2,
+                    },
+                    PollOption {
+                        text: String::from("b"),
+                        votes: 0,
+                    },
+                ],
+                closed: true,
+                create_time_unix: 4,
+            }],
+            word_clouds: vec![WordCloud {
+                id: 0,
+                prompt: String::from("cloud"),
+                words: vec![WordCount {
+                    text: String::from("rust"),
+                    count: 3,
+                }],
+                closed: false,
+                create_time_unix: 5,
+            }],
+            sessions: vec![Session {
+                id: 0,
+                name: String::from("keynote"),
+                state: EventState {
+                    state: States::VotingOnly,
+                },
+            }],
+            survey: true,
+            survey_responses: vec![SurveyResponse {
+                rating: 4,
+                comment: Some(String::from("great")),
+                create_time_unix: 6,
+            }],
+            series: Some(EventSeries {
+                id: String::from("series"),
+                interval_days: 7,
+                occurrences: vec![SeriesOccurrence {
+                    public_token: String::from("series"),
+                    start_unix: 7,
+                }],
+            }),
+            slug: Some(String::from("slug")),
+            theme: EventTheme::Dark,
+            hide_branding: true,
+            receipt: Some(ReceiptDetails {
+                name: Some(String::from("Live-Ask GmbH")),
+                address: None,
+                vat_id: Some(String::from("DE123")),
+            }),
+            trial_end_unix: Some(6),
+            peak_viewers: 42,
+            push_subscriptions: vec![WebPushSubscription {
+                endpoint: String::from("https://push.example.com/1"),
+                keys: WebPushKeys {
+                    p256dh: String::from("p256dh"),
+                    auth: String::from("auth"),
+                },
+            }],
+            notifications: NotificationSettings {
+                email: Some(String::from("mod@live-ask.com")),
+                push: false,
+                likes_threshold: 5,
+            },
+            speaker_queue: SpeakerQueueState {
+                speakers: vec![QueuedSpeaker {
+                    speaker: Speaker {
+                        id: 1,
+                        name: String::from("Ada"),
+                        create_time_unix: 8,
+                    },
+                    token: String::from("token"),
+                }],
+                current: Some(1),
+                next_id: 2,
+            },
+            timer: Some(EventTimer {
+                end_unix: 9,
+                label: String::from("left for questions"),
+            }),
+            question_cooldown_mins: 5,
+            last_questions: HashMap::from([(String::from("participant"), 10)]),
+            question_limits: QuestionLimits {
+                total: 300,
+                per_minute: 20,
+            },
+            viewer_capacity: 250,
+            flagged_likes: vec![FlaggedLikes {
+                question_id: 0,
+                discounted: 12,
+            }],
+            participant_questions: HashMap::from([(String::from("participant"), vec![0])]),
+            shadow_banned: vec![String::from("participant")],
+            blocked_terms: BlockedTerms {
+                terms: vec![String::from("spam")],
+                action: BlockedTermsAction::Hide,
+            },
+            question_languages: QuestionLanguages {
+                languages: vec![String::from("en"), String::from("de")],
+                action: LanguageAction::Reject,
+            },
+            question_summary: Some(QuestionSummary {
+                text: String::from("top themes: pricing, roadmap"),
+                questions: 2,
+                create_time_unix: 1,
+            }),
+            sentiments: vec![QuestionSentiment {
+                question_id: 0,
+                sentiment: Sentiment::Negative,
+            }],
         };
 
         let map: AttributeMap = event_to_attributes(entry.clone());
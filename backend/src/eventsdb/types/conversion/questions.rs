@@ -1,7 +1,7 @@
 use super::AttributeMap;
 use crate::eventsdb::Error;
 use aws_sdk_dynamodb::types::AttributeValue;
-use shared::{QuestionItem, TagId};
+use shared::{QuestionItem, Reactions, TagId};
 
 pub fn questions_to_attributes(value: Vec<QuestionItem>) -> Vec<AttributeValue> {
     value
@@ -33,6 +33,15 @@ const ATTR_QUESTION_ANSWERED: &str = "answered";
 const ATTR_QUESTION_SCREENING: &str = "screening";
 const ATTR_QUESTION_HIDDEN: &str = "hidden";
 const ATTR_QUESTION_TAG: &str = "tag";
+const ATTR_QUESTION_NAME: &str = "name";
+const ATTR_QUESTION_ATTACHMENT: &str = "attachment";
+const ATTR_QUESTION_REACTIONS: &str = "reactions";
+const ATTR_QUESTION_DOWNVOTES: &str = "downvotes";
+const ATTR_QUESTION_SESSION: &str = "session";
+const ATTR_QUESTION_PINNED: &str = "pinned";
+const ATTR_QUESTION_SHADOWED: &str = "shadowed";
+const ATTR_QUESTION_NOTE: &str = "note";
+const ATTR_QUESTION_MERGED_INTO: &str = "merged_into";
 
 fn question_to_attributes(value: QuestionItem) -> AttributeMap {
     let mut map = AttributeMap::new();
@@ -60,16 +69,79 @@ fn question_to_attributes(value: QuestionItem) -> AttributeMap {
     if value.screening {
         map.insert(ATTR_QUESTION_SCREENING.into(), AttributeValue::Bool(true));
     }
+    if value.pinned {
+        map.insert(ATTR_QUESTION_PINNED.into(), AttributeValue::Bool(true));
+    }
+    if value.shadowed {
+        map.insert(ATTR_QUESTION_SHADOWED.into(), AttributeValue::Bool(true));
+    }
     if let Some(tag) = value.tag {
         map.insert(
             ATTR_QUESTION_TAG.into(),
             AttributeValue::N(tag.0.to_string()),
         );
     }
+    if let Some(name) = value.name {
+        map.insert(ATTR_QUESTION_NAME.into(), AttributeValue::S(name));
+    }
+    if let Some(attachment) = value.attachment {
+        map.insert(
+            ATTR_QUESTION_ATTACHMENT.into(),
+            AttributeValue::S(attachment),
+        );
+    }
+    if let Some(note) = value.note {
+        map.insert(ATTR_QUESTION_NOTE.into(), AttributeValue::S(note));
+    }
+    if value.downvotes > 0 {
+        map.insert(
+            ATTR_QUESTION_DOWNVOTES.into(),
+            AttributeValue::N(value.downvotes.to_string()),
+        );
+    }
+    if let Some(session) = value.session {
+        map.insert(
+            ATTR_QUESTION_SESSION.into(),
+            AttributeValue::N(session.to_string()),
+        );
+    }
+    if let Some(merged_into) = value.merged_into {
+        map.insert(
+            ATTR_QUESTION_MERGED_INTO.into(),
+            AttributeValue::N(merged_into.to_string()),
+        );
+    }
+    if !value.reactions.is_empty() {
+        map.insert(
+            ATTR_QUESTION_REACTIONS.into(),
+            AttributeValue::L(
+                value
+                    .reactions
+                    .0
+                    .iter()
+                    .map(|count| AttributeValue::N(count.to_string()))
+                    .collect(),
+            ),
+        );
+    }
 
     map
 }
 
+fn attributes_to_reactions(value: &[AttributeValue]) -> Reactions {
+    let mut reactions = Reactions::default();
+
+    for (count, value) in reactions.0.iter_mut().zip(value) {
+        *count = value
+            .as_n()
+            .ok()
+            .and_then(|value| value.parse::<i32>().ok())
+            .unwrap_or_default();
+    }
+
+    reactions
+}
+
 fn attributes_to_question(value: &AttributeMap) -> Result<QuestionItem, super::Error> {
     let id = value[ATTR_QUESTION_ID]
         .as_n()
@@ -106,12 +178,56 @@ fn attributes_to_question(value: &AttributeMap) -> Result<QuestionItem, super::E
         .and_then(|value| value.as_bool().ok().copied())
         .unwrap_or_default();
 
+    let pinned = value
+        .get(ATTR_QUESTION_PINNED)
+        .and_then(|value| value.as_bool().ok().copied())
+        .unwrap_or_default();
+
+    let shadowed = value
+        .get(ATTR_QUESTION_SHADOWED)
+        .and_then(|value| value.as_bool().ok().copied())
+        .unwrap_or_default();
+
     let tag = value
         .get(ATTR_QUESTION_TAG)
         .and_then(|v| v.as_n().ok())
         .and_then(|v| v.parse::<usize>().ok())
         .map(TagId);
 
+    let name = value
+        .get(ATTR_QUESTION_NAME)
+        .and_then(|value| value.as_s().ok().cloned());
+
+    let attachment = value
+        .get(ATTR_QUESTION_ATTACHMENT)
+        .and_then(|value| value.as_s().ok().cloned());
+
+    let note = value
+        .get(ATTR_QUESTION_NOTE)
+        .and_then(|value| value.as_s().ok().cloned());
+
+    let reactions = value
+        .get(ATTR_QUESTION_REACTIONS)
+        .and_then(|value| value.as_l().ok())
+        .map(|value| attributes_to_reactions(value.as_slice()))
+        .unwrap_or_default();
+
+    let downvotes = value
+        .get(ATTR_QUESTION_DOWNVOTES)
+        .and_then(|v| v.as_n().ok())
+        .and_then(|v| v.parse::<i32>().ok())
+        .unwrap_or_default();
+
+    let session = value
+        .get(ATTR_QUESTION_SESSION)
+        .and_then(|v| v.as_n().ok())
+        .and_then(|v| v.parse::<i64>().ok());
+
+    let merged_into = value
+        .get(ATTR_QUESTION_MERGED_INTO)
+        .and_then(|v| v.as_n().ok())
+        .and_then(|v| v.parse::<i64>().ok());
+
     Ok(QuestionItem {
         id,
         likes,
@@ -121,5 +237,14 @@ fn attributes_to_question(value: &AttributeMap) -> Result<QuestionItem, super::E
         screening,
         create_time_unix,
         tag,
+        name,
+        attachment,
+        reactions,
+        downvotes,
+        session,
+        pinned,
+        shadowed,
+        note,
+        merged_into,
     })
 }
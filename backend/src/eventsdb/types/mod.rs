@@ -6,8 +6,11 @@ use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
 use serde_dynamo::from_item;
 use shared::{
-    ContextItem, EventData, EventFlags, EventInfo, EventPassword, EventState, EventTags,
-    EventTokens, QuestionItem,
+    BlockedTerms, ContextItem, Entitlements, EventData, EventFlags, EventInfo, EventPassword,
+    EventSeries, EventState, EventTags, EventTheme, EventTimer, EventTokens, FlaggedLikes,
+    NotificationSettings, OrgRole, Poll, QuestionItem, QuestionLanguages, QuestionLength,
+    QuestionLimits, QuestionNames, QuestionSentiment, QuestionSummary, Reactions, ReceiptDetails,
+    Session, Speaker, SpeakerQueue, SurveyResponse, WebPushSubscription, WordCloud,
 };
 use std::collections::HashMap;
 
@@ -19,6 +22,101 @@ use super::{event_key, Error};
 pub enum PremiumOrder {
     PaypalOrderId(String),
     StripeSessionId(String),
+    /// created while the organizer's subscription was active
+    StripeSubscriptionId(String),
+}
+
+/// recurring subscription of an organizer, keyed by its secret account token
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct Subscription {
+    pub stripe_id: String,
+    pub active: bool,
+    /// creation time of the stripe event this state comes from, older deliveries are stale
+    pub updated_unix: i64,
+}
+
+/// organization owning events, keyed by its id
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct Organization {
+    pub id: String,
+    pub name: String,
+    pub members: Vec<OrgMemberEntry>,
+    /// public tokens of the events created in the workspace, oldest first
+    pub events: Vec<String>,
+    /// subscription account token of the team billing
+    pub account: Option<String>,
+}
+
+impl Organization {
+    pub fn member(&self, token: &str) -> Option<&OrgMemberEntry> {
+        self.members.iter().find(|member| member.token == token)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct OrgMemberEntry {
+    pub name: String,
+    pub role: OrgRole,
+    /// secret login of the member
+    pub token: String,
+}
+
+/// daily counter of the admin dashboard
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatCounter {
+    EventCreated,
+    PremiumUpgrade,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct OrgEntry {
+    pub org: Organization,
+    pub version: usize,
+}
+
+impl OrgEntry {
+    pub fn bump(&mut self) {
+        self.version += 1;
+    }
+}
+
+/// raised hand together with the secret of the attendee that allows to lower it
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct QueuedSpeaker {
+    pub speaker: Speaker,
+    pub token: String,
+}
+
+/// speaker queue of an event, only exposed without the tokens as [`shared::SpeakerQueue`]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct SpeakerQueueState {
+    pub speakers: Vec<QueuedSpeaker>,
+    #[serde(default)]
+    pub current: Option<i64>,
+    /// ids are never reused, lowered hands leave gaps
+    #[serde(default)]
+    pub next_id: i64,
+}
+
+impl SpeakerQueueState {
+    pub fn queue(&self) -> SpeakerQueue {
+        SpeakerQueue {
+            speakers: self.speakers.iter().map(|s| s.speaker.clone()).collect(),
+            current: self.current,
+        }
+    }
+
+    /// returns `false` if there was no such speaker
+    pub fn remove(&mut self, id: i64) -> bool {
+        let len = self.speakers.len();
+        self.speakers.retain(|s| s.speaker.id != id);
+
+        if self.current == Some(id) {
+            self.current = None;
+        }
+
+        self.speakers.len() != len
+    }
 }
 
 /// old db entries had a untyped payment receipt that was only used in the paypal implmentation.
@@ -51,6 +149,82 @@ pub struct ApiEventInfo {
     pub context: Vec<ContextItem>,
     #[serde(default)]
     pub tags: EventTags,
+    #[serde(default)]
+    pub question_names: QuestionNames,
+    #[serde(default)]
+    pub question_length: QuestionLength,
+    #[serde(default)]
+    pub downvotes: bool,
+    #[serde(default)]
+    pub polls: Vec<Poll>,
+    #[serde(default)]
+    pub word_clouds: Vec<WordCloud>,
+    #[serde(default)]
+    pub sessions: Vec<Session>,
+    #[serde(default)]
+    pub survey: bool,
+    /// only exposed to the moderator aggregated as [`shared::SurveyResults`]
+    #[serde(default)]
+    pub survey_responses: Vec<SurveyResponse>,
+    #[serde(default)]
+    pub series: Option<EventSeries>,
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub theme: EventTheme,
+    #[serde(default)]
+    pub hide_branding: bool,
+    /// buyer details for the receipt of the premium purchase
+    #[serde(default)]
+    pub receipt: Option<ReceiptDetails>,
+    /// end of the one-off premium trial, kept after it ran out so it cannot be started again
+    #[serde(default)]
+    pub trial_end_unix: Option<i64>,
+    /// highest number of concurrent viewers seen so far
+    #[serde(default)]
+    pub peak_viewers: i64,
+    /// browsers of moderators that get notified about new and popular questions
+    #[serde(default)]
+    pub push_subscriptions: Vec<WebPushSubscription>,
+    /// moderator choices which notifications to send
+    #[serde(default)]
+    pub notifications: NotificationSettings,
+    #[serde(default)]
+    pub speaker_queue: SpeakerQueueState,
+    /// countdown started by the moderator, kept after it ran out until stopped
+    #[serde(default)]
+    pub timer: Option<EventTimer>,
+    #[serde(default)]
+    pub question_cooldown_mins: u32,
+    /// participant token to the time of their latest question, only tracked while a cooldown is set
+    /// and only until it ran out
+    #[serde(default)]
+    pub last_questions: HashMap<String, i64>,
+    #[serde(default)]
+    pub question_limits: QuestionLimits,
+    /// only enforced while the event is entitled to moderation features
+    #[serde(default)]
+    pub viewer_capacity: u32,
+    /// likes discounted per question, see `App::edit_like`
+    #[serde(default)]
+    pub flagged_likes: Vec<FlaggedLikes>,
+    /// ids of the questions asked per participant token, never sent to clients
+    #[serde(default)]
+    pub participant_questions: HashMap<String, Vec<i64>>,
+    /// participant tokens whose questions are shadowed
+    #[serde(default)]
+    pub shadow_banned: Vec<String>,
+    /// only known to moderators
+    #[serde(default)]
+    pub blocked_terms: BlockedTerms,
+    #[serde(default)]
+    pub question_languages: QuestionLanguages,
+    /// latest summary generated for the moderators
+    #[serde(default)]
+    pub question_summary: Option<QuestionSummary>,
+    /// tone per question, only classified while `LA_SENTIMENT` is enabled
+    #[serde(default)]
+    pub sentiments: Vec<QuestionSentiment>,
 }
 
 const LOREM_IPSUM:&str = "Lorem ipsum dolor sit amet. Et adipisci repellendus id dolore molestiae sed quidem ratione! Aut itaque magnam eos corporis dolores ut repudiandae consequuntur et maiores accusantium. 33 quas illum vel cumque quisquam et possimus quaerat et nostrum galisum et similique dolorum quo earum earum et accusantium dignissimos!";
@@ -63,6 +237,23 @@ pub fn mask_string(s: &str) -> &str {
 }
 
 impl ApiEventInfo {
+    /// drops what `participant` must not see, their own shadowed questions are kept
+    /// but do not tell them about the shadow ban, moderator notes are removed
+    pub fn retain_visible_questions(&mut self, participant: Option<&str>) {
+        let own = participant
+            .and_then(|participant| self.participant_questions.get(participant))
+            .cloned()
+            .unwrap_or_default();
+
+        self.questions
+            .retain(|q| !q.hidden && !q.screening && (!q.shadowed || own.contains(&q.id)));
+
+        for q in &mut self.questions {
+            q.shadowed = false;
+            q.note = None;
+        }
+    }
+
     fn is_timed_out(&self) -> bool {
         shared::EventInfo::timestamp_to_datetime(self.create_time_unix).map_or_else(
             || {
@@ -77,7 +268,32 @@ impl ApiEventInfo {
     }
 
     pub fn is_timed_out_and_free(&self) -> bool {
-        self.premium_id.is_none() && self.is_timed_out()
+        !self.is_entitled(Entitlements::LONG_RETENTION) && self.is_timed_out()
+    }
+
+    pub fn is_trial_running(&self) -> bool {
+        self.trial_end_unix
+            .is_some_and(|trial_end| trial_end > timestamp_now())
+    }
+
+    /// paid for or during the trial
+    pub fn is_premium(&self) -> bool {
+        !self.entitlements().is_empty()
+    }
+
+    /// every premium order unlocks all features, the trial does not keep the event from timing out
+    pub fn entitlements(&self) -> Entitlements {
+        if self.premium_id.is_some() {
+            Entitlements::all()
+        } else if self.is_trial_running() {
+            Entitlements::all().difference(Entitlements::LONG_RETENTION)
+        } else {
+            Entitlements::empty()
+        }
+    }
+
+    pub fn is_entitled(&self, entitlement: Entitlements) -> bool {
+        self.entitlements().contains(entitlement)
     }
 
     pub fn adapt_if_timedout(&mut self) -> bool {
@@ -92,6 +308,32 @@ impl ApiEventInfo {
     pub fn mask_data(&mut self) {
         for q in &mut self.questions {
             q.text = mask_string(&q.text).to_string();
+            q.name = q.name.as_deref().map(|name| mask_string(name).to_string());
+            q.attachment = None;
+        }
+        for p in &mut self.polls {
+            p.question = mask_string(&p.question).to_string();
+            for o in &mut p.options {
+                o.text = mask_string(&o.text).to_string();
+            }
+        }
+        for c in &mut self.word_clouds {
+            c.prompt = mask_string(&c.prompt).to_string();
+            for w in &mut c.words {
+                w.text = mask_string(&w.text).to_string();
+            }
+        }
+        for s in &mut self.speaker_queue.speakers {
+            s.speaker.name = mask_string(&s.speaker.name).to_string();
+        }
+        if let Some(timer) = &mut self.timer {
+            timer.label = mask_string(&timer.label).to_string();
+        }
+        for r in &mut self.survey_responses {
+            r.comment = r
+                .comment
+                .as_deref()
+                .map(|comment| mask_string(comment).to_string());
         }
         self.data.description = mask_string(&self.data.description).to_string();
     }
@@ -106,9 +348,22 @@ impl From<ApiEventInfo> for EventInfo {
         let mut flags = EventFlags::empty();
 
         flags.set(EventFlags::DELETED, val.deleted);
-        flags.set(EventFlags::PREMIUM, val.premium_id.is_some());
+        flags.set(EventFlags::PREMIUM, val.is_premium());
+        flags.set(
+            EventFlags::TRIAL,
+            val.premium_id.is_none() && val.is_trial_running(),
+        );
         flags.set(EventFlags::SCREENING, val.do_screening);
         flags.set(EventFlags::PASSWORD, val.password.is_enabled());
+        flags.set(EventFlags::DOWNVOTES, val.downvotes);
+        flags.set(EventFlags::SURVEY, val.survey);
+        //Note: a lapsed premium must not keep the white label
+        flags.set(
+            EventFlags::HIDE_BRANDING,
+            val.hide_branding && val.is_entitled(Entitlements::BRANDING),
+        );
+
+        let entitlements = val.entitlements();
 
         Self {
             tokens: val.tokens,
@@ -121,6 +376,26 @@ impl From<ApiEventInfo> for EventInfo {
             flags,
             context: val.context,
             tags: val.tags,
+            question_names: val.question_names,
+            question_length: val.question_length,
+            polls: val.polls,
+            word_clouds: val.word_clouds,
+            sessions: val.sessions,
+            speakers: val.speaker_queue.queue(),
+            timer: val.timer,
+            question_cooldown_mins: val.question_cooldown_mins,
+            question_limits: val.question_limits,
+            viewer_capacity: val.viewer_capacity,
+            question_languages: val.question_languages,
+            //Note: the archive of occurrences is only served to the moderator
+            series: val.series.map(|series| EventSeries {
+                occurrences: Vec::new(),
+                ..series
+            }),
+            slug: val.slug,
+            theme: val.theme,
+            trial_end_unix: val.trial_end_unix,
+            entitlements,
         }
     }
 }
@@ -237,6 +512,10 @@ mod test_serialization {
                     description: String::from("desc"),
                     short_url: String::from(""),
                     long_url: None,
+                    start_unix: None,
+                    end_unix: None,
+                    timezone: None,
+                    branding: None,
                 },
                 create_time_unix: 1,
                 delete_time_unix: 0,
@@ -253,6 +532,15 @@ mod test_serialization {
                     screening: false,
                     create_time_unix: 3,
                     tag: None,
+                    name: None,
+                    attachment: None,
+                    reactions: Reactions::default(),
+                    downvotes: 0,
+                    session: None,
+                    pinned: false,
+                    shadowed: false,
+                    note: None,
+                    merged_into: None,
                 }],
                 do_screening: true,
                 state: EventState {
@@ -260,6 +548,36 @@ mod test_serialization {
                 },
                 context: Vec::new(),
                 tags: EventTags::default(),
+                question_names: QuestionNames::Anonymous,
+                question_length: QuestionLength::default(),
+                downvotes: false,
+                polls: Vec::new(),
+                word_clouds: Vec::new(),
+                sessions: Vec::new(),
+                survey: false,
+                survey_responses: Vec::new(),
+                series: None,
+                slug: None,
+                theme: EventTheme::default(),
+                hide_branding: false,
+                receipt: None,
+                trial_end_unix: None,
+                peak_viewers: 0,
+                push_subscriptions: Vec::new(),
+                notifications: NotificationSettings::default(),
+                speaker_queue: SpeakerQueueState::default(),
+                timer: None,
+                question_cooldown_mins: 0,
+                last_questions: HashMap::new(),
+                question_limits: QuestionLimits::default(),
+                viewer_capacity: 0,
+                flagged_likes: Vec::new(),
+                participant_questions: HashMap::new(),
+                shadow_banned: Vec::new(),
+                blocked_terms: BlockedTerms::default(),
+                question_languages: QuestionLanguages::default(),
+                question_summary: None,
+                sentiments: Vec::new(),
             },
             version: 2,
             ttl: None,
@@ -286,6 +604,10 @@ mod test_serialization {
                     description: String::from("desc"),
                     short_url: String::from(""),
                     long_url: Some(String::from("foo")),
+                    start_unix: None,
+                    end_unix: None,
+                    timezone: None,
+                    branding: None,
                 },
                 create_time_unix: 1,
                 delete_time_unix: 0,
@@ -302,6 +624,15 @@ mod test_serialization {
                     screening: true,
                     create_time_unix: 3,
                     tag: Some(TagId(0)),
+                    name: Some(String::from("name")),
+                    attachment: None,
+                    reactions: Reactions::default(),
+                    downvotes: 0,
+                    session: None,
+                    pinned: false,
+                    shadowed: false,
+                    note: None,
+                    merged_into: None,
                 }],
                 do_screening: false,
                 state: EventState {
@@ -318,6 +649,36 @@ mod test_serialization {
                     }],
                     current_tag: Some(TagId(0)),
                 },
+                question_names: QuestionNames::Required,
+                question_length: QuestionLength { min: 5, max: 500 },
+                downvotes: false,
+                polls: Vec::new(),
+                word_clouds: Vec::new(),
+                sessions: Vec::new(),
+                survey: false,
+                survey_responses: Vec::new(),
+                series: None,
+                slug: None,
+                theme: EventTheme::default(),
+                hide_branding: false,
+                receipt: None,
+                trial_end_unix: None,
+                peak_viewers: 0,
+                push_subscriptions: Vec::new(),
+                notifications: NotificationSettings::default(),
+                speaker_queue: SpeakerQueueState::default(),
+                timer: None,
+                question_cooldown_mins: 0,
+                last_questions: HashMap::new(),
+                question_limits: QuestionLimits::default(),
+                viewer_capacity: 0,
+                flagged_likes: Vec::new(),
+                participant_questions: HashMap::new(),
+                shadow_banned: Vec::new(),
+                blocked_terms: BlockedTerms::default(),
+                question_languages: QuestionLanguages::default(),
+                question_summary: None,
+                sentiments: Vec::new(),
             },
             version: 2,
             ttl: Some(12345),
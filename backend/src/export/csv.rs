@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+
+use shared::{EventInfo, QuestionItem};
+
+use crate::eventsdb::ApiEventInfo;
+
+const CSV_HEADER: [&str; 6] = [
+    "text",
+    "likes",
+    "created (utc)",
+    "answered",
+    "hidden",
+    "tags",
+];
+
+/// one row per question, oldest first
+pub fn questions_csv(e: &ApiEventInfo) -> String {
+    let mut questions: Vec<&QuestionItem> = e.questions.iter().collect();
+    questions.sort_by_key(|q| (q.create_time_unix, q.id));
+
+    let rows = questions.into_iter().map(|q| {
+        let created = EventInfo::timestamp_to_datetime(q.create_time_unix)
+            .map(|date| date.format("%F %R").to_string())
+            .unwrap_or_default();
+
+        csv_row(&[
+            &defuse_formula(&q.text),
+            &q.likes.to_string(),
+            &created,
+            &q.answered.to_string(),
+            &q.hidden.to_string(),
+            &defuse_formula(tag_name(e, q)),
+        ])
+    });
+
+    std::iter::once(csv_row(&CSV_HEADER)).chain(rows).collect()
+}
+
+fn tag_name<'a>(e: &'a ApiEventInfo, q: &QuestionItem) -> &'a str {
+    q.tag
+        .and_then(|id| e.tags.tags.iter().find(|tag| tag.id == id))
+        .map(|tag| tag.name.as_str())
+        .unwrap_or_default()
+}
+
+fn csv_row(fields: &[&str]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+/// quotes fields containing separators, quotes or line breaks (RFC 4180)
+fn csv_field(field: &str) -> Cow<str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// user text a spreadsheet would evaluate as a formula is prefixed to stay plain text
+fn defuse_formula(text: &str) -> Cow<str> {
+    if text.starts_with(['=', '+', '-', '@']) {
+        Cow::Owned(format!("'{text}"))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
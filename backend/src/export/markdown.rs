@@ -0,0 +1,72 @@
+use shared::QuestionItem;
+use std::cmp::Reverse;
+
+use super::like_count;
+use crate::eventsdb::ApiEventInfo;
+
+/// the event for meeting notes: its visible questions ranked by score as a list,
+/// answered ones are followed by a blockquote
+pub fn event_markdown(e: &ApiEventInfo) -> String {
+    let mut questions: Vec<&QuestionItem> = e
+        .questions
+        .iter()
+        .filter(|q| !q.hidden && !q.screening)
+        .collect();
+    questions.sort_by_key(|q| Reverse(q.score()));
+
+    let mut blocks = vec![format!("# {}", escape(&e.data.name))];
+
+    //Note: the description is markdown already
+    if !e.data.description.trim().is_empty() {
+        blocks.push(e.data.description.trim().to_string());
+    }
+
+    if questions.is_empty() {
+        blocks.push(String::from("_no questions_"));
+    } else {
+        blocks.push(
+            questions
+                .into_iter()
+                .map(list_item)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+
+    let mut markdown = blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+fn list_item(q: &QuestionItem) -> String {
+    let text = escape(q.text.trim())
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n  ");
+
+    let name = q
+        .name
+        .as_ref()
+        .map(|name| format!(" \u{2014} {}", escape(name)))
+        .unwrap_or_default();
+
+    let answered = if q.answered { "\n  > answered" } else { "" };
+
+    format!("- **{}** {text}{name}{answered}", like_count(q.likes))
+}
+
+/// escapes the characters markdown would interpret in user text
+fn escape(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut escaped, c| {
+            if matches!(
+                c,
+                '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|'
+            ) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+            escaped
+        })
+}
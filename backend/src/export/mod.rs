@@ -0,0 +1,19 @@
+mod csv;
+mod markdown;
+mod pdf;
+mod preview;
+mod slides;
+
+pub use csv::questions_csv;
+pub use markdown::event_markdown;
+pub use pdf::event_pdf;
+pub use preview::event_preview;
+pub use slides::event_slides;
+
+fn like_count(likes: i32) -> String {
+    if likes == 1 {
+        String::from("1 like")
+    } else {
+        format!("{likes} likes")
+    }
+}
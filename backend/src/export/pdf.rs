@@ -0,0 +1,341 @@
+use qrcode::{Color, QrCode};
+use shared::{EventData, EventInfo, QuestionItem};
+use std::cmp::Reverse;
+
+use super::like_count;
+use crate::eventsdb::ApiEventInfo;
+
+/// A4 in points
+const PAGE_WIDTH: i32 = 595;
+const PAGE_HEIGHT: i32 = 842;
+const MARGIN: i32 = 50;
+const QR_MODULE_SIZE: i32 = 3;
+const SECTION_SPACING: i32 = 24;
+const QUESTION_SPACING: i32 = 10;
+
+#[derive(Clone, Copy)]
+enum Style {
+    Title,
+    Heading,
+    Text,
+    Meta,
+}
+
+impl Style {
+    /// resource names of the fonts registered in `document`
+    const fn font(self) -> &'static str {
+        match self {
+            Self::Title | Self::Heading => "F2",
+            Self::Text | Self::Meta => "F1",
+        }
+    }
+
+    const fn size(self) -> i32 {
+        match self {
+            Self::Title => 20,
+            Self::Heading => 14,
+            Self::Text => 11,
+            Self::Meta => 9,
+        }
+    }
+
+    const fn gray(self) -> &'static str {
+        match self {
+            Self::Meta => "0.45",
+            Self::Title | Self::Heading | Self::Text => "0",
+        }
+    }
+
+    const fn line_height(self) -> i32 {
+        self.size().saturating_mul(4).div_euclid(3)
+    }
+
+    /// the standard fonts are not embedded, so lines are wrapped
+    /// based on an average glyph width of 0.55em
+    fn max_chars(self) -> usize {
+        let chars = (PAGE_WIDTH - 2 * MARGIN)
+            .saturating_mul(20)
+            .div_euclid(self.size().saturating_mul(11));
+
+        usize::try_from(chars).unwrap_or_default().max(1)
+    }
+}
+
+/// content streams of the pages, filled top to bottom
+struct Layout {
+    pages: Vec<Vec<u8>>,
+    y: i32,
+}
+
+impl Layout {
+    fn new() -> Self {
+        Self {
+            pages: vec![Vec::new()],
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    /// starts a new page unless `height` still fits on the current one
+    fn reserve(&mut self, height: i32) {
+        if self.y - height < MARGIN {
+            self.pages.push(Vec::new());
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+    }
+
+    fn space(&mut self, height: i32) {
+        self.y -= height;
+    }
+
+    fn draw(&mut self, ops: &[u8]) {
+        if let Some(page) = self.pages.last_mut() {
+            page.extend_from_slice(ops);
+        }
+    }
+
+    fn text(&mut self, text: &str, style: Style) {
+        for paragraph in text.lines() {
+            for line in wrap(paragraph, style.max_chars()) {
+                self.reserve(style.line_height());
+                self.space(style.line_height());
+
+                let mut ops = format!(
+                    "BT /{} {} Tf {} g {MARGIN} {} Td (",
+                    style.font(),
+                    style.size(),
+                    style.gray(),
+                    self.y
+                )
+                .into_bytes();
+                ops.extend(encode(&line));
+                ops.extend_from_slice(b") Tj ET\n");
+
+                self.draw(&ops);
+            }
+        }
+    }
+
+    fn qr(&mut self, code: &QrCode) {
+        let width = i32::try_from(code.width()).unwrap_or_default();
+        if width == 0 {
+            return;
+        }
+
+        let size = width.saturating_mul(QR_MODULE_SIZE);
+        self.reserve(size);
+
+        let top = self.y;
+        let modules = code
+            .to_colors()
+            .into_iter()
+            .zip(0_i32..)
+            .filter(|(color, _)| matches!(color, Color::Dark))
+            .map(|(_, index)| {
+                let x = MARGIN + index.rem_euclid(width) * QR_MODULE_SIZE;
+                let y = top - (index.div_euclid(width) + 1) * QR_MODULE_SIZE;
+                format!("{x} {y} {QR_MODULE_SIZE} {QR_MODULE_SIZE} re\n")
+            })
+            .collect::<String>();
+
+        self.draw(format!("0 g\n{modules}f\n").as_bytes());
+        self.space(size);
+    }
+}
+
+/// printable document of the event with the qr code to join it and all visible questions,
+/// answered ones first and each group ranked by score
+pub fn event_pdf(e: &ApiEventInfo, share_url: &str) -> Vec<u8> {
+    let mut layout = Layout::new();
+
+    layout.text(&e.data.name, Style::Title);
+    layout.space(QUESTION_SPACING);
+    layout.text(&e.data.description, Style::Text);
+
+    if let Some(schedule) = schedule(&e.data) {
+        layout.space(QUESTION_SPACING);
+        layout.text(&schedule, Style::Meta);
+    }
+
+    if let Some(code) = Some(share_url)
+        .filter(|url| !url.is_empty())
+        .and_then(|url| QrCode::new(url.as_bytes()).ok())
+    {
+        layout.space(SECTION_SPACING);
+        layout.qr(&code);
+        layout.text(share_url, Style::Meta);
+    }
+
+    let (mut answered, mut open): (Vec<&QuestionItem>, Vec<&QuestionItem>) = e
+        .questions
+        .iter()
+        .filter(|q| !q.hidden && !q.screening)
+        .partition(|q| q.answered);
+    answered.sort_by_key(|q| Reverse(q.score()));
+    open.sort_by_key(|q| Reverse(q.score()));
+
+    for (title, questions) in [("Answered questions", answered), ("Open questions", open)] {
+        if questions.is_empty() {
+            continue;
+        }
+
+        layout.space(SECTION_SPACING);
+        layout.text(title, Style::Heading);
+
+        for q in questions {
+            layout.space(QUESTION_SPACING);
+            //Note: keeps the meta line on the page of the question
+            layout.reserve(Style::Meta.line_height() + Style::Text.line_height());
+            layout.text(&question_meta(q), Style::Meta);
+            layout.text(&q.text, Style::Text);
+        }
+    }
+
+    document(&layout.pages)
+}
+
+fn question_meta(q: &QuestionItem) -> String {
+    let likes = like_count(q.likes);
+
+    let name = q
+        .name
+        .as_ref()
+        .map(|name| format!(" \u{b7} {name}"))
+        .unwrap_or_default();
+
+    format!("{likes}{name}")
+}
+
+/// in utc as the server does not know the timezone of the reader
+fn schedule(data: &EventData) -> Option<String> {
+    let format = |unix: i64| {
+        EventInfo::timestamp_to_datetime(unix)
+            .map(|date| date.format("%F %R UTC").to_string())
+            .unwrap_or_default()
+    };
+
+    match (data.start_unix, data.end_unix) {
+        (Some(start), Some(end)) => Some(format!("{} \u{2013} {}", format(start), format(end))),
+        (Some(start), None) => Some(format!("starts {}", format(start))),
+        (None, Some(end)) => Some(format!("ends {}", format(end))),
+        (None, None) => None,
+    }
+}
+
+/// splits `text` into lines of at most `max_chars`,
+/// words are only broken if they are too long themselves
+fn wrap(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let chars: Vec<char> = word.chars().collect();
+
+        for chunk in chars.chunks(max_chars) {
+            let line_len = line.chars().count();
+
+            if line_len > 0 && line_len + 1 + chunk.len() > max_chars {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.extend(chunk);
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// escaped bytes of a pdf string literal in `WinAnsiEncoding`,
+/// characters the standard fonts cannot show become `?`
+fn encode(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len());
+
+    for c in text.chars() {
+        let byte = match c {
+            '\u{20ac}' => 0x80,
+            '\u{2026}' => 0x85,
+            '\u{2018}' => 0x91,
+            '\u{2019}' => 0x92,
+            '\u{201c}' => 0x93,
+            '\u{201d}' => 0x94,
+            '\u{2022}' => 0x95,
+            '\u{2013}' => 0x96,
+            '\u{2014}' => 0x97,
+            _ => match u8::try_from(u32::from(c)) {
+                Ok(byte @ (0x20..=0x7e | 0xa0..=0xff)) => byte,
+                Ok(_) => b' ',
+                Err(_) => b'?',
+            },
+        };
+
+        if matches!(byte, b'(' | b')' | b'\\') {
+            bytes.push(b'\\');
+        }
+        bytes.push(byte);
+    }
+
+    bytes
+}
+
+/// assembles the objects, cross-reference table and trailer around the page contents
+fn document(pages: &[Vec<u8>]) -> Vec<u8> {
+    //Note: objects 1-4 are fixed, every page adds its page object followed by its content
+    let kids = (0..pages.len())
+        .map(|index| format!("{} 0 R", 5 + 2 * index))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!("<< /Type /Pages /Kids [{kids}] /Count {} >>", pages.len()).into_bytes(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+    ];
+
+    for (index, content) in pages.iter().enumerate() {
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                6 + 2 * index
+            )
+            .into_bytes(),
+        );
+
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend_from_slice(content);
+        stream.extend_from_slice(b"\nendstream");
+        objects.push(stream);
+    }
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+
+    for (object, id) in objects.iter().zip(1_usize..) {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{id} 0 obj\n").as_bytes());
+        pdf.extend_from_slice(object);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref = pdf.len();
+    let size = objects.len() + 1;
+
+    pdf.extend_from_slice(format!("xref\n0 {size}\n0000000000 65535 f \n").as_bytes());
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!("trailer\n<< /Size {size} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n").as_bytes(),
+    );
+
+    pdf
+}
@@ -0,0 +1,81 @@
+use handlebars::Handlebars;
+use serde::Serialize;
+use shared::{GetEventResponse, QuestionItem, QuestionSort};
+
+use super::like_count;
+
+const PREVIEW_TEMPLATE: &str = include_str!("../../preview_template.html.hbs");
+
+/// only the best ranked questions make it into the preview
+const PREVIEW_MAX_QUESTIONS: usize = 5;
+/// link unfurlers cut longer descriptions anyway
+const PREVIEW_SUMMARY_MAX_CHARS: usize = 200;
+
+#[derive(Serialize, Debug)]
+struct Preview {
+    event_name: String,
+    description: String,
+    summary: String,
+    url: String,
+    questions: Vec<PreviewQuestion>,
+}
+
+#[derive(Serialize, Debug)]
+struct PreviewQuestion {
+    text: String,
+    likes: String,
+}
+
+/// static snapshot of the public event page for link unfurlers and
+/// the first paint before the wasm app is loaded
+pub fn event_preview(
+    e: &GetEventResponse,
+    url: String,
+) -> std::result::Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    //Note: masked questions are placeholders, those are not worth showing
+    let mut questions: Vec<QuestionItem> = if e.masked {
+        Vec::new()
+    } else {
+        e.info
+            .questions
+            .iter()
+            .filter(|q| !q.hidden && !q.screening && !q.answered)
+            .cloned()
+            .collect()
+    };
+    QuestionSort::Score.sort(&mut questions);
+
+    let description = e.info.data.description.clone();
+
+    let preview = Preview {
+        event_name: e.info.data.name.clone(),
+        summary: summary(&description),
+        description,
+        url,
+        questions: questions
+            .into_iter()
+            .take(PREVIEW_MAX_QUESTIONS)
+            .map(|q| PreviewQuestion {
+                text: q.text,
+                likes: like_count(q.likes),
+            })
+            .collect(),
+    };
+
+    let mut hb = Handlebars::new();
+    hb.register_template_string("preview", PREVIEW_TEMPLATE)?;
+
+    Ok(hb.render("preview", &preview)?)
+}
+
+/// single line of the description for the meta tags
+fn summary(description: &str) -> String {
+    let line = description.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if line.chars().count() > PREVIEW_SUMMARY_MAX_CHARS {
+        let cut: String = line.chars().take(PREVIEW_SUMMARY_MAX_CHARS).collect();
+        format!("{}...", cut.trim_end())
+    } else {
+        line
+    }
+}
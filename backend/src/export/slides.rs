@@ -0,0 +1,55 @@
+use handlebars::Handlebars;
+use serde::Serialize;
+use shared::QuestionItem;
+use std::cmp::Reverse;
+
+use super::like_count;
+use crate::eventsdb::ApiEventInfo;
+
+const SLIDES_TEMPLATE: &str = include_str!("../../slides_template.html.hbs");
+
+/// only the best ranked questions make it into the deck
+const SLIDES_MAX_QUESTIONS: usize = 10;
+
+#[derive(Serialize, Debug)]
+struct Slides {
+    event_name: String,
+    questions: Vec<Slide>,
+}
+
+#[derive(Serialize, Debug)]
+struct Slide {
+    text: String,
+    likes: String,
+    name: Option<String>,
+}
+
+/// reveal.js deck with one slide per top open question for answering them on the projector
+pub fn event_slides(
+    e: &ApiEventInfo,
+) -> std::result::Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    let mut questions: Vec<&QuestionItem> = e
+        .questions
+        .iter()
+        .filter(|q| !q.hidden && !q.screening && !q.answered)
+        .collect();
+    questions.sort_by_key(|q| Reverse(q.score()));
+
+    let slides = Slides {
+        event_name: e.data.name.clone(),
+        questions: questions
+            .into_iter()
+            .take(SLIDES_MAX_QUESTIONS)
+            .map(|q| Slide {
+                text: q.text.clone(),
+                likes: like_count(q.likes),
+                name: q.name.clone(),
+            })
+            .collect(),
+    };
+
+    let mut hb = Handlebars::new();
+    hb.register_template_string("slides", SLIDES_TEMPLATE)?;
+
+    Ok(hb.render("slides", &slides)?)
+}
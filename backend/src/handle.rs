@@ -1,172 +1,1026 @@
 use axum::{
-    extract::{ws::WebSocket, Path, State, WebSocketUpgrade},
-    response::{Html, IntoResponse},
+    extract::{ws::WebSocket, Path, Query, State, WebSocketUpgrade},
+    http::{header, HeaderMap, Request},
+    middleware::Next,
+    response::{Html, IntoResponse, Redirect, Response},
     Json,
 };
 use axum_sessions::extractors::{ReadableSession, WritableSession};
-use shared::EventPasswordResponse;
+use shared::{
+    EventMod, EventParams, EventPasswordResponse, EventPublic, PushParams, QuestionsParams,
+    UpdatesParams, UpgradeParams, QUESTIONS_PAGE_MAX_LIMIT,
+};
 use tracing::instrument;
 
-use crate::{app::SharedApp, auth::OptionalUser, error::InternalError, GIT_HASH};
+use crate::{app::SharedApp, auth::OptionalUser, error::InternalError, GIT_HASH};
+
+async fn socket_handler(ws: WebSocket, id: String, params: PushParams, app: SharedApp) {
+    app.push_subscriber(ws, id, params).await;
+}
+
+#[instrument(skip(app, ws, params))]
+pub async fn push_handler(
+    ws: WebSocketUpgrade,
+    Path(id): Path<String>,
+    Query(params): Query<PushParams>,
+    State(app): State<SharedApp>,
+) -> impl IntoResponse {
+    tracing::info!("push subscriber: {}", id);
+
+    ws.on_upgrade(move |ws| socket_handler(ws, id, params, app))
+}
+
+#[instrument(skip(app))]
+pub async fn event_updates_handler(
+    Path(id): Path<String>,
+    Query(params): Query<UpdatesParams>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    Ok(Json(app.event_updates(id, params.since).await?))
+}
+
+#[instrument(skip(app, session, headers))]
+pub async fn questions_handler(
+    Path(id): Path<String>,
+    Query(params): Query<QuestionsParams>,
+    session: ReadableSession,
+    State(app): State<SharedApp>,
+    headers: HeaderMap,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    let password = session.get_raw("pwd");
+
+    Ok(Json(
+        app.get_questions(id, params, password, fingerprint(&headers))
+            .await?,
+    ))
+}
+
+/// seconds link unfurlers and the browser may reuse a preview
+const PREVIEW_MAX_AGE_SECS: u32 = 60;
+
+#[instrument(skip(app))]
+pub async fn preview_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("preview_handler");
+
+    Ok((
+        [(
+            header::CACHE_CONTROL,
+            format!("public, max-age={PREVIEW_MAX_AGE_SECS}"),
+        )],
+        Html(app.event_preview(id).await?),
+    ))
+}
+
+/// participant token the browser sends along, see [`shared::FINGERPRINT_HEADER`]
+fn fingerprint(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(shared::FINGERPRINT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string)
+}
+
+/// address of the client as appended by our load balancer
+fn client_ip(headers: &HeaderMap) -> Option<String> {
+    //Note: clients can send their own `x-forwarded-for`, only the last entry is trustworthy
+    headers
+        .get("x-forwarded-for")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.rsplit(',').next())
+        .map(|ip| ip.trim().to_string())
+        .filter(|ip| !ip.is_empty())
+}
+
+#[instrument(skip(app, headers))]
+pub async fn editlike_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+    headers: HeaderMap,
+    Json(payload): Json<shared::EditLike>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("edit like: {}/{}", payload.question_id, id);
+
+    Ok(Json(app.edit_like(id, payload, client_ip(&headers)).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn editdownvote_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::EditDownvote>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("edit downvote: {}/{}", payload.question_id, id);
+
+    Ok(Json(app.edit_downvote(id, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn editreaction_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::EditReaction>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("edit reaction: {}/{}", payload.question_id, id);
+
+    Ok(Json(app.edit_reaction(id, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn vote_poll_handler(
+    Path((id, poll_id)): Path<(String, i64)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::PollVote>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("vote poll: {}/{}", poll_id, id);
+
+    Ok(Json(app.vote_poll(id, poll_id, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn raise_hand_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::RaiseHand>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("raise hand: {}", id);
+
+    Ok(Json(app.raise_hand(id, payload).await?))
+}
+
+#[instrument(skip(app, payload))]
+pub async fn lower_hand_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::RaisedHand>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("lower hand: {}/{}", payload.id, id);
+
+    Ok(Json(app.lower_hand(id, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn add_word_handler(
+    Path((id, cloud_id)): Path<(String, i64)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddWord>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("add word: {}/{}", cloud_id, id);
+
+    Ok(Json(app.add_word(id, cloud_id, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn survey_response_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddSurveyResponse>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("survey response: {}", id);
+
+    Ok(Json(app.add_survey_response(id, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn series_current_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("series current: {}", id);
+
+    Ok(Json(app.series_current(id).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn slug_handler(
+    Path(slug): Path<String>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("slug: {}", slug);
+
+    Ok(Json(app.slug_target(slug).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn addevent_handler(
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddEvent>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("create event");
+
+    Ok(Json(app.create_event(payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn addquestion_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddQuestion>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("add question: {} in event:  {}", payload.text, id);
+
+    Ok(Json(app.add_question(id, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn request_attachment_handler(
+    Path(id): Path<String>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AttachmentRequest>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!(
+        "request attachment: {} in event: {}",
+        payload.content_type,
+        id
+    );
+
+    Ok(Json(app.request_attachment(id, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn get_attachment_handler(
+    Path((id, file)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("get attachment");
+
+    Ok(Redirect::temporary(
+        &app.attachment_url(id, None, file).await?,
+    ))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_get_attachment(
+    Path((id, secret, file)): Path<(String, String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_get_attachment");
+
+    Ok(Redirect::temporary(
+        &app.attachment_url(id, Some(secret), file).await?,
+    ))
+}
+
+#[instrument(skip(app))]
+pub async fn get_logo_handler(
+    Path((id, file)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("get logo");
+
+    Ok(Redirect::temporary(&app.logo_url(id, file).await?))
+}
+
+#[instrument(skip(app, session, headers))]
+pub async fn getevent_handler(
+    Path(id): Path<String>,
+    Query(params): Query<EventParams>,
+    OptionalUser(user): OptionalUser,
+    session: ReadableSession,
+    State(app): State<SharedApp>,
+    headers: HeaderMap,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("getevent_handler");
+
+    let password = session.get_raw("pwd");
+
+    let mut response = app
+        .get_event(id, None, user.is_some(), password, fingerprint(&headers))
+        .await?;
+
+    //Note: admins also see hidden questions, those are not part of the pages
+    if let Some(limit) = params.questions.filter(|_| !response.admin) {
+        response.limit_questions(limit.clamp(1, QUESTIONS_PAGE_MAX_LIMIT));
+    }
+
+    Ok(Json(EventPublic::from(response)))
+}
+
+#[instrument(skip(app, session))]
+pub async fn set_event_password(
+    Path(id): Path<String>,
+    mut session: WritableSession,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::EventPasswordRequest>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("set_event_password");
+
+    let response = EventPasswordResponse {
+        ok: app.check_event_password(id, &payload.pwd).await?,
+    };
+
+    if response.ok {
+        session.insert_raw("pwd", payload.pwd);
+    }
+
+    Ok(Json(response))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_get_event(
+    Path((id, secret)): Path<(String, String)>,
+    OptionalUser(user): OptionalUser,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_get_event");
+
+    Ok(Json(EventMod::from(
+        app.get_event(id, Some(secret), user.is_some(), None, None)
+            .await?,
+    )))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_delete_event(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_delete_event");
+
+    Ok(Json(app.delete_event(id, secret).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_duplicate_event(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_duplicate_event");
+
+    Ok(Json(app.mod_duplicate_event(id, secret).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_premium_upgrade(
+    Path((id, secret)): Path<(String, String)>,
+    Query(params): Query<UpgradeParams>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_premium_upgrade");
+
+    Ok(Json(
+        app.request_premium_upgrade(id, secret, params.promo)
+            .await?,
+    ))
+}
+
+#[instrument(skip(app))]
+pub async fn admin_add_promo(
+    OptionalUser(user): OptionalUser,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::PromoCode>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("admin_add_promo");
+
+    if user.is_none() {
+        return Err(InternalError::InvalidLogin);
+    }
+
+    Ok(Json(app.admin_add_promo(payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn admin_dashboard(
+    OptionalUser(user): OptionalUser,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("admin_dashboard");
+
+    if user.is_none() {
+        return Err(InternalError::InvalidLogin);
+    }
+
+    Ok(Json(app.admin_dashboard().await?))
+}
+
+#[instrument(skip(app))]
+pub async fn admin_event(
+    Path(id): Path<String>,
+    OptionalUser(user): OptionalUser,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("admin_event");
+
+    if user.is_none() {
+        return Err(InternalError::InvalidLogin);
+    }
+
+    Ok(Json(app.admin_event(id).await?))
+}
+
+/// middleware counting answered requests for the admin dashboard
+pub async fn track_response<B>(
+    State(app): State<SharedApp>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let response = next.run(request).await;
+
+    app.track_response(response.status());
+
+    response
+}
+
+#[instrument(skip(app))]
+pub async fn admin_blocklist(
+    OptionalUser(user): OptionalUser,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("admin_blocklist");
+
+    if user.is_none() {
+        return Err(InternalError::InvalidLogin);
+    }
+
+    Ok(Json(app.admin_blocklist().await?))
+}
+
+#[instrument(skip(app))]
+pub async fn admin_block(
+    OptionalUser(user): OptionalUser,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::BlockEntry>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("admin_block");
+
+    if user.is_none() {
+        return Err(InternalError::InvalidLogin);
+    }
+
+    Ok(Json(app.admin_block(payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn admin_unblock(
+    OptionalUser(user): OptionalUser,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::BlockEntry>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("admin_unblock");
+
+    if user.is_none() {
+        return Err(InternalError::InvalidLogin);
+    }
+
+    Ok(Json(app.admin_unblock(payload).await?))
+}
+
+/// middleware turning away clients on the abuse blocklist
+pub async fn check_blocklist<B>(
+    State(app): State<SharedApp>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let ip = client_ip(request.headers()).and_then(|ip| ip.parse().ok());
+    let fingerprint = fingerprint(request.headers());
+
+    if app.is_blocked(ip, fingerprint.as_deref()).await {
+        return InternalError::BlockedClient(request.uri().path().to_string()).into_response();
+    }
+
+    next.run(request).await
+}
+
+#[instrument(skip(app))]
+pub async fn admin_get_promo(
+    Path(code): Path<String>,
+    OptionalUser(user): OptionalUser,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("admin_get_promo");
+
+    if user.is_none() {
+        return Err(InternalError::InvalidLogin);
+    }
+
+    Ok(Json(app.admin_get_promo(code).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_start_trial(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_start_trial");
+
+    Ok(Json(app.mod_start_trial(id, secret).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_receipt(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_receipt");
+
+    Ok(Html(app.mod_receipt(id, secret).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_edit_receipt(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::ReceiptDetails>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_edit_receipt");
+
+    Ok(Json(app.mod_edit_receipt(id, secret, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn web_push_key_handler(
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("web_push_key");
+
+    Ok(app.web_push_key()?)
+}
+
+#[instrument(skip(app))]
+pub async fn mod_push_subscribe(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::WebPushSubscription>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_push_subscribe");
+
+    Ok(Json(app.mod_push_subscribe(id, secret, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_push_unsubscribe(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::WebPushUnsubscribe>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_push_unsubscribe");
+
+    Ok(Json(app.mod_push_unsubscribe(id, secret, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn request_subscription_handler(
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("request_subscription");
+
+    Ok(Json(app.request_subscription().await?))
+}
+
+#[instrument(skip(app))]
+pub async fn subscription_state_handler(
+    Path(account): Path<String>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("subscription_state");
+
+    Ok(Json(app.subscription_state(account).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn add_org_handler(
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddOrg>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("add_org");
+
+    Ok(Json(app.create_org(payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn org_workspace_handler(
+    Path((id, token)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("org_workspace");
+
+    Ok(Json(app.org_workspace(id, token).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn org_add_member(
+    Path((id, token)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddOrgMember>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("org_add_member");
+
+    Ok(Json(app.org_add_member(id, token, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn org_remove_member(
+    Path((id, token, member)): Path<(String, String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("org_remove_member");
+
+    Ok(Json(app.org_remove_member(id, token, member).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn org_add_event(
+    Path((id, token)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddEvent>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("org_add_event");
+
+    Ok(Json(app.org_create_event(id, token, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn org_subscription(
+    Path((id, token)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("org_subscription");
+
+    Ok(Json(app.org_request_subscription(id, token).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn org_checkout_handler(
+    Path(session): Path<String>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("org_checkout_handler");
+
+    Ok(Json(app.org_checkout(session).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_premium_capture(
+    Path((id, order)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_premium_capture");
+
+    Ok(Json(app.premium_capture(id, order).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_get_question(
+    Path((id, secret, question_id)): Path<(String, String, i64)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_get_question");
+
+    Ok(Json(app.get_question(id, Some(secret), question_id).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_translate_question(
+    Path((id, secret, question_id, language)): Path<(String, String, i64, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_translate_question");
 
-async fn socket_handler(ws: WebSocket, id: String, app: SharedApp) {
-    app.push_subscriber(ws, id).await;
+    Ok(Json(
+        app.translate_question(id, Some(secret), None, question_id, language)
+            .await?,
+    ))
 }
 
-#[instrument(skip(app, ws))]
-pub async fn push_handler(
-    ws: WebSocketUpgrade,
-    Path(id): Path<String>,
+#[instrument(skip(app))]
+pub async fn mod_like_timeline(
+    Path((id, secret)): Path<(String, String)>,
     State(app): State<SharedApp>,
-) -> impl IntoResponse {
-    tracing::info!("push subscriber: {}", id);
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_like_timeline");
 
-    ws.on_upgrade(|ws| socket_handler(ws, id, app))
+    Ok(Json(app.mod_like_timeline(id, secret).await?))
 }
 
 #[instrument(skip(app))]
-pub async fn editlike_handler(
-    Path(id): Path<String>,
+pub async fn mod_export_csv(
+    Path((id, secret)): Path<(String, String)>,
     State(app): State<SharedApp>,
-    Json(payload): Json<shared::EditLike>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("edit like: {}/{}", payload.question_id, id);
+    tracing::info!("mod_export_csv");
+
+    let csv = app.mod_export_csv(id.clone(), secret).await?;
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                String::from("text/csv; charset=utf-8"),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"live-ask-{id}.csv\""),
+            ),
+        ],
+        csv,
+    ))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_export_pdf(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_export_pdf");
+
+    let pdf = app.mod_export_pdf(id.clone(), secret).await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, String::from("application/pdf")),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"live-ask-{id}.pdf\""),
+            ),
+        ],
+        pdf,
+    ))
+}
 
-    Ok(Json(app.edit_like(id, payload).await?))
+#[instrument(skip(app))]
+pub async fn mod_export_markdown(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_export_markdown");
+
+    let markdown = app.mod_export_markdown(id.clone(), secret).await?;
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                String::from("text/markdown; charset=utf-8"),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"live-ask-{id}.md\""),
+            ),
+        ],
+        markdown,
+    ))
 }
 
 #[instrument(skip(app))]
-pub async fn addevent_handler(
+pub async fn mod_export_slides(
+    Path((id, secret)): Path<(String, String)>,
     State(app): State<SharedApp>,
-    Json(payload): Json<shared::AddEvent>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("create event");
+    tracing::info!("mod_export_slides");
 
-    Ok(Json(app.create_event(payload).await?))
+    Ok(Html(app.mod_export_slides(id, secret).await?))
 }
 
 #[instrument(skip(app))]
-pub async fn addquestion_handler(
-    Path(id): Path<String>,
+pub async fn mod_event_stats(
+    Path((id, secret)): Path<(String, String)>,
     State(app): State<SharedApp>,
-    Json(payload): Json<shared::AddQuestion>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("add question: {} in event:  {}", payload.text, id);
+    tracing::info!("mod_event_stats");
 
-    Ok(Json(app.add_question(id, payload).await?))
+    Ok(Json(app.mod_event_stats(id, secret).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_question_summary(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_question_summary");
+
+    Ok(Json(app.mod_question_summary(id, secret).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_survey_results(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_survey_results");
+
+    Ok(Json(app.mod_survey_results(id, secret).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn get_question(
+    Path((id, question_id)): Path<(String, i64)>,
+    State(app): State<SharedApp>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("get_question");
+
+    Ok(Json(app.get_question(id, None, question_id).await?))
 }
 
 #[instrument(skip(app, session))]
-pub async fn getevent_handler(
-    Path(id): Path<String>,
-    OptionalUser(user): OptionalUser,
+pub async fn translate_question(
+    Path((id, question_id, language)): Path<(String, i64, String)>,
     session: ReadableSession,
     State(app): State<SharedApp>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("getevent_handler");
+    tracing::info!("translate_question");
 
     let password = session.get_raw("pwd");
 
     Ok(Json(
-        app.get_event(id, None, user.is_some(), password).await?,
+        app.translate_question(id, None, password, question_id, language)
+            .await?,
     ))
 }
 
-#[instrument(skip(app, session))]
-pub async fn set_event_password(
-    Path(id): Path<String>,
-    mut session: WritableSession,
+#[instrument(skip(app))]
+pub async fn mod_edit_question(
+    Path((id, secret, question_id)): Path<(String, String, i64)>,
     State(app): State<SharedApp>,
-    Json(payload): Json<shared::EventPasswordRequest>,
+    Json(payload): Json<shared::ModQuestion>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("set_event_password");
+    tracing::info!("mod_edit_question");
 
-    let response = EventPasswordResponse {
-        ok: app.check_event_password(id, &payload.pwd).await?,
-    };
+    Ok(Json(
+        app.mod_edit_question(id, secret, question_id, payload)
+            .await?,
+    ))
+}
 
-    if response.ok {
-        session.insert_raw("pwd", payload.pwd);
-    }
+#[instrument(skip(app))]
+pub async fn mod_edit_questions(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::ModQuestionsBulk>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_edit_questions");
 
-    Ok(Json(response))
+    Ok(Json(app.mod_edit_questions(id, secret, payload).await?))
 }
 
 #[instrument(skip(app))]
-pub async fn mod_get_event(
+pub async fn mod_question_clusters(
     Path((id, secret)): Path<(String, String)>,
-    OptionalUser(user): OptionalUser,
     State(app): State<SharedApp>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("mod_get_event");
+    tracing::info!("mod_question_clusters");
+
+    Ok(Json(app.mod_question_clusters(id, secret).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_merge_questions(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::ModMergeQuestions>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_merge_questions");
+
+    Ok(Json(app.mod_merge_questions(id, secret, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_question_note(
+    Path((id, secret, question_id)): Path<(String, String, i64)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::ModQuestionNote>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_question_note");
 
-    //TODO: special response type for mods to add more info
     Ok(Json(
-        app.get_event(id, Some(secret), user.is_some(), None)
+        app.mod_question_note(id, secret, question_id, payload)
             .await?,
     ))
 }
 
 #[instrument(skip(app))]
-pub async fn mod_delete_event(
+pub async fn mod_edit_series(
     Path((id, secret)): Path<(String, String)>,
     State(app): State<SharedApp>,
+    Json(payload): Json<shared::EditSeries>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("mod_delete_event");
+    tracing::info!("mod_edit_series");
 
-    Ok(Json(app.delete_event(id, secret).await?))
+    Ok(Json(app.mod_edit_series(id, secret, payload).await?))
 }
 
 #[instrument(skip(app))]
-pub async fn mod_premium_upgrade(
+pub async fn mod_edit_slug(
     Path((id, secret)): Path<(String, String)>,
     State(app): State<SharedApp>,
+    Json(payload): Json<shared::EditSlug>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("mod_premium_upgrade");
+    tracing::info!("mod_edit_slug");
 
-    Ok(Json(app.request_premium_upgrade(id, secret).await?))
+    Ok(Json(app.mod_edit_slug(id, secret, payload).await?))
 }
 
 #[instrument(skip(app))]
-pub async fn mod_premium_capture(
-    Path((id, order)): Path<(String, String)>,
+pub async fn mod_request_logo(
+    Path((id, secret)): Path<(String, String)>,
     State(app): State<SharedApp>,
+    Json(payload): Json<shared::AttachmentRequest>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("mod_premium_capture");
+    tracing::info!("mod_request_logo: {}", payload.content_type);
 
-    Ok(Json(app.premium_capture(id, order).await?))
+    Ok(Json(app.mod_request_logo(id, secret, payload).await?))
 }
 
 #[instrument(skip(app))]
-pub async fn mod_get_question(
-    Path((id, secret, question_id)): Path<(String, String, i64)>,
+pub async fn mod_series_archive(
+    Path((id, secret)): Path<(String, String)>,
     State(app): State<SharedApp>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("mod_get_question");
+    tracing::info!("mod_series_archive");
 
-    Ok(Json(app.get_question(id, Some(secret), question_id).await?))
+    Ok(Json(app.mod_series_archive(id, secret).await?))
 }
 
 #[instrument(skip(app))]
-pub async fn get_question(
-    Path((id, question_id)): Path<(String, i64)>,
+pub async fn mod_add_session(
+    Path((id, secret)): Path<(String, String)>,
     State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddSession>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("get_question");
+    tracing::info!("mod_add_session");
 
-    Ok(Json(app.get_question(id, None, question_id).await?))
+    Ok(Json(app.mod_add_session(id, secret, payload).await?))
 }
 
 #[instrument(skip(app))]
-pub async fn mod_edit_question(
-    Path((id, secret, question_id)): Path<(String, String, i64)>,
+pub async fn mod_edit_session(
+    Path((id, secret, session_id)): Path<(String, String, i64)>,
     State(app): State<SharedApp>,
-    Json(payload): Json<shared::ModQuestion>,
+    Json(payload): Json<shared::ModSession>,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
-    tracing::info!("mod_edit_question");
+    tracing::info!("mod_edit_session");
 
     Ok(Json(
-        app.mod_edit_question(id, secret, question_id, payload)
+        app.mod_edit_session(id, secret, session_id, payload)
+            .await?,
+    ))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_add_poll(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddPoll>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_add_poll");
+
+    Ok(Json(app.mod_add_poll(id, secret, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_edit_poll(
+    Path((id, secret, poll_id)): Path<(String, String, i64)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::ModPoll>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_edit_poll");
+
+    Ok(Json(app.mod_edit_poll(id, secret, poll_id, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_edit_speakers(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::ModSpeaker>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_edit_speakers");
+
+    Ok(Json(app.mod_edit_speakers(id, secret, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_edit_timer(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::ModTimer>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_edit_timer");
+
+    Ok(Json(app.mod_edit_timer(id, secret, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_add_word_cloud(
+    Path((id, secret)): Path<(String, String)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::AddWordCloud>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_add_word_cloud");
+
+    Ok(Json(app.mod_add_word_cloud(id, secret, payload).await?))
+}
+
+#[instrument(skip(app))]
+pub async fn mod_edit_word_cloud(
+    Path((id, secret, cloud_id)): Path<(String, String, i64)>,
+    State(app): State<SharedApp>,
+    Json(payload): Json<shared::ModWordCloud>,
+) -> std::result::Result<impl IntoResponse, InternalError> {
+    tracing::info!("mod_edit_word_cloud");
+
+    Ok(Json(
+        app.mod_edit_word_cloud(id, secret, cloud_id, payload)
             .await?,
     ))
 }
@@ -205,8 +1059,10 @@ mod test_db_conflicts {
     use crate::payment::Payment;
     use crate::tracking::Tracking;
     use crate::utils::timestamp_now;
-    use crate::viewers::MockViewers;
-    use crate::{app::App, pubsub::PubSubInMemory};
+    use crate::{
+        app::{App, Stores},
+        pubsub::PubSubInMemory,
+    };
     use async_trait::async_trait;
     use axum::{
         body::Body,
@@ -243,13 +1099,77 @@ mod test_db_conflicts {
             tracing::info!("fake db put: {}", event.event.tokens.public_token);
             Err(crate::eventsdb::Error::Concurrency)
         }
+        async fn claim_slug(&self, _slug: &str, _event: &str) -> crate::eventsdb::Result<()> {
+            Err(crate::eventsdb::Error::Concurrency)
+        }
+        async fn get_slug(&self, _slug: &str) -> crate::eventsdb::Result<String> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn put_subscription(
+            &self,
+            _account: &str,
+            _subscription: crate::eventsdb::Subscription,
+        ) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn get_subscription(
+            &self,
+            _account: &str,
+        ) -> crate::eventsdb::Result<crate::eventsdb::Subscription> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn put_webhook(
+            &self,
+            _id: &str,
+            _payload: String,
+            _received_unix: i64,
+        ) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn add_promo(&self, _promo: shared::PromoCode) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn get_promo(&self, _code: &str) -> crate::eventsdb::Result<shared::PromoCode> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn redeem_promo(&self, _code: &str) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn get_org(&self, _id: &str) -> crate::eventsdb::Result<crate::eventsdb::OrgEntry> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn put_org(&self, _org: crate::eventsdb::OrgEntry) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn count_stat(
+            &self,
+            _day: &str,
+            _counter: crate::eventsdb::StatCounter,
+        ) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn get_stats(&self, _day: &str) -> crate::eventsdb::Result<shared::AdminDayStats> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn get_blocklist(&self) -> crate::eventsdb::Result<shared::Blocklist> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn put_blocklist(
+            &self,
+            _blocklist: shared::Blocklist,
+        ) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn delete_webhook(&self, _id: &str) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
     }
 
     fn app() -> Router {
         let app = Arc::new(App::new(
             Arc::new(ConflictDB::default()),
             Arc::new(PubSubInMemory::default()),
-            Arc::new(MockViewers::new()),
+            Stores::in_memory(),
             Arc::new(Payment::default()),
             Tracking::default(),
             String::new(),
@@ -276,6 +1196,7 @@ mod test_db_conflicts {
                         serde_json::to_string(&shared::EditLike {
                             like: true,
                             question_id: 1,
+                            participant: None,
                         })
                         .unwrap(),
                     ))
@@ -292,13 +1213,12 @@ mod test_db_conflicts {
 mod test_db_item_not_found {
     use super::*;
     use crate::{
-        app::App,
+        app::{App, Stores},
         auth,
         eventsdb::{EventEntry, EventsDB, InMemoryEventsDB},
         payment::Payment,
         pubsub::PubSubInMemory,
         tracking::Tracking,
-        viewers::MockViewers,
     };
     use async_trait::async_trait;
     use axum::{
@@ -325,6 +1245,70 @@ mod test_db_item_not_found {
         async fn put(&self, _event: EventEntry) -> crate::eventsdb::Result<()> {
             Ok(())
         }
+        async fn claim_slug(&self, _slug: &str, _event: &str) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn get_slug(&self, _slug: &str) -> crate::eventsdb::Result<String> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn put_subscription(
+            &self,
+            _account: &str,
+            _subscription: crate::eventsdb::Subscription,
+        ) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn get_subscription(
+            &self,
+            _account: &str,
+        ) -> crate::eventsdb::Result<crate::eventsdb::Subscription> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn put_webhook(
+            &self,
+            _id: &str,
+            _payload: String,
+            _received_unix: i64,
+        ) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn add_promo(&self, _promo: shared::PromoCode) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn get_promo(&self, _code: &str) -> crate::eventsdb::Result<shared::PromoCode> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn redeem_promo(&self, _code: &str) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn get_org(&self, _id: &str) -> crate::eventsdb::Result<crate::eventsdb::OrgEntry> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn put_org(&self, _org: crate::eventsdb::OrgEntry) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn count_stat(
+            &self,
+            _day: &str,
+            _counter: crate::eventsdb::StatCounter,
+        ) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn get_stats(&self, _day: &str) -> crate::eventsdb::Result<shared::AdminDayStats> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn get_blocklist(&self) -> crate::eventsdb::Result<shared::Blocklist> {
+            Err(crate::eventsdb::Error::ItemNotFound)
+        }
+        async fn put_blocklist(
+            &self,
+            _blocklist: shared::Blocklist,
+        ) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
+        async fn delete_webhook(&self, _id: &str) -> crate::eventsdb::Result<()> {
+            Ok(())
+        }
     }
 
     #[tokio::test]
@@ -334,7 +1318,7 @@ mod test_db_item_not_found {
             let app = Arc::new(App::new(
                 Arc::new(ItemNotFoundDB::default()),
                 Arc::new(PubSubInMemory::default()),
-                Arc::new(MockViewers::new()),
+                Stores::in_memory(),
                 Arc::new(Payment::default()),
                 Tracking::default(),
                 String::new(),
@@ -373,7 +1357,7 @@ mod test_db_item_not_found {
             let app = Arc::new(App::new(
                 events.clone(),
                 Arc::new(PubSubInMemory::default()),
-                Arc::new(MockViewers::new()),
+                Stores::in_memory(),
                 Arc::new(Payment::default()),
                 Tracking::default(),
                 String::new(),
@@ -396,6 +1380,7 @@ mod test_db_item_not_found {
                     ..Default::default()
                 },
                 moderator_email: None,
+                subscription: None,
                 test: false,
             })
             .await
@@ -424,7 +1409,7 @@ mod test_db_item_not_found {
             let app = Arc::new(App::new(
                 events.clone(),
                 Arc::new(PubSubInMemory::default()),
-                Arc::new(MockViewers::new()),
+                Stores::in_memory(),
                 Arc::new(Payment::default()),
                 Tracking::default(),
                 String::new(),
@@ -448,6 +1433,7 @@ mod test_db_item_not_found {
                     ..Default::default()
                 },
                 moderator_email: None,
+                subscription: None,
                 test: false,
             })
             .await
@@ -474,12 +1460,13 @@ mod test_db_item_not_found {
         )
         .unwrap();
 
-        let response: shared::GetEventResponse = server
+        let response: shared::EventPublic = server
             .get(&format!("/api/event/{}", e.tokens.public_token))
             .await
             .json();
 
         assert!(response.flags.contains(EventResponseFlags::WRONG_PASSWORD));
+        assert!(response.info.tokens.moderator_token.is_none());
 
         let res: shared::EventPasswordResponse = server
             .post(&format!("/api/event/{}/pwd", e.tokens.public_token))
@@ -499,7 +1486,7 @@ mod test_db_item_not_found {
             .json();
         assert!(res.ok);
 
-        let response: shared::GetEventResponse = server
+        let response: shared::EventPublic = server
             .get(&format!("/api/event/{}", e.tokens.public_token))
             .await
             .json();
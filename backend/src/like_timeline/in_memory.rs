@@ -0,0 +1,31 @@
+use super::LikeTimelines;
+use async_trait::async_trait;
+use shared::LikeTimeline;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemoryLikeTimelines {
+    pub events: Arc<RwLock<HashMap<String, LikeTimeline>>>,
+}
+
+#[async_trait]
+impl LikeTimelines for InMemoryLikeTimelines {
+    async fn record(&self, event: &str, question_id: i64, timestamp: i64, delta: i32) {
+        self.events
+            .write()
+            .await
+            .entry(event.to_string())
+            .or_default()
+            .record(question_id, timestamp, delta);
+    }
+
+    async fn get(&self, event: &str) -> LikeTimeline {
+        self.events
+            .read()
+            .await
+            .get(event)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
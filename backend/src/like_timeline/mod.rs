@@ -0,0 +1,19 @@
+mod in_memory;
+mod redis;
+
+pub use self::redis::RedisLikeTimelines;
+#[cfg(test)]
+pub use in_memory::InMemoryLikeTimelines;
+
+use async_trait::async_trait;
+use shared::LikeTimeline;
+
+/// per minute like counts of questions, kept outside of the event item
+/// so busy events do not grow it towards the storage item size limit
+#[async_trait]
+pub trait LikeTimelines: Send + Sync {
+    /// adds `delta` to the bucket of the minute `timestamp` falls into
+    async fn record(&self, event: &str, question_id: i64, timestamp: i64, delta: i32);
+    /// timelines of all questions of `event`
+    async fn get(&self, event: &str) -> LikeTimeline;
+}
@@ -0,0 +1,82 @@
+use super::LikeTimelines;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use shared::LikeTimeline;
+use std::collections::HashMap;
+use tracing::instrument;
+
+/// hash per event: `{question_id}/{minute}` -> net likes within that minute
+pub struct RedisLikeTimelines {
+    redis: deadpool_redis::Pool,
+}
+
+impl RedisLikeTimelines {
+    pub const fn new(pool: deadpool_redis::Pool) -> Self {
+        Self { redis: pool }
+    }
+}
+
+/// refreshed on every like, so only timelines of inactive events expire
+const KEY_TTL: usize = 30 * 24 * 60 * 60;
+
+#[async_trait]
+impl LikeTimelines for RedisLikeTimelines {
+    #[instrument(skip(self))]
+    async fn record(&self, event: &str, question_id: i64, timestamp: i64, delta: i32) {
+        let Ok(mut db) = self.redis.get().await else {
+            tracing::error!("like timeline: redis unavailable");
+            return;
+        };
+
+        let key = create_key(event);
+        let minute = timestamp - timestamp.rem_euclid(60);
+
+        if let Err(e) = db
+            .hincr::<_, _, _, i64>(&key, format!("{question_id}/{minute}"), delta)
+            .await
+        {
+            tracing::error!("like timeline record error: {e}");
+        }
+
+        db.expire::<_, isize>(&key, KEY_TTL).await.ok();
+    }
+
+    #[instrument(skip(self))]
+    async fn get(&self, event: &str) -> LikeTimeline {
+        let Ok(mut db) = self.redis.get().await else {
+            return LikeTimeline::default();
+        };
+
+        let fields = db
+            .hgetall::<_, HashMap<String, i32>>(create_key(event))
+            .await
+            .unwrap_or_default();
+
+        let mut buckets = fields
+            .into_iter()
+            .filter_map(|(field, likes)| {
+                let (question_id, minute) = field.split_once('/')?;
+                Some((
+                    question_id.parse::<i64>().ok()?,
+                    minute.parse::<i64>().ok()?,
+                    likes,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        //Note: `LikeTimeline::record` expects the buckets of a question in chronological order
+        buckets.sort_unstable();
+
+        let mut timeline = LikeTimeline::default();
+
+        for (question_id, minute, likes) in buckets {
+            timeline.record(question_id, minute, likes);
+        }
+
+        timeline
+    }
+}
+
+fn create_key(key: &str) -> String {
+    format!("likes/{key}")
+}
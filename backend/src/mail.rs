@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 use handlebars::Handlebars;
+use serde::Serialize;
+use shared::WebPushNotification;
 use tracing::instrument;
 
 use crate::{aws_ses_client, ses};
@@ -9,6 +11,20 @@ use crate::{aws_ses_client, ses};
 pub struct MailConfig;
 
 const MAIL_TEMPLATE: &str = include_str!("../mail_template.html.hbs");
+const RECEIPT_TEMPLATE: &str = include_str!("../receipt_template.html.hbs");
+const NOTIFICATION_TEMPLATE: &str = include_str!("../notification_template.html.hbs");
+
+/// content of the receipt of a premium purchase
+#[derive(Serialize, Debug, Default)]
+pub struct Receipt {
+    pub event_name: String,
+    pub order: String,
+    pub date: String,
+    pub amount: String,
+    pub name: Option<String>,
+    pub address: Option<String>,
+    pub vat_id: Option<String>,
+}
 
 impl MailConfig {
     pub const fn new() -> Self {
@@ -32,6 +48,75 @@ impl MailConfig {
         Ok(content)
     }
 
+    pub fn create_receipt(
+        receipt: &Receipt,
+    ) -> std::result::Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let mut hb = Handlebars::new();
+        hb.register_template_string("receipt", RECEIPT_TEMPLATE)?;
+
+        Ok(hb.render("receipt", receipt)?)
+    }
+
+    fn create_notification(
+        notification: &WebPushNotification,
+    ) -> std::result::Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let mut hb = Handlebars::new();
+        hb.register_template_string("notification", NOTIFICATION_TEMPLATE)?;
+
+        Ok(hb.render("notification", notification)?)
+    }
+
+    /// same content as the Web Push notification, for moderators that opted into mails
+    #[instrument(err, skip(self, notification))]
+    pub async fn send_notification(
+        &self,
+        receiver: String,
+        notification: &WebPushNotification,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        tracing::info!("mail::send_notification");
+
+        let content = Self::create_notification(notification)?;
+
+        let client = aws_ses_client().await?;
+        let response = ses::send_message(
+            &client,
+            &[receiver],
+            &format!("Live-Ask: {}", notification.title),
+            &content,
+            "mail@live-ask.com",
+        )
+        .await?;
+
+        tracing::info!("notification sent response: {:?}", response);
+
+        Ok(())
+    }
+
+    #[instrument(err, skip(self, receipt))]
+    pub async fn send_receipt(
+        &self,
+        receiver: String,
+        receipt: Receipt,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        tracing::info!("mail::send_receipt: {}", receipt.order);
+
+        let content = Self::create_receipt(&receipt)?;
+
+        let client = aws_ses_client().await?;
+        let response = ses::send_message(
+            &client,
+            &[receiver],
+            "Your Live-Ask Receipt",
+            &content,
+            "mail@live-ask.com",
+        )
+        .await?;
+
+        tracing::info!("receipt sent response: {:?}", response);
+
+        Ok(())
+    }
+
     #[instrument(err, skip(self, mod_link))]
     pub async fn send_mail(
         &self,
@@ -1,28 +1,43 @@
 #![forbid(unsafe_code)]
 
 mod app;
+mod attachments;
 mod auth;
+mod clusters;
+mod counters;
 mod ecs_task_id;
 mod env;
 mod error;
 mod eventsdb;
+mod export;
 mod handle;
+mod like_timeline;
 mod mail;
 mod payment;
+mod poll_voters;
 mod pubsub;
 mod redis_pool;
+mod request_id;
+mod schedule;
+mod sentiment;
 mod ses;
 mod signals;
 mod stripe_webhooks;
+mod summary;
+mod survey_respondents;
 mod tracking;
+mod translate;
+mod typing;
 mod utils;
 mod viewers;
+mod webpush;
 
 use async_redis_session::RedisSessionStore;
 use aws_config::BehaviorVersion;
 use aws_sdk_dynamodb::config::Credentials;
 use axum::{
-    http::header,
+    http::{header, HeaderName},
+    middleware,
     routing::{get, post},
     Router,
 };
@@ -30,24 +45,33 @@ use sentry::integrations::{
     tower::{NewSentryLayer, SentryHttpLayer},
     tracing::EventFilter,
 };
-use std::{iter::once, net::SocketAddr, sync::Arc};
+use std::{iter::once, net::SocketAddr, sync::Arc, time::Duration};
 use tower_http::{
-    cors::CorsLayer, sensitive_headers::SetSensitiveRequestHeadersLayer, trace::TraceLayer,
+    cors::CorsLayer,
+    request_id::{PropagateRequestIdLayer, SetRequestIdLayer},
+    sensitive_headers::SetSensitiveRequestHeadersLayer,
+    trace::TraceLayer,
 };
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use crate::{
-    app::App,
+    app::{App, Stores},
     auth::{admin_user_handler, login_handler, logout_handler},
+    counters::RedisCounters,
     ecs_task_id::server_id,
     env::session_secret,
     error::Result,
     eventsdb::DynamoEventsDB,
     handle::push_handler,
+    like_timeline::RedisLikeTimelines,
     payment::Payment,
+    poll_voters::RedisPollVoters,
     pubsub::PubSubRedis,
     redis_pool::{create_pool, ping_test_redis},
+    schedule::{RedisSchedule, SCHEDULE_INTERVAL_SECS},
+    survey_respondents::RedisSurveyRespondents,
     tracking::Tracking,
+    typing::RedisTyping,
     viewers::RedisViewers,
 };
 
@@ -69,7 +93,9 @@ pub const fn is_debug() -> bool {
 fn setup_cors() -> CorsLayer {
     if use_relaxed_cors() {
         tracing::info!("cors setup: very_permissive");
-        CorsLayer::very_permissive().allow_credentials(true)
+        CorsLayer::very_permissive()
+            .allow_credentials(true)
+            .expose_headers([HeaderName::from_static("x-request-id")])
     } else {
         tracing::info!("cors setup: default");
         CorsLayer::new()
@@ -123,6 +149,14 @@ async fn aws_ses_client() -> Result<aws_sdk_ses::Client> {
     Ok(aws_sdk_ses::Client::new(&config))
 }
 
+async fn aws_s3_client() -> aws_sdk_s3::Client {
+    let config = aws_config::defaults(BehaviorVersion::v2023_11_09());
+
+    let config = config.load().await;
+
+    aws_sdk_s3::Client::new(&config)
+}
+
 async fn dynamo_client() -> Result<aws_sdk_dynamodb::Client> {
     use aws_sdk_dynamodb::Client;
 
@@ -203,13 +237,21 @@ async fn setup_app(
     let payment = payment().await?;
 
     let pubsub = Arc::new(PubSubRedis::new(redis_pool.clone(), redis_url.to_string()));
-    let viewers = Arc::new(RedisViewers::new(redis_pool));
+    let stores = Stores {
+        viewers: Arc::new(RedisViewers::new(redis_pool.clone())),
+        typing: Arc::new(RedisTyping::new(redis_pool.clone())),
+        schedule: Arc::new(RedisSchedule::new(redis_pool.clone())),
+        like_timelines: Arc::new(RedisLikeTimelines::new(redis_pool.clone())),
+        poll_voters: Arc::new(RedisPollVoters::new(redis_pool.clone())),
+        survey_respondents: Arc::new(RedisSurveyRespondents::new(redis_pool.clone())),
+        counters: Arc::new(RedisCounters::new(redis_pool)),
+    };
 
     let eventsdb = Arc::new(DynamoEventsDB::new(dynamo_client().await?, use_local_db()).await?);
     let app = Arc::new(App::new(
         eventsdb,
         Arc::<PubSubRedis>::clone(&pubsub),
-        viewers,
+        stores,
         payment,
         tracking,
         base_url,
@@ -220,6 +262,18 @@ async fn setup_app(
     Ok(app)
 }
 
+/// flips events open/closed once their scheduled start/end passed
+fn spawn_scheduler(app: Arc<App>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(SCHEDULE_INTERVAL_SECS));
+
+        loop {
+            interval.tick().await;
+            app.run_schedule().await;
+        }
+    });
+}
+
 #[tokio::main]
 async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let log_level = std::env::var("RUST_LOG")
@@ -260,6 +314,8 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
     let app = setup_app(&redis_url, &prod_env, &log_level).await?;
 
+    spawn_scheduler(Arc::clone(&app));
+
     let secret = session_secret()
         .ok_or_else(|| error::InternalError::General(String::from("invalid session secret")))?;
 
@@ -271,42 +327,136 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let admin_routes = Router::new()
         .route("/user", get(admin_user_handler))
         .route("/login", post(login_handler))
-        .route("/logout", get(logout_handler));
+        .route("/logout", get(logout_handler))
+        .route("/promo", post(handle::admin_add_promo))
+        .route("/promo/:code", get(handle::admin_get_promo))
+        .route("/dashboard", get(handle::admin_dashboard))
+        .route("/blocklist", get(handle::admin_blocklist))
+        .route("/blocklist", post(handle::admin_block))
+        .route("/blocklist/remove", post(handle::admin_unblock))
+        .route("/event/:id", get(handle::admin_event));
+
+    let blocklist = middleware::from_fn_with_state(Arc::clone(&app), handle::check_blocklist);
 
     let event_routes = Router::new()
         .route("/:id", get(handle::getevent_handler))
         .route("/:id/pwd", post(handle::set_event_password))
-        .route("/add", post(handle::addevent_handler))
+        .route("/:id/updates", get(handle::event_updates_handler))
+        .route("/:id/questions", get(handle::questions_handler))
+        .route("/:id/preview", get(handle::preview_handler))
+        .route(
+            "/add",
+            post(handle::addevent_handler).layer(blocklist.clone()),
+        )
         .route("/editlike/:id", post(handle::editlike_handler))
-        .route("/addquestion/:id", post(handle::addquestion_handler))
-        .route("/question/:id/:question_id", get(handle::get_question));
+        .route("/editdownvote/:id", post(handle::editdownvote_handler))
+        .route("/editreaction/:id", post(handle::editreaction_handler))
+        .route("/pollvote/:id/:poll_id", post(handle::vote_poll_handler))
+        .route("/addword/:id/:cloud_id", post(handle::add_word_handler))
+        .route("/raisehand/:id", post(handle::raise_hand_handler))
+        .route("/lowerhand/:id", post(handle::lower_hand_handler))
+        .route("/survey/:id", post(handle::survey_response_handler))
+        .route("/series/:id", get(handle::series_current_handler))
+        .route("/slug/:slug", get(handle::slug_handler))
+        .route(
+            "/addquestion/:id",
+            post(handle::addquestion_handler).layer(blocklist),
+        )
+        .route("/attachment/:id", post(handle::request_attachment_handler))
+        .route("/attachment/:id/:file", get(handle::get_attachment_handler))
+        .route("/logo/:id/:file", get(handle::get_logo_handler))
+        .route("/question/:id/:question_id", get(handle::get_question))
+        .route(
+            "/translate/:id/:question_id/:language",
+            get(handle::translate_question),
+        );
 
     #[rustfmt::skip]
     let mod_routes = Router::new()
         .route("/:id/:secret", get(handle::mod_get_event))
         .route("/upgrade/:id/:secret", get(handle::mod_premium_upgrade))
         .route("/capture/:id/:order", get(handle::mod_premium_capture))
+        .route("/trial/:id/:secret", post(handle::mod_start_trial))
+        .route("/receipt/:id/:secret", get(handle::mod_receipt))
+        .route("/receipt/:id/:secret", post(handle::mod_edit_receipt))
+        .route("/webpush/:id/:secret", post(handle::mod_push_subscribe))
+        .route("/webpush/:id/:secret/remove", post(handle::mod_push_unsubscribe))
         .route("/delete/:id/:secret", get(handle::mod_delete_event))
+        .route("/duplicate/:id/:secret", post(handle::mod_duplicate_event))
         .route("/question/:id/:secret/:question_id", get(handle::mod_get_question))
+        .route(
+            "/translate/:id/:secret/:question_id/:language",
+            get(handle::mod_translate_question),
+        )
+        .route("/attachment/:id/:secret/:file", get(handle::mod_get_attachment))
+        .route("/stats/likes/:id/:secret", get(handle::mod_like_timeline))
+        .route("/stats/event/:id/:secret", get(handle::mod_event_stats))
+        .route("/summary/:id/:secret", post(handle::mod_question_summary))
+        .route("/export/:id/:secret/export.csv", get(handle::mod_export_csv))
+        .route("/export/:id/:secret/export.pdf", get(handle::mod_export_pdf))
+        .route("/export/:id/:secret/export.md", get(handle::mod_export_markdown))
+        .route("/export/:id/:secret/slides.html", get(handle::mod_export_slides))
+        .route("/stats/survey/:id/:secret", get(handle::mod_survey_results))
         .route("/questionmod/:id/:secret/:question_id", post(handle::mod_edit_question))
+        .route("/questionnote/:id/:secret/:question_id", post(handle::mod_question_note))
+        .route("/questionsmod/:id/:secret", post(handle::mod_edit_questions))
+        .route("/clusters/:id/:secret", get(handle::mod_question_clusters))
+        .route("/merge/:id/:secret", post(handle::mod_merge_questions))
+        .route("/series/:id/:secret", post(handle::mod_edit_series))
+        .route("/series/:id/:secret/archive", get(handle::mod_series_archive))
+        .route("/slug/:id/:secret", post(handle::mod_edit_slug))
+        .route("/logo/:id/:secret", post(handle::mod_request_logo))
+        .route("/session/:id/:secret", post(handle::mod_add_session))
+        .route("/sessionmod/:id/:secret/:session_id", post(handle::mod_edit_session))
+        .route("/poll/:id/:secret", post(handle::mod_add_poll))
+        .route("/pollmod/:id/:secret/:poll_id", post(handle::mod_edit_poll))
+        .route("/wordcloud/:id/:secret", post(handle::mod_add_word_cloud))
+        .route("/wordcloudmod/:id/:secret/:cloud_id", post(handle::mod_edit_word_cloud))
+        .route("/speakers/:id/:secret", post(handle::mod_edit_speakers))
+        .route("/timer/:id/:secret", post(handle::mod_edit_timer))
         .route("/:id/:secret", post(handle::mod_edit_event));
 
+    let subscription_routes = Router::new()
+        .route("/", post(handle::request_subscription_handler))
+        .route("/:account", get(handle::subscription_state_handler));
+
+    let org_routes = Router::new()
+        .route("/", post(handle::add_org_handler))
+        .route("/checkout/:session", get(handle::org_checkout_handler))
+        .route("/:id/:token", get(handle::org_workspace_handler))
+        .route("/:id/:token/member", post(handle::org_add_member))
+        .route(
+            "/:id/:token/member/:member/remove",
+            post(handle::org_remove_member),
+        )
+        .route("/:id/:token/event", post(handle::org_add_event))
+        .route("/:id/:token/subscription", post(handle::org_subscription));
+
     #[rustfmt::skip]
     let router = Router::new()
         .route("/api/ping", get(handle::ping_handler))
         .route("/api/version", get(handle::version_handler))
+        .route("/api/webpush/key", get(handle::web_push_key_handler))
         .route("/api/error", get(handle::error_handler))
         .route("/api/payment/stripe/webhook", post(stripe_webhooks::handle_webhook))
         .route("/push/:id", get(push_handler))
         .nest("/api/event", event_routes)
         .nest("/api/mod/event", mod_routes)
+        .nest("/api/subscription", subscription_routes)
+        .nest("/api/org", org_routes)
         .nest("/api/admin", admin_routes)
+        .layer(middleware::from_fn_with_state(
+            Arc::clone(&app),
+            handle::track_response,
+        ))
         .layer(auth_layer)
         .layer(session_layer)
         .layer(SetSensitiveRequestHeadersLayer::new(once(header::COOKIE)))
         .layer(SentryHttpLayer::with_transaction())
         .layer(NewSentryLayer::new_from_top())
-        .layer(TraceLayer::new_for_http())
+        .layer(TraceLayer::new_for_http().make_span_with(request_id::request_span))
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(SetRequestIdLayer::x_request_id(request_id::MakeRequestUlid))
         .layer(setup_cors())
         .with_state(Arc::clone(&app));
 
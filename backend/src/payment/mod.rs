@@ -1,19 +1,38 @@
 mod error;
 
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use stripe::{
-    CheckoutSession, CheckoutSessionId, CheckoutSessionMode, CheckoutSessionStatus, Client,
-    CreateCheckoutSession, CreateCheckoutSessionLineItems, ListProducts,
+    CheckoutSession, CheckoutSessionId, CheckoutSessionMode, CheckoutSessionStatus, Client, Coupon,
+    CouponDuration, CreateCheckoutSession, CreateCheckoutSessionDiscounts,
+    CreateCheckoutSessionLineItems, CreateCheckoutSessionSubscriptionData, CreateCoupon,
+    ListProducts,
 };
 
 pub use self::error::PaymentError;
 use self::error::PaymentResult;
 
+/// what a checkout was paid with, printed on the receipt
+#[derive(Clone, Debug)]
+pub struct PaymentReceipt {
+    pub email: Option<String>,
+    /// in the smallest unit of `currency`, e.g. cents
+    pub amount: i64,
+    pub currency: String,
+    pub created: i64,
+}
+
+/// stripe coupon backing the promo code `code`
+fn coupon_id(code: &str) -> String {
+    format!("liveask-{code}")
+}
+
 #[derive(Clone)]
 pub struct Payment {
     client: Client,
     price: Option<String>,
+    /// recurring price of the product with `id: subscription` metadata
+    subscription_price: Option<String>,
 }
 
 #[cfg(test)]
@@ -23,6 +42,7 @@ impl Default for Payment {
         Self {
             client: Client::new(String::new()),
             price: None,
+            subscription_price: None,
         }
     }
 }
@@ -33,12 +53,15 @@ impl Payment {
         Self {
             client,
             price: None,
+            subscription_price: None,
         }
     }
 
     pub async fn authenticate(&mut self, live: bool) -> PaymentResult<String> {
         let res = stripe::Product::list(&self.client, &ListProducts::new()).await?;
 
+        let mut premium = None;
+
         for p in &res.data {
             tracing::info!(
                 "[stripe] prod: {:?} [id: {}, live: {:?}, active: {:?}, price: {:?}]",
@@ -49,52 +72,98 @@ impl Payment {
                 p.default_price,
             );
 
-            if p.metadata
+            if p.livemode.unwrap_or_default() != live {
+                continue;
+            }
+
+            let price = || {
+                p.default_price
+                    .as_ref()
+                    .map(|price| price.id().to_string())
+                    .ok_or_else(|| PaymentError::Generic("default price not set".into()))
+            };
+
+            match p
+                .metadata
                 .as_ref()
                 .and_then(|meta| meta.get("id"))
-                .is_some_and(|id| id == "premium")
+                .map(String::as_str)
             {
-                tracing::info!("[stripe] prod id: {:?} is premium package", p.id);
-
-                if p.livemode.unwrap_or_default() == live {
-                    self.price = Some(
-                        p.default_price
-                            .as_ref()
-                            .ok_or_else(|| PaymentError::Generic("default price not set".into()))?
-                            .id()
-                            .to_string(),
-                    );
-
-                    return Ok(p.id.to_string());
+                Some("premium") => {
+                    tracing::info!("[stripe] prod id: {:?} is premium package", p.id);
+
+                    self.price = Some(price()?);
+                    premium = Some(p.id.to_string());
                 }
+                Some("subscription") => {
+                    tracing::info!("[stripe] prod id: {:?} is subscription", p.id);
+
+                    self.subscription_price = Some(price()?);
+                }
+                _ => {}
             }
         }
 
-        Err(PaymentError::Generic(String::from(
-            "no premium product found",
-        )))
+        if self.subscription_price.is_none() {
+            tracing::warn!("[stripe] no subscription product found");
+        }
+
+        premium.ok_or_else(|| PaymentError::Generic(String::from("no premium product found")))
     }
 
+    /// one-off discount applied at checkout when `code` is used,
+    /// stripe refuses checkouts with it once `max_uses` purchases redeemed it
+    pub async fn create_coupon(
+        &self,
+        code: &str,
+        percent_off: u8,
+        max_uses: u32,
+    ) -> PaymentResult<()> {
+        let id = coupon_id(code);
+
+        let mut params = CreateCoupon::new();
+        params.id = Some(&id);
+        params.name = Some(code);
+        params.percent_off = Some(f64::from(percent_off));
+        params.duration = Some(CouponDuration::Once);
+        params.max_redemptions = Some(i64::from(max_uses));
+
+        Coupon::create(&self.client, params).await?;
+
+        Ok(())
+    }
+
+    /// `promo` is an already validated promo code, it ends up in the session metadata
     pub async fn create_order(
         &self,
         event: &str,
         mod_url: &str,
         return_url: &str,
+        promo: Option<&str>,
     ) -> PaymentResult<String> {
         let checkout_session = {
+            let mut metadata = vec![
+                (String::from("event"), event.to_string()),
+                (String::from("url"), mod_url.to_string()),
+            ];
+
             let mut params = CreateCheckoutSession::new();
             params.cancel_url = Some(mod_url);
             params.success_url = Some(return_url);
             params.client_reference_id = Some(event);
-            params.allow_promotion_codes = Some(true);
-            params.metadata = Some(
-                vec![
-                    (String::from("event"), event.to_string()),
-                    (String::from("url"), mod_url.to_string()),
-                ]
-                .into_iter()
-                .collect(),
-            );
+
+            //Note: stripe refuses sessions that allow promotion codes and apply a discount
+            if let Some(code) = promo {
+                metadata.push((String::from("promo"), code.to_string()));
+                params.discounts = Some(vec![CreateCheckoutSessionDiscounts {
+                    coupon: Some(coupon_id(code)),
+                    ..Default::default()
+                }]);
+            } else {
+                params.allow_promotion_codes = Some(true);
+            }
+
+            params.metadata = Some(metadata.into_iter().collect());
             params.mode = Some(CheckoutSessionMode::Payment);
             params.line_items = Some(vec![CreateCheckoutSessionLineItems {
                 quantity: Some(1),
@@ -114,6 +183,53 @@ impl Payment {
             .ok_or_else(|| PaymentError::Generic("no url in payment session".into()))
     }
 
+    /// checkout of a recurring subscription, `account` comes back in the webhooks,
+    /// `org` is kept with the checkout session for [`Self::retrieve_org`]
+    pub async fn create_subscription(
+        &self,
+        account: &str,
+        org: Option<&str>,
+        cancel_url: &str,
+        return_url: &str,
+    ) -> PaymentResult<String> {
+        let metadata = || {
+            vec![(String::from("account"), account.to_string())]
+                .into_iter()
+                .collect()
+        };
+
+        let checkout_session = {
+            let mut params = CreateCheckoutSession::new();
+            params.cancel_url = Some(cancel_url);
+            params.success_url = Some(return_url);
+            params.client_reference_id = Some(account);
+            params.allow_promotion_codes = Some(true);
+            let mut session_metadata: HashMap<String, String> = metadata();
+            if let Some(org) = org {
+                session_metadata.insert(String::from("org"), org.to_string());
+            }
+            params.metadata = Some(session_metadata);
+            params.subscription_data = Some(CreateCheckoutSessionSubscriptionData {
+                metadata: Some(metadata()),
+                ..Default::default()
+            });
+            params.mode = Some(CheckoutSessionMode::Subscription);
+            params.line_items = Some(vec![CreateCheckoutSessionLineItems {
+                quantity: Some(1),
+                price: Some(self.subscription_price.clone().ok_or_else(|| {
+                    PaymentError::Generic("subscription price id not defined".into())
+                })?),
+                ..Default::default()
+            }]);
+
+            CheckoutSession::create(&self.client, params).await?
+        };
+
+        checkout_session
+            .url
+            .ok_or_else(|| PaymentError::Generic("no url in payment session".into()))
+    }
+
     pub async fn retrieve_event_state(&self, session_id: String) -> PaymentResult<(String, bool)> {
         let sess = CheckoutSessionId::from_str(session_id.as_str())?;
 
@@ -126,4 +242,46 @@ impl Payment {
 
         Ok((event, completed))
     }
+
+    /// promo code the checkout was created with
+    pub async fn retrieve_promo(&self, session_id: &str) -> PaymentResult<Option<String>> {
+        let sess = CheckoutSessionId::from_str(session_id)?;
+
+        let sess = CheckoutSession::retrieve(&self.client, &sess, &[]).await?;
+
+        Ok(sess
+            .metadata
+            .as_ref()
+            .and_then(|meta| meta.get("promo"))
+            .cloned())
+    }
+
+    /// organization the subscription checkout was started for
+    pub async fn retrieve_org(&self, session_id: &str) -> PaymentResult<Option<String>> {
+        let sess = CheckoutSessionId::from_str(session_id)?;
+
+        let sess = CheckoutSession::retrieve(&self.client, &sess, &[]).await?;
+
+        Ok(sess
+            .metadata
+            .as_ref()
+            .and_then(|meta| meta.get("org"))
+            .cloned())
+    }
+
+    pub async fn retrieve_receipt(&self, session_id: &str) -> PaymentResult<PaymentReceipt> {
+        let sess = CheckoutSessionId::from_str(session_id)?;
+
+        let sess = CheckoutSession::retrieve(&self.client, &sess, &[]).await?;
+
+        Ok(PaymentReceipt {
+            email: sess.customer_details.and_then(|details| details.email),
+            amount: sess.amount_total.unwrap_or_default(),
+            currency: sess
+                .currency
+                .map(|currency| currency.to_string())
+                .unwrap_or_default(),
+            created: sess.created,
+        })
+    }
 }
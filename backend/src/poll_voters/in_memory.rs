@@ -0,0 +1,27 @@
+use super::PollVoters;
+use async_trait::async_trait;
+use std::{collections::HashSet, sync::Arc};
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemoryPollVoters {
+    /// `{event}/{poll}/{participant}`
+    pub votes: Arc<RwLock<HashSet<String>>>,
+}
+
+#[async_trait]
+impl PollVoters for InMemoryPollVoters {
+    async fn add(&self, event: &str, poll: i64, participant: &str) -> bool {
+        self.votes
+            .write()
+            .await
+            .insert(format!("{event}/{poll}/{participant}"))
+    }
+
+    async fn remove(&self, event: &str, poll: i64, participant: &str) {
+        self.votes
+            .write()
+            .await
+            .remove(&format!("{event}/{poll}/{participant}"));
+    }
+}
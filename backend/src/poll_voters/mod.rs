@@ -0,0 +1,17 @@
+mod in_memory;
+mod redis;
+
+pub use self::redis::RedisPollVoters;
+#[cfg(test)]
+pub use in_memory::InMemoryPollVoters;
+
+use async_trait::async_trait;
+
+/// participants that voted per poll, shared across all server instances
+#[async_trait]
+pub trait PollVoters: Send + Sync {
+    /// records the vote of `participant`, `false` if it voted in this poll already
+    async fn add(&self, event: &str, poll: i64, participant: &str) -> bool;
+    /// forgets a vote that could not be stored, so the participant can try again
+    async fn remove(&self, event: &str, poll: i64, participant: &str);
+}
@@ -0,0 +1,62 @@
+use super::PollVoters;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use tracing::instrument;
+
+/// set per poll: participant tokens that voted
+pub struct RedisPollVoters {
+    redis: deadpool_redis::Pool,
+}
+
+impl RedisPollVoters {
+    pub const fn new(pool: deadpool_redis::Pool) -> Self {
+        Self { redis: pool }
+    }
+}
+
+/// refreshed on every vote, so only sets of polls nobody votes in anymore expire
+const KEY_TTL: usize = 30 * 24 * 60 * 60;
+
+#[async_trait]
+impl PollVoters for RedisPollVoters {
+    #[instrument(skip(self))]
+    async fn add(&self, event: &str, poll: i64, participant: &str) -> bool {
+        //Note: votes keep working while redis is unavailable, they are just not deduplicated
+        let Ok(mut db) = self.redis.get().await else {
+            tracing::error!("poll voters: redis unavailable");
+            return true;
+        };
+
+        let key = create_key(event, poll);
+
+        let added = match db.sadd::<_, _, isize>(&key, participant).await {
+            Ok(added) => added > 0,
+            Err(e) => {
+                tracing::error!("poll voters add error: {e}");
+                true
+            }
+        };
+
+        db.expire::<_, isize>(&key, KEY_TTL).await.ok();
+
+        added
+    }
+
+    #[instrument(skip(self))]
+    async fn remove(&self, event: &str, poll: i64, participant: &str) {
+        let Ok(mut db) = self.redis.get().await else {
+            return;
+        };
+
+        if let Err(e) = db
+            .srem::<_, _, isize>(create_key(event, poll), participant)
+            .await
+        {
+            tracing::error!("poll voters remove error: {e}");
+        }
+    }
+}
+
+fn create_key(event: &str, poll: i64) -> String {
+    format!("pollvoters/{event}/{poll}")
+}
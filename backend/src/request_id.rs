@@ -0,0 +1,31 @@
+use axum::http::{HeaderValue, Request};
+use tower_http::request_id::{MakeRequestId, RequestId};
+use ulid::Ulid;
+
+/// every response carries the id in `x-request-id` so users can quote it when reporting errors
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MakeRequestUlid;
+
+impl MakeRequestId for MakeRequestUlid {
+    fn make_request_id<B>(&mut self, _request: &Request<B>) -> Option<RequestId> {
+        HeaderValue::from_str(&Ulid::new().to_string())
+            .ok()
+            .map(RequestId::new)
+    }
+}
+
+/// request span of the `TraceLayer` including the request id to find the logs of a reported error
+pub fn request_span<B>(request: &Request<B>) -> tracing::Span {
+    let request_id = request
+        .headers()
+        .get("x-request-id")
+        .and_then(|id| id.to_str().ok())
+        .unwrap_or_default();
+
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        request_id,
+    )
+}
@@ -0,0 +1,33 @@
+use super::Schedule;
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemorySchedule {
+    /// event -> timestamp of its next transition
+    pub events: Arc<RwLock<HashMap<String, i64>>>,
+}
+
+#[async_trait]
+impl Schedule for InMemorySchedule {
+    async fn add(&self, event: &str, at: i64) {
+        self.events.write().await.insert(event.to_string(), at);
+    }
+
+    async fn take_due(&self, now: i64) -> Vec<String> {
+        let mut events = self.events.write().await;
+
+        let due = events
+            .iter()
+            .filter(|(_, at)| **at <= now)
+            .map(|(event, _)| event.clone())
+            .collect::<Vec<_>>();
+
+        for event in &due {
+            events.remove(event);
+        }
+
+        due
+    }
+}
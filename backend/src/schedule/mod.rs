@@ -0,0 +1,20 @@
+mod in_memory;
+mod redis;
+
+pub use self::redis::RedisSchedule;
+#[cfg(test)]
+pub use in_memory::InMemorySchedule;
+
+use async_trait::async_trait;
+
+/// how often every server instance checks for due open/close transitions
+pub const SCHEDULE_INTERVAL_SECS: u64 = 10;
+
+/// pending scheduled state changes of events, shared across all server instances
+#[async_trait]
+pub trait Schedule: Send + Sync {
+    /// (re)schedules `event` to be processed at the unix timestamp `at`
+    async fn add(&self, event: &str, at: i64);
+    /// removes and returns all events due at `now`, each event is only handed to one caller
+    async fn take_due(&self, now: i64) -> Vec<String>;
+}
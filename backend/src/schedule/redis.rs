@@ -0,0 +1,60 @@
+use super::Schedule;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use tracing::instrument;
+
+const KEY: &str = "schedule";
+
+/// one sorted set for all events: event -> timestamp of its next transition
+pub struct RedisSchedule {
+    redis: deadpool_redis::Pool,
+}
+
+impl RedisSchedule {
+    pub const fn new(pool: deadpool_redis::Pool) -> Self {
+        Self { redis: pool }
+    }
+}
+
+#[async_trait]
+impl Schedule for RedisSchedule {
+    #[instrument(skip(self))]
+    async fn add(&self, event: &str, at: i64) {
+        let Ok(mut db) = self.redis.get().await else {
+            tracing::error!("schedule: redis unavailable");
+            return;
+        };
+
+        if let Err(e) = db.zadd::<_, _, _, isize>(KEY, event, at).await {
+            tracing::error!("schedule add error: {e}");
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn take_due(&self, now: i64) -> Vec<String> {
+        let Ok(mut db) = self.redis.get().await else {
+            return Vec::new();
+        };
+
+        let due = db
+            .zrangebyscore::<_, _, _, Vec<String>>(KEY, "-inf", now)
+            .await
+            .unwrap_or_default();
+
+        let mut taken = Vec::with_capacity(due.len());
+
+        //Note: only the instance that actually removed the entry processes it
+        for event in due {
+            if db
+                .zrem::<_, _, isize>(KEY, &event)
+                .await
+                .unwrap_or_default()
+                > 0
+            {
+                taken.push(event);
+            }
+        }
+
+        taken
+    }
+}
@@ -0,0 +1,182 @@
+use shared::Sentiment;
+use std::cmp::Ordering;
+
+/// words lifting the tone of a question
+const POSITIVE_WORDS: &[&str] = &[
+    "good",
+    "great",
+    "love",
+    "loved",
+    "thanks",
+    "thank",
+    "awesome",
+    "excellent",
+    "amazing",
+    "helpful",
+    "nice",
+    "enjoy",
+    "enjoyed",
+    "appreciate",
+    "interesting",
+    "fantastic",
+    "brilliant",
+    "cool",
+    "happy",
+    "glad",
+    "wonderful",
+    "best",
+    "impressive",
+    "inspiring",
+    "congrats",
+    "congratulations",
+    "danke",
+    "super",
+    "toll",
+    "gut",
+    "klasse",
+    "prima",
+    "spannend",
+];
+
+/// words dragging the tone of a question down
+const NEGATIVE_WORDS: &[&str] = &[
+    "bad",
+    "worst",
+    "terrible",
+    "awful",
+    "hate",
+    "useless",
+    "boring",
+    "wrong",
+    "fail",
+    "failed",
+    "failure",
+    "broken",
+    "disappointed",
+    "disappointing",
+    "waste",
+    "ridiculous",
+    "horrible",
+    "poor",
+    "annoying",
+    "angry",
+    "lie",
+    "lies",
+    "shame",
+    "nonsense",
+    "sucks",
+    "schlecht",
+    "schlimm",
+    "peinlich",
+    "unsinn",
+    "langweilig",
+];
+
+/// insults, these weigh twice as much as plain negative words
+const HOSTILE_WORDS: &[&str] = &[
+    "idiot",
+    "idiots",
+    "stupid",
+    "moron",
+    "morons",
+    "dumb",
+    "liar",
+    "liars",
+    "pathetic",
+    "incompetent",
+    "clown",
+    "clowns",
+    "scam",
+    "fraud",
+    "disgrace",
+    "disgusting",
+    "dumm",
+    "idioten",
+    "luegner",
+];
+
+/// flip the tone of the word right after them
+const NEGATIONS: &[&str] = &[
+    "not", "no", "never", "don't", "dont", "isn't", "isnt", "doesn't", "doesnt", "wasn't", "wasnt",
+    "nicht", "kein", "keine", "nie",
+];
+
+/// lexicon based tone of `text`, cheap enough to run on every incoming question
+pub fn classify(text: &str) -> Sentiment {
+    let mut score = 0_i32;
+    let mut negated = false;
+
+    for word in text
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+    {
+        let weight = word_weight(&word);
+
+        score += if negated { -weight } else { weight };
+        negated = NEGATIONS.contains(&word.as_str());
+    }
+
+    match score.cmp(&0) {
+        Ordering::Greater => Sentiment::Positive,
+        Ordering::Less => Sentiment::Negative,
+        Ordering::Equal => Sentiment::Neutral,
+    }
+}
+
+fn word_weight(word: &str) -> i32 {
+    if HOSTILE_WORDS.contains(&word) {
+        -2
+    } else if NEGATIVE_WORDS.contains(&word) {
+        -1
+    } else if POSITIVE_WORDS.contains(&word) {
+        1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("Great talk, thanks!"), Sentiment::Positive);
+        assert_eq!(classify("This was boring and useless"), Sentiment::Negative);
+        assert_eq!(
+            classify("When does the next session start?"),
+            Sentiment::Neutral
+        );
+        assert_eq!(classify(""), Sentiment::Neutral);
+    }
+
+    #[test]
+    fn test_negation() {
+        assert_eq!(classify("This is not good"), Sentiment::Negative);
+        assert_eq!(classify("That wasn't bad at all"), Sentiment::Positive);
+        //only the very next word is flipped
+        assert_eq!(classify("not the best talk"), Sentiment::Positive);
+    }
+
+    #[test]
+    fn test_hostile_words() {
+        assert_eq!(word_weight("idiot"), -2);
+        assert_eq!(word_weight("Idiot"), 0);
+        assert_eq!(
+            classify("Great talk, but what an idiot"),
+            Sentiment::Negative
+        );
+    }
+
+    #[test]
+    fn test_mixed() {
+        assert_eq!(classify("Good idea, bad execution"), Sentiment::Neutral);
+        assert_eq!(classify("Thanks, but you are a liar"), Sentiment::Negative);
+        assert_eq!(
+            classify("Super spannend, aber langweilig erklärt"),
+            Sentiment::Positive
+        );
+    }
+}
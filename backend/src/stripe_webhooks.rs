@@ -4,11 +4,12 @@ use axum::{
     response::{Html, IntoResponse, Response},
 };
 use reqwest::StatusCode;
-use stripe::{Event, EventObject, EventType};
+use stripe::{CheckoutSessionMode, Event, EventObject, EventType, SubscriptionStatus};
 
 use crate::{app::SharedApp, env, error::InternalError};
 
-pub struct StripeEvent(Event);
+/// verified stripe event along with the raw payload it was parsed from
+pub struct StripeEvent(Event, String);
 
 #[async_trait::async_trait]
 impl<S, B> FromRequest<S, B> for StripeEvent
@@ -33,30 +34,97 @@ where
         //TODO: do not read env everytime
         let secret = std::env::var(env::ENV_STRIPE_HOOK_SECRET).unwrap_or_default();
 
-        Ok(Self(
-            stripe::Webhook::construct_event(
-                &payload,
-                signature.to_str().unwrap_or_default(),
-                &secret,
-            )
-            .map_err(|_| StatusCode::BAD_REQUEST.into_response())?,
-        ))
+        if secret.is_empty() {
+            tracing::error!("[hooks] no webhook secret configured");
+            return Err(StatusCode::SERVICE_UNAVAILABLE.into_response());
+        }
+
+        //Note: also rejects signatures older than five minutes to prevent replays
+        let event = stripe::Webhook::construct_event(
+            &payload,
+            signature.to_str().unwrap_or_default(),
+            &secret,
+        )
+        .map_err(|e| {
+            tracing::warn!("[hooks] invalid signature: {e}");
+            StatusCode::BAD_REQUEST.into_response()
+        })?;
+
+        Ok(Self(event, payload))
     }
 }
 
 pub async fn handle_webhook(
     State(app): State<SharedApp>,
-    StripeEvent(event): StripeEvent,
+    StripeEvent(event, payload): StripeEvent,
 ) -> std::result::Result<impl IntoResponse, InternalError> {
+    if !app.webhook_received(event.id.as_str(), payload).await? {
+        tracing::info!("[hooks] duplicate delivery: {}", event.id);
+        return Ok(Html(""));
+    }
+
+    let id = event.id.as_str().to_string();
+
+    if let Err(e) = process_event(&app, event).await {
+        tracing::error!("[hooks] processing {id} failed: {e}");
+
+        //Note: stripe retries deliveries that were not answered with a 2xx
+        app.webhook_failed(&id).await;
+        return Err(InternalError::General(format!(
+            "stripe webhook {id} not processed"
+        )));
+    }
+
+    Ok(Html(""))
+}
+
+async fn process_event(app: &SharedApp, event: Event) -> std::result::Result<(), InternalError> {
+    let created = event.created;
+
     match event.type_ {
         EventType::CheckoutSessionCompleted => {
             if let EventObject::CheckoutSession(session) = event.data.object {
                 tracing::info!("[hooks] CheckoutSessionCompleted: {:?}", session.id);
 
-                if let Some(event) = session.client_reference_id {
-                    if let Err(e) = app.payment_webhook(session.id.to_string(), event).await {
-                        tracing::error!("[hooks] failed: {e}");
+                if matches!(session.mode, CheckoutSessionMode::Subscription) {
+                    //Note: the client reference of a subscription checkout is the account token
+                    if let (Some(account), Some(subscription)) =
+                        (session.client_reference_id, session.subscription)
+                    {
+                        app.subscription_webhook(
+                            account,
+                            subscription.id().to_string(),
+                            true,
+                            created,
+                        )
+                        .await?;
                     }
+                } else if let Some(event) = session.client_reference_id {
+                    app.payment_webhook(session.id.to_string(), event).await?;
+                }
+            }
+        }
+        EventType::CustomerSubscriptionUpdated | EventType::CustomerSubscriptionDeleted => {
+            if let EventObject::Subscription(subscription) = event.data.object {
+                tracing::info!(
+                    "[hooks] subscription: {:?} [{:?}]",
+                    subscription.id,
+                    subscription.status
+                );
+
+                if let Some(account) = subscription.metadata.get("account") {
+                    let active = matches!(
+                        subscription.status,
+                        SubscriptionStatus::Active | SubscriptionStatus::Trialing
+                    );
+
+                    app.subscription_webhook(
+                        account.clone(),
+                        subscription.id.to_string(),
+                        active,
+                        created,
+                    )
+                    .await?;
                 }
             }
         }
@@ -66,5 +134,5 @@ pub async fn handle_webhook(
         }
     };
 
-    Ok(Html(""))
+    Ok(())
 }
@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SummaryError {
+    #[error("Summaries not configured")]
+    NotConfigured,
+    /// the endpoint answered without any text
+    #[error("Empty Summary")]
+    Empty,
+    #[error("Request Error: {0}")]
+    Request(#[from] reqwest::Error),
+}
+
+pub type SummaryResult<T> = std::result::Result<T, SummaryError>;
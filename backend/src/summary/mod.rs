@@ -0,0 +1,135 @@
+mod error;
+
+pub use error::SummaryError;
+
+use error::SummaryResult;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::instrument;
+
+use crate::env;
+
+const DEFAULT_MODEL: &str = "gpt-4o-mini";
+/// a summary taking longer than this is given up on
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+const PROMPT: &str = "You summarize the questions of a live Q&A for its moderators. \
+Answer with a single line naming the top themes, like `top themes: pricing, roadmap, hiring`, \
+followed by at most three short bullet points on what people want to know most. \
+Answer in the language most questions are written in.";
+
+#[derive(Clone)]
+struct Endpoint {
+    url: String,
+    key: Option<String>,
+    model: String,
+}
+
+#[derive(Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: [ChatMessage<'a>; 2],
+}
+
+#[derive(Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatAnswer,
+}
+
+#[derive(Deserialize)]
+struct ChatAnswer {
+    content: Option<String>,
+}
+
+/// summarizes questions with a configurable chat completions endpoint (OpenAI compatible)
+#[derive(Clone)]
+pub struct Summarizer {
+    endpoint: Option<Endpoint>,
+    client: reqwest::Client,
+}
+
+impl Summarizer {
+    pub fn new() -> Self {
+        let endpoint = std::env::var(env::ENV_LLM_URL)
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .map(|url| Endpoint {
+                url,
+                key: std::env::var(env::ENV_LLM_KEY)
+                    .ok()
+                    .filter(|key| !key.trim().is_empty()),
+                model: std::env::var(env::ENV_LLM_MODEL)
+                    .ok()
+                    .filter(|model| !model.trim().is_empty())
+                    .unwrap_or_else(|| String::from(DEFAULT_MODEL)),
+            });
+
+        if let Some(endpoint) = &endpoint {
+            tracing::info!("summaries: {} ({})", endpoint.url, endpoint.model);
+        } else {
+            tracing::warn!("no llm endpoint set, use `LA_LLM_URL` to do so");
+        }
+
+        Self {
+            endpoint,
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    pub const fn is_configured(&self) -> bool {
+        self.endpoint.is_some()
+    }
+
+    #[instrument(err, skip_all)]
+    pub async fn summarize(&self, questions: &[String]) -> SummaryResult<String> {
+        let endpoint = self.endpoint.as_ref().ok_or(SummaryError::NotConfigured)?;
+
+        let list = questions
+            .iter()
+            .map(|question| format!("- {}", question.replace('\n', " ")))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut request = self.client.post(&endpoint.url).json(&ChatRequest {
+            model: &endpoint.model,
+            messages: [
+                ChatMessage {
+                    role: "system",
+                    content: PROMPT,
+                },
+                ChatMessage {
+                    role: "user",
+                    content: &list,
+                },
+            ],
+        });
+
+        if let Some(key) = &endpoint.key {
+            request = request.bearer_auth(key);
+        }
+
+        let response: ChatResponse = request.send().await?.error_for_status()?.json().await?;
+
+        response
+            .choices
+            .into_iter()
+            .find_map(|choice| choice.message.content)
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+            .ok_or(SummaryError::Empty)
+    }
+}
@@ -0,0 +1,27 @@
+use super::SurveyRespondents;
+use async_trait::async_trait;
+use std::{collections::HashSet, sync::Arc};
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemorySurveyRespondents {
+    /// `{event}/{participant}`
+    pub responses: Arc<RwLock<HashSet<String>>>,
+}
+
+#[async_trait]
+impl SurveyRespondents for InMemorySurveyRespondents {
+    async fn add(&self, event: &str, participant: &str) -> bool {
+        self.responses
+            .write()
+            .await
+            .insert(format!("{event}/{participant}"))
+    }
+
+    async fn remove(&self, event: &str, participant: &str) {
+        self.responses
+            .write()
+            .await
+            .remove(&format!("{event}/{participant}"));
+    }
+}
@@ -0,0 +1,17 @@
+mod in_memory;
+mod redis;
+
+pub use self::redis::RedisSurveyRespondents;
+#[cfg(test)]
+pub use in_memory::InMemorySurveyRespondents;
+
+use async_trait::async_trait;
+
+/// participants that responded to the survey per event, shared across all server instances
+#[async_trait]
+pub trait SurveyRespondents: Send + Sync {
+    /// records the response of `participant`, `false` if it responded to this survey already
+    async fn add(&self, event: &str, participant: &str) -> bool;
+    /// forgets a response that could not be stored, so the participant can try again
+    async fn remove(&self, event: &str, participant: &str);
+}
@@ -0,0 +1,59 @@
+use super::SurveyRespondents;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use tracing::instrument;
+
+/// set per event: participant tokens that responded to the survey
+pub struct RedisSurveyRespondents {
+    redis: deadpool_redis::Pool,
+}
+
+impl RedisSurveyRespondents {
+    pub const fn new(pool: deadpool_redis::Pool) -> Self {
+        Self { redis: pool }
+    }
+}
+
+/// refreshed on every response, so only sets of surveys nobody responds to anymore expire
+const KEY_TTL: usize = 30 * 24 * 60 * 60;
+
+#[async_trait]
+impl SurveyRespondents for RedisSurveyRespondents {
+    #[instrument(skip(self))]
+    async fn add(&self, event: &str, participant: &str) -> bool {
+        //Note: responses keep working while redis is unavailable, they are just not deduplicated
+        let Ok(mut db) = self.redis.get().await else {
+            tracing::error!("survey respondents: redis unavailable");
+            return true;
+        };
+
+        let key = create_key(event);
+
+        let added = match db.sadd::<_, _, isize>(&key, participant).await {
+            Ok(added) => added > 0,
+            Err(e) => {
+                tracing::error!("survey respondents add error: {e}");
+                true
+            }
+        };
+
+        db.expire::<_, isize>(&key, KEY_TTL).await.ok();
+
+        added
+    }
+
+    #[instrument(skip(self))]
+    async fn remove(&self, event: &str, participant: &str) {
+        let Ok(mut db) = self.redis.get().await else {
+            return;
+        };
+
+        if let Err(e) = db.srem::<_, _, isize>(create_key(event), participant).await {
+            tracing::error!("survey respondents remove error: {e}");
+        }
+    }
+}
+
+fn create_key(event: &str) -> String {
+    format!("surveyrespondents/{event}")
+}
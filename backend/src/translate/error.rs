@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TranslateError {
+    #[error("Translations not configured")]
+    NotConfigured,
+    /// the endpoint answered without any text
+    #[error("Empty Translation")]
+    Empty,
+    #[error("Request Error: {0}")]
+    Request(#[from] reqwest::Error),
+}
+
+pub type TranslateResult<T> = std::result::Result<T, TranslateError>;
@@ -0,0 +1,127 @@
+mod error;
+
+pub use error::TranslateError;
+
+use error::TranslateResult;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+use tracing::instrument;
+
+use crate::env;
+
+/// a translation taking longer than this is given up on
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// cached translations of this instance, dropped all at once when full
+const CACHE_MAX_ENTRIES: usize = 10_000;
+
+#[derive(Clone)]
+struct Endpoint {
+    url: String,
+    key: Option<String>,
+}
+
+#[derive(Serialize)]
+struct TranslateRequest<'a> {
+    q: &'a str,
+    source: &'a str,
+    target: &'a str,
+    format: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_key: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct TranslateResponse {
+    #[serde(rename = "translatedText")]
+    translated_text: Option<String>,
+}
+
+/// translates question texts with a configurable endpoint (LibreTranslate compatible),
+/// results are cached per target language and text
+#[derive(Clone)]
+pub struct Translator {
+    endpoint: Option<Endpoint>,
+    client: reqwest::Client,
+    cache: Arc<RwLock<HashMap<(String, String), String>>>,
+}
+
+impl Translator {
+    pub fn new() -> Self {
+        let endpoint = std::env::var(env::ENV_TRANSLATE_URL)
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .map(|url| Endpoint {
+                url,
+                key: std::env::var(env::ENV_TRANSLATE_KEY)
+                    .ok()
+                    .filter(|key| !key.trim().is_empty()),
+            });
+
+        if let Some(endpoint) = &endpoint {
+            tracing::info!("translations: {}", endpoint.url);
+        } else {
+            tracing::warn!("no translation endpoint set, use `LA_TRANSLATE_URL` to do so");
+        }
+
+        Self {
+            endpoint,
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            cache: Arc::default(),
+        }
+    }
+
+    pub const fn is_configured(&self) -> bool {
+        self.endpoint.is_some()
+    }
+
+    #[instrument(err, skip(self, text))]
+    pub async fn translate(&self, text: &str, language: &str) -> TranslateResult<String> {
+        let endpoint = self
+            .endpoint
+            .as_ref()
+            .ok_or(TranslateError::NotConfigured)?;
+
+        let key = (language.to_string(), text.to_string());
+
+        let cached = self.cache.read().await.get(&key).cloned();
+        if let Some(translation) = cached {
+            return Ok(translation);
+        }
+
+        let response: TranslateResponse = self
+            .client
+            .post(&endpoint.url)
+            .json(&TranslateRequest {
+                q: text,
+                source: "auto",
+                target: language,
+                format: "text",
+                api_key: endpoint.key.as_deref(),
+            })
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let translation = response
+            .translated_text
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+            .ok_or(TranslateError::Empty)?;
+
+        {
+            let mut cache = self.cache.write().await;
+            if cache.len() >= CACHE_MAX_ENTRIES {
+                cache.clear();
+            }
+            cache.insert(key, translation.clone());
+        }
+
+        Ok(translation)
+    }
+}
@@ -0,0 +1,41 @@
+use super::{Typing, TYPING_TIMEOUT_SECS};
+use crate::utils::timestamp_now;
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemoryTyping {
+    /// per event: user -> last typing signal
+    pub users: Arc<RwLock<HashMap<String, HashMap<String, i64>>>>,
+}
+
+#[async_trait]
+impl Typing for InMemoryTyping {
+    async fn signal(&self, event: &str, user: &str) -> usize {
+        let now = timestamp_now();
+        let mut typing = self.users.write().await;
+        let users = typing.entry(event.to_string()).or_default();
+
+        users.insert(user.to_string(), now);
+        users.retain(|_, last| now - *last < TYPING_TIMEOUT_SECS);
+
+        users.len()
+    }
+
+    async fn remove(&self, event: &str, user: &str) -> Option<usize> {
+        let now = timestamp_now();
+        let mut typing = self.users.write().await;
+
+        let users = typing.get_mut(event)?;
+        let removed = users.remove(user).is_some();
+        users.retain(|_, last| now - *last < TYPING_TIMEOUT_SECS);
+        let count = users.len();
+
+        if count == 0 {
+            typing.remove(event);
+        }
+
+        removed.then_some(count)
+    }
+}
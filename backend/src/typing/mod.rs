@@ -0,0 +1,20 @@
+mod in_memory;
+mod redis;
+
+pub use self::redis::RedisTyping;
+#[cfg(test)]
+pub use in_memory::InMemoryTyping;
+
+use async_trait::async_trait;
+
+/// how long a typing signal counts before the participant is considered idle
+pub const TYPING_TIMEOUT_SECS: i64 = 5;
+
+/// tracks participants currently writing a question, shared across all server instances
+#[async_trait]
+pub trait Typing: Send + Sync {
+    /// records a typing signal, returns the number of users currently typing in `event`
+    async fn signal(&self, event: &str, user: &str) -> usize;
+    /// removes the user, returns the remaining count if the user was typing
+    async fn remove(&self, event: &str, user: &str) -> Option<usize>;
+}
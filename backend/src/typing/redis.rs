@@ -0,0 +1,59 @@
+use super::{Typing, TYPING_TIMEOUT_SECS};
+use crate::utils::timestamp_now;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use tracing::instrument;
+
+/// sorted set per event: user -> timestamp of the last signal
+pub struct RedisTyping {
+    redis: deadpool_redis::Pool,
+}
+
+impl RedisTyping {
+    pub const fn new(pool: deadpool_redis::Pool) -> Self {
+        Self { redis: pool }
+    }
+}
+
+const KEY_TTL: usize = 60 * 60;
+
+#[async_trait]
+impl Typing for RedisTyping {
+    #[instrument(skip(self))]
+    async fn signal(&self, event: &str, user: &str) -> usize {
+        let Ok(mut db) = self.redis.get().await else {
+            return 0;
+        };
+
+        let key = create_key(event);
+        let now = timestamp_now();
+
+        db.zadd::<_, _, _, isize>(&key, user, now).await.ok();
+        db.zrembyscore::<_, _, _, isize>(&key, "-inf", now - TYPING_TIMEOUT_SECS)
+            .await
+            .ok();
+        db.expire::<_, isize>(&key, KEY_TTL).await.ok();
+
+        db.zcard::<_, usize>(&key).await.unwrap_or_default()
+    }
+
+    #[instrument(skip(self))]
+    async fn remove(&self, event: &str, user: &str) -> Option<usize> {
+        let mut db = self.redis.get().await.ok()?;
+
+        let key = create_key(event);
+
+        let removed = db.zrem::<_, _, isize>(&key, user).await.unwrap_or_default() > 0;
+        db.zrembyscore::<_, _, _, isize>(&key, "-inf", timestamp_now() - TYPING_TIMEOUT_SECS)
+            .await
+            .ok();
+
+        let count = db.zcard::<_, usize>(&key).await.unwrap_or_default();
+
+        removed.then_some(count)
+    }
+}
+
+fn create_key(key: &str) -> String {
+    format!("typing/{key}")
+}
@@ -1,6 +1,13 @@
-use chrono::Utc;
+use chrono::{Duration, Utc};
 
 pub fn timestamp_now() -> i64 {
     let now = Utc::now();
     now.timestamp_millis().saturating_div(1000)
 }
+
+/// `YYYY-MM-DD` (utc) of the day `days_ago` days before today
+pub fn day_string(days_ago: i64) -> String {
+    (Utc::now() - Duration::days(days_ago))
+        .format("%F")
+        .to_string()
+}
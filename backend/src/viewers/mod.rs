@@ -9,5 +9,17 @@ use async_trait::async_trait;
 pub trait Viewers: Send + Sync {
     async fn count(&self, key: &str) -> i64;
     async fn add(&self, key: &str);
+    /// adds only while the count stays within `max`, returns whether it was added
+    async fn try_add(&self, key: &str, max: i64) -> bool;
     async fn remove(&self, key: &str);
 }
+
+#[cfg(test)]
+impl MockViewers {
+    /// nobody watching, for tests that do not assert on viewers
+    pub fn idle() -> Self {
+        let mut viewers = Self::new();
+        viewers.expect_count().return_const(0_i64);
+        viewers
+    }
+}
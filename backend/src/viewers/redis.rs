@@ -35,6 +35,30 @@ impl Viewers for RedisViewers {
         }
     }
 
+    #[instrument(skip(self))]
+    async fn try_add(&self, key: &str, max: i64) -> bool {
+        //Note: without redis nobody is counted, so nobody is kept waiting either
+        let Ok(mut db) = self.redis.get().await else {
+            return true;
+        };
+
+        let key = create_key(key);
+
+        //Note: the increment is atomic, going over `max` is taken back right away
+        let Ok(count) = db.incr::<_, i64, i64>(key.clone(), 1).await else {
+            return true;
+        };
+
+        if count > max {
+            db.decr::<_, i64, i64>(key, 1).await.ok();
+            return false;
+        }
+
+        db.expire::<_, isize>(key, KEY_TTL).await.ok();
+
+        true
+    }
+
     #[instrument(skip(self))]
     async fn remove(&self, key: &str) {
         if let Ok(mut db) = self.redis.get().await {
@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WebPushError {
+    #[error("Web Push not configured")]
+    NotConfigured,
+    /// the subscription expired or was revoked by the user
+    #[error("Subscription Gone")]
+    Gone,
+    #[error("Push Service Error: {0}")]
+    Push(#[from] web_push::WebPushError),
+}
+
+pub type WebPushResult<T> = std::result::Result<T, WebPushError>;
@@ -0,0 +1,133 @@
+mod error;
+
+pub use error::WebPushError;
+
+use error::WebPushResult;
+use shared::{WebPushNotification, WebPushSubscription};
+use std::sync::Arc;
+use tracing::instrument;
+use web_push::{
+    ContentEncoding, HyperWebPushClient, SubscriptionInfo, VapidSignatureBuilder, WebPushClient,
+    WebPushMessageBuilder,
+};
+
+use crate::env;
+
+/// hosts of the push services run by the browser vendors, subscriptions may only point there
+const PUSH_SERVICE_HOSTS: &[&str] = &[
+    "fcm.googleapis.com",
+    "push.services.mozilla.com",
+    "push.apple.com",
+    "notify.windows.com",
+];
+
+/// whether `endpoint` is a https url of a known push service (or one of its subdomains),
+/// so subscriptions cannot make the server send requests into its own network
+pub fn is_push_service(endpoint: &str) -> bool {
+    let Ok(url) = reqwest::Url::parse(endpoint) else {
+        return false;
+    };
+
+    if url.scheme() != "https" || url.port().is_some() {
+        return false;
+    }
+
+    //Note: ip addresses never match one of the host names
+    let Some(host) = url.host_str().map(str::to_lowercase) else {
+        return false;
+    };
+
+    PUSH_SERVICE_HOSTS.iter().any(|service| {
+        host == *service
+            || host
+                .strip_suffix(service)
+                .is_some_and(|sub| sub.ends_with('.'))
+    })
+}
+
+#[derive(Clone)]
+struct VapidKeys {
+    private: String,
+    public: String,
+}
+
+/// sends Web Push notifications signed with the VAPID keys of the server
+#[derive(Clone)]
+pub struct WebPush {
+    keys: Option<VapidKeys>,
+    client: Arc<HyperWebPushClient>,
+}
+
+impl WebPush {
+    pub fn new() -> Self {
+        let private = std::env::var(env::ENV_VAPID_PRIVATE_KEY)
+            .ok()
+            .filter(|key| !key.trim().is_empty());
+        let public = std::env::var(env::ENV_VAPID_PUBLIC_KEY)
+            .ok()
+            .filter(|key| !key.trim().is_empty());
+
+        let keys = if let (Some(private), Some(public)) = (private, public) {
+            tracing::info!("web push public key: {public}");
+            Some(VapidKeys { private, public })
+        } else {
+            tracing::warn!(
+                "no vapid keys set, use `LA_VAPID_PRIVATE_KEY` and `LA_VAPID_PUBLIC_KEY` to do so"
+            );
+            None
+        };
+
+        Self {
+            keys,
+            client: Arc::new(HyperWebPushClient::new()),
+        }
+    }
+
+    pub const fn is_configured(&self) -> bool {
+        self.keys.is_some()
+    }
+
+    fn keys(&self) -> WebPushResult<&VapidKeys> {
+        self.keys.as_ref().ok_or(WebPushError::NotConfigured)
+    }
+
+    /// application server key the browser subscribes with
+    pub fn public_key(&self) -> WebPushResult<String> {
+        Ok(self.keys()?.public.clone())
+    }
+
+    #[instrument(err, skip_all)]
+    pub async fn send(
+        &self,
+        subscription: &WebPushSubscription,
+        notification: &WebPushNotification,
+    ) -> WebPushResult<()> {
+        let keys = self.keys()?;
+
+        //Note: subscriptions stored before endpoints were checked get dropped
+        if !is_push_service(&subscription.endpoint) {
+            return Err(WebPushError::Gone);
+        }
+
+        let info = SubscriptionInfo::new(
+            &subscription.endpoint,
+            &subscription.keys.p256dh,
+            &subscription.keys.auth,
+        );
+
+        let signature = VapidSignatureBuilder::from_base64(&keys.private, &info)?.build()?;
+
+        let payload = serde_json::to_vec(notification).unwrap_or_default();
+
+        let mut builder = WebPushMessageBuilder::new(&info);
+        builder.set_payload(ContentEncoding::Aes128Gcm, &payload);
+        builder.set_vapid_signature(signature);
+
+        match self.client.send(builder.build()?).await {
+            Err(
+                web_push::WebPushError::EndpointNotValid | web_push::WebPushError::EndpointNotFound,
+            ) => Err(WebPushError::Gone),
+            result => result.map_err(WebPushError::from),
+        }
+    }
+}
@@ -58,17 +58,20 @@ fn process_html_template(git_hash: &str) {
 
             data.insert("fathom", "XWFWPSUF");
             data.insert("sentry", "production");
+            data.insert("api", "https://prod.www.live-ask.com");
         }
         LiveAskEnv::Beta => {
             println!("cargo:warning=env is beta");
 
             data.insert("fathom", "OAMRSQQM");
             data.insert("sentry", "beta");
+            data.insert("api", "https://beta.www.live-ask.com");
         }
         LiveAskEnv::Local => {
             println!("cargo:warning=env is local");
 
             data.insert("sentry", "local");
+            data.insert("api", "http://localhost:8090");
         }
     }
 
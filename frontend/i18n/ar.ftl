@@ -0,0 +1,52 @@
+# Arabic catalog, missing messages fall back to `en.ftl`.
+
+## icon bar
+
+iconbar-share = مشاركة
+iconbar-create-event = إنشاء فعالية
+iconbar-ask = اطرح سؤالاً
+iconbar-language = اللغة
+
+color-scheme-system = المظهر: النظام
+color-scheme-light = المظهر: فاتح
+color-scheme-dark = المظهر: داكن
+
+## footer
+
+footer-about = حول
+footer-privacy = سياسة الخصوصية
+footer-status = الحالة
+
+## home page
+
+home-title = أسئلة جمهورك في الوقت الفعلي
+home-create = أنشئ فعاليتك
+home-example = عرض مثال
+home-try = جرّبه الآن مجاناً!
+home-recent = فعالياتك الأخيرة
+home-recent-viewer = مشاهد
+home-recent-moderator = مشرف
+
+## questions
+
+question-age-now = الآن
+question-age-minutes = منذ { $count } دقيقة
+question-age-hours = منذ { $count } ساعة
+question-age-days = منذ { $count } يوم
+
+question-popup-tag = الوسم الحالي:
+question-popup-placeholder = ما هو سؤالك؟
+question-popup-send = اسأل!
+question-popup-offline = أنت غير متصل، سيُرسل سؤالك عند عودة الاتصال.
+question-popup-offline-attachment = لا يمكن إرسال الصور إلا بعد عودة الاتصال.
+question-popup-cooldown = يمكنك طرح سؤالك التالي بعد { $countdown }.
+question-popup-attachment-size = يُسمح فقط بالصور حتى { $size } ميغابايت.
+question-popup-name = اسمك
+question-popup-name-optional = اسمك (اختياري)
+question-popup-name-required = يطلب المشرف اسماً.
+question-popup-name-too-short = الاسم قصير جداً. الحد الأدنى: { $min }
+question-popup-name-too-long = الاسم طويل جداً. الحد الأقصى: { $max }
+question-popup-too-short = السؤال قصير جداً.
+question-popup-too-long = السؤال طويل جداً. الحد الأقصى: { $max }
+question-popup-min-words = الحد الأدنى لعدد الكلمات: { $min }.
+question-popup-word-too-long = لا يمكن أن تكون أي كلمة أطول من: { $max }.
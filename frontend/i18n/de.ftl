@@ -0,0 +1,500 @@
+# Deutscher Katalog, fehlende Einträge fallen auf `en.ftl` zurück.
+
+## icon bar
+
+iconbar-share = Teilen
+iconbar-create-event = Event erstellen
+iconbar-ask = Frage stellen
+iconbar-home = Startseite
+iconbar-reconnect = Jetzt neu verbinden
+iconbar-language = Sprache
+
+color-scheme-system = Design: System
+color-scheme-light = Design: hell
+color-scheme-dark = Design: dunkel
+
+## footer
+
+footer-about = Über uns
+footer-privacy = Datenschutz
+footer-status = Status
+
+## home page
+
+home-title = Fragen aus deinem Publikum in Echtzeit
+home-intro = Hast du schon einmal ein Meetup oder eine Konferenz organisiert oder eine Podiumsdiskussion moderiert und dir einen einfachen Weg
+    gewünscht, Fragen aus dem Publikum in Echtzeit zu sammeln? Willkommen bei Live-Ask.
+home-create = Erstelle dein Event
+home-example = Beispiel ansehen
+home-incognito = Anonym
+home-incognito-text = Keine Registrierung nötig - alle können Fragen stellen und abstimmen. Die Anonymität der Teilnehmenden sichert freie Meinungsäußerung
+    und eine reibungslose Nutzung.
+home-effortless = Mühelos
+home-effortless-text = Richte dein Event in Sekunden ein! Teile den Link mit deinem Publikum und lass es entscheiden, was wichtig ist.
+home-realtime = Echtzeit
+home-realtime-text = Gemacht für Live-Events. Fragen werden in Echtzeit gestellt und bewertet. So kannst du nahtlos mit allen interagieren.
+home-crossplatform = Plattformübergreifend
+home-crossplatform-text = Nutze Live-Ask auf Smartphone, Tablet, Laptop oder Desktop. Oder wirf es einfach auf deinen Smart-TV!
+home-social = Sozial
+home-social-text = Teilen soll so einfach wie möglich sein. Du hast ein großartiges Event organisiert? Mit Live-Ask teilst du es ganz leicht
+    mit anderen. Du lieferst die Inhalte, wir helfen beim Verbreiten.
+home-try = Jetzt kostenlos ausprobieren!
+home-recent = Deine letzten Events
+home-recent-viewer = Teilnehmer
+home-recent-moderator = Moderator
+
+## new event form
+
+newevent-title = Event erstellen
+newevent-name = Name des Events
+newevent-email = E-Mail (optional)
+newevent-description = Beschreibung des Events
+newevent-start = Beginn (optional)
+newevent-end = Ende (optional)
+newevent-schedule-invalid = Das Ende muss nach dem Beginn liegen.
+newevent-finish = fertig
+newevent-template = Mit einer Vorlage beginnen
+newevent-template-remove = entfernen
+newevent-template-hint = { $placeholder } im Namen einer Vorlage wird durch das aktuelle Datum ersetzt
+newevent-org = Dieses Event wird deinem Team-Workspace hinzugefügt und über dessen Abo abgerechnet.
+newevent-subscription-active = Dein Abo macht dieses Event zu einem Premium-Event.
+newevent-subscription-inactive = Dein Abo ist (noch) nicht aktiv.
+newevent-subscription-offer = Du organisierst regelmäßig Events? Mit einem Abo werden alle deine Events zu Premium-Events.
+newevent-subscribe = abonnieren
+newevent-workspace-open = Team-Workspace öffnen
+newevent-workspace-create = Q&As im Team? Erstelle einen Workspace
+newevent-name-empty = Ein Name ist erforderlich.
+newevent-name-too-short = Der Name muss mindestens { $min } Zeichen lang sein. ({ $len }/{ $min })
+newevent-name-too-long = Der Name darf höchstens { $max } Zeichen lang sein.
+newevent-name-too-many-words = Der Name darf höchstens { $max } Wörter enthalten.
+newevent-description-empty = Die Beschreibung darf nicht leer sein
+newevent-description-too-short = Die Beschreibung muss mindestens { $min } Zeichen lang sein. ({ $len }/{ $min })
+newevent-description-too-long = Die Beschreibung darf höchstens { $max } Zeichen lang sein.
+newevent-email-invalid = Ungültige E-Mail-Adresse
+newevent-unknown-error = unbekannter Fehler
+
+## event page
+
+event-starts-in = Dieses Event beginnt in { $countdown }. Sobald es begonnen hat, kannst du Fragen stellen.
+event-ended-on = Dieses Event endete am { $date }. Du kannst keine Fragen mehr stellen oder bewerten.
+event-ends-on = Dieses Event endet am { $date }.
+event-premium = Dies ist ein Premium-Event
+event-premium-trial = Premium-Test bis { $time }
+event-loading = Event wird geladen…
+event-search = Fragen durchsuchen
+event-shortcuts = Tastenkürzel
+event-bulk-selected = { $count ->
+        [one] { $count } Frage ausgewählt
+       *[other] { $count } Fragen ausgewählt
+    }
+event-bulk-hide = Ausblenden
+event-bulk-answer = Als beantwortet markieren
+event-bulk-tag = Taggen
+event-bulk-untag = Tag entfernen
+event-bulk-clear = Auswahl aufheben
+event-confirm-bulk-hide-title = Die ausgewählten Fragen ausblenden?
+event-share = Event teilen
+event-waiting-room-title = Dieses Event ist gerade voll
+event-waiting-room-text = Du kommst automatisch hinein, sobald ein Platz frei wird. Bitte lass diese Seite geöffnet.
+copy-link = Kopieren
+copy-done = Kopiert ✓
+event-confirm-hide-title = Diese Frage ausblenden?
+event-confirm-hide-text = Teilnehmende sehen sie nicht mehr. Du kannst sie bei den ausgeblendeten Fragen wieder einblenden.
+event-confirm-hide = Ausblenden
+event-confirm-close-title = Dieses Event schließen?
+event-confirm-close-text = Teilnehmende können keine Fragen mehr stellen oder bewerten, bis du es wieder öffnest.
+event-confirm-close = Event schließen
+event-confirm-archive-title = Dieses Event archivieren?
+event-confirm-archive-text = Fragen und Antworten bleiben lesbar, aber es kann nichts mehr hinzugefügt oder bewertet werden.
+event-confirm-archive = Event archivieren
+
+delete-popup-title = Event endgültig löschen
+delete-popup-text = Diese Aktion kann nicht rückgängig gemacht werden. Nur du als Moderator kannst ein Event löschen. Personen, mit denen du dieses Event geteilt hast, können es danach nicht mehr sehen.
+delete-popup-confirm = Löschen
+
+confirm-cancel = Abbrechen
+
+shortcuts-title = Tastenkürzel
+shortcuts-next = Nächste Frage auswählen
+shortcuts-previous = Vorherige Frage auswählen
+shortcuts-answer = Ausgewählte Frage als beantwortet markieren
+shortcuts-hide = Ausgewählte Frage ausblenden
+shortcuts-pin = Ausgewählte Frage oben anheften
+shortcuts-search = Fragen durchsuchen
+shortcuts-help = Diese Liste ein- oder ausblenden
+shortcuts-escape = Auswahl aufheben
+shortcuts-close = Schließen
+
+event-not-found = Event nicht gefunden
+event-deleted = Event gelöscht
+event-closed = Dieses Event wurde vom Moderator geschlossen. Du kannst keine Fragen mehr stellen oder bewerten.
+event-closed-updates = Änderungen durch den Moderator siehst du weiterhin in Echtzeit.
+event-archived = Dieses Event ist archiviert. Fragen und Antworten bleiben lesbar, aber es kann nichts mehr hinzugefügt oder bewertet werden.
+event-vote-only = Der Moderator hat für dieses Event nur Abstimmungen erlaubt. Du kannst keine neuen Fragen stellen, aber weiterhin abstimmen.
+event-timed-out = Dieses kostenlose Event ist abgelaufen. Nur der Moderator kann es upgraden, damit es wieder erreichbar ist.
+event-scan-to-join = scannen zum Mitmachen
+event-screening-note = Der Moderator prüft neue Fragen. Sie müssen erst freigegeben werden.
+event-ask = Frage stellen
+event-session-all = Alle
+event-session-closed = Diese Session wurde vom Moderator geschlossen. Du kannst darin keine Fragen mehr stellen oder bewerten.
+event-session-vote-only = Der Moderator hat in dieser Session nur Abstimmungen erlaubt. Du kannst darin keine neuen Fragen stellen.
+event-no-questions = noch keine Fragen
+event-questions-review = Zur Prüfung
+event-questions-in-review = Deine Fragen werden vom Moderator geprüft
+event-questions-hot = Beliebte Fragen
+event-questions-answered = Beantwortet
+event-questions-hidden = Ausgeblendet
+event-pending-title = Wird gesendet, sobald du wieder online bist
+event-viewers-now = { $count ->
+        [one] { $count } Person ist gerade hier
+       *[other] { $count } Personen sind gerade hier
+    }
+event-typing-now = { $count ->
+        [one] { $count } Person schreibt gerade eine Frage…
+       *[other] { $count } Personen schreiben gerade eine Frage…
+    }
+event-stats-viewers = aktuelle Zuschauer
+event-stats-questions = alle Fragen
+event-stats-likes = alle Likes
+
+print-starts = beginnt { $date }
+print-ends = endet { $date }
+
+## moderator panel
+
+mod-state-open = Event offen
+mod-state-vote-only = Event nur Abstimmung
+mod-state-closed = Event geschlossen
+mod-state-archived = Event archiviert
+mod-names-anonymous = Nur anonym
+mod-names-optional = Namen optional
+mod-names-required = Namen erforderlich
+mod-theme-auto = Design: Wahl der Teilnehmenden
+mod-theme-light = Design: hell
+mod-theme-dark = Design: dunkel
+mod-theme-branding = Design: Branding (Premium)
+mod-downvotes = Negative Bewertungen erlauben
+mod-survey = Feedback-Umfrage nach dem Schließen
+mod-tab-alert = Bei neuen Fragen Ton abspielen und Tab blinken lassen
+mod-delete = Event löschen
+mod-duplicate = Event duplizieren
+mod-template-save = Als Vorlage speichern
+mod-template-saved = Als Vorlage gespeichert
+mod-timezone = Zeitzone des Veranstalters: { $timezone }
+mod-timezone-unknown = Zeitzone des Veranstalters: unbekannt
+mod-timezone-local = { " " }· Zeiten werden in deiner Zeitzone angezeigt ({ $timezone })
+mod-screening = Prüfung
+mod-hide-branding = Branding ausblenden
+mod-export-csv = CSV exportieren
+mod-export-pdf = PDF exportieren
+mod-export-markdown = Markdown exportieren
+mod-export-slides = Folien
+mod-deadline-premium = Dies ist ein Premium-Event und läuft nicht ab!
+mod-deadline-free = Ein kostenloses Event ist { $days } Tage gültig. Dein Event ist bis zum { $date } erreichbar. Mache ein Upgrade auf ein Premium-Event, damit es dauerhaft bleibt.
+mod-event-link = Das ist dein Event-Link
+mod-moderation-link = Das ist dein Moderations-Link
+mod-share = Mein Event teilen
+mod-feedback = Gib uns Feedback
+
+mod-refresh = Aktualisieren
+mod-start = Starten
+mod-blocked-terms-placeholder = gesperrte Wörter, durch Komma getrennt
+mod-blocked-terms-review = zur Prüfung senden
+mod-blocked-terms-hide = ausblenden
+mod-branding-title = Branding
+mod-branding-color = Farbe
+mod-branding-banner = Bannertext
+mod-branding-save = Branding speichern
+mod-branding-reset = Zurücksetzen
+mod-branding-logo-url = Logo-URL (https://...)
+
+mod-branding-remove-logo = Hochgeladenes Logo entfernen
+mod-branding-logo-invalid = Nur png-, jpeg- oder webp-Bilder bis { $size } MB sind erlaubt.
+mod-clusters-title = Ähnliche Fragen
+mod-clusters-find = Ähnliche Fragen finden
+mod-clusters-none = Keine ähnlichen Fragen gefunden.
+mod-clusters-merge = In die erste zusammenführen
+mod-clusters-merge-hint = blendet die anderen aus und rechnet ihre Likes der ersten zu
+mod-countdown-title = Countdown
+mod-countdown-label = Beschriftung, z. B. verbleibend für Fragen
+mod-countdown-label-default = verbleibend für Fragen
+mod-countdown-minutes = { $minutes } Min.
+mod-countdown-stop = Stoppen
+mod-notify-email = E-Mail-Benachrichtigungen
+mod-notify-email-address = E-Mail-Adresse
+mod-notify-push = Push-Benachrichtigungen
+mod-notify-at = Benachrichtigen ab
+mod-notify-likes = Likes (0 = aus)
+mod-push-browser = In diesem Browser empfangen
+mod-push-failed = Push-Benachrichtigungen konnten nicht aktiviert werden
+mod-password-title = Passwort
+mod-password-placeholder = Passwort
+mod-poll-open = Umfrage starten
+mod-poll-question = Frage der Umfrage
+mod-poll-option = Option { $number }
+mod-poll-add-option = Option hinzufügen
+mod-cooldown-before = Eine Frage pro Person alle
+mod-cooldown-after = Minuten (0 für keine Begrenzung)
+mod-languages-review = andere Sprachen: zur Prüfung senden
+mod-languages-reject = andere Sprachen: ablehnen
+mod-length-before = Fragenlänge
+mod-length-to = bis
+mod-limits-before = Höchstens
+mod-limits-between = Fragen annehmen, höchstens
+mod-limits-after = pro Minute (0 für keine Begrenzung)
+mod-capacity-before = Höchstens
+mod-capacity-after = Zuschauer gleichzeitig einlassen (0 für keine Begrenzung)
+mod-receipt-company = Firmenname
+mod-receipt-address = Rechnungsadresse
+mod-receipt-vat = USt-IdNr.
+mod-receipt-save = Angaben speichern
+mod-receipt-download = Beleg herunterladen
+mod-series-daily = täglich
+mod-series-weekly = wöchentlich
+mod-series-two-weeks = alle zwei Wochen
+mod-series-four-weeks = alle vier Wochen
+mod-series-days = alle { $days } Tage
+mod-series-repeat = Wiederholen: { $interval }
+mod-series-start = Serie starten
+mod-series-active = Wiederholt sich { $interval }, der Serien-Link öffnet immer das neueste Event:{ " " }
+mod-series-end = Serie beenden
+mod-sessions-placeholder = Neue Session, z. B. Keynote
+mod-sessions-add = Session hinzufügen
+mod-session-open = Session offen
+mod-session-vote-only = Session nur Abstimmung
+mod-session-closed = Session geschlossen
+mod-slug-save = Link speichern
+mod-slug-taken = Dieser Link ist bereits vergeben.
+mod-stats-title = Statistiken
+mod-stats-questions = Fragen im Zeitverlauf
+mod-stats-likes = Likes im Zeitverlauf
+mod-stats-peak-viewers = Zuschauer in der Spitze
+mod-stats-answered = beantwortet ({ $answered } von { $total })
+mod-summary-title = Zusammenfassung der Fragen
+mod-summary-failed = Die Zusammenfassung konnte nicht erstellt werden, bitte versuche es erneut.
+mod-summary-loading = Wird zusammengefasst…
+mod-summary-create = Fragen zusammenfassen
+mod-summary-based-on = basierend auf { $count } Fragen, { $time }
+mod-survey-title = Feedback-Umfrage
+mod-survey-empty = noch kein Feedback
+mod-survey-average = ★ { $average } von { $max } · { $count ->
+        [one] { $count } Antwort
+       *[other] { $count } Antworten
+    }
+mod-tag-enable = Neue Fragen taggen
+mod-tag-placeholder = Tag
+mod-word-cloud-open = Wortwolke starten
+mod-word-cloud-prompt = Frage für die Wortwolke
+
+upgrade-now = Jetzt upgraden auf{ " " }
+upgrade-premium = PREMIUM-EVENT
+upgrade-features = Damit schaltest du folgende Funktionen frei:
+upgrade-feature-access = Unbegrenzter Zugriff auf dein Event
+upgrade-feature-statistics = Echtzeit-Statistiken (Teilnehmende, Likes ..)
+upgrade-feature-export = Export deiner Event-Daten
+upgrade-feature-screening = Fragen prüfen, bevor sie erscheinen
+upgrade-feature-tags = Fragen automatisch taggen
+upgrade-feature-context = Kontext-Link zu deinem Event hinzufügen
+upgrade-feature-more = Und vieles mehr ..
+upgrade-contact-before = Planst du mehrere Events?{ " " }
+upgrade-contact = Kontaktiere uns
+upgrade-contact-after = { " " }für besondere Rabatte.
+upgrade-promo = Promo-Code (optional)
+upgrade-buy = upgraden für 7 €
+upgrade-trial = { $minutes } Minuten kostenlos testen
+
+## event widgets
+
+event-meta-label = Das Event
+event-meta-moderator-online = Moderator ist online
+
+context-add = Link hinzufügen..
+context-title-add = Kontext-Link hinzufügen
+context-title-edit = Kontext-Link bearbeiten
+context-label = Link-Titel
+context-cancel = abbrechen
+context-remove = entfernen
+context-create = erstellen
+context-change = ändern
+context-url = URL - https://
+
+context-label-too-short = Der Titel muss mindestens { $max } Zeichen lang sein. ({ $len }/{ $max })
+context-label-too-long = Der Titel darf nicht länger als { $max } Zeichen sein.
+context-url-base-missing = Protokoll fehlt (wie http://)
+context-url-invalid = Ungültige URL
+
+meta-title = Event bearbeiten
+meta-name = Titel
+meta-cancel = abbrechen
+meta-change = ändern
+
+share-title = Diesen Link teilen
+share-print = Druckversion anzeigen
+share-screen = Auf großem Bildschirm zeigen
+
+password-placeholder = Passwort eingeben
+password-confirm = Ok
+password-try-again = versuche es erneut
+password-invalid = ungültiges Passwort
+
+speakers-title = Melde dich, um live zu fragen
+speakers-speaking = spricht
+speakers-move-up = nach oben
+speakers-move-down = nach unten
+speakers-done = Fertig
+speakers-call = Aufrufen
+speakers-remove = Entfernen
+speakers-turn = Du bist dran!
+speakers-position = Du bist Nr. { $position } in der Reihe
+speakers-lower = Hand senken
+speakers-name = dein Name
+speakers-raise = Hand heben
+
+word-cloud-thanks = Danke für deine Antwort!
+word-cloud-placeholder = ein oder zwei Wörter
+word-cloud-send = Senden
+word-cloud-empty = noch keine Antworten
+word-cloud-reopen = Wortwolke wieder öffnen
+word-cloud-close = Wortwolke schließen
+
+survey-thanks = Danke für dein Feedback!
+survey-title = Wie hat dir dieses Event gefallen?
+survey-rating = { $rating } von { $max }
+survey-comment = Möchtest du dem Veranstalter noch etwas sagen? (optional)
+survey-send = Feedback senden
+
+stats-empty = noch keine Aktivität
+
+## print and screen pages
+
+print-option-answered = beantwortete Fragen
+print-option-hidden = ausgeblendete Fragen
+print-option-qr = QR-Code
+print-layout-blocks = ein Block pro Frage
+print-layout-compact = kompakte Liste
+print-print = Drucken
+print-section-answered = Beantwortete Fragen
+print-section-open = Offene Fragen
+print-section-hidden = Ausgeblendete Fragen
+print-answered = ✓ beantwortet
+
+screen-previous = ← zurück
+screen-next = beantwortet, weiter →
+screen-ask-now = Stellt jetzt eure Fragen
+
+## polls
+
+poll-votes = { $count ->
+        [one] { $count } Stimme
+       *[other] { $count } Stimmen
+    }
+poll-closed = { " " }· geschlossen
+poll-reopen = Umfrage wieder öffnen
+poll-close = Umfrage schließen
+
+## questions
+
+question-age-now = gerade eben
+question-age-minutes = vor { $count } Min.
+question-age-hours = { $count ->
+        [one] vor { $count } Stunde
+       *[other] vor { $count } Stunden
+    }
+question-age-days = { $count ->
+        [one] vor { $count } Tag
+       *[other] vor { $count } Tagen
+    }
+
+question-like = Gefällt mir!
+question-unlike = Gefällt mir nicht mehr
+question-likes = { $count ->
+        [one] { $count } Like
+       *[other] { $count } Likes
+    }
+question-downvote = Nicht relevant
+question-downvote-undo = Doch relevant
+question-hide = ausblenden
+question-unhide = einblenden
+question-answered = beantwortet
+question-not-answered = nicht beantwortet
+question-approve = freigeben
+question-pin = anheften
+question-unpin = lösen
+question-pinned = angeheftet
+question-flagged-likes = { $count ->
+        [one] { $count } verdächtiges Like ignoriert
+       *[other] { $count } verdächtige Likes ignoriert
+    }
+question-flagged-likes-hint = Likes, die unplausibel schnell von einem einzelnen Browser oder Netzwerk kamen, wurden nicht gezählt
+question-sentiment-positive = positiver Ton
+question-sentiment-neutral = neutraler Ton
+question-sentiment-negative = negativer Ton
+question-translate = übersetzen
+question-translation-original = Original anzeigen
+question-shadow-ban = stumm schalten
+question-shadow-unban = Stummschaltung aufheben
+question-shadowed = stumm geschaltet
+question-shadowed-hint = Fragen dieser Person sehen nur Moderatoren und die Person selbst
+question-note = private Notiz der Moderation
+question-bulk-select = für Sammelaktionen auswählen
+question-note-placeholder = Notiz hinzufügen, nur für Moderatoren sichtbar
+question-attachment = Anhang
+question-react = { $reaction } ({ $count })
+
+reaction-thumbs-up = Daumen hoch
+reaction-heart = Herz
+reaction-laugh = Lachen
+reaction-thinking = Nachdenklich
+
+a11y-new-question = Eine neue Frage wurde gestellt
+a11y-new-question-text = Neue Frage: { $text }
+tab-alert-title = ({ $count }) { $count ->
+        [one] Neue Frage
+       *[other] Neue Fragen
+    }
+
+question-popup-title = Frage stellen
+
+question-popup-tag = aktuelles Tag:
+question-popup-placeholder = Was ist deine Frage?
+question-popup-send = Fragen!
+question-popup-offline = Du bist offline, deine Frage wird gesendet, sobald du wieder online bist.
+question-popup-offline-attachment = Bilder können erst gesendet werden, wenn du wieder online bist.
+question-popup-cooldown = Du kannst deine nächste Frage in { $countdown } stellen.
+question-popup-languages = Bitte frage auf: { $languages }
+question-popup-attachment-size = Nur Bilder bis { $size } MB sind erlaubt.
+question-popup-name = Dein Name
+question-popup-name-optional = Dein Name (optional)
+question-popup-name-required = Die Moderation verlangt einen Namen.
+question-popup-name-too-short = Name zu kurz. Min: { $min }
+question-popup-name-too-long = Name zu lang. Max: { $max }
+question-popup-too-short = Frage zu kurz.
+question-popup-too-long = Frage zu lang. Max: { $max }
+question-popup-min-words = Mindestanzahl an Wörtern: { $min }.
+question-popup-word-too-long = Kein Wort darf länger sein als: { $max }.
+
+## toasts
+
+toast-online = Du bist wieder online.
+toast-offline = Verbindung verloren, verbinde neu…
+toast-question-sent = Deine Frage wurde gesendet.
+toast-question-failed = Deine Frage konnte nicht gesendet werden, bitte versuche es erneut.
+toast-question-cooldown = Bitte warte kurz, bevor du deine nächste Frage stellst.
+toast-question-limit = Dieses Event nimmt keine weiteren Fragen mehr an.
+toast-question-rate-limit = Gerade kommen sehr viele Fragen an, bitte versuche es in einer Minute erneut.
+toast-question-language = Dieses Event nimmt nur Fragen in seinen eigenen Sprachen an.
+toast-question-queued = Du bist offline, deine Frage wird gesendet, sobald du wieder online bist.
+toast-link-copied = Link in die Zwischenablage kopiert.
+toast-copy-failed = Kopieren fehlgeschlagen, bitte kopiere den Link selbst.
+toast-moderation-failed = Die Frage konnte nicht geändert werden, bitte versuche es erneut.
+toast-vote-failed = Deine Stimme konnte nicht gespeichert werden, bitte versuche es erneut.
+toast-translation-failed = Die Frage konnte nicht übersetzt werden, bitte versuche es erneut.
+toast-save-failed = Die Änderung konnte nicht gespeichert werden, bitte versuche es erneut.
+toast-duplicate-failed = Das Event konnte nicht dupliziert werden, bitte versuche es erneut.
+
+## error banner
+
+error-banner-text = Bei uns ist etwas schiefgelaufen (Fehler { $status }).
+error-banner-request-id = Request-ID: { $id }
+error-banner-retry = Erneut versuchen
+error-banner-dismiss = Schließen
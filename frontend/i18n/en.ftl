@@ -0,0 +1,501 @@
+# English string catalog, the fallback for messages missing in other languages.
+# Message ids are prefixed with the component or page they are used in.
+
+## icon bar
+
+iconbar-share = Share
+iconbar-create-event = Create Event
+iconbar-ask = Ask a question
+iconbar-home = Home
+iconbar-reconnect = Reconnect now
+iconbar-language = Language
+
+color-scheme-system = Theme: system
+color-scheme-light = Theme: light
+color-scheme-dark = Theme: dark
+
+## footer
+
+footer-about = About
+footer-privacy = Privacy Policy
+footer-status = Status
+
+## home page
+
+home-title = Real-Time questions from your audience
+home-intro = Have you ever organized a meetup, conference, or moderated a panel discussion and wanted an easy way to receive real-time
+    questions from your audience? Welcome to Live-Ask.
+home-create = Create your Event
+home-example = View Example
+home-incognito = Incognito
+home-incognito-text = No registration necessary - everyone can ask questions and vote. Participant anonymity ensures freedom of speech and a smooth
+    user experience.
+home-effortless = Effortless
+home-effortless-text = Set up your event in seconds! Share the link with your audience and let them decide what’s hot.
+home-realtime = Real-Time
+home-realtime-text = Designed for live events. Questions can be asked and voted on in real time. This way, you can interact with everyone seamlessly.
+home-crossplatform = Cross Platform
+home-crossplatform-text = Use Live-Ask on your mobile phone, tablet, laptop or desktop computer. Go crazy and cast it to your smart TV, too!
+home-social = Social
+home-social-text = We want to make sharing as effortless as possible. Have you organized an awesome event? Live-Ask makes it easy to share it
+    with others. You bring the great content, we’ll help you spread the word.
+home-try = Try it now for free!
+home-recent = Your recent events
+home-recent-viewer = viewer
+home-recent-moderator = moderator
+
+## new event form
+
+newevent-title = Create Event
+newevent-name = event name
+newevent-email = email (optional)
+newevent-description = event description
+newevent-start = starts (optional)
+newevent-end = ends (optional)
+newevent-schedule-invalid = The end has to be after the start.
+newevent-finish = finish
+newevent-template = Start from a template
+newevent-template-remove = remove
+newevent-template-hint = { $placeholder } in a template name is replaced with the current date
+newevent-org = This event is added to your team workspace and billed by its subscription.
+newevent-subscription-active = Your subscription makes this event premium.
+newevent-subscription-inactive = Your subscription is not active (yet).
+newevent-subscription-offer = Organizing regularly? A subscription makes all your events premium.
+newevent-subscribe = subscribe
+newevent-workspace-open = Open your team workspace
+newevent-workspace-create = Running Q&As as a team? Create a workspace
+newevent-name-empty = Name is required.
+newevent-name-too-short = Name must be at least { $min } characters long. ({ $len }/{ $min })
+newevent-name-too-long = Name cannot be longer than { $max } characters.
+newevent-name-too-many-words = Name must not contain more than { $max } words.
+newevent-description-empty = Description cannot be empty
+newevent-description-too-short = Description must be at least { $min } characters long. ({ $len }/{ $min })
+newevent-description-too-long = Description cannot be longer than { $max } characters.
+newevent-email-invalid = Invalid Email Provided
+newevent-unknown-error = unknown error
+
+## event page
+
+event-starts-in = This event starts in { $countdown }. You can ask questions once it started.
+event-ended-on = This event ended on { $date }. You cannot add or vote questions anymore.
+event-ends-on = This event ends on { $date }.
+event-premium = This is a premium event
+event-premium-trial = Premium trial until { $time }
+event-loading = loading event…
+event-search = Search questions
+event-shortcuts = Keyboard shortcuts
+event-bulk-selected = { $count ->
+        [one] { $count } question selected
+       *[other] { $count } questions selected
+    }
+event-bulk-hide = Hide
+event-bulk-answer = Mark answered
+event-bulk-tag = Tag
+event-bulk-untag = Remove tag
+event-bulk-clear = Clear selection
+event-confirm-bulk-hide-title = Hide the selected questions?
+event-share = Share event
+event-waiting-room-title = This event is full right now
+event-waiting-room-text = You will join automatically as soon as a spot frees up. Please keep this page open.
+copy-link = Copy
+copy-done = Copied ✓
+event-confirm-hide-title = Hide this question?
+event-confirm-hide-text = Participants will not see it anymore. You can unhide it from the hidden questions.
+event-confirm-hide = Hide
+event-confirm-close-title = Close this event?
+event-confirm-close-text = Participants cannot add or vote questions anymore until you open it again.
+event-confirm-close = Close event
+event-confirm-archive-title = Archive this event?
+event-confirm-archive-text = Questions and answers stay readable, but nothing can be added or voted on anymore.
+event-confirm-archive = Archive event
+
+delete-popup-title = Delete event permanently
+delete-popup-text = This action is irreversible. Only you as the moderator can delete an event. Users you shared this event with will not be able to see it anymore.
+delete-popup-confirm = Delete
+
+confirm-cancel = Cancel
+
+shortcuts-title = Keyboard shortcuts
+shortcuts-next = Select the next question
+shortcuts-previous = Select the previous question
+shortcuts-answer = Mark the selected question answered
+shortcuts-hide = Hide the selected question
+shortcuts-pin = Pin the selected question on top
+shortcuts-search = Search questions
+shortcuts-help = Show or hide this list
+shortcuts-escape = Clear the selection
+shortcuts-close = Close
+
+event-not-found = event not found
+event-deleted = event deleted
+event-closed = This event was closed by the moderator. You cannot add or vote questions anymore.
+event-closed-updates = Updates by the moderator are still seen in real-time.
+event-archived = This event is archived. Its questions and answers stay readable, but nothing can be added or voted on anymore.
+event-vote-only = This event is set to vote-only by the moderator. You cannot add new questions. You can still vote though.
+event-timed-out = This free event timed out. Only the moderator can upgrade it to be accessible again.
+event-scan-to-join = scan to join
+event-screening-note = Moderator enabled question reviewing. New questions have to be approved first.
+event-ask = Ask a Question
+event-session-all = All
+event-session-closed = This session was closed by the moderator. You cannot add or vote questions in it anymore.
+event-session-vote-only = This session is set to vote-only by the moderator. You cannot add new questions to it.
+event-no-questions = no questions yet
+event-questions-review = For review
+event-questions-in-review = Your Questions in review by host
+event-questions-hot = Hot Questions
+event-questions-answered = Answered
+event-questions-hidden = Hidden
+event-pending-title = Sent once you are back online
+event-viewers-now = { $count ->
+        [one] { $count } person here now
+       *[other] { $count } people here now
+    }
+event-typing-now = { $count ->
+        [one] { $count } person is writing a question…
+       *[other] { $count } people are writing a question…
+    }
+event-stats-viewers = current viewers
+event-stats-questions = all questions
+event-stats-likes = all likes
+
+print-starts = starts { $date }
+print-ends = ends { $date }
+
+## moderator panel
+
+mod-state-open = Event open
+mod-state-vote-only = Event vote only
+mod-state-closed = Event closed
+mod-state-archived = Event archived
+mod-names-anonymous = Anonymous only
+mod-names-optional = Names optional
+mod-names-required = Names required
+mod-theme-auto = Theme: attendee's choice
+mod-theme-light = Theme: light
+mod-theme-dark = Theme: dark
+mod-theme-branding = Theme: branding (premium)
+mod-downvotes = Allow downvotes
+mod-survey = Feedback survey after closing
+mod-tab-alert = Chime and flash the tab on new questions
+mod-delete = Delete Event
+mod-duplicate = Duplicate Event
+mod-template-save = Save as template
+mod-template-saved = Saved as template
+mod-timezone = Organizer timezone: { $timezone }
+mod-timezone-unknown = Organizer timezone: unknown
+mod-timezone-local = { " " }· times are shown in your timezone ({ $timezone })
+mod-screening = Screening
+mod-hide-branding = Hide branding
+mod-export-csv = Export CSV
+mod-export-pdf = Export PDF
+mod-export-markdown = Export Markdown
+mod-export-slides = Slides
+mod-deadline-premium = This is a premium event and will not time out!
+mod-deadline-free = Currently a free event is valid for { $days } days. Your event will be accessible until { $date }. Please upgrade to a premium event to make it permanent.
+mod-event-link = This is your event link
+mod-moderation-link = This is your moderation link
+mod-share = Share my event
+mod-feedback = Give us feedback
+
+mod-refresh = Refresh
+mod-start = Start
+mod-blocked-terms-placeholder = blocked words, comma separated
+mod-blocked-terms-review = send to review
+mod-blocked-terms-hide = hide
+mod-branding-title = Branding
+mod-branding-color = Color
+mod-branding-banner = Banner text
+mod-branding-save = Save branding
+mod-branding-reset = Reset
+mod-branding-logo-url = Logo url (https://...)
+
+mod-branding-remove-logo = Remove uploaded logo
+mod-branding-logo-invalid = Only png, jpeg or webp images up to { $size } MB are allowed.
+mod-clusters-title = Similar questions
+mod-clusters-find = Find similar questions
+mod-clusters-none = No similar questions found.
+mod-clusters-merge = Merge into first
+mod-clusters-merge-hint = hide the others and add their likes to the first one
+mod-countdown-title = Countdown
+mod-countdown-label = Label, e.g. left for questions
+mod-countdown-label-default = left for questions
+mod-countdown-minutes = { $minutes } min
+mod-countdown-stop = Stop
+mod-notify-email = Email notifications
+mod-notify-email-address = Email address
+mod-notify-push = Push notifications
+mod-notify-at = Notify at
+mod-notify-likes = likes (0 = off)
+mod-push-browser = Receive them in this browser
+mod-push-failed = Push notifications could not be enabled
+mod-password-title = Password
+mod-password-placeholder = password
+mod-poll-open = Start a poll
+mod-poll-question = Poll question
+mod-poll-option = Option { $number }
+mod-poll-add-option = Add option
+mod-cooldown-before = One question per participant every
+mod-cooldown-after = minutes (0 for no limit)
+mod-languages-review = other languages: send to review
+mod-languages-reject = other languages: reject
+mod-length-before = Question length
+mod-length-to = to
+mod-limits-before = Take at most
+mod-limits-between = questions, at most
+mod-limits-after = per minute (0 for no limit)
+mod-capacity-before = Let in at most
+mod-capacity-after = viewers at once (0 for no limit)
+mod-receipt-company = Company name
+mod-receipt-address = Billing address
+mod-receipt-vat = VAT ID
+mod-receipt-save = Save details
+mod-receipt-download = Download receipt
+mod-series-daily = daily
+mod-series-weekly = weekly
+mod-series-two-weeks = every two weeks
+mod-series-four-weeks = every four weeks
+mod-series-days = every { $days } days
+mod-series-repeat = Repeat { $interval }
+mod-series-start = Start series
+mod-series-active = Repeats { $interval }, the series link always opens the latest event:{ " " }
+mod-series-end = End series
+mod-sessions-placeholder = New session, e.g. Keynote
+mod-sessions-add = Add session
+mod-session-open = Session open
+mod-session-vote-only = Session vote only
+mod-session-closed = Session closed
+mod-slug-save = Save link
+mod-slug-taken = This link is taken already.
+mod-stats-title = Statistics
+mod-stats-questions = Questions over time
+mod-stats-likes = Likes over time
+mod-stats-peak-viewers = peak viewers
+mod-stats-answered = answered ({ $answered } of { $total })
+mod-summary-title = Question summary
+mod-summary-failed = The summary could not be generated, please try again.
+mod-summary-loading = Summarizing…
+mod-summary-create = Summarize questions
+mod-summary-based-on = based on { $count } questions, { $time }
+mod-survey-title = Feedback survey
+mod-survey-empty = no feedback yet
+mod-survey-average = ★ { $average } of { $max } · { $count ->
+        [one] { $count } response
+       *[other] { $count } responses
+    }
+mod-tag-enable = Tag new questions
+mod-tag-placeholder = tag
+mod-word-cloud-open = Start a word cloud
+mod-word-cloud-prompt = Word cloud prompt
+
+upgrade-now = Upgrade now to{ " " }
+upgrade-premium = PREMIUM EVENT
+upgrade-features = To unlock the following features:
+upgrade-feature-access = Unlimited access to your event
+upgrade-feature-statistics = Realtime statistics (participants, likes ..)
+upgrade-feature-export = Export your event data
+upgrade-feature-screening = Prescreen questions before they appear
+upgrade-feature-tags = Automatically tag questions
+upgrade-feature-context = Add context link to your event
+upgrade-feature-more = Plus much more ..
+upgrade-contact-before = Are you planning to host multiple events?{ " " }
+upgrade-contact = Contact us
+upgrade-contact-after = { " " }for special discounts.
+upgrade-promo = promo code (optional)
+upgrade-buy = upgrade for €7
+upgrade-trial = try it free for { $minutes } minutes
+
+## event widgets
+
+event-meta-label = The Event
+event-meta-moderator-online = Moderator is online
+
+context-add = add link..
+context-title-add = Add context link
+context-title-edit = Edit context link
+context-label = Link Title
+context-cancel = cancel
+context-remove = remove
+context-create = create
+context-change = change
+context-url = URL - https://
+
+context-label-too-short = Title must be at least { $max } characters long. ({ $len }/{ $max })
+context-label-too-long = Title cannot be longer than { $max } characters.
+context-url-base-missing = Base missing (like http://)
+context-url-invalid = Invalid URL
+
+meta-title = Edit Event
+meta-name = Title
+meta-cancel = cancel
+meta-change = change
+
+share-title = Share this Link
+share-print = Show print version
+share-screen = Show on big screen
+
+password-placeholder = Enter password
+password-confirm = Ok
+password-try-again = try again
+password-invalid = invalid password
+
+speakers-title = Raise your hand to ask live
+speakers-speaking = speaking
+speakers-move-up = move up
+speakers-move-down = move down
+speakers-done = Done
+speakers-call = Call
+speakers-remove = Remove
+speakers-turn = It's your turn!
+speakers-position = You are #{ $position } in line
+speakers-lower = Lower hand
+speakers-name = your name
+speakers-raise = Raise hand
+
+word-cloud-thanks = Thanks for your answer!
+word-cloud-placeholder = one or two words
+word-cloud-send = Send
+word-cloud-empty = no answers yet
+word-cloud-reopen = Reopen word cloud
+word-cloud-close = Close word cloud
+
+survey-thanks = Thanks for your feedback!
+survey-title = How did you like this event?
+survey-rating = { $rating } of { $max }
+survey-comment = Anything else you want to tell the organizer? (optional)
+survey-send = Send feedback
+
+stats-empty = no activity yet
+
+## print and screen pages
+
+print-option-answered = answered questions
+print-option-hidden = hidden questions
+print-option-qr = QR code
+print-layout-blocks = one block per question
+print-layout-compact = compact list
+print-print = Print
+print-section-answered = Answered questions
+print-section-open = Open questions
+print-section-hidden = Hidden questions
+print-answered = ✓ answered
+
+screen-previous = ← previous
+screen-next = answered, next →
+screen-ask-now = Ask your questions now
+
+## polls
+
+poll-votes = { $count ->
+        [one] { $count } vote
+       *[other] { $count } votes
+    }
+poll-closed = { " " }· closed
+poll-reopen = Reopen poll
+poll-close = Close poll
+
+## questions
+
+question-age-now = just now
+question-age-minutes = { $count } min ago
+question-age-hours = { $count ->
+        [one] { $count } hour ago
+       *[other] { $count } hours ago
+    }
+question-age-days = { $count ->
+        [one] { $count } day ago
+       *[other] { $count } days ago
+    }
+
+question-like = I like!
+question-unlike = Unlike!
+question-likes = { $count ->
+        [one] { $count } like
+       *[other] { $count } likes
+    }
+question-downvote = Not relevant
+question-downvote-undo = Relevant after all
+question-hide = hide
+question-unhide = unhide
+question-answered = answered
+question-not-answered = not answered
+question-approve = approve
+question-pin = pin
+question-unpin = unpin
+question-pinned = pinned
+question-flagged-likes = { $count ->
+        [one] { $count } suspicious like ignored
+       *[other] { $count } suspicious likes ignored
+    }
+question-flagged-likes-hint = Likes that came in implausibly fast from a single browser or network were not counted
+question-sentiment-positive = positive tone
+question-sentiment-neutral = neutral tone
+question-sentiment-negative = negative tone
+question-translate = translate
+question-translation-original = show original
+question-shadow-ban = shadow ban
+question-shadow-unban = lift shadow ban
+question-shadowed = shadow banned
+question-shadowed-hint = Only moderators and the author see questions of this participant
+question-note = private moderator note
+question-bulk-select = select for bulk actions
+question-note-placeholder = Add a note only moderators see
+question-attachment = attachment
+question-react = { $reaction } ({ $count })
+
+reaction-thumbs-up = thumbs up
+reaction-heart = heart
+reaction-laugh = laughing
+reaction-thinking = thinking
+
+a11y-new-question = A new question was asked
+a11y-new-question-text = New question: { $text }
+tab-alert-title = ({ $count }) { $count ->
+        [one] New question
+       *[other] New questions
+    }
+
+question-popup-title = Ask a question
+
+question-popup-tag = current tag:
+question-popup-placeholder = What’s your question?
+question-popup-send = Ask!
+question-popup-offline = You are offline, your question is sent once you are back online.
+question-popup-offline-attachment = Images can only be sent once you are back online.
+question-popup-cooldown = You can ask your next question in { $countdown }.
+question-popup-languages = Please ask in: { $languages }
+question-popup-attachment-size = Only images up to { $size } MB are allowed.
+question-popup-name = Your name
+question-popup-name-optional = Your name (optional)
+question-popup-name-required = Moderator requires a name.
+question-popup-name-too-short = Name too short. Min: { $min }
+question-popup-name-too-long = Name too long. Max: { $max }
+question-popup-too-short = Question too short.
+question-popup-too-long = Question too long. Max: { $max }
+question-popup-min-words = Minimum words required: { $min }.
+question-popup-word-too-long = No word can be longer than: { $max }.
+
+## toasts
+
+toast-online = You are back online.
+toast-offline = Connection lost, reconnecting…
+toast-question-sent = Your question was sent.
+toast-question-failed = Your question could not be sent, please try again.
+toast-question-cooldown = Please wait a little before asking your next question.
+toast-question-limit = This event does not take any more questions.
+toast-question-rate-limit = Many questions are coming in right now, please try again in a minute.
+toast-question-language = This event only takes questions in its own languages.
+toast-question-queued = You are offline, your question is sent once you are back online.
+toast-link-copied = Link copied to the clipboard.
+toast-copy-failed = Copying failed, please copy the link manually.
+toast-moderation-failed = The question could not be updated, please try again.
+toast-vote-failed = Your vote could not be saved, please try again.
+toast-translation-failed = The question could not be translated, please try again.
+toast-save-failed = The change could not be saved, please try again.
+toast-duplicate-failed = The event could not be duplicated, please try again.
+
+## error banner
+
+error-banner-text = Something went wrong on our side (error { $status }).
+error-banner-request-id = Request id: { $id }
+error-banner-retry = Try again
+error-banner-dismiss = Dismiss
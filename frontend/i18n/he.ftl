@@ -0,0 +1,58 @@
+# Hebrew catalog, missing messages fall back to `en.ftl`.
+
+## icon bar
+
+iconbar-share = שיתוף
+iconbar-create-event = יצירת אירוע
+iconbar-ask = שאלו שאלה
+iconbar-language = שפה
+
+color-scheme-system = ערכת נושא: מערכת
+color-scheme-light = ערכת נושא: בהירה
+color-scheme-dark = ערכת נושא: כהה
+
+## footer
+
+footer-about = אודות
+footer-privacy = מדיניות פרטיות
+footer-status = סטטוס
+
+## home page
+
+home-title = שאלות מהקהל שלכם בזמן אמת
+home-create = צרו את האירוע שלכם
+home-example = לצפייה בדוגמה
+home-try = נסו עכשיו בחינם!
+home-recent = האירועים האחרונים שלכם
+home-recent-viewer = צופה
+home-recent-moderator = מנחה
+
+## questions
+
+question-age-now = עכשיו
+question-age-minutes = לפני { $count } דק׳
+question-age-hours = { $count ->
+        [one] לפני שעה
+       *[other] לפני { $count } שעות
+    }
+question-age-days = { $count ->
+        [one] לפני יום
+       *[other] לפני { $count } ימים
+    }
+
+question-popup-tag = תגית נוכחית:
+question-popup-placeholder = מה השאלה שלכם?
+question-popup-send = שאלו!
+question-popup-offline = אין חיבור, השאלה תישלח כשהחיבור יחזור.
+question-popup-offline-attachment = ניתן לשלוח תמונות רק כשהחיבור יחזור.
+question-popup-cooldown = אפשר לשאול את השאלה הבאה בעוד { $countdown }.
+question-popup-attachment-size = מותרות רק תמונות עד { $size } MB.
+question-popup-name = השם שלכם
+question-popup-name-optional = השם שלכם (לא חובה)
+question-popup-name-required = המנחה דורש שם.
+question-popup-name-too-short = השם קצר מדי. מינימום: { $min }
+question-popup-name-too-long = השם ארוך מדי. מקסימום: { $max }
+question-popup-too-short = השאלה קצרה מדי.
+question-popup-too-long = השאלה ארוכה מדי. מקסימום: { $max }
+question-popup-min-words = מספר מילים מינימלי: { $min }.
+question-popup-word-too-long = אף מילה לא יכולה להיות ארוכה מ: { $max }.
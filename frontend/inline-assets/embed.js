@@ -0,0 +1,77 @@
+/*
+ * liveask embed loader
+ *
+ *   <div data-liveask-event="EVENT_ID"></div>
+ *   <script src="https://www.live-ask.com/embed.js" async></script>
+ *
+ * Every element with `data-liveask-event` gets an iframe of the event that grows with its content.
+ * The element dispatches a `liveask:questions` event (`detail.count`) whenever the number of
+ * visible questions changes and `LiveAsk.ask(element)` opens the ask dialog inside the iframe.
+ */
+(function () {
+  var script = document.currentScript;
+  var origin = script ? new URL(script.src).origin : "https://www.live-ask.com";
+
+  function frameOf(element) {
+    return element && element.querySelector("iframe[data-liveask]");
+  }
+
+  function mount(element) {
+    if (frameOf(element)) {
+      return;
+    }
+
+    var frame = document.createElement("iframe");
+    frame.setAttribute("data-liveask", "");
+    frame.src = origin + "/embed/" + encodeURIComponent(element.getAttribute("data-liveask-event"));
+    frame.title = "liveask Q&A";
+    frame.style.width = "100%";
+    frame.style.border = "0";
+    frame.style.height = (element.getAttribute("data-liveask-height") || "600") + "px";
+    element.appendChild(frame);
+  }
+
+  window.addEventListener("message", function (e) {
+    if (e.origin !== origin || !e.data || typeof e.data.type !== "string") {
+      return;
+    }
+
+    var frames = document.querySelectorAll("iframe[data-liveask]");
+    for (var i = 0; i < frames.length; i++) {
+      if (frames[i].contentWindow !== e.source) {
+        continue;
+      }
+
+      if (e.data.type === "liveask:height") {
+        frames[i].style.height = e.data.height + "px";
+      } else if (e.data.type === "liveask:questions") {
+        frames[i].parentNode.dispatchEvent(
+          new CustomEvent("liveask:questions", { detail: { count: e.data.count } })
+        );
+      }
+    }
+  });
+
+  function mountAll() {
+    var elements = document.querySelectorAll("[data-liveask-event]");
+    for (var i = 0; i < elements.length; i++) {
+      mount(elements[i]);
+    }
+  }
+
+  window.LiveAsk = {
+    mount: mount,
+    ask: function (element) {
+      var frame = frameOf(element || document.querySelector("[data-liveask-event]"));
+      if (frame) {
+        frame.contentWindow.postMessage({ type: "liveask:ask" }, origin);
+      }
+    },
+  };
+
+  if (document.readyState === "loading") {
+    document.addEventListener("DOMContentLoaded", mountAll);
+  } else {
+    mountAll();
+  }
+})();
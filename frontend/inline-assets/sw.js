@@ -0,0 +1,112 @@
+/*
+ * caches the app shell so installed apps and event links open instantly, even offline.
+ * everything is served from the cache first and refreshed in the background, trunk hashes
+ * the file names of the wasm, js and css so a stale index only references files cached with it.
+ * api calls and the websocket always go to the network.
+ * moderators subscribed to web push get notified about their event, see `mod_push.rs`.
+ */
+var CACHE = "liveask-shell-v1";
+var SHELL = ["/", "/manifest.webmanifest", "/apple-touch-icon.png"];
+
+self.addEventListener("install", function (e) {
+  e.waitUntil(
+    caches.open(CACHE).then(function (cache) {
+      return cache.addAll(SHELL);
+    })
+  );
+  self.skipWaiting();
+});
+
+self.addEventListener("activate", function (e) {
+  e.waitUntil(
+    caches.keys().then(function (keys) {
+      return Promise.all(
+        keys
+          .filter(function (key) {
+            return key !== CACHE;
+          })
+          .map(function (key) {
+            return caches.delete(key);
+          })
+      );
+    })
+  );
+  self.clients.claim();
+});
+
+self.addEventListener("fetch", function (e) {
+  var url = new URL(e.request.url);
+
+  if (
+    e.request.method !== "GET" ||
+    url.origin !== self.location.origin ||
+    url.pathname.startsWith("/api/") ||
+    url.pathname.startsWith("/push/")
+  ) {
+    return;
+  }
+
+  //Note: every route of the single page app is served by the same index
+  var key = e.request.mode === "navigate" ? "/" : e.request;
+
+  e.respondWith(
+    caches.open(CACHE).then(function (cache) {
+      return cache.match(key).then(function (cached) {
+        var fresh = fetch(e.request)
+          .then(function (response) {
+            if (response.ok) {
+              cache.put(key, response.clone());
+            }
+            return response;
+          })
+          .catch(function (err) {
+            if (cached) {
+              return cached;
+            }
+            throw err;
+          });
+
+        if (cached) {
+          e.waitUntil(fresh.catch(function () {}));
+          return cached;
+        }
+
+        return fresh;
+      });
+    })
+  );
+});
+
+self.addEventListener("push", function (e) {
+  var data = e.data ? e.data.json() : {};
+
+  e.waitUntil(
+    self.registration.showNotification(data.title || "Live-Ask", {
+      body: data.body,
+      icon: "/apple-touch-icon.png",
+      //Note: one notification per event, a newer one replaces it
+      tag: data.url,
+      renotify: true,
+      data: { url: data.url || "/" },
+    })
+  );
+});
+
+self.addEventListener("notificationclick", function (e) {
+  e.notification.close();
+
+  var url = e.notification.data.url;
+
+  e.waitUntil(
+    self.clients
+      .matchAll({ type: "window", includeUncontrolled: true })
+      .then(function (windows) {
+        for (var i = 0; i < windows.length; i++) {
+          if (windows[i].url === url && "focus" in windows[i]) {
+            return windows[i].focus();
+          }
+        }
+        return self.clients.openWindow(url);
+      })
+  );
+});
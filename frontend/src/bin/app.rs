@@ -1,7 +1,8 @@
-use frontend::AppRoot;
+use frontend::{register_service_worker, AppRoot};
 
 fn main() {
     console_error_panic_hook::set_once();
     wasm_logger::init(wasm_logger::Config::new(log::Level::Info));
+    register_service_worker();
     yew::Renderer::<AppRoot>::new().render();
 }
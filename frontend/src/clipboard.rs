@@ -0,0 +1,43 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{HtmlDocument, HtmlTextAreaElement};
+
+/// writes `text` to the clipboard, `false` if the browser refused to
+pub async fn copy(text: &str) -> bool {
+    //Note: the async clipboard api only exists in secure contexts
+    if let Some(clipboard) = gloo_utils::window().navigator().clipboard() {
+        if JsFuture::from(clipboard.write_text(text)).await.is_ok() {
+            return true;
+        }
+    }
+
+    copy_from_selection(text)
+}
+
+/// deprecated `execCommand` on a temporary text area, still works on plain http
+fn copy_from_selection(text: &str) -> bool {
+    let document = gloo_utils::document();
+
+    let (Some(body), Ok(element)) = (document.body(), document.create_element("textarea")) else {
+        return false;
+    };
+    let textarea = element.unchecked_into::<HtmlTextAreaElement>();
+
+    textarea.set_value(text);
+    let _ = textarea.style().set_property("position", "fixed");
+    let _ = textarea.style().set_property("opacity", "0");
+
+    if body.append_child(&textarea).is_err() {
+        return false;
+    }
+    textarea.select();
+
+    let copied = document
+        .dyn_ref::<HtmlDocument>()
+        .and_then(|document| document.exec_command("copy").ok())
+        .unwrap_or_default();
+
+    textarea.remove();
+
+    copied
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::UnwrapThrowExt;
+
+use crate::{i18n::tr, local_cache::LocalCache};
+
+const CLASS_LIGHT: &str = "scheme-light";
+const CLASS_DARK: &str = "scheme-dark";
+
+/// color scheme chosen by the user, `System` follows `prefers-color-scheme`
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum ColorScheme {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ColorScheme {
+    /// order the icon bar toggle cycles through
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::System => Self::Dark,
+            Self::Dark => Self::Light,
+            Self::Light => Self::System,
+        }
+    }
+
+    #[must_use]
+    pub fn label(self) -> String {
+        tr(match self {
+            Self::System => "color-scheme-system",
+            Self::Light => "color-scheme-light",
+            Self::Dark => "color-scheme-dark",
+        })
+    }
+
+    const fn class(self) -> Option<&'static str> {
+        match self {
+            Self::System => None,
+            Self::Light => Some(CLASS_LIGHT),
+            Self::Dark => Some(CLASS_DARK),
+        }
+    }
+}
+
+/// sets the class on `<html>` that `dark.scss` keys on
+pub fn apply(scheme: ColorScheme) {
+    let Some(root) = gloo_utils::document().document_element() else {
+        return;
+    };
+
+    let classes = root.class_list();
+    classes.remove_2(CLASS_LIGHT, CLASS_DARK).unwrap_throw();
+
+    if let Some(class) = scheme.class() {
+        classes.add_1(class).unwrap_throw();
+    }
+}
+
+/// persists and applies a choice made by the user
+pub fn set(scheme: ColorScheme) {
+    LocalCache::set_color_scheme(scheme);
+    apply(scheme);
+}
@@ -0,0 +1,59 @@
+use shared::{EventBranding, LOGO_KEY_PREFIX};
+use yew::prelude::*;
+
+use crate::pages::BASE_API;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct BrandingProps {
+    pub branding: Option<EventBranding>,
+    #[prop_or_default]
+    pub printable: bool,
+}
+
+/// logo and banner text of a premium event, the color is applied via `EventBranding::css_variables`
+pub struct Branding;
+impl Component for Branding {
+    type Message = ();
+    type Properties = BrandingProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {}
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let Some(branding) = props
+            .branding
+            .as_ref()
+            .filter(|b| b.banner.is_some() || b.logo_url.is_some() || b.logo.is_some())
+        else {
+            return html! {};
+        };
+
+        html! {
+            <div class={classes!("event-branding",props.printable.then_some("printable"))}>
+                {
+                    logo_src(branding).map_or_else(|| html! {}, |url| html! {
+                        <img class="logo" src={url} alt="logo" />
+                    })
+                }
+                {
+                    branding.banner.as_ref().map_or_else(|| html! {}, |banner| html! {
+                        <div class="banner">{ banner.clone() }</div>
+                    })
+                }
+            </div>
+        }
+    }
+}
+
+/// an uploaded logo wins over a linked one
+fn logo_src(branding: &EventBranding) -> Option<String> {
+    branding
+        .logo
+        .as_deref()
+        .and_then(|key| key.strip_prefix(LOGO_KEY_PREFIX))
+        .map(|path| format!("{BASE_API}/api/event/logo{path}"))
+        .or_else(|| branding.logo_url.clone())
+}
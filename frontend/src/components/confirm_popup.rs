@@ -0,0 +1,58 @@
+use yew::prelude::*;
+
+use crate::{components::Popup, i18n::tr};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ConfirmPopupProps {
+    pub title: AttrValue,
+    pub text: AttrValue,
+    /// label of the confirm button
+    pub confirm: AttrValue,
+    /// destructive actions get a warning colored confirm button
+    #[prop_or_default]
+    pub danger: bool,
+    pub on_confirm: Callback<()>,
+    pub on_cancel: Callback<()>,
+}
+
+/// asks before an action is taken, the owner decides when to render it
+pub struct ConfirmPopup;
+
+impl Component for ConfirmPopup {
+    type Message = ();
+    type Properties = ConfirmPopupProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let ConfirmPopupProps {
+            title,
+            text,
+            confirm,
+            danger,
+            on_confirm,
+            on_cancel,
+        } = ctx.props();
+
+        html! {
+            <Popup
+                class={classes!("confirm-popup",danger.then_some("danger"))}
+                label={title.clone()}
+                on_close={on_cancel.clone()}
+            >
+                <div class="title">{ title.clone() }</div>
+                <div class="text">{ text.clone() }</div>
+                <div class="buttons">
+                    <button class="btn-cancel" onclick={on_cancel.reform(|_| ())}>
+                        { tr("confirm-cancel") }
+                    </button>
+                    <button class="btn-confirm" onclick={on_confirm.reform(|_| ())}>
+                        { confirm.clone() }
+                    </button>
+                </div>
+            </Popup>
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{components::Popup, fetch, pages::BASE_API};
+use crate::{
+    components::Popup,
+    fetch,
+    i18n::{tr, tr_args},
+    pages::BASE_API,
+};
 use shared::{
     ContextItem, ContextLabelError, ContextUrlError, ContextValidation, ModEvent, ValidationState,
 };
@@ -169,12 +174,12 @@ impl Component for ContextPopup {
 
             html! {
                 <Popup class="context-popup" {on_close}>
-                    <div class="title">{ if is_create { "Add context link" } else { "Edit context link" } }</div>
+                    <div class="title">{ tr(if is_create { "context-title-add" } else { "context-title-edit" }) }</div>
                     <div class="input-box">
                         <input
                             type="text"
                             name="label"
-                            placeholder="Link Title"
+                            placeholder={tr("context-label")}
                             value={self.label.clone()}
                             maxlength="20"
                             required=true
@@ -188,7 +193,7 @@ impl Component for ContextPopup {
                         <input
                             type="text"
                             name="url"
-                            placeholder="URL - https://"
+                            placeholder={tr("context-url")}
                             value={self.url.clone()}
                             maxlength="100"
                             required=true
@@ -202,12 +207,12 @@ impl Component for ContextPopup {
                         <button class="button-white"
                             disabled={self.send_pending}
                             onclick={on_click_delete}>
-                            { if is_create { "cancel" } else { "remove" } }
+                            { tr(if is_create { "context-cancel" } else { "context-remove" }) }
                         </button>
                         <button class="button-red"
                             disabled={self.send_pending || has_errors}
                             onclick={on_click_ok}>
-                            { if is_create { "create" } else { "change" } }
+                            { tr(if is_create { "context-create" } else { "context-change" }) }
                         </button>
                     </div>
                 </Popup>
@@ -221,11 +226,12 @@ impl Component for ContextPopup {
 impl ContextPopup {
     fn label_err(&self) -> Option<String> {
         match self.errors.label {
-            ValidationState::Invalid(ContextLabelError::MinLength(len, max)) => Some(format!(
-                "Title must be at least {max} characters long. ({len}/{max})",
+            ValidationState::Invalid(ContextLabelError::MinLength(len, max)) => Some(tr_args(
+                "context-label-too-short",
+                [("len", len.into()), ("max", max.into())],
             )),
             ValidationState::Invalid(ContextLabelError::MaxLength(_, max)) => {
-                Some(format!("Title cannot be longer than {max} characters.",))
+                Some(tr_args("context-label-too-long", [("max", max.into())]))
             }
             _ => None,
         }
@@ -235,9 +241,9 @@ impl ContextPopup {
         match self.errors.url {
             ValidationState::Invalid(ContextUrlError::Invalid(
                 url::ParseError::RelativeUrlWithoutBase,
-            )) => Some("Base missing (like http://)".to_string()),
+            )) => Some(tr("context-url-base-missing")),
             ValidationState::Invalid(ContextUrlError::Invalid(_)) => {
-                Some("Invalid URL".to_string())
+                Some(tr("context-url-invalid"))
             }
             _ => None,
         }
@@ -0,0 +1,56 @@
+use events::use_events;
+use gloo_timers::callback::Timeout;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use crate::{clipboard, i18n::tr, GlobalEvent, Toast};
+
+/// the checkmark is shown this long before the button reads "copy" again
+const COPIED_DURATION_MS: u32 = 2000;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CopyButtonProps {
+    pub text: AttrValue,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// copies `text` to the clipboard and confirms it with a checkmark and a toast
+#[function_component(CopyButton)]
+pub fn copy_button(props: &CopyButtonProps) -> Html {
+    let events = use_events::<GlobalEvent>();
+    let copied = use_state_eq(|| false);
+    let reset = use_mut_ref(|| None::<Timeout>);
+
+    let onclick = {
+        let (text, copied) = (props.text.clone(), copied.clone());
+        Callback::from(move |_| {
+            let (text, copied, events, reset) =
+                (text.clone(), copied.clone(), events.clone(), reset.clone());
+
+            spawn_local(async move {
+                let success = clipboard::copy(&text).await;
+
+                if let Some(events) = events {
+                    events.emit(GlobalEvent::Toast(if success {
+                        Toast::info("toast-link-copied")
+                    } else {
+                        Toast::error("toast-copy-failed")
+                    }));
+                }
+
+                if success {
+                    copied.set(true);
+                    *reset.borrow_mut() =
+                        Some(Timeout::new(COPIED_DURATION_MS, move || copied.set(false)));
+                }
+            });
+        })
+    };
+
+    html! {
+        <button class={classes!("copy-button",props.class.clone())} {onclick}>
+            { if *copied { tr("copy-done") } else { tr("copy-link") } }
+        </button>
+    }
+}
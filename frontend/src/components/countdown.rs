@@ -0,0 +1,81 @@
+use chrono::Utc;
+use gloo_timers::callback::Interval;
+use shared::EventTimer;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct CountdownProps {
+    pub timer: EventTimer,
+}
+
+pub enum Msg {
+    Tick,
+}
+
+/// moderator timer counting down to its end, stays at zero once it ran out
+pub struct Countdown {
+    interval: Option<Interval>,
+}
+
+impl Component for Countdown {
+    type Message = Msg;
+    type Properties = CountdownProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut res = Self { interval: None };
+        res.update_interval(ctx);
+        res
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Tick => {
+                self.update_interval(ctx);
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.update_interval(ctx);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let timer = &ctx.props().timer;
+        let left = timer.seconds_left(Utc::now().timestamp());
+
+        html! {
+            <div class={classes!("countdown",(left == 0).then_some("over"))} role="timer" aria-live="off">
+                <div class="time">{ format_timer(left) }</div>
+                <div class="label">
+                    { if left == 0 { String::from("time is up") } else { timer.label.clone() } }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Countdown {
+    /// ticks only as long as there is time left
+    fn update_interval(&mut self, ctx: &Context<Self>) {
+        if ctx.props().timer.seconds_left(Utc::now().timestamp()) == 0 {
+            self.interval = None;
+        } else if self.interval.is_none() {
+            let link = ctx.link().clone();
+            self.interval = Some(Interval::new(1000, move || link.send_message(Msg::Tick)));
+        }
+    }
+}
+
+fn format_timer(seconds: i64) -> String {
+    let hours = seconds.div_euclid(3600);
+    let minutes = seconds.div_euclid(60) % 60;
+    let seconds = seconds % 60;
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
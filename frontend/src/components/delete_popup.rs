@@ -1,4 +1,7 @@
-use crate::{components::Popup, fetch, pages::BASE_API, routes::Route, tracking, GlobalEvent};
+use crate::{
+    components::ConfirmPopup, fetch, i18n::tr, pages::BASE_API, routes::Route, tracking,
+    GlobalEvent,
+};
 use events::{event_context, EventBridge};
 use wasm_bindgen::UnwrapThrowExt;
 use yew::prelude::*;
@@ -72,22 +75,15 @@ impl Component for DeletePopup {
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         if self.show {
-            let on_close = ctx.link().callback(|()| Msg::Close);
-            let on_click_ok = ctx.link().callback(|_| Msg::ConfirmedDelete);
-            let on_click_no = ctx.link().callback(|_| Msg::Close);
-
             html! {
-                <Popup class="delete-popup" {on_close}>
-                    <div class="title">{ "Delete event permanently" }</div>
-                    <div class="text">
-                        { "This action is irreversible. Only you as the moderator can delete an event. Users you shared this event with will not be
-                      able to see it anymore." }
-                    </div>
-                    <div class="buttons">
-                        <div class="btn-yes" onclick={on_click_ok}>{ "yes" }</div>
-                        <div class="btn-yes" onclick={on_click_no}>{ "no" }</div>
-                    </div>
-                </Popup>
+                <ConfirmPopup
+                    title={tr("delete-popup-title")}
+                    text={tr("delete-popup-text")}
+                    confirm={tr("delete-popup-confirm")}
+                    danger=true
+                    on_confirm={ctx.link().callback(|()| Msg::ConfirmedDelete)}
+                    on_cancel={ctx.link().callback(|()| Msg::Close)}
+                />
             }
         } else {
             html! {}
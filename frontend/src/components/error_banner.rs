@@ -0,0 +1,54 @@
+use yew::prelude::*;
+
+use crate::{
+    fetch::ApiError,
+    i18n::{tr, tr_args},
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ErrorBannerProps {
+    pub error: ApiError,
+    pub on_retry: Callback<()>,
+    pub on_dismiss: Callback<()>,
+}
+
+/// shown when loading a page failed on the server, the request id helps support to find the logs
+pub struct ErrorBanner;
+
+impl Component for ErrorBanner {
+    type Message = ();
+    type Properties = ErrorBannerProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let ErrorBannerProps {
+            error,
+            on_retry,
+            on_dismiss,
+        } = ctx.props();
+
+        html! {
+            <div class="error-banner" role="alert">
+                <div class="text">
+                    { tr_args("error-banner-text", [("status", error.status.into())]) }
+                </div>
+                { error.request_id.as_ref().map_or_else(|| html! {}, |id| html! {
+                    <div class="request-id">
+                        { tr_args("error-banner-request-id", [("id", id.clone().into())]) }
+                    </div>
+                }) }
+                <div class="buttons">
+                    <button class="button-white" onclick={on_retry.reform(|_| ())}>
+                        { tr("error-banner-retry") }
+                    </button>
+                    <button class="link" onclick={on_dismiss.reform(|_| ())}>
+                        { tr("error-banner-dismiss") }
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use shared::ContextItem;
 use yew::prelude::*;
 
-use crate::components::ContextPopup;
+use crate::{components::ContextPopup, i18n::tr};
 
 #[derive(Clone, Debug, Eq, PartialEq, Properties)]
 pub struct EventContextProps {
@@ -79,7 +79,7 @@ impl EventContext {
                 html! {
                     <div class="item" onclick={on_click_edit}>
                         <img src="assets/context.svg" />
-                        <div class="label">{ "add link.." }</div>
+                        <div class="label">{ tr("context-add") }</div>
                     </div>
                 }
             } else {
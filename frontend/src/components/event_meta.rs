@@ -1,4 +1,7 @@
-use crate::components::{EventContext, MetaPopup};
+use crate::{
+    components::{EventContext, Markdown, MetaPopup},
+    i18n::tr,
+};
 use shared::{ContextItem, EditMetaData, EventData, EventTokens};
 use yew::prelude::*;
 
@@ -10,6 +13,8 @@ pub struct EventMetaProps {
     pub is_premium: bool,
     pub is_masked: bool,
     pub is_first_24h: bool,
+    #[prop_or_default]
+    pub moderator_online: bool,
 }
 
 pub struct EventMeta {
@@ -59,7 +64,7 @@ impl Component for EventMeta {
 
         html! {
             <>
-                <div class="event-name-label">{"The Event"}{ Self::mod_view_edit(ctx) }</div>
+                <div class="event-name-label">{ tr("event-meta-label") }{ Self::view_moderator_online(ctx) }{ Self::mod_view_edit(ctx) }</div>
                 <div class="event-name">{name}</div>
                 <EventContext {context} tokens={ctx.props().tokens.clone()} {is_premium} />
                 <MetaPopup tokens={ctx.props().tokens.clone()} on_close={on_close_popup} show={self.show_meta_popup} {meta} />
@@ -67,7 +72,7 @@ impl Component for EventMeta {
                 <div
                     class={classes!("event-desc",is_masked.then_some("blurr"))}
                 >
-                    { {desc} }
+                    <Markdown class="markdown" text={desc} />
                 </div>
             </>
         }
@@ -75,6 +80,16 @@ impl Component for EventMeta {
 }
 
 impl EventMeta {
+    fn view_moderator_online(ctx: &Context<Self>) -> Html {
+        if ctx.props().moderator_online && !ctx.props().tokens.is_mod() {
+            html! {
+                <span class="moderator-online" title={tr("event-meta-moderator-online")} aria-label={tr("event-meta-moderator-online")} />
+            }
+        } else {
+            html! {}
+        }
+    }
+
     fn mod_view_edit(ctx: &Context<Self>) -> Html {
         //TODO: show clock icon with tooltip that only in first 24h the text can be edited
         let is_mod = ctx.props().tokens.is_mod() && ctx.props().is_first_24h;
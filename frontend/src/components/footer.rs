@@ -2,7 +2,7 @@ use wasm_bindgen::UnwrapThrowExt;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-use crate::{fetch, routes::Route, GIT_BRANCH, VERSION_STR};
+use crate::{fetch, i18n::tr, routes::Route, GIT_BRANCH, VERSION_STR};
 
 pub struct Footer {
     api_version: Option<String>,
@@ -66,13 +66,13 @@ impl Component for Footer {
                 <div class="copyright">{ "© 2024 Rustunit B.V. All right reserved." }</div>
                 { Self::view_social() }
                 <a class="about" href="https://github.com/liveask/liveask" target="_blank">
-                    { "About" }
+                    { tr("footer-about") }
                 </a>
                 <div class="link about" onclick={ctx.link().callback(|_| Msg::Privacy)}>
-                    { "Privacy Policy" }
+                    { tr("footer-privacy") }
                 </div>
                 <a class="status" href="https://liveask.instatus.com" target="_blank">
-                    { "Status" }
+                    { tr("footer-status") }
                 </a>
                 <a
                     class="version"
@@ -1,16 +1,27 @@
-use chrono::{Duration, Utc};
+use chrono::Utc;
 use events::{event_context, EventBridge};
 use gloo_timers::callback::Interval;
 use std::rc::Rc;
-use wasm_bindgen::UnwrapThrowExt;
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+use web_sys::HtmlSelectElement;
 use yew::prelude::*;
 use yew_router::prelude::*;
 use yewdux::prelude::*;
 
-use crate::{not, routes::Route, GlobalEvent, State};
+use crate::{
+    color_scheme::{self, ColorScheme},
+    i18n::{self, tr, Language},
+    local_cache::LocalCache,
+    not,
+    routes::Route,
+    store::{ConnectionStore, EventStore, UiStore},
+    GlobalEvent,
+};
 
 pub enum Msg {
-    State(Rc<State>),
+    EventStore(Rc<EventStore>),
+    UiStore(Rc<UiStore>),
+    Connection(Rc<ConnectionStore>),
     Share,
     Event(GlobalEvent),
     Ask,
@@ -18,6 +29,8 @@ pub enum Msg {
     Reconnect,
     ReconnectTimer,
     RouteChange,
+    ToggleColorScheme,
+    LanguageSelected(Event),
 }
 
 #[allow(clippy::empty_structs_with_brackets)]
@@ -25,10 +38,15 @@ pub enum Msg {
 pub struct IconBarProps;
 
 pub struct IconBar {
-    connected: bool,
-    reconnect_timeout: Option<chrono::DateTime<Utc>>,
-    state: Rc<State>,
-    _dispatch: Dispatch<State>,
+    color_scheme: ColorScheme,
+    connection: Rc<ConnectionStore>,
+    /// only what is shown here is kept, the event changes far more often
+    has_event: bool,
+    event_open: bool,
+    admin: bool,
+    _event_dispatch: Dispatch<EventStore>,
+    _ui_dispatch: Dispatch<UiStore>,
+    _connection_dispatch: Dispatch<ConnectionStore>,
     events: EventBridge<GlobalEvent>,
     _interal: Interval,
     _route_listener: LocationHandle,
@@ -49,11 +67,19 @@ impl Component for IconBar {
             .subscribe(ctx.link().callback(Msg::Event));
 
         Self {
-            _dispatch: Dispatch::<State>::subscribe(ctx.link().callback(Msg::State)),
-            state: Rc::default(),
-            connected: true,
+            _event_dispatch: Dispatch::<EventStore>::subscribe(
+                ctx.link().callback(Msg::EventStore),
+            ),
+            _ui_dispatch: Dispatch::<UiStore>::subscribe(ctx.link().callback(Msg::UiStore)),
+            _connection_dispatch: Dispatch::<ConnectionStore>::subscribe(
+                ctx.link().callback(Msg::Connection),
+            ),
+            color_scheme: LocalCache::color_scheme(),
+            connection: Rc::default(),
+            has_event: false,
+            event_open: false,
+            admin: false,
             events,
-            reconnect_timeout: None,
             _interal: timer_interval,
             _route_listener: ctx
                 .link()
@@ -64,8 +90,22 @@ impl Component for IconBar {
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
-            Msg::State(state) => {
-                self.state = state;
+            Msg::EventStore(store) => {
+                let has_event = store.event.is_some();
+                let event_open = store.event.as_ref().is_some_and(|e| e.info.state.is_open());
+
+                let changed = has_event != self.has_event || event_open != self.event_open;
+                self.has_event = has_event;
+                self.event_open = event_open;
+                changed
+            }
+            Msg::UiStore(ui) => {
+                let changed = ui.admin != self.admin;
+                self.admin = ui.admin;
+                changed
+            }
+            Msg::Connection(connection) => {
+                self.connection = connection;
                 true
             }
             Msg::Share => {
@@ -85,25 +125,26 @@ impl Component for IconBar {
                 false
             }
             //ignore global events
-            Msg::Event(msg) => match msg {
-                GlobalEvent::SocketStatus {
-                    connected,
-                    timeout_secs,
-                } => {
-                    self.connected = connected;
-                    self.reconnect_timeout = None;
-                    if let Some(timeout_secs) = timeout_secs {
-                        self.reconnect_timeout = Some(Utc::now() + Duration::seconds(timeout_secs));
-                    }
-                    true
-                }
-                _ => false,
-            },
+            Msg::Event(_) => false,
             Msg::ReconnectTimer => {
                 //TODO: refresh only during timeout being set
-                self.reconnect_timeout.is_some()
+                self.connection.reconnect_at.is_some()
             }
             Msg::RouteChange => true,
+            Msg::ToggleColorScheme => {
+                self.color_scheme = self.color_scheme.next();
+                color_scheme::set(self.color_scheme);
+                true
+            }
+            Msg::LanguageSelected(ev) => {
+                let e: HtmlSelectElement = ev.target().unwrap_throw().dyn_into().unwrap_throw();
+
+                if let Some(language) = Language::from_code(&e.value()) {
+                    i18n::set_language(language);
+                    self.events.emit(GlobalEvent::LanguageChanged);
+                }
+                false
+            }
         }
     }
 
@@ -127,7 +168,7 @@ impl Component for IconBar {
             Html::VRef(svg.into())
         };
 
-        let has_event = self.state.event.is_some();
+        let has_event = self.has_event;
         let is_newevent_page = ctx
             .link()
             .route::<Route>()
@@ -136,40 +177,43 @@ impl Component for IconBar {
 
         html! {
             <div
-                class={classes!(vec!["topbar", "shrink"],not(self.connected).then_some("offline"))}
+                class={classes!(vec!["topbar", "shrink"],not(self.connection.connected).then_some("offline"))}
             >
                 { self.view_offline_bar(ctx) }
                 <div class="innerbox">
                     <div class="logo">
-                        <div
+                        <button
                             class="link clickable-logo"
+                            aria-label={tr("iconbar-home")}
                             onclick={ctx.link().callback(|_| Msg::Home)}
                         >
                             { logo_svg }
-                        </div>
+                        </button>
                         { logo_text_svg }
                     </div>
-                    { if self.state.event.is_some() {
+                    { if has_event {
                             html! {
-                                <div class="link share"
+                                <button class="link share"
                                     onclick={ctx.link().callback(|_| Msg::Share)}>
-                                    {"Share"}
-                                </div>
+                                    { tr("iconbar-share") }
+                                </button>
                             }
                         }else{html! {}} }
-                    <div class="admin" hidden={!self.state.admin}>
+                    <div class="admin" hidden={!self.admin}>
                         <Link<Route> to={Route::Login}>
                             <img alt="admin" src="/assets/admin.svg" />
                         </Link<Route>>
                     </div>
                     <div class="iconbar">
+                        { Self::view_language(ctx) }
+                        { self.view_color_scheme(ctx) }
                         { if has_event {
                                 self.view_ask_question(ctx)
                             }
                             else if !is_newevent_page {html!{
                             <Link<Route> to={Route::NewEvent}>
                                 <div class="createevent">
-                                    {"Create Event"}
+                                    { tr("iconbar-create-event") }
                                 </div>
                             </Link<Route>>
                             }}
@@ -183,27 +227,62 @@ impl Component for IconBar {
 
 impl IconBar {
     fn view_ask_question(&self, ctx: &Context<Self>) -> Html {
-        let is_open = self
-            .state
-            .event
-            .as_ref()
-            .is_some_and(|e| e.info.state.is_open());
-
-        if is_open {
+        if self.event_open {
             return html! {
-                <div class="link createevent" onclick={ctx.link().callback(|_| Msg::Ask)}>
-                    { "Ask a question" }
-                </div>
+                <button class="link createevent" onclick={ctx.link().callback(|_| Msg::Ask)}>
+                    { tr("iconbar-ask") }
+                </button>
             };
         }
         html! {}
     }
 
+    fn view_language(ctx: &Context<Self>) -> Html {
+        let current = i18n::language();
+        let label = tr("iconbar-language");
+
+        html! {
+            <select
+                class="language"
+                title={label.clone()}
+                aria-label={label}
+                onchange={ctx.link().callback(Msg::LanguageSelected)}
+            >
+                { for Language::ALL.into_iter().map(|language| html! {
+                    <option value={language.code()} selected={language == current}>
+                        { language.label() }
+                    </option>
+                }) }
+            </select>
+        }
+    }
+
+    fn view_color_scheme(&self, ctx: &Context<Self>) -> Html {
+        let icon = match self.color_scheme {
+            ColorScheme::System => "\u{25d0}",
+            ColorScheme::Light => "\u{2600}",
+            ColorScheme::Dark => "\u{263e}",
+        };
+        let label = self.color_scheme.label();
+
+        html! {
+            <button
+                class="color-scheme"
+                title={label.clone()}
+                aria-label={label}
+                onclick={ctx.link().callback(|_| Msg::ToggleColorScheme)}
+            >
+                { icon }
+            </button>
+        }
+    }
+
     fn view_offline_bar(&self, ctx: &Context<Self>) -> Html {
-        let is_online = self.connected;
+        let is_online = self.connection.connected;
 
         let seconds_till_reconnect = self
-            .reconnect_timeout
+            .connection
+            .reconnect_at
             .map(|timeout| (timeout - Utc::now()).num_seconds())
             .unwrap_or_default()
             .max(0);
@@ -212,7 +291,13 @@ impl IconBar {
             <div
                 id="ico-offline"
                 class={classes!(is_online.then_some("hidden"))}
+                role="button"
+                tabindex={if is_online { "-1" } else { "0" }}
+                aria-label={tr("iconbar-reconnect")}
                 onclick={ctx.link().callback(|_| Msg::Reconnect)}
+                onkeydown={ctx.link().batch_callback(|e: KeyboardEvent| {
+                    matches!(e.key().as_str(), "Enter" | " ").then_some(Msg::Reconnect)
+                })}
             >
                 <img alt="offline" hidden={is_online} src="/assets/offline.svg" />
                 <div hidden={is_online} class="timeout">
@@ -0,0 +1,114 @@
+use js_sys::Array;
+use wasm_bindgen::{closure::Closure, JsCast, UnwrapThrowExt};
+use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+use yew::prelude::*;
+
+use crate::components::Spinner;
+
+/// starts loading before the end of the list actually becomes visible
+const ROOT_MARGIN: &str = "0px 0px 600px 0px";
+
+#[derive(Properties, PartialEq)]
+pub struct InfiniteScrollProps {
+    pub on_end: Callback<()>,
+    /// the next page is on its way, the end is not reported again until it arrived
+    #[prop_or_default]
+    pub loading: bool,
+}
+
+pub enum Msg {
+    Intersected(bool),
+}
+
+/// placed below a list, reports when the list was scrolled to its end
+pub struct InfiniteScroll {
+    sentinel: NodeRef,
+    visible: bool,
+    observer: Option<IntersectionObserver>,
+    callback: Closure<dyn Fn(Array)>,
+}
+
+impl Component for InfiniteScroll {
+    type Message = Msg;
+    type Properties = InfiniteScrollProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let link = ctx.link().clone();
+        let callback = Closure::<dyn Fn(Array)>::new(move |entries: Array| {
+            let intersecting = entries
+                .iter()
+                .filter_map(|entry| entry.dyn_into::<IntersectionObserverEntry>().ok())
+                .any(|entry| entry.is_intersecting());
+
+            link.send_message(Msg::Intersected(intersecting));
+        });
+
+        Self {
+            sentinel: NodeRef::default(),
+            visible: false,
+            observer: None,
+            callback,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Intersected(visible) => {
+                self.visible = visible;
+                self.report_end(ctx);
+                false
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        //Note: the observer only reports changes, a short page leaves the sentinel in view
+        if old_props.loading && !ctx.props().loading {
+            self.report_end(ctx);
+        }
+        true
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if !first_render {
+            return;
+        }
+
+        let mut options = IntersectionObserverInit::new();
+        options.root_margin(ROOT_MARGIN);
+
+        let observer = IntersectionObserver::new_with_options(
+            self.callback.as_ref().unchecked_ref(),
+            &options,
+        )
+        .unwrap_throw();
+
+        if let Some(sentinel) = self.sentinel.cast::<Element>() {
+            observer.observe(&sentinel);
+        }
+
+        self.observer = Some(observer);
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        if let Some(observer) = self.observer.take() {
+            observer.disconnect();
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="infinite-scroll" ref={self.sentinel.clone()} aria-busy={ctx.props().loading.to_string()}>
+                { if ctx.props().loading { html! { <Spinner /> } } else { html! {} } }
+            </div>
+        }
+    }
+}
+
+impl InfiniteScroll {
+    fn report_end(&self, ctx: &Context<Self>) {
+        if self.visible && !ctx.props().loading {
+            ctx.props().on_end.emit(());
+        }
+    }
+}
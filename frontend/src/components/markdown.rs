@@ -0,0 +1,112 @@
+use pulldown_cmark::{html, Event, Parser, Tag};
+use shared::find_terms;
+use std::ops::Range;
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct MarkdownProps {
+    pub text: AttrValue,
+    #[prop_or_default]
+    pub class: Classes,
+    /// terms to mark in the text
+    #[prop_or_default]
+    pub highlight: Vec<String>,
+}
+
+/// renders the supported markdown subset (bold, italics, links, lists).
+/// everything else is rendered as plain text, raw html is never passed through.
+pub struct Markdown;
+impl Component for Markdown {
+    type Message = ();
+    type Properties = MarkdownProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let div = gloo_utils::document().create_element("div").unwrap_throw();
+        div.set_inner_html(&render(&ctx.props().text, &ctx.props().highlight));
+        div.set_class_name(&ctx.props().class.to_string());
+
+        Html::VRef(div.into())
+    }
+}
+
+fn render(text: &str, highlight: &[String]) -> String {
+    let mut link_allowed = Vec::new();
+
+    let events = Parser::new(text).filter_map(|event| match event {
+        Event::Start(tag) => match tag {
+            Tag::Paragraph | Tag::Emphasis | Tag::Strong | Tag::List(_) | Tag::Item => {
+                Some(Event::Start(tag))
+            }
+            Tag::Link(link_type, url, title) => {
+                let allowed = is_safe_url(&url);
+                link_allowed.push(allowed);
+                allowed.then_some(Event::Start(Tag::Link(link_type, url, title)))
+            }
+            _ => None,
+        },
+        Event::End(tag) => match tag {
+            Tag::Paragraph | Tag::Emphasis | Tag::Strong | Tag::List(_) | Tag::Item => {
+                Some(Event::End(tag))
+            }
+            Tag::Link(..) => link_allowed
+                .pop()
+                .unwrap_or_default()
+                .then_some(Event::End(tag)),
+            _ => None,
+        },
+        Event::Text(text) | Event::Code(text) | Event::Html(text) => Some(Event::Text(text)),
+        Event::SoftBreak | Event::HardBreak => Some(Event::HardBreak),
+        _ => None,
+    });
+
+    let events = events.flat_map(|event| mark_terms(event, highlight));
+
+    let mut result = String::with_capacity(text.len());
+    html::push_html(&mut result, events);
+
+    result.replace(
+        "<a href",
+        "<a target=\"_blank\" rel=\"noopener noreferrer\" href",
+    )
+}
+
+/// wraps the occurrences of `terms` in a text event in `<mark>`
+fn mark_terms<'a>(event: Event<'a>, terms: &[String]) -> Vec<Event<'a>> {
+    let Event::Text(text) = event else {
+        return vec![event];
+    };
+
+    let ranges = find_terms(&text, terms);
+
+    if ranges.is_empty() {
+        return vec![Event::Text(text)];
+    }
+
+    let part =
+        |range: Range<usize>| Event::Text(text.get(range).unwrap_or_default().to_string().into());
+
+    let mut events = Vec::new();
+    let mut last = 0;
+
+    for range in ranges {
+        events.push(part(last..range.start));
+        events.push(Event::Html("<mark class=\"blocked-term\">".into()));
+        last = range.end;
+        events.push(part(range));
+        events.push(Event::Html("</mark>".into()));
+    }
+
+    events.push(part(last..text.len()));
+
+    events
+}
+
+fn is_safe_url(url: &str) -> bool {
+    let url = url.to_lowercase();
+    url.starts_with("https://") || url.starts_with("http://") || url.starts_with("mailto:")
+}
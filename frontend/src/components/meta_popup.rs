@@ -1,6 +1,7 @@
 use crate::{
     components::{Popup, TextArea},
     fetch,
+    i18n::tr,
     pages::{NewEvent, BASE_API},
 };
 use shared::{CreateEventValidation, EditMetaData, ModEvent};
@@ -135,12 +136,12 @@ impl Component for MetaPopup {
 
             html! {
                 <Popup class="meta-popup" {on_close}>
-                    <div class="title">{ "Edit Event" }</div>
+                    <div class="title">{ tr("meta-title") }</div>
                     <div class="input-box">
                         <input
                             type="text"
                             name="title"
-                            placeholder="Title"
+                            placeholder={tr("meta-name")}
                             value={self.meta.title.clone()}
                             maxlength="20"
                             required=true
@@ -154,7 +155,7 @@ impl Component for MetaPopup {
                         <TextArea
                             id="input-desc"
                             name="desc"
-                            placeholder="event description"
+                            placeholder={tr("newevent-description")}
                             value={self.meta.description.clone()}
                             maxlength="1000"
                             required=true
@@ -169,12 +170,12 @@ impl Component for MetaPopup {
                         <button class="button-white"
                             disabled={self.send_pending}
                             onclick={on_click_close}>
-                            { "cancel" }
+                            { tr("meta-cancel") }
                         </button>
                         <button class="button-red"
                             disabled={self.send_pending || has_errors}
                             onclick={on_click_ok}>
-                            { "change" }
+                            { tr("meta-change") }
                         </button>
                     </div>
                 </Popup>
@@ -1,40 +1,114 @@
+mod branding;
+mod confirm_popup;
 mod context_popup;
+mod copy_button;
+mod countdown;
 mod delete_popup;
+mod error_banner;
 mod event_context;
 mod event_meta;
 mod footer;
 mod iconbar;
+mod infinite_scroll;
+mod markdown;
 mod meta_popup;
+mod mod_blocked_terms;
+mod mod_branding;
+mod mod_clusters;
+mod mod_countdown;
+mod mod_notifications;
 mod mod_password;
+mod mod_poll;
+mod mod_push;
+mod mod_question_cooldown;
+mod mod_question_languages;
+mod mod_question_length;
+mod mod_question_limits;
+mod mod_receipt;
+mod mod_series;
+mod mod_sessions;
+mod mod_slug;
+mod mod_stats;
+mod mod_summary;
+mod mod_survey;
 mod mod_tag;
+mod mod_viewer_capacity;
+mod mod_word_cloud;
 mod password_popup;
 mod payment_popup;
+mod poll;
 mod popup;
 mod qr;
 mod question;
 mod question_popup;
+mod share_button;
 mod share_popup;
+mod shortcuts_popup;
+mod skeleton;
 mod socket;
+mod speaker_queue;
 mod spinner;
+mod stats_chart;
+mod survey;
 mod textarea;
+mod toasts;
 mod upgrade;
+mod virtual_feed;
+mod word_cloud;
 
+pub use branding::Branding;
+pub use confirm_popup::ConfirmPopup;
 pub use context_popup::ContextPopup;
+pub use copy_button::CopyButton;
+pub use countdown::Countdown;
 pub use delete_popup::DeletePopup;
+pub use error_banner::ErrorBanner;
 pub use event_context::EventContext;
 pub use event_meta::EventMeta;
 pub use footer::Footer;
 pub use iconbar::IconBar;
+pub use infinite_scroll::InfiniteScroll;
+pub use markdown::Markdown;
 pub use meta_popup::MetaPopup;
+pub use mod_blocked_terms::ModBlockedTerms;
+pub use mod_branding::ModBranding;
+pub use mod_clusters::ModClusters;
+pub use mod_countdown::ModCountdown;
+pub use mod_notifications::ModNotifications;
 pub use mod_password::ModPassword;
+pub use mod_poll::ModPollCreate;
+pub use mod_push::ModPush;
+pub use mod_question_cooldown::ModQuestionCooldown;
+pub use mod_question_languages::ModQuestionLanguages;
+pub use mod_question_length::ModQuestionLength;
+pub use mod_question_limits::ModQuestionLimits;
+pub use mod_receipt::ModReceipt;
+pub use mod_series::ModSeries;
+pub use mod_sessions::ModSessions;
+pub use mod_slug::ModSlug;
+pub use mod_stats::ModStats;
+pub use mod_summary::ModSummary;
+pub use mod_survey::ModSurveyResults;
 pub use mod_tag::{ModTag, SharableTags};
+pub use mod_viewer_capacity::ModViewerCapacity;
+pub use mod_word_cloud::ModWordCloudCreate;
 pub use password_popup::PasswordPopup;
+pub use poll::PollItem;
 pub use popup::Popup;
 pub use qr::Qr;
 pub use question::{Question, QuestionClickType, QuestionFlags};
 pub use question_popup::QuestionPopup;
+pub use share_button::ShareButton;
 pub use share_popup::SharePopup;
+pub use shortcuts_popup::ShortcutsPopup;
+pub use skeleton::EventSkeleton;
 pub use socket::{EventSocket, SocketResponse};
+pub use speaker_queue::SpeakerQueueList;
 pub use spinner::Spinner;
+pub use stats_chart::StatsChart;
+pub use survey::SurveyForm;
 pub use textarea::TextArea;
+pub use toasts::Toasts;
 pub use upgrade::Upgrade;
+pub use virtual_feed::VirtualFeed;
+pub use word_cloud::WordCloudItem;
@@ -0,0 +1,139 @@
+use shared::{BlockedTerms, BlockedTermsAction, EventTokens, ModEvent};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Eq, PartialEq, Properties)]
+pub struct BlockedTermsProps {
+    pub tokens: EventTokens,
+    pub blocked_terms: BlockedTerms,
+}
+
+pub enum Msg {
+    TermsChange(Event),
+    ActionChange(Event),
+    Edited(bool),
+}
+
+/// words and phrases that send questions to the approval queue or hide them right away
+pub struct ModBlockedTerms {
+    /// comma separated as typed by the moderator
+    terms: String,
+    action: BlockedTermsAction,
+}
+
+impl Component for ModBlockedTerms {
+    type Message = Msg;
+    type Properties = BlockedTermsProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            terms: ctx.props().blocked_terms.terms.join(", "),
+            action: ctx.props().blocked_terms.action,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::TermsChange(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.terms = target.value();
+                self.request_edit(ctx);
+                true
+            }
+            Msg::ActionChange(e) => {
+                let target: HtmlSelectElement = e.target_dyn_into().unwrap_throw();
+                self.action = if target.value() == "hide" {
+                    BlockedTermsAction::Hide
+                } else {
+                    BlockedTermsAction::Review
+                };
+                self.request_edit(ctx);
+                true
+            }
+            Msg::Edited(_) => true,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        //Note: keep what is being typed unless the terms really changed
+        if self.blocked_terms() != ctx.props().blocked_terms {
+            self.terms = ctx.props().blocked_terms.terms.join(", ");
+            self.action = ctx.props().blocked_terms.action;
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let valid = self.blocked_terms().is_valid();
+
+        html! {
+            <div class={classes!("blocked-terms",(!valid).then_some("invalid"))}>
+                <input
+                    type="text"
+                    placeholder={tr("mod-blocked-terms-placeholder")}
+                    value={self.terms.clone()}
+                    onchange={ctx.link().callback(Msg::TermsChange)}
+                />
+                <select onchange={ctx.link().callback(Msg::ActionChange)}>
+                    <option value="review" selected={self.action == BlockedTermsAction::Review}>
+                        { tr("mod-blocked-terms-review") }
+                    </option>
+                    <option value="hide" selected={self.action == BlockedTermsAction::Hide}>
+                        { tr("mod-blocked-terms-hide") }
+                    </option>
+                </select>
+            </div>
+        }
+    }
+}
+
+impl ModBlockedTerms {
+    fn blocked_terms(&self) -> BlockedTerms {
+        BlockedTerms {
+            terms: self
+                .terms
+                .split(',')
+                .map(str::trim)
+                .filter(|term| !term.is_empty())
+                .map(ToString::to_string)
+                .collect(),
+            action: self.action,
+        }
+        .normalized()
+    }
+
+    fn request_edit(&self, ctx: &Context<Self>) {
+        let blocked_terms = self.blocked_terms();
+
+        if !blocked_terms.is_valid() {
+            return;
+        }
+
+        let props = ctx.props();
+        let id = props.tokens.public_token.clone();
+        let secret = props.tokens.moderator_token.clone().unwrap_throw();
+
+        ctx.link().send_future(async move {
+            match fetch::mod_edit_event(
+                BASE_API,
+                id,
+                secret,
+                ModEvent {
+                    blocked_terms: Some(blocked_terms),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Err(e) => {
+                    log::error!("mod_edit_event error: {e}");
+                    Msg::Edited(false)
+                }
+                Ok(_) => Msg::Edited(true),
+            }
+        });
+    }
+}
@@ -0,0 +1,248 @@
+use shared::{
+    EventBranding, EventTokens, ModEvent, BRANDING_BANNER_MAX_LENGTH, LOGO_CONTENT_TYPES,
+    LOGO_MAX_SIZE_MB,
+};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::{File, HtmlInputElement};
+use yew::prelude::*;
+
+use crate::{
+    fetch,
+    i18n::{tr, tr_args},
+    pages::BASE_API,
+};
+
+const DEFAULT_COLOR: &str = "#282828";
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModBrandingProps {
+    pub tokens: EventTokens,
+    pub branding: Option<EventBranding>,
+}
+
+pub enum Input {
+    Color,
+    Banner,
+    Logo,
+}
+
+pub enum Msg {
+    InputChange(Input, InputEvent),
+    LogoChanged(Event),
+    LogoUploaded(Option<String>),
+    RemoveLogo,
+    Save,
+    Reset,
+    Saved(bool),
+}
+
+/// editor for color, banner text and logo of a premium event
+pub struct ModBranding {
+    branding: EventBranding,
+    sending: bool,
+    uploading: bool,
+    logo_invalid: bool,
+}
+
+impl Component for ModBranding {
+    type Message = Msg;
+    type Properties = ModBrandingProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            branding: ctx.props().branding.clone().unwrap_or_default(),
+            sending: false,
+            uploading: false,
+            logo_invalid: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::InputChange(input, e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                let value = target.value();
+                let value = (!value.trim().is_empty()).then_some(value);
+
+                match input {
+                    Input::Color => self.branding.color = value,
+                    Input::Banner => self.branding.banner = value,
+                    Input::Logo => self.branding.logo_url = value,
+                }
+
+                true
+            }
+            Msg::LogoChanged(ev) => {
+                let target: HtmlInputElement = ev.target_dyn_into().unwrap_throw();
+                let Some(file) = target.files().and_then(|files| files.get(0)) else {
+                    return false;
+                };
+
+                self.logo_invalid = !fetch::attachment_request(&file).is_valid_logo();
+                if self.logo_invalid || self.uploading {
+                    return true;
+                }
+
+                self.uploading = true;
+
+                let tokens = &ctx.props().tokens;
+                let id = tokens.public_token.clone();
+                let secret = tokens.moderator_token.clone().unwrap_throw();
+
+                ctx.link().send_future(async move {
+                    match Self::upload(id, secret, &file).await {
+                        Err(e) => {
+                            log::error!("logo upload error: {e}");
+                            Msg::LogoUploaded(None)
+                        }
+                        Ok(key) => Msg::LogoUploaded(Some(key)),
+                    }
+                });
+
+                true
+            }
+            Msg::LogoUploaded(key) => {
+                self.uploading = false;
+
+                if key.is_none() {
+                    return true;
+                }
+
+                self.branding.logo = key;
+                self.request_edit(ctx, self.branding.clone())
+            }
+            Msg::RemoveLogo => {
+                self.branding.logo = None;
+                self.request_edit(ctx, self.branding.clone())
+            }
+            Msg::Save => self.request_edit(ctx, self.branding.clone()),
+            Msg::Reset => {
+                self.branding = EventBranding::default();
+                self.request_edit(ctx, EventBranding::default())
+            }
+            Msg::Saved(_) => {
+                self.sending = false;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().branding != old_props.branding {
+            self.branding = ctx.props().branding.clone().unwrap_or_default();
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let valid = self.branding.is_valid();
+        let unchanged = ctx.props().branding.clone().unwrap_or_default() == self.branding;
+
+        html! {
+            <div class={classes!("mod-branding",(!valid).then_some("invalid"))}>
+                <div class="title">{ tr("mod-branding-title") }</div>
+                <label>
+                    { tr("mod-branding-color") }
+                    <input
+                        type="color"
+                        value={self.branding.color.clone().unwrap_or_else(|| DEFAULT_COLOR.to_string())}
+                        oninput={ctx.link().callback(|e| Msg::InputChange(Input::Color, e))}
+                    />
+                </label>
+                <input
+                    type="text"
+                    placeholder={tr("mod-branding-banner")}
+                    maxlength={BRANDING_BANNER_MAX_LENGTH.to_string()}
+                    value={self.branding.banner.clone().unwrap_or_default()}
+                    oninput={ctx.link().callback(|e| Msg::InputChange(Input::Banner, e))}
+                />
+                <input
+                    type="url"
+                    placeholder={tr("mod-branding-logo-url")}
+                    value={self.branding.logo_url.clone().unwrap_or_default()}
+                    oninput={ctx.link().callback(|e| Msg::InputChange(Input::Logo, e))}
+                />
+                { self.view_logo_upload(ctx) }
+                <div class="buttons">
+                    <button class="button-white" disabled={!valid || unchanged || self.sending} onclick={ctx.link().callback(|_| Msg::Save)}>
+                        { tr("mod-branding-save") }
+                    </button>
+                    <button class="button-white" disabled={self.sending} onclick={ctx.link().callback(|_| Msg::Reset)}>
+                        { tr("mod-branding-reset") }
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ModBranding {
+    fn view_logo_upload(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="logo-upload">
+                {
+                    if self.branding.logo.is_some() {
+                        html! {
+                            <button class="button-white" disabled={self.sending} onclick={ctx.link().callback(|_| Msg::RemoveLogo)}>
+                                { tr("mod-branding-remove-logo") }
+                            </button>
+                        }
+                    } else {
+                        html! {
+                            <input
+                                type="file"
+                                accept={LOGO_CONTENT_TYPES.join(",")}
+                                disabled={self.uploading}
+                                onchange={ctx.link().callback(Msg::LogoChanged)}
+                            />
+                        }
+                    }
+                }
+                <div hidden={!self.logo_invalid} class="invalid">
+                    { tr_args("mod-branding-logo-invalid", [("size", LOGO_MAX_SIZE_MB.into())]) }
+                </div>
+            </div>
+        }
+    }
+
+    async fn upload(id: String, secret: String, file: &File) -> Result<String, fetch::FetchError> {
+        let upload = fetch::mod_request_logo(BASE_API, id, secret, file).await?;
+        fetch::upload_attachment(&upload.upload_url, file).await?;
+        Ok(upload.key)
+    }
+
+    fn request_edit(&mut self, ctx: &Context<Self>, branding: EventBranding) -> bool {
+        if self.sending {
+            return false;
+        }
+
+        self.sending = true;
+
+        let tokens = &ctx.props().tokens;
+        let id = tokens.public_token.clone();
+        let secret = tokens.moderator_token.clone().unwrap_throw();
+
+        ctx.link().send_future(async move {
+            match fetch::mod_edit_event(
+                BASE_API,
+                id,
+                secret,
+                ModEvent {
+                    branding: Some(branding),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Err(e) => {
+                    log::error!("mod_edit_event branding error: {e}");
+                    Msg::Saved(false)
+                }
+                Ok(_) => Msg::Saved(true),
+            }
+        });
+
+        true
+    }
+}
@@ -0,0 +1,160 @@
+use shared::{EventTokens, ModMergeQuestions, QuestionCluster, QuestionItem};
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModClustersProps {
+    pub tokens: EventTokens,
+    pub questions: Vec<QuestionItem>,
+}
+
+pub enum Msg {
+    Refresh,
+    Fetched(Option<Vec<QuestionCluster>>),
+    Merge(usize),
+    Merged,
+}
+
+/// groups of similar open questions the moderator can merge with one click
+pub struct ModClusters {
+    clusters: Option<Vec<QuestionCluster>>,
+    loading: bool,
+}
+
+impl Component for ModClusters {
+    type Message = Msg;
+    type Properties = ModClustersProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            clusters: None,
+            loading: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Refresh => {
+                self.loading = true;
+
+                let tokens = &ctx.props().tokens;
+                let id = tokens.public_token.clone();
+                let secret = tokens.moderator_token.clone().unwrap_throw();
+
+                ctx.link().send_future(async move {
+                    match fetch::mod_question_clusters(BASE_API, id, secret).await {
+                        Err(e) => {
+                            log::error!("mod_question_clusters error: {e}");
+                            Msg::Fetched(None)
+                        }
+                        Ok(clusters) => Msg::Fetched(Some(clusters)),
+                    }
+                });
+
+                true
+            }
+            Msg::Fetched(clusters) => {
+                self.loading = false;
+                self.clusters = clusters;
+                true
+            }
+            Msg::Merge(index) => {
+                let Some(cluster) = self
+                    .clusters
+                    .as_mut()
+                    .filter(|clusters| index < clusters.len())
+                    .map(|clusters| clusters.remove(index))
+                else {
+                    return false;
+                };
+
+                let Some((target, questions)) = cluster.questions.split_first() else {
+                    return true;
+                };
+
+                let merge = ModMergeQuestions {
+                    target: *target,
+                    questions: questions.to_vec(),
+                };
+
+                let tokens = &ctx.props().tokens;
+                let id = tokens.public_token.clone();
+                let secret = tokens.moderator_token.clone().unwrap_throw();
+
+                ctx.link().send_future(async move {
+                    if let Err(e) = fetch::mod_merge_questions(BASE_API, id, secret, merge).await {
+                        log::error!("mod_merge_questions error: {e}");
+                    }
+
+                    Msg::Merged
+                });
+
+                true
+            }
+            Msg::Merged => {
+                ctx.link().send_message(Msg::Refresh);
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="mod-stats mod-clusters">
+                <div class="mod-stats-title">{ tr("mod-clusters-title") }</div>
+                { self.view_clusters(ctx) }
+                <button
+                    class="button-red"
+                    disabled={self.loading}
+                    onclick={ctx.link().callback(|_| Msg::Refresh)}
+                >
+                    { tr(if self.clusters.is_some() { "mod-refresh" } else { "mod-clusters-find" }) }
+                </button>
+            </div>
+        }
+    }
+}
+
+impl ModClusters {
+    fn view_clusters(&self, ctx: &Context<Self>) -> Html {
+        let Some(clusters) = &self.clusters else {
+            return html! {};
+        };
+
+        if clusters.is_empty() {
+            return html! { <div class="hint">{ tr("mod-clusters-none") }</div> };
+        }
+
+        html! {
+            <>
+            {
+                for clusters.iter().enumerate().map(|(index, cluster)| html! {
+                    <div class="cluster">
+                        <ul>
+                            {
+                                for cluster.questions.iter().filter_map(|id| {
+                                    ctx.props().questions.iter().find(|q| q.id == *id)
+                                }).map(|q| html! {
+                                    <li>
+                                        <span class="likes">{ q.likes }</span>
+                                        { q.text.clone() }
+                                    </li>
+                                })
+                            }
+                        </ul>
+                        <button
+                            class="button-white"
+                            title={tr("mod-clusters-merge-hint")}
+                            onclick={ctx.link().callback(move |_| Msg::Merge(index))}
+                        >
+                            { tr("mod-clusters-merge") }
+                        </button>
+                    </div>
+                })
+            }
+            </>
+        }
+    }
+}
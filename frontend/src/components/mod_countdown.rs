@@ -0,0 +1,117 @@
+use shared::{EventTimer, EventTokens, ModTimer, TIMER_LABEL_MAX_LENGTH};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{
+    fetch,
+    i18n::{tr, tr_args},
+    pages::BASE_API,
+};
+
+/// durations offered to the moderator, in minutes
+const PRESETS: [i64; 4] = [1, 2, 5, 10];
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModCountdownProps {
+    pub tokens: EventTokens,
+    pub timer: Option<EventTimer>,
+}
+
+pub enum Msg {
+    LabelInput(InputEvent),
+    Start(i64),
+    Stop,
+    Sent,
+}
+
+/// moderator controls to start a countdown shown on every screen, or to stop the running one
+pub struct ModCountdown {
+    label: String,
+}
+
+impl Component for ModCountdown {
+    type Message = Msg;
+    type Properties = ModCountdownProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            label: tr("mod-countdown-label-default"),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::LabelInput(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.label = target.value();
+                true
+            }
+            Msg::Start(minutes) => {
+                Self::request_edit(
+                    ctx,
+                    ModTimer {
+                        seconds: Some(minutes * 60),
+                        label: self.label.clone(),
+                    },
+                );
+                false
+            }
+            Msg::Stop => {
+                Self::request_edit(ctx, ModTimer::default());
+                false
+            }
+            Msg::Sent => false,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="mod-countdown">
+                <div class="title">{ tr("mod-countdown-title") }</div>
+                <input
+                    type="text"
+                    placeholder={tr("mod-countdown-label")}
+                    maxlength={TIMER_LABEL_MAX_LENGTH.to_string()}
+                    value={self.label.clone()}
+                    oninput={ctx.link().callback(Msg::LabelInput)}
+                />
+                <div class="mod-countdown-buttons">
+                    {
+                        for PRESETS.iter().map(|minutes| {
+                            let minutes = *minutes;
+                            html! {
+                                <button class="button-white" onclick={ctx.link().callback(move |_| Msg::Start(minutes))}>
+                                    { tr_args("mod-countdown-minutes", [("minutes", minutes.into())]) }
+                                </button>
+                            }
+                        })
+                    }
+                    <button
+                        class="button-white"
+                        hidden={ctx.props().timer.is_none()}
+                        onclick={ctx.link().callback(|_| Msg::Stop)}
+                    >
+                        { tr("mod-countdown-stop") }
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ModCountdown {
+    fn request_edit(ctx: &Context<Self>, modify: ModTimer) {
+        let props = ctx.props();
+        let event = props.tokens.public_token.clone();
+        let secret = props.tokens.moderator_token.clone().unwrap_throw();
+
+        ctx.link().send_future(async move {
+            if let Err(e) = fetch::mod_timer(BASE_API, event, secret, modify).await {
+                log::error!("mod timer error: {e}");
+            }
+
+            Msg::Sent
+        });
+    }
+}
@@ -0,0 +1,171 @@
+use shared::{EventTokens, ModEvent, NotificationSettings, NOTIFY_LIKES_THRESHOLD_MAX};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{components::ModPush, fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Eq, PartialEq, Properties)]
+pub struct ModNotificationsProps {
+    pub tokens: EventTokens,
+    pub settings: NotificationSettings,
+}
+
+pub enum Msg {
+    ToggleEmail,
+    EmailChange(Event),
+    TogglePush,
+    ThresholdChange(Event),
+    Edited(bool),
+}
+
+/// which notifications the moderators get about new and popular questions, stored with the event
+pub struct ModNotifications {
+    settings: NotificationSettings,
+    /// the address input is shown before a valid address was entered
+    email_enabled: bool,
+}
+
+impl Component for ModNotifications {
+    type Message = Msg;
+    type Properties = ModNotificationsProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let settings = ctx.props().settings.clone();
+
+        Self {
+            email_enabled: settings.email.is_some(),
+            settings,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleEmail => {
+                self.email_enabled = !self.email_enabled;
+
+                if !self.email_enabled && self.settings.email.take().is_some() {
+                    self.request_edit(ctx);
+                }
+
+                true
+            }
+            Msg::EmailChange(e) => {
+                let value = Self::input_value(&e);
+                self.settings.email = (!value.trim().is_empty()).then_some(value);
+
+                self.request_edit(ctx);
+                true
+            }
+            Msg::TogglePush => {
+                self.settings.push = !self.settings.push;
+
+                self.request_edit(ctx);
+                true
+            }
+            Msg::ThresholdChange(e) => {
+                self.settings.likes_threshold =
+                    Self::input_value(&e).parse::<i32>().unwrap_or_default();
+
+                self.request_edit(ctx);
+                true
+            }
+            Msg::Edited(_) => false,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.settings = ctx.props().settings.clone();
+        self.email_enabled |= self.settings.email.is_some();
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let valid = self.settings.is_valid();
+
+        html! {
+            <div class={classes!("mod-notifications",(!valid).then_some("invalid"))}>
+                <div class="notify-option" onclick={ctx.link().callback(|_| Msg::ToggleEmail)}>
+                    <input
+                        type="checkbox"
+                        id="notify-email"
+                        name="notify-email"
+                        checked={self.email_enabled}
+                    />
+                    { tr("mod-notify-email") }
+                </div>
+                { if self.email_enabled { html! {
+                    <input
+                        class="notify-email"
+                        type="email"
+                        placeholder={tr("mod-notify-email-address")}
+                        value={self.settings.email.clone().unwrap_or_default()}
+                        onchange={ctx.link().callback(Msg::EmailChange)}
+                    />
+                }} else { html! {} } }
+                <div class="notify-option" onclick={ctx.link().callback(|_| Msg::TogglePush)}>
+                    <input
+                        type="checkbox"
+                        id="notify-push"
+                        name="notify-push"
+                        checked={self.settings.push}
+                    />
+                    { tr("mod-notify-push") }
+                </div>
+                { if self.settings.push { html! {
+                    <ModPush tokens={ctx.props().tokens.clone()} />
+                }} else { html! {} } }
+                <div class="notify-threshold">
+                    { tr("mod-notify-at") }
+                    <input
+                        type="number"
+                        min="0"
+                        max={NOTIFY_LIKES_THRESHOLD_MAX.to_string()}
+                        value={self.settings.likes_threshold.to_string()}
+                        onchange={ctx.link().callback(Msg::ThresholdChange)}
+                    />
+                    { tr("mod-notify-likes") }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ModNotifications {
+    fn input_value(e: &Event) -> String {
+        let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+        target.value()
+    }
+
+    /// invalid settings stay local until they are fixed
+    fn request_edit(&self, ctx: &Context<Self>) {
+        if !self.settings.is_valid() {
+            return;
+        }
+
+        let tokens = &ctx.props().tokens;
+        let id = tokens.public_token.clone();
+        let secret = tokens.moderator_token.clone().unwrap_throw();
+        let notifications = self.settings.clone();
+
+        ctx.link().send_future(async move {
+            match fetch::mod_edit_event(
+                BASE_API,
+                id,
+                secret,
+                ModEvent {
+                    notifications: Some(notifications),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Err(e) => {
+                    log::error!("mod_edit_event error: {e}");
+                    Msg::Edited(false)
+                }
+                Ok(_) => Msg::Edited(true),
+            }
+        });
+    }
+}
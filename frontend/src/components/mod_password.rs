@@ -3,7 +3,7 @@ use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
-use crate::{fetch, pages::BASE_API};
+use crate::{fetch, i18n::tr, pages::BASE_API};
 
 #[derive(Eq, PartialEq, Properties)]
 pub struct PasswordProps {
@@ -112,7 +112,7 @@ impl ModPassword {
                 class="button-white"
                 onclick={ctx.link().callback(|_|Msg::EnablePasswordInput)}
             >
-                { "Password" }
+                { tr("mod-password-title") }
             </button>
         }
     }
@@ -124,7 +124,7 @@ impl ModPassword {
                 <input
                     ref={self.input.clone()}
                     type="text"
-                    placeholder="password"
+                    placeholder={tr("mod-password-placeholder")}
                     maxlength="30"
                     {value}
                     oninput={ctx.link().callback(Msg::InputChange)}
@@ -0,0 +1,173 @@
+use shared::{AddPoll, EventTokens, POLL_MAX_OPTIONS, POLL_MIN_OPTIONS, POLL_TEXT_MAX_LENGTH};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{
+    fetch,
+    i18n::{tr, tr_args},
+    pages::BASE_API,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModPollProps {
+    pub tokens: EventTokens,
+}
+
+pub enum Msg {
+    Open,
+    Cancel,
+    QuestionInput(InputEvent),
+    OptionInput(usize, InputEvent),
+    AddOption,
+    Submit,
+    Added(bool),
+}
+
+/// moderator form to start a new poll
+pub struct ModPollCreate {
+    editing: bool,
+    sending: bool,
+    poll: AddPoll,
+}
+
+impl Component for ModPollCreate {
+    type Message = Msg;
+    type Properties = ModPollProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            editing: false,
+            sending: false,
+            poll: Self::empty_poll(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Open => {
+                self.editing = true;
+                true
+            }
+            Msg::Cancel => {
+                self.editing = false;
+                self.poll = Self::empty_poll();
+                true
+            }
+            Msg::QuestionInput(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.poll.question = target.value();
+                true
+            }
+            Msg::OptionInput(index, e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                if let Some(option) = self.poll.options.get_mut(index) {
+                    *option = target.value();
+                }
+                true
+            }
+            Msg::AddOption => {
+                if self.poll.options.len() < POLL_MAX_OPTIONS {
+                    self.poll.options.push(String::new());
+                }
+                true
+            }
+            Msg::Submit => {
+                if !self.poll.is_valid() || self.sending {
+                    return false;
+                }
+
+                self.sending = true;
+
+                let props = ctx.props();
+                Self::request_add(
+                    props.tokens.public_token.clone(),
+                    props.tokens.moderator_token.clone().unwrap_throw(),
+                    self.poll.clone(),
+                    ctx.link(),
+                );
+
+                true
+            }
+            Msg::Added(ok) => {
+                self.sending = false;
+
+                if ok {
+                    self.editing = false;
+                    self.poll = Self::empty_poll();
+                }
+
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if !self.editing {
+            return html! {
+                <button class="button-white" onclick={ctx.link().callback(|_| Msg::Open)}>
+                    { tr("mod-poll-open") }
+                </button>
+            };
+        }
+
+        let maxlength = POLL_TEXT_MAX_LENGTH.to_string();
+        let can_add = self.poll.options.len() < POLL_MAX_OPTIONS;
+        let can_submit = self.poll.is_valid() && !self.sending;
+
+        html! {
+            <div class="mod-poll">
+                <input
+                    type="text"
+                    placeholder={tr("mod-poll-question")}
+                    maxlength={maxlength.clone()}
+                    value={self.poll.question.clone()}
+                    oninput={ctx.link().callback(Msg::QuestionInput)}
+                />
+                {
+                    self.poll.options.iter().enumerate().map(|(index, option)| html! {
+                        <input
+                            type="text"
+                            placeholder={tr_args("mod-poll-option", [("number", index.saturating_add(1).into())])}
+                            maxlength={maxlength.clone()}
+                            value={option.clone()}
+                            oninput={ctx.link().callback(move |e| Msg::OptionInput(index, e))}
+                        />
+                    }).collect::<Html>()
+                }
+                <div class="mod-poll-buttons">
+                    <button class="button-white" hidden={!can_add} onclick={ctx.link().callback(|_| Msg::AddOption)}>
+                        { tr("mod-poll-add-option") }
+                    </button>
+                    <button class="button-white" disabled={!can_submit} onclick={ctx.link().callback(|_| Msg::Submit)}>
+                        { tr("mod-start") }
+                    </button>
+                    <button class="button-white" onclick={ctx.link().callback(|_| Msg::Cancel)}>
+                        { tr("confirm-cancel") }
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ModPollCreate {
+    fn empty_poll() -> AddPoll {
+        AddPoll {
+            question: String::new(),
+            options: vec![String::new(); POLL_MIN_OPTIONS],
+        }
+    }
+
+    fn request_add(id: String, secret: String, poll: AddPoll, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            match fetch::mod_add_poll(BASE_API, id, secret, poll).await {
+                Err(e) => {
+                    log::error!("mod_add_poll error: {e}");
+                    Msg::Added(false)
+                }
+                Ok(_) => Msg::Added(true),
+            }
+        });
+    }
+}
@@ -0,0 +1,194 @@
+use gloo_utils::format::JsValueSerdeExt;
+use shared::{EventTokens, WebPushSubscription};
+use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{PushManager, PushSubscriptionOptionsInit, ServiceWorkerRegistration};
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, local_cache::LocalCache, pages::BASE_API};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModPushProps {
+    pub tokens: EventTokens,
+}
+
+pub enum Msg {
+    Available(Option<String>),
+    Toggle,
+    Subscribed(Option<String>),
+    Unsubscribed(bool),
+}
+
+/// notifies the moderator about new and popular questions via Web Push, even with the tab closed
+pub struct ModPush {
+    /// application server key, unset where push is unavailable
+    key: Option<String>,
+    /// push endpoint of this browser while subscribed to the event
+    endpoint: Option<String>,
+    sending: bool,
+    /// the browser refused (e.g. notifications blocked) or the server did not store it
+    failed: bool,
+}
+
+impl Component for ModPush {
+    type Message = Msg;
+    type Properties = ModPushProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link()
+            .send_future(async { Msg::Available(Self::server_key().await) });
+
+        Self {
+            key: None,
+            endpoint: LocalCache::web_push(&ctx.props().tokens.public_token),
+            sending: false,
+            failed: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Available(key) => {
+                self.key = key;
+                true
+            }
+            Msg::Toggle => {
+                let Some(key) = self.key.clone() else {
+                    return false;
+                };
+
+                if self.sending {
+                    return false;
+                }
+
+                self.sending = true;
+                self.failed = false;
+
+                let tokens = &ctx.props().tokens;
+                let id = tokens.public_token.clone();
+                let secret = tokens.moderator_token.clone().unwrap_throw();
+
+                let endpoint = self.endpoint.clone();
+                ctx.link().send_future(async move {
+                    if let Some(endpoint) = endpoint {
+                        Msg::Unsubscribed(Self::unsubscribe(id, secret, endpoint).await)
+                    } else {
+                        Msg::Subscribed(Self::subscribe(&key, id, secret).await)
+                    }
+                });
+
+                true
+            }
+            Msg::Subscribed(endpoint) => {
+                self.sending = false;
+                self.failed = endpoint.is_none();
+
+                if endpoint.is_some() {
+                    self.endpoint = endpoint;
+                    LocalCache::set_web_push(
+                        &ctx.props().tokens.public_token,
+                        self.endpoint.clone(),
+                    );
+                }
+
+                true
+            }
+            Msg::Unsubscribed(success) => {
+                self.sending = false;
+
+                if success {
+                    self.endpoint = None;
+                    LocalCache::set_web_push(&ctx.props().tokens.public_token, None);
+                }
+
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if self.key.is_none() {
+            return html! {};
+        }
+
+        html! {
+            <>
+                <div class="push-option" onclick={ctx.link().callback(|_| Msg::Toggle)}>
+                    <input
+                        type="checkbox"
+                        id="web-push"
+                        name="web-push"
+                        checked={self.endpoint.is_some()}
+                        disabled={self.sending}
+                    />
+                    { tr("mod-push-browser") }
+                </div>
+                { if self.failed { html! {
+                    <div class="push-failed">{ tr("mod-push-failed") }</div>
+                }} else { html! {} } }
+            </>
+        }
+    }
+}
+
+impl ModPush {
+    /// `None` without a service worker, it is not registered when running locally
+    async fn push_manager() -> Option<PushManager> {
+        let registration = JsFuture::from(
+            gloo_utils::window()
+                .navigator()
+                .service_worker()
+                .get_registration(),
+        )
+        .await
+        .ok()?;
+
+        registration
+            .dyn_into::<ServiceWorkerRegistration>()
+            .ok()?
+            .push_manager()
+            .ok()
+    }
+
+    /// `None` if the browser or the server do not support push
+    async fn server_key() -> Option<String> {
+        Self::push_manager().await?;
+
+        fetch::web_push_key(BASE_API)
+            .await
+            .map_err(|e| log::info!("web push unavailable: {e}"))
+            .ok()
+    }
+
+    /// asks for the notification permission, returns the endpoint once the server stored it
+    async fn subscribe(key: &str, id: String, secret: String) -> Option<String> {
+        let manager = Self::push_manager().await?;
+
+        let mut options = PushSubscriptionOptionsInit::new();
+        options.user_visible_only(true);
+        options.application_server_key(Some(&JsValue::from_str(key)));
+
+        let subscription = JsFuture::from(manager.subscribe_with_options(&options).ok()?)
+            .await
+            .map_err(|e| log::warn!("push subscribe error: {e:?}"))
+            .ok()?;
+
+        //Note: serialized via `PushSubscription.toJSON()`
+        let subscription: WebPushSubscription = subscription.into_serde().ok()?;
+        let endpoint = subscription.endpoint.clone();
+
+        fetch::mod_push_subscribe(BASE_API, id, secret, subscription)
+            .await
+            .map_err(|e| log::error!("mod_push_subscribe error: {e}"))
+            .ok()?;
+
+        Some(endpoint)
+    }
+
+    async fn unsubscribe(id: String, secret: String, endpoint: String) -> bool {
+        fetch::mod_push_unsubscribe(BASE_API, id, secret, endpoint)
+            .await
+            .map_err(|e| log::error!("mod_push_unsubscribe error: {e}"))
+            .is_ok()
+    }
+}
@@ -0,0 +1,102 @@
+use shared::{EventTokens, ModEvent, QUESTION_COOLDOWN_MAX_MINS};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Eq, PartialEq, Properties)]
+pub struct QuestionCooldownProps {
+    pub tokens: EventTokens,
+    pub minutes: u32,
+}
+
+pub enum Msg {
+    InputChange(Event),
+    Edited(bool),
+}
+
+/// limits every participant to one question per number of minutes
+pub struct ModQuestionCooldown {
+    minutes: u32,
+}
+
+impl Component for ModQuestionCooldown {
+    type Message = Msg;
+    type Properties = QuestionCooldownProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            minutes: ctx.props().minutes,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::InputChange(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.minutes = target.value().parse::<u32>().unwrap_or_default();
+
+                if self.minutes <= QUESTION_COOLDOWN_MAX_MINS {
+                    let props = ctx.props();
+                    Self::request_edit(
+                        props.tokens.public_token.clone(),
+                        props.tokens.moderator_token.clone().unwrap_throw(),
+                        ctx.link(),
+                        self.minutes,
+                    );
+                }
+
+                true
+            }
+            Msg::Edited(_) => true,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.minutes = ctx.props().minutes;
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let invalid = self.minutes > QUESTION_COOLDOWN_MAX_MINS;
+
+        html! {
+            <div class={classes!("question-cooldown",invalid.then_some("invalid"))}>
+                { tr("mod-cooldown-before") }
+                <input
+                    type="number"
+                    min="0"
+                    max={QUESTION_COOLDOWN_MAX_MINS.to_string()}
+                    value={self.minutes.to_string()}
+                    onchange={ctx.link().callback(Msg::InputChange)}
+                />
+                { tr("mod-cooldown-after") }
+            </div>
+        }
+    }
+}
+
+impl ModQuestionCooldown {
+    fn request_edit(id: String, secret: String, link: &html::Scope<Self>, minutes: u32) {
+        link.send_future(async move {
+            match fetch::mod_edit_event(
+                BASE_API,
+                id,
+                secret,
+                ModEvent {
+                    question_cooldown_mins: Some(minutes),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Err(e) => {
+                    log::error!("mod_edit_event error: {e}");
+                    Msg::Edited(false)
+                }
+                Ok(_) => Msg::Edited(true),
+            }
+        });
+    }
+}
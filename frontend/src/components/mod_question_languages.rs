@@ -0,0 +1,129 @@
+use shared::{EventTokens, LanguageAction, ModEvent, QuestionLanguages, QUESTION_LANGUAGES};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Eq, PartialEq, Properties)]
+pub struct QuestionLanguagesProps {
+    pub tokens: EventTokens,
+    pub languages: QuestionLanguages,
+}
+
+pub enum Msg {
+    Toggle(&'static str),
+    ActionChange(Event),
+    Edited(bool),
+}
+
+/// languages the event takes questions in, others are sent to review or rejected
+pub struct ModQuestionLanguages {
+    languages: QuestionLanguages,
+}
+
+impl Component for ModQuestionLanguages {
+    type Message = Msg;
+    type Properties = QuestionLanguagesProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            languages: ctx.props().languages.clone(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Toggle(code) => {
+                if let Some(index) = self.languages.languages.iter().position(|l| l == code) {
+                    self.languages.languages.remove(index);
+                } else {
+                    self.languages.languages.push(code.to_string());
+                }
+                self.request_edit(ctx);
+                true
+            }
+            Msg::ActionChange(e) => {
+                let target: HtmlSelectElement = e.target_dyn_into().unwrap_throw();
+                self.languages.action = if target.value() == "reject" {
+                    LanguageAction::Reject
+                } else {
+                    LanguageAction::Review
+                };
+                self.request_edit(ctx);
+                true
+            }
+            Msg::Edited(_) => true,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.languages = ctx.props().languages.clone();
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="question-languages">
+                <div class="languages">
+                    {
+                        for QUESTION_LANGUAGES.iter().map(|(code, name)| {
+                            let code = *code;
+                            let checked = self.languages.languages.iter().any(|l| l == code);
+                            html! {
+                                <label key={code}>
+                                    <input
+                                        type="checkbox"
+                                        {checked}
+                                        onchange={ctx.link().callback(move |_| Msg::Toggle(code))}
+                                    />
+                                    { *name }
+                                </label>
+                            }
+                        })
+                    }
+                </div>
+                <select
+                    disabled={!self.languages.is_enabled()}
+                    onchange={ctx.link().callback(Msg::ActionChange)}
+                >
+                    <option value="review" selected={self.languages.action == LanguageAction::Review}>
+                        { tr("mod-languages-review") }
+                    </option>
+                    <option value="reject" selected={self.languages.action == LanguageAction::Reject}>
+                        { tr("mod-languages-reject") }
+                    </option>
+                </select>
+            </div>
+        }
+    }
+}
+
+impl ModQuestionLanguages {
+    fn request_edit(&self, ctx: &Context<Self>) {
+        let props = ctx.props();
+        let id = props.tokens.public_token.clone();
+        let secret = props.tokens.moderator_token.clone().unwrap_throw();
+        let question_languages = self.languages.clone();
+
+        ctx.link().send_future(async move {
+            match fetch::mod_edit_event(
+                BASE_API,
+                id,
+                secret,
+                ModEvent {
+                    question_languages: Some(question_languages),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Err(e) => {
+                    log::error!("mod_edit_event error: {e}");
+                    Msg::Edited(false)
+                }
+                Ok(_) => Msg::Edited(true),
+            }
+        });
+    }
+}
@@ -0,0 +1,127 @@
+use shared::{
+    EventTokens, ModEvent, QuestionLength, QUESTION_LENGTH_LOWER_BOUND, QUESTION_LENGTH_UPPER_BOUND,
+};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Eq, PartialEq, Properties)]
+pub struct QuestionLengthProps {
+    pub tokens: EventTokens,
+    pub length: QuestionLength,
+}
+
+#[derive(Debug)]
+pub enum Input {
+    Min,
+    Max,
+}
+
+pub enum Msg {
+    InputChange(Input, Event),
+    Edited(bool),
+}
+
+pub struct ModQuestionLength {
+    length: QuestionLength,
+}
+
+impl Component for ModQuestionLength {
+    type Message = Msg;
+    type Properties = QuestionLengthProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            length: ctx.props().length,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::InputChange(input, e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                let value = target.value().parse::<usize>().unwrap_or_default();
+
+                match input {
+                    Input::Min => self.length.min = value,
+                    Input::Max => self.length.max = value,
+                }
+
+                if self.length.is_valid() {
+                    let props = ctx.props();
+                    Self::request_edit(
+                        props.tokens.public_token.clone(),
+                        props.tokens.moderator_token.clone().unwrap_throw(),
+                        ctx.link(),
+                        self.length,
+                    );
+                }
+
+                true
+            }
+            Msg::Edited(_) => true,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.length = ctx.props().length;
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let min = QUESTION_LENGTH_LOWER_BOUND.to_string();
+        let max = QUESTION_LENGTH_UPPER_BOUND.to_string();
+
+        html! {
+            <div class={classes!("question-length",(!self.length.is_valid()).then_some("invalid"))}>
+                { tr("mod-length-before") }
+                <input
+                    type="number"
+                    min={min.clone()}
+                    max={max.clone()}
+                    value={self.length.min.to_string()}
+                    onchange={ctx.link().callback(|e| Msg::InputChange(Input::Min,e))}
+                />
+                { tr("mod-length-to") }
+                <input
+                    type="number"
+                    {min}
+                    {max}
+                    value={self.length.max.to_string()}
+                    onchange={ctx.link().callback(|e| Msg::InputChange(Input::Max,e))}
+                />
+            </div>
+        }
+    }
+}
+
+impl ModQuestionLength {
+    fn request_edit(
+        id: String,
+        secret: String,
+        link: &html::Scope<Self>,
+        question_length: QuestionLength,
+    ) {
+        link.send_future(async move {
+            match fetch::mod_edit_event(
+                BASE_API,
+                id,
+                secret,
+                ModEvent {
+                    question_length: Some(question_length),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Err(e) => {
+                    log::error!("mod_edit_event error: {e}");
+                    Msg::Edited(false)
+                }
+                Ok(_) => Msg::Edited(true),
+            }
+        });
+    }
+}
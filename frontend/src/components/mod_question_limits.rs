@@ -0,0 +1,126 @@
+use shared::{
+    EventTokens, ModEvent, QuestionLimits, QUESTIONS_MAX, QUESTIONS_PER_MINUTE_UPPER_BOUND,
+};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Eq, PartialEq, Properties)]
+pub struct QuestionLimitsProps {
+    pub tokens: EventTokens,
+    pub limits: QuestionLimits,
+}
+
+#[derive(Debug)]
+pub enum Input {
+    Total,
+    PerMinute,
+}
+
+pub enum Msg {
+    InputChange(Input, Event),
+    Edited(bool),
+}
+
+/// caps the questions an event takes in total and per minute
+pub struct ModQuestionLimits {
+    limits: QuestionLimits,
+}
+
+impl Component for ModQuestionLimits {
+    type Message = Msg;
+    type Properties = QuestionLimitsProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            limits: ctx.props().limits,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::InputChange(input, e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                let value = target.value().parse::<usize>().unwrap_or_default();
+
+                match input {
+                    Input::Total => self.limits.total = value,
+                    Input::PerMinute => self.limits.per_minute = value,
+                }
+
+                if self.limits.is_valid() {
+                    let props = ctx.props();
+                    Self::request_edit(
+                        props.tokens.public_token.clone(),
+                        props.tokens.moderator_token.clone().unwrap_throw(),
+                        ctx.link(),
+                        self.limits,
+                    );
+                }
+
+                true
+            }
+            Msg::Edited(_) => true,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.limits = ctx.props().limits;
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class={classes!("question-limits",(!self.limits.is_valid()).then_some("invalid"))}>
+                { tr("mod-limits-before") }
+                <input
+                    type="number"
+                    min="0"
+                    max={QUESTIONS_MAX.to_string()}
+                    value={self.limits.total.to_string()}
+                    onchange={ctx.link().callback(|e| Msg::InputChange(Input::Total,e))}
+                />
+                { tr("mod-limits-between") }
+                <input
+                    type="number"
+                    min="0"
+                    max={QUESTIONS_PER_MINUTE_UPPER_BOUND.to_string()}
+                    value={self.limits.per_minute.to_string()}
+                    onchange={ctx.link().callback(|e| Msg::InputChange(Input::PerMinute,e))}
+                />
+                { tr("mod-limits-after") }
+            </div>
+        }
+    }
+}
+
+impl ModQuestionLimits {
+    fn request_edit(
+        id: String,
+        secret: String,
+        link: &html::Scope<Self>,
+        question_limits: QuestionLimits,
+    ) {
+        link.send_future(async move {
+            match fetch::mod_edit_event(
+                BASE_API,
+                id,
+                secret,
+                ModEvent {
+                    question_limits: Some(question_limits),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Err(e) => {
+                    log::error!("mod_edit_event error: {e}");
+                    Msg::Edited(false)
+                }
+                Ok(_) => Msg::Edited(true),
+            }
+        });
+    }
+}
@@ -0,0 +1,147 @@
+use shared::{EventTokens, ReceiptDetails, RECEIPT_FIELD_MAX_LENGTH};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModReceiptProps {
+    pub tokens: EventTokens,
+}
+
+pub enum Msg {
+    NameInput(InputEvent),
+    AddressInput(InputEvent),
+    VatIdInput(InputEvent),
+    Save,
+    Saved(Option<ReceiptDetails>),
+}
+
+/// buyer details (e.g. VAT id) for the receipt of the premium purchase and a link to download it
+pub struct ModReceipt {
+    details: ReceiptDetails,
+    sending: bool,
+    saved: bool,
+}
+
+impl Component for ModReceipt {
+    type Message = Msg;
+    type Properties = ModReceiptProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            details: ReceiptDetails::default(),
+            sending: false,
+            saved: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::NameInput(e) => {
+                self.details.name = Self::input_value(&e);
+                self.saved = false;
+                true
+            }
+            Msg::AddressInput(e) => {
+                self.details.address = Self::input_value(&e);
+                self.saved = false;
+                true
+            }
+            Msg::VatIdInput(e) => {
+                self.details.vat_id = Self::input_value(&e);
+                self.saved = false;
+                true
+            }
+            Msg::Save => {
+                if !self.details.is_valid() || self.sending {
+                    return false;
+                }
+
+                self.sending = true;
+
+                let props = ctx.props();
+                Self::request_edit(
+                    props.tokens.public_token.clone(),
+                    props.tokens.moderator_token.clone().unwrap_throw(),
+                    self.details.clone(),
+                    ctx.link(),
+                );
+
+                true
+            }
+            Msg::Saved(details) => {
+                self.sending = false;
+
+                if let Some(details) = details {
+                    self.details = details;
+                    self.saved = true;
+                }
+
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let tokens = &ctx.props().tokens;
+        let download = format!(
+            "{BASE_API}/api/mod/event/receipt/{}/{}",
+            tokens.public_token,
+            tokens.moderator_token.clone().unwrap_or_default()
+        );
+        let can_save = self.details.is_valid() && !self.sending && !self.saved;
+
+        html! {
+            <div class="mod-receipt">
+                <input
+                    type="text"
+                    placeholder={tr("mod-receipt-company")}
+                    maxlength={RECEIPT_FIELD_MAX_LENGTH.to_string()}
+                    value={self.details.name.clone().unwrap_or_default()}
+                    oninput={ctx.link().callback(Msg::NameInput)}
+                />
+                <input
+                    type="text"
+                    placeholder={tr("mod-receipt-address")}
+                    maxlength={RECEIPT_FIELD_MAX_LENGTH.to_string()}
+                    value={self.details.address.clone().unwrap_or_default()}
+                    oninput={ctx.link().callback(Msg::AddressInput)}
+                />
+                <input
+                    type="text"
+                    placeholder={tr("mod-receipt-vat")}
+                    maxlength={RECEIPT_FIELD_MAX_LENGTH.to_string()}
+                    value={self.details.vat_id.clone().unwrap_or_default()}
+                    oninput={ctx.link().callback(Msg::VatIdInput)}
+                />
+                <button class="button-white" disabled={!can_save} onclick={ctx.link().callback(|_| Msg::Save)}>
+                    { tr("mod-receipt-save") }
+                </button>
+                <a href={download} target="_blank">{ tr("mod-receipt-download") }</a>
+            </div>
+        }
+    }
+}
+
+impl ModReceipt {
+    fn input_value(e: &InputEvent) -> Option<String> {
+        let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+        let value = target.value();
+
+        (!value.trim().is_empty()).then_some(value)
+    }
+
+    fn request_edit(id: String, secret: String, details: ReceiptDetails, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            match fetch::mod_edit_receipt(BASE_API, id, secret, details).await {
+                Err(e) => {
+                    log::error!("mod_edit_receipt error: {e}");
+                    Msg::Saved(None)
+                }
+                Ok(details) => Msg::Saved(Some(details)),
+            }
+        });
+    }
+}
@@ -0,0 +1,226 @@
+use shared::{EditSeries, EventSeries, EventTokens};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::{
+    fetch,
+    i18n::{tr, tr_args},
+    pages::BASE_API,
+    time::format_date,
+};
+
+/// intervals offered to start a series with, in days, along with their message id
+const SERIES_INTERVALS: [(u32, &str); 4] = [
+    (1, "mod-series-daily"),
+    (7, "mod-series-weekly"),
+    (14, "mod-series-two-weeks"),
+    (28, "mod-series-four-weeks"),
+];
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModSeriesProps {
+    pub tokens: EventTokens,
+    pub series: Option<EventSeries>,
+}
+
+pub enum Msg {
+    IntervalChange(Event),
+    Start,
+    End,
+    Edited(Option<EventSeries>),
+    Fetched(Option<EventSeries>),
+}
+
+/// moderator controls to repeat the event as a series and browse its past occurrences
+pub struct ModSeries {
+    interval_days: u32,
+    sending: bool,
+    archive: Option<EventSeries>,
+}
+
+impl Component for ModSeries {
+    type Message = Msg;
+    type Properties = ModSeriesProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self::request_archive(ctx);
+
+        Self {
+            interval_days: 7,
+            sending: false,
+            archive: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::IntervalChange(ev) => {
+                let e: HtmlSelectElement = ev.target_dyn_into().unwrap_throw();
+                self.interval_days = e.value().parse().unwrap_or(self.interval_days);
+                false
+            }
+            Msg::Start => self.request_edit(ctx, self.interval_days),
+            Msg::End => self.request_edit(ctx, 0),
+            Msg::Edited(series) => {
+                self.sending = false;
+
+                if series.is_some() {
+                    self.archive = series;
+                }
+
+                true
+            }
+            Msg::Fetched(series) => {
+                self.archive = series;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().series != old_props.series {
+            Self::request_archive(ctx);
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let active = self.archive.as_ref().is_some_and(EventSeries::is_active);
+
+        html! {
+            <div class="mod-series">
+                { if active { self.view_active(ctx) } else { self.view_start(ctx) } }
+                { self.view_archive(ctx) }
+            </div>
+        }
+    }
+}
+
+impl ModSeries {
+    fn view_start(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="mod-series-start">
+                <select onchange={ctx.link().callback(Msg::IntervalChange)}>
+                    {
+                        SERIES_INTERVALS.iter().map(|(days, label)| html! {
+                            <option value={days.to_string()} selected={*days == self.interval_days}>
+                                { tr_args("mod-series-repeat", [("interval", tr(label).into())]) }
+                            </option>
+                        }).collect::<Html>()
+                    }
+                </select>
+                <button class="button-white" disabled={self.sending} onclick={ctx.link().callback(|_| Msg::Start)}>
+                    { tr("mod-series-start") }
+                </button>
+            </div>
+        }
+    }
+
+    fn view_active(&self, ctx: &Context<Self>) -> Html {
+        let Some(series) = &self.archive else {
+            return html! {};
+        };
+
+        let url = format!("/series/{}", series.id);
+        let interval = SERIES_INTERVALS
+            .iter()
+            .find(|(days, _)| *days == series.interval_days)
+            .map_or_else(
+                || tr_args("mod-series-days", [("days", series.interval_days.into())]),
+                |(_, label)| tr(label),
+            );
+
+        html! {
+            <div class="mod-series-active">
+                <div class="note">
+                    { tr_args("mod-series-active", [("interval", interval.into())]) }
+                    <a href={url.clone()}>{ url }</a>
+                </div>
+                <button class="button-white" disabled={self.sending} onclick={ctx.link().callback(|_| Msg::End)}>
+                    { tr("mod-series-end") }
+                </button>
+            </div>
+        }
+    }
+
+    /// newest first, every past occurrence stays available to the moderator
+    fn view_archive(&self, ctx: &Context<Self>) -> Html {
+        let Some(series) = &self.archive else {
+            return html! {};
+        };
+
+        if series.occurrences.len() < 2 {
+            return html! {};
+        }
+
+        let tokens = &ctx.props().tokens;
+        let secret = tokens.moderator_token.clone().unwrap_or_default();
+
+        html! {
+            <div class="mod-series-archive">
+                {
+                    series.occurrences.iter().rev().map(|occurrence| {
+                        let current = occurrence.public_token == tokens.public_token;
+
+                        html! {
+                            <a
+                                key={occurrence.public_token.clone()}
+                                class={classes!("occurrence",current.then_some("current"))}
+                                href={format!("/eventmod/{}/{secret}", occurrence.public_token)}
+                            >
+                                { format_date(occurrence.start_unix) }
+                            </a>
+                        }
+                    }).collect::<Html>()
+                }
+            </div>
+        }
+    }
+
+    fn request_edit(&mut self, ctx: &Context<Self>, interval_days: u32) -> bool {
+        if self.sending {
+            return false;
+        }
+
+        self.sending = true;
+
+        let tokens = &ctx.props().tokens;
+        let id = tokens.public_token.clone();
+        let secret = tokens.moderator_token.clone().unwrap_throw();
+
+        ctx.link().send_future(async move {
+            match fetch::mod_edit_series(BASE_API, id, secret, EditSeries { interval_days }).await {
+                Err(e) => {
+                    log::error!("mod_edit_series error: {e}");
+                    Msg::Edited(None)
+                }
+                Ok(series) => Msg::Edited(Some(series)),
+            }
+        });
+
+        true
+    }
+
+    fn request_archive(ctx: &Context<Self>) {
+        let props = ctx.props();
+
+        if props.series.is_none() {
+            return;
+        }
+
+        let id = props.tokens.public_token.clone();
+        let secret = props.tokens.moderator_token.clone().unwrap_throw();
+
+        ctx.link().send_future(async move {
+            match fetch::mod_series_archive(BASE_API, id, secret).await {
+                Err(e) => {
+                    log::error!("mod_series_archive error: {e}");
+                    Msg::Fetched(None)
+                }
+                Ok(series) => Msg::Fetched(Some(series)),
+            }
+        });
+    }
+}
@@ -0,0 +1,177 @@
+use shared::{
+    AddSession, EventState, EventTokens, ModSession, Session, States, SESSION_MAX_PER_EVENT,
+    SESSION_NAME_MAX_LENGTH,
+};
+use std::str::FromStr;
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModSessionsProps {
+    pub tokens: EventTokens,
+    pub sessions: Vec<Session>,
+}
+
+pub enum Msg {
+    NameInput(InputEvent),
+    Add,
+    Added(bool),
+    StateChange(i64, Event),
+    Sent,
+}
+
+/// moderator controls to add agenda sessions and open/close each of them
+pub struct ModSessions {
+    sending: bool,
+    session: AddSession,
+}
+
+impl Component for ModSessions {
+    type Message = Msg;
+    type Properties = ModSessionsProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            sending: false,
+            session: AddSession::default(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::NameInput(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.session.name = target.value();
+                true
+            }
+            Msg::Add => {
+                if !self.session.is_valid() || self.sending {
+                    return false;
+                }
+
+                self.sending = true;
+
+                let props = ctx.props();
+                Self::request_add(
+                    props.tokens.public_token.clone(),
+                    props.tokens.moderator_token.clone().unwrap_throw(),
+                    self.session.clone(),
+                    ctx.link(),
+                );
+
+                true
+            }
+            Msg::Added(ok) => {
+                self.sending = false;
+
+                if ok {
+                    self.session = AddSession::default();
+                }
+
+                true
+            }
+            Msg::StateChange(session, ev) => {
+                let e: HtmlSelectElement = ev.target().unwrap_throw().dyn_into().unwrap_throw();
+                let state = States::from_str(e.value().as_str()).unwrap_throw();
+
+                let props = ctx.props();
+                Self::request_edit(
+                    props.tokens.public_token.clone(),
+                    props.tokens.moderator_token.clone().unwrap_throw(),
+                    session,
+                    ModSession {
+                        state: EventState { state },
+                    },
+                    ctx.link(),
+                );
+
+                false
+            }
+            Msg::Sent => false,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let sessions = &ctx.props().sessions;
+
+        html! {
+            <div class="mod-sessions">
+                {
+                    sessions.iter().map(|session| Self::view_session(ctx, session)).collect::<Html>()
+                }
+                { self.view_add(ctx, sessions.len() < SESSION_MAX_PER_EVENT) }
+            </div>
+        }
+    }
+}
+
+impl ModSessions {
+    fn view_add(&self, ctx: &Context<Self>, allowed: bool) -> Html {
+        if !allowed {
+            return html! {};
+        }
+
+        let can_add = self.session.is_valid() && !self.sending;
+
+        html! {
+            <div class="mod-session-add">
+                <input
+                    type="text"
+                    placeholder={tr("mod-sessions-placeholder")}
+                    maxlength={SESSION_NAME_MAX_LENGTH.to_string()}
+                    value={self.session.name.clone()}
+                    oninput={ctx.link().callback(Msg::NameInput)}
+                />
+                <button class="button-white" disabled={!can_add} onclick={ctx.link().callback(|_| Msg::Add)}>
+                    { tr("mod-sessions-add") }
+                </button>
+            </div>
+        }
+    }
+
+    fn view_session(ctx: &Context<Self>, session: &Session) -> Html {
+        let id = session.id;
+
+        html! {
+            <div class="mod-session" key={id}>
+                <div class="name">{ session.name.clone() }</div>
+                <select onchange={ctx.link().callback(move |e| Msg::StateChange(id, e))}>
+                    <option value="0" selected={session.state.is_open()}>{ tr("mod-session-open") }</option>
+                    <option value="1" selected={session.state.is_vote_only()}>{ tr("mod-session-vote-only") }</option>
+                    <option value="2" selected={session.state.is_closed()}>{ tr("mod-session-closed") }</option>
+                </select>
+            </div>
+        }
+    }
+
+    fn request_add(id: String, secret: String, session: AddSession, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            match fetch::mod_add_session(BASE_API, id, secret, session).await {
+                Err(e) => {
+                    log::error!("mod_add_session error: {e}");
+                    Msg::Added(false)
+                }
+                Ok(_) => Msg::Added(true),
+            }
+        });
+    }
+
+    fn request_edit(
+        event: String,
+        secret: String,
+        session: i64,
+        modify: ModSession,
+        link: &html::Scope<Self>,
+    ) {
+        link.send_future(async move {
+            if let Err(e) = fetch::mod_session(BASE_API, event, secret, session, modify).await {
+                log::error!("mod session error: {e}");
+            }
+
+            Msg::Sent
+        });
+    }
+}
@@ -0,0 +1,115 @@
+use shared::{EditSlug, EventTokens, SLUG_MAX_LENGTH};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModSlugProps {
+    pub tokens: EventTokens,
+    pub slug: Option<String>,
+}
+
+pub enum Msg {
+    Input(InputEvent),
+    Save,
+    Saved(bool),
+}
+
+/// lets the moderator of a premium event claim a readable short url like `/e/rustconf-keynote`
+pub struct ModSlug {
+    slug: EditSlug,
+    sending: bool,
+    failed: bool,
+}
+
+impl Component for ModSlug {
+    type Message = Msg;
+    type Properties = ModSlugProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            slug: EditSlug {
+                slug: ctx.props().slug.clone().unwrap_or_default(),
+            },
+            sending: false,
+            failed: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Input(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.slug.slug = target.value().trim().to_lowercase();
+                self.failed = false;
+                true
+            }
+            Msg::Save => {
+                if !self.slug.is_valid() || self.sending {
+                    return false;
+                }
+
+                self.sending = true;
+
+                let props = ctx.props();
+                Self::request_edit(
+                    props.tokens.public_token.clone(),
+                    props.tokens.moderator_token.clone().unwrap_throw(),
+                    self.slug.clone(),
+                    ctx.link(),
+                );
+
+                true
+            }
+            Msg::Saved(ok) => {
+                self.sending = false;
+                self.failed = !ok;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let unchanged = ctx.props().slug.as_ref() == Some(&self.slug.slug);
+        let can_save = self.slug.is_valid() && !self.sending && !unchanged;
+
+        html! {
+            <div class={classes!("mod-slug",self.failed.then_some("invalid"))}>
+                <span class="prefix">{ "/e/" }</span>
+                <input
+                    type="text"
+                    placeholder="rustconf-keynote"
+                    maxlength={SLUG_MAX_LENGTH.to_string()}
+                    value={self.slug.slug.clone()}
+                    oninput={ctx.link().callback(Msg::Input)}
+                />
+                <button class="button-white" disabled={!can_save} onclick={ctx.link().callback(|_| Msg::Save)}>
+                    { tr("mod-slug-save") }
+                </button>
+                {
+                    if self.failed {
+                        html! { <div class="error">{ tr("mod-slug-taken") }</div> }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+impl ModSlug {
+    fn request_edit(id: String, secret: String, slug: EditSlug, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            match fetch::mod_edit_slug(BASE_API, id, secret, slug).await {
+                Err(e) => {
+                    log::error!("mod_edit_slug error: {e}");
+                    Msg::Saved(false)
+                }
+                Ok(_) => Msg::Saved(true),
+            }
+        });
+    }
+}
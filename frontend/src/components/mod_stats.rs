@@ -0,0 +1,109 @@
+use shared::{EventStats, EventTokens};
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+
+use super::StatsChart;
+use crate::{
+    fetch,
+    i18n::{tr, tr_args},
+    pages::BASE_API,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModStatsProps {
+    pub tokens: EventTokens,
+}
+
+pub enum Msg {
+    Refresh,
+    Fetched(Option<EventStats>),
+}
+
+/// statistics of the event over time, only shown to the moderator of premium events
+pub struct ModStats {
+    stats: Option<EventStats>,
+}
+
+impl Component for ModStats {
+    type Message = Msg;
+    type Properties = ModStatsProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link().send_message(Msg::Refresh);
+
+        Self { stats: None }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Refresh => {
+                let tokens = &ctx.props().tokens;
+
+                Self::request_stats(
+                    tokens.public_token.clone(),
+                    tokens.moderator_token.clone().unwrap_throw(),
+                    ctx.link(),
+                );
+
+                false
+            }
+            Msg::Fetched(stats) => {
+                self.stats = stats;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Some(stats) = &self.stats else {
+            return html! {};
+        };
+
+        html! {
+            <div class="mod-stats">
+                <div class="mod-stats-title">{ tr("mod-stats-title") }</div>
+                { Self::view_summary(stats) }
+                <StatsChart title={tr("mod-stats-questions")} buckets={stats.questions.clone()} />
+                <StatsChart title={tr("mod-stats-likes")} buckets={stats.likes.clone()} />
+                <button class="button-red" onclick={ctx.link().callback(|_| Msg::Refresh)}>
+                    { tr("mod-refresh") }
+                </button>
+            </div>
+        }
+    }
+}
+
+impl ModStats {
+    fn view_summary(stats: &EventStats) -> Html {
+        let answered_percent = stats
+            .answered
+            .saturating_mul(100)
+            .checked_div(stats.total)
+            .unwrap_or_default();
+
+        html! {
+            <div class="mod-stats-summary">
+                <div class="figure">
+                    <div class="value">{ stats.peak_viewers }</div>
+                    <div class="label">{ tr("mod-stats-peak-viewers") }</div>
+                </div>
+                <div class="figure">
+                    <div class="value">{ format!("{answered_percent}%") }</div>
+                    <div class="label">{ tr_args("mod-stats-answered", [("answered", stats.answered.into()), ("total", stats.total.into())]) }</div>
+                </div>
+            </div>
+        }
+    }
+
+    fn request_stats(id: String, secret: String, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            match fetch::mod_event_stats(BASE_API, id, secret).await {
+                Err(e) => {
+                    log::error!("mod_event_stats error: {e}");
+                    Msg::Fetched(None)
+                }
+                Ok(stats) => Msg::Fetched(Some(stats)),
+            }
+        });
+    }
+}
@@ -0,0 +1,137 @@
+use chrono::{Local, TimeZone};
+use shared::{EventTokens, QuestionSummary};
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+
+use crate::{
+    fetch,
+    i18n::{tr, tr_args},
+    pages::BASE_API,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModSummaryProps {
+    pub tokens: EventTokens,
+    /// latest summary stored with the event
+    pub summary: Option<QuestionSummary>,
+}
+
+pub enum Msg {
+    Summarize,
+    Summarized(Option<QuestionSummary>),
+}
+
+/// generated overview of the top themes of the questions, refreshed on demand
+pub struct ModSummary {
+    summary: Option<QuestionSummary>,
+    loading: bool,
+    failed: bool,
+}
+
+impl Component for ModSummary {
+    type Message = Msg;
+    type Properties = ModSummaryProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            summary: ctx.props().summary.clone(),
+            loading: false,
+            failed: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Summarize => {
+                if self.loading {
+                    return false;
+                }
+
+                self.loading = true;
+                self.failed = false;
+
+                let tokens = &ctx.props().tokens;
+                let id = tokens.public_token.clone();
+                let secret = tokens.moderator_token.clone().unwrap_throw();
+
+                ctx.link().send_future(async move {
+                    match fetch::mod_question_summary(BASE_API, id, secret).await {
+                        Err(e) => {
+                            log::error!("mod_question_summary error: {e}");
+                            Msg::Summarized(None)
+                        }
+                        Ok(summary) => Msg::Summarized(Some(summary)),
+                    }
+                });
+
+                true
+            }
+            Msg::Summarized(summary) => {
+                self.loading = false;
+                self.failed = summary.is_none();
+                if summary.is_some() {
+                    self.summary = summary;
+                }
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        //Note: another moderator may have summarized in the meantime
+        if ctx.props().summary.is_some() {
+            self.summary = ctx.props().summary.clone();
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="mod-stats mod-summary">
+                <div class="mod-stats-title">{ tr("mod-summary-title") }</div>
+                { self.view_summary() }
+                <div hidden={!self.failed} class="invalid">
+                    { tr("mod-summary-failed") }
+                </div>
+                <button
+                    class="button-red"
+                    disabled={self.loading}
+                    onclick={ctx.link().callback(|_| Msg::Summarize)}
+                >
+                    {
+                        tr(if self.loading {
+                            "mod-summary-loading"
+                        } else if self.summary.is_some() {
+                            "mod-refresh"
+                        } else {
+                            "mod-summary-create"
+                        })
+                    }
+                </button>
+            </div>
+        }
+    }
+}
+
+impl ModSummary {
+    fn view_summary(&self) -> Html {
+        let Some(summary) = &self.summary else {
+            return html! {};
+        };
+
+        let time = Local
+            .timestamp_opt(summary.create_time_unix, 0)
+            .latest()
+            .map(|time| time.format("%H:%M").to_string())
+            .unwrap_or_default();
+
+        html! {
+            <>
+                <div class="text">{ summary.text.clone() }</div>
+                <div class="hint">
+                    { tr_args("mod-summary-based-on", [("count", summary.questions.into()), ("time", time.into())]) }
+                </div>
+            </>
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use easy_cast::ConvFloat;
+use shared::{EventTokens, SurveyResults, SURVEY_RATING_MAX};
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+
+use crate::{
+    fetch,
+    i18n::{tr, tr_args},
+    pages::BASE_API,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModSurveyProps {
+    pub tokens: EventTokens,
+}
+
+pub enum Msg {
+    Refresh,
+    Fetched(Option<SurveyResults>),
+}
+
+/// aggregated feedback survey results, only shown to the moderator
+pub struct ModSurveyResults {
+    results: Option<SurveyResults>,
+}
+
+impl Component for ModSurveyResults {
+    type Message = Msg;
+    type Properties = ModSurveyProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link().send_message(Msg::Refresh);
+
+        Self { results: None }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Refresh => {
+                let tokens = &ctx.props().tokens;
+
+                Self::request_results(
+                    tokens.public_token.clone(),
+                    tokens.moderator_token.clone().unwrap_throw(),
+                    ctx.link(),
+                );
+
+                false
+            }
+            Msg::Fetched(results) => {
+                self.results = results;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Some(results) = &self.results else {
+            return html! {};
+        };
+
+        let count = results.count();
+
+        html! {
+            <div class="survey survey-results">
+                <div class="survey-title">{ tr("mod-survey-title") }</div>
+                { Self::view_summary(results) }
+                { if count > 0 { Self::view_ratings(results) } else { html! {} } }
+                { Self::view_comments(results) }
+                <button class="button-red" onclick={ctx.link().callback(|_| Msg::Refresh)}>
+                    { tr("mod-refresh") }
+                </button>
+            </div>
+        }
+    }
+}
+
+impl ModSurveyResults {
+    fn view_summary(results: &SurveyResults) -> Html {
+        let count = results.count();
+
+        if count == 0 {
+            return html! { <div class="survey-note">{ tr("mod-survey-empty") }</div> };
+        }
+
+        let sum = results
+            .ratings
+            .iter()
+            .zip(1_u32..)
+            .map(|(votes, rating)| votes * rating)
+            .sum::<u32>();
+        let average = f64::from(sum) / f64::from(count);
+
+        html! {
+            <div class="survey-note">
+                { tr_args("mod-survey-average", [
+                    ("average", format!("{average:.1}").into()),
+                    ("max", SURVEY_RATING_MAX.into()),
+                    ("count", count.into()),
+                ]) }
+            </div>
+        }
+    }
+
+    /// highest rating first
+    fn view_ratings(results: &SurveyResults) -> Html {
+        let count = f64::from(results.count());
+
+        results
+            .ratings
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, votes)| {
+                let percent = i32::conv_nearest(f64::from(*votes) * 100_f64 / count);
+
+                html! {
+                    <div class="survey-result">
+                        <div class="bar" style={format!("width: {percent}%")} />
+                        <div class="text">{ "\u{2605}".repeat(index + 1) }</div>
+                        <div class="percent">{ votes.to_string() }</div>
+                    </div>
+                }
+            })
+            .collect::<Html>()
+    }
+
+    fn view_comments(results: &SurveyResults) -> Html {
+        results
+            .comments
+            .iter()
+            .map(|response| {
+                html! {
+                    <div class="survey-comment">
+                        <span class="rating">{ format!("{}\u{2605}", response.rating) }</span>
+                        { response.comment.clone().unwrap_or_default() }
+                    </div>
+                }
+            })
+            .collect::<Html>()
+    }
+
+    fn request_results(id: String, secret: String, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            match fetch::mod_survey_results(BASE_API, id, secret).await {
+                Err(e) => {
+                    log::error!("mod_survey_results error: {e}");
+                    Msg::Fetched(None)
+                }
+                Ok(results) => Msg::Fetched(Some(results)),
+            }
+        });
+    }
+}
@@ -5,7 +5,7 @@ use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
-use crate::{fetch, pages::BASE_API};
+use crate::{fetch, i18n::tr, pages::BASE_API};
 
 pub type SharableTags = Rc<HashMap<TagId, String>>;
 
@@ -114,7 +114,7 @@ impl ModTag {
     fn view_disabled(ctx: &Context<Self>) -> Html {
         html! {
             <button class="button-white" onclick={ctx.link().callback(|_|Msg::EnableInput)}>
-                { "Tag new questions" }
+                { tr("mod-tag-enable") }
             </button>
         }
     }
@@ -126,7 +126,7 @@ impl ModTag {
                 <input
                     ref={self.input.clone()}
                     type="text"
-                    placeholder="tag"
+                    placeholder={tr("mod-tag-placeholder")}
                     maxlength="30"
                     {value}
                     oninput={ctx.link().callback(Msg::InputChange)}
@@ -0,0 +1,102 @@
+use shared::{EventTokens, ModEvent, VIEWER_CAPACITY_MAX};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Eq, PartialEq, Properties)]
+pub struct ViewerCapacityProps {
+    pub tokens: EventTokens,
+    pub capacity: u32,
+}
+
+pub enum Msg {
+    InputChange(Event),
+    Edited(bool),
+}
+
+/// caps the concurrent viewers of a premium event, newcomers wait for a free slot
+pub struct ModViewerCapacity {
+    capacity: u32,
+}
+
+impl Component for ModViewerCapacity {
+    type Message = Msg;
+    type Properties = ViewerCapacityProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            capacity: ctx.props().capacity,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::InputChange(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.capacity = target.value().parse::<u32>().unwrap_or_default();
+
+                if self.capacity <= VIEWER_CAPACITY_MAX {
+                    let props = ctx.props();
+                    Self::request_edit(
+                        props.tokens.public_token.clone(),
+                        props.tokens.moderator_token.clone().unwrap_throw(),
+                        ctx.link(),
+                        self.capacity,
+                    );
+                }
+
+                true
+            }
+            Msg::Edited(_) => true,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.capacity = ctx.props().capacity;
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let invalid = self.capacity > VIEWER_CAPACITY_MAX;
+
+        html! {
+            <div class={classes!("viewer-capacity",invalid.then_some("invalid"))}>
+                { tr("mod-capacity-before") }
+                <input
+                    type="number"
+                    min="0"
+                    max={VIEWER_CAPACITY_MAX.to_string()}
+                    value={self.capacity.to_string()}
+                    onchange={ctx.link().callback(Msg::InputChange)}
+                />
+                { tr("mod-capacity-after") }
+            </div>
+        }
+    }
+}
+
+impl ModViewerCapacity {
+    fn request_edit(id: String, secret: String, link: &html::Scope<Self>, capacity: u32) {
+        link.send_future(async move {
+            match fetch::mod_edit_event(
+                BASE_API,
+                id,
+                secret,
+                ModEvent {
+                    viewer_capacity: Some(capacity),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Err(e) => {
+                    log::error!("mod_edit_event error: {e}");
+                    Msg::Edited(false)
+                }
+                Ok(_) => Msg::Edited(true),
+            }
+        });
+    }
+}
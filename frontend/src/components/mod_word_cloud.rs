@@ -0,0 +1,131 @@
+use shared::{AddWordCloud, EventTokens, WORD_CLOUD_PROMPT_MAX_LENGTH};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, pages::BASE_API};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ModWordCloudProps {
+    pub tokens: EventTokens,
+}
+
+pub enum Msg {
+    Open,
+    Cancel,
+    PromptInput(InputEvent),
+    Submit,
+    Added(bool),
+}
+
+/// moderator form to start a new word cloud
+pub struct ModWordCloudCreate {
+    editing: bool,
+    sending: bool,
+    cloud: AddWordCloud,
+}
+
+impl Component for ModWordCloudCreate {
+    type Message = Msg;
+    type Properties = ModWordCloudProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            editing: false,
+            sending: false,
+            cloud: AddWordCloud::default(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Open => {
+                self.editing = true;
+                true
+            }
+            Msg::Cancel => {
+                self.editing = false;
+                self.cloud = AddWordCloud::default();
+                true
+            }
+            Msg::PromptInput(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.cloud.prompt = target.value();
+                true
+            }
+            Msg::Submit => {
+                if !self.cloud.is_valid() || self.sending {
+                    return false;
+                }
+
+                self.sending = true;
+
+                let props = ctx.props();
+                Self::request_add(
+                    props.tokens.public_token.clone(),
+                    props.tokens.moderator_token.clone().unwrap_throw(),
+                    self.cloud.clone(),
+                    ctx.link(),
+                );
+
+                true
+            }
+            Msg::Added(ok) => {
+                self.sending = false;
+
+                if ok {
+                    self.editing = false;
+                    self.cloud = AddWordCloud::default();
+                }
+
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if !self.editing {
+            return html! {
+                <button class="button-white" onclick={ctx.link().callback(|_| Msg::Open)}>
+                    { tr("mod-word-cloud-open") }
+                </button>
+            };
+        }
+
+        let can_submit = self.cloud.is_valid() && !self.sending;
+
+        html! {
+            <div class="mod-poll">
+                <input
+                    type="text"
+                    placeholder={tr("mod-word-cloud-prompt")}
+                    maxlength={WORD_CLOUD_PROMPT_MAX_LENGTH.to_string()}
+                    value={self.cloud.prompt.clone()}
+                    oninput={ctx.link().callback(Msg::PromptInput)}
+                />
+                <div class="mod-poll-buttons">
+                    <button class="button-white" disabled={!can_submit} onclick={ctx.link().callback(|_| Msg::Submit)}>
+                        { tr("mod-start") }
+                    </button>
+                    <button class="button-white" onclick={ctx.link().callback(|_| Msg::Cancel)}>
+                        { tr("confirm-cancel") }
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ModWordCloudCreate {
+    fn request_add(id: String, secret: String, cloud: AddWordCloud, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            match fetch::mod_add_word_cloud(BASE_API, id, secret, cloud).await {
+                Err(e) => {
+                    log::error!("mod_add_word_cloud error: {e}");
+                    Msg::Added(false)
+                }
+                Ok(_) => Msg::Added(true),
+            }
+        });
+    }
+}
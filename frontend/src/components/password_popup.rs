@@ -1,4 +1,4 @@
-use crate::{components::Popup, fetch, pages::BASE_API};
+use crate::{components::Popup, fetch, i18n::tr, pages::BASE_API};
 use shared::PasswordValidation;
 use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlInputElement;
@@ -105,7 +105,7 @@ impl Component for PasswordPopup {
                                 ref={self.input.clone()}
                                 maxlength="30"
                                 value={self.text.clone()}
-                                placeholder="Enter password"
+                                placeholder={tr("password-placeholder")}
                                 required=true
                                 oninput={ctx.link().callback(Msg::InputChanged)}
                                 onkeydown={ctx.link().callback(Msg::KeyDown)}
@@ -117,7 +117,7 @@ impl Component for PasswordPopup {
                             onclick={on_click_send}
                             disabled={self.errors.has_any() || self.try_again}
                         >
-                            { "Ok" }
+                            { tr("password-confirm") }
                         </button>
                     </div>
                 </Popup>
@@ -133,13 +133,13 @@ impl PasswordPopup {
         if self.try_again {
             html! {
                 <div class="invalid">
-                    <div>{ "try again" }</div>
+                    <div>{ tr("password-try-again") }</div>
                 </div>
             }
         } else if self.errors.content.is_invalid() {
             html! {
                 <div class="invalid">
-                    <div>{ "invalid password" }</div>
+                    <div>{ tr("password-invalid") }</div>
                 </div>
             }
         } else {
@@ -13,6 +13,9 @@ use yew::prelude::*;
 #[derive(Clone, Debug, Eq, PartialEq, Properties)]
 pub struct PaymentProps {
     pub tokens: EventTokens,
+    /// valid promo code to apply at checkout
+    #[prop_or_default]
+    pub promo: Option<String>,
 }
 
 pub enum Msg {
@@ -49,10 +52,12 @@ impl Component for PaymentPopup {
                 if matches!(e, GlobalEvent::PayForUpgrade) {
                     log::info!("open payment popup");
 
-                    let tokens = ctx.props().tokens.clone();
+                    let props = ctx.props();
+                    let tokens = props.tokens.clone();
                     request_upgrade(
                         tokens.public_token.clone(),
                         tokens.moderator_token,
+                        props.promo.clone(),
                         ctx.link(),
                     );
                     self.show = true;
@@ -97,9 +102,14 @@ impl Component for PaymentPopup {
     }
 }
 
-fn request_upgrade(id: String, secret: Option<String>, link: &html::Scope<PaymentPopup>) {
+fn request_upgrade(
+    id: String,
+    secret: Option<String>,
+    promo: Option<String>,
+    link: &html::Scope<PaymentPopup>,
+) {
     link.send_future(async move {
-        match fetch::mod_upgrade(BASE_API, id, secret.unwrap_throw()).await {
+        match fetch::mod_upgrade(BASE_API, id, secret.unwrap_throw(), promo).await {
             Err(e) => {
                 log::error!("request_upgrade error: {e}");
                 Msg::UpgradeRequested(None)
@@ -0,0 +1,193 @@
+use easy_cast::ConvFloat;
+use shared::{EventTokens, ModPoll, Poll};
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+
+use crate::{
+    fetch,
+    i18n::{tr, tr_args},
+    local_cache::LocalCache,
+    pages::BASE_API,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct PollProps {
+    pub tokens: EventTokens,
+    pub poll: Poll,
+}
+
+pub enum Msg {
+    Vote(usize),
+    ToggleClosed,
+    Sent,
+}
+
+/// a single poll: vote buttons until the participant voted, result bars afterwards (or once closed)
+pub struct PollItem {
+    voted: Option<usize>,
+}
+
+impl Component for PollItem {
+    type Message = Msg;
+    type Properties = PollProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            voted: Self::cached_vote(ctx),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Vote(option) => {
+                let props = ctx.props();
+
+                if self.voted.is_some() || props.poll.closed {
+                    return false;
+                }
+
+                LocalCache::set_poll_vote(&props.tokens.public_token, props.poll.id, option);
+                self.voted = Some(option);
+
+                Self::request_vote(
+                    props.tokens.public_token.clone(),
+                    props.poll.id,
+                    option,
+                    ctx.link(),
+                );
+
+                true
+            }
+            Msg::ToggleClosed => {
+                let props = ctx.props();
+
+                Self::request_edit(
+                    props.tokens.public_token.clone(),
+                    props.tokens.moderator_token.clone().unwrap_throw(),
+                    props.poll.id,
+                    ModPoll {
+                        closed: !props.poll.closed,
+                    },
+                    ctx.link(),
+                );
+
+                false
+            }
+            Msg::Sent => false,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.voted = Self::cached_vote(ctx);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let poll = &ctx.props().poll;
+        let is_mod = ctx.props().tokens.is_mod();
+        let show_results = is_mod || poll.closed || self.voted.is_some();
+        let total = poll.total_votes();
+
+        let options = if show_results {
+            self.view_results(poll)
+        } else {
+            Self::view_options(ctx, poll)
+        };
+
+        html! {
+            <div class={classes!("poll",poll.closed.then_some("closed"))}>
+                <div class="poll-question">{ poll.question.clone() }</div>
+                { options }
+                <div class="poll-footer">
+                    { tr_args("poll-votes", [("count", total.into())]) }
+                    { if poll.closed { tr("poll-closed") } else { String::new() } }
+                </div>
+                { Self::mod_view_toggle(ctx, is_mod, poll.closed) }
+            </div>
+        }
+    }
+}
+
+impl PollItem {
+    fn cached_vote(ctx: &Context<Self>) -> Option<usize> {
+        LocalCache::poll_vote(&ctx.props().tokens.public_token, ctx.props().poll.id)
+    }
+
+    fn view_options(ctx: &Context<Self>, poll: &Poll) -> Html {
+        poll.options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                html! {
+                    <button class="poll-option" onclick={ctx.link().callback(move |_| Msg::Vote(index))}>
+                        { option.text.clone() }
+                    </button>
+                }
+            })
+            .collect::<Html>()
+    }
+
+    fn view_results(&self, poll: &Poll) -> Html {
+        let total = poll.total_votes();
+
+        poll.options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                let percent = if total > 0 {
+                    i32::conv_nearest(f64::from(option.votes) * 100_f64 / f64::from(total))
+                } else {
+                    0
+                };
+
+                html! {
+                    <div class={classes!("poll-result",(self.voted == Some(index)).then_some("voted"))}>
+                        <div class="bar" style={format!("width: {percent}%")} />
+                        <div class="text">{ option.text.clone() }</div>
+                        <div class="percent">{ format!("{percent}%") }</div>
+                    </div>
+                }
+            })
+            .collect::<Html>()
+    }
+
+    fn mod_view_toggle(ctx: &Context<Self>, is_mod: bool, closed: bool) -> Html {
+        if !is_mod {
+            return html! {};
+        }
+
+        html! {
+            <button class="button-red" onclick={ctx.link().callback(|_| Msg::ToggleClosed)}>
+                { tr(if closed { "poll-reopen" } else { "poll-close" }) }
+            </button>
+        }
+    }
+
+    fn request_vote(event: String, poll: i64, option: usize, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            if let Err(e) =
+                fetch::vote_poll(BASE_API, event, poll, option, LocalCache::participant()).await
+            {
+                log::error!("vote poll error: {e}");
+            }
+
+            Msg::Sent
+        });
+    }
+
+    fn request_edit(
+        event: String,
+        secret: String,
+        poll: i64,
+        modify: ModPoll,
+        link: &html::Scope<Self>,
+    ) {
+        link.send_future(async move {
+            if let Err(e) = fetch::mod_poll(BASE_API, event, secret, poll, modify).await {
+                log::error!("mod poll error: {e}");
+            }
+
+            Msg::Sent
+        });
+    }
+}
@@ -1,7 +1,13 @@
-use wasm_bindgen::UnwrapThrowExt;
-use web_sys::HtmlElement;
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
 
+/// elements reachable with the tab key, these get the focus trapped inside the dialog
+const FOCUSABLE: &str = concat!(
+    "button:not([disabled]), [href], input:not([disabled]), ",
+    "select, textarea, [tabindex]:not([tabindex='-1'])"
+);
+
 #[derive(Properties, PartialEq)]
 pub struct PopupProps {
     #[prop_or_default]
@@ -10,15 +16,22 @@ pub struct PopupProps {
     pub children: Children,
     #[prop_or_default]
     pub on_close: Callback<()>,
+    /// accessible name of the dialog announced by screen readers
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
 }
 
 pub enum Msg {
     ClickOutside,
     ClickInside,
+    KeyDown(KeyboardEvent),
 }
 
 pub struct Popup {
     body: HtmlElement,
+    dialog: NodeRef,
+    /// focused before the dialog opened, focused again once it closes
+    previous_focus: Option<HtmlElement>,
 }
 
 impl Component for Popup {
@@ -30,7 +43,15 @@ impl Component for Popup {
             .body()
             .expect_throw("no body node found");
 
-        let result = Self { body };
+        let previous_focus = gloo_utils::document()
+            .active_element()
+            .and_then(|e| e.dyn_into::<HtmlElement>().ok());
+
+        let result = Self {
+            body,
+            dialog: NodeRef::default(),
+            previous_focus,
+        };
         result.toggle_modal(true);
         result
     }
@@ -45,11 +66,34 @@ impl Component for Popup {
                 //do nothing
                 false
             }
+            Msg::KeyDown(e) => {
+                match e.key().as_str() {
+                    "Escape" => ctx.props().on_close.emit(()),
+                    "Tab" => self.trap_focus(&e),
+                    _ => (),
+                }
+                false
+            }
+        }
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            let first = self.focusable().into_iter().next();
+            let dialog = self.dialog.cast::<HtmlElement>();
+
+            if let Some(element) = first.or(dialog) {
+                let _ = element.focus();
+            }
         }
     }
 
     fn destroy(&mut self, _ctx: &Context<Self>) {
         self.toggle_modal(false);
+
+        if let Some(element) = self.previous_focus.take() {
+            let _ = element.focus();
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -61,7 +105,10 @@ impl Component for Popup {
         });
 
         let PopupProps {
-            class, children, ..
+            class,
+            children,
+            label,
+            ..
         } = &ctx.props();
 
         let mut c = class.clone();
@@ -69,7 +116,18 @@ impl Component for Popup {
 
         html! {
             <div class="popup-bg" onclick={click_outside}>
-                <div class={c} onclick={click_inside}>{ for children.iter() }</div>
+                <div
+                    class={c}
+                    ref={self.dialog.clone()}
+                    role="dialog"
+                    aria-modal="true"
+                    aria-label={label.clone()}
+                    tabindex="-1"
+                    onclick={click_inside}
+                    onkeydown={ctx.link().callback(Msg::KeyDown)}
+                >
+                    { for children.iter() }
+                </div>
             </div>
         }
     }
@@ -89,4 +147,44 @@ impl Popup {
                 .expect_throw("toggle_modal error 2");
         }
     }
+
+    fn focusable(&self) -> Vec<HtmlElement> {
+        let Some(dialog) = self.dialog.cast::<Element>() else {
+            return Vec::new();
+        };
+
+        let Ok(nodes) = dialog.query_selector_all(FOCUSABLE) else {
+            return Vec::new();
+        };
+
+        (0..nodes.length())
+            .filter_map(|index| nodes.get(index))
+            .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+            .filter(|element| !element.hidden())
+            .collect()
+    }
+
+    /// tabbing past the last element wraps around to the first and vice versa
+    fn trap_focus(&self, e: &KeyboardEvent) {
+        let focusable = self.focusable();
+        let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+            e.prevent_default();
+            return;
+        };
+
+        let active = gloo_utils::document().active_element();
+        let is_active = |element: &HtmlElement| {
+            active
+                .as_ref()
+                .is_some_and(|active| active == element.unchecked_ref::<Element>())
+        };
+
+        if e.shift_key() && is_active(first) {
+            e.prevent_default();
+            let _ = last.focus();
+        } else if !e.shift_key() && is_active(last) {
+            e.prevent_default();
+            let _ = first.focus();
+        }
+    }
 }
@@ -7,8 +7,9 @@ pub struct QrProps {
     pub dimensions: u32,
 }
 
+/// svg qr code generated in the browser
 pub struct Qr {
-    qr_image: String,
+    qr_image: Option<String>,
 }
 
 impl Component for Qr {
@@ -16,27 +17,27 @@ impl Component for Qr {
     type Properties = QrProps;
 
     fn create(ctx: &Context<Self>) -> Self {
-        use qrcode::{render::svg, EcLevel, QrCode, Version};
-
-        let dim = ctx.props().dimensions;
-
-        let code =
-            QrCode::with_version(ctx.props().url.to_string(), Version::Normal(6), EcLevel::M)
-                .unwrap_throw();
+        Self {
+            qr_image: render(ctx.props()),
+        }
+    }
 
-        let qr_image = code
-            .render()
-            .min_dimensions(dim, dim)
-            .dark_color(svg::Color("#000000"))
-            .light_color(svg::Color("#ffffff"))
-            .build();
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props() == old_props {
+            return false;
+        }
 
-        Self { qr_image }
+        self.qr_image = render(ctx.props());
+        true
     }
 
     fn view(&self, _ctx: &Context<Self>) -> Html {
+        let Some(qr_image) = &self.qr_image else {
+            return html! {};
+        };
+
         let div = gloo_utils::document().create_element("div").unwrap_throw();
-        div.set_inner_html(&self.qr_image);
+        div.set_inner_html(qr_image);
         div.class_list().add_1("qrcode").unwrap_throw();
 
         let qr_svg = Html::VRef(div.into());
@@ -44,3 +45,22 @@ impl Component for Qr {
         html! { { qr_svg } }
     }
 }
+
+/// picks the smallest version fitting the url, long custom urls do not fit a fixed one
+fn render(props: &QrProps) -> Option<String> {
+    use qrcode::{render::svg, EcLevel, QrCode};
+
+    let dim = props.dimensions;
+
+    let code = QrCode::with_error_correction_level(props.url.as_bytes(), EcLevel::M)
+        .map_err(|e| log::error!("qr code error: {e}"))
+        .ok()?;
+
+    Some(
+        code.render()
+            .min_dimensions(dim, dim)
+            .dark_color(svg::Color("#000000"))
+            .light_color(svg::Color("#ffffff"))
+            .build(),
+    )
+}
@@ -6,22 +6,42 @@ use bitflags::bitflags;
 use chrono::Utc;
 use gloo_timers::callback::Interval;
 use gloo_timers::callback::Timeout;
-use shared::QuestionItem;
+use shared::{
+    detect_language, QuestionItem, Reaction, Sentiment, ATTACHMENT_KEY_PREFIX,
+    QUESTION_NOTE_MAX_LENGTH,
+};
 use std::rc::Rc;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::UnwrapThrowExt;
 use web_sys::Element;
 use web_sys::HtmlElement;
+use web_sys::HtmlInputElement;
 use web_sys::ScrollBehavior;
 use web_sys::ScrollIntoViewOptions;
 use web_sys::ScrollLogicalPosition;
 use yew::prelude::*;
 
+use crate::{
+    components::{Markdown, Popup},
+    i18n::{self, tr, tr_args},
+    pages::BASE_API,
+};
+
 pub enum QuestionClickType {
     Like,
     Hide,
     Answer,
+    Pin,
     Approve,
+    ShadowBan,
+    /// private moderator note was edited
+    Note(String),
+    /// picked for or dropped from a bulk action
+    BulkSelect,
+    /// show the text in the language of the viewer or back in the original
+    Translate,
+    Downvote,
+    React(Reaction),
 }
 
 bitflags! {
@@ -32,6 +52,11 @@ bitflags! {
         const LOCAL_LIKE = 1 << 2;
         const CAN_VOTE = 1 << 3;
         const BLURR = 1<< 4;
+        const CAN_DOWNVOTE = 1 << 5;
+        const LOCAL_DOWNVOTE = 1 << 6;
+        const SELECTED = 1 << 7;
+        const BULK_SELECTED = 1 << 8;
+        const CAN_TRANSLATE = 1 << 9;
     }
 }
 
@@ -42,6 +67,27 @@ pub struct Props {
     pub flags: QuestionFlags,
     pub on_click: Callback<(i64, QuestionClickType)>,
     pub tag: Option<String>,
+    /// number of questions in the list, announced as the feed size
+    #[prop_or_default]
+    pub set_size: usize,
+    /// reactions given by the local user
+    #[prop_or_default]
+    pub reactions: Vec<Reaction>,
+    /// likes discounted as suspected vote inflation, only known to moderators
+    #[prop_or_default]
+    pub flagged_likes: u32,
+    /// classified tone, only known to moderators while the server classifies questions
+    #[prop_or_default]
+    pub sentiment: Option<Sentiment>,
+    /// text in the language of the viewer, requested via `QuestionClickType::Translate`
+    #[prop_or_default]
+    pub translation: Option<String>,
+    /// event's blocked terms, marked in the text for moderators
+    #[prop_or_default]
+    pub blocked_terms: Vec<String>,
+    /// moderator secret, opens attachments of questions that are not public yet
+    #[prop_or_default]
+    pub secret: Option<String>,
 }
 
 impl Props {
@@ -57,9 +103,24 @@ impl Props {
     const fn local_like(&self) -> bool {
         self.flags.contains(QuestionFlags::LOCAL_LIKE)
     }
+    const fn can_downvote(&self) -> bool {
+        self.flags.contains(QuestionFlags::CAN_DOWNVOTE)
+    }
+    const fn local_downvote(&self) -> bool {
+        self.flags.contains(QuestionFlags::LOCAL_DOWNVOTE)
+    }
+    const fn can_translate(&self) -> bool {
+        self.flags.contains(QuestionFlags::CAN_TRANSLATE)
+    }
     const fn is_new(&self) -> bool {
         self.flags.contains(QuestionFlags::NEW_QUESTION)
     }
+    const fn selected(&self) -> bool {
+        self.flags.contains(QuestionFlags::SELECTED)
+    }
+    const fn bulk_selected(&self) -> bool {
+        self.flags.contains(QuestionFlags::BULK_SELECTED)
+    }
 }
 
 pub struct Question {
@@ -74,6 +135,9 @@ pub struct Question {
     _interval: Interval,
     highlighted: bool,
     wiggle: bool,
+    lightbox: bool,
+    /// selection already scrolled into view
+    scrolled_to_selection: bool,
 }
 
 pub enum AnimationState {
@@ -87,6 +151,8 @@ pub enum Msg {
     ReorderAnimation(AnimationState),
     HighlightEnd,
     WiggleEnd,
+    Lightbox(bool),
+    NoteChanged(Event),
 }
 impl Component for Question {
     type Message = Msg;
@@ -110,6 +176,8 @@ impl Component for Question {
             _interval: interval,
             highlighted: false,
             wiggle: false,
+            lightbox: false,
+            scrolled_to_selection: false,
         };
 
         if res.data.is_new() {
@@ -142,6 +210,16 @@ impl Component for Question {
                 ctx.props().on_click.emit((self.data.item.id, click_type));
                 true
             }
+            Msg::NoteChanged(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                let note = target.value();
+                if self.data.item.note.as_deref().unwrap_or_default() != note.trim() {
+                    ctx.props()
+                        .on_click
+                        .emit((self.data.item.id, QuestionClickType::Note(note)));
+                }
+                false
+            }
             Msg::UpdateAge => {
                 let age = self.get_age();
                 if age != self.age_text {
@@ -184,6 +262,11 @@ impl Component for Question {
                 self.wiggle_animation_timeout = None;
                 true
             }
+
+            Msg::Lightbox(open) => {
+                self.lightbox = open;
+                true
+            }
         }
     }
 
@@ -194,7 +277,7 @@ impl Component for Question {
         } else {
             // log::info!("changed: {}", props.item.id);
 
-            let likes_changed = self.data.item.likes != props.item.likes;
+            let likes_changed = self.data.item.score() != props.item.score();
             if likes_changed {
                 // log::info!(
                 //     "q: {} likes changed (old: {})",
@@ -257,6 +340,16 @@ impl Component for Question {
                     .behavior(ScrollBehavior::Smooth),
             );
         }
+
+        let selected = self.data.selected();
+        if selected && !self.scrolled_to_selection {
+            elem.scroll_into_view_with_scroll_into_view_options(
+                ScrollIntoViewOptions::new()
+                    .block(ScrollLogicalPosition::Nearest)
+                    .behavior(ScrollBehavior::Smooth),
+            );
+        }
+        self.scrolled_to_selection = selected;
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -269,6 +362,7 @@ impl Component for Question {
             "question-host",
             "questions-move",
             self.data.item.screening.then_some("unscreened-question"),
+            ctx.props().selected().then_some("selected"),
         );
 
         let tag = ctx.props().tag.as_ref().map_or_else(
@@ -278,33 +372,63 @@ impl Component for Question {
             },
         );
 
+        let text_id = format!("question-{}", self.data.item.id);
+
         html! {
-            <div class={main_classes} ref={self.node_ref.clone()}>
+            <div
+                class={main_classes}
+                ref={self.node_ref.clone()}
+                role="article"
+                tabindex="0"
+                aria-posinset={ctx.props().index.saturating_add(1).to_string()}
+                aria-setsize={ctx.props().set_size.to_string()}
+                aria-labelledby={text_id.clone()}
+            >
                 <div
                     class={classes!("questionanchor",self.highlighted.then_some("highlighted"),)}
                     onclick={ctx.link().callback(|_| Msg::QuestionClick(QuestionClickType::Like))}
                 >
                     <div class="time-since">{ self.get_age() }</div>
                     { tag }
+                    { self.view_pinned() }
+                    { Self::view_flagged_likes(ctx, mod_view) }
+                    { Self::view_sentiment(ctx, mod_view) }
+                    { self.view_shadowed(mod_view) }
                     { if screened {
                             if liked {
-                                Self::get_bubble_liked(self.data.item.likes,self.wiggle)
+                                Self::get_bubble_liked(self.data.item.score(),self.wiggle)
                             }
                             else
                             {
-                                Self::get_bubble_not_liked(self.data.item.likes,self.wiggle)
+                                Self::get_bubble_not_liked(self.data.item.score(),self.wiggle)
                             }
                         } else { html!() } }
                     <div
+                        id={text_id}
                         class={classes!("text",self.data.item.answered.then_some("answered"),blurred.then_some("blurr"))}
                     >
-                        { &self.data.item.text }
+                        <Markdown
+                            class="markdown"
+                            text={self.data.item.text.clone()}
+                            highlight={if mod_view { ctx.props().blocked_terms.clone() } else { Vec::new() }}
+                        />
                     </div>
-                    { self.view_like(can_vote,liked,mod_view) }
+                    { self.view_translation(ctx, blurred) }
+                    { self.view_name(blurred) }
+                    { self.view_attachment(ctx, blurred) }
+                    { self.view_reactions(ctx, can_vote && !mod_view) }
+                    { self.view_like(can_vote && !ctx.props().local_downvote(),liked,mod_view) }
+                    { self.view_downvote(ctx,can_vote && !liked,mod_view) }
                     { self.view_checkmark(mod_view) }
                 </div>
+                { self.view_lightbox(ctx) }
                 { if mod_view{
-                        self.view_mod(ctx)
+                        html! {
+                            <>
+                                { self.view_mod(ctx) }
+                                { self.view_note(ctx) }
+                            </>
+                        }
                     } else {
                         html!{}
                     } }
@@ -336,55 +460,148 @@ impl Question {
 
         let hidden = self.data.item.hidden;
         let answered = self.data.item.answered;
+        let pinned = self.data.item.pinned;
+        let shadowed = self.data.item.shadowed;
         let screened = !self.data.item.screening;
 
         if screened {
             html! {
                 <div class="options">
+                    { Self::view_bulk_select(ctx) }
                     <button
                         class={classes!("button-hide",hidden.then_some("reverse"))}
                         onclick={ctx.link().callback(|_| Msg::QuestionClick(QuestionClickType::Hide))}
                         hidden={answered}
+                        aria-pressed={hidden.to_string()}
                     >
-                        { if hidden {
-                                html!{"unhide"}
-                            }else{
-                                html!{"hide"}
-                            } }
+                        { tr(if hidden { "question-unhide" } else { "question-hide" }) }
                     </button>
                     <button
                         class={classes!("button-answered",answered.then_some("reverse"))}
                         onclick={ctx.link().callback(|_| Msg::QuestionClick(QuestionClickType::Answer))}
                         hidden={hidden}
+                        aria-pressed={answered.to_string()}
+                    >
+                        { tr(if answered { "question-not-answered" } else { "question-answered" }) }
+                    </button>
+                    <button
+                        class={classes!("button-pin",pinned.then_some("reverse"))}
+                        onclick={ctx.link().callback(|_| Msg::QuestionClick(QuestionClickType::Pin))}
+                        hidden={hidden || answered}
+                        aria-pressed={pinned.to_string()}
+                    >
+                        { tr(if pinned { "question-unpin" } else { "question-pin" }) }
+                    </button>
+                    <button
+                        class={classes!("button-shadow-ban",shadowed.then_some("reverse"))}
+                        onclick={ctx.link().callback(|_| Msg::QuestionClick(QuestionClickType::ShadowBan))}
+                        aria-pressed={shadowed.to_string()}
                     >
-                        { if answered {
-                                html!{"not answered"}
-                            }else{
-                                html!{"answered"}
-                            } }
+                        { tr(if shadowed { "question-shadow-unban" } else { "question-shadow-ban" }) }
                     </button>
                 </div>
             }
         } else {
             html! {
                 <div class="options">
+                    { Self::view_bulk_select(ctx) }
                     <button
                         class={classes!("button-hide",hidden.then_some("reverse"))}
                         onclick={ctx.link().callback(|_| Msg::QuestionClick(QuestionClickType::Hide))}
                     >
-                        { "hide" }
+                        { tr("question-hide") }
                     </button>
                     <button
                         class="button-answered"
                         onclick={ctx.link().callback(|_| Msg::QuestionClick(QuestionClickType::Approve))}
                     >
-                        { "approve" }
+                        { tr("question-approve") }
                     </button>
                 </div>
             }
         }
     }
 
+    fn view_pinned(&self) -> Html {
+        let item = &self.data.item;
+        if !item.pinned || item.answered || item.hidden {
+            return html! {};
+        }
+
+        html! { <div class="pinned">{ tr("question-pinned") }</div> }
+    }
+
+    fn view_bulk_select(ctx: &Context<Self>) -> Html {
+        html! {
+            <input
+                class="bulk-select"
+                type="checkbox"
+                aria-label={tr("question-bulk-select")}
+                checked={ctx.props().bulk_selected()}
+                onchange={ctx.link().callback(|_| Msg::QuestionClick(QuestionClickType::BulkSelect))}
+            />
+        }
+    }
+
+    fn view_note(&self, ctx: &Context<Self>) -> Html {
+        if ctx.props().blurr() {
+            return html! {};
+        }
+
+        html! {
+            <input
+                class="question-note"
+                type="text"
+                maxlength={QUESTION_NOTE_MAX_LENGTH.to_string()}
+                placeholder={tr("question-note-placeholder")}
+                aria-label={tr("question-note")}
+                value={self.data.item.note.clone().unwrap_or_default()}
+                onchange={ctx.link().callback(Msg::NoteChanged)}
+            />
+        }
+    }
+
+    fn view_shadowed(&self, mod_view: bool) -> Html {
+        if !mod_view || !self.data.item.shadowed {
+            return html! {};
+        }
+
+        html! {
+            <div class="shadowed" title={tr("question-shadowed-hint")}>
+                { tr("question-shadowed") }
+            </div>
+        }
+    }
+
+    fn view_flagged_likes(ctx: &Context<Self>, mod_view: bool) -> Html {
+        let count = ctx.props().flagged_likes;
+        if !mod_view || count == 0 {
+            return html! {};
+        }
+
+        html! {
+            <div class="flagged-likes" title={tr("question-flagged-likes-hint")}>
+                { tr_args("question-flagged-likes", [("count", count.into())]) }
+            </div>
+        }
+    }
+
+    fn view_sentiment(ctx: &Context<Self>, mod_view: bool) -> Html {
+        let Some(sentiment) = ctx.props().sentiment.filter(|_| mod_view) else {
+            return html! {};
+        };
+
+        let (class, label) = match sentiment {
+            Sentiment::Positive => ("positive", tr("question-sentiment-positive")),
+            Sentiment::Neutral => ("neutral", tr("question-sentiment-neutral")),
+            Sentiment::Negative => ("negative", tr("question-sentiment-negative")),
+        };
+
+        html! {
+            <div class={classes!("sentiment",class)} title={label.clone()} aria-label={label} />
+        }
+    }
+
     fn get_age(&self) -> String {
         use chrono::TimeZone;
 
@@ -395,19 +612,26 @@ impl Question {
                 .unwrap_throw();
 
         if delta.num_minutes() < 1 {
-            String::from("just now")
+            tr("question-age-now")
         } else if delta.num_hours() < 1 {
-            format!("{} min ago", delta.num_minutes())
+            tr_args(
+                "question-age-minutes",
+                [("count", delta.num_minutes().into())],
+            )
         } else if delta.num_days() < 1 {
-            format!("{} hours ago", delta.num_hours())
+            tr_args("question-age-hours", [("count", delta.num_hours().into())])
         } else {
-            format!("{} days ago", delta.num_days())
+            tr_args("question-age-days", [("count", delta.num_days().into())])
         }
     }
 
     fn get_bubble_liked(likes: i32, wiggle: bool) -> Html {
         html! {
-            <span class={classes!("bubble",wiggle.then_some("wiggle"))}>
+            <span
+                class={classes!("bubble",wiggle.then_some("wiggle"))}
+                role="img"
+                aria-label={tr_args("question-likes", [("count", likes.into())])}
+            >
                 <svg width="29px" height="19px" viewBox="0 0 29 19">
                     <g id="Mobile" stroke="none" stroke-width="1" fill-rule="evenodd">
                         <g
@@ -463,7 +687,11 @@ impl Question {
 
     fn get_bubble_not_liked(likes: i32, wiggle: bool) -> Html {
         html! {
-            <span class={classes!("bubble",wiggle.then_some("wiggle"))}>
+            <span
+                class={classes!("bubble",wiggle.then_some("wiggle"))}
+                role="img"
+                aria-label={tr_args("question-likes", [("count", likes.into())])}
+            >
                 <svg width="29px" height="19px" viewBox="0 0 29 19">
                     <g id="Mobile" stroke="none" stroke-width="1" fill-rule="evenodd">
                         <g
@@ -517,6 +745,177 @@ impl Question {
         }
     }
 
+    fn view_name(&self, blurred: bool) -> Html {
+        self.data.item.name.as_ref().map_or_else(
+            || html! {},
+            |name| {
+                html! {
+                    <div class={classes!("name",blurred.then_some("blurr"))}>
+                        { name.clone() }
+                    </div>
+                }
+            },
+        )
+    }
+
+    fn view_translation(&self, ctx: &Context<Self>, blurred: bool) -> Html {
+        if blurred || !ctx.props().can_translate() {
+            return html! {};
+        }
+
+        let translation = ctx.props().translation.as_ref();
+
+        //Note: no need to offer what already is in the language of the viewer
+        if translation.is_none()
+            && detect_language(&self.data.item.text) == Some(i18n::language().code())
+        {
+            return html! {};
+        }
+
+        let onclick = ctx.link().callback(|e: MouseEvent| {
+            e.stop_propagation();
+            Msg::QuestionClick(QuestionClickType::Translate)
+        });
+
+        html! {
+            <div class="translation">
+                {
+                    translation.map_or_else(
+                        || html! {},
+                        |text| html! { <div class="translated-text">{ text.clone() }</div> },
+                    )
+                }
+                <button
+                    type="button"
+                    class="translate-action"
+                    aria-pressed={translation.is_some().to_string()}
+                    {onclick}
+                >
+                    { tr(if translation.is_some() { "question-translation-original" } else { "question-translate" }) }
+                </button>
+            </div>
+        }
+    }
+
+    fn view_downvote(&self, ctx: &Context<Self>, can_vote: bool, mod_view: bool) -> Html {
+        if !can_vote || !ctx.props().can_downvote() || self.data.item.answered || mod_view {
+            return html! {};
+        }
+
+        let downvoted = ctx.props().local_downvote();
+        let onclick = ctx.link().callback(|e: MouseEvent| {
+            e.stop_propagation();
+            Msg::QuestionClick(QuestionClickType::Downvote)
+        });
+
+        html! {
+            <button
+                type="button"
+                class={classes!("downvote-action",downvoted.then_some("downvoted"))}
+                aria-pressed={downvoted.to_string()}
+                {onclick}
+            >
+                { tr(if downvoted { "question-downvote-undo" } else { "question-downvote" }) }
+            </button>
+        }
+    }
+
+    fn view_reactions(&self, ctx: &Context<Self>, can_react: bool) -> Html {
+        let reactions = self.data.item.reactions;
+
+        if !can_react && reactions.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            <div class="reactions">
+                { for Reaction::ALL.into_iter().map(|reaction| {
+                    let count = reactions.get(reaction);
+                    let reacted = ctx.props().reactions.contains(&reaction);
+                    let onclick = ctx.link().callback(move |e: MouseEvent| {
+                        e.stop_propagation();
+                        Msg::QuestionClick(QuestionClickType::React(reaction))
+                    });
+
+                    html! {
+                        <button
+                            class={classes!("reaction",reacted.then_some("reacted"))}
+                            hidden={!can_react && count == 0}
+                            disabled={!can_react}
+                            aria-pressed={reacted.to_string()}
+                            aria-label={tr_args("question-react", [("reaction", reaction_label(reaction).into()), ("count", count.into())])}
+                            {onclick}
+                        >
+                            { reaction.emoji() }
+                            { if count > 0 { html!{<span class="count">{ count }</span>} } else { html!{} } }
+                        </button>
+                    }
+                }) }
+            </div>
+        }
+    }
+
+    fn view_attachment(&self, ctx: &Context<Self>, blurred: bool) -> Html {
+        let Some(url) = self.attachment_url() else {
+            return html! {};
+        };
+
+        let on_open = ctx.link().callback(|e: MouseEvent| {
+            e.stop_propagation();
+            Msg::Lightbox(true)
+        });
+        let on_key = ctx.link().batch_callback(|e: KeyboardEvent| {
+            matches!(e.key().as_str(), "Enter" | " ").then(|| {
+                e.prevent_default();
+                e.stop_propagation();
+                Msg::Lightbox(true)
+            })
+        });
+
+        html! {
+            <img
+                class={classes!("attachment",blurred.then_some("blurr"))}
+                src={url}
+                alt={tr("question-attachment")}
+                loading="lazy"
+                role="button"
+                tabindex="0"
+                onclick={on_open}
+                onkeydown={on_key}
+            />
+        }
+    }
+
+    fn view_lightbox(&self, ctx: &Context<Self>) -> Html {
+        match self.attachment_url() {
+            Some(url) if self.lightbox => html! {
+                <Popup class="lightbox" label={tr("question-attachment")} on_close={ctx.link().callback(|()| Msg::Lightbox(false))}>
+                    <img src={url} alt={tr("question-attachment")} />
+                </Popup>
+            },
+            _ => html! {},
+        }
+    }
+
+    fn attachment_url(&self) -> Option<String> {
+        let path = self
+            .data
+            .item
+            .attachment
+            .as_deref()?
+            .strip_prefix(ATTACHMENT_KEY_PREFIX)?;
+
+        match &self.data.secret {
+            Some(secret) => {
+                let (event, file) = path.strip_prefix('/')?.split_once('/')?;
+                Some(format!(
+                    "{BASE_API}/api/mod/event/attachment/{event}/{secret}/{file}"
+                ))
+            }
+            None => Some(format!("{BASE_API}/api/event/attachment{path}")),
+        }
+    }
+
     fn view_checkmark(&self, mod_view: bool) -> Html {
         if !mod_view && self.data.item.answered {
             return html! {
@@ -546,14 +945,11 @@ impl Question {
 
     fn view_like(&self, can_like: bool, liked: bool, mod_view: bool) -> Html {
         if can_like && !self.data.item.answered && !mod_view {
+            //Note: the click bubbles up to `questionanchor` which sends the like
             return html! {
-                <div class="like-action">
-                    { if liked {
-                            "Unlike!"
-                        }else{
-                            "I like!"
-                        } }
-                </div>
+                <button type="button" class="like-action" aria-pressed={liked.to_string()}>
+                    { tr(if liked { "question-unlike" } else { "question-like" }) }
+                </button>
             };
         }
 
@@ -581,3 +977,12 @@ impl Question {
         self.node_ref.cast::<Element>()
     }
 }
+
+fn reaction_label(reaction: Reaction) -> String {
+    tr(match reaction {
+        Reaction::ThumbsUp => "reaction-thumbs-up",
+        Reaction::Heart => "reaction-heart",
+        Reaction::Laugh => "reaction-laugh",
+        Reaction::Thinking => "reaction-thinking",
+    })
+}
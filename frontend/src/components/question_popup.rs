@@ -1,28 +1,51 @@
 use crate::{
     components::{Popup, TextArea},
     fetch,
-    local_cache::LocalCache,
+    i18n::{tr, tr_args},
+    local_cache::{LocalCache, PendingAction},
     pages::BASE_API,
-    tracking, GlobalEvent,
+    tracking, GlobalEvent, Toast,
 };
 use events::{event_context, EventBridge};
-use shared::{AddQuestionError, AddQuestionValidation, ValidationState};
+use gloo_timers::callback::Interval;
+use shared::{
+    language_name, AddQuestionError, AddQuestionValidation, NameError, NameValidation,
+    QuestionLength, QuestionNames, ValidationState, ATTACHMENT_CONTENT_TYPES,
+    ATTACHMENT_MAX_SIZE_MB,
+};
 use wasm_bindgen::UnwrapThrowExt;
-use web_sys::HtmlTextAreaElement;
+use web_sys::{File, HtmlInputElement, HtmlTextAreaElement};
 use yew::prelude::*;
 
+/// min seconds between two typing signals sent while writing
+const TYPING_SIGNAL_INTERVAL_SECS: i64 = 3;
+
 pub enum Msg {
     GlobalEvent(GlobalEvent),
     Send,
     QuestionCreated(Option<i64>),
+    /// the event did not take the question, carries the toast explaining why
+    QuestionRejected(&'static str),
+    CooldownTick,
     Close,
     InputChanged(InputEvent),
+    NameChanged(InputEvent),
+    AttachmentChanged(Event),
 }
 
 pub struct QuestionPopup {
     show: bool,
     text: String,
+    name: String,
     errors: AddQuestionValidation,
+    name_errors: NameValidation,
+    attachment: Option<File>,
+    attachment_invalid: bool,
+    last_typing_signal: i64,
+    /// questions asked while offline are queued instead of sent
+    online: bool,
+    /// ticks while the popup shows the remaining question cooldown
+    cooldown_timer: Option<Interval>,
     events: EventBridge<GlobalEvent>,
 }
 
@@ -30,6 +53,20 @@ pub struct QuestionPopup {
 pub struct AddQuestionProps {
     pub event_id: AttrValue,
     pub tag: Option<String>,
+    #[prop_or_default]
+    pub names: QuestionNames,
+    #[prop_or_default]
+    pub limits: QuestionLength,
+    #[prop_or_default]
+    pub attachments: bool,
+    #[prop_or_default]
+    pub session: Option<i64>,
+    /// minutes between two questions of this participant, `0` for no limit
+    #[prop_or_default]
+    pub cooldown_mins: u32,
+    /// language codes questions are expected in, empty for any
+    #[prop_or_default]
+    pub languages: Vec<String>,
 }
 
 impl Component for QuestionPopup {
@@ -45,57 +82,81 @@ impl Component for QuestionPopup {
             show: false,
             events,
             errors: AddQuestionValidation::default(),
+            name_errors: NameValidation::default(),
             text: String::new(),
+            name: String::new(),
+            attachment: None,
+            attachment_invalid: false,
+            last_typing_signal: 0,
+            online: true,
+            cooldown_timer: None,
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
-            Msg::GlobalEvent(e) => {
-                if matches!(e, GlobalEvent::OpenQuestionPopup) {
+            Msg::GlobalEvent(e) => match e {
+                GlobalEvent::OpenQuestionPopup => {
                     tracking::track_event(tracking::EVNT_ASK_OPEN);
+                    self.name_errors
+                        .check(&self.name, ctx.props().names.required());
                     self.show = true;
-                    return true;
+                    self.update_cooldown_timer(ctx);
+                    true
                 }
-                false
-            }
+                GlobalEvent::SocketStatus { connected, .. } => {
+                    let changed = self.online != connected;
+                    self.online = connected;
+                    changed && self.show
+                }
+                _ => false,
+            },
             Msg::Close => {
                 self.show = false;
+                self.cooldown_timer = None;
                 true
             }
-            Msg::Send => {
-                let event_id: String = ctx.props().event_id.to_string();
-                let text = self.text.clone();
-
-                tracking::track_event(tracking::EVNT_ASK_SENT);
-
-                ctx.link().send_future(async move {
-                    if let Ok(item) = fetch::add_question(BASE_API, event_id.clone(), text).await {
-                        LocalCache::set_like_state(&event_id, item.id, true);
-                        if item.screening {
-                            LocalCache::add_unscreened_question(&event_id, &item);
-                        }
-                        Msg::QuestionCreated(Some(item.id))
-                    } else {
-                        Msg::QuestionCreated(None)
-                    }
-                });
-
-                self.show = false;
-                self.text.clear();
-
-                true
-            }
+            Msg::Send => self.send(ctx),
             Msg::QuestionCreated(id) => {
                 if let Some(id) = id {
                     self.events.emit(GlobalEvent::QuestionCreated(id));
+                    self.events
+                        .emit(GlobalEvent::Toast(Toast::info("toast-question-sent")));
+                } else {
+                    self.events
+                        .emit(GlobalEvent::Toast(Toast::error("toast-question-failed")));
                 }
                 true
             }
+            Msg::QuestionRejected(message) => {
+                self.events.emit(GlobalEvent::Toast(Toast::error(message)));
+                false
+            }
+            Msg::CooldownTick => {
+                self.update_cooldown_timer(ctx);
+                true
+            }
             Msg::InputChanged(ev) => {
                 let target: HtmlTextAreaElement = ev.target_dyn_into().unwrap_throw();
                 self.text = target.value();
-                self.errors.check(&self.text);
+                self.errors.check(&self.text, ctx.props().limits);
+                self.signal_typing();
+                true
+            }
+            Msg::NameChanged(ev) => {
+                let target: HtmlInputElement = ev.target_dyn_into().unwrap_throw();
+                self.name = target.value();
+                self.name_errors
+                    .check(&self.name, ctx.props().names.required());
+                true
+            }
+            Msg::AttachmentChanged(ev) => {
+                let target: HtmlInputElement = ev.target_dyn_into().unwrap_throw();
+                self.attachment = target.files().and_then(|files| files.get(0));
+                self.attachment_invalid = self
+                    .attachment
+                    .as_ref()
+                    .is_some_and(|file| !fetch::attachment_request(file).is_valid());
                 true
             }
         }
@@ -112,30 +173,40 @@ impl Component for QuestionPopup {
                 |tag| {
                     html! {
                         <div class="tag">
-                            { "current tag:" }
+                            { tr("question-popup-tag") }
                             <div class="name">{ tag.clone() }</div>
                         </div>
                     }
                 },
             );
 
+            let names = ctx.props().names;
+            let max_len = ctx.props().limits.max;
+            let offline_attachment = !self.online && self.attachment.is_some();
+            let cooldown = Self::cooldown_left(ctx);
+            let has_errors = cooldown > 0
+                || self.errors.has_any()
+                || (names.allowed() && self.name_errors.has_any())
+                || self.attachment_invalid
+                || offline_attachment;
+
             html! {
-                <Popup class="share-popup" {on_close}>
+                <Popup class="share-popup" label={tr("question-popup-title")} {on_close}>
                     <div class="newquestion">
                         <div class="add-question">
                             <TextArea
                                 id="questiontext"
                                 name="questiontext"
-                                maxlength="200"
+                                maxlength={max_len.to_string()}
                                 value={self.text.clone()}
-                                placeholder="What’s your question?"
+                                placeholder={tr("question-popup-placeholder")}
                                 required=true
                                 oninput={ctx.link().callback(Msg::InputChanged)}
                                 autosize=true
                             />
                             <div class="more-info">
                                 <div class="chars-info">
-                                    <code>{ format!("{}",200 - self.text.len()) }</code>
+                                    <code>{ format!("{}",max_len.saturating_sub(self.text.len())) }</code>
                                 </div>
                                 { html!{
                                 <div hidden={!self.errors.has_any()} class="invalid">
@@ -146,13 +217,25 @@ impl Component for QuestionPopup {
                             } }
                             </div>
                             { tag }
+                            { self.view_name(ctx) }
+                            { self.view_attachment(ctx) }
+                            <div hidden={!offline_attachment} class="invalid">
+                                { tr("question-popup-offline-attachment") }
+                            </div>
+                            <div hidden={self.online || offline_attachment} class="offline-hint">
+                                { tr("question-popup-offline") }
+                            </div>
+                            { Self::view_languages(ctx) }
+                            <div hidden={cooldown == 0} class="cooldown-hint" aria-live="polite">
+                                { tr_args("question-popup-cooldown", [("countdown", format!("{}:{:02}", cooldown.div_euclid(60), cooldown % 60).into())]) }
+                            </div>
                         </div>
                         <button
                             class="dlg-button"
                             onclick={on_click_ask}
-                            disabled={self.errors.has_any()}
+                            disabled={has_errors}
                         >
-                            { "Ask!" }
+                            { tr("question-popup-send") }
                         </button>
                     </div>
                 </Popup>
@@ -164,20 +247,229 @@ impl Component for QuestionPopup {
 }
 
 impl QuestionPopup {
+    fn send(&mut self, ctx: &Context<Self>) -> bool {
+        let event_id: String = ctx.props().event_id.to_string();
+        let text = self.text.clone();
+        let name = (ctx.props().names.allowed() && !self.name.trim().is_empty())
+            .then(|| self.name.trim().to_string());
+        let attachment = self.attachment.take();
+        let session = ctx.props().session;
+
+        tracking::track_event(tracking::EVNT_ASK_SENT);
+
+        //Note: attachments cannot be queued, asking is disabled offline with one selected
+        if !self.online && attachment.is_none() {
+            LocalCache::queue_action(
+                &event_id,
+                PendingAction::Question {
+                    text,
+                    name,
+                    session,
+                },
+            );
+            self.events.emit(GlobalEvent::ActionQueued);
+            self.events
+                .emit(GlobalEvent::Toast(Toast::info("toast-question-queued")));
+
+            self.show = false;
+            self.text.clear();
+
+            return true;
+        }
+
+        ctx.link().send_future(async move {
+            let attachment = match attachment {
+                Some(file) => match Self::upload(event_id.clone(), &file).await {
+                    Ok(key) => Some(key),
+                    Err(e) => {
+                        log::error!("attachment upload error: {e}");
+                        return Msg::QuestionCreated(None);
+                    }
+                },
+                None => None,
+            };
+
+            match fetch::add_question(
+                BASE_API,
+                event_id.clone(),
+                text,
+                name,
+                attachment,
+                session,
+                LocalCache::participant(),
+            )
+            .await
+            {
+                Ok(item) => {
+                    LocalCache::set_like_state(&event_id, item.id, true);
+                    LocalCache::set_last_question(&event_id, item.create_time_unix);
+                    if item.screening {
+                        LocalCache::add_unscreened_question(&event_id, &item);
+                    }
+                    Msg::QuestionCreated(Some(item.id))
+                }
+                Err(fetch::FetchError::Api(e)) if e.status == 429 && e.retry_after.is_some() => {
+                    Msg::QuestionRejected("toast-question-rate-limit")
+                }
+                Err(fetch::FetchError::Api(e)) if e.status == 429 => {
+                    Msg::QuestionRejected("toast-question-cooldown")
+                }
+                Err(fetch::FetchError::Api(e)) if e.status == 403 => {
+                    Msg::QuestionRejected("toast-question-limit")
+                }
+                Err(fetch::FetchError::Api(e)) if e.status == 422 => {
+                    Msg::QuestionRejected("toast-question-language")
+                }
+                Err(_) => Msg::QuestionCreated(None),
+            }
+        });
+
+        self.show = false;
+        self.text.clear();
+
+        true
+    }
+
+    fn view_languages(ctx: &Context<Self>) -> Html {
+        let languages = &ctx.props().languages;
+
+        if languages.is_empty() {
+            return html! {};
+        }
+
+        let names = languages
+            .iter()
+            .filter_map(|code| language_name(code))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        html! {
+            <div class="languages-hint">
+                { tr_args("question-popup-languages", [("languages", names.into())]) }
+            </div>
+        }
+    }
+
+    /// seconds until this participant can ask again
+    fn cooldown_left(ctx: &Context<Self>) -> i64 {
+        let cooldown = i64::from(ctx.props().cooldown_mins) * 60;
+        if cooldown == 0 {
+            return 0;
+        }
+
+        LocalCache::last_question(&ctx.props().event_id).map_or(0, |last| {
+            (last + cooldown - chrono::Utc::now().timestamp()).max(0)
+        })
+    }
+
+    fn update_cooldown_timer(&mut self, ctx: &Context<Self>) {
+        if Self::cooldown_left(ctx) == 0 {
+            self.cooldown_timer = None;
+        } else if self.cooldown_timer.is_none() {
+            let link = ctx.link().clone();
+            self.cooldown_timer = Some(Interval::new(1000, move || {
+                link.send_message(Msg::CooldownTick);
+            }));
+        }
+    }
+
+    fn signal_typing(&mut self) {
+        let now = chrono::Utc::now().timestamp();
+
+        if now - self.last_typing_signal >= TYPING_SIGNAL_INTERVAL_SECS {
+            self.last_typing_signal = now;
+            self.events.emit(GlobalEvent::QuestionTyping);
+        }
+    }
+
+    async fn upload(event_id: String, file: &File) -> Result<String, fetch::FetchError> {
+        let upload = fetch::request_attachment(BASE_API, event_id, file).await?;
+        fetch::upload_attachment(&upload.upload_url, file).await?;
+        Ok(upload.key)
+    }
+
+    fn view_attachment(&self, ctx: &Context<Self>) -> Html {
+        if !ctx.props().attachments {
+            return html! {};
+        }
+
+        html! {
+            <div class="question-attachment">
+                <input
+                    id="questionattachment"
+                    type="file"
+                    accept={ATTACHMENT_CONTENT_TYPES.join(",")}
+                    onchange={ctx.link().callback(Msg::AttachmentChanged)}
+                />
+                <div hidden={!self.attachment_invalid} class="invalid">
+                    { tr_args("question-popup-attachment-size", [("size", ATTACHMENT_MAX_SIZE_MB.into())]) }
+                </div>
+            </div>
+        }
+    }
+
+    fn view_name(&self, ctx: &Context<Self>) -> Html {
+        let names = ctx.props().names;
+
+        if !names.allowed() {
+            return html! {};
+        }
+
+        let placeholder = tr(if names.required() {
+            "question-popup-name"
+        } else {
+            "question-popup-name-optional"
+        });
+
+        html! {
+            <div class="question-name">
+                <input
+                    id="questionname"
+                    type="text"
+                    maxlength="30"
+                    value={self.name.clone()}
+                    {placeholder}
+                    oninput={ctx.link().callback(Msg::NameChanged)}
+                />
+                <div hidden={!self.name_errors.content.is_invalid()} class="invalid">
+                    { self.name_error_text().unwrap_or_default() }
+                </div>
+            </div>
+        }
+    }
+
+    fn name_error_text(&self) -> Option<String> {
+        match self.name_errors.content {
+            ValidationState::Invalid(NameError::Required) => {
+                Some(tr("question-popup-name-required"))
+            }
+            ValidationState::Invalid(NameError::MinLength(_, min)) => Some(tr_args(
+                "question-popup-name-too-short",
+                [("min", min.into())],
+            )),
+            ValidationState::Invalid(NameError::MaxLength(_, max)) => Some(tr_args(
+                "question-popup-name-too-long",
+                [("max", max.into())],
+            )),
+            _ => None,
+        }
+    }
+
     fn error_text(&self) -> Option<String> {
         match self.errors.content {
             ValidationState::Invalid(AddQuestionError::MinLength(_, _)) => {
-                Some("Question too short.".to_string())
+                Some(tr("question-popup-too-short"))
             }
             ValidationState::Invalid(AddQuestionError::MaxLength(_, max)) => {
-                Some(format!("Question too long. Max: {max})"))
+                Some(tr_args("question-popup-too-long", [("max", max.into())]))
             }
             ValidationState::Invalid(AddQuestionError::MinWordCount(_, min)) => {
-                Some(format!("Minimum words required: {min}."))
-            }
-            ValidationState::Invalid(AddQuestionError::WordLengthMax(max)) => {
-                Some(format!("No word can be longer than: {max}."))
+                Some(tr_args("question-popup-min-words", [("min", min.into())]))
             }
+            ValidationState::Invalid(AddQuestionError::WordLengthMax(max)) => Some(tr_args(
+                "question-popup-word-too-long",
+                [("max", max.into())],
+            )),
             _ => None,
         }
     }
@@ -0,0 +1,70 @@
+use events::use_events;
+use js_sys::Reflect;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{DomException, ShareData};
+use yew::prelude::*;
+
+use crate::{clipboard, i18n::tr, tracking, GlobalEvent, Toast};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ShareButtonProps {
+    pub url: AttrValue,
+    pub title: AttrValue,
+}
+
+/// opens the share sheet of the device, copies the link where there is none
+#[function_component(ShareButton)]
+pub fn share_button(props: &ShareButtonProps) -> Html {
+    let events = use_events::<GlobalEvent>();
+
+    let onclick = {
+        let ShareButtonProps { url, title } = props.clone();
+        Callback::from(move |_| {
+            tracking::track_event(tracking::EVNT_SHARE_OPEN);
+
+            let (url, title, events) = (url.clone(), title.clone(), events.clone());
+            spawn_local(async move {
+                if share(&url, &title).await {
+                    return;
+                }
+
+                let copied = clipboard::copy(&url).await;
+
+                if let Some(events) = events {
+                    events.emit(GlobalEvent::Toast(if copied {
+                        Toast::info("toast-link-copied")
+                    } else {
+                        Toast::error("toast-copy-failed")
+                    }));
+                }
+            });
+        })
+    };
+
+    html! {
+        <button class="share-button button-white" {onclick}>
+            { tr("event-share") }
+        </button>
+    }
+}
+
+/// `false` if the device has no share sheet or sharing failed, a dismissed sheet counts as shared
+async fn share(url: &str, title: &str) -> bool {
+    let navigator = gloo_utils::window().navigator();
+
+    //Note: `navigator.share` only exists on supporting browsers and in secure contexts
+    if !Reflect::has(&navigator, &JsValue::from_str("share")).unwrap_or_default() {
+        return false;
+    }
+
+    let mut data = ShareData::new();
+    data.title(title).url(url);
+
+    match JsFuture::from(navigator.share_with_data(&data)).await {
+        Ok(_) => true,
+        Err(e) => e
+            .dyn_into::<DomException>()
+            .is_ok_and(|e| e.name() == "AbortError"),
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{components::Popup, components::Qr, routes::Route, tracking, GlobalEvent};
+use crate::{
+    clipboard, components::Popup, components::Qr, i18n::tr, routes::Route, tracking, GlobalEvent,
+    Toast,
+};
 use events::{event_context, EventBridge};
 use wasm_bindgen::UnwrapThrowExt;
 
@@ -9,6 +12,10 @@ use yew_router::scope_ext::RouterScopeExt;
 pub struct ShareProps {
     pub url: AttrValue,
     pub event_id: AttrValue,
+    /// moderators get a print version that can include hidden questions
+    /// and a big screen they can advance through the questions from
+    #[prop_or_default]
+    pub secret: Option<AttrValue>,
 }
 
 #[derive(Debug)]
@@ -23,15 +30,17 @@ pub enum Msg {
     GlobalEvent(GlobalEvent),
     Close,
     Copy,
+    Copied(bool),
     Share(ShareLink),
     OpenPrint,
+    OpenScreen,
 }
 
 pub struct SharePopup {
     show: bool,
     copied_to_clipboard: bool,
     url: String,
-    _events: EventBridge<GlobalEvent>,
+    events: EventBridge<GlobalEvent>,
 }
 
 impl Component for SharePopup {
@@ -47,7 +56,7 @@ impl Component for SharePopup {
             url: ctx.props().url.to_string(),
             show: false,
             copied_to_clipboard: false,
-            _events: events,
+            events,
         }
     }
 
@@ -68,17 +77,46 @@ impl Component for SharePopup {
             Msg::OpenPrint => {
                 self.show = false;
 
-                ctx.link().navigator().unwrap_throw().push(&Route::Print {
-                    id: ctx.props().event_id.to_string(),
-                });
+                let id = ctx.props().event_id.to_string();
+                let route = ctx.props().secret.as_ref().map_or_else(
+                    || Route::Print { id: id.clone() },
+                    |secret| Route::PrintMod {
+                        id: id.clone(),
+                        secret: secret.to_string(),
+                    },
+                );
+
+                ctx.link().navigator().unwrap_throw().push(&route);
+                true
+            }
+            Msg::OpenScreen => {
+                self.show = false;
+
+                let id = ctx.props().event_id.to_string();
+                let route = ctx.props().secret.as_ref().map_or_else(
+                    || Route::Screen { id: id.clone() },
+                    |secret| Route::ScreenMod {
+                        id: id.clone(),
+                        secret: secret.to_string(),
+                    },
+                );
+
+                ctx.link().navigator().unwrap_throw().push(&route);
                 true
             }
             Msg::Copy => {
-                self.copied_to_clipboard = true;
-                gloo_utils::window()
-                    .navigator()
-                    .clipboard()
-                    .map(|c| c.write_text(&self.url));
+                let url = self.url.clone();
+                ctx.link()
+                    .send_future(async move { Msg::Copied(clipboard::copy(&url).await) });
+                false
+            }
+            Msg::Copied(success) => {
+                self.copied_to_clipboard = success;
+                self.events.emit(GlobalEvent::Toast(if success {
+                    Toast::info("toast-link-copied")
+                } else {
+                    Toast::error("toast-copy-failed")
+                }));
                 true
             }
             Msg::Share(share) => {
@@ -126,14 +164,15 @@ impl Component for SharePopup {
             let on_click_share_whatsapp = ctx.link().callback(|_| Msg::Share(ShareLink::Whatsapp));
             let on_click_share_sms = ctx.link().callback(|_| Msg::Share(ShareLink::Sms));
             let on_click_print = ctx.link().callback(|_| Msg::OpenPrint);
+            let on_click_screen = ctx.link().callback(|_| Msg::OpenScreen);
 
             html! {
-                <Popup class="share-popup" {on_close}>
-                    <div class="title">{ "Share this Link" }</div>
+                <Popup class="share-popup" label={tr("share-title")} {on_close}>
+                    <div class="title">{ tr("share-title") }</div>
                     <div class="link-box" onclick={on_click_copy}>
                         <div class="link">{ self.url.clone() }</div>
                         <div class="copy">
-                            { if self.copied_to_clipboard {"Copied"} else {"Copy"} }
+                            { if self.copied_to_clipboard { tr("copy-done") } else { tr("copy-link") } }
                         </div>
                     </div>
                     <div class="sharebuttons">
@@ -153,7 +192,8 @@ impl Component for SharePopup {
                     <div class="qr">
                         <Qr url={self.url.clone()} dimensions=100 />
                     </div>
-                    <div class="print" onclick={on_click_print}>{ "Show print version" }</div>
+                    <div class="print" onclick={on_click_print}>{ tr("share-print") }</div>
+                    <div class="print screen" onclick={on_click_screen}>{ tr("share-screen") }</div>
                 </Popup>
             }
         } else {
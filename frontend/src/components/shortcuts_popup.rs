@@ -0,0 +1,64 @@
+use yew::prelude::*;
+
+use crate::{components::Popup, i18n::tr};
+
+/// keys handled by the moderator view of the event page, see `Event::on_shortcut`
+const SHORTCUTS: &[(&str, &str)] = &[
+    ("j", "shortcuts-next"),
+    ("k", "shortcuts-previous"),
+    ("a", "shortcuts-answer"),
+    ("h", "shortcuts-hide"),
+    ("p", "shortcuts-pin"),
+    ("/", "shortcuts-search"),
+    ("?", "shortcuts-help"),
+    ("Esc", "shortcuts-escape"),
+];
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ShortcutsPopupProps {
+    pub show: bool,
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+}
+
+pub struct ShortcutsPopup;
+
+impl Component for ShortcutsPopup {
+    type Message = ();
+    type Properties = ShortcutsPopupProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if !ctx.props().show {
+            return html! {};
+        }
+
+        let title = tr("shortcuts-title");
+
+        html! {
+            <Popup
+                class="shortcuts-popup"
+                label={AttrValue::from(title.clone())}
+                on_close={ctx.props().on_close.clone()}
+            >
+                <div class="title">{ title }</div>
+                <dl class="shortcuts">
+                    {
+                        for SHORTCUTS.iter().map(|(key, id)| html! {
+                            <>
+                                <dt><kbd>{ *key }</kbd></dt>
+                                <dd>{ tr(id) }</dd>
+                            </>
+                        })
+                    }
+                </dl>
+                <button class="button-white" onclick={ctx.props().on_close.reform(|_| ())}>
+                    { tr("shortcuts-close") }
+                </button>
+            </Popup>
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use yew::prelude::*;
+
+use crate::i18n::tr;
+
+/// placeholder cards shown while the questions load
+const SKELETON_QUESTIONS: usize = 3;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct EventSkeletonProps {
+    #[prop_or_default]
+    pub moderator: bool,
+}
+
+/// stands in for the event header and question cards until the event is fetched
+pub struct EventSkeleton;
+
+impl Component for EventSkeleton {
+    type Message = ();
+    type Properties = EventSkeletonProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let background = if ctx.props().moderator {
+            "bg-mod"
+        } else {
+            "bg-event"
+        };
+
+        html! {
+            <div class="some-event event-skeleton" aria-busy="true">
+                <div class={background} />
+                <span class="sr-only" role="status">{ tr("event-loading") }</span>
+                <div class="event-block" aria-hidden="true">
+                    <div class="skeleton-line label" />
+                    <div class="skeleton-line name" />
+                    <div class="skeleton-line desc" />
+                    <div class="skeleton-line desc short" />
+                </div>
+                <div class="questions" aria-hidden="true">
+                    { for (0..SKELETON_QUESTIONS).map(|_| html! {
+                        <div class="question-host skeleton-question">
+                            <div class="skeleton-line text" />
+                            <div class="skeleton-line text short" />
+                        </div>
+                    }) }
+                </div>
+            </div>
+        }
+    }
+}
@@ -1,15 +1,62 @@
-use chrono::Duration;
-use gloo_timers::callback::Interval;
-use wasm_bindgen::UnwrapThrowExt;
-use web_sys::CloseEvent;
+use chrono::{Duration, Utc};
+use easy_cast::ConvFloat;
+use events::{event_context, EventBridge};
+use gloo_timers::callback::{Interval, Timeout};
+use serde::{Deserialize, Serialize};
+use shared::{WsClientMessage, WsMessage};
+use wasm_bindgen::{closure::Closure, JsCast, UnwrapThrowExt};
+use web_sys::{BroadcastChannel, CloseEvent, MessageEvent};
 use yew::{html::Scope, prelude::*};
 
+use crate::GlobalEvent;
+
+/// server answers every ping, a socket silent for longer than this is considered dead
+const SILENT_TIMEOUT_SECS: i64 = 10;
+/// first reconnect happens after this, doubling with every failed attempt
+const RECONNECT_BASE_SECS: i64 = 1;
+const RECONNECT_MAX_SECS: i64 = 30;
+/// random delay added to every reconnect so clients do not all hit the server at once
+const RECONNECT_JITTER_MS: f64 = 1000.0;
+/// time a new tab waits for a tab already holding the socket to answer
+const ELECTION_BASE_MS: u32 = 300;
+/// random delay added to an election so tabs losing their leader do not all take over
+const ELECTION_JITTER_MS: f64 = 300.0;
+
+/// sent between the tabs showing the same socket url so only one of them holds the socket
+#[derive(Serialize, Deserialize, Debug, Clone)]
+enum TabMessage {
+    /// a tab looks for the leader
+    Hello,
+    /// heartbeat of the tab holding the socket
+    Leader {
+        id: String,
+        connected: bool,
+    },
+    /// the leader closes
+    Bye,
+    Text(String),
+    Binary(Vec<u8>),
+    /// a follower asks the leader to signal typing
+    Typing,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Role {
+    /// waiting for a leader to answer
+    Electing,
+    /// holds the socket and forwards everything it receives
+    Leader,
+    /// gets the socket messages from the leader
+    Follower,
+}
+
 #[derive(Clone, Debug)]
 pub enum SocketResponse {
     Connecting,
     Connected,
     Disconnected { reconnect: Option<Duration> },
     Message(String),
+    Binary(Vec<u8>),
 }
 
 #[derive(Clone, Debug, PartialEq, Properties)]
@@ -22,18 +69,32 @@ pub struct SocketProperties {
 pub enum Msg {
     Ping,
     MessageReceived(String),
+    BinaryReceived(Vec<u8>),
     Connected,
     Disconnected,
     Reconnect,
+    GlobalEvent(GlobalEvent),
+    Tab(String),
+    ElectionTimeout,
 }
 
 pub struct EventSocket {
     link: Scope<Self>,
     properties: SocketProperties,
     connected: bool,
+    last_received: i64,
     ws: Option<wasm_sockets::EventClient>,
-    reconnect_interval: Option<(Duration, Interval)>,
+    reconnect_timeout: Option<(Duration, Timeout)>,
+    reconnect_attempt: u32,
+    /// shares the socket with other tabs, `None` where `BroadcastChannel` is unsupported
+    channel: Option<(BroadcastChannel, Closure<dyn Fn(MessageEvent)>)>,
+    tab_id: String,
+    role: Role,
+    election: Option<Timeout>,
+    last_leader_seen: i64,
+    leader_connected: bool,
     _ping_interval: Interval,
+    _events: EventBridge<GlobalEvent>,
 }
 impl Component for EventSocket {
     type Message = Msg;
@@ -45,16 +106,33 @@ impl Component for EventSocket {
             Interval::new(3000, move || link.send_message(Msg::Ping))
         };
 
+        let events = event_context(ctx)
+            .unwrap_throw()
+            .subscribe(ctx.link().callback(Msg::GlobalEvent));
+
         let mut new_self = Self {
             link: ctx.link().clone(),
             properties: ctx.props().clone(),
             connected: false,
+            last_received: 0,
             ws: None,
-            reconnect_interval: None,
+            reconnect_timeout: None,
+            reconnect_attempt: 0,
+            channel: open_channel(&ctx.props().url, ctx.link()),
+            tab_id: js_sys::Math::random().to_string(),
+            role: Role::Electing,
+            election: None,
+            last_leader_seen: 0,
+            leader_connected: false,
             _ping_interval: ping_interval,
+            _events: events,
         };
 
-        new_self.connect();
+        if new_self.channel.is_some() {
+            new_self.elect();
+        } else {
+            new_self.lead();
+        }
 
         new_self
     }
@@ -63,40 +141,89 @@ impl Component for EventSocket {
         match msg {
             Msg::Ping => {
                 // log::info!("<EventSocket> update:ping");
+                match self.role {
+                    Role::Leader => self.broadcast_leader(),
+                    Role::Follower => {
+                        if Utc::now().timestamp() - self.last_leader_seen > SILENT_TIMEOUT_SECS {
+                            log::warn!("<EventSocket> leader tab silent, electing a new one");
+                            self.elect();
+                        }
+                    }
+                    Role::Electing => {}
+                }
+
                 if self.connected {
-                    self.ws.as_ref().map(|ws| ws.send_string("p"));
+                    if Utc::now().timestamp() - self.last_received > SILENT_TIMEOUT_SECS {
+                        log::warn!("<EventSocket> no response from server, reconnecting");
+                        self.link.send_message(Msg::Disconnected);
+                    } else {
+                        self.send(WsClientMessage::Ping);
+                    }
                 }
             }
+            Msg::GlobalEvent(GlobalEvent::QuestionTyping) => {
+                if self.connected {
+                    self.send(WsClientMessage::Typing);
+                } else if matches!(self.role, Role::Follower) {
+                    self.broadcast(&TabMessage::Typing);
+                }
+            }
+            Msg::GlobalEvent(_) => {}
             Msg::MessageReceived(msg) => {
                 // log::info!("<EventSocket> update:msg");
-                self.emit(SocketResponse::Message(msg));
+                self.last_received = Utc::now().timestamp();
+
+                //Note: pong is just the answer to our ping
+                if WsMessage::decode(&msg) != Some(WsMessage::Pong) {
+                    self.broadcast(&TabMessage::Text(msg.clone()));
+                    self.emit(SocketResponse::Message(msg));
+                }
+            }
+            Msg::BinaryReceived(data) => {
+                self.last_received = Utc::now().timestamp();
+                self.broadcast(&TabMessage::Binary(data.clone()));
+                self.emit(SocketResponse::Binary(data));
             }
             Msg::Connected => {
                 // log::info!("<EventSocket> update:connected");
                 self.connected = true;
-                self.reconnect_interval = None;
+                self.last_received = Utc::now().timestamp();
+                self.reconnect_timeout = None;
+                self.reconnect_attempt = 0;
+                self.broadcast_leader();
                 self.emit(SocketResponse::Connected);
             }
             Msg::Disconnected => {
                 // log::info!("<EventSocket> update:disconnected");
-                let do_reconnect = self.connected;
+                //Note: late close event of a socket we already dropped ourselves
+                if self.ws.is_none() {
+                    return false;
+                }
 
                 self.disconnect();
+                self.broadcast_leader();
 
-                if do_reconnect {
-                    let duration = self.set_reconnect();
-                    self.emit(SocketResponse::Disconnected {
-                        reconnect: Some(duration),
-                    });
-                } else {
-                    self.emit(SocketResponse::Disconnected { reconnect: None });
-                }
+                //Note: a first connect that fails is retried as well,
+                // the page counts the failures to fall back to long polling
+                let duration = self.set_reconnect();
+                self.emit(SocketResponse::Disconnected {
+                    reconnect: Some(duration),
+                });
             }
             Msg::Reconnect => {
-                if self.reconnect_interval.is_some() && !self.connected {
+                if self.reconnect_timeout.is_some() && !self.connected {
                     self.connect();
                 }
             }
+            Msg::ElectionTimeout => {
+                if matches!(self.role, Role::Electing) {
+                    self.lead();
+                }
+            }
+            Msg::Tab(msg) => match serde_json::from_str(&msg) {
+                Ok(msg) => self.on_tab_message(msg),
+                Err(e) => log::error!("<EventSocket> invalid tab message: {e}"),
+            },
         }
         false
     }
@@ -104,7 +231,8 @@ impl Component for EventSocket {
     fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
         self.properties = ctx.props().clone();
 
-        if self.properties.reconnect {
+        if self.properties.reconnect && matches!(self.role, Role::Leader) {
+            self.reconnect_attempt = 0;
             self.connect();
         }
         true
@@ -116,11 +244,142 @@ impl Component for EventSocket {
 
     fn destroy(&mut self, _ctx: &Context<Self>) {
         // log::info!("<EventSocket> destroy");
+        if matches!(self.role, Role::Leader) {
+            self.broadcast(&TabMessage::Bye);
+        }
+
+        if let Some((channel, _listener)) = self.channel.take() {
+            channel.set_onmessage(None);
+            channel.close();
+        }
+
         self.disconnect();
     }
 }
 
+/// one channel per socket url, moderator and viewer tabs of an event do not share a socket
+fn open_channel(
+    url: &str,
+    link: &Scope<EventSocket>,
+) -> Option<(BroadcastChannel, Closure<dyn Fn(MessageEvent)>)> {
+    let channel = BroadcastChannel::new(&format!("liveask:socket:{url}")).ok()?;
+
+    let link = link.clone();
+    let listener = Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
+        if let Some(msg) = e.data().as_string() {
+            link.send_message(Msg::Tab(msg));
+        }
+    });
+    channel.set_onmessage(Some(listener.as_ref().unchecked_ref()));
+
+    Some((channel, listener))
+}
+
+/// delay before reconnect `attempt` (counting from 0), without the jitter
+fn reconnect_backoff_secs(attempt: u32) -> i64 {
+    RECONNECT_BASE_SECS
+        .saturating_mul(1_i64 << attempt.min(5))
+        .min(RECONNECT_MAX_SECS)
+}
+
 impl EventSocket {
+    /// asks the other tabs for a leader and takes over if none answers in time
+    fn elect(&mut self) {
+        self.role = Role::Electing;
+        self.broadcast(&TabMessage::Hello);
+
+        let jitter = u32::conv_nearest(js_sys::Math::random() * ELECTION_JITTER_MS);
+        let link = self.link.clone();
+        self.election = Some(Timeout::new(ELECTION_BASE_MS + jitter, move || {
+            link.send_message(Msg::ElectionTimeout);
+        }));
+    }
+
+    fn lead(&mut self) {
+        // log::info!("<EventSocket> leading");
+        self.role = Role::Leader;
+        self.election = None;
+        self.connect();
+        self.broadcast_leader();
+    }
+
+    fn follow(&mut self, connected: bool) {
+        if !matches!(self.role, Role::Follower) {
+            // log::info!("<EventSocket> following");
+            self.role = Role::Follower;
+            self.election = None;
+            //Note: makes sure the change of `connected` below is reported
+            self.leader_connected = !connected;
+        }
+
+        self.last_leader_seen = Utc::now().timestamp();
+
+        if self.leader_connected != connected {
+            self.leader_connected = connected;
+            self.emit(if connected {
+                SocketResponse::Connected
+            } else {
+                SocketResponse::Disconnected { reconnect: None }
+            });
+        }
+    }
+
+    fn on_tab_message(&mut self, msg: TabMessage) {
+        match (msg, self.role) {
+            (TabMessage::Hello, Role::Leader) => self.broadcast_leader(),
+            (TabMessage::Leader { id, .. }, Role::Leader) => {
+                //Note: two tabs elected themselves at once, the smaller id keeps the socket
+                if id < self.tab_id {
+                    self.disconnect();
+                    self.reconnect_timeout = None;
+                    self.elect();
+                }
+            }
+            (TabMessage::Leader { connected, .. }, Role::Electing | Role::Follower) => {
+                self.follow(connected);
+            }
+            (TabMessage::Bye, Role::Follower) => self.elect(),
+            (TabMessage::Text(msg), Role::Follower) => {
+                self.last_leader_seen = Utc::now().timestamp();
+                self.emit(SocketResponse::Message(msg));
+            }
+            (TabMessage::Binary(data), Role::Follower) => {
+                self.last_leader_seen = Utc::now().timestamp();
+                self.emit(SocketResponse::Binary(data));
+            }
+            (TabMessage::Typing, Role::Leader) => {
+                if self.connected {
+                    self.send(WsClientMessage::Typing);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn broadcast_leader(&self) {
+        if matches!(self.role, Role::Leader) {
+            self.broadcast(&TabMessage::Leader {
+                id: self.tab_id.clone(),
+                connected: self.connected,
+            });
+        }
+    }
+
+    fn broadcast(&self, msg: &TabMessage) {
+        let Some((channel, _)) = &self.channel else {
+            return;
+        };
+
+        match serde_json::to_string(msg) {
+            Ok(msg) => {
+                if let Err(e) = channel.post_message(&msg.into()) {
+                    log::error!("<EventSocket> broadcast error: {e:?}");
+                }
+            }
+            Err(e) => log::error!("<EventSocket> broadcast encode error: {e}"),
+        }
+    }
+
     fn connect(&mut self) {
         if self.ws.is_some() {
             // log::warn!("<EventSocket> already started");
@@ -136,6 +395,7 @@ impl EventSocket {
         let ws_close_callback = self.link.callback(|()| Msg::Disconnected);
         let ws_connected_callback = self.link.callback(|()| Msg::Connected);
         let ws_msg_callback = self.link.callback(Msg::MessageReceived);
+        let ws_binary_callback = self.link.callback(Msg::BinaryReceived);
 
         let mut client =
             wasm_sockets::EventClient::new(&url).expect_throw("error creating websocket");
@@ -153,15 +413,10 @@ impl EventSocket {
             ws_close_callback.emit(());
         })));
         client.set_on_message(Some(Box::new(
-            move |_client: &wasm_sockets::EventClient, message: wasm_sockets::Message| {
-                if let wasm_sockets::Message::Text(txt) = message {
-                    ws_msg_callback.emit(txt);
-                } else {
-                    log::error!(
-                        "<EventSocket> error: unexpected messages type: {:?}",
-                        message
-                    );
-                }
+            move |_client: &wasm_sockets::EventClient, message: wasm_sockets::Message| match message
+            {
+                wasm_sockets::Message::Text(txt) => ws_msg_callback.emit(txt),
+                wasm_sockets::Message::Binary(data) => ws_binary_callback.emit(data),
             },
         )));
 
@@ -183,12 +438,16 @@ impl EventSocket {
     }
 
     fn set_reconnect(&mut self) -> Duration {
-        //TODO: base duration on previous one
-        let duration = Duration::seconds(4);
+        let backoff = reconnect_backoff_secs(self.reconnect_attempt);
+        let jitter = i64::conv_nearest(js_sys::Math::random() * RECONNECT_JITTER_MS);
+
+        let duration = Duration::seconds(backoff) + Duration::milliseconds(jitter);
 
-        let interval = {
+        self.reconnect_attempt = self.reconnect_attempt.saturating_add(1);
+
+        let timeout = {
             let link = self.link.clone();
-            Interval::new(
+            Timeout::new(
                 duration
                     .num_milliseconds()
                     .try_into()
@@ -201,12 +460,33 @@ impl EventSocket {
 
         // log::info!("<EventSocket> set reconnect timeout: {}", duration);
 
-        self.reconnect_interval = Some((duration, interval));
+        self.reconnect_timeout = Some((duration, timeout));
 
         duration
     }
 
+    fn send(&self, msg: WsClientMessage) {
+        if let Some(ws) = &self.ws {
+            if let Err(e) = ws.send_string(&msg.encode()) {
+                log::error!("<EventSocket> send error: {e:?}");
+            }
+        }
+    }
+
     fn emit(&self, msg: SocketResponse) {
         self.properties.msg.emit(msg);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reconnect_backoff() {
+        assert_eq!(
+            (0..8).map(reconnect_backoff_secs).collect::<Vec<_>>(),
+            vec![1, 2, 4, 8, 16, 30, 30, 30]
+        );
+    }
+}
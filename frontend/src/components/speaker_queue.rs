@@ -0,0 +1,287 @@
+use shared::{EventTokens, ModSpeaker, NameValidation, RaisedHand, Speaker, SpeakerQueue};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{
+    fetch,
+    i18n::{tr, tr_args},
+    local_cache::LocalCache,
+    pages::BASE_API,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct SpeakerQueueProps {
+    pub tokens: EventTokens,
+    pub queue: SpeakerQueue,
+    /// hands can only be raised while the event is open
+    pub open: bool,
+}
+
+pub enum Msg {
+    Input(InputEvent),
+    KeyDown(KeyboardEvent),
+    Raise,
+    Raised(Option<RaisedHand>),
+    Lower,
+    Mod(ModSpeaker),
+    Sent,
+}
+
+/// raise-hand queue of attendees that want to ask live, the moderator calls and reorders them
+pub struct SpeakerQueueList {
+    name: String,
+    hand: Option<RaisedHand>,
+    sending: bool,
+}
+
+impl Component for SpeakerQueueList {
+    type Message = Msg;
+    type Properties = SpeakerQueueProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            name: String::new(),
+            hand: LocalCache::raised_hand(&ctx.props().tokens.public_token),
+            sending: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Input(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.name = target.value();
+                true
+            }
+            Msg::KeyDown(e) => {
+                if e.key() == "Enter" {
+                    ctx.link().send_message(Msg::Raise);
+                }
+                false
+            }
+            Msg::Raise => {
+                if self.sending || self.queued(ctx).is_some() || !Self::name_valid(&self.name) {
+                    return false;
+                }
+
+                self.sending = true;
+
+                let event = ctx.props().tokens.public_token.clone();
+                let name = self.name.trim().to_string();
+                ctx.link().send_future(async move {
+                    match fetch::raise_hand(BASE_API, event, name).await {
+                        Ok(hand) => Msg::Raised(Some(hand)),
+                        Err(e) => {
+                            log::error!("raise hand error: {e}");
+                            Msg::Raised(None)
+                        }
+                    }
+                });
+
+                true
+            }
+            Msg::Raised(hand) => {
+                self.sending = false;
+
+                if hand.is_some() {
+                    LocalCache::set_raised_hand(&ctx.props().tokens.public_token, hand.clone());
+                    self.hand = hand;
+                }
+
+                true
+            }
+            Msg::Lower => {
+                let Some(hand) = self.hand.take() else {
+                    return false;
+                };
+
+                let event = ctx.props().tokens.public_token.clone();
+                LocalCache::set_raised_hand(&event, None);
+
+                ctx.link().send_future(async move {
+                    if let Err(e) = fetch::lower_hand(BASE_API, event, hand).await {
+                        log::error!("lower hand error: {e}");
+                    }
+
+                    Msg::Sent
+                });
+
+                true
+            }
+            Msg::Mod(modify) => {
+                let props = ctx.props();
+                let event = props.tokens.public_token.clone();
+                let secret = props.tokens.moderator_token.clone().unwrap_throw();
+
+                ctx.link().send_future(async move {
+                    if let Err(e) = fetch::mod_speakers(BASE_API, event, secret, modify).await {
+                        log::error!("mod speakers error: {e}");
+                    }
+
+                    Msg::Sent
+                });
+
+                false
+            }
+            Msg::Sent => false,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let queue = &ctx.props().queue;
+        let is_mod = ctx.props().tokens.is_mod();
+
+        html! {
+            <div class="speaker-queue">
+                <div class="speaker-queue-title">{ tr("speakers-title") }</div>
+                <ol class="speakers">
+                    {
+                        for queue.speakers.iter().enumerate().map(|(index, speaker)| {
+                            self.view_speaker(ctx, queue, index, speaker, is_mod)
+                        })
+                    }
+                </ol>
+                { if is_mod { html! {} } else { self.view_hand(ctx) } }
+            </div>
+        }
+    }
+}
+
+impl SpeakerQueueList {
+    fn name_valid(name: &str) -> bool {
+        let mut validation = NameValidation::default();
+        validation.check(name, true);
+        !validation.has_any()
+    }
+
+    /// position of the own raised hand, gone once the moderator took it off the queue
+    fn queued(&self, ctx: &Context<Self>) -> Option<usize> {
+        self.hand
+            .as_ref()
+            .and_then(|hand| ctx.props().queue.position(hand.id))
+    }
+
+    fn view_speaker(
+        &self,
+        ctx: &Context<Self>,
+        queue: &SpeakerQueue,
+        index: usize,
+        speaker: &Speaker,
+        is_mod: bool,
+    ) -> Html {
+        let id = speaker.id;
+        let current = queue.current == Some(id);
+        let own = self.hand.as_ref().is_some_and(|hand| hand.id == id);
+
+        html! {
+            <li key={id} class={classes!("speaker",current.then_some("current"),own.then_some("own"))}>
+                <span class="name">{ speaker.name.clone() }</span>
+                { if current { html! { <span class="speaking">{ tr("speakers-speaking") }</span> } } else { html! {} } }
+                { if is_mod { Self::mod_view_buttons(ctx, queue, index, id, current) } else { html! {} } }
+            </li>
+        }
+    }
+
+    fn mod_view_buttons(
+        ctx: &Context<Self>,
+        queue: &SpeakerQueue,
+        index: usize,
+        id: i64,
+        current: bool,
+    ) -> Html {
+        let last = index + 1 >= queue.speakers.len();
+
+        html! {
+            <div class="speaker-buttons">
+                <button
+                    class="button-white"
+                    disabled={index == 0}
+                    title={tr("speakers-move-up")}
+                    onclick={ctx.link().callback(move |_| Msg::Mod(ModSpeaker::Move { id, index: index.saturating_sub(1) }))}
+                >
+                    { "\u{2191}" }
+                </button>
+                <button
+                    class="button-white"
+                    disabled={last}
+                    title={tr("speakers-move-down")}
+                    onclick={ctx.link().callback(move |_| Msg::Mod(ModSpeaker::Move { id, index: index + 1 }))}
+                >
+                    { "\u{2193}" }
+                </button>
+                {
+                    if current {
+                        html! {
+                            <button class="button-red" onclick={ctx.link().callback(move |_| Msg::Mod(ModSpeaker::Remove(id)))}>
+                                { tr("speakers-done") }
+                            </button>
+                        }
+                    } else {
+                        html! {
+                            <>
+                                <button class="button-red" onclick={ctx.link().callback(move |_| Msg::Mod(ModSpeaker::Call(id)))}>
+                                    { tr("speakers-call") }
+                                </button>
+                                <button class="button-white" onclick={ctx.link().callback(move |_| Msg::Mod(ModSpeaker::Remove(id)))}>
+                                    { tr("speakers-remove") }
+                                </button>
+                            </>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+
+    fn view_hand(&self, ctx: &Context<Self>) -> Html {
+        if let Some(position) = self.queued(ctx) {
+            let turn = self
+                .hand
+                .as_ref()
+                .is_some_and(|hand| ctx.props().queue.current == Some(hand.id));
+
+            return html! {
+                <div class="speaker-hand">
+                    <div class="speaker-note">
+                        {
+                            if turn {
+                                tr("speakers-turn")
+                            } else {
+                                tr_args("speakers-position", [("position", (position + 1).into())])
+                            }
+                        }
+                    </div>
+                    <button class="button-white" onclick={ctx.link().callback(|_| Msg::Lower)}>
+                        { tr("speakers-lower") }
+                    </button>
+                </div>
+            };
+        }
+
+        if !ctx.props().open {
+            return html! {};
+        }
+
+        let valid = Self::name_valid(&self.name);
+
+        html! {
+            <div class="speaker-hand">
+                <input
+                    type="text"
+                    placeholder={tr("speakers-name")}
+                    value={self.name.clone()}
+                    oninput={ctx.link().callback(Msg::Input)}
+                    onkeydown={ctx.link().callback(Msg::KeyDown)}
+                />
+                <button
+                    class="button-red"
+                    disabled={!valid || self.sending}
+                    onclick={ctx.link().callback(|_| Msg::Raise)}
+                >
+                    { tr("speakers-raise") }
+                </button>
+            </div>
+        }
+    }
+}
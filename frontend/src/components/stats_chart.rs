@@ -0,0 +1,76 @@
+use shared::StatsBucket;
+use yew::prelude::*;
+
+use crate::{i18n::tr, time::format_time};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct StatsChartProps {
+    pub title: AttrValue,
+    pub buckets: Vec<StatsBucket>,
+}
+
+/// bar chart of a statistics timeline, one bar per bucket scaled to the highest one
+pub struct StatsChart;
+impl Component for StatsChart {
+    type Message = ();
+    type Properties = StatsChartProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {}
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let (Some(first), Some(last)) = (props.buckets.first(), props.buckets.last()) else {
+            return html! {
+                <div class="stats-chart">
+                    <div class="stats-chart-title">{ props.title.clone() }</div>
+                    <div class="stats-note">{ tr("stats-empty") }</div>
+                </div>
+            };
+        };
+
+        let max = props
+            .buckets
+            .iter()
+            .map(|bucket| bucket.count)
+            .max()
+            .unwrap_or_default();
+
+        html! {
+            <div class="stats-chart">
+                <div class="stats-chart-title">{ props.title.clone() }</div>
+                <div class="bars">
+                    {
+                        props.buckets.iter().map(|bucket| Self::view_bar(bucket, max)).collect::<Html>()
+                    }
+                </div>
+                <div class="axis">
+                    <span>{ format_time(first.start) }</span>
+                    <span>{ format_time(last.start) }</span>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl StatsChart {
+    fn view_bar(bucket: &StatsBucket, max: i64) -> Html {
+        //Note: unlikes can make a bucket negative, these are drawn as empty
+        let height = bucket
+            .count
+            .max(0)
+            .saturating_mul(100)
+            .checked_div(max)
+            .unwrap_or_default();
+
+        let title = format!("{}: {}", format_time(bucket.start), bucket.count);
+
+        html! {
+            <div class="bar" key={bucket.start} {title}>
+                <div class="fill" style={format!("height: {height}%")} />
+            </div>
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use shared::{AddSurveyResponse, SURVEY_COMMENT_MAX_LENGTH, SURVEY_RATING_MAX};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlTextAreaElement;
+use yew::prelude::*;
+
+use crate::{
+    components::TextArea,
+    fetch,
+    i18n::{tr, tr_args},
+    local_cache::LocalCache,
+    pages::BASE_API,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct SurveyProps {
+    pub event: AttrValue,
+}
+
+pub enum Msg {
+    Rate(u8),
+    CommentInput(InputEvent),
+    Submit,
+    Sent(bool),
+}
+
+/// feedback survey shown to attendees once the event is closed
+pub struct SurveyForm {
+    answered: bool,
+    sending: bool,
+    rating: u8,
+    comment: String,
+}
+
+impl Component for SurveyForm {
+    type Message = Msg;
+    type Properties = SurveyProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            answered: LocalCache::is_survey_answered(&ctx.props().event),
+            sending: false,
+            rating: 0,
+            comment: String::new(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Rate(rating) => {
+                self.rating = rating;
+                true
+            }
+            Msg::CommentInput(e) => {
+                let target: HtmlTextAreaElement = e.target_dyn_into().unwrap_throw();
+                self.comment = target.value();
+                true
+            }
+            Msg::Submit => {
+                let response = self.response();
+
+                if !response.is_valid() || self.sending || self.answered {
+                    return false;
+                }
+
+                self.sending = true;
+
+                Self::request_send(ctx.props().event.to_string(), response, ctx.link());
+
+                true
+            }
+            Msg::Sent(ok) => {
+                self.sending = false;
+
+                if ok {
+                    LocalCache::set_survey_answered(&ctx.props().event);
+                    self.answered = true;
+                }
+
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if self.answered {
+            return html! {
+                <div class="survey">
+                    <div class="survey-note">{ tr("survey-thanks") }</div>
+                </div>
+            };
+        }
+
+        let can_submit = self.response().is_valid() && !self.sending;
+
+        html! {
+            <div class="survey">
+                <div class="survey-title">{ tr("survey-title") }</div>
+                <div class="survey-stars">
+                    {
+                        (1..=SURVEY_RATING_MAX).map(|rating| {
+                            let selected = rating <= self.rating;
+                            html! {
+                                <button
+                                    class={classes!("star",selected.then_some("selected"))}
+                                    title={tr_args("survey-rating", [("rating", rating.into()), ("max", SURVEY_RATING_MAX.into())])}
+                                    onclick={ctx.link().callback(move |_| Msg::Rate(rating))}
+                                >
+                                    { if selected { "\u{2605}" } else { "\u{2606}" } }
+                                </button>
+                            }
+                        }).collect::<Html>()
+                    }
+                </div>
+                <TextArea
+                    id="survey-comment"
+                    name="survey-comment"
+                    placeholder={tr("survey-comment")}
+                    value={self.comment.clone()}
+                    maxlength={SURVEY_COMMENT_MAX_LENGTH.to_string()}
+                    autosize=true
+                    oninput={ctx.link().callback(Msg::CommentInput)}
+                />
+                <button class="button-red" disabled={!can_submit} onclick={ctx.link().callback(|_| Msg::Submit)}>
+                    { tr("survey-send") }
+                </button>
+            </div>
+        }
+    }
+}
+
+impl SurveyForm {
+    fn response(&self) -> AddSurveyResponse {
+        let comment = self.comment.trim();
+
+        AddSurveyResponse {
+            rating: self.rating,
+            comment: (!comment.is_empty()).then(|| comment.to_string()),
+            participant: LocalCache::participant(),
+        }
+    }
+
+    fn request_send(event: String, response: AddSurveyResponse, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            match fetch::add_survey_response(BASE_API, event, response).await {
+                Err(e) => {
+                    log::error!("add survey response error: {e}");
+                    Msg::Sent(false)
+                }
+                Ok(()) => Msg::Sent(true),
+            }
+        });
+    }
+}
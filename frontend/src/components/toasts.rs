@@ -0,0 +1,126 @@
+use events::{event_context, EventBridge};
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+
+use crate::{i18n::tr, GlobalEvent, Toast, ToastKind};
+
+const TOAST_DURATION_MS: u32 = 4000;
+/// older toasts are dropped once more pile up
+const MAX_TOASTS: usize = 3;
+
+struct ShownToast {
+    id: usize,
+    toast: Toast,
+    _timeout: Timeout,
+}
+
+pub enum Msg {
+    Event(GlobalEvent),
+    Dismiss(usize),
+}
+
+/// transient feedback for everything emitted as `GlobalEvent::Toast`
+pub struct Toasts {
+    toasts: Vec<ShownToast>,
+    next_id: usize,
+    connected: bool,
+    _events: EventBridge<GlobalEvent>,
+}
+
+impl Component for Toasts {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let events = event_context(ctx)
+            .unwrap_throw()
+            .subscribe(ctx.link().callback(Msg::Event));
+
+        Self {
+            toasts: Vec::new(),
+            next_id: 0,
+            connected: true,
+            _events: events,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Event(GlobalEvent::Toast(toast)) => {
+                self.show(toast, ctx);
+                true
+            }
+            //Note: the socket reports every failed reconnect, only the change is shown
+            Msg::Event(GlobalEvent::SocketStatus { connected, .. }) => {
+                if connected == self.connected {
+                    return false;
+                }
+                self.connected = connected;
+
+                self.show(
+                    if connected {
+                        Toast::info("toast-online")
+                    } else {
+                        Toast::error("toast-offline")
+                    },
+                    ctx,
+                );
+                true
+            }
+            Msg::Event(_) => false,
+            Msg::Dismiss(id) => {
+                self.toasts.retain(|shown| shown.id != id);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="toasts" role="status" aria-live="polite">
+                {
+                    for self.toasts.iter().map(|shown| {
+                        let id = shown.id;
+                        let kind = match shown.toast.kind {
+                            ToastKind::Info => "info",
+                            ToastKind::Error => "error",
+                        };
+
+                        html! {
+                            <button
+                                key={id}
+                                class={classes!("toast",kind)}
+                                onclick={ctx.link().callback(move |_| Msg::Dismiss(id))}
+                            >
+                                { tr(shown.toast.message) }
+                            </button>
+                        }
+                    })
+                }
+            </div>
+        }
+    }
+}
+
+impl Toasts {
+    fn show(&mut self, toast: Toast, ctx: &Context<Self>) {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+
+        let link = ctx.link().clone();
+        let timeout = Timeout::new(TOAST_DURATION_MS, move || {
+            link.send_message(Msg::Dismiss(id));
+        });
+
+        self.toasts.push(ShownToast {
+            id,
+            toast,
+            _timeout: timeout,
+        });
+
+        if self.toasts.len() > MAX_TOASTS {
+            self.toasts.remove(0);
+        }
+    }
+}
@@ -1,10 +1,18 @@
 use events::event_context;
-use shared::EventTokens;
+use shared::{is_valid_promo_code, EventTokens, PREMIUM_TRIAL_SECS, PROMO_CODE_MAX_LENGTH};
 use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
 use crate::tracking;
-use crate::{components::Spinner, not, Events, GlobalEvent};
+use crate::{
+    components::Spinner,
+    fetch,
+    i18n::{tr, tr_args},
+    not,
+    pages::BASE_API,
+    Events, GlobalEvent,
+};
 
 use super::payment_popup::PaymentPopup;
 
@@ -12,16 +20,23 @@ use super::payment_popup::PaymentPopup;
 pub struct Props {
     pub tokens: EventTokens,
     pub pending: bool,
+    /// the one-off premium trial was not started yet
+    pub trial_available: bool,
 }
 
 pub struct Upgrade {
     data: Props,
     collapsed: bool,
+    starting_trial: bool,
+    promo: String,
     events: Events<GlobalEvent>,
 }
 pub enum Msg {
     ToggleExpansion,
     UpgradeClicked,
+    PromoInput(InputEvent),
+    TrialClicked,
+    TrialStarted,
 }
 impl Component for Upgrade {
     type Message = Msg;
@@ -31,11 +46,13 @@ impl Component for Upgrade {
         Self {
             data: ctx.props().clone(),
             collapsed: false,
+            starting_trial: false,
+            promo: String::new(),
             events: event_context(ctx).unwrap_throw(),
         }
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::ToggleExpansion => {
                 self.collapsed = !self.collapsed;
@@ -49,6 +66,32 @@ impl Component for Upgrade {
                 self.events.emit(GlobalEvent::PayForUpgrade);
                 false
             }
+            Msg::PromoInput(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.promo = target.value().trim().to_uppercase();
+                true
+            }
+            Msg::TrialClicked => {
+                if self.starting_trial {
+                    return false;
+                }
+
+                self.starting_trial = true;
+
+                let tokens = &ctx.props().tokens;
+                Self::request_trial(
+                    tokens.public_token.clone(),
+                    tokens.moderator_token.clone().unwrap_throw(),
+                    ctx.link(),
+                );
+
+                true
+            }
+            //Note: the event itself gets updated via the socket
+            Msg::TrialStarted => {
+                self.starting_trial = false;
+                true
+            }
         }
     }
 
@@ -59,8 +102,8 @@ impl Component for Upgrade {
                 <div class="rectangle">
                     <div class="toprow" onclick={ctx.link().callback(|_| Msg::ToggleExpansion)}>
                         <span>
-                            { "Upgrade now to " }
-                            <strong>{ "PREMIUM EVENT" }</strong>
+                            { tr("upgrade-now") }
+                            <strong>{ tr("upgrade-premium") }</strong>
                         </span>
                         <img
                             alt="dropdown"
@@ -94,31 +137,73 @@ impl Upgrade {
         html! {
             <div class="expanded">
                 <div class="features">
-                {"To unlock the following features:"}
+                { tr("upgrade-features") }
                 <ul>
-                    <li>{"Unlimited access to your event"}</li>
-                    <li>{"Realtime statistics (participants, likes ..)"}</li>
-                    <li>{"Export your event data"}</li>
-                    <li>{"Prescreen questions before they appear"}</li>
-                    <li>{"Automatically tag questions"}</li>
-                    <li>{"Add context link to your event"}</li>
-                    <li>{"Plus much more .."}</li>
+                    <li>{ tr("upgrade-feature-access") }</li>
+                    <li>{ tr("upgrade-feature-statistics") }</li>
+                    <li>{ tr("upgrade-feature-export") }</li>
+                    <li>{ tr("upgrade-feature-screening") }</li>
+                    <li>{ tr("upgrade-feature-tags") }</li>
+                    <li>{ tr("upgrade-feature-context") }</li>
+                    <li>{ tr("upgrade-feature-more") }</li>
                 </ul>
                 </div>
                 <div class="tmp-subscription">
-                    { "Are you planning to host multiple events? " }
-                    <a href="mailto:mail@live-ask.com">{ "Contact us" }</a>
-                    { " for special discounts." }
+                    { tr("upgrade-contact-before") }
+                    <a href="mailto:mail@live-ask.com">{ tr("upgrade-contact") }</a>
+                    { tr("upgrade-contact-after") }
                 </div>
+                <input
+                    class={classes!("promo",(!self.promo.is_empty() && self.valid_promo().is_none()).then_some("invalid"))}
+                    type="text"
+                    placeholder={tr("upgrade-promo")}
+                    maxlength={PROMO_CODE_MAX_LENGTH.to_string()}
+                    hidden={pending}
+                    value={self.promo.clone()}
+                    oninput={ctx.link().callback(Msg::PromoInput)}
+                />
                 <button
                     class="button"
                     hidden={pending}
                     onclick={ctx.link().callback(|_| Msg::UpgradeClicked)}
                 >
-                    { "upgrade for \u{20AC}7" }
+                    { tr("upgrade-buy") }
                 </button>
-                <PaymentPopup tokens={self.data.tokens.clone()} />
+                { self.view_trial(ctx) }
+                <PaymentPopup tokens={self.data.tokens.clone()} promo={self.valid_promo()} />
             </div>
         }
     }
+
+    fn valid_promo(&self) -> Option<String> {
+        is_valid_promo_code(&self.promo).then(|| self.promo.clone())
+    }
+
+    fn view_trial(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        if !props.trial_available || props.pending {
+            return html! {};
+        }
+
+        html! {
+            <button
+                class="button trial"
+                disabled={self.starting_trial}
+                onclick={ctx.link().callback(|_| Msg::TrialClicked)}
+            >
+                { tr_args("upgrade-trial", [("minutes", PREMIUM_TRIAL_SECS.div_euclid(60).into())]) }
+            </button>
+        }
+    }
+
+    fn request_trial(id: String, secret: String, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            if let Err(e) = fetch::mod_start_trial(BASE_API, id, secret).await {
+                log::error!("mod_start_trial error: {e}");
+            }
+
+            Msg::TrialStarted
+        });
+    }
 }
@@ -0,0 +1,214 @@
+use easy_cast::{Conv, ConvFloat};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::Element;
+use yew::prelude::*;
+
+/// feeds up to this size are rendered completely and keep their reorder animations
+const VIRTUALIZE_AFTER: usize = 100;
+/// guessed height of a card in pixels until mounted cards were measured
+const ESTIMATED_ITEM_HEIGHT: f64 = 120.0;
+/// cards mounted above and below the viewport so scrolling does not show the swap
+const OVERSCAN: usize = 10;
+/// window events that can move the feed within the viewport
+const LISTENED_EVENTS: [&str; 2] = ["scroll", "resize"];
+
+#[derive(Properties, PartialEq)]
+pub struct VirtualFeedProps {
+    #[prop_or_default]
+    pub class: Classes,
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub children: Children,
+    /// index of a child that has to be mounted, the window scrolls to it if it is not
+    #[prop_or_default]
+    pub focus: Option<usize>,
+}
+
+pub enum Msg {
+    Scrolled,
+}
+
+/// ARIA feed that only mounts the children around the viewport once it gets long,
+/// the rest is replaced by spacers of the estimated height
+pub struct VirtualFeed {
+    feed: NodeRef,
+    /// mounted range of children
+    start: usize,
+    end: usize,
+    item_height: f64,
+    listener: Closure<dyn Fn()>,
+}
+
+impl Component for VirtualFeed {
+    type Message = Msg;
+    type Properties = VirtualFeedProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let link = ctx.link().clone();
+        let listener = Closure::<dyn Fn()>::new(move || link.send_message(Msg::Scrolled));
+
+        for event in LISTENED_EVENTS {
+            let _ = gloo_utils::window()
+                .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref());
+        }
+
+        let len = ctx.props().children.len();
+
+        Self {
+            feed: NodeRef::default(),
+            start: 0,
+            //Note: the real window is known once the feed is mounted and its position is known
+            end: if len > VIRTUALIZE_AFTER {
+                OVERSCAN
+            } else {
+                len
+            },
+            item_height: ESTIMATED_ITEM_HEIGHT,
+            listener,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Scrolled => self.update_window(ctx),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        //Note: only when the focus moves, otherwise scrolling away from it would be impossible
+        if let Some(focus) = ctx
+            .props()
+            .focus
+            .filter(|focus| old_props.focus != Some(*focus))
+        {
+            if ctx.props().children.len() > VIRTUALIZE_AFTER
+                && !(self.start..self.end).contains(&focus)
+            {
+                self.scroll_to(focus);
+            }
+        }
+
+        self.update_window(ctx);
+        true
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            ctx.link().send_message(Msg::Scrolled);
+        }
+
+        self.measure(ctx);
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        for event in LISTENED_EVENTS {
+            let _ = gloo_utils::window()
+                .remove_event_listener_with_callback(event, self.listener.as_ref().unchecked_ref());
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let VirtualFeedProps {
+            class,
+            label,
+            children,
+            ..
+        } = ctx.props();
+
+        let len = children.len();
+        let end = self.end.min(len);
+        let start = self.start.min(end);
+
+        html! {
+            <div ref={self.feed.clone()} class={class.clone()} role="feed" aria-label={label.clone()}>
+                { self.view_spacer(start) }
+                { for children.iter().skip(start).take(end - start) }
+                { self.view_spacer(len - end) }
+            </div>
+        }
+    }
+}
+
+impl VirtualFeed {
+    fn view_spacer(&self, items: usize) -> Html {
+        if items == 0 {
+            return html! {};
+        }
+
+        let height = f64::conv(items) * self.item_height;
+
+        html! {
+            <div class="virtual-spacer" aria-hidden="true" style={format!("height: {height}px")} />
+        }
+    }
+
+    /// returns `true` if another range of children needs to be mounted
+    fn update_window(&mut self, ctx: &Context<Self>) -> bool {
+        let len = ctx.props().children.len();
+
+        let (start, end) = if len > VIRTUALIZE_AFTER {
+            let Some(feed) = self.feed.cast::<Element>() else {
+                return false;
+            };
+
+            let viewport = gloo_utils::window()
+                .inner_height()
+                .ok()
+                .and_then(|height| height.as_f64())
+                .unwrap_or_default();
+
+            //Note: negative once the top of the feed is scrolled out of the viewport
+            let hidden = (-feed.get_bounding_client_rect().top()).max(0.0);
+            let first = usize::conv_floor(hidden / self.item_height).min(len);
+            let visible = usize::conv_ceil(viewport / self.item_height);
+
+            (
+                first.saturating_sub(OVERSCAN),
+                first
+                    .saturating_add(visible)
+                    .saturating_add(OVERSCAN)
+                    .min(len),
+            )
+        } else {
+            (0, len)
+        };
+
+        let changed = start != self.start || end != self.end;
+        self.start = start;
+        self.end = end;
+        changed
+    }
+
+    /// average height of the mounted children replaces the estimate
+    fn measure(&mut self, ctx: &Context<Self>) {
+        let mounted = self.end.saturating_sub(self.start);
+        if ctx.props().children.len() <= VIRTUALIZE_AFTER || mounted == 0 {
+            return;
+        }
+
+        let Some(feed) = self.feed.cast::<Element>() else {
+            return;
+        };
+
+        let spacers =
+            f64::conv(ctx.props().children.len().saturating_sub(mounted)) * self.item_height;
+        let height = (feed.get_bounding_client_rect().height() - spacers) / f64::conv(mounted);
+
+        //Note: only adopted on the next scroll, re-rendering right away could oscillate
+        if height > 0.0 {
+            self.item_height = height;
+        }
+    }
+
+    fn scroll_to(&self, index: usize) {
+        let Some(feed) = self.feed.cast::<Element>() else {
+            return;
+        };
+
+        let window = gloo_utils::window();
+        let scroll_y = window.scroll_y().unwrap_or_default();
+        let top = feed.get_bounding_client_rect().top() + scroll_y;
+
+        window.scroll_to_with_x_and_y(0.0, top + f64::conv(index) * self.item_height);
+    }
+}
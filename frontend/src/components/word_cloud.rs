@@ -0,0 +1,301 @@
+use easy_cast::Conv;
+use shared::{
+    normalize_word, EventTokens, ModWordCloud, WordCloud, WordCount, WORD_CLOUD_ENTRY_MAX_LENGTH,
+};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{fetch, i18n::tr, local_cache::LocalCache, pages::BASE_API};
+
+const CLOUD_WIDTH: f64 = 600_f64;
+const CLOUD_HEIGHT: f64 = 300_f64;
+const MIN_FONT_SIZE: f64 = 14_f64;
+const MAX_FONT_SIZE: f64 = 52_f64;
+/// rough width of a glyph relative to the font size
+const CHAR_WIDTH: f64 = 0.6_f64;
+const MAX_WORDS_SHOWN: usize = 60;
+const SPIRAL_STEPS: u32 = 1000;
+const COLORS: [&str; 5] = ["#FB0A4C", "#282828", "#4A90E2", "#2ECC71", "#F5A623"];
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct WordCloudProps {
+    pub tokens: EventTokens,
+    pub cloud: WordCloud,
+}
+
+pub enum Msg {
+    Input(InputEvent),
+    KeyDown(KeyboardEvent),
+    Submit,
+    ToggleClosed,
+    Sent,
+}
+
+/// word cloud prompt: answer input until the participant answered, size weighted svg cloud of all answers
+pub struct WordCloudItem {
+    answered: bool,
+    text: String,
+}
+
+impl Component for WordCloudItem {
+    type Message = Msg;
+    type Properties = WordCloudProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            answered: Self::cached_answered(ctx),
+            text: String::new(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Input(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.text = target.value();
+                true
+            }
+            Msg::KeyDown(e) => {
+                if e.key() == "Enter" {
+                    ctx.link().send_message(Msg::Submit);
+                }
+                false
+            }
+            Msg::Submit => {
+                let props = ctx.props();
+
+                if self.answered || props.cloud.closed || normalize_word(&self.text).is_none() {
+                    return false;
+                }
+
+                LocalCache::set_word_cloud_answered(&props.tokens.public_token, props.cloud.id);
+                self.answered = true;
+
+                Self::request_add(
+                    props.tokens.public_token.clone(),
+                    props.cloud.id,
+                    std::mem::take(&mut self.text),
+                    ctx.link(),
+                );
+
+                true
+            }
+            Msg::ToggleClosed => {
+                let props = ctx.props();
+
+                Self::request_edit(
+                    props.tokens.public_token.clone(),
+                    props.tokens.moderator_token.clone().unwrap_throw(),
+                    props.cloud.id,
+                    ModWordCloud {
+                        closed: !props.cloud.closed,
+                    },
+                    ctx.link(),
+                );
+
+                false
+            }
+            Msg::Sent => false,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.answered = Self::cached_answered(ctx);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let cloud = &ctx.props().cloud;
+        let is_mod = ctx.props().tokens.is_mod();
+
+        html! {
+            <div class={classes!("word-cloud",cloud.closed.then_some("closed"))}>
+                <div class="word-cloud-prompt">{ cloud.prompt.clone() }</div>
+                { self.view_input(ctx, is_mod) }
+                { Self::view_cloud(&cloud.words) }
+                { Self::mod_view_toggle(ctx, is_mod, cloud.closed) }
+            </div>
+        }
+    }
+}
+
+impl WordCloudItem {
+    fn cached_answered(ctx: &Context<Self>) -> bool {
+        LocalCache::is_word_cloud_answered(&ctx.props().tokens.public_token, ctx.props().cloud.id)
+    }
+
+    fn view_input(&self, ctx: &Context<Self>, is_mod: bool) -> Html {
+        if is_mod || ctx.props().cloud.closed {
+            return html! {};
+        }
+
+        if self.answered {
+            return html! { <div class="word-cloud-note">{ tr("word-cloud-thanks") }</div> };
+        }
+
+        let valid = normalize_word(&self.text).is_some();
+
+        html! {
+            <div class="word-cloud-input">
+                <input
+                    type="text"
+                    placeholder={tr("word-cloud-placeholder")}
+                    maxlength={WORD_CLOUD_ENTRY_MAX_LENGTH.to_string()}
+                    value={self.text.clone()}
+                    oninput={ctx.link().callback(Msg::Input)}
+                    onkeydown={ctx.link().callback(Msg::KeyDown)}
+                />
+                <button class="button-red" disabled={!valid} onclick={ctx.link().callback(|_| Msg::Submit)}>
+                    { tr("word-cloud-send") }
+                </button>
+            </div>
+        }
+    }
+
+    fn view_cloud(words: &[WordCount]) -> Html {
+        if words.is_empty() {
+            return html! { <div class="word-cloud-note">{ tr("word-cloud-empty") }</div> };
+        }
+
+        let view_box = format!(
+            "{} {} {CLOUD_WIDTH} {CLOUD_HEIGHT}",
+            -CLOUD_WIDTH / 2_f64,
+            -CLOUD_HEIGHT / 2_f64
+        );
+
+        html! {
+            <svg class="word-cloud-svg" viewBox={view_box}>
+                {
+                    layout(words).into_iter().enumerate().map(|(index, word)| html! {
+                        <text
+                            key={word.text.clone()}
+                            class="word"
+                            text-anchor="middle"
+                            dominant-baseline="middle"
+                            fill={COLORS[index % COLORS.len()]}
+                            style={format!(
+                                "transform: translate({:.0}px, {:.0}px); font-size: {:.0}px",
+                                word.x, word.y, word.size
+                            )}
+                        >
+                            { word.text.clone() }
+                            <title>{ word.count.to_string() }</title>
+                        </text>
+                    }).collect::<Html>()
+                }
+            </svg>
+        }
+    }
+
+    fn mod_view_toggle(ctx: &Context<Self>, is_mod: bool, closed: bool) -> Html {
+        if !is_mod {
+            return html! {};
+        }
+
+        html! {
+            <button class="button-red" onclick={ctx.link().callback(|_| Msg::ToggleClosed)}>
+                { tr(if closed { "word-cloud-reopen" } else { "word-cloud-close" }) }
+            </button>
+        }
+    }
+
+    fn request_add(event: String, cloud: i64, text: String, link: &html::Scope<Self>) {
+        link.send_future(async move {
+            if let Err(e) =
+                fetch::add_word(BASE_API, event, cloud, text, LocalCache::participant()).await
+            {
+                log::error!("add word error: {e}");
+            }
+
+            Msg::Sent
+        });
+    }
+
+    fn request_edit(
+        event: String,
+        secret: String,
+        cloud: i64,
+        modify: ModWordCloud,
+        link: &html::Scope<Self>,
+    ) {
+        link.send_future(async move {
+            if let Err(e) = fetch::mod_word_cloud(BASE_API, event, secret, cloud, modify).await {
+                log::error!("mod word cloud error: {e}");
+            }
+
+            Msg::Sent
+        });
+    }
+}
+
+struct PlacedWord {
+    text: String,
+    count: i32,
+    x: f64,
+    y: f64,
+    size: f64,
+}
+
+/// bounding box around its center
+struct Area {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl Area {
+    fn intersects(&self, other: &Self) -> bool {
+        (self.x - other.x).abs() * 2_f64 < self.width + other.width
+            && (self.y - other.y).abs() * 2_f64 < self.height + other.height
+    }
+
+    fn inside_cloud(&self) -> bool {
+        self.x.abs().mul_add(2_f64, self.width) <= CLOUD_WIDTH
+            && self.y.abs().mul_add(2_f64, self.height) <= CLOUD_HEIGHT
+    }
+}
+
+/// places the most frequent words first, each one walking outwards on a spiral until it fits
+fn layout(words: &[WordCount]) -> Vec<PlacedWord> {
+    let mut words = words.iter().filter(|w| w.count > 0).collect::<Vec<_>>();
+    words.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.text.cmp(&b.text)));
+    words.truncate(MAX_WORDS_SHOWN);
+
+    let max = f64::from(words.first().map_or(1, |w| w.count).max(1));
+
+    let mut placed: Vec<(Area, PlacedWord)> = Vec::with_capacity(words.len());
+
+    for word in words {
+        let size =
+            (MAX_FONT_SIZE - MIN_FONT_SIZE).mul_add(f64::from(word.count) / max, MIN_FONT_SIZE);
+        let width = f64::conv(word.text.chars().count()) * size * CHAR_WIDTH;
+
+        let spot = (0..SPIRAL_STEPS)
+            .map(|step| {
+                let t = f64::from(step) * 0.1_f64;
+                Area {
+                    x: t * 3_f64 * t.cos(),
+                    y: t * 1.5_f64 * t.sin(),
+                    width,
+                    height: size,
+                }
+            })
+            .find(|area| area.inside_cloud() && !placed.iter().any(|(a, _)| a.intersects(area)));
+
+        //Note: words that do not fit anymore are left out
+        if let Some(area) = spot {
+            let word = PlacedWord {
+                text: word.text.clone(),
+                count: word.count,
+                x: area.x,
+                y: area.y,
+                size,
+            };
+            placed.push((area, word));
+        }
+    }
+
+    placed.into_iter().map(|(_, word)| word).collect()
+}
@@ -1,18 +1,48 @@
 #![allow(clippy::future_not_send)]
 
+use easy_cast::ConvFloat;
 use gloo_utils::format::JsValueSerdeExt;
 use shared::{
-    AddEvent, AddQuestion, EditLike, EventData, EventInfo, EventPasswordRequest,
-    EventPasswordResponse, EventUpgrade, GetEventResponse, GetUserInfo, ModEvent, ModQuestion,
-    PaymentCapture, QuestionItem, UserLogin,
+    AddEvent, AddOrg, AddOrgMember, AddPoll, AddQuestion, AddSession, AddSurveyResponse, AddWord,
+    AddWordCloud, AttachmentRequest, AttachmentUpload, EditDownvote, EditLike, EditReaction,
+    EditSeries, EditSlug, EventData, EventInfo, EventMod, EventPasswordRequest,
+    EventPasswordResponse, EventPublic, EventSeries, EventStats, EventUpdates, EventUpgrade,
+    GetEventResponse, ModEvent, ModMergeQuestions, ModPoll, ModQuestion, ModQuestionNote,
+    ModQuestionsBulk, ModSession, ModSpeaker, ModTimer, ModWordCloud, OrgCheckout, OrgMembership,
+    OrgWorkspace, PaymentCapture, Poll, PollVote, QuestionCluster, QuestionItem, QuestionSummary,
+    QuestionTranslation, QuestionsPage, RaiseHand, RaisedHand, Reaction, ReceiptDetails,
+    SeriesCurrent, Session, SlugTarget, SubscriptionState, SurveyResults, WebPushSubscription,
+    WebPushUnsubscribe, WordCloud, WordCount, FINGERPRINT_HEADER,
 };
+#[cfg(feature = "admin")]
+use shared::{AdminDashboard, BlockEntry, Blocklist, GetUserInfo, PromoCode, UserLogin};
 use std::{
+    cell::RefCell,
     error::Error,
     fmt::{self, Debug, Display, Formatter},
 };
 use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{Request, RequestCredentials, RequestInit, Response};
+use web_sys::{File, Request, RequestCredentials, RequestInit, Response};
+use yew::Callback;
+
+use crate::{local_cache::LocalCache, time::local_timezone};
+
+/// the server answered with an error status
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiError {
+    pub status: u16,
+    /// `x-request-id` of the response to find the request in the server logs
+    pub request_id: Option<String>,
+    /// seconds from the `retry-after` header of a rate limited response
+    pub retry_after: Option<u32>,
+}
+
+impl ApiError {
+    const fn is_server_error(&self) -> bool {
+        self.status >= 500
+    }
+}
 
 /// Something wrong has occurred while fetching an external resource.
 #[derive(Debug)]
@@ -20,6 +50,7 @@ pub enum FetchError {
     Generic(String),
     JsonError(JsValue),
     SerdeError(serde_json::error::Error),
+    Api(ApiError),
 }
 impl Display for FetchError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -27,6 +58,12 @@ impl Display for FetchError {
             Self::JsonError(e) => Debug::fmt(e, f),
             Self::SerdeError(e) => Debug::fmt(e, f),
             Self::Generic(e) => Debug::fmt(e, f),
+            Self::Api(e) => write!(
+                f,
+                "status {} (request id: {})",
+                e.status,
+                e.request_id.as_deref().unwrap_or("none")
+            ),
         }
     }
 }
@@ -43,6 +80,45 @@ impl From<serde_json::error::Error> for FetchError {
     }
 }
 
+thread_local! {
+    static ERROR_HANDLER: RefCell<Option<Callback<ApiError>>> = RefCell::new(None);
+}
+
+/// receives server errors of requests loading a page, mutations are reported by their callers
+pub fn set_error_handler(handler: Callback<ApiError>) {
+    ERROR_HANDLER.with(|cell| *cell.borrow_mut() = Some(handler));
+}
+
+async fn send(request: &Request) -> Result<Response, FetchError> {
+    let resp_value = JsFuture::from(gloo_utils::window().fetch_with_request(request)).await?;
+    let resp: Response = resp_value.dyn_into()?;
+
+    if resp.ok() {
+        return Ok(resp);
+    }
+
+    let error = ApiError {
+        status: resp.status(),
+        request_id: resp.headers().get("x-request-id").ok().flatten(),
+        retry_after: resp
+            .headers()
+            .get("retry-after")
+            .ok()
+            .flatten()
+            .and_then(|secs| secs.parse().ok()),
+    };
+
+    if error.is_server_error() && request.method() == "GET" {
+        ERROR_HANDLER.with(|cell| {
+            if let Some(handler) = cell.borrow().as_ref() {
+                handler.emit(error.clone());
+            }
+        });
+    }
+
+    Err(FetchError::Api(error))
+}
+
 pub async fn fetch_version(base_api: &str) -> Result<String, FetchError> {
     let url = format!("{base_api}/api/version");
 
@@ -51,37 +127,93 @@ pub async fn fetch_version(base_api: &str) -> Result<String, FetchError> {
 
     let request = Request::new_with_str_and_init(&url, &opts)?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
+    let resp = send(&request).await?;
     let resp = JsFuture::from(resp.text()?).await?;
 
     resp.as_string()
         .ok_or_else(|| FetchError::Generic(String::from("string error")))
 }
 
+/// viewers can limit the questions to the first `questions`, see [`questions_page`] for the rest
 pub async fn fetch_event(
     base_api: &str,
     id: String,
     secret: Option<String>,
+    questions: Option<usize>,
 ) -> Result<GetEventResponse, FetchError> {
-    let url = secret.map_or_else(
-        || format!("{base_api}/api/event/{id}"),
-        |secret| format!("{base_api}/api/mod/event/{id}/{secret}"),
-    );
+    let is_mod = secret.is_some();
+    let url = match (secret, questions) {
+        (Some(secret), _) => format!("{base_api}/api/mod/event/{id}/{secret}"),
+        (None, Some(limit)) => format!("{base_api}/api/event/{id}?questions={limit}"),
+        (None, None) => format!("{base_api}/api/event/{id}"),
+    };
 
     let mut opts = RequestInit::new();
     opts.method("GET");
     opts.credentials(RequestCredentials::Include);
 
     let request = Request::new_with_str_and_init(&url, &opts)?;
+    //Note: lets the author of shadowed questions see them
+    request
+        .headers()
+        .set(FINGERPRINT_HEADER, &LocalCache::participant())?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = if is_mod {
+        JsValueSerdeExt::into_serde::<EventMod>(&json)?.into()
+    } else {
+        JsValueSerdeExt::into_serde::<EventPublic>(&json)?.into()
+    };
+
+    Ok(res)
+}
+
+/// questions following the first page of a [`fetch_event`] limited to some questions
+pub async fn questions_page(
+    base_api: &str,
+    id: String,
+    offset: usize,
+    limit: usize,
+) -> Result<QuestionsPage, FetchError> {
+    let url = format!("{base_api}/api/event/{id}/questions?offset={offset}&limit={limit}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+    opts.credentials(RequestCredentials::Include);
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request
+        .headers()
+        .set(FINGERPRINT_HEADER, &LocalCache::participant())?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<QuestionsPage>(&json)?;
+
+    Ok(res)
+}
+
+/// long polling fallback, resolves once the event is newer than `since` or the server times out
+pub async fn event_updates(
+    base_api: &str,
+    id: String,
+    since: usize,
+) -> Result<EventUpdates, FetchError> {
+    let url = format!("{base_api}/api/event/{id}/updates?since={since}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+    opts.credentials(RequestCredentials::Include);
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
 
     let json = JsFuture::from(resp.json()?).await?;
-    let res = JsValueSerdeExt::into_serde::<GetEventResponse>(&json)?;
+    let res = JsValueSerdeExt::into_serde::<EventUpdates>(&json)?;
 
     Ok(res)
 }
@@ -104,9 +236,7 @@ pub async fn mod_edit_event(
     let request = Request::new_with_str_and_init(&url, &opts)?;
     request.headers().set("content-type", "application/json")?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
+    let resp = send(&request).await?;
 
     let json = JsFuture::from(resp.json()?).await?;
     let res = JsValueSerdeExt::into_serde::<EventInfo>(&json)?;
@@ -132,9 +262,7 @@ pub async fn event_set_password(
     let request = Request::new_with_str_and_init(&url, &opts)?;
     request.headers().set("content-type", "application/json")?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
+    let resp = send(&request).await?;
 
     let json = JsFuture::from(resp.json()?).await?;
     let res = JsValueSerdeExt::into_serde::<EventPasswordResponse>(&json)?;
@@ -142,21 +270,162 @@ pub async fn event_set_password(
     Ok(res.ok)
 }
 
+/// `promo` has to be a valid promo code, see [`shared::is_valid_promo_code`]
 pub async fn mod_upgrade(
     base_api: &str,
     id: String,
     secret: String,
+    promo: Option<String>,
 ) -> Result<EventUpgrade, FetchError> {
-    let url = format!("{base_api}/api/mod/event/upgrade/{id}/{secret}");
+    let url = promo.map_or_else(
+        || format!("{base_api}/api/mod/event/upgrade/{id}/{secret}"),
+        |promo| format!("{base_api}/api/mod/event/upgrade/{id}/{secret}?promo={promo}"),
+    );
 
     let mut opts = RequestInit::new();
     opts.method("GET");
 
     let request = Request::new_with_str_and_init(&url, &opts)?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventUpgrade>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn request_subscription(base_api: &str) -> Result<EventUpgrade, FetchError> {
+    let url = format!("{base_api}/api/subscription");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventUpgrade>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn subscription_state(
+    base_api: &str,
+    account: String,
+) -> Result<SubscriptionState, FetchError> {
+    let url = format!("{base_api}/api/subscription/{account}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<SubscriptionState>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn create_org(base_api: &str, org: AddOrg) -> Result<OrgMembership, FetchError> {
+    let body = serde_json::to_string(&org)?;
+    let body = JsValue::from_str(&body);
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&format!("{base_api}/api/org"), &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<OrgMembership>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn org_workspace(
+    base_api: &str,
+    id: String,
+    token: String,
+) -> Result<OrgWorkspace, FetchError> {
+    let url = format!("{base_api}/api/org/{id}/{token}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<OrgWorkspace>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn org_add_member(
+    base_api: &str,
+    id: String,
+    token: String,
+    member: AddOrgMember,
+) -> Result<OrgMembership, FetchError> {
+    let body = serde_json::to_string(&member)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/org/{id}/{token}/member");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<OrgMembership>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn org_remove_member(
+    base_api: &str,
+    id: String,
+    token: String,
+    member: String,
+) -> Result<(), FetchError> {
+    let url = format!("{base_api}/api/org/{id}/{token}/member/{member}/remove");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn org_subscription(
+    base_api: &str,
+    id: String,
+    token: String,
+) -> Result<EventUpgrade, FetchError> {
+    let url = format!("{base_api}/api/org/{id}/{token}/subscription");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
 
     let json = JsFuture::from(resp.json()?).await?;
     let res = JsValueSerdeExt::into_serde::<EventUpgrade>(&json)?;
@@ -164,6 +433,22 @@ pub async fn mod_upgrade(
     Ok(res)
 }
 
+pub async fn org_checkout(base_api: &str, session: String) -> Result<OrgCheckout, FetchError> {
+    let url = format!("{base_api}/api/org/checkout/{session}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<OrgCheckout>(&json)?;
+
+    Ok(res)
+}
+
 pub async fn mod_premium_capture(
     base_api: &str,
     id: String,
@@ -176,9 +461,7 @@ pub async fn mod_premium_capture(
 
     let request = Request::new_with_str_and_init(&url, &opts)?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
+    let resp = send(&request).await?;
 
     let json = JsFuture::from(resp.json()?).await?;
     let res = JsValueSerdeExt::into_serde::<PaymentCapture>(&json)?;
@@ -191,8 +474,13 @@ pub async fn like_question(
     event_id: String,
     question_id: i64,
     like: bool,
+    participant: String,
 ) -> Result<QuestionItem, FetchError> {
-    let body = EditLike { question_id, like };
+    let body = EditLike {
+        question_id,
+        like,
+        participant: Some(participant),
+    };
     let body = serde_json::to_string(&body)?;
     let body = JsValue::from_str(&body);
 
@@ -205,9 +493,7 @@ pub async fn like_question(
     let request = Request::new_with_str_and_init(&url, &opts)?;
     request.headers().set("content-type", "application/json")?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
+    let resp = send(&request).await?;
 
     let json = JsFuture::from(resp.json()?).await?;
     let res = JsValueSerdeExt::into_serde::<QuestionItem>(&json)?;
@@ -215,18 +501,20 @@ pub async fn like_question(
     Ok(res)
 }
 
-pub async fn mod_question(
+pub async fn downvote_question(
     base_api: &str,
     event_id: String,
-    event_secret: String,
     question_id: i64,
-    modify: ModQuestion,
-) -> Result<(), FetchError> {
-    let body = serde_json::to_string(&modify)?;
+    downvote: bool,
+) -> Result<QuestionItem, FetchError> {
+    let body = EditDownvote {
+        question_id,
+        downvote,
+    };
+    let body = serde_json::to_string(&body)?;
     let body = JsValue::from_str(&body);
 
-    let url =
-        format!("{base_api}/api/mod/event/questionmod/{event_id}/{event_secret}/{question_id}");
+    let url = format!("{base_api}/api/event/editdownvote/{event_id}");
 
     let mut opts = RequestInit::new();
     opts.method("POST");
@@ -235,22 +523,30 @@ pub async fn mod_question(
     let request = Request::new_with_str_and_init(&url, &opts)?;
     request.headers().set("content-type", "application/json")?;
 
-    let window = gloo_utils::window();
-    let _resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
+    let resp = send(&request).await?;
 
-    Ok(())
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<QuestionItem>(&json)?;
+
+    Ok(res)
 }
 
-pub async fn add_question(
+pub async fn react_question(
     base_api: &str,
     event_id: String,
-    text: String,
+    question_id: i64,
+    reaction: Reaction,
+    add: bool,
 ) -> Result<QuestionItem, FetchError> {
-    let body = AddQuestion { text };
+    let body = EditReaction {
+        question_id,
+        reaction,
+        add,
+    };
     let body = serde_json::to_string(&body)?;
     let body = JsValue::from_str(&body);
 
-    let url = format!("{base_api}/api/event/addquestion/{event_id}");
+    let url = format!("{base_api}/api/event/editreaction/{event_id}");
 
     let mut opts = RequestInit::new();
     opts.method("POST");
@@ -259,9 +555,7 @@ pub async fn add_question(
     let request = Request::new_with_str_and_init(&url, &opts)?;
     request.headers().set("content-type", "application/json")?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
+    let resp = send(&request).await?;
 
     let json = JsFuture::from(resp.json()?).await?;
     let res = JsValueSerdeExt::into_serde::<QuestionItem>(&json)?;
@@ -269,69 +563,70 @@ pub async fn add_question(
     Ok(res)
 }
 
-pub async fn create_event(
+pub async fn mod_question(
     base_api: &str,
-    name: String,
-    desc: String,
-    email: Option<String>,
-) -> Result<EventInfo, FetchError> {
-    let body = AddEvent {
-        data: EventData {
-            name,
-            description: desc,
-            long_url: None,
-            short_url: String::new(),
-        },
-        test: false,
-        moderator_email: email,
-    };
-    let body = serde_json::to_string(&body)?;
+    event_id: String,
+    event_secret: String,
+    question_id: i64,
+    modify: ModQuestion,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&modify)?;
     let body = JsValue::from_str(&body);
 
+    let url =
+        format!("{base_api}/api/mod/event/questionmod/{event_id}/{event_secret}/{question_id}");
+
     let mut opts = RequestInit::new();
     opts.method("POST");
     opts.body(Some(&body));
 
-    let request = Request::new_with_str_and_init(&format!("{base_api}/api/event/add"), &opts)?;
+    let request = Request::new_with_str_and_init(&url, &opts)?;
     request.headers().set("content-type", "application/json")?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
-
-    let json = JsFuture::from(resp.json()?).await?;
-    let res = JsValueSerdeExt::into_serde::<EventInfo>(&json)?;
+    send(&request).await?;
 
-    Ok(res)
+    Ok(())
 }
 
-pub async fn admin_login(base_api: &str, name: String, pwd_hash: String) -> Result<(), FetchError> {
-    let body = UserLogin { name, pwd_hash };
-    let body = serde_json::to_string(&body)?;
+pub async fn mod_edit_questions(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    bulk: ModQuestionsBulk,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&bulk)?;
     let body = JsValue::from_str(&body);
 
+    let url = format!("{base_api}/api/mod/event/questionsmod/{event_id}/{event_secret}");
+
     let mut opts = RequestInit::new();
     opts.method("POST");
     opts.body(Some(&body));
-    opts.credentials(RequestCredentials::Include);
 
-    let request = Request::new_with_str_and_init(&format!("{base_api}/api/admin/login"), &opts)?;
+    let request = Request::new_with_str_and_init(&url, &opts)?;
     request.headers().set("content-type", "application/json")?;
 
-    let window = gloo_utils::window();
+    send(&request).await?;
 
-    let resp = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp.dyn_into()?;
-
-    if resp.ok() {
-        Ok(())
-    } else {
-        Err(FetchError::Generic("request failed".into()))
-    }
+    Ok(())
 }
 
-pub async fn fetch_user(base_api: &str) -> Result<GetUserInfo, FetchError> {
-    let url = format!("{base_api}/api/admin/user");
+/// moderators pass their `secret` to translate questions that are not public yet
+pub async fn translate_question(
+    base_api: &str,
+    event_id: String,
+    secret: Option<String>,
+    question_id: i64,
+    language: &str,
+) -> Result<QuestionTranslation, FetchError> {
+    let url = secret.map_or_else(
+        || format!("{base_api}/api/event/translate/{event_id}/{question_id}/{language}"),
+        |secret| {
+            format!(
+                "{base_api}/api/mod/event/translate/{event_id}/{secret}/{question_id}/{language}"
+            )
+        },
+    );
 
     let mut opts = RequestInit::new();
     opts.method("GET");
@@ -339,16 +634,699 @@ pub async fn fetch_user(base_api: &str) -> Result<GetUserInfo, FetchError> {
 
     let request = Request::new_with_str_and_init(&url, &opts)?;
 
-    let window = gloo_utils::window();
-    let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp_value.dyn_into()?;
+    let resp = send(&request).await?;
 
     let json = JsFuture::from(resp.json()?).await?;
-    let res = JsValueSerdeExt::into_serde::<GetUserInfo>(&json)?;
+    let res = JsValueSerdeExt::into_serde::<QuestionTranslation>(&json)?;
 
     Ok(res)
 }
 
+pub async fn mod_question_clusters(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+) -> Result<Vec<QuestionCluster>, FetchError> {
+    let url = format!("{base_api}/api/mod/event/clusters/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<Vec<QuestionCluster>>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_merge_questions(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    merge: ModMergeQuestions,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&merge)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/merge/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn mod_question_note(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    question_id: i64,
+    note: String,
+) -> Result<QuestionItem, FetchError> {
+    let body = serde_json::to_string(&ModQuestionNote { note })?;
+    let body = JsValue::from_str(&body);
+
+    let url =
+        format!("{base_api}/api/mod/event/questionnote/{event_id}/{event_secret}/{question_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<QuestionItem>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn vote_poll(
+    base_api: &str,
+    event_id: String,
+    poll_id: i64,
+    option: usize,
+    participant: String,
+) -> Result<Poll, FetchError> {
+    let body = serde_json::to_string(&PollVote {
+        option,
+        participant,
+    })?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/event/pollvote/{event_id}/{poll_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<Poll>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_add_poll(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    poll: AddPoll,
+) -> Result<Poll, FetchError> {
+    let body = serde_json::to_string(&poll)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/poll/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<Poll>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_poll(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    poll_id: i64,
+    modify: ModPoll,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&modify)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/pollmod/{event_id}/{event_secret}/{poll_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn raise_hand(
+    base_api: &str,
+    event_id: String,
+    name: String,
+) -> Result<RaisedHand, FetchError> {
+    let body = serde_json::to_string(&RaiseHand { name })?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/event/raisehand/{event_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<RaisedHand>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn lower_hand(
+    base_api: &str,
+    event_id: String,
+    hand: RaisedHand,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&hand)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/event/lowerhand/{event_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn mod_speakers(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    modify: ModSpeaker,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&modify)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/speakers/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn mod_timer(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    modify: ModTimer,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&modify)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/timer/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn add_word(
+    base_api: &str,
+    event_id: String,
+    cloud_id: i64,
+    text: String,
+    participant: String,
+) -> Result<WordCount, FetchError> {
+    let body = serde_json::to_string(&AddWord { text, participant })?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/event/addword/{event_id}/{cloud_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<WordCount>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_add_session(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    session: AddSession,
+) -> Result<Session, FetchError> {
+    let body = serde_json::to_string(&session)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/session/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<Session>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_session(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    session_id: i64,
+    modify: ModSession,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&modify)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/sessionmod/{event_id}/{event_secret}/{session_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn mod_add_word_cloud(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    cloud: AddWordCloud,
+) -> Result<WordCloud, FetchError> {
+    let body = serde_json::to_string(&cloud)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/wordcloud/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<WordCloud>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_word_cloud(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+    cloud_id: i64,
+    modify: ModWordCloud,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&modify)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/wordcloudmod/{event_id}/{event_secret}/{cloud_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn add_survey_response(
+    base_api: &str,
+    event_id: String,
+    response: AddSurveyResponse,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&response)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/event/survey/{event_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn mod_event_stats(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+) -> Result<EventStats, FetchError> {
+    let url = format!("{base_api}/api/mod/event/stats/event/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventStats>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_question_summary(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+) -> Result<QuestionSummary, FetchError> {
+    let url = format!("{base_api}/api/mod/event/summary/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<QuestionSummary>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_survey_results(
+    base_api: &str,
+    event_id: String,
+    event_secret: String,
+) -> Result<SurveyResults, FetchError> {
+    let url = format!("{base_api}/api/mod/event/stats/survey/{event_id}/{event_secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<SurveyResults>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn add_question(
+    base_api: &str,
+    event_id: String,
+    text: String,
+    name: Option<String>,
+    attachment: Option<String>,
+    session: Option<i64>,
+    participant: String,
+) -> Result<QuestionItem, FetchError> {
+    let body = AddQuestion {
+        text,
+        name,
+        attachment,
+        session,
+        participant: Some(participant),
+    };
+    let body = serde_json::to_string(&body)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/event/addquestion/{event_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+    request
+        .headers()
+        .set(FINGERPRINT_HEADER, &LocalCache::participant())?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<QuestionItem>(&json)?;
+
+    Ok(res)
+}
+
+#[must_use]
+pub fn attachment_request(file: &File) -> AttachmentRequest {
+    AttachmentRequest {
+        content_type: file.type_(),
+        //Note: sizes beyond usize are rejected by the server as too large anyway
+        size: usize::try_conv_nearest(file.size()).unwrap_or(usize::MAX),
+    }
+}
+
+pub async fn request_attachment(
+    base_api: &str,
+    event_id: String,
+    file: &File,
+) -> Result<AttachmentUpload, FetchError> {
+    let body = attachment_request(file);
+    let body = serde_json::to_string(&body)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/event/attachment/{event_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<AttachmentUpload>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_request_logo(
+    base_api: &str,
+    event_id: String,
+    secret: String,
+    file: &File,
+) -> Result<AttachmentUpload, FetchError> {
+    let body = attachment_request(file);
+    let body = serde_json::to_string(&body)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/logo/{event_id}/{secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<AttachmentUpload>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn upload_attachment(upload_url: &str, file: &File) -> Result<(), FetchError> {
+    let mut opts = RequestInit::new();
+    opts.method("PUT");
+    opts.body(Some(file));
+
+    let request = Request::new_with_str_and_init(upload_url, &opts)?;
+    request.headers().set("content-type", &file.type_())?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn create_event(
+    base_api: &str,
+    name: String,
+    desc: String,
+    email: Option<String>,
+    subscription: Option<String>,
+    start_unix: Option<i64>,
+    end_unix: Option<i64>,
+) -> Result<EventInfo, FetchError> {
+    let body = add_event_body(name, desc, email, subscription, start_unix, end_unix);
+
+    post_event(&format!("{base_api}/api/event/add"), &body).await
+}
+
+/// creates the event in the workspace of `org`, billed by its team subscription
+pub async fn org_create_event(
+    base_api: &str,
+    org: OrgMembership,
+    name: String,
+    desc: String,
+    email: Option<String>,
+    start_unix: Option<i64>,
+    end_unix: Option<i64>,
+) -> Result<EventInfo, FetchError> {
+    let body = add_event_body(name, desc, email, None, start_unix, end_unix);
+
+    post_event(
+        &format!("{base_api}/api/org/{}/{}/event", org.org, org.token),
+        &body,
+    )
+    .await
+}
+
+fn add_event_body(
+    name: String,
+    desc: String,
+    email: Option<String>,
+    subscription: Option<String>,
+    start_unix: Option<i64>,
+    end_unix: Option<i64>,
+) -> AddEvent {
+    AddEvent {
+        data: EventData {
+            name,
+            description: desc,
+            long_url: None,
+            short_url: String::new(),
+            start_unix,
+            end_unix,
+            timezone: local_timezone(),
+            branding: None,
+        },
+        test: false,
+        moderator_email: email,
+        subscription,
+    }
+}
+
+async fn post_event(url: &str, body: &AddEvent) -> Result<EventInfo, FetchError> {
+    let body = serde_json::to_string(body)?;
+    let body = JsValue::from_str(&body);
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+    request
+        .headers()
+        .set(FINGERPRINT_HEADER, &LocalCache::participant())?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventInfo>(&json)?;
+
+    Ok(res)
+}
+
+#[cfg(feature = "admin")]
+pub async fn admin_login(base_api: &str, name: String, pwd_hash: String) -> Result<(), FetchError> {
+    let body = UserLogin { name, pwd_hash };
+    let body = serde_json::to_string(&body)?;
+    let body = JsValue::from_str(&body);
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+    opts.credentials(RequestCredentials::Include);
+
+    let request = Request::new_with_str_and_init(&format!("{base_api}/api/admin/login"), &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "admin")]
+pub async fn fetch_user(base_api: &str) -> Result<GetUserInfo, FetchError> {
+    let url = format!("{base_api}/api/admin/user");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+    opts.credentials(RequestCredentials::Include);
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<GetUserInfo>(&json)?;
+
+    Ok(res)
+}
+
+#[cfg(feature = "admin")]
 pub async fn admin_logout(base_api: &str) -> Result<(), FetchError> {
     let url = format!("{base_api}/api/admin/logout");
 
@@ -358,15 +1336,113 @@ pub async fn admin_logout(base_api: &str) -> Result<(), FetchError> {
 
     let request = Request::new_with_str_and_init(&url, &opts)?;
 
-    let window = gloo_utils::window();
-    let resp = JsFuture::from(window.fetch_with_request(&request)).await?;
-    let resp: Response = resp.dyn_into()?;
+    send(&request).await?;
 
-    if resp.ok() {
-        Ok(())
+    Ok(())
+}
+
+#[cfg(feature = "admin")]
+pub async fn admin_add_promo(base_api: &str, promo: PromoCode) -> Result<PromoCode, FetchError> {
+    let body = serde_json::to_string(&promo)?;
+    let body = JsValue::from_str(&body);
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+    opts.credentials(RequestCredentials::Include);
+
+    let request = Request::new_with_str_and_init(&format!("{base_api}/api/admin/promo"), &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<PromoCode>(&json)?;
+
+    Ok(res)
+}
+
+#[cfg(feature = "admin")]
+pub async fn admin_dashboard(base_api: &str) -> Result<AdminDashboard, FetchError> {
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+    opts.credentials(RequestCredentials::Include);
+
+    let request =
+        Request::new_with_str_and_init(&format!("{base_api}/api/admin/dashboard"), &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<AdminDashboard>(&json)?;
+
+    Ok(res)
+}
+
+#[cfg(feature = "admin")]
+pub async fn admin_event(base_api: &str, id: String) -> Result<EventInfo, FetchError> {
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+    opts.credentials(RequestCredentials::Include);
+
+    let request =
+        Request::new_with_str_and_init(&format!("{base_api}/api/admin/event/{id}"), &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventInfo>(&json)?;
+
+    Ok(res)
+}
+
+#[cfg(feature = "admin")]
+pub async fn admin_blocklist(base_api: &str) -> Result<Blocklist, FetchError> {
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+    opts.credentials(RequestCredentials::Include);
+
+    let request =
+        Request::new_with_str_and_init(&format!("{base_api}/api/admin/blocklist"), &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<Blocklist>(&json)?;
+
+    Ok(res)
+}
+
+/// blocks the client of `entry`, or lifts the block if `remove` is set
+#[cfg(feature = "admin")]
+pub async fn admin_edit_blocklist(
+    base_api: &str,
+    entry: BlockEntry,
+    remove: bool,
+) -> Result<Blocklist, FetchError> {
+    let body = serde_json::to_string(&entry)?;
+    let body = JsValue::from_str(&body);
+
+    let url = if remove {
+        format!("{base_api}/api/admin/blocklist/remove")
     } else {
-        Err(FetchError::Generic("request failed".into()))
-    }
+        format!("{base_api}/api/admin/blocklist")
+    };
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+    opts.credentials(RequestCredentials::Include);
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<Blocklist>(&json)?;
+
+    Ok(res)
 }
 
 pub async fn delete_event(
@@ -384,9 +1460,242 @@ pub async fn delete_event(
 
     let request = Request::new_with_str_and_init(&url, &opts)?;
 
-    let window = gloo_utils::window();
-    let req = window.fetch_with_request(&request);
-    let _resp_value = JsFuture::from(req).await?;
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn mod_duplicate_event(
+    base_api: &str,
+    event_id: String,
+    secret: String,
+) -> Result<EventInfo, FetchError> {
+    let url = format!("{base_api}/api/mod/event/duplicate/{event_id}/{secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventInfo>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_start_trial(
+    base_api: &str,
+    event_id: String,
+    secret: String,
+) -> Result<EventInfo, FetchError> {
+    let url = format!("{base_api}/api/mod/event/trial/{event_id}/{secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventInfo>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_edit_series(
+    base_api: &str,
+    event_id: String,
+    secret: String,
+    edit: EditSeries,
+) -> Result<EventSeries, FetchError> {
+    let body = serde_json::to_string(&edit)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/series/{event_id}/{secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventSeries>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_series_archive(
+    base_api: &str,
+    event_id: String,
+    secret: String,
+) -> Result<EventSeries, FetchError> {
+    let url = format!("{base_api}/api/mod/event/series/{event_id}/{secret}/archive");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventSeries>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn series_current(
+    base_api: &str,
+    series_id: String,
+) -> Result<SeriesCurrent, FetchError> {
+    let url = format!("{base_api}/api/event/series/{series_id}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<SeriesCurrent>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_edit_slug(
+    base_api: &str,
+    event_id: String,
+    secret: String,
+    edit: EditSlug,
+) -> Result<EventInfo, FetchError> {
+    let body = serde_json::to_string(&edit)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/slug/{event_id}/{secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<EventInfo>(&json)?;
+
+    Ok(res)
+}
+
+pub async fn mod_edit_receipt(
+    base_api: &str,
+    event_id: String,
+    secret: String,
+    details: ReceiptDetails,
+) -> Result<ReceiptDetails, FetchError> {
+    let body = serde_json::to_string(&details)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/receipt/{event_id}/{secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<ReceiptDetails>(&json)?;
+
+    Ok(res)
+}
+
+/// public VAPID key of the server, fails where Web Push is not configured
+pub async fn web_push_key(base_api: &str) -> Result<String, FetchError> {
+    let url = format!("{base_api}/api/webpush/key");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+    let resp = JsFuture::from(resp.text()?).await?;
+
+    resp.as_string()
+        .ok_or_else(|| FetchError::Generic(String::from("string error")))
+}
+
+pub async fn mod_push_subscribe(
+    base_api: &str,
+    event_id: String,
+    secret: String,
+    subscription: WebPushSubscription,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&subscription)?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/webpush/{event_id}/{secret}");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
+
+    Ok(())
+}
+
+pub async fn mod_push_unsubscribe(
+    base_api: &str,
+    event_id: String,
+    secret: String,
+    endpoint: String,
+) -> Result<(), FetchError> {
+    let body = serde_json::to_string(&WebPushUnsubscribe { endpoint })?;
+    let body = JsValue::from_str(&body);
+
+    let url = format!("{base_api}/api/mod/event/webpush/{event_id}/{secret}/remove");
+
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&body));
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+    request.headers().set("content-type", "application/json")?;
+
+    send(&request).await?;
 
     Ok(())
 }
+
+pub async fn slug_target(base_api: &str, slug: String) -> Result<SlugTarget, FetchError> {
+    let url = format!("{base_api}/api/event/slug/{slug}");
+
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+
+    let request = Request::new_with_str_and_init(&url, &opts)?;
+
+    let resp = send(&request).await?;
+
+    let json = JsFuture::from(resp.json()?).await?;
+    let res = JsValueSerdeExt::into_serde::<SlugTarget>(&json)?;
+
+    Ok(res)
+}
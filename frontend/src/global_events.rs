@@ -10,4 +10,40 @@ pub enum GlobalEvent {
     QuestionCreated(i64),
     PayForUpgrade,
     SocketManualReconnect,
+    QuestionTyping,
+    /// an action was queued in `LocalCache` while offline
+    ActionQueued,
+    /// the user picked another language in the icon bar
+    LanguageChanged,
+    /// transient feedback shown by `Toasts`
+    Toast(Toast),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Error,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Toast {
+    pub kind: ToastKind,
+    /// message id in the i18n catalog, translated once shown
+    pub message: &'static str,
+}
+
+impl Toast {
+    pub const fn info(message: &'static str) -> Self {
+        Self {
+            kind: ToastKind::Info,
+            message,
+        }
+    }
+
+    pub const fn error(message: &'static str) -> Self {
+        Self {
+            kind: ToastKind::Error,
+            message,
+        }
+    }
 }
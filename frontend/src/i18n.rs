@@ -0,0 +1,194 @@
+use fluent::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use unic_langid::LanguageIdentifier;
+use wasm_bindgen::UnwrapThrowExt;
+
+use crate::local_cache::LocalCache;
+
+/// languages the frontend has a string catalog for, see `i18n/*.ftl`
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+    Arabic,
+    Hebrew,
+}
+
+impl Language {
+    pub const ALL: [Self; 4] = [Self::English, Self::German, Self::Arabic, Self::Hebrew];
+
+    /// BCP 47 tag, also used for `Intl` date formatting
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::German => "de",
+            Self::Arabic => "ar",
+            Self::Hebrew => "he",
+        }
+    }
+
+    /// name of the language in that language
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::German => "Deutsch",
+            Self::Arabic => "\u{627}\u{644}\u{639}\u{631}\u{628}\u{64a}\u{629}",
+            Self::Hebrew => "\u{5e2}\u{5d1}\u{5e8}\u{5d9}\u{5ea}",
+        }
+    }
+
+    /// value of the `dir` attribute, `rtl.scss` mirrors the layout for `rtl`
+    #[must_use]
+    pub const fn direction(self) -> &'static str {
+        match self {
+            Self::English | Self::German => "ltr",
+            Self::Arabic | Self::Hebrew => "rtl",
+        }
+    }
+
+    /// matches on the primary subtag so `de-AT` picks German
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        let primary = code.split(['-', '_']).next().unwrap_or_default();
+
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(primary))
+    }
+
+    const fn catalog(self) -> &'static str {
+        match self {
+            Self::English => include_str!("../i18n/en.ftl"),
+            Self::German => include_str!("../i18n/de.ftl"),
+            Self::Arabic => include_str!("../i18n/ar.ftl"),
+            Self::Hebrew => include_str!("../i18n/he.ftl"),
+        }
+    }
+}
+
+struct Catalog {
+    language: Language,
+    bundle: FluentBundle<FluentResource>,
+    /// english strings used for messages missing in `bundle`
+    fallback: FluentBundle<FluentResource>,
+}
+
+impl Catalog {
+    fn new(language: Language) -> Self {
+        Self {
+            language,
+            bundle: bundle(language),
+            fallback: bundle(Language::English),
+        }
+    }
+}
+
+thread_local! {
+    static CATALOG: RefCell<Catalog> = RefCell::new(Catalog::new(Language::default()));
+}
+
+/// picks the stored choice or the preferred browser language, run once on startup
+pub fn init() {
+    let language = LocalCache::language()
+        .or_else(browser_language)
+        .unwrap_or_default();
+
+    apply(language);
+}
+
+#[must_use]
+pub fn language() -> Language {
+    CATALOG.with(|catalog| catalog.borrow().language)
+}
+
+/// persists and applies a choice made by the user
+pub fn set_language(language: Language) {
+    LocalCache::set_language(language);
+    apply(language);
+}
+
+/// translated message `id` of the current language
+#[must_use]
+pub fn tr(id: &str) -> String {
+    translate(id, None)
+}
+
+/// translated message `id` with its `{ $variable }` placeables filled from `args`
+#[must_use]
+pub fn tr_args<'a>(id: &str, args: impl IntoIterator<Item = (&'a str, FluentValue<'a>)>) -> String {
+    let args: FluentArgs = args.into_iter().collect();
+    translate(id, Some(&args))
+}
+
+fn apply(language: Language) {
+    CATALOG.with(|catalog| *catalog.borrow_mut() = Catalog::new(language));
+
+    if let Some(root) = gloo_utils::document().document_element() {
+        root.set_attribute("lang", language.code()).unwrap_throw();
+        root.set_attribute("dir", language.direction())
+            .unwrap_throw();
+    }
+}
+
+fn browser_language() -> Option<Language> {
+    gloo_utils::window()
+        .navigator()
+        .languages()
+        .iter()
+        .filter_map(|code| code.as_string())
+        .find_map(|code| Language::from_code(&code))
+}
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let id: LanguageIdentifier = language.code().parse().unwrap_throw();
+    let mut bundle = FluentBundle::new(vec![id]);
+    //Note: unicode isolation marks would show up in placeholders and titles
+    bundle.set_use_isolating(false);
+
+    let resource = FluentResource::try_new(language.catalog().to_owned()).unwrap_or_else(
+        |(resource, errors)| {
+            log::error!("catalog {} parse errors: {errors:?}", language.code());
+            resource
+        },
+    );
+
+    if let Err(errors) = bundle.add_resource(resource) {
+        log::error!("catalog {} errors: {errors:?}", language.code());
+    }
+
+    bundle
+}
+
+fn translate(id: &str, args: Option<&FluentArgs>) -> String {
+    CATALOG.with(|catalog| {
+        let catalog = catalog.borrow();
+
+        format_bundle(&catalog.bundle, id, args)
+            .or_else(|| format_bundle(&catalog.fallback, id, args))
+            .unwrap_or_else(|| {
+                log::warn!("missing translation: {id}");
+                id.to_owned()
+            })
+    })
+}
+
+fn format_bundle(
+    bundle: &FluentBundle<FluentResource>,
+    id: &str,
+    args: Option<&FluentArgs>,
+) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+
+    if !errors.is_empty() {
+        log::warn!("translation {id} errors: {errors:?}");
+    }
+
+    Some(text.into_owned())
+}
@@ -1,123 +1,155 @@
 #![allow(clippy::non_ascii_literal)]
 
+mod clipboard;
+mod color_scheme;
 mod components;
 mod environment;
 mod fetch;
 mod global_events;
+mod i18n;
 mod local_cache;
 mod pages;
+mod pwa;
+#[cfg(feature = "admin")]
 mod pwd;
 mod routes;
+mod store;
+mod tab_alert;
+mod time;
 mod tracking;
 
 use events::{EventBridge, Events};
-use global_events::GlobalEvent;
+use global_events::{GlobalEvent, Toast, ToastKind};
+use local_cache::LocalCache;
+#[cfg(feature = "admin")]
 use pages::AdminLogin;
+#[cfg(feature = "print")]
+use pages::Print;
+#[cfg(feature = "screen")]
+use pages::Screen;
 use routes::Route;
-use shared::GetEventResponse;
+use shared::OrgMembership;
 use std::rc::Rc;
+use store::ConnectionStore;
 use yew::prelude::*;
 use yew_router::prelude::*;
-use yewdux::{prelude::Dispatch, store::Store};
+use yewdux::prelude::Dispatch;
+
+pub use pwa::register_service_worker;
 
 use crate::{
-    components::IconBar,
-    pages::{Event, Home, NewEvent, Print, Privacy},
+    components::{ErrorBanner, IconBar, Toasts},
+    fetch::ApiError,
+    pages::{Event, Home, NewEvent, NewOrg, Org, OrgCheckout, Privacy, Series, Slug, Subscription},
 };
 
 pub const VERSION_STR: &str = "2.9.0";
 pub const GIT_BRANCH: &str = env!("VERGEN_GIT_BRANCH");
 
-#[derive(Default, Clone, Eq, PartialEq, Store)]
-pub struct State {
-    pub event: Option<GetEventResponse>,
-    pub new_question: Option<i64>,
-    pub admin: bool,
-}
-
-impl State {
-    #[must_use]
-    pub const fn set_new_question(mut self, v: Option<i64>) -> Self {
-        self.new_question = v;
-        self
-    }
-    #[must_use]
-    #[allow(clippy::missing_const_for_fn)]
-    pub fn set_event(mut self, v: Option<GetEventResponse>) -> Self {
-        self.event = v;
-        self
-    }
-
-    #[must_use]
-    pub fn set_event_viewers(mut self, viewers: i64) -> Self {
-        if let Some(e) = &mut self.event {
-            e.viewers = viewers;
-        }
-        self
-    }
-
-    #[must_use]
-    pub const fn set_admin(mut self, v: bool) -> Self {
-        self.admin = v;
-        self
-    }
-}
-
 pub enum Msg {
-    State(Rc<State>),
+    Connection(Rc<ConnectionStore>),
     GlobalEvent(GlobalEvent),
+    ApiError(ApiError),
+    Retry,
+    DismissError,
 }
 
 pub struct AppRoot {
     connected: bool,
+    language: i18n::Language,
+    /// last server error of a page load
+    error: Option<ApiError>,
+    /// bumped on retry to recreate the page and load it again
+    reloads: usize,
     events: EventBridge<GlobalEvent>,
-    state: Rc<State>,
-    _dispatch: Dispatch<State>,
+    _dispatch: Dispatch<ConnectionStore>,
 }
 impl Component for AppRoot {
     type Message = Msg;
     type Properties = ();
 
     fn create(ctx: &Context<Self>) -> Self {
+        LocalCache::migrate();
+        color_scheme::apply(LocalCache::color_scheme());
+        i18n::init();
+        fetch::set_error_handler(ctx.link().callback(Msg::ApiError));
+
         let mut context = Events::<GlobalEvent>::default();
 
         let events = context.subscribe(ctx.link().callback(Msg::GlobalEvent));
 
         Self {
-            _dispatch: Dispatch::<State>::subscribe(ctx.link().callback(Msg::State)),
-            state: Rc::default(),
+            _dispatch: Dispatch::<ConnectionStore>::subscribe(ctx.link().callback(Msg::Connection)),
             connected: true,
+            language: i18n::language(),
+            error: None,
+            reloads: 0,
             events,
         }
     }
 
     fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
-            Msg::State(state) => {
-                self.state = state;
-                false
+            //Note: only re-renders when the socket goes on- or offline, not on every reconnect attempt
+            Msg::Connection(connection) => {
+                let changed = self.connected != connection.connected;
+                self.connected = connection.connected;
+                changed
             }
             Msg::GlobalEvent(e) => match e {
-                GlobalEvent::SocketStatus { connected, .. } => {
-                    self.connected = connected;
+                GlobalEvent::LanguageChanged => {
+                    self.language = i18n::language();
                     true
                 }
                 _ => false,
             },
+            Msg::ApiError(error) => {
+                self.error = Some(error);
+                true
+            }
+            Msg::Retry => {
+                self.error = None;
+                self.reloads = self.reloads.wrapping_add(1);
+                true
+            }
+            Msg::DismissError => {
+                self.error = None;
+                true
+            }
+        }
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        //Note: replaces the server rendered snapshot `index.html` shows while the wasm loads
+        if first_render {
+            if let Some(preview) = gloo_utils::document().get_element_by_id("event-preview") {
+                preview.remove();
+            }
         }
     }
 
-    fn view(&self, _ctx: &Context<Self>) -> Html {
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        //Note: keyed so every page is recreated with its strings translated again or to load again on retry
+        let key = format!("{}-{}", self.language.code(), self.reloads);
+
         html! {
             <BrowserRouter>
                 <div class="app-host">
                     <ContextProvider<Events<GlobalEvent>> context={self.events.clone()}>
-                        <div class={classes!("main",not(self.connected).then_some("offline"))}>
-                            <IconBar />
+                        { self.error.clone().map_or_else(|| html! {}, |error| html! {
+                            <ErrorBanner
+                                {error}
+                                on_retry={ctx.link().callback(|()| Msg::Retry)}
+                                on_dismiss={ctx.link().callback(|()| Msg::DismissError)}
+                            />
+                        }) }
+                        <div {key} class={classes!("main",not(self.connected).then_some("offline"))}>
+                            <Switch<Route> render={icon_bar} />
                             <div class="router">
                                 <Switch<Route> render={switch} />
                             </div>
                         </div>
+                        <Toasts />
                     </ContextProvider<Events<GlobalEvent>>>
                 </div>
             </BrowserRouter>
@@ -130,16 +162,70 @@ pub const fn not(b: bool) -> bool {
     !b
 }
 
+/// embedded events show no chrome of their own
+fn icon_bar(route: Route) -> Html {
+    if matches!(route, Route::Embed { .. }) {
+        html! {}
+    } else {
+        html! { <IconBar /> }
+    }
+}
+
 fn switch(switch: Route) -> Html {
     match switch {
         Route::Event { id } => {
             html! { <Event {id} /> }
         }
+        Route::Embed { id } => {
+            html! { <Event {id} embedded=true /> }
+        }
+        #[cfg(feature = "print")]
         Route::Print { id } => {
             html! { <Print {id} /> }
         }
+        #[cfg(feature = "print")]
+        Route::PrintMod { id, secret } => {
+            html! { <Print {id} {secret} /> }
+        }
+        #[cfg(not(feature = "print"))]
+        Route::Print { .. } | Route::PrintMod { .. } => not_in_build(),
+        #[cfg(feature = "screen")]
+        Route::Screen { id } => {
+            html! { <Screen {id} /> }
+        }
+        #[cfg(feature = "screen")]
+        Route::ScreenMod { id, secret } => {
+            html! { <Screen {id} {secret} /> }
+        }
+        #[cfg(not(feature = "screen"))]
+        Route::Screen { .. } | Route::ScreenMod { .. } => not_in_build(),
+        Route::Slug { slug } => {
+            html! { <Slug {slug} /> }
+        }
+        Route::Series { id } => {
+            html! { <Series {id} /> }
+        }
+        Route::Subscription { account } => {
+            html! { <Subscription {account} /> }
+        }
+        Route::NewOrg => {
+            html! { <NewOrg /> }
+        }
+        Route::OrgCheckoutCancelled => {
+            html! { <OrgCheckout /> }
+        }
+        Route::OrgCheckout { session } => {
+            html! { <OrgCheckout {session} /> }
+        }
+        Route::Org { id, token } => {
+            html! { <Org key={id.clone()} {id} {token} /> }
+        }
+        Route::OrgNewEvent { id, token } => {
+            html! { <NewEvent org={OrgMembership { org: id, token }} /> }
+        }
         Route::EventMod { id, secret } => {
-            html! { <Event {id} {secret} /> }
+            //Note: keyed so switching to another event (e.g. a duplicate) recreates the page
+            html! { <Event key={id.clone()} {id} {secret} /> }
         }
         Route::NewEvent => {
             html! { <NewEvent /> }
@@ -150,8 +236,17 @@ fn switch(switch: Route) -> Html {
         Route::Privacy => {
             html! { <Privacy /> }
         }
+        #[cfg(feature = "admin")]
         Route::Login => {
             html! { <AdminLogin /> }
         }
+        #[cfg(not(feature = "admin"))]
+        Route::Login => not_in_build(),
     }
 }
+
+/// pages left out of a slim build via cargo features, see `[features]` in `Cargo.toml`
+#[cfg(not(all(feature = "admin", feature = "print", feature = "screen")))]
+fn not_in_build() -> Html {
+    html! { <Redirect<Route> to={Route::Home} /> }
+}
@@ -1,13 +1,150 @@
-use gloo_storage::{LocalStorage, Storage};
-use serde::{Deserialize, Serialize};
-use shared::QuestionItem;
-use std::collections::HashSet;
-use wasm_bindgen::UnwrapThrowExt;
+use chrono::Local;
+use gloo_storage::{errors::StorageError, LocalStorage, Storage};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use shared::{
+    EventBranding, EventFlags, EventInfo, EventTheme, ModEvent, OrgMembership, QuestionItem,
+    RaisedHand, Reaction,
+};
+use std::collections::{HashMap, HashSet};
 
+use crate::{color_scheme::ColorScheme, i18n::Language};
+
+/// every field defaults so stores written by older releases keep loading
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
 struct EventStore {
     likes: HashSet<i64>,
+    downvotes: HashSet<i64>,
+    reactions: HashSet<(i64, Reaction)>,
+    /// voted option per poll
+    polls: HashMap<i64, usize>,
+    /// word clouds answered already
+    word_clouds: HashSet<i64>,
+    /// feedback survey answered already
+    survey: bool,
     unscreened: Vec<QuestionItem>,
+    /// actions taken while offline, oldest first
+    pending: Vec<PendingAction>,
+    /// push endpoint this browser subscribed to the event with as its moderator
+    web_push: Option<String>,
+    /// hand raised in the speaker queue, its token is needed to lower it again
+    raised_hand: Option<RaisedHand>,
+    /// time of the latest question asked, to show the remaining question cooldown
+    last_question_unix: Option<i64>,
+}
+
+/// user action taken while offline, replayed once the connection is back
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub enum PendingAction {
+    Question {
+        text: String,
+        name: Option<String>,
+        session: Option<i64>,
+    },
+    Like {
+        id: i64,
+        like: bool,
+    },
+}
+
+/// placeholder in a template name that gets replaced with the current date
+pub const TEMPLATE_DATE_PLACEHOLDER: &str = "{date}";
+const TEMPLATES_KEY: &str = "templates";
+/// account token of the organizer's subscription
+const SUBSCRIPTION_KEY: &str = "subscription";
+/// membership of the last opened organization workspace
+const ORG_KEY: &str = "org";
+const TEMPLATES_MAX: usize = 10;
+const RECENT_EVENTS_KEY: &str = "recent_events";
+const RECENT_EVENTS_MAX: usize = 10;
+const NEW_EVENT_DRAFT_KEY: &str = "new_event_draft";
+const COLOR_SCHEME_KEY: &str = "color_scheme";
+/// language picked by the user, unset while the browser language is used
+const LANGUAGE_KEY: &str = "language";
+const TAB_ALERT_KEY: &str = "tab_alert";
+/// random token identifying this browser, e.g. towards poll votes
+const PARTICIPANT_KEY: &str = "participant";
+/// per-event stores are namespaced so event ids cannot collide with the keys above
+const EVENT_KEY_PREFIX: &str = "event:";
+
+/// bumped whenever stored data needs converting, see `MIGRATIONS`
+const SCHEMA_VERSION: usize = 1;
+const SCHEMA_VERSION_KEY: &str = "cache_version";
+/// `MIGRATIONS[n]` converts the data of schema version `n` to `n + 1`
+const MIGRATIONS: [fn(); SCHEMA_VERSION] = [migrate_event_keys];
+
+/// event opened in this browser, listed on the home page
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct RecentEvent {
+    pub id: String,
+    pub name: String,
+    /// moderator secret if the event was opened as its moderator
+    pub secret: Option<String>,
+    pub visited_unix: i64,
+}
+
+/// unfinished input of the new event form, restored after a reload
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+pub struct NewEventDraft {
+    pub name: String,
+    pub description: String,
+}
+
+impl NewEventDraft {
+    pub fn is_empty(&self) -> bool {
+        self.name.trim().is_empty() && self.description.trim().is_empty()
+    }
+}
+
+/// settings of an event saved by its organizer to create similar events from
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+pub struct EventTemplate {
+    pub name: String,
+    pub description: String,
+    /// moderation settings applied right after creating an event from this template
+    #[serde(default)]
+    pub settings: ModEvent,
+    /// branding, hidden live-ask branding and custom theme, only applied to new events entitled to them
+    #[serde(default)]
+    pub branding: ModEvent,
+}
+
+impl EventTemplate {
+    pub fn from_event(e: &EventInfo) -> Self {
+        Self {
+            name: e.data.name.clone(),
+            description: e.data.description.clone(),
+            settings: ModEvent {
+                screening: Some(e.flags.contains(EventFlags::SCREENING)),
+                question_names: Some(e.question_names),
+                question_length: Some(e.question_length),
+                question_languages: Some(e.question_languages.clone()),
+                downvotes: Some(e.downvotes_enabled()),
+                survey: Some(e.survey_enabled()),
+                //Note: the custom theme is premium only and kept with the branding
+                theme: Some(e.theme).filter(|theme| !matches!(theme, EventTheme::Custom)),
+                ..Default::default()
+            },
+            branding: ModEvent {
+                //Note: an uploaded logo belongs to the event it was uploaded for
+                branding: e.data.branding.clone().map(|branding| EventBranding {
+                    logo: None,
+                    ..branding
+                }),
+                hide_branding: Some(e.branding_hidden()),
+                theme: Some(e.theme).filter(|theme| matches!(theme, EventTheme::Custom)),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// name of a new event created from this template
+    pub fn event_name(&self) -> String {
+        self.name.replace(
+            TEMPLATE_DATE_PLACEHOLDER,
+            &Local::now().format("%F").to_string(),
+        )
+    }
 }
 
 pub struct LocalCache;
@@ -27,6 +164,98 @@ impl LocalCache {
         Self::set_state(event, store);
     }
 
+    pub fn is_downvoted(event: &str, id: i64) -> bool {
+        Self::get_state(event).downvotes.contains(&id)
+    }
+
+    pub fn set_downvote_state(event: &str, id: i64, downvote: bool) {
+        let mut store = Self::get_state(event);
+        if downvote {
+            store.downvotes.insert(id);
+        } else {
+            store.downvotes.remove(&id);
+        }
+        Self::set_state(event, store);
+    }
+
+    pub fn reactions(event: &str, id: i64) -> Vec<Reaction> {
+        let store = Self::get_state(event);
+        Reaction::ALL
+            .into_iter()
+            .filter(|reaction| store.reactions.contains(&(id, *reaction)))
+            .collect()
+    }
+
+    pub fn set_reaction_state(event: &str, id: i64, reaction: Reaction, add: bool) {
+        let mut store = Self::get_state(event);
+        if add {
+            store.reactions.insert((id, reaction));
+        } else {
+            store.reactions.remove(&(id, reaction));
+        }
+        Self::set_state(event, store);
+    }
+
+    pub fn poll_vote(event: &str, poll: i64) -> Option<usize> {
+        Self::get_state(event).polls.get(&poll).copied()
+    }
+
+    pub fn set_poll_vote(event: &str, poll: i64, option: usize) {
+        let mut store = Self::get_state(event);
+        store.polls.insert(poll, option);
+        Self::set_state(event, store);
+    }
+
+    pub fn is_word_cloud_answered(event: &str, cloud: i64) -> bool {
+        Self::get_state(event).word_clouds.contains(&cloud)
+    }
+
+    pub fn set_word_cloud_answered(event: &str, cloud: i64) {
+        let mut store = Self::get_state(event);
+        store.word_clouds.insert(cloud);
+        Self::set_state(event, store);
+    }
+
+    pub fn is_survey_answered(event: &str) -> bool {
+        Self::get_state(event).survey
+    }
+
+    pub fn set_survey_answered(event: &str) {
+        let mut store = Self::get_state(event);
+        store.survey = true;
+        Self::set_state(event, store);
+    }
+
+    pub fn web_push(event: &str) -> Option<String> {
+        Self::get_state(event).web_push
+    }
+
+    pub fn set_web_push(event: &str, endpoint: Option<String>) {
+        let mut store = Self::get_state(event);
+        store.web_push = endpoint;
+        Self::set_state(event, store);
+    }
+
+    pub fn raised_hand(event: &str) -> Option<RaisedHand> {
+        Self::get_state(event).raised_hand
+    }
+
+    pub fn set_raised_hand(event: &str, hand: Option<RaisedHand>) {
+        let mut store = Self::get_state(event);
+        store.raised_hand = hand;
+        Self::set_state(event, store);
+    }
+
+    pub fn last_question(event: &str) -> Option<i64> {
+        Self::get_state(event).last_question_unix
+    }
+
+    pub fn set_last_question(event: &str, time: i64) {
+        let mut store = Self::get_state(event);
+        store.last_question_unix = Some(time);
+        Self::set_state(event, store);
+    }
+
     pub fn add_unscreened_question(event: &str, q: &QuestionItem) {
         // log::info!("question pending review: {}", q.id);
         let mut store = Self::get_state(event);
@@ -50,11 +279,229 @@ impl LocalCache {
         state.unscreened
     }
 
+    pub fn pending_actions(event: &str) -> Vec<PendingAction> {
+        Self::get_state(event).pending
+    }
+
+    /// liking a question again while its like is still queued cancels both
+    pub fn queue_action(event: &str, action: PendingAction) {
+        let mut store = Self::get_state(event);
+
+        let queued_like = match &action {
+            PendingAction::Like { id, .. } => store
+                .pending
+                .iter()
+                .position(|a| matches!(a, PendingAction::Like { id: queued, .. } if queued == id)),
+            PendingAction::Question { .. } => None,
+        };
+
+        if let Some(index) = queued_like {
+            store.pending.remove(index);
+        } else {
+            store.pending.push(action);
+        }
+
+        Self::set_state(event, store);
+    }
+
+    /// empties the queue for replaying it
+    pub fn take_pending_actions(event: &str) -> Vec<PendingAction> {
+        let mut store = Self::get_state(event);
+        let pending = std::mem::take(&mut store.pending);
+        Self::set_state(event, store);
+        pending
+    }
+
+    /// puts actions that failed to replay back in front of the ones queued meanwhile
+    pub fn requeue_actions(event: &str, mut actions: Vec<PendingAction>) {
+        if actions.is_empty() {
+            return;
+        }
+
+        let mut store = Self::get_state(event);
+        actions.append(&mut store.pending);
+        store.pending = actions;
+        Self::set_state(event, store);
+    }
+
+    /// most recently saved first
+    pub fn templates() -> Vec<EventTemplate> {
+        load(TEMPLATES_KEY)
+    }
+
+    /// replaces an existing template of the same name
+    pub fn save_template(template: EventTemplate) {
+        let mut templates = Self::templates();
+        templates.retain(|t| t.name != template.name);
+        templates.insert(0, template);
+        templates.truncate(TEMPLATES_MAX);
+        store(TEMPLATES_KEY, &templates);
+    }
+
+    pub fn remove_template(name: &str) {
+        let mut templates = Self::templates();
+        templates.retain(|t| t.name != name);
+        store(TEMPLATES_KEY, &templates);
+    }
+
+    /// most recently visited first
+    pub fn recent_events() -> Vec<RecentEvent> {
+        load(RECENT_EVENTS_KEY)
+    }
+
+    /// a moderator visiting their event as a viewer keeps the moderator link
+    pub fn add_recent_event(mut event: RecentEvent) {
+        let mut events = Self::recent_events();
+
+        if let Some(index) = events.iter().position(|e| e.id == event.id) {
+            let previous = events.remove(index);
+            event.secret = event.secret.or(previous.secret);
+        }
+
+        events.insert(0, event);
+        events.truncate(RECENT_EVENTS_MAX);
+        store(RECENT_EVENTS_KEY, &events);
+    }
+
+    pub fn new_event_draft() -> Option<NewEventDraft> {
+        load::<Option<NewEventDraft>>(NEW_EVENT_DRAFT_KEY).filter(|draft| !draft.is_empty())
+    }
+
+    pub fn set_new_event_draft(draft: &NewEventDraft) {
+        if draft.is_empty() {
+            Self::clear_new_event_draft();
+        } else {
+            store(NEW_EVENT_DRAFT_KEY, draft);
+        }
+    }
+
+    pub fn clear_new_event_draft() {
+        LocalStorage::delete(NEW_EVENT_DRAFT_KEY);
+    }
+
+    pub fn color_scheme() -> ColorScheme {
+        load(COLOR_SCHEME_KEY)
+    }
+
+    pub fn set_color_scheme(scheme: ColorScheme) {
+        store(COLOR_SCHEME_KEY, &scheme);
+    }
+
+    pub fn language() -> Option<Language> {
+        load(LANGUAGE_KEY)
+    }
+
+    pub fn set_language(language: Language) {
+        store(LANGUAGE_KEY, &language);
+    }
+
+    /// moderators opted into a chime and flashing tab title on new questions
+    pub fn tab_alert() -> bool {
+        load(TAB_ALERT_KEY)
+    }
+
+    pub fn set_tab_alert(enabled: bool) {
+        store(TAB_ALERT_KEY, &enabled);
+    }
+
+    pub fn subscription() -> Option<String> {
+        load(SUBSCRIPTION_KEY)
+    }
+
+    pub fn set_subscription(account: &str) {
+        store(SUBSCRIPTION_KEY, account);
+    }
+
+    pub fn org() -> Option<OrgMembership> {
+        load(ORG_KEY)
+    }
+
+    pub fn set_org(membership: &OrgMembership) {
+        store(ORG_KEY, membership);
+    }
+
+    /// brings data written by older releases up to `SCHEMA_VERSION`, run once on startup
+    pub fn migrate() {
+        let version: usize = load(SCHEMA_VERSION_KEY);
+
+        if version > SCHEMA_VERSION {
+            log::warn!("local cache written by a newer release: {version}");
+            return;
+        }
+
+        for (from, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+            log::info!("migrate local cache from version {from}");
+            migration();
+        }
+
+        if version != SCHEMA_VERSION {
+            store(SCHEMA_VERSION_KEY, &SCHEMA_VERSION);
+        }
+    }
+
+    /// created on first use
+    pub fn participant() -> String {
+        let participant: String = load(PARTICIPANT_KEY);
+        if !participant.is_empty() {
+            return participant;
+        }
+
+        let participant = js_sys::Math::random().to_string();
+        store(PARTICIPANT_KEY, &participant);
+        participant
+    }
+
     fn get_state(event: &str) -> EventStore {
-        LocalStorage::get(event).unwrap_or_default()
+        load(&format!("{EVENT_KEY_PREFIX}{event}"))
     }
 
     fn set_state(event: &str, data: EventStore) {
-        LocalStorage::set(event, data).unwrap_throw();
+        store(&format!("{EVENT_KEY_PREFIX}{event}"), &data);
+    }
+}
+
+/// missing keys give the default, corrupt ones are dropped so they do not fail again
+fn load<T: DeserializeOwned + Default>(key: &str) -> T {
+    match LocalStorage::get(key) {
+        Ok(value) => value,
+        Err(StorageError::KeyNotFound(_)) => T::default(),
+        Err(e) => {
+            log::warn!("dropping corrupt local cache entry {key}: {e}");
+            LocalStorage::delete(key);
+            T::default()
+        }
+    }
+}
+
+/// a full or disabled storage only loses what is cached, the app keeps working
+fn store<T: Serialize + ?Sized>(key: &str, value: &T) {
+    if let Err(e) = LocalStorage::set(key, value) {
+        log::error!("local cache write error {key}: {e}");
+    }
+}
+
+/// version 0 stored the per-event data under the bare event id
+fn migrate_event_keys() {
+    let storage = LocalStorage::raw();
+    let keys: Vec<String> = (0..storage.length().unwrap_or_default())
+        .filter_map(|index| storage.key(index).ok().flatten())
+        .collect();
+
+    for key in keys {
+        if key.contains(':')
+            || [TEMPLATES_KEY, SUBSCRIPTION_KEY, ORG_KEY, RECENT_EVENTS_KEY].contains(&key.as_str())
+        {
+            continue;
+        }
+
+        //Note: version 0 event stores always had the `likes` field
+        let is_event_store = LocalStorage::get::<serde_json::Value>(&key)
+            .is_ok_and(|value| value.get("likes").is_some());
+
+        if is_event_store {
+            let data: EventStore = load(&key);
+            store(&format!("{EVENT_KEY_PREFIX}{key}"), &data);
+            LocalStorage::delete(&key);
+        }
     }
 }
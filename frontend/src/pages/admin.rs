@@ -1,9 +1,14 @@
-use shared::{GetUserInfo, UserInfo};
+use chrono::NaiveDateTime;
+use shared::{
+    parse_ip_range, AdminDashboard, BlockEntry, BlockTarget, Blocklist, EventInfo, GetUserInfo,
+    PromoCode, UserInfo, BLOCK_NOTE_MAX_LENGTH, PROMO_CODE_MAX_LENGTH,
+};
 use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
+use yew_router::prelude::*;
 
-use crate::{fetch, pwd::pwd_hash};
+use crate::{fetch, pwd::pwd_hash, routes::Route};
 
 use super::BASE_API;
 
@@ -17,12 +22,26 @@ pub struct AdminLogin {
     name: String,
     pwd: String,
     state: AdminState,
+    promo: PromoCode,
+    promo_result: Option<String>,
+    dashboard: Option<AdminDashboard>,
+    search: String,
+    /// `Some(None)` if the searched event was not found
+    found: Option<Option<EventInfo>>,
+    blocklist: Option<Blocklist>,
+    block: BlockEntry,
 }
 
 #[derive(Debug)]
 pub enum Input {
     Name,
     Pwd,
+    PromoCode,
+    PromoPercentOff,
+    PromoMaxUses,
+    Search,
+    BlockValue,
+    BlockNote,
 }
 
 #[allow(clippy::empty_structs_with_brackets)]
@@ -35,6 +54,15 @@ pub enum Msg {
     UserInfoResult(GetUserInfo),
     LoginResult(bool),
     InputChange(Input, InputEvent),
+    AddPromo,
+    PromoAdded(Option<PromoCode>),
+    RefreshDashboard,
+    DashboardFetched(Option<AdminDashboard>),
+    Search,
+    SearchResult(Option<EventInfo>),
+    Block,
+    Unblock(BlockEntry),
+    BlocklistFetched(Option<Blocklist>),
 }
 impl Component for AdminLogin {
     type Message = Msg;
@@ -47,25 +75,109 @@ impl Component for AdminLogin {
             name: String::new(),
             pwd: String::new(),
             state: AdminState::RequestingInfo,
+            promo: PromoCode::default(),
+            promo_result: None,
+            dashboard: None,
+            search: String::new(),
+            found: None,
+            blocklist: None,
+            block: BlockEntry::default(),
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::InputChange(input, c) => {
+                let target: HtmlInputElement = c.target_dyn_into().unwrap_throw();
+
                 match input {
-                    Input::Name => {
-                        let target: HtmlInputElement = c.target_dyn_into().unwrap_throw();
-                        self.name = target.value();
+                    Input::Name => self.name = target.value(),
+                    Input::Pwd => self.pwd = target.value(),
+                    Input::PromoCode => self.promo.code = target.value().trim().to_uppercase(),
+                    Input::PromoPercentOff => {
+                        self.promo.percent_off = target.value().parse().unwrap_or_default();
+                    }
+                    Input::PromoMaxUses => {
+                        self.promo.max_uses = target.value().parse().unwrap_or_default();
                     }
-                    Input::Pwd => {
-                        let target: HtmlInputElement = c.target_dyn_into().unwrap_throw();
-                        self.pwd = target.value();
+                    Input::Search => self.search = target.value().trim().to_string(),
+                    Input::BlockValue => {
+                        self.block.value = target.value().trim().to_string();
+                        //Note: anything that is no address or range is taken as a fingerprint
+                        self.block.target = if parse_ip_range(&self.block.value).is_some() {
+                            BlockTarget::IpRange
+                        } else {
+                            BlockTarget::Fingerprint
+                        };
                     }
+                    Input::BlockNote => self.block.note = target.value(),
                 }
 
                 true
             }
+            Msg::AddPromo => {
+                if !self.promo.is_valid() {
+                    return false;
+                }
+
+                request_add_promo(self.promo.clone(), ctx.link());
+
+                false
+            }
+            Msg::PromoAdded(promo) => {
+                self.promo_result = Some(match promo {
+                    Some(promo) => {
+                        self.promo = PromoCode::default();
+                        format!(
+                            "created '{}': {}% off, {} uses",
+                            promo.code, promo.percent_off, promo.max_uses
+                        )
+                    }
+                    None => String::from("creating the promo code failed"),
+                });
+
+                true
+            }
+            Msg::RefreshDashboard => {
+                request_dashboard(ctx.link());
+                false
+            }
+            Msg::DashboardFetched(dashboard) => {
+                self.dashboard = dashboard;
+                true
+            }
+            Msg::Search => {
+                if self.search.is_empty() {
+                    return false;
+                }
+
+                request_event(self.search.clone(), ctx.link());
+
+                false
+            }
+            Msg::SearchResult(event) => {
+                self.found = Some(event);
+                true
+            }
+            Msg::Block => {
+                if !self.block.is_valid() {
+                    return false;
+                }
+
+                request_edit_blocklist(std::mem::take(&mut self.block), false, ctx.link());
+
+                true
+            }
+            Msg::Unblock(entry) => {
+                request_edit_blocklist(entry, true, ctx.link());
+                false
+            }
+            Msg::BlocklistFetched(blocklist) => {
+                if blocklist.is_some() {
+                    self.blocklist = blocklist;
+                }
+                true
+            }
             Msg::Login => {
                 let name = self.name.clone();
                 let pwd = self.pwd.clone();
@@ -105,6 +217,8 @@ impl Component for AdminLogin {
             Msg::UserInfoResult(res) => {
                 if let Some(user) = res.user {
                     self.state = AdminState::LoggedIn(user);
+                    request_dashboard(ctx.link());
+                    request_blocklist(ctx.link());
                 } else {
                     self.state = AdminState::NotLoggedIn;
                 }
@@ -119,7 +233,7 @@ impl Component for AdminLogin {
         match &self.state {
             AdminState::NotLoggedIn => self.view_login(ctx),
             AdminState::RequestingInfo => Self::view_waiting(),
-            AdminState::LoggedIn(user) => Self::view_logged_in(ctx, user),
+            AdminState::LoggedIn(user) => self.view_logged_in(ctx, user),
         }
     }
 }
@@ -166,7 +280,7 @@ impl AdminLogin {
         }
     }
 
-    fn view_logged_in(ctx: &Context<Self>, user: &UserInfo) -> Html {
+    fn view_logged_in(&self, ctx: &Context<Self>, user: &UserInfo) -> Html {
         html! {
             <div class="newevent-bg">
                 <div class="title">{ "Admin Login" }</div>
@@ -177,6 +291,232 @@ impl AdminLogin {
                         { "logout" }
                     </button>
                 </div>
+                { self.view_dashboard(ctx) }
+                { self.view_search(ctx) }
+                { self.view_promo(ctx) }
+                { self.view_blocklist(ctx) }
+            </div>
+        }
+    }
+
+    fn view_dashboard(&self, ctx: &Context<Self>) -> Html {
+        let Some(dashboard) = &self.dashboard else {
+            return html! {};
+        };
+
+        let basis_points = dashboard
+            .server_errors
+            .saturating_mul(10_000)
+            .checked_div(dashboard.requests)
+            .unwrap_or_default();
+
+        html! {
+            <div class="form admin-dashboard">
+                <p>{ format!("server instance {}, connections and errors only cover this one", dashboard.instance) }</p>
+                <p>{ format!("websocket connections: {}", dashboard.connections) }</p>
+                <p>
+                    { format!(
+                        "server errors: {} of {} requests ({}.{:02}%)",
+                        dashboard.server_errors,
+                        dashboard.requests,
+                        basis_points.div_euclid(100),
+                        basis_points.rem_euclid(100)
+                    ) }
+                </p>
+                <table>
+                    <tr>
+                        <th>{ "day (utc)" }</th>
+                        <th>{ "events" }</th>
+                        <th>{ "upgrades" }</th>
+                    </tr>
+                    {
+                        dashboard.days.iter().map(|day| html! {
+                            <tr>
+                                <td>{ day.day.clone() }</td>
+                                <td>{ day.events }</td>
+                                <td>{ day.upgrades }</td>
+                            </tr>
+                        }).collect::<Html>()
+                    }
+                </table>
+                <button class="button-white" onclick={ctx.link().callback(|_| Msg::RefreshDashboard)}>
+                    { "refresh" }
+                </button>
+            </div>
+        }
+    }
+
+    fn view_search(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="form">
+                <div class="newevent">
+                    <div class="input-box">
+                        <input
+                            type="text"
+                            placeholder="public token of an event"
+                            value={self.search.clone()}
+                            oninput={ctx.link().callback(|input| Msg::InputChange(Input::Search,input))}
+                        />
+                    </div>
+                </div>
+                <button
+                    class="button-finish"
+                    disabled={self.search.is_empty()}
+                    onclick={ctx.link().callback(|_| Msg::Search)}
+                >
+                    { "find event" }
+                </button>
+                { Self::view_found(self.found.as_ref()) }
+            </div>
+        }
+    }
+
+    fn view_found(found: Option<&Option<EventInfo>>) -> Html {
+        match found {
+            None => html! {},
+            Some(None) => html! { <p>{ "event not found" }</p> },
+            Some(Some(e)) => {
+                let created = NaiveDateTime::from_timestamp_opt(e.create_time_unix, 0)
+                    .map(|date| date.format("%F %R").to_string())
+                    .unwrap_or_default();
+
+                html! {
+                    <div class="admin-event">
+                        <p>{ format!("'{}' ({} questions)", e.data.name, e.questions.len()) }</p>
+                        <p>
+                            { format!(
+                                "created: {created}, premium: {}, deleted: {}",
+                                e.is_premium(),
+                                e.is_deleted()
+                            ) }
+                        </p>
+                        {
+                            e.tokens.moderator_token.clone().map_or_else(
+                                || html! {},
+                                |secret| html! {
+                                    <Link<Route> to={Route::EventMod { id: e.tokens.public_token.clone(), secret }}>
+                                        { "open moderator view" }
+                                    </Link<Route>>
+                                },
+                            )
+                        }
+                    </div>
+                }
+            }
+        }
+    }
+
+    fn view_promo(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="form">
+                <div class="newevent">
+                    <div class="input-box">
+                        <input
+                            type="text"
+                            placeholder="promo code, e.g. RUSTCONF-24"
+                            value={self.promo.code.clone()}
+                            maxlength={PROMO_CODE_MAX_LENGTH.to_string()}
+                            oninput={ctx.link().callback(|input| Msg::InputChange(Input::PromoCode,input))}
+                        />
+                    </div>
+                    <div class="input-box">
+                        <input
+                            type="number"
+                            placeholder="percent off"
+                            min="1"
+                            max="100"
+                            value={(self.promo.percent_off > 0).then(|| self.promo.percent_off.to_string()).unwrap_or_default()}
+                            oninput={ctx.link().callback(|input| Msg::InputChange(Input::PromoPercentOff,input))}
+                        />
+                    </div>
+                    <div class="input-box">
+                        <input
+                            type="number"
+                            placeholder="max uses"
+                            min="1"
+                            value={(self.promo.max_uses > 0).then(|| self.promo.max_uses.to_string()).unwrap_or_default()}
+                            oninput={ctx.link().callback(|input| Msg::InputChange(Input::PromoMaxUses,input))}
+                        />
+                    </div>
+                </div>
+                <button
+                    class="button-finish"
+                    disabled={!self.promo.is_valid()}
+                    onclick={ctx.link().callback(|_| Msg::AddPromo)}
+                >
+                    { "create promo code" }
+                </button>
+                {
+                    self.promo_result.as_ref().map_or_else(
+                        || html! {},
+                        |result| html! { <p>{ result.clone() }</p> },
+                    )
+                }
+            </div>
+        }
+    }
+
+    fn view_blocklist(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="form admin-blocklist">
+                <div class="newevent">
+                    <div class="input-box">
+                        <input
+                            type="text"
+                            placeholder="ip range (e.g. 203.0.113.0/24) or fingerprint"
+                            value={self.block.value.clone()}
+                            oninput={ctx.link().callback(|input| Msg::InputChange(Input::BlockValue,input))}
+                        />
+                    </div>
+                    <div class="input-box">
+                        <input
+                            type="text"
+                            placeholder="note"
+                            value={self.block.note.clone()}
+                            maxlength={BLOCK_NOTE_MAX_LENGTH.to_string()}
+                            oninput={ctx.link().callback(|input| Msg::InputChange(Input::BlockNote,input))}
+                        />
+                    </div>
+                </div>
+                <button
+                    class="button-finish"
+                    disabled={!self.block.is_valid()}
+                    onclick={ctx.link().callback(|_| Msg::Block)}
+                >
+                    { "block" }
+                </button>
+                <table>
+                    <tr>
+                        <th>{ "blocked" }</th>
+                        <th>{ "note" }</th>
+                        <th>{ "since" }</th>
+                        <th></th>
+                    </tr>
+                    {
+                        self.blocklist.iter().flat_map(|blocklist| blocklist.entries.iter()).map(|entry| {
+                            let since = NaiveDateTime::from_timestamp_opt(entry.create_time_unix, 0)
+                                .map(|date| date.format("%F").to_string())
+                                .unwrap_or_default();
+                            let unblock = entry.clone();
+
+                            html! {
+                                <tr>
+                                    <td>{ entry.value.clone() }</td>
+                                    <td>{ entry.note.clone() }</td>
+                                    <td>{ since }</td>
+                                    <td>
+                                        <button
+                                            class="button-white"
+                                            onclick={ctx.link().callback(move |_| Msg::Unblock(unblock.clone()))}
+                                        >
+                                            { "unblock" }
+                                        </button>
+                                    </td>
+                                </tr>
+                            }
+                        }).collect::<Html>()
+                    }
+                </table>
             </div>
         }
     }
@@ -212,3 +552,63 @@ fn request_logout(link: &html::Scope<AdminLogin>) {
         Msg::UserInfoResult(GetUserInfo { user: None })
     });
 }
+
+fn request_dashboard(link: &html::Scope<AdminLogin>) {
+    link.send_future(async move {
+        match fetch::admin_dashboard(BASE_API).await {
+            Err(e) => {
+                log::error!("admin_dashboard error: {e}");
+                Msg::DashboardFetched(None)
+            }
+            Ok(dashboard) => Msg::DashboardFetched(Some(dashboard)),
+        }
+    });
+}
+
+fn request_event(id: String, link: &html::Scope<AdminLogin>) {
+    link.send_future(async move {
+        match fetch::admin_event(BASE_API, id).await {
+            Err(e) => {
+                log::error!("admin_event error: {e}");
+                Msg::SearchResult(None)
+            }
+            Ok(event) => Msg::SearchResult(Some(event)),
+        }
+    });
+}
+
+fn request_add_promo(promo: PromoCode, link: &html::Scope<AdminLogin>) {
+    link.send_future(async move {
+        match fetch::admin_add_promo(BASE_API, promo).await {
+            Err(e) => {
+                log::error!("admin_add_promo error: {e}");
+                Msg::PromoAdded(None)
+            }
+            Ok(promo) => Msg::PromoAdded(Some(promo)),
+        }
+    });
+}
+
+fn request_blocklist(link: &html::Scope<AdminLogin>) {
+    link.send_future(async move {
+        match fetch::admin_blocklist(BASE_API).await {
+            Err(e) => {
+                log::error!("admin_blocklist error: {e}");
+                Msg::BlocklistFetched(None)
+            }
+            Ok(blocklist) => Msg::BlocklistFetched(Some(blocklist)),
+        }
+    });
+}
+
+fn request_edit_blocklist(entry: BlockEntry, remove: bool, link: &html::Scope<AdminLogin>) {
+    link.send_future(async move {
+        match fetch::admin_edit_blocklist(BASE_API, entry, remove).await {
+            Err(e) => {
+                log::error!("admin_edit_blocklist error: {e}");
+                Msg::BlocklistFetched(None)
+            }
+            Ok(blocklist) => Msg::BlocklistFetched(Some(blocklist)),
+        }
+    });
+}
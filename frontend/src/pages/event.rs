@@ -1,27 +1,45 @@
-use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use chrono::{Duration, Utc};
 use const_format::formatcp;
 use events::{event_context, EventBridge};
+use gloo_timers::callback::{Interval, Timeout};
 use serde::Deserialize;
 use shared::{
-    EventFlags, EventInfo, GetEventResponse, ModEvent, ModQuestion, QuestionItem, States,
+    BulkAction, Entitlements, EventBranding, EventDelta, EventFlags, EventInfo, EventTheme,
+    EventUpdates, GetEventResponse, ModEvent, ModQuestion, ModQuestionsBulk, Poll, QuestionItem,
+    QuestionNames, QuestionTranslation, QuestionsPage, Reaction, Session, States, WordCloud,
+    WsMessage, QUESTIONS_PAGE_DEFAULT_LIMIT,
 };
-use std::{collections::HashMap, rc::Rc, str::FromStr};
-use wasm_bindgen::{JsCast, UnwrapThrowExt};
-use web_sys::HtmlAnchorElement;
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+    str::FromStr,
+};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue, UnwrapThrowExt};
+use web_sys::{Element, HtmlAnchorElement, HtmlElement, HtmlInputElement, MessageEvent};
 use yew::prelude::*;
 use yew_router::scope_ext::RouterScopeExt;
 use yewdux::prelude::*;
 
 use crate::{
     components::{
-        DeletePopup, EventMeta, EventSocket, Footer, ModPassword, ModTag, PasswordPopup, Question,
-        QuestionClickType, QuestionFlags, QuestionPopup, SharableTags, SharePopup, SocketResponse,
-        Upgrade,
+        Branding, ConfirmPopup, CopyButton, Countdown, DeletePopup, EventMeta, EventSkeleton,
+        EventSocket, Footer, InfiniteScroll, ModBlockedTerms, ModBranding, ModClusters,
+        ModCountdown, ModNotifications, ModPassword, ModPollCreate, ModQuestionCooldown,
+        ModQuestionLanguages, ModQuestionLength, ModQuestionLimits, ModReceipt, ModSeries,
+        ModSessions, ModSlug, ModStats, ModSummary, ModSurveyResults, ModTag, ModViewerCapacity,
+        ModWordCloudCreate, PasswordPopup, PollItem, Qr, Question, QuestionClickType,
+        QuestionFlags, QuestionPopup, SharableTags, ShareButton, SharePopup, ShortcutsPopup,
+        SocketResponse, SpeakerQueueList, SurveyForm, Upgrade, VirtualFeed, WordCloudItem,
     },
     environment::{la_env, LiveAskEnv},
     fetch,
-    local_cache::LocalCache,
-    tracking, GlobalEvent, State,
+    i18n::{self, tr, tr_args},
+    local_cache::{EventTemplate, LocalCache, PendingAction, RecentEvent},
+    routes::Route,
+    store::{ConnectionStore, EventStore, UiStore},
+    tab_alert::TabAlert,
+    time::{format_date, format_date_time, format_time, local_timezone},
+    tracking, GlobalEvent, Toast,
 };
 
 enum Mode {
@@ -33,6 +51,16 @@ enum Mode {
 pub struct Props {
     pub id: AttrValue,
     pub secret: Option<String>,
+    /// rendered inside an iframe of the organizer's website
+    #[prop_or_default]
+    pub embedded: bool,
+}
+
+/// moderator actions waiting for confirmation
+enum Confirm {
+    HideQuestion(i64),
+    BulkHide,
+    State(States),
 }
 
 pub enum LoadingState {
@@ -60,6 +88,21 @@ pub const BASE_SOCKET_LOCAL: &str = "ws://localhost:8090";
 
 const FREE_EVENT_DURATION_DAYS: i64 = 7;
 
+/// `postMessage` protocol with the page embedding `/embed/{id}`, see `inline-assets/embed.js`
+const EMBED_HEIGHT_MESSAGE: &str = "liveask:height";
+const EMBED_QUESTIONS_MESSAGE: &str = "liveask:questions";
+/// sent by the embedding page to open the ask dialog
+const EMBED_ASK_MESSAGE: &str = "liveask:ask";
+/// consecutive failed socket connects before falling back to long polling
+const LONG_POLL_AFTER_SOCKET_FAILURES: u32 = 3;
+const LONG_POLL_RETRY_MS: u32 = 5000;
+
+/// typing count is dropped if no update arrives in time (server considers signals for 5s)
+const TYPING_EXPIRE_MS: u32 = 6000;
+
+/// keys of the moderator shortcut layer, listed in `ShortcutsPopup`
+const SHORTCUT_KEYS: &[&str] = &["j", "k", "a", "h", "p", "/", "?", "Escape"];
+
 #[derive(Debug, Default, Deserialize)]
 struct QueryParams {
     #[serde(rename = "token")]
@@ -68,7 +111,6 @@ struct QueryParams {
 
 pub struct Event {
     current_event_id: String,
-    copied_to_clipboard: bool,
     query_params: QueryParams,
     mode: Mode,
     tags: SharableTags,
@@ -77,11 +119,57 @@ pub struct Event {
     hidden: Vec<Rc<QuestionItem>>,
     unscreened: Vec<Rc<QuestionItem>>,
     loading_state: LoadingState,
-    state: Rc<State>,
-    dispatch: Dispatch<State>,
+    store: Rc<EventStore>,
+    dispatch: Dispatch<EventStore>,
+    ui: Rc<UiStore>,
+    ui_dispatch: Dispatch<UiStore>,
     events: EventBridge<GlobalEvent>,
     socket_url: String,
     manual_reconnect: bool,
+    socket_disconnected: bool,
+    socket_failures: u32,
+    long_polling: bool,
+    long_poll_retry: Option<Timeout>,
+    typing: usize,
+    typing_timeout: Option<Timeout>,
+    moderator_online: bool,
+    /// selected agenda session, `None` shows the questions of all sessions
+    session: Option<i64>,
+    /// ticks while a scheduled start or end is ahead
+    schedule_timer: Option<Interval>,
+    schedule_last_tick: i64,
+    template_saved: bool,
+    /// questions and likes queued while offline
+    pending: Vec<PendingAction>,
+    /// the visit is listed on the home page once the event loaded
+    visit_recorded: bool,
+    /// last document height posted to the embedding page
+    embed_height: i32,
+    /// last question count posted to the embedding page
+    embed_questions: Option<usize>,
+    /// chime and flashing title on new questions while a moderator's tab is in the background
+    tab_alert: TabAlert,
+    embed_listener: Option<Closure<dyn Fn(MessageEvent)>>,
+    /// read out by screen readers when someone else asks a question
+    announcement: String,
+    /// question the moderator shortcuts act on
+    selected: Option<i64>,
+    /// questions the moderator picked for a bulk action
+    bulk_selection: HashSet<i64>,
+    bulk_tag: String,
+    /// moderator filter of the question lists
+    search: String,
+    search_ref: NodeRef,
+    shortcuts_help: bool,
+    shortcut_listener: Option<Closure<dyn Fn(KeyboardEvent)>>,
+    confirm: Option<Confirm>,
+    state_select: NodeRef,
+    /// next page of questions is requested
+    loading_page: bool,
+    /// the event is at its viewer capacity, the socket waits for a free slot
+    waiting_room: bool,
+    /// question texts translated on request of the viewer
+    translations: HashMap<i64, String>,
 }
 pub enum Msg {
     FeedbackClick,
@@ -93,13 +181,41 @@ pub enum Msg {
     QuestionClick((i64, QuestionClickType)),
     QuestionUpdated(i64),
     ModDelete,
-    ModExport,
+    /// file of the export endpoint, e.g. `export.csv`
+    ModExport(&'static str),
     ModStateChange(yew::Event),
+    ModQuestionNamesChange(yew::Event),
+    ModThemeChange(yew::Event),
     StateChanged,
     PasswordSet,
-    CopyLink,
     ModEditScreening,
+    ModEditDownvotes,
+    ModEditSurvey,
+    ModToggleTabAlert,
+    ModEditHideBranding,
+    ModSaveTemplate,
+    ModDuplicate,
+    Duplicated(Option<EventInfo>),
+    SelectSession(Option<i64>),
+    TypingExpired,
+    PendingReplayed,
+    ScheduleTick,
+    LongPoll,
+    Updates(Option<EventUpdates>),
     GlobalEvent(GlobalEvent),
+    /// `KeyboardEvent::key` of a moderator shortcut
+    Shortcut(String),
+    Search(InputEvent),
+    Bulk(BulkAction),
+    BulkTagInput(InputEvent),
+    BulkClear,
+    BulkApplied(bool),
+    ShortcutsHelp(bool),
+    Toast(Toast),
+    Confirmed(bool),
+    LoadPage,
+    PageLoaded(Option<QuestionsPage>),
+    Translated(Option<QuestionTranslation>),
 }
 impl Component for Event {
     type Message = Msg;
@@ -108,9 +224,20 @@ impl Component for Event {
     fn create(ctx: &Context<Self>) -> Self {
         let event_id = ctx.props().id.to_string();
 
-        request_fetch(event_id.clone(), ctx.props().secret.clone(), ctx.link());
+        request_fetch(
+            event_id.clone(),
+            ctx.props().secret.clone(),
+            ctx.props()
+                .secret
+                .is_none()
+                .then_some(QUESTIONS_PAGE_DEFAULT_LIMIT),
+            ctx.link(),
+        );
 
-        let socket_url = format!("{BASE_SOCKET}/push/{event_id}",);
+        let socket_url = ctx.props().secret.as_ref().map_or_else(
+            || format!("{BASE_SOCKET}/push/{event_id}?encoding=msgpack"),
+            |secret| format!("{BASE_SOCKET}/push/{event_id}?encoding=msgpack&secret={secret}"),
+        );
 
         let query_params = ctx
             .link()
@@ -126,28 +253,75 @@ impl Component for Event {
             .unwrap_throw()
             .subscribe(ctx.link().callback(Msg::GlobalEvent));
 
-        let dispatch = Dispatch::<State>::subscribe(Callback::noop());
+        let dispatch = Dispatch::<EventStore>::subscribe(Callback::noop());
+        let ui_dispatch = Dispatch::<UiStore>::subscribe(Callback::noop());
+
+        let tab_alert = TabAlert::new(ctx.props().secret.is_some() && LocalCache::tab_alert());
 
         Self {
             current_event_id: event_id,
             query_params,
-            copied_to_clipboard: false,
             mode: if ctx.props().secret.is_some() {
                 Mode::Moderator
             } else {
                 Mode::Viewer
             },
             loading_state: LoadingState::Loading,
-            state: dispatch.get(),
+            store: dispatch.get(),
+            ui: ui_dispatch.get(),
             tags: Rc::new(HashMap::new()),
             unanswered: Vec::new(),
             answered: Vec::new(),
             hidden: Vec::new(),
             unscreened: Vec::new(),
             dispatch,
+            ui_dispatch,
             events,
             socket_url,
             manual_reconnect: false,
+            socket_disconnected: false,
+            socket_failures: 0,
+            long_polling: false,
+            long_poll_retry: None,
+            typing: 0,
+            typing_timeout: None,
+            moderator_online: false,
+            session: None,
+            schedule_timer: None,
+            schedule_last_tick: Utc::now().timestamp(),
+            template_saved: false,
+            pending: LocalCache::pending_actions(&event_id),
+            visit_recorded: false,
+            announcement: String::new(),
+            embed_height: 0,
+            embed_questions: None,
+            tab_alert,
+            embed_listener: ctx
+                .props()
+                .embedded
+                .then(|| embed_listener(ctx.link().clone())),
+            selected: None,
+            bulk_selection: HashSet::new(),
+            bulk_tag: String::new(),
+            search: String::new(),
+            search_ref: NodeRef::default(),
+            shortcuts_help: false,
+            shortcut_listener: ctx
+                .props()
+                .secret
+                .is_some()
+                .then(|| shortcut_listener(ctx.link().clone())),
+            confirm: None,
+            state_select: NodeRef::default(),
+            loading_page: false,
+            waiting_room: false,
+            translations: HashMap::new(),
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if ctx.props().embedded {
+            self.post_embed_state();
         }
     }
 
@@ -157,20 +331,14 @@ impl Component for Event {
                 log::info!("payment captured");
                 false
             }
-            Msg::QuestionClick((id, kind)) => {
-                self.on_question_click(&kind, id, ctx);
-                false
-            }
+            Msg::QuestionClick((id, kind)) => self.on_question_click(&kind, id, ctx),
             Msg::QuestionUpdated(_id) => {
                 //Note: we wait for the question socket event to poll
                 false
             }
-            Msg::CopyLink => {
-                self.copied_to_clipboard = true;
-                gloo_utils::window()
-                    .navigator()
-                    .clipboard()
-                    .map(|c| c.write_text(&self.moderator_url()));
+            Msg::PendingReplayed => {
+                self.pending = LocalCache::pending_actions(&self.current_event_id);
+                self.refetch(ctx);
                 true
             }
             Msg::Socket(msg) => self.handle_socket(msg, ctx),
@@ -180,11 +348,145 @@ impl Component for Event {
                     ev.target().unwrap_throw().dyn_into().unwrap_throw();
                 let new_state = States::from_str(e.value().as_str()).unwrap_throw();
 
+                if matches!(new_state, States::Closed | States::Archived) {
+                    self.confirm = Some(Confirm::State(new_state));
+                    return true;
+                }
+
+                self.request_state_change(new_state, ctx);
+
+                false
+            }
+
+            Msg::ModQuestionNamesChange(ev) => {
+                let e: web_sys::HtmlSelectElement =
+                    ev.target().unwrap_throw().dyn_into().unwrap_throw();
+                let question_names = e
+                    .value()
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(QuestionNames::from_value)
+                    .unwrap_throw();
+
+                request_event_change(
+                    self.current_event_id.clone(),
+                    ctx.props().secret.clone(),
+                    ModEvent {
+                        question_names: Some(question_names),
+                        ..Default::default()
+                    },
+                    ctx.link(),
+                );
+
+                false
+            }
+            Msg::ModThemeChange(ev) => {
+                let e: web_sys::HtmlSelectElement =
+                    ev.target().unwrap_throw().dyn_into().unwrap_throw();
+                let theme = e
+                    .value()
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(EventTheme::from_value)
+                    .unwrap_throw();
+
+                request_event_change(
+                    self.current_event_id.clone(),
+                    ctx.props().secret.clone(),
+                    ModEvent {
+                        theme: Some(theme),
+                        ..Default::default()
+                    },
+                    ctx.link(),
+                );
+
+                false
+            }
+
+            Msg::LongPoll => {
+                self.long_poll_retry = None;
+                if self.long_polling {
+                    request_updates(
+                        self.current_event_id.clone(),
+                        self.store
+                            .event
+                            .as_ref()
+                            .map(|e| e.version)
+                            .unwrap_or_default(),
+                        ctx.link(),
+                    );
+                }
+                false
+            }
+
+            Msg::Updates(updates) => self.handle_updates(updates, ctx),
+
+            Msg::TypingExpired => {
+                self.typing = 0;
+                self.typing_timeout = None;
+                true
+            }
+
+            Msg::ModEditDownvotes => {
+                request_event_change(
+                    self.current_event_id.clone(),
+                    ctx.props().secret.clone(),
+                    ModEvent {
+                        downvotes: Some(
+                            self.store
+                                .event
+                                .as_ref()
+                                .is_some_and(|e| !e.info.downvotes_enabled()),
+                        ),
+                        ..Default::default()
+                    },
+                    ctx.link(),
+                );
+
+                false
+            }
+
+            Msg::SelectSession(session) => {
+                self.session = session;
+                self.init_event();
+                true
+            }
+
+            Msg::ModToggleTabAlert => {
+                let enabled = !self.tab_alert.enabled();
+                LocalCache::set_tab_alert(enabled);
+                self.tab_alert.set_enabled(enabled);
+                true
+            }
+            Msg::ModEditSurvey => {
+                request_event_change(
+                    self.current_event_id.clone(),
+                    ctx.props().secret.clone(),
+                    ModEvent {
+                        survey: Some(
+                            self.store
+                                .event
+                                .as_ref()
+                                .is_some_and(|e| !e.info.survey_enabled()),
+                        ),
+                        ..Default::default()
+                    },
+                    ctx.link(),
+                );
+
+                false
+            }
+            Msg::ModEditHideBranding => {
                 request_event_change(
                     self.current_event_id.clone(),
                     ctx.props().secret.clone(),
                     ModEvent {
-                        state: Some(shared::EventState { state: new_state }),
+                        hide_branding: Some(
+                            self.store
+                                .event
+                                .as_ref()
+                                .is_some_and(|e| !e.info.branding_hidden()),
+                        ),
                         ..Default::default()
                     },
                     ctx.link(),
@@ -193,13 +495,22 @@ impl Component for Event {
                 false
             }
 
+            Msg::ModSaveTemplate => {
+                if let Some(e) = &self.store.event {
+                    LocalCache::save_template(EventTemplate::from_event(&e.info));
+                    self.template_saved = true;
+                }
+
+                true
+            }
+
             Msg::ModEditScreening => {
                 request_event_change(
                     self.current_event_id.clone(),
                     ctx.props().secret.clone(),
                     ModEvent {
                         screening: Some(
-                            self.state
+                            self.store
                                 .event
                                 .as_ref()
                                 .is_some_and(|e| !e.info.is_screening()),
@@ -215,6 +526,28 @@ impl Component for Event {
                 self.events.emit(GlobalEvent::DeletePopup);
                 false
             }
+            Msg::ModDuplicate => {
+                if let Some(secret) = ctx.props().secret.clone() {
+                    request_duplicate(self.current_event_id.clone(), secret, ctx.link());
+                }
+                false
+            }
+            Msg::Duplicated(event) => {
+                let Some(event) = event else {
+                    self.events
+                        .emit(GlobalEvent::Toast(Toast::error("toast-duplicate-failed")));
+                    return false;
+                };
+
+                ctx.link()
+                    .navigator()
+                    .unwrap_throw()
+                    .push(&Route::EventMod {
+                        id: event.tokens.public_token,
+                        secret: event.tokens.moderator_token.unwrap_throw(),
+                    });
+                false
+            }
             Msg::ShareEventClick => {
                 self.events.emit(GlobalEvent::OpenSharePopup);
                 false
@@ -230,28 +563,125 @@ impl Component for Event {
                 false
             }
             Msg::Fetched(res) => self.handle_fetched(res, ctx),
-            Msg::ModExport => {
-                self.export_event();
+            Msg::ModExport(file) => {
+                self.export_event(file);
                 false
             }
             Msg::PasswordSet => {
-                request_fetch(
-                    self.current_event_id.clone(),
-                    ctx.props().secret.clone(),
-                    ctx.link(),
-                );
+                self.refetch(ctx);
                 false
             }
+            Msg::ScheduleTick => self.on_schedule_tick(),
             Msg::GlobalEvent(ev) => self.handle_global_event(ev),
+            Msg::Shortcut(key) => self.on_shortcut(&key, ctx),
+            Msg::Search(ev) => {
+                let input: HtmlInputElement = ev.target_dyn_into().unwrap_throw();
+                self.search = input.value();
+                self.init_event();
+                true
+            }
+            Msg::Bulk(BulkAction::Hide) => {
+                self.confirm = Some(Confirm::BulkHide);
+                true
+            }
+            Msg::Bulk(action) => {
+                self.request_bulk(action, ctx);
+                false
+            }
+            Msg::BulkTagInput(ev) => {
+                let input: HtmlInputElement = ev.target_dyn_into().unwrap_throw();
+                self.bulk_tag = input.value();
+                true
+            }
+            Msg::BulkClear | Msg::BulkApplied(true) => {
+                self.bulk_selection.clear();
+                self.bulk_tag.clear();
+                true
+            }
+            Msg::BulkApplied(false) => {
+                self.events
+                    .emit(GlobalEvent::Toast(Toast::error("toast-moderation-failed")));
+                false
+            }
+            Msg::ShortcutsHelp(show) => {
+                self.shortcuts_help = show;
+                true
+            }
+            Msg::Toast(toast) => {
+                self.events.emit(GlobalEvent::Toast(toast));
+                false
+            }
+            Msg::Confirmed(confirmed) => {
+                self.on_confirmed(confirmed, ctx);
+                true
+            }
+            Msg::LoadPage => {
+                if self.loading_page {
+                    return false;
+                }
+
+                let Some(offset) = self.store.event.as_ref().map(|e| e.info.questions.len()) else {
+                    return false;
+                };
+
+                self.loading_page = true;
+                request_questions_page(self.current_event_id.clone(), offset, ctx.link());
+                true
+            }
+            Msg::PageLoaded(page) => {
+                self.loading_page = false;
+                if let Some(page) = page {
+                    self.dispatch
+                        .reduce(|old| (*old).clone().append_questions(page).into());
+                    self.store = self.dispatch.get();
+                    self.init_event();
+                }
+                true
+            }
+            Msg::Translated(translation) => {
+                //Note: the viewer may have switched languages in the meantime
+                match translation {
+                    Some(translation) if translation.language == i18n::language().code() => {
+                        self.translations
+                            .insert(translation.question_id, translation.text);
+                        true
+                    }
+                    Some(_) => false,
+                    None => {
+                        self.events
+                            .emit(GlobalEvent::Toast(Toast::error("toast-translation-failed")));
+                        false
+                    }
+                }
+            }
         }
     }
 
     fn destroy(&mut self, _ctx: &Context<Self>) {
-        self.dispatch.reduce(|_| State::default().into());
+        self.dispatch.set(EventStore::default());
+        self.ui_dispatch.set(UiStore::default());
+        Dispatch::<ConnectionStore>::new().set(ConnectionStore::default());
+
+        if let Some(listener) = self.embed_listener.take() {
+            let _ = gloo_utils::window()
+                .remove_event_listener_with_callback("message", listener.as_ref().unchecked_ref());
+        }
+
+        if let Some(listener) = self.shortcut_listener.take() {
+            let _ = gloo_utils::window()
+                .remove_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref());
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let msg = ctx.link().callback(Msg::Socket);
+        let branding_hidden = self
+            .store
+            .event
+            .as_ref()
+            .is_some_and(|e| e.info.branding_hidden());
+        //Note: the moderator keeps the footer to reach support
+        let hide_footer = ctx.props().embedded || (!self.is_mod() && branding_hidden);
         html! {
             <>
                 <div class="event">
@@ -261,13 +691,78 @@ impl Component for Event {
                         {msg}
                     />
                     { self.view_internal(ctx) }
+                    <div class="sr-only" role="status" aria-live="polite" aria-atomic="true">
+                        { self.announcement.clone() }
+                    </div>
                 </div>
-                <Footer />
+                { if hide_footer { html!{} } else { html!{ <Footer /> } } }
             </>
         }
     }
 }
 
+fn post_embed_message(parent: &web_sys::Window, kind: &str, key: &str, value: JsValue) {
+    let msg = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&msg, &"type".into(), &kind.into());
+    let _ = js_sys::Reflect::set(&msg, &key.into(), &value);
+
+    if let Err(e) = parent.post_message(&msg, "*") {
+        log::error!("embed message error: {e:?}");
+    }
+}
+
+/// listens for commands of the embedding page
+fn embed_listener(link: html::Scope<Event>) -> Closure<dyn Fn(MessageEvent)> {
+    let listener = Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
+        let kind = js_sys::Reflect::get(&e.data(), &"type".into())
+            .ok()
+            .and_then(|kind| kind.as_string());
+
+        if kind.as_deref() == Some(EMBED_ASK_MESSAGE) {
+            link.send_message(Msg::AskQuestionClick);
+        }
+    });
+
+    gloo_utils::window()
+        .add_event_listener_with_callback("message", listener.as_ref().unchecked_ref())
+        .unwrap_throw();
+
+    listener
+}
+
+/// forwards shortcut keys unless the moderator is typing or a popup is open
+fn shortcut_listener(link: html::Scope<Event>) -> Closure<dyn Fn(KeyboardEvent)> {
+    let listener = Closure::<dyn Fn(KeyboardEvent)>::new(move |e: KeyboardEvent| {
+        if e.ctrl_key() || e.meta_key() || e.alt_key() || !SHORTCUT_KEYS.contains(&e.key().as_str())
+        {
+            return;
+        }
+
+        let typing = e
+            .target()
+            .and_then(|target| target.dyn_into::<Element>().ok())
+            .is_some_and(|element| {
+                matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+            });
+        let popup_open = gloo_utils::document()
+            .body()
+            .is_some_and(|body| body.class_list().contains("modal-open"));
+
+        if typing || popup_open {
+            return;
+        }
+
+        e.prevent_default();
+        link.send_message(Msg::Shortcut(e.key()));
+    });
+
+    gloo_utils::window()
+        .add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
+        .unwrap_throw();
+
+    listener
+}
+
 #[allow(clippy::needless_pass_by_value)]
 fn request_toggle_hide(
     event: String,
@@ -280,9 +775,12 @@ fn request_toggle_hide(
             hide: !item.hidden,
             answered: item.answered,
             screened: !item.screening,
+            pinned: item.pinned,
+            shadow_ban: None,
         };
         if let Err(res) = fetch::mod_question(BASE_API, event, secret, item.id, modify).await {
             log::error!("hide error: {}", res);
+            return Msg::Toast(Toast::error("toast-moderation-failed"));
         }
 
         Msg::QuestionUpdated(item.id)
@@ -301,10 +799,13 @@ fn request_toggle_answered(
             hide: item.hidden,
             answered: !item.answered,
             screened: !item.screening,
+            pinned: item.pinned,
+            shadow_ban: None,
         };
 
         if let Err(e) = fetch::mod_question(BASE_API, event, secret, item.id, modify).await {
             log::error!("mod_questio error: {e}");
+            return Msg::Toast(Toast::error("toast-moderation-failed"));
         }
 
         Msg::QuestionUpdated(item.id)
@@ -312,7 +813,7 @@ fn request_toggle_answered(
 }
 
 #[allow(clippy::needless_pass_by_value)]
-fn request_approve_question(
+fn request_toggle_pin(
     event: String,
     secret: String,
     item: QuestionItem,
@@ -320,96 +821,381 @@ fn request_approve_question(
 ) {
     link.send_future(async move {
         let modify = ModQuestion {
-            hide: false,
-            answered: false,
-            screened: true,
+            hide: item.hidden,
+            answered: item.answered,
+            screened: !item.screening,
+            pinned: !item.pinned,
+            shadow_ban: None,
         };
 
         if let Err(e) = fetch::mod_question(BASE_API, event, secret, item.id, modify).await {
-            log::error!("mod_questio error: {e}");
+            log::error!("pin question error: {e}");
+            return Msg::Toast(Toast::error("toast-moderation-failed"));
         }
 
         Msg::QuestionUpdated(item.id)
     });
 }
 
-fn request_like(event: String, id: i64, like: bool, link: &html::Scope<Event>) {
+fn request_question_note(
+    event: String,
+    secret: String,
+    id: i64,
+    note: String,
+    link: &html::Scope<Event>,
+) {
     link.send_future(async move {
-        if let Err(e) = fetch::like_question(BASE_API, event, id, like).await {
-            log::error!("like question error: {e}");
+        if let Err(e) = fetch::mod_question_note(BASE_API, event, secret, id, note).await {
+            log::error!("question note error: {e}");
+            return Msg::Toast(Toast::error("toast-moderation-failed"));
         }
 
         Msg::QuestionUpdated(id)
     });
 }
 
-//TODO: dedup
-fn request_fetch(id: String, secret: Option<String>, link: &html::Scope<Event>) {
+#[allow(clippy::needless_pass_by_value)]
+fn request_toggle_shadow_ban(
+    event: String,
+    secret: String,
+    item: QuestionItem,
+    link: &html::Scope<Event>,
+) {
     link.send_future(async move {
-        let res = fetch::fetch_event(BASE_API, id, secret).await;
-
-        res.map_or(Msg::Fetched(None), |val| Msg::Fetched(Some(val)))
-    });
-}
+        let modify = ModQuestion {
+            hide: item.hidden,
+            answered: item.answered,
+            screened: !item.screening,
+            pinned: item.pinned,
+            shadow_ban: Some(!item.shadowed),
+        };
 
-fn request_capture(id: String, order_id: String, link: &html::Scope<Event>) {
-    link.send_future(async move {
-        if let Err(e) = fetch::mod_premium_capture(BASE_API, id, order_id).await {
-            log::error!("mod_premium_capture error: {e}");
+        if let Err(e) = fetch::mod_question(BASE_API, event, secret, item.id, modify).await {
+            log::error!("shadow ban error: {e}");
+            return Msg::Toast(Toast::error("toast-moderation-failed"));
         }
 
-        Msg::Captured
+        Msg::QuestionUpdated(item.id)
     });
 }
 
-fn request_event_change(
-    id: String,
-    secret: Option<String>,
-    change: ModEvent,
+#[allow(clippy::needless_pass_by_value)]
+fn request_approve_question(
+    event: String,
+    secret: String,
+    item: QuestionItem,
     link: &html::Scope<Event>,
 ) {
     link.send_future(async move {
-        if let Err(e) = fetch::mod_edit_event(BASE_API, id, secret.unwrap_throw(), change).await {
-            log::error!("mod_edit_event error: {e}");
+        let modify = ModQuestion {
+            hide: false,
+            answered: false,
+            screened: true,
+            pinned: false,
+            shadow_ban: None,
+        };
+
+        if let Err(e) = fetch::mod_question(BASE_API, event, secret, item.id, modify).await {
+            log::error!("mod_questio error: {e}");
+            return Msg::Toast(Toast::error("toast-moderation-failed"));
         }
 
-        Msg::StateChanged
+        Msg::QuestionUpdated(item.id)
     });
 }
 
-const fn question_state(q: &QuestionItem) -> &str {
-    if q.answered {
-        "answered"
-    } else if q.hidden {
-        "hidden"
-    } else {
-        "asked"
-    }
+fn request_translate(event: String, secret: Option<String>, id: i64, link: &html::Scope<Event>) {
+    let language = i18n::language().code();
+
+    link.send_future(async move {
+        match fetch::translate_question(BASE_API, event, secret, id, language).await {
+            Ok(translation) => Msg::Translated(Some(translation)),
+            Err(e) => {
+                log::error!("translate question error: {e}");
+                Msg::Translated(None)
+            }
+        }
+    });
 }
 
-impl Event {
-    fn is_premium(&self) -> bool {
-        self.state
-            .event
-            .as_ref()
-            .is_some_and(|e| e.info.is_premium())
-    }
+/// counts a failed socket connect, `true` once enough failed in a row to fall back to long polling
+fn count_socket_failure(failures: &mut u32) -> bool {
+    *failures = failures.saturating_add(1);
+    *failures >= LONG_POLL_AFTER_SOCKET_FAILURES
+}
 
-    fn export_event(&self) {
-        if !self.is_premium() {
-            return;
+fn request_like(event: String, id: i64, like: bool, link: &html::Scope<Event>) {
+    link.send_future(async move {
+        if let Err(e) =
+            fetch::like_question(BASE_API, event.clone(), id, like, LocalCache::participant()).await
+        {
+            log::error!("like question error: {e}");
+            // revert so the local record matches the server count again
+            LocalCache::set_like_state(&event, id, !like);
+            return Msg::Toast(Toast::error("toast-vote-failed"));
         }
 
-        tracking::track_event(tracking::EVNT_EXPORT);
+        Msg::QuestionUpdated(id)
+    });
+}
 
-        let name = self
-            .state
+/// sends the actions queued while offline in order, questions that fail stay queued
+fn request_replay(event: String, actions: Vec<PendingAction>, link: &html::Scope<Event>) {
+    link.send_future(async move {
+        let mut failed = Vec::new();
+
+        for action in actions {
+            match &action {
+                PendingAction::Question {
+                    text,
+                    name,
+                    session,
+                } => {
+                    match fetch::add_question(
+                        BASE_API,
+                        event.clone(),
+                        text.clone(),
+                        name.clone(),
+                        None,
+                        *session,
+                        LocalCache::participant(),
+                    )
+                    .await
+                    {
+                        Ok(item) => {
+                            LocalCache::set_like_state(&event, item.id, true);
+                            LocalCache::set_last_question(&event, item.create_time_unix);
+                            if item.screening {
+                                LocalCache::add_unscreened_question(&event, &item);
+                            }
+                        }
+                        Err(e) => {
+                            log::error!("replay question error: {e}");
+                            failed.push(action);
+                        }
+                    }
+                }
+                PendingAction::Like { id, like } => {
+                    if let Err(e) = fetch::like_question(
+                        BASE_API,
+                        event.clone(),
+                        *id,
+                        *like,
+                        LocalCache::participant(),
+                    )
+                    .await
+                    {
+                        log::error!("replay like error: {e}");
+                        LocalCache::set_like_state(&event, *id, !like);
+                    }
+                }
+            }
+        }
+
+        LocalCache::requeue_actions(&event, failed);
+
+        Msg::PendingReplayed
+    });
+}
+
+fn request_downvote(event: String, id: i64, downvote: bool, link: &html::Scope<Event>) {
+    link.send_future(async move {
+        if let Err(e) = fetch::downvote_question(BASE_API, event.clone(), id, downvote).await {
+            log::error!("downvote question error: {e}");
+            // revert so the local record matches the server count again
+            LocalCache::set_downvote_state(&event, id, !downvote);
+            return Msg::Toast(Toast::error("toast-vote-failed"));
+        }
+
+        Msg::QuestionUpdated(id)
+    });
+}
+
+fn request_reaction(
+    event: String,
+    id: i64,
+    reaction: Reaction,
+    add: bool,
+    link: &html::Scope<Event>,
+) {
+    link.send_future(async move {
+        if let Err(e) = fetch::react_question(BASE_API, event.clone(), id, reaction, add).await {
+            log::error!("react question error: {e}");
+            // revert so the local record matches the server count again
+            LocalCache::set_reaction_state(&event, id, reaction, !add);
+            return Msg::Toast(Toast::error("toast-vote-failed"));
+        }
+
+        Msg::QuestionUpdated(id)
+    });
+}
+
+//TODO: dedup
+fn request_fetch(
+    id: String,
+    secret: Option<String>,
+    questions: Option<usize>,
+    link: &html::Scope<Event>,
+) {
+    link.send_future(async move {
+        let res = fetch::fetch_event(BASE_API, id, secret, questions).await;
+
+        res.map_or(Msg::Fetched(None), |val| Msg::Fetched(Some(val)))
+    });
+}
+
+fn request_questions_page(id: String, offset: usize, link: &html::Scope<Event>) {
+    link.send_future(async move {
+        match fetch::questions_page(BASE_API, id, offset, QUESTIONS_PAGE_DEFAULT_LIMIT).await {
+            Ok(page) => Msg::PageLoaded(Some(page)),
+            Err(e) => {
+                log::error!("questions_page error: {e}");
+                Msg::PageLoaded(None)
+            }
+        }
+    });
+}
+
+fn request_duplicate(id: String, secret: String, link: &html::Scope<Event>) {
+    link.send_future(async move {
+        match fetch::mod_duplicate_event(BASE_API, id, secret).await {
+            Err(e) => {
+                log::error!("duplicate event error: {e}");
+                Msg::Duplicated(None)
+            }
+            Ok(event) => Msg::Duplicated(Some(event)),
+        }
+    });
+}
+
+fn request_updates(id: String, since: usize, link: &html::Scope<Event>) {
+    link.send_future(async move {
+        match fetch::event_updates(BASE_API, id, since).await {
+            Ok(updates) => Msg::Updates(Some(updates)),
+            Err(e) => {
+                log::error!("event_updates error: {e}");
+                Msg::Updates(None)
+            }
+        }
+    });
+}
+
+fn request_capture(id: String, order_id: String, link: &html::Scope<Event>) {
+    link.send_future(async move {
+        if let Err(e) = fetch::mod_premium_capture(BASE_API, id, order_id).await {
+            log::error!("mod_premium_capture error: {e}");
+        }
+
+        Msg::Captured
+    });
+}
+
+fn request_event_change(
+    id: String,
+    secret: Option<String>,
+    change: ModEvent,
+    link: &html::Scope<Event>,
+) {
+    link.send_future(async move {
+        if let Err(e) = fetch::mod_edit_event(BASE_API, id, secret.unwrap_throw(), change).await {
+            log::error!("mod_edit_event error: {e}");
+            return Msg::Toast(Toast::error("toast-save-failed"));
+        }
+
+        Msg::StateChanged
+    });
+}
+
+impl Event {
+    fn replay_pending(&self, ctx: &Context<Self>) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        log::info!("replay {} queued actions", self.pending.len());
+
+        let actions = LocalCache::take_pending_actions(&self.current_event_id);
+        request_replay(self.current_event_id.clone(), actions, ctx.link());
+    }
+
+    fn view_pending(&self) -> Html {
+        if self.pending.is_empty() {
+            return html! {};
+        }
+
+        let likes = self
+            .pending
+            .iter()
+            .filter(|action| matches!(action, PendingAction::Like { .. }))
+            .count();
+        let likes = if likes == 0 {
+            String::new()
+        } else {
+            tr_args("question-likes", [("count", likes.into())])
+        };
+
+        html! {
+            <div class="pending-actions">
+                <div class="title">{ tr("event-pending-title") }</div>
+                {
+                    for self.pending.iter().filter_map(|action| match action {
+                        PendingAction::Question { text, .. } => Some(html! {
+                            <div class="pending-question">{ text.clone() }</div>
+                        }),
+                        PendingAction::Like { .. } => None,
+                    })
+                }
+                <div class="pending-likes" hidden={likes.is_empty()}>{ likes }</div>
+            </div>
+        }
+    }
+
+    /// tells the embedding page the height the iframe needs to show everything without scrolling
+    /// and how many questions are visible, each only when it changed
+    fn post_embed_state(&mut self) {
+        let Ok(Some(parent)) = gloo_utils::window().parent() else {
+            return;
+        };
+
+        let height = gloo_utils::document()
+            .document_element()
+            .map(|e| e.scroll_height())
+            .unwrap_or_default();
+
+        if height != self.embed_height {
+            self.embed_height = height;
+            post_embed_message(&parent, EMBED_HEIGHT_MESSAGE, "height", height.into());
+        }
+
+        let questions = self.unanswered.len() + self.answered.len();
+
+        if self.embed_questions != Some(questions) {
+            self.embed_questions = Some(questions);
+            post_embed_message(&parent, EMBED_QUESTIONS_MESSAGE, "count", questions.into());
+        }
+    }
+
+    fn is_entitled(&self, entitlement: Entitlements) -> bool {
+        self.store
             .event
             .as_ref()
-            .map(|e| e.info.data.name.clone())
-            .unwrap_or_default();
+            .is_some_and(|e| e.info.is_entitled(entitlement))
+    }
+
+    fn export_event(&self, file: &str) {
+        if !self.is_entitled(Entitlements::EXPORT) {
+            return;
+        }
+
+        let Some(e) = self.store.event.as_ref() else {
+            return;
+        };
+
+        let Some(secret) = e.info.tokens.moderator_token.as_ref() else {
+            return;
+        };
 
-        let csv = self.event_as_csv().unwrap_or_default();
+        tracking::track_event(tracking::EVNT_EXPORT);
 
         let anchor = gloo_utils::document()
             .create_element("a")
@@ -418,78 +1204,56 @@ impl Event {
             .unwrap_throw();
 
         anchor.set_href(&format!(
-            "data:text/csv;charset=utf-8,{}",
-            web_sys::js_sys::encode_uri_component(&csv)
+            "{BASE_API}/api/mod/event/export/{}/{secret}/{file}",
+            e.info.tokens.public_token
         ));
         anchor.set_target("_blank");
-        anchor.set_download(&format!("live-ask:{name}.csv"));
         anchor.click();
     }
 
-    fn event_as_csv(&self) -> anyhow::Result<String> {
-        use csv::WriterBuilder;
-
-        let questions = self
-            .state
-            .event
-            .as_ref()
-            .map(|e| e.info.questions.clone())
-            .unwrap_or_default();
-        let mut wtr = WriterBuilder::new().from_writer(vec![]);
-        wtr.write_record(["date (utc)", "text", "state", "likes"])
-            .unwrap_throw();
-        for q in questions {
-            let create_time = DateTime::<Utc>::from_naive_utc_and_offset(
-                NaiveDateTime::from_timestamp_opt(q.create_time_unix, 0).unwrap_throw(),
-                Utc,
-            );
-            let state = question_state(&q).to_string();
-
-            wtr.write_record(&[
-                create_time.format("%Y-%m-%d %H:%M").to_string(),
-                q.text,
-                state,
-                q.likes.to_string(),
-            ])?;
-        }
-        Ok(String::from_utf8(wtr.into_inner()?)?)
-    }
-
     fn view_internal(&self, ctx: &Context<Self>) -> Html {
         match self.loading_state {
+            LoadingState::Loaded if self.waiting_room => Self::view_waiting_room(),
             LoadingState::Loaded => self.view_event(ctx),
-            LoadingState::Loading => {
-                html! {
-                    <div class="noevent">
-                        <h2>{ "loading event..." }</h2>
-                    </div>
-                }
-            }
+            LoadingState::Loading => html! { <EventSkeleton moderator={self.is_mod()} /> },
             LoadingState::NotFound => {
                 html! {
                     <div class="noevent">
-                        <h2>{ "event not found" }</h2>
+                        <h2>{ tr("event-not-found") }</h2>
                     </div>
                 }
             }
             LoadingState::Deleted => {
                 html! {
                     <div class="noevent">
-                        <h2>{ "event deleted" }</h2>
+                        <h2>{ tr("event-deleted") }</h2>
                     </div>
                 }
             }
         }
     }
 
+    fn view_waiting_room() -> Html {
+        html! {
+            <div class="noevent waiting-room" aria-live="polite">
+                <h2>{ tr("event-waiting-room-title") }</h2>
+                <p>{ tr("event-waiting-room-text") }</p>
+            </div>
+        }
+    }
+
     #[allow(clippy::if_not_else)]
     fn view_event(&self, ctx: &Context<Self>) -> Html {
-        self.state.event.as_ref().map_or_else(|| html! {}, |e| {
-            let share_url = if e.info.data.short_url.is_empty() {
-                e.info.data.long_url.clone().unwrap_or_default()
-            } else {
-                e.info.data.short_url.clone()
-            };
+        self.store.event.as_ref().map_or_else(|| html! {}, |e| {
+            let share_url = Self::share_url(e);
+
+            let print_secret = e
+                .info
+                .tokens
+                .moderator_token
+                .clone()
+                .filter(|_| e.info.tokens.is_mod())
+                .map(AttrValue::from);
 
             let background = classes!(match self.mode {
                 Mode::Moderator => "bg-mod",
@@ -498,69 +1262,120 @@ impl Event {
 
             let mod_view = matches!(self.mode, Mode::Moderator);
             let admin = e.admin;
-            let is_premium = e.info.is_premium();
             let is_masked = e.masked;
             let is_first_24h = EventInfo::during_first_day(e.info.create_time_unix);
 
             let tag = e.info.tags.get_current_tag_label();
             let screening_enabled = e.info.flags.contains(EventFlags::SCREENING);
+            //Note: the scheduler closes the event outside its schedule, `view_schedule` explains that
+            let now = Utc::now().timestamp();
+            let outside_schedule = e.info.data.not_started(now) || e.info.data.ended(now);
+            let branding_style = e.info.data.branding.as_ref().map(EventBranding::css_variables);
 
             html! {
-                <div class="some-event">
+                <div class={classes!("some-event",theme_class(e.info.theme))} style={branding_style}>
                     <div class={background} />
                     <PasswordPopup
                         event={e.info.tokens.public_token.clone()}
                         show={e.is_wrong_pwd()}
                         onconfirmed={ctx.link().callback(|()|Msg::PasswordSet)}
                     />
-                    <QuestionPopup event_id={e.info.tokens.public_token.clone()} {tag} />
-                    <SharePopup url={share_url} event_id={e.info.tokens.public_token.clone()} />
+                    <QuestionPopup
+                        event_id={e.info.tokens.public_token.clone()}
+                        {tag}
+                        names={e.info.question_names}
+                        limits={e.info.question_length}
+                        attachments={e.info.is_entitled(Entitlements::MODERATION)}
+                        session={self.session}
+                        cooldown_mins={e.info.question_cooldown_mins}
+                        languages={e.info.question_languages.languages.clone()}
+                    />
+                    <SharePopup
+                        url={share_url.clone()}
+                        event_id={e.info.tokens.public_token.clone()}
+                        secret={print_secret}
+                    />
                     <div class="event-block">
+                        <Branding branding={e.info.data.branding.clone()} />
                         <EventMeta
                             context={e.info.context.clone()}
                             tokens={e.info.tokens.clone()}
                             data={e.info.data.clone()}
-                            {is_premium}
+                            is_premium={e.info.is_entitled(Entitlements::MODERATION)}
                             {is_masked}
                             {is_first_24h}
+                            moderator_online={self.moderator_online}
                              />
                         { self.mod_view(ctx,e) }
-                        <div class="not-open" hidden={!e.info.state.is_closed()}>
-                            { "This event was closed by the moderator. You cannot add or vote questions anymore." }
+                        <div class="not-open" hidden={!e.info.state.is_closed() || outside_schedule}>
+                            { tr("event-closed") }
                             <br />
-                            { "Updates by the moderator are still seen in real-time." }
+                            { tr("event-closed-updates") }
+                        </div>
+                        <div class="not-open" hidden={!e.info.state.is_archived()}>
+                            { tr("event-archived") }
                         </div>
                         <div class="not-open" hidden={!e.info.state.is_vote_only()}>
-                            { "This event is set to vote-only by the moderator. You cannot add new questions. You can still vote though." }
+                            { tr("event-vote-only") }
                         </div>
                         <div class="not-open" hidden={!e.is_timed_out()}>
-                            { "This free event timed out. Only the moderator can upgrade it to be accessible again." }
+                            { tr("event-timed-out") }
+                        </div>
+                        { Self::view_schedule(e) }
+                        { Self::view_timer(e) }
+                        { if ctx.props().embedded { html! {} } else { html! {
+                            <ShareButton url={share_url.clone()} title={e.info.data.name.clone()} />
+                        } } }
+                        <div class="event-qr">
+                            <Qr url={share_url} dimensions=120 />
+                            <div class="hint">{ tr("event-scan-to-join") }</div>
                         </div>
+                        { self.view_pending() }
                     </div>
-                    { self.mod_urls(ctx,admin) }
+                    { self.mod_urls(ctx,admin,&share_url) }
                     { self.view_stats() }
+                    { self.view_mod_stats() }
+                    { self.view_mod_summary() }
+                    { self.view_mod_clusters() }
+                    { self.view_viewers() }
+                    { self.view_typing(e) }
+                    { Self::view_speakers(e) }
+                    { Self::view_polls(e) }
+                    { Self::view_word_clouds(e) }
+                    { Self::view_survey(e) }
+                    { self.view_sessions(ctx,e) }
                     <div class="review-note" hidden={!screening_enabled || mod_view}>
-                    { "Moderator enabled question reviewing. New questions have to be approved first." }
+                    { tr("event-screening-note") }
                     </div>
+                    { self.view_search(ctx) }
+                    { self.view_bulk_actions(ctx) }
+                    { self.view_confirm(ctx) }
                     { self.view_questions(ctx,e) }
-                    { Self::view_ask_question(mod_view,ctx,e) }
+                    { if e.has_more_questions() {
+                        html! { <InfiniteScroll on_end={ctx.link().callback(|()| Msg::LoadPage)} loading={self.loading_page} /> }
+                    } else { html! {} } }
+                    { self.view_ask_question(mod_view,ctx,e) }
                 </div>
             }
         })
     }
 
     #[allow(clippy::if_not_else)]
-    fn view_ask_question(mod_view: bool, ctx: &Context<Self>, e: &GetEventResponse) -> Html {
+    fn view_ask_question(&self, mod_view: bool, ctx: &Context<Self>, e: &GetEventResponse) -> Html {
+        let session_not_open = self.selected_session(e).is_some_and(|s| !s.state.is_open());
+        let now = Utc::now().timestamp();
+        let outside_schedule = e.info.data.not_started(now) || e.info.data.ended(now);
+
         if mod_view {
             html! {}
         } else {
             html! {
-                <div class="addquestion" hidden={!e.info.state.is_open()}>
+                <div class="addquestion" hidden={!e.info.state.is_open() || session_not_open || outside_schedule}>
                     <button
                         class="button-red"
                         onclick={ctx.link().callback(|_| Msg::AskQuestionClick)}
                     >
-                        { "Ask a Question" }
+                        { tr("event-ask") }
                     </button>
                 </div>
             }
@@ -578,23 +1393,76 @@ impl Event {
         matches!(self.mode, Mode::Moderator)
     }
 
+    fn selected_session<'a>(&self, e: &'a GetEventResponse) -> Option<&'a Session> {
+        self.session.and_then(|id| e.get_session(id))
+    }
+
+    /// session tabs, only shown once the moderator added sessions
+    fn view_sessions(&self, ctx: &Context<Self>, e: &GetEventResponse) -> Html {
+        if e.info.sessions.is_empty() {
+            return html! {};
+        }
+
+        let tab = |session: Option<i64>, name: String, closed: bool| {
+            let selected = self.session == session;
+            html! {
+                <button
+                    class={classes!("session",selected.then_some("selected"),closed.then_some("closed"))}
+                    onclick={ctx.link().callback(move |_| Msg::SelectSession(session))}
+                >
+                    { name }
+                </button>
+            }
+        };
+
+        let closed_note = self
+            .selected_session(e)
+            .filter(|s| !s.state.is_open() && e.info.state.is_open());
+
+        html! {
+            <>
+                <div class="sessions">
+                    { tab(None, tr("event-session-all"), false) }
+                    {
+                        e.info.sessions.iter().map(|s| {
+                            tab(Some(s.id), s.name.clone(), s.state.is_closed())
+                        }).collect::<Html>()
+                    }
+                </div>
+                { closed_note.map_or_else(|| html! {}, |s| html! {
+                    <div class="review-note">
+                        { if s.state.is_closed() {
+                            tr("event-session-closed")
+                        } else {
+                            tr("event-session-vote-only")
+                        } }
+                    </div>
+                }) }
+            </>
+        }
+    }
+
     fn view_questions(&self, ctx: &Context<Self>, e: &GetEventResponse) -> Html {
-        if e.info.questions.is_empty() && self.unscreened.is_empty() {
+        if self.unanswered.is_empty()
+            && self.answered.is_empty()
+            && self.hidden.is_empty()
+            && self.unscreened.is_empty()
+        {
             let no_questions_classes = classes!(match self.mode {
                 Mode::Moderator => "noquestions modview",
                 Mode::Viewer => "noquestions",
             });
 
-            html! { <div class={no_questions_classes}>{ "no questions yet" }</div> }
+            html! { <div class={no_questions_classes}>{ tr("event-no-questions") }</div> }
         } else {
-            let can_vote = !e.is_closed();
+            let can_vote = !e.is_closed() && !e.info.data.ended(Utc::now().timestamp());
             let is_mod = self.is_mod();
             html! {
                 <>
-                    { self.view_items(ctx,&self.unscreened,if is_mod {"For review"} else {"Your Questions in review by host"},can_vote) }
-                    { self.view_items(ctx,&self.unanswered,"Hot Questions",can_vote) }
-                    { self.view_items(ctx,&self.answered,"Answered",can_vote) }
-                    { self.view_items(ctx,&self.hidden,"Hidden",can_vote) }
+                    { self.view_items(ctx,&self.unscreened,&tr(if is_mod {"event-questions-review"} else {"event-questions-in-review"}),can_vote) }
+                    { self.view_items(ctx,&self.unanswered,&tr("event-questions-hot"),can_vote) }
+                    { self.view_items(ctx,&self.answered,&tr("event-questions-answered"),can_vote) }
+                    { self.view_items(ctx,&self.hidden,&tr("event-questions-hidden"),can_vote) }
                 </>
             }
         }
@@ -610,14 +1478,19 @@ impl Event {
         if !items.is_empty() {
             let title_classes = self.question_separator_classes();
 
-            let masked = self.state.event.as_ref().is_some_and(|e| e.masked);
+            let masked = self.store.event.as_ref().is_some_and(|e| e.masked);
+
+            //Note: long feeds only mount the cards around the viewport, these have to be kept
+            let focus = items
+                .iter()
+                .position(|q| self.selected == Some(q.id) || self.ui.new_question == Some(q.id));
 
             return html! {
                 <div>
                     <div class={title_classes}>{ title }</div>
-                    <div class="questions">
-                        { for items.iter().enumerate().map(|(e,i)|self.view_item(ctx,can_vote,masked,e,i)) }
-                    </div>
+                    <VirtualFeed class="questions" label={title.to_owned()} {focus}>
+                        { for items.iter().enumerate().map(|(e,i)|self.view_item(ctx,can_vote,masked,e,items.len(),i)) }
+                    </VirtualFeed>
                 </div>
             };
         }
@@ -631,19 +1504,44 @@ impl Event {
         can_vote: bool,
         blurr: bool,
         index: usize,
+        set_size: usize,
         item: &Rc<QuestionItem>,
     ) -> Html {
         let local_like = LocalCache::is_liked(&self.current_event_id, item.id);
+        let local_downvote = LocalCache::is_downvoted(&self.current_event_id, item.id);
+        let can_downvote = self
+            .store
+            .event
+            .as_ref()
+            .is_some_and(|e| e.info.downvotes_enabled());
+        let reactions = LocalCache::reactions(&self.current_event_id, item.id);
         let mod_view = matches!(self.mode, Mode::Moderator);
-        let is_new = self.state.new_question.is_some_and(|id| id == item.id);
+        let is_new = self.ui.new_question.is_some_and(|id| id == item.id);
+        let session_closed = self.store.event.as_ref().is_some_and(|e| {
+            item.session
+                .and_then(|id| e.get_session(id))
+                .is_some_and(|s| s.state.is_closed())
+        });
+        let can_vote = can_vote && !session_closed;
 
         let mut flags = QuestionFlags::empty();
 
         flags.set(QuestionFlags::NEW_QUESTION, is_new);
         flags.set(QuestionFlags::MOD_VIEW, mod_view);
         flags.set(QuestionFlags::LOCAL_LIKE, local_like);
+        flags.set(QuestionFlags::LOCAL_DOWNVOTE, local_downvote);
+        flags.set(QuestionFlags::CAN_DOWNVOTE, can_downvote);
         flags.set(QuestionFlags::CAN_VOTE, can_vote);
         flags.set(QuestionFlags::BLURR, blurr);
+        flags.set(QuestionFlags::SELECTED, self.selected == Some(item.id));
+        flags.set(
+            QuestionFlags::BULK_SELECTED,
+            self.bulk_selection.contains(&item.id),
+        );
+        flags.set(
+            QuestionFlags::CAN_TRANSLATE,
+            self.store.event.as_ref().is_some_and(|e| e.can_translate()),
+        );
 
         let tag = item
             .tag
@@ -651,13 +1549,44 @@ impl Event {
             .and_then(|tag| self.tags.get(tag))
             .cloned();
 
+        let flagged_likes = self
+            .store
+            .event
+            .as_ref()
+            .and_then(|e| e.mod_info.as_ref())
+            .and_then(|info| info.flagged_likes.iter().find(|f| f.question_id == item.id))
+            .map_or(0, |f| f.discounted);
+
+        let sentiment = self
+            .store
+            .event
+            .as_ref()
+            .and_then(|e| e.mod_info.as_ref())
+            .and_then(|info| info.sentiments.iter().find(|s| s.question_id == item.id))
+            .map(|s| s.sentiment);
+
+        let blocked_terms = self
+            .store
+            .event
+            .as_ref()
+            .and_then(|e| e.mod_info.as_ref())
+            .map(|info| info.blocked_terms.terms.clone())
+            .unwrap_or_default();
+
         html! {
             <Question
                 {item}
                 {index}
+                {set_size}
                 key={item.id}
                 {flags}
                 {tag}
+                {reactions}
+                {flagged_likes}
+                {sentiment}
+                {blocked_terms}
+                translation={self.translations.get(&item.id).cloned()}
+                secret={ctx.props().secret.clone()}
                 on_click={ctx.link().callback(Msg::QuestionClick)}
             />
         }
@@ -669,7 +1598,9 @@ impl Event {
             return html! {};
         }
 
-        let payment_allowed = !e.info.is_premium();
+        //Note: a running trial can still be upgraded
+        let payment_allowed = !e.info.is_premium() || e.info.is_trial();
+        let trial_available = !e.info.is_premium() && e.info.trial_end_unix.is_none();
         let pending_payment = self.query_params.paypal_token.is_some() && payment_allowed;
 
         let timed_out = e.is_timed_out();
@@ -678,6 +1609,16 @@ impl Event {
             .as_ref()
             .map(|info| info.pwd.clone())
             .unwrap_or_default();
+        let notifications = e
+            .mod_info
+            .as_ref()
+            .map(|info| info.notifications.clone())
+            .unwrap_or_default();
+        let blocked_terms = e
+            .mod_info
+            .as_ref()
+            .map(|info| info.blocked_terms.clone())
+            .unwrap_or_default();
 
         html! {
             <>
@@ -685,24 +1626,109 @@ impl Event {
                     <DeletePopup tokens={e.info.tokens.clone()} />
                     { if timed_out {html!{}}else {html!{
                         <div class="state">
-                            <select onchange={ctx.link().callback(Msg::ModStateChange)} >
-                                <option value="0" selected={e.info.state.is_open()}>{"Event open"}</option>
-                                <option value="1" selected={e.info.state.is_vote_only()}>{"Event vote only"}</option>
-                                <option value="2" selected={e.info.state.is_closed()}>{"Event closed"}</option>
+                            <select ref={self.state_select.clone()} onchange={ctx.link().callback(Msg::ModStateChange)} >
+                                <option value="0" selected={e.info.state.is_open()}>{ tr("mod-state-open") }</option>
+                                <option value="1" selected={e.info.state.is_vote_only()}>{ tr("mod-state-vote-only") }</option>
+                                <option value="2" selected={e.info.state.is_closed()}>{ tr("mod-state-closed") }</option>
+                                <option value="3" selected={e.info.state.is_archived()}>{ tr("mod-state-archived") }</option>
                             </select>
                         </div>
                         }} }
+                    { if timed_out {html!{}}else {html!{
+                        <div class="state">
+                            <select onchange={ctx.link().callback(Msg::ModQuestionNamesChange)} >
+                                <option value="0" selected={e.info.question_names == QuestionNames::Anonymous}>{ tr("mod-names-anonymous") }</option>
+                                <option value="1" selected={e.info.question_names == QuestionNames::Optional}>{ tr("mod-names-optional") }</option>
+                                <option value="2" selected={e.info.question_names == QuestionNames::Required}>{ tr("mod-names-required") }</option>
+                            </select>
+                        </div>
+                        }} }
+                    { if timed_out {html!{}}else {html!{
+                        <div class="state">
+                            <select onchange={ctx.link().callback(Msg::ModThemeChange)} >
+                                <option value="0" selected={e.info.theme == EventTheme::Auto}>{ tr("mod-theme-auto") }</option>
+                                <option value="1" selected={e.info.theme == EventTheme::Light}>{ tr("mod-theme-light") }</option>
+                                <option value="2" selected={e.info.theme == EventTheme::Dark}>{ tr("mod-theme-dark") }</option>
+                                <option value="3" selected={e.info.theme == EventTheme::Custom} disabled={!e.info.is_entitled(Entitlements::BRANDING)}>{ tr("mod-theme-branding") }</option>
+                            </select>
+                        </div>
+                        }} }
+                    { if timed_out {html!{}}else {html!{
+                        <div
+                            class="downvote-option"
+                            onclick={ctx.link().callback(|_| Msg::ModEditDownvotes)}
+                        >
+                            <input
+                                type="checkbox"
+                                id="downvotes"
+                                name="downvotes"
+                                checked={e.info.downvotes_enabled()}
+                            />
+                            { tr("mod-downvotes") }
+                        </div>
+                        }} }
+                    { if timed_out {html!{}}else {html!{
+                        <div
+                            class="survey-option"
+                            onclick={ctx.link().callback(|_| Msg::ModEditSurvey)}
+                        >
+                            <input
+                                type="checkbox"
+                                id="survey"
+                                name="survey"
+                                checked={e.info.survey_enabled()}
+                            />
+                            { tr("mod-survey") }
+                        </div>
+                        }} }
+                    <div
+                        class="tab-alert-option"
+                        onclick={ctx.link().callback(|_| Msg::ModToggleTabAlert)}
+                    >
+                        <input
+                            type="checkbox"
+                            id="tab-alert"
+                            name="tab-alert"
+                            checked={self.tab_alert.enabled()}
+                        />
+                        { tr("mod-tab-alert") }
+                    </div>
+                    <ModNotifications tokens={e.info.tokens.clone()} settings={notifications} />
                     <button class="button-white" onclick={ctx.link().callback(|_|Msg::ModDelete)}>
-                        { "Delete Event" }
+                        { tr("mod-delete") }
+                    </button>
+                    <button class="button-white" onclick={ctx.link().callback(|_|Msg::ModDuplicate)}>
+                        { tr("mod-duplicate") }
+                    </button>
+                    <button class="button-white" onclick={ctx.link().callback(|_|Msg::ModSaveTemplate)}>
+                        { tr(if self.template_saved { "mod-template-saved" } else { "mod-template-save" }) }
                     </button>
                     <ModPassword tokens={e.info.tokens.clone()} {pwd} />
+                    <ModQuestionLength tokens={e.info.tokens.clone()} length={e.info.question_length} />
+                    <ModQuestionCooldown tokens={e.info.tokens.clone()} minutes={e.info.question_cooldown_mins} />
+                    <ModQuestionLimits tokens={e.info.tokens.clone()} limits={e.info.question_limits} />
+                    <ModBlockedTerms tokens={e.info.tokens.clone()} {blocked_terms} />
+                    <ModQuestionLanguages tokens={e.info.tokens.clone()} languages={e.info.question_languages.clone()} />
+                    { Self::mod_view_timezone(e) }
+                    { if timed_out {html!{}}else {html!{
+                        <ModPollCreate tokens={e.info.tokens.clone()} />
+                        }} }
+                    { if timed_out {html!{}}else {html!{
+                        <ModWordCloudCreate tokens={e.info.tokens.clone()} />
+                        }} }
+                    { if timed_out {html!{}}else {html!{
+                        <ModCountdown tokens={e.info.tokens.clone()} timer={e.info.timer.clone()} />
+                        }} }
+                    { if timed_out {html!{}}else {html!{
+                        <ModSessions tokens={e.info.tokens.clone()} sessions={e.info.sessions.clone()} />
+                        }} }
                     { if e.info.is_premium() {
                             self.mod_view_premium(ctx,e)
                         } else { html!{} } }
                 </div>
                 { if payment_allowed {
                         html!{
-                            <Upgrade pending={pending_payment} tokens={e.info.tokens.clone()} />
+                            <Upgrade pending={pending_payment} tokens={e.info.tokens.clone()} {trial_available} />
                         }
                     } else { html!{} } }
                 { Self::mod_view_deadline(e) }
@@ -710,14 +1736,37 @@ impl Event {
         }
     }
 
+    /// all times are stored in utc and rendered in the timezone of the viewer
+    fn mod_view_timezone(e: &GetEventResponse) -> Html {
+        let organizer = e.info.data.timezone.as_ref();
+        let local = local_timezone()
+            .filter(|local| Some(local) != organizer)
+            .map(|local| tr_args("mod-timezone-local", [("timezone", local.into())]))
+            .unwrap_or_default();
+
+        html! {
+            <div class="mod-timezone">
+                { organizer.map_or_else(
+                    || tr("mod-timezone-unknown"),
+                    |timezone| tr_args("mod-timezone", [("timezone", timezone.as_str().into())]),
+                ) }
+                { local }
+            </div>
+        }
+    }
+
     fn mod_view_premium(&self, ctx: &Context<Self>, e: &GetEventResponse) -> Html {
         let tag = e.info.tags.get_current_tag_label();
         let tags = SharableTags::clone(&self.tags);
 
+        let moderation = e.info.is_entitled(Entitlements::MODERATION);
+        let branding = e.info.is_entitled(Entitlements::BRANDING);
+
         html! {
             <div class="premium">
-                <div class="title">{ "This is a premium event" }</div>
+                <div class="title">{ Self::premium_title(e) }</div>
                 <div class="button-box">
+                    { if moderation { html!{
                     <div
                         class="screening-option"
                         onclick={ctx.link().callback(|_| Msg::ModEditScreening)}
@@ -728,100 +1777,348 @@ impl Event {
                             name="vehicle1"
                             checked={e.info.is_screening()}
                         />
-                        { "Screening" }
+                        { tr("mod-screening") }
                     </div>
-                    <button class="button-white" onclick={ctx.link().callback(|_|Msg::ModExport)}>
-                        { "Export" }
-                    </button>
+                    }} else { html!{} } }
+                    { if branding { html!{
+                    <div
+                        class="hide-branding-option"
+                        onclick={ctx.link().callback(|_| Msg::ModEditHideBranding)}
+                    >
+                        <input
+                            type="checkbox"
+                            id="hide-branding"
+                            name="hide-branding"
+                            checked={e.info.branding_hidden()}
+                        />
+                        { tr("mod-hide-branding") }
+                    </div>
+                    }} else { html!{} } }
+                    { if e.info.is_entitled(Entitlements::EXPORT) { html!{
+                    <>
+                        <button class="button-white" onclick={ctx.link().callback(|_|Msg::ModExport("export.csv"))}>
+                            { tr("mod-export-csv") }
+                        </button>
+                        <button class="button-white" onclick={ctx.link().callback(|_|Msg::ModExport("export.pdf"))}>
+                            { tr("mod-export-pdf") }
+                        </button>
+                        <button class="button-white" onclick={ctx.link().callback(|_|Msg::ModExport("export.md"))}>
+                            { tr("mod-export-markdown") }
+                        </button>
+                        <button class="button-white" onclick={ctx.link().callback(|_|Msg::ModExport("slides.html"))}>
+                            { tr("mod-export-slides") }
+                        </button>
+                    </>
+                    }} else { html!{} } }
+                    { if moderation { html!{
                     <ModTag tokens={e.info.tokens.clone()} {tag} {tags} />
+                    }} else { html!{} } }
                 </div>
+                { if branding { html!{
+                <>
+                <ModSlug tokens={e.info.tokens.clone()} slug={e.info.slug.clone()} />
+                <ModBranding tokens={e.info.tokens.clone()} branding={e.info.data.branding.clone()} />
+                </>
+                }} else { html!{} } }
+                { if moderation { html!{
+                <>
+                <ModViewerCapacity tokens={e.info.tokens.clone()} capacity={e.info.viewer_capacity} />
+                <ModSeries tokens={e.info.tokens.clone()} series={e.info.series.clone()} />
+                </>
+                }} else { html!{} } }
+                //Note: only purchases come with a receipt
+                { if e.info.is_trial() { html!{} } else { html!{
+                <ModReceipt tokens={e.info.tokens.clone()} />
+                }} }
+            </div>
+        }
+    }
+
+    fn view_viewers(&self) -> Html {
+        //Note: premium events show the count as part of the statistics already
+        if self.is_entitled(Entitlements::ANALYTICS) {
+            return html! {};
+        }
+
+        let viewers = self
+            .store
+            .event
+            .as_ref()
+            .map(|e| e.viewers)
+            .unwrap_or_default();
+
+        if viewers <= 0 {
+            return html! {};
+        }
+
+        let text = tr_args("event-viewers-now", [("count", viewers.into())]);
+
+        html! {
+            <div class="viewers-now" aria-live="polite">{ text }</div>
+        }
+    }
+
+    fn view_typing(&self, e: &GetEventResponse) -> Html {
+        if self.typing == 0 || !e.info.state.is_open() {
+            return html! {};
+        }
+
+        let text = tr_args("event-typing-now", [("count", self.typing.into())]);
+
+        html! {
+            <div class="typing-now" aria-live="polite">{ text }</div>
+        }
+    }
+
+    /// shown while hands can be raised and as long as anybody is still waiting
+    fn view_speakers(e: &GetEventResponse) -> Html {
+        let open = e.info.state.is_open() && !e.is_timed_out();
+
+        if e.masked || (!open && e.info.speakers.speakers.is_empty()) {
+            return html! {};
+        }
+
+        html! {
+            <SpeakerQueueList
+                tokens={e.info.tokens.clone()}
+                queue={e.info.speakers.clone()}
+                {open}
+            />
+        }
+    }
+
+    /// open polls first, newest first
+    fn view_polls(e: &GetEventResponse) -> Html {
+        if e.info.polls.is_empty() || e.masked {
+            return html! {};
+        }
+
+        let archived = e.info.state.is_archived();
+        let mut polls = e.info.polls.iter().collect::<Vec<_>>();
+        polls.sort_by_key(|poll| (poll.closed, std::cmp::Reverse(poll.id)));
+
+        html! {
+            <div class="polls">
+                {
+                    polls.into_iter().map(|poll| html! {
+                        <PollItem
+                            key={poll.id}
+                            tokens={e.info.tokens.clone()}
+                            poll={Poll { closed: poll.closed || archived, ..poll.clone() }}
+                        />
+                    }).collect::<Html>()
+                }
+            </div>
+        }
+    }
+
+    /// open word clouds first, newest first
+    fn view_word_clouds(e: &GetEventResponse) -> Html {
+        if e.info.word_clouds.is_empty() || e.masked {
+            return html! {};
+        }
+
+        let archived = e.info.state.is_archived();
+        let mut clouds = e.info.word_clouds.iter().collect::<Vec<_>>();
+        clouds.sort_by_key(|cloud| (cloud.closed, std::cmp::Reverse(cloud.id)));
+
+        html! {
+            <div class="word-clouds">
+                {
+                    clouds.into_iter().map(|cloud| html! {
+                        <WordCloudItem
+                            key={cloud.id}
+                            tokens={e.info.tokens.clone()}
+                            cloud={WordCloud { closed: cloud.closed || archived, ..cloud.clone() }}
+                        />
+                    }).collect::<Html>()
+                }
+            </div>
+        }
+    }
+
+    /// attendees get the survey once the event is closed, the moderator sees the results
+    fn view_survey(e: &GetEventResponse) -> Html {
+        if !e.info.survey_enabled() || !e.info.state.is_closed() || e.is_timed_out() || e.masked {
+            return html! {};
+        }
+
+        let tokens = e.info.tokens.clone();
+
+        html! {
+            <div class="surveys">
+                { if tokens.is_mod() {
+                    html! { <ModSurveyResults {tokens} /> }
+                } else {
+                    html! { <SurveyForm event={tokens.public_token} /> }
+                } }
             </div>
         }
     }
 
     fn view_stats(&self) -> Html {
-        if !self.is_premium() {
+        if !self.is_entitled(Entitlements::ANALYTICS) {
             return html! {};
         }
 
         let viewers = self
-            .state
+            .store
             .event
             .as_ref()
             .map(|e| e.viewers)
             .unwrap_or_default();
         let likes = self
-            .state
+            .store
             .event
             .as_ref()
             .map(GetEventResponse::get_likes)
             .unwrap_or_default();
         let questions = self
-            .state
+            .store
             .event
             .as_ref()
-            .map(|e| e.info.questions.len())
+            .map(|e| e.questions_total.unwrap_or(e.info.questions.len()))
             .unwrap_or_default();
 
         html! {
             <div class="statistics">
-                <abbr title="current viewers" tabindex="0">
-                    <img alt="viewers" src="/assets/symbols/viewers.svg" />
+                <abbr title={tr("event-stats-viewers")} tabindex="0">
+                    <img alt={tr("event-stats-viewers")} src="/assets/symbols/viewers.svg" />
                 </abbr>
                 <div class="count">{ {viewers} }</div>
-                <abbr title="all questions" tabindex="0">
-                    <img alt="questions" src="/assets/symbols/questions.svg" />
+                <abbr title={tr("event-stats-questions")} tabindex="0">
+                    <img alt={tr("event-stats-questions")} src="/assets/symbols/questions.svg" />
                 </abbr>
                 <div class="count">{ {questions} }</div>
-                <abbr title="all likes" tabindex="0">
-                    <img alt="likes" src="/assets/symbols/likes.svg" />
+                <abbr title={tr("event-stats-likes")} tabindex="0">
+                    <img alt={tr("event-stats-likes")} src="/assets/symbols/likes.svg" />
                 </abbr>
                 <div class="count">{ {likes} }</div>
             </div>
         }
     }
 
+    fn view_mod_stats(&self) -> Html {
+        if !matches!(self.mode, Mode::Moderator) || !self.is_entitled(Entitlements::ANALYTICS) {
+            return html! {};
+        }
+
+        self.store.event.as_ref().map_or_else(
+            || html! {},
+            |e| html! { <ModStats tokens={e.info.tokens.clone()} /> },
+        )
+    }
+
+    fn view_mod_summary(&self) -> Html {
+        if !matches!(self.mode, Mode::Moderator) || !self.is_entitled(Entitlements::MODERATION) {
+            return html! {};
+        }
+
+        let Some(e) = &self.store.event else {
+            return html! {};
+        };
+
+        match &e.mod_info {
+            Some(info) if info.summaries => html! {
+                <ModSummary tokens={e.info.tokens.clone()} summary={info.question_summary.clone()} />
+            },
+            _ => html! {},
+        }
+    }
+
+    fn view_mod_clusters(&self) -> Html {
+        if !matches!(self.mode, Mode::Moderator) {
+            return html! {};
+        }
+
+        self.store.event.as_ref().map_or_else(
+            || html! {},
+            |e| html! { <ModClusters tokens={e.info.tokens.clone()} questions={e.info.questions.clone()} /> },
+        )
+    }
+
+    fn view_schedule(e: &GetEventResponse) -> Html {
+        let data = &e.info.data;
+        let now = Utc::now().timestamp();
+
+        if let Some(start) = data.start_unix.filter(|_| data.not_started(now)) {
+            html! {
+                <div class="schedule-note">
+                    { tr_args("event-starts-in", [("countdown", format_countdown(start - now).into())]) }
+                </div>
+            }
+        } else if let Some(end) = data.end_unix.filter(|_| data.ended(now)) {
+            html! {
+                <div class="not-open">
+                    { tr_args("event-ended-on", [("date", format_date_time(end).into())]) }
+                </div>
+            }
+        } else if let Some(end) = data.end_unix {
+            html! {
+                <div class="schedule-note">{ tr_args("event-ends-on", [("date", format_date_time(end).into())]) }</div>
+            }
+        } else {
+            html! {}
+        }
+    }
+
+    fn view_timer(e: &GetEventResponse) -> Html {
+        match &e.info.timer {
+            Some(timer) if !e.masked => html! { <Countdown timer={timer.clone()} /> },
+            _ => html! {},
+        }
+    }
+
+    fn premium_title(e: &GetEventResponse) -> String {
+        match e.info.trial_end_unix {
+            Some(end) if e.info.is_trial() => {
+                tr_args("event-premium-trial", [("time", format_time(end).into())])
+            }
+            _ => tr("event-premium"),
+        }
+    }
+
     fn mod_view_deadline(e: &GetEventResponse) -> Html {
-        if e.info.is_premium() {
+        if e.info.is_entitled(Entitlements::LONG_RETENTION) {
             html! {
-                <div class="deadline">{ "This is a premium event and will not time out!" }</div>
+                <div class="deadline">{ tr("mod-deadline-premium") }</div>
             }
         } else {
             html! {
                 <div class="deadline">
-                    { "Currently a " }
-                    <strong>{ "free" }</strong>
-                    { format!(" event is valid for {FREE_EVENT_DURATION_DAYS} days. Your event will be accessible until ") }
-                    <span>{ Self::get_event_timeout(&e.info) }</span>
-                    { ". Please upgrade to a " }
-                    <strong>{ "premium" }</strong>
-                    { " event to make it " }
-                    <strong>{ "permanent" }</strong>
-                    { "." }
+                    { tr_args("mod-deadline-free", [
+                        ("days", FREE_EVENT_DURATION_DAYS.into()),
+                        ("date", Self::get_event_timeout(&e.info).into()),
+                    ]) }
                 </div>
             }
         }
     }
 
-    fn mod_urls(&self, ctx: &Context<Self>, admin: bool) -> Html {
+    fn mod_urls(&self, ctx: &Context<Self>, admin: bool, share_url: &str) -> Html {
         if matches!(self.mode, Mode::Moderator) || (matches!(self.mode, Mode::Viewer) && admin) {
             html! {
                 <div id="moderator-urls">
-                    <div class="linkbox-title">{ "This is your moderation link" }</div>
+                    <div class="linkbox-title">{ tr("mod-event-link") }</div>
                     <div class="linkbox-box">
                         <div class="linkbox-url">
-                            <div>{ self.moderator_url() }</div>
+                            <div>{ share_url }</div>
                         </div>
-                        <div class="linkbox-copy" onclick={ctx.link().callback(|_| Msg::CopyLink)}>
-                            { if self.copied_to_clipboard {"Copied"}else{"Copy"} }
+                        <CopyButton class="linkbox-copy" text={share_url.to_string()} />
+                    </div>
+                    <div class="linkbox-title">{ tr("mod-moderation-link") }</div>
+                    <div class="linkbox-box">
+                        <div class="linkbox-url">
+                            <div>{ self.moderator_url() }</div>
                         </div>
+                        <CopyButton class="linkbox-copy" text={self.moderator_url()} />
                     </div>
                     <div class="floating-share">
                         <button
                             class="button-dark"
                             onclick={ctx.link().callback(|_| Msg::ShareEventClick)}
                         >
-                            { "Share my event" }
+                            { tr("mod-share") }
                         </button>
                         <button class="button-blue">
                             <a
@@ -830,7 +2127,7 @@ impl Event {
                                 target="_blank"
                                 onclick={ctx.link().callback(|_| Msg::FeedbackClick)}
                             >
-                                <div class="feedback-text">{ "Give us feedback" }</div>
+                                <div class="feedback-text">{ tr("mod-feedback") }</div>
                             </a>
                         </button>
                     </div>
@@ -841,8 +2138,17 @@ impl Event {
         }
     }
 
+    /// short url if the event got one, otherwise the long one
+    fn share_url(e: &GetEventResponse) -> String {
+        if e.info.data.short_url.is_empty() {
+            e.info.data.long_url.clone().unwrap_or_default()
+        } else {
+            e.info.data.short_url.clone()
+        }
+    }
+
     fn moderator_url(&self) -> String {
-        self.state
+        self.store
             .event
             .as_ref()
             .map(|e| {
@@ -856,16 +2162,10 @@ impl Event {
     }
 
     //TODO: put event duration into object from backend
-    fn get_event_timeout(e: &EventInfo) -> Html {
-        let event_duration = Duration::days(FREE_EVENT_DURATION_DAYS);
+    fn get_event_timeout(e: &EventInfo) -> String {
+        let end_time = e.create_time_unix + Duration::days(FREE_EVENT_DURATION_DAYS).num_seconds();
 
-        let create_time = DateTime::<Utc>::from_naive_utc_and_offset(
-            NaiveDateTime::from_timestamp_opt(e.create_time_unix, 0).unwrap_throw(),
-            Utc,
-        );
-        let end_time = create_time + event_duration;
-
-        html! { end_time.format("%F") }
+        format_date(end_time)
     }
 
     fn init_event(&mut self) {
@@ -874,15 +2174,25 @@ impl Event {
         self.unanswered.clear();
         self.tags = Rc::default();
 
-        if let Some(e) = &self.state.event {
+        if let Some(e) = &self.store.event {
             let mut questions = e.info.questions.clone();
-            questions.sort_by(|a, b| b.likes.cmp(&a.likes));
+            //Note: only open questions stay pinned, see `mod_edit_question`
+            questions.sort_by_key(|q| (!q.pinned, std::cmp::Reverse(q.score())));
 
             let local_unscreened =
                 LocalCache::unscreened_questions(&e.info.tokens.public_token, &questions);
 
             questions.extend(local_unscreened);
 
+            if let Some(session) = self.session {
+                questions.retain(|q| q.session == Some(session));
+            }
+
+            let search = self.search.trim().to_lowercase();
+            if !search.is_empty() {
+                questions.retain(|q| q.text.to_lowercase().contains(&search));
+            }
+
             let (unscreened, screened) = questions.into_iter().map(Rc::new).split(|i| !i.screening);
             let (not_hidden, hidden) = screened.into_iter().split(|i| i.hidden);
             let (unanswered, answered) = not_hidden.into_iter().split(|i| i.answered);
@@ -926,21 +2236,28 @@ impl Event {
         }
 
         if let Some(ev) = res {
-            self.dispatch.reduce(|old| {
-                (*old)
-                    .clone()
-                    .set_event(Some(ev.clone()))
-                    .set_admin(ev.admin)
-                    .into()
-            });
-            self.state = self.dispatch.get();
+            self.dispatch
+                .reduce(|old| (*old).clone().set_event(Some(ev.clone())).into());
+            self.store = self.dispatch.get();
+            self.ui_dispatch
+                .reduce(|old| (*old).clone().set_admin(ev.admin).into());
+            self.ui = self.ui_dispatch.get();
             self.init_event();
         }
     }
 
-    fn on_question_click(&mut self, kind: &QuestionClickType, id: i64, ctx: &Context<Self>) {
+    /// returns `true` if the page has to render again, e.g. to ask for a confirmation first
+    fn on_question_click(
+        &mut self,
+        kind: &QuestionClickType,
+        id: i64,
+        ctx: &Context<Self>,
+    ) -> bool {
         match kind {
             QuestionClickType::Like => {
+                if LocalCache::is_downvoted(&self.current_event_id, id) {
+                    return false;
+                }
                 let liked = LocalCache::is_liked(&self.current_event_id, id);
                 if liked {
                     tracking::track_event(tracking::EVNT_QUESTION_UNLIKE);
@@ -948,11 +2265,54 @@ impl Event {
                     tracking::track_event(tracking::EVNT_QUESTION_LIKE);
                 }
                 LocalCache::set_like_state(&self.current_event_id, id, !liked);
-                request_like(self.current_event_id.clone(), id, !liked, ctx.link());
+
+                if self.socket_disconnected {
+                    LocalCache::queue_action(
+                        &self.current_event_id,
+                        PendingAction::Like { id, like: !liked },
+                    );
+                    self.pending = LocalCache::pending_actions(&self.current_event_id);
+                } else {
+                    request_like(self.current_event_id.clone(), id, !liked, ctx.link());
+                }
+            }
+            QuestionClickType::Downvote => {
+                if LocalCache::is_liked(&self.current_event_id, id) {
+                    return false;
+                }
+                let downvoted = LocalCache::is_downvoted(&self.current_event_id, id);
+                LocalCache::set_downvote_state(&self.current_event_id, id, !downvoted);
+                request_downvote(self.current_event_id.clone(), id, !downvoted, ctx.link());
+            }
+            QuestionClickType::React(reaction) => {
+                let reacted = LocalCache::reactions(&self.current_event_id, id).contains(reaction);
+                LocalCache::set_reaction_state(&self.current_event_id, id, *reaction, !reacted);
+                request_reaction(
+                    self.current_event_id.clone(),
+                    id,
+                    *reaction,
+                    !reacted,
+                    ctx.link(),
+                );
             }
             QuestionClickType::Hide => {
-                if let Some(q) = self.state.event.as_ref().unwrap_throw().get_question(id) {
-                    request_toggle_hide(
+                //Note: unhiding is harmless and happens right away
+                let hidden = self
+                    .store
+                    .event
+                    .as_ref()
+                    .and_then(|e| e.get_question(id))
+                    .is_some_and(|q| q.hidden);
+                if hidden {
+                    self.request_hide(id, ctx);
+                } else {
+                    self.confirm = Some(Confirm::HideQuestion(id));
+                    return true;
+                }
+            }
+            QuestionClickType::Answer => {
+                if let Some(q) = self.store.event.as_ref().unwrap_throw().get_question(id) {
+                    request_toggle_answered(
                         self.current_event_id.clone(),
                         ctx.props().secret.clone().unwrap_throw(),
                         q,
@@ -960,9 +2320,9 @@ impl Event {
                     );
                 }
             }
-            QuestionClickType::Answer => {
-                if let Some(q) = self.state.event.as_ref().unwrap_throw().get_question(id) {
-                    request_toggle_answered(
+            QuestionClickType::Pin => {
+                if let Some(q) = self.store.event.as_ref().unwrap_throw().get_question(id) {
+                    request_toggle_pin(
                         self.current_event_id.clone(),
                         ctx.props().secret.clone().unwrap_throw(),
                         q,
@@ -971,7 +2331,7 @@ impl Event {
                 }
             }
             QuestionClickType::Approve => {
-                if let Some(q) = self.state.event.as_ref().unwrap_throw().get_question(id) {
+                if let Some(q) = self.store.event.as_ref().unwrap_throw().get_question(id) {
                     request_approve_question(
                         self.current_event_id.clone(),
                         ctx.props().secret.clone().unwrap_throw(),
@@ -980,78 +2340,402 @@ impl Event {
                     );
                 }
             }
+            QuestionClickType::BulkSelect => {
+                if !self.bulk_selection.remove(&id) {
+                    self.bulk_selection.insert(id);
+                }
+                return true;
+            }
+            QuestionClickType::Note(note) => {
+                request_question_note(
+                    self.current_event_id.clone(),
+                    ctx.props().secret.clone().unwrap_throw(),
+                    id,
+                    note.clone(),
+                    ctx.link(),
+                );
+            }
+            QuestionClickType::ShadowBan => {
+                if let Some(q) = self.store.event.as_ref().unwrap_throw().get_question(id) {
+                    request_toggle_shadow_ban(
+                        self.current_event_id.clone(),
+                        ctx.props().secret.clone().unwrap_throw(),
+                        q,
+                        ctx.link(),
+                    );
+                }
+            }
+            QuestionClickType::Translate => {
+                if self.translations.remove(&id).is_some() {
+                    return true;
+                }
+
+                request_translate(
+                    self.current_event_id.clone(),
+                    ctx.props().secret.clone(),
+                    id,
+                    ctx.link(),
+                );
+            }
+        }
+
+        false
+    }
+
+    fn request_hide(&self, id: i64, ctx: &Context<Self>) {
+        if let Some(q) = self.store.event.as_ref().and_then(|e| e.get_question(id)) {
+            request_toggle_hide(
+                self.current_event_id.clone(),
+                ctx.props().secret.clone().unwrap_throw(),
+                q,
+                ctx.link(),
+            );
+        }
+    }
+
+    fn request_bulk(&self, action: BulkAction, ctx: &Context<Self>) {
+        let event = self.current_event_id.clone();
+        let secret = ctx.props().secret.clone().unwrap_throw();
+        let bulk = ModQuestionsBulk {
+            questions: self.bulk_selection.iter().copied().collect(),
+            action,
+        };
+
+        ctx.link().send_future(async move {
+            match fetch::mod_edit_questions(BASE_API, event, secret, bulk).await {
+                Ok(()) => Msg::BulkApplied(true),
+                Err(e) => {
+                    log::error!("bulk moderation error: {e}");
+                    Msg::BulkApplied(false)
+                }
+            }
+        });
+    }
+
+    fn request_state_change(&self, state: States, ctx: &Context<Self>) {
+        request_event_change(
+            self.current_event_id.clone(),
+            ctx.props().secret.clone(),
+            ModEvent {
+                state: Some(shared::EventState { state }),
+                ..Default::default()
+            },
+            ctx.link(),
+        );
+    }
+
+    fn on_confirmed(&mut self, confirmed: bool, ctx: &Context<Self>) {
+        match (self.confirm.take(), confirmed) {
+            (Some(Confirm::HideQuestion(id)), true) => self.request_hide(id, ctx),
+            (Some(Confirm::BulkHide), true) => self.request_bulk(BulkAction::Hide, ctx),
+            (Some(Confirm::State(state)), true) => self.request_state_change(state, ctx),
+            //Note: the select already shows the state that was not confirmed
+            (Some(Confirm::State(_)), false) => {
+                let current = self.store.event.as_ref().map(|e| e.info.state.state);
+                if let (Some(select), Some(current)) = (
+                    self.state_select.cast::<web_sys::HtmlSelectElement>(),
+                    current,
+                ) {
+                    select.set_value(&(current as u8).to_string());
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn view_confirm(&self, ctx: &Context<Self>) -> Html {
+        let Some(confirm) = &self.confirm else {
+            return html! {};
+        };
+
+        let (title, text, button) = match confirm {
+            Confirm::HideQuestion(_) => (
+                "event-confirm-hide-title",
+                "event-confirm-hide-text",
+                "event-confirm-hide",
+            ),
+            Confirm::BulkHide => (
+                "event-confirm-bulk-hide-title",
+                "event-confirm-hide-text",
+                "event-confirm-hide",
+            ),
+            Confirm::State(States::Archived) => (
+                "event-confirm-archive-title",
+                "event-confirm-archive-text",
+                "event-confirm-archive",
+            ),
+            Confirm::State(_) => (
+                "event-confirm-close-title",
+                "event-confirm-close-text",
+                "event-confirm-close",
+            ),
+        };
+
+        html! {
+            <ConfirmPopup
+                title={tr(title)}
+                text={tr(text)}
+                confirm={tr(button)}
+                danger=true
+                on_confirm={ctx.link().callback(|()| Msg::Confirmed(true))}
+                on_cancel={ctx.link().callback(|()| Msg::Confirmed(false))}
+            />
+        }
+    }
+
+    /// moderator search and the entry to the shortcut cheat sheet
+    fn view_search(&self, ctx: &Context<Self>) -> Html {
+        if !self.is_mod() {
+            return html! {};
+        }
+
+        html! {
+            <div class="mod-search">
+                <input
+                    type="search"
+                    ref={self.search_ref.clone()}
+                    value={self.search.clone()}
+                    placeholder={tr("event-search")}
+                    aria-label={tr("event-search")}
+                    oninput={ctx.link().callback(Msg::Search)}
+                />
+                <button class="link" onclick={ctx.link().callback(|_| Msg::ShortcutsHelp(true))}>
+                    { tr("event-shortcuts") }
+                </button>
+                <ShortcutsPopup
+                    show={self.shortcuts_help}
+                    on_close={ctx.link().callback(|()| Msg::ShortcutsHelp(false))}
+                />
+            </div>
+        }
+    }
+
+    /// actions on the questions the moderator ticked, only shown while some are
+    fn view_bulk_actions(&self, ctx: &Context<Self>) -> Html {
+        if !self.is_mod() || self.bulk_selection.is_empty() {
+            return html! {};
+        }
+
+        let tag = self.bulk_tag.trim().to_string();
+        let tag_action = BulkAction::Tag(Some(tag.clone()));
+
+        html! {
+            <div class="bulk-actions">
+                <span>
+                    { tr_args("event-bulk-selected", [("count", self.bulk_selection.len().into())]) }
+                </span>
+                <button class="link" onclick={ctx.link().callback(|_| Msg::Bulk(BulkAction::Hide))}>
+                    { tr("event-bulk-hide") }
+                </button>
+                <button class="link" onclick={ctx.link().callback(|_| Msg::Bulk(BulkAction::Answer))}>
+                    { tr("event-bulk-answer") }
+                </button>
+                <input
+                    type="text"
+                    value={self.bulk_tag.clone()}
+                    placeholder={tr("event-bulk-tag")}
+                    aria-label={tr("event-bulk-tag")}
+                    oninput={ctx.link().callback(Msg::BulkTagInput)}
+                />
+                <button
+                    class="link"
+                    disabled={tag.is_empty()}
+                    onclick={ctx.link().callback(move |_| Msg::Bulk(tag_action.clone()))}
+                >
+                    { tr("event-bulk-tag") }
+                </button>
+                <button class="link" onclick={ctx.link().callback(|_| Msg::Bulk(BulkAction::Tag(None)))}>
+                    { tr("event-bulk-untag") }
+                </button>
+                <button class="link" onclick={ctx.link().callback(|_| Msg::BulkClear)}>
+                    { tr("event-bulk-clear") }
+                </button>
+            </div>
+        }
+    }
+
+    /// questions in the order they are listed, the moderator moves through them with j/k
+    fn listed_questions(&self) -> impl Iterator<Item = &Rc<QuestionItem>> {
+        self.unscreened
+            .iter()
+            .chain(&self.unanswered)
+            .chain(&self.answered)
+            .chain(&self.hidden)
+    }
+
+    fn on_shortcut(&mut self, key: &str, ctx: &Context<Self>) -> bool {
+        match key {
+            "j" | "k" => {
+                let ids = self.listed_questions().map(|q| q.id).collect::<Vec<_>>();
+                let current = self
+                    .selected
+                    .and_then(|id| ids.iter().position(|other| *other == id));
+                let next = match (current, key) {
+                    (None, "j") => Some(0),
+                    (None, _) => ids.len().checked_sub(1),
+                    (Some(index), "j") => Some((index + 1).min(ids.len() - 1)),
+                    (Some(index), _) => Some(index.saturating_sub(1)),
+                };
+                self.selected = next.and_then(|index| ids.get(index).copied());
+                true
+            }
+            "a" | "h" | "p" => {
+                let Some(id) = self.selected else {
+                    return false;
+                };
+                let kind = match key {
+                    "a" => QuestionClickType::Answer,
+                    "h" => QuestionClickType::Hide,
+                    _ => QuestionClickType::Pin,
+                };
+                self.on_question_click(&kind, id, ctx)
+            }
+            "/" => {
+                if let Some(input) = self.search_ref.cast::<HtmlElement>() {
+                    let _ = input.focus();
+                }
+                false
+            }
+            "?" => {
+                self.shortcuts_help = !self.shortcuts_help;
+                true
+            }
+            "Escape" => {
+                self.selected = None;
+                self.shortcuts_help = false;
+                true
+            }
+            _ => false,
         }
     }
 
     fn handle_socket(&mut self, msg: SocketResponse, ctx: &Context<Self>) -> bool {
         match msg {
-            SocketResponse::Connecting | SocketResponse::Connected => {
+            SocketResponse::Connecting => {
                 self.manual_reconnect = false;
-                self.events.emit(GlobalEvent::SocketStatus {
-                    connected: true,
-                    timeout_secs: None,
-                });
 
                 false
             }
+            SocketResponse::Connected => {
+                self.manual_reconnect = false;
+                self.socket_failures = 0;
+                //Note: server tells us again right after connecting if a moderator is online
+                // or if the event is still full
+                self.moderator_online = false;
+                let was_waiting = std::mem::take(&mut self.waiting_room);
+                self.long_polling = false;
+                self.long_poll_retry = None;
+                self.set_connection(true, None);
+
+                //Note: we might have missed updates while disconnected
+                if self.socket_disconnected {
+                    self.socket_disconnected = false;
+                    log::info!("socket reconnected, resync event");
+                    self.refetch(ctx);
+                }
+
+                self.replay_pending(ctx);
+
+                was_waiting
+            }
             SocketResponse::Disconnected { reconnect } => {
-                self.events.emit(GlobalEvent::SocketStatus {
-                    connected: false,
-                    timeout_secs: reconnect.map(|duration| duration.num_seconds()),
-                });
+                self.socket_disconnected = true;
+                if count_socket_failure(&mut self.socket_failures) && !self.long_polling {
+                    log::warn!("socket keeps failing, fall back to long polling");
+                    self.long_polling = true;
+                    ctx.link().send_message(Msg::LongPoll);
+                }
+
+                self.set_connection(false, reconnect.map(|duration| duration.num_seconds()));
 
                 false
             }
-            SocketResponse::Message(msg) => {
-                let fetch_event = if msg == "e" {
-                    log::info!("received event update");
-                    true
-                } else if let Some(stripped_msg) = msg.strip_prefix("q:") {
-                    //TODO: only fetch q on "q"?
-
-                    let id = stripped_msg.parse::<i64>().unwrap_throw();
+            SocketResponse::Binary(data) => {
+                let applied = match EventDelta::from_msgpack(&data) {
+                    Ok(delta) => self.apply_delta(&delta),
+                    Err(e) => {
+                        log::error!("invalid binary delta: {e}");
+                        false
+                    }
+                };
 
-                    log::info!("received question update: {}", id);
+                if !applied {
+                    self.refetch(ctx);
+                }
 
-                    let found = self
-                        .state
-                        .event
-                        .as_ref()
-                        .is_some_and(|e| e.info.questions.iter().any(|q| q.id == id));
+                applied
+            }
+            SocketResponse::Message(msg) => {
+                let fetch_event = match WsMessage::decode(&msg) {
+                    Some(WsMessage::Event) => {
+                        log::info!("received event update");
+                        true
+                    }
+                    Some(WsMessage::Question(id)) => {
+                        //TODO: only fetch q on "q"?
+
+                        log::info!("received question update: {}", id);
+
+                        let found = self
+                            .store
+                            .event
+                            .as_ref()
+                            .is_some_and(|e| e.info.questions.iter().any(|q| q.id == id));
+
+                        if !found {
+                            log::info!("new question: {}", id);
+                            self.ui_dispatch
+                                .reduce(|old| (*old).clone().set_new_question(Some(id)).into());
+                            self.ui = self.ui_dispatch.get();
+                            self.announcement = tr("a11y-new-question");
+                            self.tab_alert.new_question();
+                        }
 
-                    if !found {
-                        log::info!("new question: {}", id);
-                        self.dispatch
-                            .reduce(|old| (*old).clone().set_new_question(Some(id)).into());
-                        self.state = self.dispatch.get();
+                        true
                     }
+                    Some(WsMessage::Viewers(viewers)) => {
+                        log::debug!("received viewer update: {}", viewers);
 
-                    true
-                } else if msg.starts_with("v:") {
-                    log::debug!("received viewer update: {}", msg);
+                        self.dispatch
+                            .reduce(|old| (*old).clone().set_event_viewers(viewers).into());
+                        self.store = self.dispatch.get();
 
-                    let viewers = msg
-                        .split(':')
-                        .nth(1)
-                        .and_then(|text| text.parse::<i64>().ok())
-                        .unwrap_or_default();
+                        false
+                    }
+                    Some(WsMessage::Delta(delta)) => !self.apply_delta(&delta),
+                    Some(WsMessage::Typing(count)) => {
+                        self.typing = count;
 
-                    self.dispatch
-                        .reduce(|old| (*old).clone().set_event_viewers(viewers).into());
-                    self.state = self.dispatch.get();
+                        let link = ctx.link().clone();
+                        self.typing_timeout = Some(Timeout::new(TYPING_EXPIRE_MS, move || {
+                            link.send_message(Msg::TypingExpired);
+                        }));
 
-                    false
-                } else {
-                    log::error!("unknown push msg: {msg}",);
-                    true
+                        false
+                    }
+                    Some(WsMessage::ModeratorOnline(online)) => {
+                        self.moderator_online = online;
+                        false
+                    }
+                    //Note: pongs are consumed by the socket already
+                    Some(WsMessage::Pong) => false,
+                    Some(WsMessage::WaitingRoom) => {
+                        self.waiting_room = true;
+                        false
+                    }
+                    //Note: updates were missed while waiting
+                    Some(WsMessage::Admitted) => {
+                        self.waiting_room = false;
+                        true
+                    }
+                    None => {
+                        log::error!("unknown push msg: {msg}",);
+                        true
+                    }
                 };
 
                 if fetch_event {
-                    request_fetch(
-                        self.current_event_id.clone(),
-                        ctx.props().secret.clone(),
-                        ctx.link(),
-                    );
+                    self.refetch(ctx);
                 }
 
                 !fetch_event
@@ -1059,25 +2743,179 @@ impl Event {
         }
     }
 
+    fn handle_updates(&mut self, updates: Option<EventUpdates>, ctx: &Context<Self>) -> bool {
+        if !self.long_polling {
+            return false;
+        }
+
+        let Some(updates) = updates else {
+            let link = ctx.link().clone();
+            self.long_poll_retry = Some(Timeout::new(LONG_POLL_RETRY_MS, move || {
+                link.send_message(Msg::LongPoll);
+            }));
+            return false;
+        };
+
+        let version = self
+            .store
+            .event
+            .as_ref()
+            .map(|e| e.version)
+            .unwrap_or_default();
+
+        if updates.version > version {
+            self.refetch(ctx);
+        }
+
+        request_updates(
+            self.current_event_id.clone(),
+            updates.version.max(version),
+            ctx.link(),
+        );
+
+        false
+    }
+
+    /// returns `false` if the delta could not be applied and a refetch is needed
+    fn apply_delta(&mut self, delta: &EventDelta) -> bool {
+        let moderator = matches!(self.mode, Mode::Moderator) || self.ui.admin;
+
+        if let EventDelta::QuestionAdded(question) = delta {
+            let found = self
+                .store
+                .event
+                .as_ref()
+                .is_some_and(|e| e.info.questions.iter().any(|q| q.id == question.id));
+
+            if !found {
+                log::info!("new question: {}", question.id);
+                self.ui_dispatch
+                    .reduce(|old| (*old).clone().set_new_question(Some(question.id)).into());
+                self.ui = self.ui_dispatch.get();
+                self.announcement = tr_args(
+                    "a11y-new-question-text",
+                    [("text", question.text.clone().into())],
+                );
+                self.tab_alert.new_question();
+            }
+        }
+
+        let mut store = (*self.store).clone();
+        if !store.apply_delta(delta, moderator) {
+            return false;
+        }
+
+        self.dispatch.set(store);
+        self.store = self.dispatch.get();
+        self.init_event();
+
+        true
+    }
+
+    fn refetch(&self, ctx: &Context<Self>) {
+        //Note: viewers keep the questions paged in so far
+        let questions = ctx.props().secret.is_none().then(|| {
+            self.store
+                .event
+                .as_ref()
+                .map_or(0, |e| e.info.questions.len())
+                .max(QUESTIONS_PAGE_DEFAULT_LIMIT)
+        });
+
+        request_fetch(
+            self.current_event_id.clone(),
+            ctx.props().secret.clone(),
+            questions,
+            ctx.link(),
+        );
+    }
+
+    /// the icon bar reads the `ConnectionStore`, popups and toasts listen to the event
+    fn set_connection(&self, connected: bool, timeout_secs: Option<i64>) {
+        Dispatch::<ConnectionStore>::new().set(ConnectionStore::new(connected, timeout_secs));
+        self.events.emit(GlobalEvent::SocketStatus {
+            connected,
+            timeout_secs,
+        });
+    }
+
     fn handle_global_event(&mut self, ev: GlobalEvent) -> bool {
         match ev {
             GlobalEvent::QuestionCreated(id) => {
-                self.dispatch
+                self.ui_dispatch
                     .reduce(|old| (*old).clone().set_new_question(Some(id)).into());
-                self.state = self.dispatch.get();
+                self.ui = self.ui_dispatch.get();
                 true
             }
             GlobalEvent::SocketManualReconnect => {
                 self.manual_reconnect = true;
                 true
             }
+            GlobalEvent::ActionQueued => {
+                self.pending = LocalCache::pending_actions(&self.current_event_id);
+                true
+            }
             _ => false,
         }
     }
 
+    /// re-renders every second before the start and once when the event starts or ends
+    fn on_schedule_tick(&mut self) -> bool {
+        let now = Utc::now().timestamp();
+        let last = std::mem::replace(&mut self.schedule_last_tick, now);
+
+        let Some(e) = &self.store.event else {
+            return false;
+        };
+        let data = &e.info.data;
+
+        if data.ended(now) {
+            self.schedule_timer = None;
+        }
+
+        data.not_started(last) || data.ended(now) != data.ended(last)
+    }
+
+    fn start_schedule_timer(&mut self, e: &GetEventResponse, ctx: &Context<Self>) {
+        let data = &e.info.data;
+        let now = Utc::now().timestamp();
+        let ahead = data.not_started(now) || data.end_unix.is_some_and(|_| !data.ended(now));
+
+        if !ahead {
+            self.schedule_timer = None;
+        } else if self.schedule_timer.is_none() {
+            let link = ctx.link().clone();
+            self.schedule_last_tick = now;
+            self.schedule_timer = Some(Interval::new(1000, move || {
+                link.send_message(Msg::ScheduleTick);
+            }));
+        }
+    }
+
+    fn record_visit(&mut self, e: &GetEventResponse, ctx: &Context<Self>) {
+        if self.visit_recorded || e.is_deleted() || ctx.props().embedded {
+            return;
+        }
+        self.visit_recorded = true;
+
+        LocalCache::add_recent_event(RecentEvent {
+            id: e.info.tokens.public_token.clone(),
+            name: e.info.data.name.clone(),
+            secret: ctx
+                .props()
+                .secret
+                .clone()
+                .filter(|_| e.info.tokens.is_mod()),
+            visited_unix: Utc::now().timestamp(),
+        });
+    }
+
     fn handle_fetched(&mut self, res: Option<GetEventResponse>, ctx: &Context<Self>) -> bool {
         self.on_fetched(&res);
         if let Some(e) = res {
+            self.start_schedule_timer(&e, ctx);
+            self.record_visit(&e, ctx);
+
             if !e.info.is_premium() && self.query_params.paypal_token.is_some() {
                 request_capture(
                     e.info.tokens.public_token,
@@ -1089,3 +2927,43 @@ impl Event {
         true
     }
 }
+
+/// `1d 02:03:04` or `02:03:04` if less than a day is left
+fn format_countdown(seconds: i64) -> String {
+    let left = Duration::seconds(seconds.max(0));
+    let days = left.num_days();
+    let hours = left.num_hours() - days * 24;
+    let minutes = left.num_minutes() - left.num_hours() * 60;
+    let seconds = left.num_seconds() - left.num_minutes() * 60;
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/// `Auto` leaves the choice to the attendee's light/dark preference
+const fn theme_class(theme: EventTheme) -> &'static str {
+    match theme {
+        EventTheme::Auto => "theme-auto",
+        EventTheme::Light => "theme-light",
+        EventTheme::Dark => "theme-dark",
+        EventTheme::Custom => "theme-custom",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_failing_first_connects_fall_back_to_long_poll() {
+        let mut failures = 0;
+
+        assert!(!count_socket_failure(&mut failures));
+        assert!(!count_socket_failure(&mut failures));
+        assert!(count_socket_failure(&mut failures));
+        assert!(count_socket_failure(&mut failures));
+    }
+}
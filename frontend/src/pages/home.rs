@@ -2,13 +2,21 @@ use wasm_bindgen::UnwrapThrowExt;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-use crate::{components::Footer, routes::Route};
+use crate::{
+    components::Footer,
+    i18n::tr,
+    local_cache::{LocalCache, RecentEvent},
+    routes::Route,
+    time::format_date,
+};
 
 #[allow(clippy::empty_structs_with_brackets)]
 #[derive(Clone, Debug, Eq, PartialEq, Properties)]
 pub struct HomeProps;
 
-pub struct Home;
+pub struct Home {
+    recent: Vec<RecentEvent>,
+}
 pub enum Msg {
     Example,
     CreateEvent,
@@ -18,7 +26,9 @@ impl Component for Home {
     type Properties = HomeProps;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        Self {}
+        Self {
+            recent: LocalCache::recent_events(),
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -40,35 +50,34 @@ impl Component for Home {
         html! {
             <div id="home">
                 <div class="feature-dark">
-                    <h1 id="firstheader">{ "Real-Time questions from your audience" }</h1>
+                    <h1 id="firstheader">{ tr("home-title") }</h1>
                     <p>
-                        { "Have you ever organized a meetup, conference, or moderated a panel discussion and wanted an easy way to receive real-time
-                        questions from your audience? Welcome to Live-Ask." }
+                        { tr("home-intro") }
                     </p>
                     <button class="button-red" onclick={ctx.link().callback(|_| Msg::CreateEvent)}>
-                        { "Create your Event" }
+                        { tr("home-create") }
                     </button>
                     <button class="button-dark" onclick={ctx.link().callback(|_| Msg::Example)}>
-                        { "View Example" }
+                        { tr("home-example") }
                     </button>
+                    { self.view_recent() }
                 </div>
                 <div class="feature-bright">
-                    <h1>{ "Incognito" }</h1>
+                    <h1>{ tr("home-incognito") }</h1>
                     <img alt="anonymous" class="img-simple" src="assets/main-incognito.png" />
                     <p>
-                        { "No registration necessary - everyone can ask questions and vote. Participant anonymity ensures freedom of speech and a smooth
-                        user experience." }
+                        { tr("home-incognito-text") }
                     </p>
                 </div>
                 <div class="feature-dark">
-                    <h1>{ "Effortless" }</h1>
+                    <h1>{ tr("home-effortless") }</h1>
                     <img alt="effortless" class="img-simple" src="assets/main-effortless.png" />
                     <p>
-                        { "Set up your event in seconds! Share the link with your audience and let them decide what’s hot." }
+                        { tr("home-effortless-text") }
                     </p>
                 </div>
                 <div class="feature-bright">
-                    <h1>{ "Real-Time" }</h1>
+                    <h1>{ tr("home-realtime") }</h1>
                     <img
                         alt="realtime"
                         class="img-simple"
@@ -76,11 +85,11 @@ impl Component for Home {
                         src="assets/main-realtime.png"
                     />
                     <p>
-                        { " Designed for live events. Questions can be asked and voted on in real time. This way, you can interact with everyone seamlessly." }
+                        { tr("home-realtime-text") }
                     </p>
                 </div>
                 <div class="feature-dark">
-                    <h1>{ "Cross Platform" }</h1>
+                    <h1>{ tr("home-crossplatform") }</h1>
                     <img
                         alt="simple"
                         class="img-simple"
@@ -88,21 +97,20 @@ impl Component for Home {
                         src="assets/main-crossplatform.png"
                     />
                     <p>
-                        { "Use Live-Ask on your mobile phone, tablet, laptop or desktop computer. Go crazy and cast it to your smart TV, too!" }
+                        { tr("home-crossplatform-text") }
                     </p>
                 </div>
                 <div class="feature-bright">
-                    <h1>{ "Social" }</h1>
+                    <h1>{ tr("home-social") }</h1>
                     <img alt="social" class="img-simple" src="assets/main-social.png" />
                     <p>
-                        { "We want to make sharing as effortless as possible. Have you organized an awesome event? Live-Ask makes it easy to share it
-                        with others. You bring the great content, we’ll help you spread the word." }
+                        { tr("home-social-text") }
                     </p>
                 </div>
                 <div class="feature-dark">
-                    <h1>{ "Try it now for free!" }</h1>
+                    <h1>{ tr("home-try") }</h1>
                     <button class="button-red" onclick={ctx.link().callback(|_| Msg::CreateEvent)}>
-                        { "Create your Event" }
+                        { tr("home-create") }
                     </button>
                 </div>
                 <Footer />
@@ -110,3 +118,50 @@ impl Component for Home {
         }
     }
 }
+
+impl Home {
+    fn view_recent(&self) -> Html {
+        if self.recent.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            <div class="recent-events">
+                <div class="title">{ tr("home-recent") }</div>
+                { for self.recent.iter().map(Self::view_recent_event) }
+            </div>
+        }
+    }
+
+    fn view_recent_event(event: &RecentEvent) -> Html {
+        let (route, role) = event.secret.as_ref().map_or_else(
+            || {
+                (
+                    Route::Event {
+                        id: event.id.clone(),
+                    },
+                    "home-recent-viewer",
+                )
+            },
+            |secret| {
+                (
+                    Route::EventMod {
+                        id: event.id.clone(),
+                        secret: secret.clone(),
+                    },
+                    "home-recent-moderator",
+                )
+            },
+        );
+
+        let visited = format_date(event.visited_unix);
+
+        html! {
+            <div class="recent-event" key={event.id.clone()}>
+                <Link<Route> to={route}>{ event.name.clone() }</Link<Route>>
+                <span class="role">{ tr(role) }</span>
+                <span class="date">{ visited }</span>
+            </div>
+        }
+    }
+}
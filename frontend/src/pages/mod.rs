@@ -1,13 +1,33 @@
+#[cfg(feature = "admin")]
 mod admin;
 mod event;
 mod home;
 mod newevent;
+mod neworg;
+mod org;
+mod org_checkout;
+#[cfg(feature = "print")]
 mod print;
 mod privacy;
+#[cfg(feature = "screen")]
+mod screen;
+mod series;
+mod slug;
+mod subscription;
 
+#[cfg(feature = "admin")]
 pub use admin::AdminLogin;
-pub use event::{Event, LoadingState, BASE_API};
+pub use event::{Event, LoadingState, BASE_API, BASE_SOCKET};
 pub use home::Home;
 pub use newevent::NewEvent;
+pub use neworg::NewOrg;
+pub use org::Org;
+pub use org_checkout::OrgCheckout;
+#[cfg(feature = "print")]
 pub use print::Print;
 pub use privacy::Privacy;
+#[cfg(feature = "screen")]
+pub use screen::Screen;
+pub use series::Series;
+pub use slug::Slug;
+pub use subscription::Subscription;
@@ -1,7 +1,18 @@
-use crate::{components::TextArea, fetch, routes::Route, tracking};
-use shared::{CreateEventError, CreateEventValidation, EventInfo};
-use wasm_bindgen::UnwrapThrowExt;
-use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use crate::{
+    components::TextArea,
+    fetch,
+    i18n::{tr, tr_args},
+    local_cache::{EventTemplate, LocalCache, NewEventDraft, TEMPLATE_DATE_PLACEHOLDER},
+    routes::Route,
+    tracking,
+};
+use chrono::{Local, NaiveDateTime};
+use shared::{
+    CreateEventError, CreateEventValidation, Entitlements, EventData, EventInfo, ModEvent,
+    OrgMembership,
+};
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -11,8 +22,16 @@ pub struct NewEvent {
     name: String,
     desc: String,
     email: String,
+    start_unix: Option<i64>,
+    end_unix: Option<i64>,
     name_ref: NodeRef,
     errors: CreateEventValidation,
+    templates: Vec<EventTemplate>,
+    /// index into `templates` the form was filled from
+    template: Option<usize>,
+    /// account token of a subscription, see `LocalCache::subscription`
+    subscription: Option<String>,
+    subscription_active: bool,
 }
 
 #[derive(Debug)]
@@ -20,28 +39,60 @@ pub enum Input {
     Name,
     Email,
     Desc,
+    Start,
+    End,
 }
 
-#[allow(clippy::empty_structs_with_brackets)]
 #[derive(Clone, Debug, Eq, PartialEq, Properties)]
-pub struct NewEventProps;
+pub struct NewEventProps {
+    /// creates the event in the workspace of this organization
+    #[prop_or_default]
+    pub org: Option<OrgMembership>,
+}
 
 pub enum Msg {
     Create,
     CreatedResult(Option<EventInfo>),
     InputChange(Input, InputEvent),
+    TemplateSelected(Event),
+    TemplateRemove,
+    SubscriptionState(bool),
+    Subscribe,
+    SubscribeUrl(Option<String>),
 }
 impl Component for NewEvent {
     type Message = Msg;
     type Properties = NewEventProps;
 
-    fn create(_: &Context<Self>) -> Self {
+    fn create(ctx: &Context<Self>) -> Self {
+        let subscription = LocalCache::subscription();
+
+        if let Some(account) = subscription.clone() {
+            ctx.link().send_future(async move {
+                match fetch::subscription_state(BASE_API, account).await {
+                    Ok(state) => Msg::SubscriptionState(state.active),
+                    Err(e) => {
+                        log::error!("subscription state error: {e}");
+                        Msg::SubscriptionState(false)
+                    }
+                }
+            });
+        }
+
+        let draft = LocalCache::new_event_draft().unwrap_or_default();
+
         Self {
-            name: String::new(),
-            desc: String::new(),
+            name: draft.name,
+            desc: draft.description,
             email: String::new(),
+            start_unix: None,
+            end_unix: None,
             name_ref: NodeRef::default(),
             errors: CreateEventValidation::default(),
+            templates: LocalCache::templates(),
+            template: None,
+            subscription,
+            subscription_active: false,
         }
     }
 
@@ -55,14 +106,49 @@ impl Component for NewEvent {
                 } else {
                     Some(self.email.trim().to_owned())
                 };
+                let (start, end) = (self.start_unix, self.end_unix);
+                let subscription = self
+                    .subscription
+                    .clone()
+                    .filter(|_| self.subscription_active);
+                let settings = self
+                    .template
+                    .and_then(|index| self.templates.get(index))
+                    .map(|template| (template.settings.clone(), template.branding.clone()));
+                let org = ctx.props().org.clone();
 
                 tracking::track_event(tracking::EVNT_NEWEVENT_FINISH);
 
                 ctx.link().send_future(async move {
-                    let res = fetch::create_event(BASE_API, name, desc, email).await;
+                    let res = match org {
+                        Some(org) => {
+                            fetch::org_create_event(BASE_API, org, name, desc, email, start, end)
+                                .await
+                        }
+                        None => {
+                            fetch::create_event(
+                                BASE_API,
+                                name,
+                                desc,
+                                email,
+                                subscription,
+                                start,
+                                end,
+                            )
+                            .await
+                        }
+                    };
 
                     match res {
-                        Ok(e) => Msg::CreatedResult(Some(e)),
+                        Ok(e) => {
+                            if let Some((settings, branding)) = settings {
+                                Self::apply_template_settings(&e, settings).await;
+                                if e.is_entitled(Entitlements::BRANDING) {
+                                    Self::apply_template_settings(&e, branding).await;
+                                }
+                            }
+                            Msg::CreatedResult(Some(e))
+                        }
                         Err(e) => {
                             log::error!("create error: {}", e);
                             Msg::CreatedResult(None)
@@ -74,6 +160,7 @@ impl Component for NewEvent {
 
             Msg::CreatedResult(event) => {
                 if let Some(event) = event {
+                    LocalCache::clear_new_event_draft();
                     ctx.link()
                         .navigator()
                         .unwrap_throw()
@@ -88,6 +175,57 @@ impl Component for NewEvent {
                 }
             }
 
+            Msg::TemplateSelected(ev) => {
+                let e: HtmlSelectElement = ev.target().unwrap_throw().dyn_into().unwrap_throw();
+                self.template = e.value().parse::<usize>().ok();
+
+                if let Some(template) = self.template.and_then(|index| self.templates.get(index)) {
+                    self.name = template.event_name();
+                    self.desc = template.description.clone();
+                    self.errors = self.errors.check(&self.name, &self.desc, &self.email);
+                    self.save_draft();
+                }
+
+                true
+            }
+
+            Msg::TemplateRemove => {
+                if let Some(template) = self
+                    .template
+                    .take()
+                    .map(|index| self.templates.remove(index))
+                {
+                    LocalCache::remove_template(&template.name);
+                }
+
+                true
+            }
+
+            Msg::SubscriptionState(active) => {
+                self.subscription_active = active;
+                true
+            }
+
+            Msg::Subscribe => {
+                ctx.link().send_future(async move {
+                    match fetch::request_subscription(BASE_API).await {
+                        Ok(upgrade) => Msg::SubscribeUrl(Some(upgrade.url)),
+                        Err(e) => {
+                            log::error!("subscription error: {e}");
+                            Msg::SubscribeUrl(None)
+                        }
+                    }
+                });
+                false
+            }
+
+            Msg::SubscribeUrl(url) => {
+                if let Some(url) = url {
+                    gloo_utils::window().location().assign(&url).unwrap_throw();
+                }
+                false
+            }
+
             Msg::InputChange(input, c) => {
                 match input {
                     Input::Name => {
@@ -95,6 +233,7 @@ impl Component for NewEvent {
                         self.name = target.value();
 
                         self.errors = self.errors.check(&self.name, &self.desc, &self.email);
+                        self.save_draft();
                     }
                     Input::Email => {
                         let target: HtmlInputElement = c.target_dyn_into().unwrap_throw();
@@ -107,6 +246,15 @@ impl Component for NewEvent {
                         self.desc = target.value();
 
                         self.errors = self.errors.check(&self.name, &self.desc, &self.email);
+                        self.save_draft();
+                    }
+                    Input::Start => {
+                        let target: HtmlInputElement = c.target_dyn_into().unwrap_throw();
+                        self.start_unix = parse_local_datetime(&target.value());
+                    }
+                    Input::End => {
+                        let target: HtmlInputElement = c.target_dyn_into().unwrap_throw();
+                        self.end_unix = parse_local_datetime(&target.value());
                     }
                 }
 
@@ -119,15 +267,16 @@ impl Component for NewEvent {
     fn view(&self, ctx: &Context<Self>) -> Html {
         html! {
             <div class="newevent-bg">
-                <div class="title">{ "Create Event" }</div>
+                <div class="title">{ tr("newevent-title") }</div>
                 <div class="form">
                     <div class="newevent">
+                        { self.view_templates(ctx) }
                         <div class="input-box">
                             <input
                                 ref={self.name_ref.clone()}
                                 type="text"
                                 name="eventname"
-                                placeholder="event name"
+                                placeholder={tr("newevent-name")}
                                 value={self.name.clone()}
                                 maxlength="30"
                                 required=true
@@ -141,7 +290,7 @@ impl Component for NewEvent {
                             <input
                                 type="email"
                                 name="mail"
-                                placeholder="email (optional)"
+                                placeholder={tr("newevent-email")}
                                 value={self.email.clone()}
                                 maxlength="100"
                                 oninput={ctx.link().callback(|input| Msg::InputChange(Input::Email,input))}
@@ -154,7 +303,7 @@ impl Component for NewEvent {
                             <TextArea
                                 id="input-desc"
                                 name="desc"
-                                placeholder="event description"
+                                placeholder={tr("newevent-description")}
                                 value={self.desc.clone()}
                                 maxlength="1000"
                                 required=true
@@ -165,13 +314,35 @@ impl Component for NewEvent {
                         <div hidden={self.errors.desc.is_none()} class="invalid">
                             { Self::desc_error(&self.errors.desc).unwrap_or_default() }
                         </div>
+                        <div class="input-box schedule">
+                            <label for="input-start">{ tr("newevent-start") }</label>
+                            <input
+                                id="input-start"
+                                type="datetime-local"
+                                name="start"
+                                oninput={ctx.link().callback(|input| Msg::InputChange(Input::Start,input))}
+                            />
+                        </div>
+                        <div class="input-box schedule">
+                            <label for="input-end">{ tr("newevent-end") }</label>
+                            <input
+                                id="input-end"
+                                type="datetime-local"
+                                name="end"
+                                oninput={ctx.link().callback(|input| Msg::InputChange(Input::End,input))}
+                            />
+                        </div>
+                        <div hidden={self.schedule_valid()} class="invalid">
+                            { tr("newevent-schedule-invalid") }
+                        </div>
+                        { self.view_subscription(ctx) }
                     </div>
                     <button
                         class="button-finish"
                         disabled={!self.can_create()}
                         onclick={ctx.link().callback(|_| Msg::Create)}
                     >
-                        { "finish" }
+                        { tr("newevent-finish") }
                     </button>
                 </div>
             </div>
@@ -180,44 +351,177 @@ impl Component for NewEvent {
 }
 
 impl NewEvent {
+    fn view_templates(&self, ctx: &Context<Self>) -> Html {
+        if self.templates.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            <div class="templates">
+                <select onchange={ctx.link().callback(Msg::TemplateSelected)}>
+                    <option value="" selected={self.template.is_none()}>{ tr("newevent-template") }</option>
+                    {
+                        self.templates.iter().enumerate().map(|(index, template)| html! {
+                            <option value={index.to_string()} selected={self.template == Some(index)}>
+                                { template.name.clone() }
+                            </option>
+                        }).collect::<Html>()
+                    }
+                </select>
+                <button
+                    class="button-white"
+                    hidden={self.template.is_none()}
+                    onclick={ctx.link().callback(|_| Msg::TemplateRemove)}
+                >
+                    { tr("newevent-template-remove") }
+                </button>
+                <div class="hint">
+                    { tr_args("newevent-template-hint", [("placeholder", TEMPLATE_DATE_PLACEHOLDER.into())]) }
+                </div>
+            </div>
+        }
+    }
+
+    fn view_subscription(&self, ctx: &Context<Self>) -> Html {
+        if ctx.props().org.is_some() {
+            return html! {
+                <div class="subscription">
+                    { tr("newevent-org") }
+                </div>
+            };
+        }
+
+        if self.subscription_active {
+            return html! {
+                <div class="subscription">
+                    { tr("newevent-subscription-active") }
+                </div>
+            };
+        }
+
+        html! {
+            <div class="subscription">
+                {
+                    tr(if self.subscription.is_some() {
+                        "newevent-subscription-inactive"
+                    } else {
+                        "newevent-subscription-offer"
+                    })
+                }
+                <button class="button-white" onclick={ctx.link().callback(|_| Msg::Subscribe)}>
+                    { tr("newevent-subscribe") }
+                </button>
+                { Self::view_workspace() }
+            </div>
+        }
+    }
+
+    fn view_workspace() -> Html {
+        html! {
+            <div class="workspace">
+                {
+                    if let Some(org) = LocalCache::org() {
+                        html! {
+                            <Link<Route> to={Route::Org { id: org.org, token: org.token }}>
+                                { tr("newevent-workspace-open") }
+                            </Link<Route>>
+                        }
+                    } else {
+                        html! {
+                            <Link<Route> to={Route::NewOrg}>
+                                { tr("newevent-workspace-create") }
+                            </Link<Route>>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+
+    /// keeps the typed name and description across reloads until the event is created
+    fn save_draft(&self) {
+        LocalCache::set_new_event_draft(&NewEventDraft {
+            name: self.name.clone(),
+            description: self.desc.clone(),
+        });
+    }
+
+    /// moderation settings of a template are applied right after creating the event
+    #[allow(clippy::future_not_send)]
+    async fn apply_template_settings(e: &EventInfo, settings: ModEvent) {
+        let Some(secret) = e.tokens.moderator_token.clone() else {
+            return;
+        };
+
+        if let Err(err) =
+            fetch::mod_edit_event(BASE_API, e.tokens.public_token.clone(), secret, settings).await
+        {
+            log::error!("apply template error: {err}");
+        }
+    }
+
     fn can_create(&self) -> bool {
-        !self.errors.has_any() && !self.name.is_empty() && !self.desc.is_empty()
+        !self.errors.has_any()
+            && !self.name.is_empty()
+            && !self.desc.is_empty()
+            && self.schedule_valid()
+    }
+
+    fn schedule_valid(&self) -> bool {
+        EventData {
+            start_unix: self.start_unix,
+            end_unix: self.end_unix,
+            ..Default::default()
+        }
+        .schedule_valid()
     }
 
     pub fn desc_error(state: &Option<CreateEventError>) -> Option<String> {
         match state {
-            Some(CreateEventError::Empty) => Some("Description cannot be empty".to_string()),
-            Some(CreateEventError::MinLength(len, max)) => Some(format!(
-                "Description must be at least {max} characters long. ({len}/{max})",
+            Some(CreateEventError::Empty) => Some(tr("newevent-description-empty")),
+            Some(CreateEventError::MinLength(len, max)) => Some(tr_args(
+                "newevent-description-too-short",
+                [("len", (*len).into()), ("min", (*max).into())],
             )),
-            Some(CreateEventError::MaxLength(_, max)) => Some(format!(
-                "Description cannot be longer than {max} characters.",
+            Some(CreateEventError::MaxLength(_, max)) => Some(tr_args(
+                "newevent-description-too-long",
+                [("max", (*max).into())],
             )),
-            Some(_) => Some("unknown error".to_string()),
+            Some(_) => Some(tr("newevent-unknown-error")),
             None => None,
         }
     }
 
     pub fn name_error(state: &Option<CreateEventError>) -> Option<String> {
         match state {
-            Some(CreateEventError::Empty) => Some("Name is required.".to_string()),
-            Some(CreateEventError::MinLength(len, max)) => Some(format!(
-                "Name must be at least {max} characters long. ({len}/{max})"
+            Some(CreateEventError::Empty) => Some(tr("newevent-name-empty")),
+            Some(CreateEventError::MinLength(len, max)) => Some(tr_args(
+                "newevent-name-too-short",
+                [("len", (*len).into()), ("min", (*max).into())],
             )),
             Some(CreateEventError::MaxLength(_, max)) => {
-                Some(format!("Name cannot be longer than {max} characters."))
-            }
-            Some(CreateEventError::MaxWords(_, max)) => {
-                Some(format!("Name must not contain more than {max} words."))
+                Some(tr_args("newevent-name-too-long", [("max", (*max).into())]))
             }
+            Some(CreateEventError::MaxWords(_, max)) => Some(tr_args(
+                "newevent-name-too-many-words",
+                [("max", (*max).into())],
+            )),
             Some(CreateEventError::InvalidEmail) | None => None,
         }
     }
 
     pub fn email_error(state: &Option<CreateEventError>) -> Option<String> {
         match state {
-            Some(CreateEventError::InvalidEmail) => Some("Invalid Email Provided".to_string()),
+            Some(CreateEventError::InvalidEmail) => Some(tr("newevent-email-invalid")),
             _ => None,
         }
     }
 }
+
+/// parses the value of a `datetime-local` input in the browsers timezone
+fn parse_local_datetime(value: &str) -> Option<i64> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M")
+        .ok()
+        .and_then(|date| date.and_local_timezone(Local).earliest())
+        .map(|date| date.timestamp())
+}
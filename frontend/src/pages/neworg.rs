@@ -0,0 +1,133 @@
+use shared::{AddOrg, OrgMembership, ORG_MEMBER_NAME_MAX_LENGTH, ORG_NAME_MAX_LENGTH};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::{fetch, local_cache::LocalCache, pages::BASE_API, routes::Route};
+
+#[derive(Debug)]
+pub enum Input {
+    Name,
+    Owner,
+}
+
+pub enum Msg {
+    InputChange(Input, InputEvent),
+    Create,
+    Created(Option<OrgMembership>),
+}
+
+/// creates an organization, the creator becomes its owner
+pub struct NewOrg {
+    org: AddOrg,
+    sending: bool,
+    failed: bool,
+}
+
+impl Component for NewOrg {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            org: AddOrg::default(),
+            sending: false,
+            failed: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::InputChange(input, e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+
+                match input {
+                    Input::Name => self.org.name = target.value(),
+                    Input::Owner => self.org.owner = target.value(),
+                }
+
+                true
+            }
+            Msg::Create => {
+                if !self.org.is_valid() || self.sending {
+                    return false;
+                }
+
+                self.sending = true;
+
+                let org = self.org.clone();
+                ctx.link().send_future(async move {
+                    match fetch::create_org(BASE_API, org).await {
+                        Ok(membership) => Msg::Created(Some(membership)),
+                        Err(e) => {
+                            log::error!("create org error: {e}");
+                            Msg::Created(None)
+                        }
+                    }
+                });
+
+                true
+            }
+            Msg::Created(membership) => {
+                self.sending = false;
+
+                if let Some(membership) = membership {
+                    LocalCache::set_org(&membership);
+
+                    ctx.link().navigator().unwrap_throw().push(&Route::Org {
+                        id: membership.org,
+                        token: membership.token,
+                    });
+
+                    return false;
+                }
+
+                self.failed = true;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="newevent-bg">
+                <div class="title">{ "Create Workspace" }</div>
+                <div class="form">
+                    <div class="newevent">
+                        <div class="input-box">
+                            <input
+                                type="text"
+                                placeholder="organization name"
+                                value={self.org.name.clone()}
+                                maxlength={ORG_NAME_MAX_LENGTH.to_string()}
+                                required=true
+                                oninput={ctx.link().callback(|input| Msg::InputChange(Input::Name,input))}
+                            />
+                        </div>
+                        <div class="input-box">
+                            <input
+                                type="text"
+                                placeholder="your name"
+                                value={self.org.owner.clone()}
+                                maxlength={ORG_MEMBER_NAME_MAX_LENGTH.to_string()}
+                                required=true
+                                oninput={ctx.link().callback(|input| Msg::InputChange(Input::Owner,input))}
+                            />
+                        </div>
+                        <div hidden={!self.failed} class="invalid">
+                            { "The workspace could not be created." }
+                        </div>
+                    </div>
+                    <button
+                        class="button-finish"
+                        disabled={!self.org.is_valid() || self.sending}
+                        onclick={ctx.link().callback(|_| Msg::Create)}
+                    >
+                        { "create" }
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
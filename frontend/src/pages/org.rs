@@ -0,0 +1,337 @@
+use chrono::NaiveDateTime;
+use shared::{
+    AddOrgMember, OrgEvent, OrgMember, OrgMembership, OrgRole, OrgWorkspace,
+    ORG_MEMBER_NAME_MAX_LENGTH,
+};
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use super::LoadingState;
+use crate::{fetch, local_cache::LocalCache, pages::BASE_API, routes::Route};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct Props {
+    pub id: AttrValue,
+    pub token: AttrValue,
+}
+
+pub enum Msg {
+    Refresh,
+    Fetched(Option<OrgWorkspace>),
+    MemberNameInput(InputEvent),
+    MemberRoleChange(Event),
+    AddMember,
+    MemberAdded(Option<OrgMembership>),
+    RemoveMember(String),
+    Subscribe,
+    SubscribeUrl(Option<String>),
+}
+
+/// dashboard of an organization listing its events and members
+pub struct Org {
+    loading_state: LoadingState,
+    workspace: Option<OrgWorkspace>,
+    member: AddOrgMember,
+    sending: bool,
+}
+
+impl Component for Org {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link().send_message(Msg::Refresh);
+
+        Self {
+            loading_state: LoadingState::Loading,
+            workspace: None,
+            member: AddOrgMember::default(),
+            sending: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Refresh => {
+                let props = ctx.props();
+                let (id, token) = (props.id.to_string(), props.token.to_string());
+
+                ctx.link().send_future(async move {
+                    match fetch::org_workspace(BASE_API, id, token).await {
+                        Ok(workspace) => Msg::Fetched(Some(workspace)),
+                        Err(e) => {
+                            log::error!("org workspace error: {e}");
+                            Msg::Fetched(None)
+                        }
+                    }
+                });
+
+                false
+            }
+            Msg::Fetched(Some(workspace)) => {
+                LocalCache::set_org(&Self::membership(ctx));
+
+                self.loading_state = LoadingState::Loaded;
+                self.workspace = Some(workspace);
+                true
+            }
+            Msg::Fetched(None) => {
+                self.loading_state = LoadingState::NotFound;
+                true
+            }
+            Msg::MemberNameInput(e) => {
+                let target: HtmlInputElement = e.target_dyn_into().unwrap_throw();
+                self.member.name = target.value();
+                true
+            }
+            Msg::MemberRoleChange(ev) => {
+                let e: HtmlSelectElement = ev.target().unwrap_throw().dyn_into().unwrap_throw();
+                self.member.role = if e.value() == "owner" {
+                    OrgRole::Owner
+                } else {
+                    OrgRole::Moderator
+                };
+                false
+            }
+            Msg::AddMember => {
+                if !self.member.is_valid() || self.sending {
+                    return false;
+                }
+
+                self.sending = true;
+
+                let org = Self::membership(ctx);
+                let member = self.member.clone();
+                ctx.link().send_future(async move {
+                    match fetch::org_add_member(BASE_API, org.org, org.token, member).await {
+                        Ok(membership) => Msg::MemberAdded(Some(membership)),
+                        Err(e) => {
+                            log::error!("org add member error: {e}");
+                            Msg::MemberAdded(None)
+                        }
+                    }
+                });
+
+                true
+            }
+            Msg::MemberAdded(membership) => {
+                self.sending = false;
+
+                if membership.is_some() {
+                    self.member = AddOrgMember::default();
+                    ctx.link().send_message(Msg::Refresh);
+                }
+
+                true
+            }
+            Msg::RemoveMember(member) => {
+                let org = Self::membership(ctx);
+                ctx.link().send_future(async move {
+                    if let Err(e) =
+                        fetch::org_remove_member(BASE_API, org.org, org.token, member).await
+                    {
+                        log::error!("org remove member error: {e}");
+                    }
+
+                    Msg::Refresh
+                });
+
+                false
+            }
+            Msg::Subscribe => {
+                let org = Self::membership(ctx);
+                ctx.link().send_future(async move {
+                    match fetch::org_subscription(BASE_API, org.org, org.token).await {
+                        Ok(upgrade) => Msg::SubscribeUrl(Some(upgrade.url)),
+                        Err(e) => {
+                            log::error!("org subscription error: {e}");
+                            Msg::SubscribeUrl(None)
+                        }
+                    }
+                });
+
+                false
+            }
+            Msg::SubscribeUrl(url) => {
+                if let Some(url) = url {
+                    gloo_utils::window().location().assign(&url).unwrap_throw();
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Some(workspace) = &self.workspace else {
+            let text = if matches!(self.loading_state, LoadingState::Loading) {
+                "loading workspace..."
+            } else {
+                "workspace not found"
+            };
+
+            return html! {
+                <div class="event">
+                    <div class="noevent">
+                        <h2>{ text }</h2>
+                    </div>
+                </div>
+            };
+        };
+
+        let props = ctx.props();
+
+        html! {
+            <div class="newevent-bg">
+                <div class="title">{ workspace.name.clone() }</div>
+                <div class="form org">
+                    <div class="org-section">
+                        <div class="org-section-title">{ "Events" }</div>
+                        <Link<Route>
+                            classes="button-white"
+                            to={Route::OrgNewEvent { id: props.id.to_string(), token: props.token.to_string() }}
+                        >
+                            { "new event" }
+                        </Link<Route>>
+                        { Self::view_events(&workspace.events) }
+                    </div>
+                    { Self::view_billing(ctx, workspace) }
+                    { self.view_members(ctx, workspace) }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Org {
+    fn membership(ctx: &Context<Self>) -> OrgMembership {
+        OrgMembership {
+            org: ctx.props().id.to_string(),
+            token: ctx.props().token.to_string(),
+        }
+    }
+
+    fn view_events(events: &[OrgEvent]) -> Html {
+        if events.is_empty() {
+            return html! { <div class="org-note">{ "no events yet" }</div> };
+        }
+
+        events
+            .iter()
+            .filter(|e| !e.deleted)
+            .map(|e| {
+                let created = NaiveDateTime::from_timestamp_opt(e.create_time_unix, 0)
+                    .map(|date| date.format("%F").to_string())
+                    .unwrap_or_default();
+
+                html! {
+                    <div class="org-event" key={e.public_token.clone()}>
+                        <Link<Route> to={Route::EventMod { id: e.public_token.clone(), secret: e.moderator_token.clone() }}>
+                            { e.name.clone() }
+                        </Link<Route>>
+                        <span class="date">{ created }</span>
+                        { if e.premium { html! { <span class="premium">{ "premium" }</span> } } else { html! {} } }
+                    </div>
+                }
+            })
+            .collect::<Html>()
+    }
+
+    fn view_billing(ctx: &Context<Self>, workspace: &OrgWorkspace) -> Html {
+        if workspace.subscribed {
+            return html! {
+                <div class="org-section">
+                    <div class="org-note">{ "The team subscription makes every new event premium." }</div>
+                </div>
+            };
+        }
+
+        if !workspace.role.is_owner() {
+            return html! {};
+        }
+
+        html! {
+            <div class="org-section">
+                <div class="org-note">{ "A team subscription makes every event of the workspace premium." }</div>
+                <button class="button-white" onclick={ctx.link().callback(|_| Msg::Subscribe)}>
+                    { "subscribe" }
+                </button>
+            </div>
+        }
+    }
+
+    fn view_members(&self, ctx: &Context<Self>, workspace: &OrgWorkspace) -> Html {
+        let owner = workspace.role.is_owner();
+
+        html! {
+            <div class="org-section">
+                <div class="org-section-title">{ "Members" }</div>
+                {
+                    workspace.members.iter().map(|member| Self::view_member(ctx, workspace, member)).collect::<Html>()
+                }
+                { if owner { self.view_add_member(ctx) } else { html! {} } }
+            </div>
+        }
+    }
+
+    fn view_member(ctx: &Context<Self>, workspace: &OrgWorkspace, member: &OrgMember) -> Html {
+        let role = if member.role.is_owner() {
+            "owner"
+        } else {
+            "moderator"
+        };
+
+        //Note: only owners get to see the logins of the members
+        let Some(token) = member.token.clone() else {
+            return html! {
+                <div class="org-member">
+                    <span class="name">{ member.name.clone() }</span>
+                    <span class="role">{ role }</span>
+                </div>
+            };
+        };
+
+        let origin = gloo_utils::window().location().origin().unwrap_throw();
+        let login = format!("{origin}/org/{}/{token}", workspace.id);
+        let own = token == ctx.props().token.as_str();
+
+        html! {
+            <div class="org-member">
+                <span class="name">{ member.name.clone() }</span>
+                <span class="role">{ role }</span>
+                <input class="login" type="text" readonly=true value={login} />
+                <button
+                    class="button-white"
+                    hidden={own}
+                    onclick={ctx.link().callback(move |_| Msg::RemoveMember(token.clone()))}
+                >
+                    { "remove" }
+                </button>
+            </div>
+        }
+    }
+
+    fn view_add_member(&self, ctx: &Context<Self>) -> Html {
+        let can_add = self.member.is_valid() && !self.sending;
+
+        html! {
+            <div class="org-member-add">
+                <input
+                    type="text"
+                    placeholder="member name"
+                    maxlength={ORG_MEMBER_NAME_MAX_LENGTH.to_string()}
+                    value={self.member.name.clone()}
+                    oninput={ctx.link().callback(Msg::MemberNameInput)}
+                />
+                <select onchange={ctx.link().callback(Msg::MemberRoleChange)}>
+                    <option value="moderator" selected={!self.member.role.is_owner()}>{ "moderator" }</option>
+                    <option value="owner" selected={self.member.role.is_owner()}>{ "owner" }</option>
+                </select>
+                <button class="button-white" disabled={!can_add} onclick={ctx.link().callback(|_| Msg::AddMember)}>
+                    { "add member" }
+                </button>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::{fetch, local_cache::LocalCache, pages::BASE_API, routes::Route};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct Props {
+    /// checkout session stripe returns with, missing if the checkout was cancelled
+    #[prop_or_default]
+    pub session: Option<String>,
+}
+
+pub enum Msg {
+    Resolved(Option<String>),
+}
+
+/// return url of the team subscription checkout, opens the workspace with the membership kept in the browser
+pub struct OrgCheckout {
+    not_found: bool,
+}
+impl Component for OrgCheckout {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        match ctx.props().session.clone() {
+            Some(session) => ctx.link().send_future(async move {
+                match fetch::org_checkout(BASE_API, session).await {
+                    Ok(checkout) => Msg::Resolved(Some(checkout.org)),
+                    Err(e) => {
+                        log::error!("org checkout error: {e}");
+                        Msg::Resolved(None)
+                    }
+                }
+            }),
+            None => ctx
+                .link()
+                .send_message(Msg::Resolved(LocalCache::org().map(|org| org.org))),
+        }
+
+        Self { not_found: false }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let Msg::Resolved(org) = msg;
+
+        match LocalCache::org().filter(|membership| Some(&membership.org) == org.as_ref()) {
+            Some(membership) => {
+                ctx.link().navigator().unwrap_throw().replace(&Route::Org {
+                    id: membership.org,
+                    token: membership.token,
+                });
+                false
+            }
+            None => {
+                self.not_found = true;
+                true
+            }
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let text = if self.not_found {
+            "open your workspace link to see the subscription"
+        } else {
+            "returning to the workspace..."
+        };
+
+        html! {
+            <div class="event">
+                <div class="noevent">
+                    <h2>{ text }</h2>
+                </div>
+            </div>
+        }
+    }
+}
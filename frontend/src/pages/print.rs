@@ -1,19 +1,63 @@
 use super::LoadingState;
-use crate::{components::Qr, fetch, pages::BASE_API};
-use shared::GetEventResponse;
+use crate::{
+    components::{Branding, Markdown, Qr},
+    fetch,
+    i18n::{tr, tr_args},
+    pages::BASE_API,
+    time::format_date_time,
+};
+use shared::{EventBranding, EventData, GetEventResponse, QuestionItem};
+use std::cmp::Reverse;
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+use web_sys::HtmlSelectElement;
 use yew::prelude::*;
 
 #[derive(Clone, Debug, Eq, PartialEq, Properties)]
 pub struct Props {
     pub id: AttrValue,
+    /// moderators can include hidden questions
+    #[prop_or_default]
+    pub secret: Option<String>,
+}
+
+/// what the print version contains, chosen in a toolbar that is not printed itself
+#[derive(Clone, Copy, Debug)]
+struct PrintOptions {
+    answered: bool,
+    hidden: bool,
+    qr: bool,
+    /// a plain list instead of one block per question
+    compact: bool,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            answered: true,
+            hidden: false,
+            qr: true,
+            compact: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum PrintOption {
+    Answered,
+    Hidden,
+    Qr,
 }
 
 pub struct Print {
     event: Option<GetEventResponse>,
     loading_state: LoadingState,
+    options: PrintOptions,
 }
 pub enum Msg {
     Fetched(Option<GetEventResponse>),
+    Toggle(PrintOption),
+    LayoutChange(Event),
+    Print,
 }
 impl Component for Print {
     type Message = Msg;
@@ -21,11 +65,12 @@ impl Component for Print {
 
     fn create(ctx: &Context<Self>) -> Self {
         let event_id = ctx.props().id.to_string();
-        request_fetch(event_id, ctx.link());
+        request_fetch(event_id, ctx.props().secret.clone(), ctx.link());
 
         Self {
             loading_state: LoadingState::Loading,
             event: None,
+            options: PrintOptions::default(),
         }
     }
 
@@ -46,6 +91,23 @@ impl Component for Print {
 
                 true
             }
+            Msg::Toggle(option) => {
+                match option {
+                    PrintOption::Answered => self.options.answered = !self.options.answered,
+                    PrintOption::Hidden => self.options.hidden = !self.options.hidden,
+                    PrintOption::Qr => self.options.qr = !self.options.qr,
+                }
+                true
+            }
+            Msg::LayoutChange(ev) => {
+                let e: HtmlSelectElement = ev.target().unwrap_throw().dyn_into().unwrap_throw();
+                self.options.compact = e.value() == "compact";
+                true
+            }
+            Msg::Print => {
+                gloo_utils::window().print().unwrap_throw();
+                false
+            }
         }
     }
 
@@ -57,43 +119,61 @@ impl Component for Print {
 }
 
 //TODO: un-dup
-fn request_fetch(id: String, link: &html::Scope<Print>) {
+fn request_fetch(id: String, secret: Option<String>, link: &html::Scope<Print>) {
     link.send_future(async move {
-        let res = fetch::fetch_event(BASE_API, id, None).await;
+        let res = fetch::fetch_event(BASE_API, id, secret, None).await;
 
         res.map_or(Msg::Fetched(None), |val| Msg::Fetched(Some(val)))
     });
 }
 
 impl Print {
+    /// rendered in the timezone of the printing browser
+    fn view_schedule(data: &EventData) -> Html {
+        let schedule = match (data.start_unix, data.end_unix) {
+            (Some(start), Some(end)) => format!(
+                "{} \u{2013} {}",
+                format_date_time(start),
+                format_date_time(end)
+            ),
+            (Some(start), None) => {
+                tr_args("print-starts", [("date", format_date_time(start).into())])
+            }
+            (None, Some(end)) => tr_args("print-ends", [("date", format_date_time(end).into())]),
+            (None, None) => return html! {},
+        };
+
+        html! { <div class="event-schedule printable">{ schedule }</div> }
+    }
+
     fn view_internal(&self, ctx: &Context<Self>) -> Html {
         match self.loading_state {
             LoadingState::Loaded => self.view_event(ctx),
             LoadingState::Loading => {
                 html! {
                     <div class="noevent">
-                        <h2>{ "loading event..." }</h2>
+                        <h2>{ tr("event-loading") }</h2>
                     </div>
                 }
             }
             LoadingState::NotFound => {
                 html! {
                     <div class="noevent">
-                        <h2>{ "event not found" }</h2>
+                        <h2>{ tr("event-not-found") }</h2>
                     </div>
                 }
             }
             LoadingState::Deleted => {
                 html! {
                     <div class="noevent">
-                        <h2>{ "event deleted" }</h2>
+                        <h2>{ tr("event-deleted") }</h2>
                     </div>
                 }
             }
         }
     }
 
-    fn view_event(&self, _ctx: &Context<Self>) -> Html {
+    fn view_event(&self, ctx: &Context<Self>) -> Html {
         self.event.as_ref().map_or_else(
             || html! {},
             |e| {
@@ -103,23 +183,120 @@ impl Print {
                     e.info.data.short_url.clone()
                 };
 
+                let branding_style = e
+                    .info
+                    .data
+                    .branding
+                    .as_ref()
+                    .map(EventBranding::css_variables);
+
                 html! {
-                    <div>
+                    <div style={branding_style}>
                         <div class="bg-print" />
+                        { self.view_options(ctx, e) }
                         <div class="event-block">
+                            <Branding branding={e.info.data.branding.clone()} printable=true />
                             <div class="event-name printable">{ &e.info.data.name.clone() }</div>
                             <div class="event-desc printable" hidden={e.masked}>
-                                { {&e.info.data.description.clone()} }
-                            </div>
-                        </div>
-                        <div class="qrbox print">
-                            <div class="qr print">
-                                <Qr url={share_url} dimensions=300 />
+                                <Markdown class="markdown" text={e.info.data.description.clone()} />
                             </div>
+                            { Self::view_schedule(&e.info.data) }
                         </div>
+                        { if self.options.qr { html! {
+                            <>
+                                <div class="qrbox print">
+                                    <div class="qr print">
+                                        <Qr url={share_url.clone()} dimensions=300 />
+                                    </div>
+                                </div>
+                                <div class="print-url">{ share_url }</div>
+                            </>
+                        } } else { html! {} } }
+                        { self.view_questions(e) }
                     </div>
                 }
             },
         )
     }
+
+    fn view_options(&self, ctx: &Context<Self>, e: &GetEventResponse) -> Html {
+        let toggle = |option| ctx.link().callback(move |_| Msg::Toggle(option));
+
+        html! {
+            <div class="print-options">
+                <label>
+                    <input type="checkbox" checked={self.options.answered} onchange={toggle(PrintOption::Answered)} />
+                    { tr("print-option-answered") }
+                </label>
+                { if e.info.tokens.is_mod() { html! {
+                    <label>
+                        <input type="checkbox" checked={self.options.hidden} onchange={toggle(PrintOption::Hidden)} />
+                        { tr("print-option-hidden") }
+                    </label>
+                } } else { html! {} } }
+                <label>
+                    <input type="checkbox" checked={self.options.qr} onchange={toggle(PrintOption::Qr)} />
+                    { tr("print-option-qr") }
+                </label>
+                <select onchange={ctx.link().callback(Msg::LayoutChange)}>
+                    <option value="blocks" selected={!self.options.compact}>{ tr("print-layout-blocks") }</option>
+                    <option value="compact" selected={self.options.compact}>{ tr("print-layout-compact") }</option>
+                </select>
+                <button class="button-white" onclick={ctx.link().callback(|_| Msg::Print)}>
+                    { tr("print-print") }
+                </button>
+            </div>
+        }
+    }
+
+    /// answered questions first, each section ranked by score like the event page
+    fn view_questions(&self, e: &GetEventResponse) -> Html {
+        let mut questions: Vec<&QuestionItem> =
+            e.info.questions.iter().filter(|q| !q.screening).collect();
+        questions.sort_by_key(|q| Reverse(q.score()));
+
+        let hidden = self.options.hidden && e.info.tokens.is_mod();
+
+        html! {
+            <div class={classes!("print-questions", self.options.compact.then_some("compact"))}>
+                { if self.options.answered {
+                    Self::view_section(&tr("print-section-answered"), questions.iter().copied().filter(|q| q.answered && !q.hidden))
+                } else { html! {} } }
+                { Self::view_section(&tr("print-section-open"), questions.iter().copied().filter(|q| !q.answered && !q.hidden)) }
+                { if hidden {
+                    Self::view_section(&tr("print-section-hidden"), questions.iter().copied().filter(|q| q.hidden))
+                } else { html! {} } }
+            </div>
+        }
+    }
+
+    fn view_section<'a>(title: &str, questions: impl Iterator<Item = &'a QuestionItem>) -> Html {
+        let items = questions.map(Self::view_question).collect::<Vec<_>>();
+
+        if items.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            <div class="print-section">
+                <div class="print-section-title">{ title.to_string() }</div>
+                { items }
+            </div>
+        }
+    }
+
+    fn view_question(q: &QuestionItem) -> Html {
+        let likes = tr_args("question-likes", [("count", q.likes.into())]);
+
+        html! {
+            <div class="print-question" key={q.id}>
+                <div class="text">{ q.text.clone() }</div>
+                <div class="meta">
+                    <span>{ likes }</span>
+                    { q.name.clone().map(|name| html! { <span>{ name }</span> }).unwrap_or_default() }
+                    { if q.answered { html! { <span class="answered">{ tr("print-answered") }</span> } } else { html! {} } }
+                </div>
+            </div>
+        }
+    }
 }
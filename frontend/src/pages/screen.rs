@@ -0,0 +1,310 @@
+use super::LoadingState;
+use crate::{
+    components::{Countdown, EventSocket, Qr, SocketResponse},
+    fetch,
+    i18n::{tr, tr_args},
+    pages::{BASE_API, BASE_SOCKET},
+};
+use shared::{EventBranding, GetEventResponse, ModQuestion, QuestionItem, WsMessage};
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct Props {
+    pub id: AttrValue,
+    /// moderators advance through the questions from the screen
+    #[prop_or_default]
+    pub secret: Option<String>,
+}
+
+pub enum Msg {
+    Fetched(Option<GetEventResponse>),
+    Socket(SocketResponse),
+    KeyDown(KeyboardEvent),
+    Next,
+    Previous,
+    Updated,
+}
+
+/// big screen view projecting the top open question during the session
+pub struct Screen {
+    event: Option<GetEventResponse>,
+    loading_state: LoadingState,
+    socket_url: String,
+    /// questions answered from this screen, most recent last, to step back to them
+    answered: Vec<i64>,
+    root: NodeRef,
+}
+
+impl Component for Screen {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let event_id = ctx.props().id.to_string();
+        request_fetch(event_id.clone(), ctx.props().secret.clone(), ctx.link());
+
+        let socket_url = ctx.props().secret.as_ref().map_or_else(
+            || format!("{BASE_SOCKET}/push/{event_id}"),
+            |secret| format!("{BASE_SOCKET}/push/{event_id}?secret={secret}"),
+        );
+
+        Self {
+            loading_state: LoadingState::Loading,
+            event: None,
+            socket_url,
+            answered: Vec::new(),
+            root: NodeRef::default(),
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        //Note: keyboard control needs the focus, the screen covers the whole page anyway
+        if first_render && ctx.props().secret.is_some() {
+            if let Some(root) = self.root.cast::<HtmlElement>() {
+                root.focus().unwrap_throw();
+            }
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Fetched(res) => {
+                match res {
+                    Some(ev) => {
+                        if ev.is_deleted() {
+                            self.loading_state = LoadingState::Deleted;
+                        } else {
+                            self.loading_state = LoadingState::Loaded;
+                            self.event = Some(ev);
+                        }
+                    }
+                    None => self.loading_state = LoadingState::NotFound,
+                }
+
+                true
+            }
+            Msg::Socket(msg) => {
+                //Note: only a single question is shown, refetching is simpler than applying deltas
+                let refetch = match msg {
+                    SocketResponse::Message(msg) => matches!(
+                        WsMessage::decode(&msg),
+                        Some(WsMessage::Event | WsMessage::Question(_) | WsMessage::Delta(_))
+                    ),
+                    SocketResponse::Binary(_) => true,
+                    SocketResponse::Connecting
+                    | SocketResponse::Connected
+                    | SocketResponse::Disconnected { .. } => false,
+                };
+
+                if refetch {
+                    request_fetch(
+                        ctx.props().id.to_string(),
+                        ctx.props().secret.clone(),
+                        ctx.link(),
+                    );
+                }
+
+                false
+            }
+            Msg::KeyDown(e) => {
+                //Note: presenter remotes send page up/down
+                match e.key().as_str() {
+                    "ArrowRight" | "PageDown" | " " => ctx.link().send_message(Msg::Next),
+                    "ArrowLeft" | "PageUp" => ctx.link().send_message(Msg::Previous),
+                    _ => return false,
+                }
+
+                e.prevent_default();
+                false
+            }
+            Msg::Next => {
+                let Some(id) = self
+                    .event
+                    .as_ref()
+                    .and_then(Self::top_question)
+                    .map(|q| q.id)
+                else {
+                    return false;
+                };
+
+                self.answered.push(id);
+                self.set_answered(id, true, ctx)
+            }
+            Msg::Previous => self
+                .answered
+                .pop()
+                .is_some_and(|id| self.set_answered(id, false, ctx)),
+            Msg::Updated => {
+                request_fetch(
+                    ctx.props().id.to_string(),
+                    ctx.props().secret.clone(),
+                    ctx.link(),
+                );
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div
+                class="screen"
+                tabindex="0"
+                ref={self.root.clone()}
+                onkeydown={ctx.link().callback(Msg::KeyDown)}
+            >
+                <EventSocket
+                    reconnect=false
+                    url={self.socket_url.clone()}
+                    msg={ctx.link().callback(Msg::Socket)}
+                />
+                { self.view_internal() }
+                { self.view_controls(ctx) }
+            </div>
+        }
+    }
+}
+
+fn request_fetch(id: String, secret: Option<String>, link: &html::Scope<Screen>) {
+    link.send_future(async move {
+        let res = fetch::fetch_event(BASE_API, id, secret, None).await;
+
+        res.map_or(Msg::Fetched(None), |val| Msg::Fetched(Some(val)))
+    });
+}
+
+impl Screen {
+    /// applied right away so the next question shows without waiting for the server
+    fn set_answered(&mut self, id: i64, answered: bool, ctx: &Context<Self>) -> bool {
+        let (Some(secret), Some(e)) = (ctx.props().secret.clone(), self.event.as_mut()) else {
+            return false;
+        };
+
+        let Some(q) = e.info.questions.iter_mut().find(|q| q.id == id) else {
+            return false;
+        };
+
+        q.answered = answered;
+
+        let modify = ModQuestion {
+            hide: q.hidden,
+            answered,
+            screened: !q.screening,
+            pinned: q.pinned,
+            shadow_ban: None,
+        };
+
+        let event = ctx.props().id.to_string();
+        ctx.link().send_future(async move {
+            if let Err(e) = fetch::mod_question(BASE_API, event, secret, id, modify).await {
+                log::error!("mod_question error: {e}");
+            }
+
+            Msg::Updated
+        });
+
+        true
+    }
+
+    fn view_controls(&self, ctx: &Context<Self>) -> Html {
+        if ctx.props().secret.is_none() || self.event.is_none() {
+            return html! {};
+        }
+
+        html! {
+            <div class="screen-controls">
+                <button
+                    disabled={self.answered.is_empty()}
+                    onclick={ctx.link().callback(|_| Msg::Previous)}
+                >
+                    { tr("screen-previous") }
+                </button>
+                <button
+                    disabled={self.event.as_ref().and_then(Self::top_question).is_none()}
+                    onclick={ctx.link().callback(|_| Msg::Next)}
+                >
+                    { tr("screen-next") }
+                </button>
+            </div>
+        }
+    }
+
+    fn view_internal(&self) -> Html {
+        match (&self.loading_state, &self.event) {
+            (LoadingState::Loaded, Some(e)) => Self::view_event(e),
+            (LoadingState::Loading | LoadingState::Loaded, _) => {
+                html! { <div class="screen-message">{ tr("event-loading") }</div> }
+            }
+            (LoadingState::NotFound, _) => {
+                html! { <div class="screen-message">{ tr("event-not-found") }</div> }
+            }
+            (LoadingState::Deleted, _) => {
+                html! { <div class="screen-message">{ tr("event-deleted") }</div> }
+            }
+        }
+    }
+
+    fn view_event(e: &GetEventResponse) -> Html {
+        let share_url = if e.info.data.short_url.is_empty() {
+            e.info.data.long_url.clone().unwrap_or_default()
+        } else {
+            e.info.data.short_url.clone()
+        };
+
+        let branding_style = e
+            .info
+            .data
+            .branding
+            .as_ref()
+            .map(EventBranding::css_variables);
+
+        html! {
+            <div class="screen-event" style={branding_style}>
+                <div class="screen-name">{ e.info.data.name.clone() }</div>
+                {
+                    e.info.timer.clone().map(|timer| html! { <Countdown {timer} /> }).unwrap_or_default()
+                }
+                { Self::view_question(Self::top_question(e)) }
+                <div class="screen-join">
+                    <div class="qr">
+                        <Qr url={share_url.clone()} dimensions=160 />
+                    </div>
+                    <div class="url">{ share_url }</div>
+                </div>
+            </div>
+        }
+    }
+
+    /// most liked open question, ties go to the older one
+    fn top_question(e: &GetEventResponse) -> Option<&QuestionItem> {
+        e.info
+            .questions
+            .iter()
+            .filter(|q| !q.answered && !q.hidden && !q.screening)
+            .max_by(|a, b| {
+                a.score()
+                    .cmp(&b.score())
+                    .then(b.create_time_unix.cmp(&a.create_time_unix))
+            })
+    }
+
+    fn view_question(question: Option<&QuestionItem>) -> Html {
+        let Some(q) = question else {
+            return html! { <div class="screen-message">{ tr("screen-ask-now") }</div> };
+        };
+
+        let likes = tr_args("question-likes", [("count", q.likes.into())]);
+
+        html! {
+            <div class="screen-question" key={q.id}>
+                <div class="text">{ q.text.clone() }</div>
+                <div class="meta">
+                    <span>{ likes }</span>
+                    { q.name.clone().map(|name| html! { <span>{ name }</span> }).unwrap_or_default() }
+                </div>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use super::LoadingState;
+use crate::{fetch, pages::BASE_API, routes::Route};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct Props {
+    pub slug: AttrValue,
+}
+
+/// custom short url of a premium event, forwards to the event itself
+pub struct Slug {
+    loading_state: LoadingState,
+}
+pub enum Msg {
+    Fetched(Option<String>),
+}
+impl Component for Slug {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        request_target(ctx.props().slug.to_string(), ctx.link());
+
+        Self {
+            loading_state: LoadingState::Loading,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Fetched(Some(id)) => {
+                ctx.link()
+                    .navigator()
+                    .unwrap_throw()
+                    .replace(&Route::Event { id });
+
+                false
+            }
+            Msg::Fetched(None) => {
+                self.loading_state = LoadingState::NotFound;
+                true
+            }
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let text = if matches!(self.loading_state, LoadingState::Loading) {
+            "loading event..."
+        } else {
+            "event not found"
+        };
+
+        html! {
+            <div class="event">
+                <div class="noevent">
+                    <h2>{ text }</h2>
+                </div>
+            </div>
+        }
+    }
+}
+
+fn request_target(slug: String, link: &html::Scope<Slug>) {
+    link.send_future(async move {
+        let res = fetch::slug_target(BASE_API, slug).await;
+
+        res.map_or(Msg::Fetched(None), |target| {
+            Msg::Fetched(Some(target.public_token))
+        })
+    });
+}
@@ -0,0 +1,42 @@
+use wasm_bindgen::UnwrapThrowExt;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::{local_cache::LocalCache, routes::Route};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct Props {
+    pub account: AttrValue,
+}
+
+/// return url of the subscription checkout, keeps the account token for creating events
+pub struct Subscription;
+impl Component for Subscription {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        LocalCache::set_subscription(&ctx.props().account);
+
+        Self {}
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            ctx.link()
+                .navigator()
+                .unwrap_throw()
+                .replace(&Route::NewEvent);
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="event">
+                <div class="noevent">
+                    <h2>{ "subscription saved..." }</h2>
+                </div>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+
+use crate::environment::{la_env, LiveAskEnv};
+
+/// installs `sw.js` caching the app shell, skipped locally to not serve stale builds
+pub fn register_service_worker() {
+    if matches!(la_env(Some(env!("LA_ENV"))), LiveAskEnv::Local) {
+        return;
+    }
+
+    let container = gloo_utils::window().navigator().service_worker();
+
+    spawn_local(async move {
+        if let Err(e) = JsFuture::from(container.register("/sw.js")).await {
+            log::error!("service worker registration error: {e:?}");
+        }
+    });
+}
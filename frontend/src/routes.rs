@@ -10,6 +10,30 @@ pub enum Route {
     Event { id: String },
     #[at("/event/print/:id")]
     Print { id: String },
+    #[at("/eventmod/print/:id/:secret")]
+    PrintMod { id: String, secret: String },
+    #[at("/screen/:id")]
+    Screen { id: String },
+    #[at("/screen/:id/:secret")]
+    ScreenMod { id: String, secret: String },
+    #[at("/embed/:id")]
+    Embed { id: String },
+    #[at("/e/:slug")]
+    Slug { slug: String },
+    #[at("/series/:id")]
+    Series { id: String },
+    #[at("/subscription/:account")]
+    Subscription { account: String },
+    #[at("/neworg")]
+    NewOrg,
+    #[at("/orgcheckout")]
+    OrgCheckoutCancelled,
+    #[at("/orgcheckout/:session")]
+    OrgCheckout { session: String },
+    #[at("/org/:id/:token")]
+    Org { id: String, token: String },
+    #[at("/org/:id/:token/newevent")]
+    OrgNewEvent { id: String, token: String },
     #[at("/eventmod/:id/:secret")]
     EventMod { id: String, secret: String },
     #[at("/login")]
@@ -0,0 +1,196 @@
+use chrono::{DateTime, Duration, Utc};
+use shared::{EventDelta, GetEventResponse, QuestionsPage, WordCount};
+use yewdux::store::Store;
+
+/// the event fetched by the event page, kept up to date by its socket
+#[derive(Default, Clone, Eq, PartialEq, Store)]
+pub struct EventStore {
+    pub event: Option<GetEventResponse>,
+}
+
+impl EventStore {
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn set_event(mut self, v: Option<GetEventResponse>) -> Self {
+        self.event = v;
+        self
+    }
+
+    #[must_use]
+    pub fn append_questions(mut self, page: QuestionsPage) -> Self {
+        if let Some(e) = &mut self.event {
+            e.append_questions(page);
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn set_event_viewers(mut self, viewers: i64) -> Self {
+        if let Some(e) = &mut self.event {
+            e.viewers = viewers;
+        }
+        self
+    }
+
+    /// applies a websocket delta to the current event.
+    /// returns `false` if the delta cannot be applied locally and the event needs to be refetched.
+    pub fn apply_delta(&mut self, delta: &EventDelta, moderator: bool) -> bool {
+        let Some(e) = &mut self.event else {
+            return false;
+        };
+
+        let questions = &mut e.info.questions;
+
+        match delta {
+            EventDelta::QuestionAdded(question) => {
+                if !questions.iter().any(|q| q.id == question.id) {
+                    questions.push(question.clone());
+                    e.questions_total = e.questions_total.map(|total| total.saturating_add(1));
+                }
+                true
+            }
+            EventDelta::QuestionLiked { id, likes } => {
+                if let Some(q) = questions.iter_mut().find(|q| q.id == *id) {
+                    q.likes = *likes;
+                    true
+                } else {
+                    //Note: viewers do not know hidden or unscreened questions
+                    !moderator
+                }
+            }
+            EventDelta::QuestionStateChanged {
+                id,
+                hidden,
+                answered,
+                screening,
+                pinned,
+            } => {
+                let visible = moderator || (!hidden && !screening);
+
+                match questions.iter().position(|q| q.id == *id) {
+                    Some(index) if visible => {
+                        let q = &mut questions[index];
+                        q.hidden = *hidden;
+                        q.answered = *answered;
+                        q.screening = *screening;
+                        q.pinned = *pinned;
+                        true
+                    }
+                    Some(index) => {
+                        questions.remove(index);
+                        e.questions_total = e.questions_total.map(|total| total.saturating_sub(1));
+                        true
+                    }
+                    //Note: question became visible, we need its content
+                    None => !visible,
+                }
+            }
+            EventDelta::EventStateChanged(state) => {
+                e.info.state = *state;
+                true
+            }
+            EventDelta::PollChanged(poll) => {
+                if let Some(p) = e.info.polls.iter_mut().find(|p| p.id == poll.id) {
+                    *p = poll.clone();
+                } else {
+                    e.info.polls.push(poll.clone());
+                }
+                true
+            }
+            EventDelta::PollVoted { id, votes } => {
+                let Some(poll) = e.info.polls.iter_mut().find(|p| p.id == *id) else {
+                    return false;
+                };
+
+                if poll.options.len() != votes.len() {
+                    return false;
+                }
+
+                for (option, votes) in poll.options.iter_mut().zip(votes) {
+                    option.votes = *votes;
+                }
+                true
+            }
+            EventDelta::WordCloudChanged(cloud) => {
+                if let Some(c) = e.info.word_clouds.iter_mut().find(|c| c.id == cloud.id) {
+                    *c = cloud.clone();
+                } else {
+                    e.info.word_clouds.push(cloud.clone());
+                }
+                true
+            }
+            EventDelta::WordCloudWord { id, text, count } => {
+                let Some(cloud) = e.info.word_clouds.iter_mut().find(|c| c.id == *id) else {
+                    return false;
+                };
+
+                if let Some(word) = cloud.words.iter_mut().find(|w| w.text == *text) {
+                    word.count = *count;
+                } else {
+                    cloud.words.push(WordCount {
+                        text: text.clone(),
+                        count: *count,
+                    });
+                }
+                true
+            }
+            EventDelta::SpeakersChanged(speakers) => {
+                e.info.speakers = speakers.clone();
+                true
+            }
+            EventDelta::TimerChanged(timer) => {
+                e.info.timer = timer.clone();
+                true
+            }
+        }
+    }
+}
+
+/// state of the event socket, changes on every (failed) reconnect
+#[derive(Clone, Eq, PartialEq, Store)]
+pub struct ConnectionStore {
+    pub connected: bool,
+    /// next reconnect attempt while disconnected
+    pub reconnect_at: Option<DateTime<Utc>>,
+}
+
+impl Default for ConnectionStore {
+    fn default() -> Self {
+        Self {
+            connected: true,
+            reconnect_at: None,
+        }
+    }
+}
+
+impl ConnectionStore {
+    #[must_use]
+    pub fn new(connected: bool, timeout_secs: Option<i64>) -> Self {
+        Self {
+            connected,
+            reconnect_at: timeout_secs.map(|secs| Utc::now() + Duration::seconds(secs)),
+        }
+    }
+}
+
+/// what the current visitor sees and is allowed to do, independent of the event data
+#[derive(Default, Clone, Eq, PartialEq, Store)]
+pub struct UiStore {
+    /// question to highlight as new
+    pub new_question: Option<i64>,
+    pub admin: bool,
+}
+
+impl UiStore {
+    #[must_use]
+    pub const fn set_new_question(mut self, v: Option<i64>) -> Self {
+        self.new_question = v;
+        self
+    }
+
+    #[must_use]
+    pub const fn set_admin(mut self, v: bool) -> Self {
+        self.admin = v;
+        self
+    }
+}
@@ -0,0 +1,162 @@
+use std::{cell::Cell, rc::Rc};
+
+use gloo_timers::callback::Interval;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AudioContext, OscillatorType};
+
+use crate::i18n::tr_args;
+
+/// the tab title alternates with the alert at this pace
+const FLASH_INTERVAL_MS: u32 = 1000;
+const CHIME_FREQUENCY_HZ: f32 = 880.0;
+const CHIME_VOLUME: f32 = 0.08;
+const CHIME_DURATION_SECS: f64 = 0.4;
+/// gain the chime fades out to, an exponential ramp cannot reach zero
+const CHIME_FADE_OUT: f32 = 0.0001;
+
+/// chime and flashing tab title telling moderators about new questions while the tab is in the background
+pub struct TabAlert {
+    enabled: bool,
+    /// questions that arrived since the tab was last visible
+    unseen: Rc<Cell<usize>>,
+    /// title of the page before flashing started
+    title: Option<String>,
+    flash: Option<Interval>,
+    audio: Option<AudioContext>,
+}
+
+impl TabAlert {
+    #[must_use]
+    pub fn new(enabled: bool) -> Self {
+        let mut alert = Self {
+            enabled: false,
+            unseen: Rc::default(),
+            title: None,
+            flash: None,
+            audio: None,
+        };
+        alert.set_enabled(enabled);
+        alert
+    }
+
+    #[must_use]
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// browsers only let audio play that was set up during a user gesture,
+    /// so enabling it by a click makes the first chime work right away
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+
+        if enabled {
+            if self.audio.is_none() {
+                self.audio = AudioContext::new().ok();
+            }
+        } else {
+            self.stop();
+        }
+    }
+
+    pub fn new_question(&mut self) {
+        if !self.enabled || !gloo_utils::document().hidden() {
+            return;
+        }
+
+        self.unseen.set(self.unseen.get().saturating_add(1));
+        self.chime();
+
+        if self.flash.is_none() {
+            self.start_flashing();
+        }
+    }
+
+    fn start_flashing(&mut self) {
+        let title = gloo_utils::document().title();
+        self.title = Some(title.clone());
+
+        let unseen = Rc::clone(&self.unseen);
+        let show_alert = Rc::new(Cell::new(true));
+
+        self.flash = Some(Interval::new(FLASH_INTERVAL_MS, move || {
+            let document = gloo_utils::document();
+
+            //Note: keeps ticking while the tab is visible again, the next question flashes right away
+            if !document.hidden() {
+                unseen.set(0);
+                show_alert.set(true);
+                document.set_title(&title);
+                return;
+            }
+
+            if show_alert.get() {
+                document.set_title(&tr_args(
+                    "tab-alert-title",
+                    [("count", unseen.get().into())],
+                ));
+            } else {
+                document.set_title(&title);
+            }
+            show_alert.set(!show_alert.get());
+        }));
+    }
+
+    fn stop(&mut self) {
+        self.flash = None;
+        self.unseen.set(0);
+
+        if let Some(title) = self.title.take() {
+            gloo_utils::document().set_title(&title);
+        }
+    }
+
+    fn chime(&self) {
+        let Some(audio) = self.audio.clone() else {
+            return;
+        };
+
+        wasm_bindgen_futures::spawn_local(async move {
+            //Note: a context created without user gesture (setting restored on load) starts suspended
+            if let Ok(resume) = audio.resume() {
+                let _ = JsFuture::from(resume).await;
+            }
+
+            if let Err(e) = play_chime(&audio) {
+                log::warn!("chime failed: {e:?}");
+            }
+        });
+    }
+}
+
+impl Drop for TabAlert {
+    fn drop(&mut self) {
+        self.stop();
+
+        if let Some(audio) = self.audio.take() {
+            let _ = audio.close();
+        }
+    }
+}
+
+/// short sine tone fading out
+fn play_chime(audio: &AudioContext) -> Result<(), JsValue> {
+    let oscillator = audio.create_oscillator()?;
+    let gain = audio.create_gain()?;
+    let now = audio.current_time();
+
+    oscillator.set_type(OscillatorType::Sine);
+    oscillator.frequency().set_value(CHIME_FREQUENCY_HZ);
+
+    gain.gain().set_value_at_time(CHIME_VOLUME, now)?;
+    gain.gain()
+        .exponential_ramp_to_value_at_time(CHIME_FADE_OUT, now + CHIME_DURATION_SECS)?;
+
+    oscillator.connect_with_audio_node(&gain)?;
+    gain.connect_with_audio_node(&audio.destination())?;
+
+    oscillator.start()?;
+    oscillator.stop_with_when(now + CHIME_DURATION_SECS)?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use easy_cast::Conv;
+use js_sys::{Array, Date, Intl, Object, Reflect};
+use wasm_bindgen::{JsValue, UnwrapThrowExt};
+
+use crate::i18n;
+
+/// IANA name of the timezone the browser runs in, e.g. `Europe/Berlin`
+#[must_use]
+pub fn local_timezone() -> Option<String> {
+    let options = Intl::DateTimeFormat::new(&Array::new(), &Object::new()).resolved_options();
+
+    Reflect::get(&options, &JsValue::from_str("timeZone"))
+        .ok()
+        .and_then(|tz| tz.as_string())
+}
+
+/// date of a utc unix timestamp in the chosen language and the browser timezone, e.g. `Oct 15, 2026`
+#[must_use]
+pub fn format_date(unix: i64) -> String {
+    format_locale(unix, &[("dateStyle", "medium")])
+}
+
+/// like `format_date` followed by the time of day
+#[must_use]
+pub fn format_date_time(unix: i64) -> String {
+    format_locale(unix, &[("dateStyle", "medium"), ("timeStyle", "short")])
+}
+
+/// time of day of a utc unix timestamp, e.g. `14:05`
+#[must_use]
+pub fn format_time(unix: i64) -> String {
+    format_locale(unix, &[("timeStyle", "short")])
+}
+
+fn format_locale(unix: i64, options: &[(&str, &str)]) -> String {
+    let date = Date::new(&JsValue::from_f64(f64::conv(unix.saturating_mul(1000))));
+
+    let js_options = Object::new();
+    for (key, value) in options {
+        Reflect::set(
+            &js_options,
+            &JsValue::from_str(key),
+            &JsValue::from_str(value),
+        )
+        .unwrap_throw();
+    }
+
+    date.to_locale_string(i18n::language().code(), &js_options)
+        .into()
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// number of days the admin dashboard covers
+pub const ADMIN_DASHBOARD_DAYS: i64 = 14;
+
+/// totals of one day (utc)
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct AdminDayStats {
+    /// `YYYY-MM-DD`
+    pub day: String,
+    /// events created
+    pub events: u64,
+    /// paid premium upgrades
+    pub upgrades: u64,
+}
+
+/// operational overview shown on the admin page
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct AdminDashboard {
+    /// newest first
+    pub days: Vec<AdminDayStats>,
+    /// id of the answering server instance, the figures below only cover this one
+    #[serde(default)]
+    pub instance: String,
+    /// open websocket connections of the answering server instance
+    pub connections: usize,
+    /// requests answered by the answering server instance since its start
+    pub requests: u64,
+    /// requests of those that ended in a server error (5xx)
+    #[serde(rename = "serverErrors")]
+    pub server_errors: u64,
+}
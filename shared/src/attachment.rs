@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+pub const ATTACHMENT_MAX_SIZE_MB: usize = 5;
+pub const ATTACHMENT_MAX_SIZE: usize = ATTACHMENT_MAX_SIZE_MB * 1024 * 1024;
+pub const ATTACHMENT_CONTENT_TYPES: [&str; 4] =
+    ["image/png", "image/jpeg", "image/gif", "image/webp"];
+
+/// prefix of every attachment object key: `attachments/<event>/<file>`
+pub const ATTACHMENT_KEY_PREFIX: &str = "attachments";
+
+pub const LOGO_MAX_SIZE_MB: usize = 1;
+pub const LOGO_MAX_SIZE: usize = LOGO_MAX_SIZE_MB * 1024 * 1024;
+pub const LOGO_CONTENT_TYPES: [&str; 3] = ["image/png", "image/jpeg", "image/webp"];
+
+/// prefix of every uploaded event logo: `logos/<event>/<file>`
+pub const LOGO_KEY_PREFIX: &str = "logos";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct AttachmentRequest {
+    pub content_type: String,
+    pub size: usize,
+}
+
+impl AttachmentRequest {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.size > 0
+            && self.size <= ATTACHMENT_MAX_SIZE
+            && ATTACHMENT_CONTENT_TYPES.contains(&self.content_type.as_str())
+    }
+
+    #[must_use]
+    pub fn is_valid_logo(&self) -> bool {
+        self.size > 0
+            && self.size <= LOGO_MAX_SIZE
+            && LOGO_CONTENT_TYPES.contains(&self.content_type.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct AttachmentUpload {
+    /// object key to pass along in `AddQuestion::attachment` or `EventBranding::logo`
+    pub key: String,
+    /// presigned url the file has to be `PUT` to
+    pub upload_url: String,
+}
+
+#[must_use]
+pub fn attachment_key(event: &str, file: &str) -> String {
+    format!("{ATTACHMENT_KEY_PREFIX}/{event}/{file}")
+}
+
+/// returns the file part of an attachment key if it belongs to `event`
+#[must_use]
+pub fn attachment_file<'a>(event: &str, key: &'a str) -> Option<&'a str> {
+    key_file(ATTACHMENT_KEY_PREFIX, event, key)
+}
+
+#[must_use]
+pub fn logo_key(event: &str, file: &str) -> String {
+    format!("{LOGO_KEY_PREFIX}/{event}/{file}")
+}
+
+/// returns the file part of a logo key if it belongs to `event`
+#[must_use]
+pub fn logo_file<'a>(event: &str, key: &'a str) -> Option<&'a str> {
+    key_file(LOGO_KEY_PREFIX, event, key)
+}
+
+fn key_file<'a>(prefix: &str, event: &str, key: &'a str) -> Option<&'a str> {
+    key.strip_prefix(prefix)?
+        .strip_prefix('/')?
+        .strip_prefix(event)?
+        .strip_prefix('/')
+        .filter(|file| !file.is_empty() && !file.contains('/'))
+}
@@ -0,0 +1,115 @@
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+pub const BLOCKED_TERMS_MAX: usize = 100;
+pub const BLOCKED_TERM_MAX_LENGTH: usize = 50;
+
+/// what happens to a question containing a blocked term
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum BlockedTermsAction {
+    /// question waits in the approval queue
+    #[default]
+    #[serde(rename = "review")]
+    Review,
+    #[serde(rename = "hide")]
+    Hide,
+}
+
+/// per event list of words and phrases the moderators do not want to see unreviewed
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct BlockedTerms {
+    pub terms: Vec<String>,
+    #[serde(default)]
+    pub action: BlockedTermsAction,
+}
+
+impl BlockedTerms {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.terms.len() <= BLOCKED_TERMS_MAX
+            && self.terms.iter().all(|term| {
+                !term.trim().is_empty() && term.chars().count() <= BLOCKED_TERM_MAX_LENGTH
+            })
+    }
+
+    /// trimmed, lowercase and without duplicates
+    #[must_use]
+    pub fn normalized(mut self) -> Self {
+        let mut terms: Vec<String> = Vec::with_capacity(self.terms.len());
+
+        for term in self.terms.iter().map(|term| term.trim().to_lowercase()) {
+            if !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+
+        self.terms = terms;
+        self
+    }
+
+    /// byte ranges of the blocked terms in `text`, see [`find_terms`]
+    #[must_use]
+    pub fn find(&self, text: &str) -> Vec<Range<usize>> {
+        find_terms(text, &self.terms)
+    }
+
+    #[must_use]
+    pub fn matches(&self, text: &str) -> bool {
+        !self.find(text).is_empty()
+    }
+}
+
+/// byte ranges of whole word occurrences of `terms` in `text`, ignoring case.
+/// ranges are ordered and do not overlap, the longest term wins at the same position.
+#[must_use]
+pub fn find_terms(text: &str, terms: &[String]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut next_start = 0;
+
+    for (start, _) in text.char_indices() {
+        if start < next_start || !word_boundary(text, start) {
+            continue;
+        }
+
+        let end = terms
+            .iter()
+            .filter(|term| !term.is_empty())
+            .filter_map(|term| match_end(text, start, term))
+            .filter(|end| word_boundary(text, *end))
+            .max();
+
+        if let Some(end) = end {
+            ranges.push(start..end);
+            next_start = end;
+        }
+    }
+
+    ranges
+}
+
+/// end of `term` if `text` continues with it at `start`
+fn match_end(text: &str, start: usize, term: &str) -> Option<usize> {
+    let mut chars = text.get(start..)?.char_indices();
+
+    for expected in term.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+
+    Some(
+        chars
+            .next()
+            .map_or(text.len(), |(offset, _)| start + offset),
+    )
+}
+
+/// no letter or digit on both sides of `index`
+fn word_boundary(text: &str, index: usize) -> bool {
+    let before = text.get(..index).and_then(|text| text.chars().next_back());
+    let after = text.get(index..).and_then(|text| text.chars().next());
+
+    !(before.is_some_and(char::is_alphanumeric) && after.is_some_and(char::is_alphanumeric))
+}
@@ -0,0 +1,163 @@
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+pub const BLOCKLIST_MAX_ENTRIES: usize = 1_000;
+pub const BLOCK_NOTE_MAX_LENGTH: usize = 200;
+pub const BLOCK_FINGERPRINT_MAX_LENGTH: usize = 64;
+/// request header carrying the fingerprint (participant id) of the browser
+pub const FINGERPRINT_HEADER: &str = "x-liveask-fingerprint";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum BlockTarget {
+    /// single address or range in CIDR notation, e.g. `203.0.113.0/24`
+    #[default]
+    #[serde(rename = "ip")]
+    IpRange,
+    /// participant id the browser sends along in `FINGERPRINT_HEADER`
+    #[serde(rename = "fingerprint")]
+    Fingerprint,
+}
+
+/// client that may neither create events nor ask questions anywhere on the platform
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct BlockEntry {
+    pub target: BlockTarget,
+    pub value: String,
+    /// why the client got blocked, only shown to admins
+    #[serde(default)]
+    pub note: String,
+    /// set by the server
+    #[serde(rename = "createTimeUnix", default)]
+    pub create_time_unix: i64,
+}
+
+impl BlockEntry {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.note.chars().count() <= BLOCK_NOTE_MAX_LENGTH
+            && match self.target {
+                BlockTarget::IpRange => parse_ip_range(&self.value).is_some(),
+                BlockTarget::Fingerprint => {
+                    !self.value.is_empty() && self.value.len() <= BLOCK_FINGERPRINT_MAX_LENGTH
+                }
+            }
+    }
+
+    /// both entries block the same clients
+    #[must_use]
+    pub fn same_target(&self, other: &Self) -> bool {
+        self.target == other.target && self.value == other.value
+    }
+
+    #[must_use]
+    pub fn matches(&self, ip: Option<IpAddr>, fingerprint: Option<&str>) -> bool {
+        match self.target {
+            BlockTarget::IpRange => ip.is_some_and(|ip| {
+                parse_ip_range(&self.value)
+                    .is_some_and(|(network, prefix)| in_range(ip, network, prefix))
+            }),
+            BlockTarget::Fingerprint => fingerprint == Some(self.value.as_str()),
+        }
+    }
+}
+
+/// platform wide abuse blocklist maintained by the admins
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct Blocklist {
+    pub entries: Vec<BlockEntry>,
+}
+
+impl Blocklist {
+    #[must_use]
+    pub fn is_blocked(&self, ip: Option<IpAddr>, fingerprint: Option<&str>) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.matches(ip, fingerprint))
+    }
+}
+
+/// network address and prefix length of `range`, an address without prefix covers only itself
+#[must_use]
+pub fn parse_ip_range(range: &str) -> Option<(IpAddr, u32)> {
+    let (address, prefix) = range.trim().split_once('/').unwrap_or((range.trim(), ""));
+
+    let address: IpAddr = address.parse().ok()?;
+    let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+
+    let prefix = if prefix.is_empty() {
+        max_prefix
+    } else {
+        prefix.parse().ok()?
+    };
+
+    (prefix <= max_prefix).then_some((address, prefix))
+}
+
+fn in_range(ip: IpAddr, network: IpAddr, prefix: u32) -> bool {
+    //Note: compared as IPv6, so IPv4 clients on a dual stack socket (`::ffff:a.b.c.d`) match IPv4 ranges
+    let (network, prefix) = match network {
+        IpAddr::V4(network) => (network.to_ipv6_mapped(), prefix + 96),
+        IpAddr::V6(network) => (network, prefix),
+    };
+    let ip = match ip {
+        IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+        IpAddr::V6(ip) => ip,
+    };
+
+    //Note: a shift by the full width (prefix 0) overflows, that range contains everything
+    let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or_default();
+    u128::from(ip) & mask == u128::from(network) & mask
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn blocked(range: &str, ip: &str) -> bool {
+        BlockEntry {
+            target: BlockTarget::IpRange,
+            value: String::from(range),
+            ..Default::default()
+        }
+        .matches(ip.parse().ok(), None)
+    }
+
+    #[test]
+    fn test_single_address() {
+        assert!(blocked("203.0.113.7", "203.0.113.7"));
+        assert!(blocked("203.0.113.7/32", "203.0.113.7"));
+        assert!(!blocked("203.0.113.7/32", "203.0.113.8"));
+    }
+
+    #[test]
+    fn test_ipv4_ranges() {
+        assert!(blocked("203.0.113.0/24", "203.0.113.255"));
+        assert!(!blocked("203.0.113.0/24", "203.0.114.0"));
+        assert!(blocked("0.0.0.0/0", "198.51.100.1"));
+        assert!(!blocked("0.0.0.0/0", "2001:db8::1"));
+    }
+
+    #[test]
+    fn test_ipv6_ranges() {
+        assert!(blocked("2001:db8::/32", "2001:db8:ffff::1"));
+        assert!(!blocked("2001:db8::/32", "2001:db9::1"));
+        assert!(blocked("2001:db8::1/128", "2001:db8::1"));
+        assert!(blocked("::/0", "2001:db8::1"));
+        assert!(!blocked("2001:db8::/32", "203.0.113.7"));
+    }
+
+    #[test]
+    fn test_ipv4_mapped() {
+        assert!(blocked("203.0.113.0/24", "::ffff:203.0.113.7"));
+        assert!(!blocked("203.0.113.0/24", "::ffff:203.0.114.7"));
+        assert!(blocked("::ffff:203.0.113.0/120", "203.0.113.7"));
+    }
+
+    #[test]
+    fn test_invalid_ranges() {
+        assert!(parse_ip_range("203.0.113.0/33").is_none());
+        assert!(parse_ip_range("2001:db8::/129").is_none());
+        assert!(parse_ip_range("example.com").is_none());
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+pub const BRANDING_BANNER_MAX_LENGTH: usize = 120;
+pub const BRANDING_LOGO_URL_MAX_LENGTH: usize = 512;
+
+/// look of a premium event, the event and print pages apply it via css variables
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct EventBranding {
+    /// primary color as `#rrggbb`
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub banner: Option<String>,
+    #[serde(rename = "logoUrl", default)]
+    pub logo_url: Option<String>,
+    /// object key of an uploaded logo, shown instead of `logo_url`
+    #[serde(default)]
+    pub logo: Option<String>,
+}
+
+impl EventBranding {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self
+            .color
+            .as_deref()
+            .is_some_and(|color| !is_hex_color(color))
+            && !self
+                .banner
+                .as_ref()
+                .is_some_and(|banner| banner.chars().count() > BRANDING_BANNER_MAX_LENGTH)
+            && !self.logo_url.as_ref().is_some_and(|url| {
+                !url.starts_with("https://") || url.len() > BRANDING_LOGO_URL_MAX_LENGTH
+            })
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.color.is_none()
+            && self.banner.is_none()
+            && self.logo_url.is_none()
+            && self.logo.is_none()
+    }
+
+    /// css custom properties for the element wrapping the event
+    #[must_use]
+    pub fn css_variables(&self) -> String {
+        self.color
+            .as_ref()
+            .map(|color| format!("--brand-color: {color};"))
+            .unwrap_or_default()
+    }
+}
+
+fn is_hex_color(color: &str) -> bool {
+    color
+        .strip_prefix('#')
+        .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{EventState, EventTimer, Poll, QuestionItem, SpeakerQueue, WordCloud};
+
+/// encoding of delta frames, negotiated when connecting via `/push/:id?encoding=msgpack`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SocketEncoding {
+    /// text frames: [`crate::WsMessage::Delta`] as json
+    #[default]
+    Json,
+    /// binary frames using [`EventDelta::to_msgpack`]
+    Msgpack,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct PushParams {
+    #[serde(default)]
+    pub encoding: SocketEncoding,
+    /// moderator token, marks the socket as moderator for the presence indicator
+    #[serde(default)]
+    pub secret: Option<String>,
+}
+
+/// incremental update pushed via websocket, applied by clients instead of refetching the whole event.
+/// values are absolute (e.g. the like count) so applying the same delta twice does no harm.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub enum EventDelta {
+    QuestionAdded(QuestionItem),
+    QuestionLiked {
+        id: i64,
+        likes: i32,
+    },
+    QuestionStateChanged {
+        id: i64,
+        hidden: bool,
+        answered: bool,
+        screening: bool,
+        #[serde(default)]
+        pinned: bool,
+    },
+    EventStateChanged(EventState),
+    /// poll was added, closed or reopened
+    PollChanged(Poll),
+    /// vote counts per option of a poll
+    PollVoted {
+        id: i64,
+        votes: Vec<i32>,
+    },
+    /// word cloud was added, closed or reopened
+    WordCloudChanged(WordCloud),
+    /// absolute count of a single (normalized) word cloud entry
+    WordCloudWord {
+        id: i64,
+        text: String,
+        count: i32,
+    },
+    /// hand was raised or lowered, the queue reordered or a speaker called
+    SpeakersChanged(SpeakerQueue),
+    /// countdown was started or stopped by the moderator
+    TimerChanged(Option<EventTimer>),
+}
+
+/// query of the long polling fallback `/api/event/:id/updates?since=<version>`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct UpdatesParams {
+    #[serde(default)]
+    pub since: usize,
+}
+
+/// response of the long polling fallback, a newer version than `since` means the event needs a refetch
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct EventUpdates {
+    pub version: usize,
+}
+
+impl EventDelta {
+    /// compact binary encoding used for [`SocketEncoding::Msgpack`] frames
+    ///
+    /// # Errors
+    ///
+    /// fails if the delta cannot be serialized
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec(self)
+    }
+
+    /// # Errors
+    ///
+    /// fails if `data` is not a msgpack encoded delta
+    pub fn from_msgpack(data: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(data)
+    }
+}
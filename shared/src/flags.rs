@@ -8,6 +8,8 @@ bitflags! {
     pub struct EventResponseFlags: u32 {
         const TIMED_OUT = 1 << 0;
         const WRONG_PASSWORD = 1 << 1;
+        /// questions can be translated on demand, see `QuestionTranslation`
+        const TRANSLATIONS = 1 << 2;
     }
 }
 
@@ -19,5 +21,26 @@ bitflags! {
         const PREMIUM = 1 << 1;
         const SCREENING = 1 << 2;
         const PASSWORD = 1 << 3;
+        const DOWNVOTES = 1 << 4;
+        const SURVEY = 1 << 5;
+        const HIDE_BRANDING = 1 << 6;
+        const TRIAL = 1 << 7;
+    }
+}
+
+bitflags! {
+    /// premium features of an event, gated one by one so plans can grant a subset
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct Entitlements: u32 {
+        /// download of the event data
+        const EXPORT = 1 << 0;
+        /// custom theme, logo, banner, slug and hiding the Live-Ask branding
+        const BRANDING = 1 << 1;
+        /// realtime viewer and like statistics
+        const ANALYTICS = 1 << 2;
+        /// event does not time out
+        const LONG_RETENTION = 1 << 3;
+        /// screening, tags, context links, attachments and series
+        const MODERATION = 1 << 4;
     }
 }
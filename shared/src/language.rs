@@ -0,0 +1,278 @@
+use serde::{Deserialize, Serialize};
+
+/// languages questions can be restricted to, code and english name
+pub const QUESTION_LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("de", "German"),
+    ("fr", "French"),
+    ("es", "Spanish"),
+    ("it", "Italian"),
+    ("pt", "Portuguese"),
+    ("nl", "Dutch"),
+    ("ru", "Russian"),
+    ("el", "Greek"),
+    ("ar", "Arabic"),
+    ("he", "Hebrew"),
+    ("zh", "Chinese"),
+    ("ja", "Japanese"),
+    ("ko", "Korean"),
+];
+
+/// fewer common words than this in a latin script text leaves the language undetected
+const MIN_WORD_HITS: usize = 2;
+
+/// frequent short words that tell the latin script languages apart
+const COMMON_WORDS: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &[
+            "the", "and", "is", "are", "what", "how", "why", "you", "we", "of", "to", "in", "it",
+            "this", "that", "do", "does", "can", "will", "for", "with", "your", "have",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "das", "und", "ist", "sind", "nicht", "ich", "wie", "was", "wir", "ihr",
+            "ein", "eine", "zu", "mit", "auf", "sich", "auch", "es", "sie", "warum", "wird",
+            "kann", "wann", "den", "dem",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "et", "est", "des", "une", "un", "que", "qui", "pour", "pas",
+            "dans", "du", "ce", "vous", "nous", "avec", "comment", "pourquoi", "sur", "au",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "la", "los", "las", "y", "es", "que", "de", "en", "un", "una", "por", "para",
+            "con", "como", "no", "se", "del", "pero", "usted", "esto", "muy",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "lo", "la", "gli", "le", "e", "di", "che", "non", "per", "una", "un", "con",
+            "come", "sono", "del", "della", "questo", "quando", "voi", "anche",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "o", "a", "os", "as", "e", "de", "que", "do", "da", "em", "um", "uma", "para", "com",
+            "como", "por", "voce", "isso", "mais", "quando", "dos", "das",
+        ],
+    ),
+    (
+        "nl",
+        &[
+            "de", "het", "een", "en", "is", "van", "niet", "dat", "ik", "wat", "hoe", "je", "we",
+            "zijn", "op", "te", "met", "voor", "waarom", "wij", "jullie",
+        ],
+    ),
+];
+
+/// what happens to a question that is not in one of the event languages
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum LanguageAction {
+    /// question waits in the approval queue
+    #[default]
+    #[serde(rename = "review")]
+    Review,
+    /// question is not taken at all
+    #[serde(rename = "reject")]
+    Reject,
+}
+
+/// languages an event takes questions in, empty for any
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct QuestionLanguages {
+    pub languages: Vec<String>,
+    #[serde(default)]
+    pub action: LanguageAction,
+}
+
+impl QuestionLanguages {
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        !self.languages.is_empty()
+    }
+
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.languages.iter().enumerate().all(|(index, language)| {
+            language_name(language).is_some()
+                && self
+                    .languages
+                    .iter()
+                    .take(index)
+                    .all(|other| other != language)
+        })
+    }
+
+    /// `text` is in one of the languages or its language could not be told,
+    /// short questions are given the benefit of the doubt
+    #[must_use]
+    pub fn allows(&self, text: &str) -> bool {
+        !self.is_enabled()
+            || detect_language(text).map_or(true, |detected| {
+                self.languages.iter().any(|l| l == detected)
+            })
+    }
+}
+
+/// english name of a supported language code
+#[must_use]
+pub fn language_name(code: &str) -> Option<&'static str> {
+    QUESTION_LANGUAGES
+        .iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, name)| *name)
+}
+
+/// best guess of the language `text` is written in, `None` if unsure.
+/// non latin scripts are told apart by their letters, latin ones by frequent words.
+#[must_use]
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    if let Some(language) = detect_script(text) {
+        return Some(language);
+    }
+
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    let mut best: Option<(&'static str, usize)> = None;
+    let mut tie = false;
+
+    for (language, common) in COMMON_WORDS {
+        let hits = words
+            .iter()
+            .filter(|word| common.contains(&word.as_str()))
+            .count();
+
+        match best {
+            Some((_, best_hits)) if hits == best_hits => tie = true,
+            Some((_, best_hits)) if hits < best_hits => {}
+            _ => {
+                best = Some((*language, hits));
+                tie = false;
+            }
+        }
+    }
+
+    best.filter(|(_, hits)| !tie && *hits >= MIN_WORD_HITS)
+        .map(|(language, _)| language)
+}
+
+/// language of the non latin script most letters of `text` are written in
+fn detect_script(text: &str) -> Option<&'static str> {
+    let mut letters = 0_usize;
+    let mut counts: Vec<(&'static str, usize)> = Vec::new();
+
+    for c in text.chars().filter(|c| c.is_alphabetic()) {
+        letters += 1;
+
+        if let Some(language) = script_language(c) {
+            match counts.iter_mut().find(|(known, _)| *known == language) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((language, 1)),
+            }
+        }
+    }
+
+    //Note: japanese mixes kana with chinese characters
+    if counts.iter().any(|(language, _)| *language == "ja") {
+        let chinese: usize = counts
+            .iter()
+            .filter(|(language, _)| *language == "zh")
+            .map(|(_, count)| *count)
+            .sum();
+
+        counts.retain(|(language, _)| *language != "zh");
+
+        if let Some((_, kana)) = counts.iter_mut().find(|(language, _)| *language == "ja") {
+            *kana += chinese;
+        }
+    }
+
+    let (language, count) = counts.into_iter().max_by_key(|(_, count)| *count)?;
+
+    (count * 2 > letters).then_some(language)
+}
+
+fn script_language(c: char) -> Option<&'static str> {
+    match u32::from(c) {
+        0x0370..=0x03FF => Some("el"),
+        0x0400..=0x04FF => Some("ru"),
+        0x0590..=0x05FF => Some("he"),
+        0x0600..=0x06FF => Some("ar"),
+        0x3040..=0x30FF => Some("ja"),
+        0x4E00..=0x9FFF => Some("zh"),
+        0xAC00..=0xD7AF => Some("ko"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_common_words() {
+        assert_eq!(
+            detect_language("What is the best way to do this?"),
+            Some("en")
+        );
+        assert_eq!(
+            detect_language("Wie ist das Wetter und warum regnet es?"),
+            Some("de")
+        );
+    }
+
+    #[test]
+    fn test_short_texts() {
+        assert_eq!(detect_language(""), None);
+        assert_eq!(detect_language("Hello?"), None);
+        assert_eq!(detect_language("the end"), None);
+    }
+
+    #[test]
+    fn test_ties() {
+        //as many french as spanish words
+        assert_eq!(detect_language("que la"), None);
+    }
+
+    #[test]
+    fn test_scripts() {
+        assert_eq!(detect_language("Что это такое?"), Some("ru"));
+        assert_eq!(detect_language("这是什么问题"), Some("zh"));
+        assert_eq!(detect_language("これは日本語です"), Some("ja"));
+        assert_eq!(detect_language("이것은 무엇입니까"), Some("ko"));
+    }
+
+    #[test]
+    fn test_mixed_scripts() {
+        assert_eq!(detect_language("Что это такое? ok"), Some("ru"));
+        //less than half of the letters are cyrillic, the latin words decide
+        assert_eq!(detect_language("Привет, how are you?"), Some("en"));
+    }
+
+    #[test]
+    fn test_allows() {
+        let languages = |languages: &[&str]| QuestionLanguages {
+            languages: languages.iter().map(ToString::to_string).collect(),
+            action: LanguageAction::Review,
+        };
+
+        assert!(languages(&[]).allows("What is the best way to do this?"));
+        assert!(languages(&["en"]).allows("What is the best way to do this?"));
+        assert!(!languages(&["de"]).allows("What is the best way to do this?"));
+        assert!(languages(&["de"]).allows("Hello?"));
+    }
+}
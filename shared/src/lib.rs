@@ -1,5 +1,29 @@
+mod admin;
+mod attachment;
+mod blocked_terms;
+mod blocklist;
+mod branding;
+mod delta;
 mod flags;
+mod language;
+mod notifications;
+mod org;
+mod payload;
+mod poll;
+mod promo;
+mod questions_page;
+mod receipt;
+mod sanitize;
+mod series;
+mod session;
+mod slug;
+mod speakers;
+mod survey;
+mod timer;
 mod validation;
+mod web_push;
+mod word_cloud;
+mod ws_message;
 
 use std::{str::FromStr, time::Duration};
 
@@ -7,15 +31,84 @@ use chrono::{DateTime, TimeZone as _, Utc};
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
-pub use flags::{EventFlags, EventResponseFlags};
+pub use admin::{AdminDashboard, AdminDayStats, ADMIN_DASHBOARD_DAYS};
+pub use attachment::{
+    attachment_file, attachment_key, logo_file, logo_key, AttachmentRequest, AttachmentUpload,
+    ATTACHMENT_CONTENT_TYPES, ATTACHMENT_KEY_PREFIX, ATTACHMENT_MAX_SIZE, ATTACHMENT_MAX_SIZE_MB,
+    LOGO_CONTENT_TYPES, LOGO_KEY_PREFIX, LOGO_MAX_SIZE, LOGO_MAX_SIZE_MB,
+};
+pub use blocked_terms::{
+    find_terms, BlockedTerms, BlockedTermsAction, BLOCKED_TERMS_MAX, BLOCKED_TERM_MAX_LENGTH,
+};
+pub use blocklist::{
+    parse_ip_range, BlockEntry, BlockTarget, Blocklist, BLOCKLIST_MAX_ENTRIES,
+    BLOCK_FINGERPRINT_MAX_LENGTH, BLOCK_NOTE_MAX_LENGTH, FINGERPRINT_HEADER,
+};
+pub use branding::{EventBranding, BRANDING_BANNER_MAX_LENGTH, BRANDING_LOGO_URL_MAX_LENGTH};
+pub use delta::{EventDelta, EventUpdates, PushParams, SocketEncoding, UpdatesParams};
+pub use flags::{Entitlements, EventFlags, EventResponseFlags};
+pub use language::{
+    detect_language, language_name, LanguageAction, QuestionLanguages, QUESTION_LANGUAGES,
+};
+pub use notifications::{
+    NotificationSettings, NOTIFY_LIKES_THRESHOLD_DEFAULT, NOTIFY_LIKES_THRESHOLD_MAX,
+};
+pub use org::{
+    AddOrg, AddOrgMember, OrgCheckout, OrgEvent, OrgMember, OrgMembership, OrgRole, OrgWorkspace,
+    ORG_MAX_EVENTS, ORG_MAX_MEMBERS, ORG_MEMBER_NAME_MAX_LENGTH, ORG_NAME_MAX_LENGTH,
+    ORG_NAME_MIN_LENGTH,
+};
+pub use payload::{EventMod, EventPublic};
+pub use poll::{
+    AddPoll, ModPoll, Poll, PollOption, PollVote, POLL_MAX_OPTIONS, POLL_MAX_PER_EVENT,
+    POLL_MIN_OPTIONS, POLL_TEXT_MAX_LENGTH,
+};
+pub use promo::{
+    is_valid_promo_code, PromoCode, UpgradeParams, PROMO_CODE_MAX_LENGTH, PROMO_CODE_MIN_LENGTH,
+    PROMO_MAX_PERCENT_OFF,
+};
+pub use questions_page::{
+    EventParams, QuestionSort, QuestionsPage, QuestionsParams, QUESTIONS_PAGE_DEFAULT_LIMIT,
+    QUESTIONS_PAGE_MAX_LIMIT,
+};
+pub use receipt::{ReceiptDetails, RECEIPT_FIELD_MAX_LENGTH};
+pub use sanitize::strip_html;
+pub use series::{
+    EditSeries, EventSeries, SeriesCurrent, SeriesOccurrence, SERIES_INTERVAL_MAX_DAYS,
+    SERIES_MAX_OCCURRENCES,
+};
+pub use session::{
+    AddSession, ModSession, Session, SESSION_MAX_PER_EVENT, SESSION_NAME_MAX_LENGTH,
+};
+pub use slug::{is_valid_slug, EditSlug, SlugTarget, SLUG_MAX_LENGTH, SLUG_MIN_LENGTH};
+pub use speakers::{ModSpeaker, RaiseHand, RaisedHand, Speaker, SpeakerQueue, SPEAKER_QUEUE_MAX};
+pub use survey::{
+    AddSurveyResponse, SurveyResponse, SurveyResults, SURVEY_COMMENT_MAX_LENGTH,
+    SURVEY_MAX_RESPONSES, SURVEY_RATING_MAX,
+};
+pub use timer::{EventTimer, ModTimer, TIMER_LABEL_MAX_LENGTH, TIMER_MAX_SECS};
 pub use validation::{
-    add_question::{AddQuestionError, AddQuestionValidation},
+    add_question::{
+        AddQuestionError, AddQuestionValidation, QuestionLength, QuestionLimits, QUESTIONS_MAX,
+        QUESTIONS_PER_MINUTE_UPPER_BOUND, QUESTION_LENGTH_LOWER_BOUND, QUESTION_LENGTH_UPPER_BOUND,
+    },
     context_validation::{ContextLabelError, ContextUrlError, ContextValidation},
     create_event::{CreateEventError, CreateEventValidation},
+    name_validation::{NameError, NameValidation},
     pwd_validation::{PasswordError, PasswordValidation},
     tag_validation::{TagError, TagValidation},
     ValidationState,
 };
+pub use web_push::{
+    WebPushKeys, WebPushNotification, WebPushSubscription, WebPushUnsubscribe,
+    WEB_PUSH_MAX_SUBSCRIPTIONS,
+};
+pub use word_cloud::{
+    normalize_word, AddWord, AddWordCloud, ModWordCloud, WordCloud, WordCount,
+    WORD_CLOUD_ENTRY_MAX_LENGTH, WORD_CLOUD_MAX_ANSWERS, WORD_CLOUD_MAX_ENTRIES,
+    WORD_CLOUD_MAX_PER_EVENT, WORD_CLOUD_MAX_WORDS, WORD_CLOUD_PROMPT_MAX_LENGTH,
+};
+pub use ws_message::{WsClientMessage, WsMessage};
 
 //TODO: validate in unittest against validator
 pub const TEST_VALID_QUESTION: &str = "1 2 3fourfive";
@@ -23,6 +116,21 @@ pub const TEST_EVENT_DESC: &str = "minimum desc length possible!!";
 pub const TEST_EVENT_NAME: &str = "min name";
 
 pub const MAX_TAGS: usize = 15;
+pub const MAX_LIKE_BUCKETS: usize = 24 * 60;
+/// width of one bar in the moderator statistics charts
+pub const EVENT_STATS_BUCKET_SECS: i64 = 5 * 60;
+/// the statistics charts cover the latest 24 hours of activity
+pub const EVENT_STATS_MAX_BUCKETS: i64 = 24 * 12;
+/// every event can try the premium features once for this long
+pub const PREMIUM_TRIAL_SECS: i64 = 30 * 60;
+/// longest wait between two questions of a participant a moderator can configure
+pub const QUESTION_COOLDOWN_MAX_MINS: u32 = 60;
+/// highest number of concurrent viewers a moderator can cap a premium event at
+pub const VIEWER_CAPACITY_MAX: u32 = 10_000;
+/// most liked questions a summary is generated from
+pub const QUESTION_SUMMARY_MAX_QUESTIONS: usize = 200;
+/// longer generated summaries are cut off
+pub const QUESTION_SUMMARY_MAX_LENGTH: usize = 2_000;
 
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
 pub struct EventTokens {
@@ -47,6 +155,48 @@ pub struct EventData {
     pub short_url: String,
     #[serde(rename = "longUrl")]
     pub long_url: Option<String>,
+    /// questions can only be submitted after this unix timestamp
+    #[serde(rename = "startUnix", default)]
+    pub start_unix: Option<i64>,
+    /// event is read-only after this unix timestamp
+    #[serde(rename = "endUnix", default)]
+    pub end_unix: Option<i64>,
+    /// IANA timezone of the organizer (e.g. `Europe/Berlin`), times are still stored as utc
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// only applied to premium events
+    #[serde(default)]
+    pub branding: Option<EventBranding>,
+}
+
+pub const EVENT_TIMEZONE_MAX_LENGTH: usize = 64;
+
+impl EventData {
+    /// `false` if the end is not after the start
+    #[must_use]
+    pub fn schedule_valid(&self) -> bool {
+        !matches!((self.start_unix, self.end_unix), (Some(start), Some(end)) if end <= start)
+    }
+
+    #[must_use]
+    pub fn not_started(&self, now: i64) -> bool {
+        self.start_unix.is_some_and(|start| now < start)
+    }
+
+    #[must_use]
+    pub fn ended(&self, now: i64) -> bool {
+        self.end_unix.is_some_and(|end| now >= end)
+    }
+
+    /// upcoming start or end, `None` once nothing is scheduled anymore
+    #[must_use]
+    pub fn next_transition(&self, now: i64) -> Option<i64> {
+        if self.not_started(now) {
+            self.start_unix
+        } else {
+            self.end_unix.filter(|_| !self.ended(now))
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -65,6 +215,153 @@ pub struct QuestionItem {
     pub create_time_unix: i64,
     #[serde(default)]
     pub tag: Option<TagId>,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// object key of an attached image
+    #[serde(default)]
+    pub attachment: Option<String>,
+    #[serde(default)]
+    pub reactions: Reactions,
+    #[serde(default)]
+    pub downvotes: i32,
+    #[serde(default)]
+    pub session: Option<i64>,
+    /// kept on top of the open questions by the moderator
+    #[serde(default)]
+    pub pinned: bool,
+    /// asked by a shadow banned participant, only the moderators and the author see it
+    #[serde(default)]
+    pub shadowed: bool,
+    /// private note of the moderators, never sent to anyone else
+    #[serde(default)]
+    pub note: Option<String>,
+    /// hidden as a duplicate of this question, which took over its likes
+    #[serde(default)]
+    pub merged_into: Option<i64>,
+}
+
+pub const QUESTION_NOTE_MAX_LENGTH: usize = 500;
+
+impl QuestionItem {
+    /// likes minus downvotes, used for ranking
+    #[must_use]
+    pub const fn score(&self) -> i32 {
+        self.likes.saturating_sub(self.downvotes)
+    }
+}
+
+/// net likes a question received within one minute
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LikeBucket {
+    /// unix timestamp of the start of the minute
+    pub minute: i64,
+    pub likes: i32,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
+pub struct QuestionLikeTimeline {
+    pub question_id: i64,
+    pub buckets: Vec<LikeBucket>,
+}
+
+/// like events of all questions of an event, bucketed per minute
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
+pub struct LikeTimeline {
+    pub questions: Vec<QuestionLikeTimeline>,
+}
+
+impl LikeTimeline {
+    /// adds `delta` to the bucket of the minute `timestamp` falls into,
+    /// only the latest `MAX_LIKE_BUCKETS` buckets per question are kept
+    pub fn record(&mut self, question_id: i64, timestamp: i64, delta: i32) {
+        let minute = timestamp - timestamp.rem_euclid(60);
+
+        let index = self
+            .questions
+            .iter()
+            .position(|q| q.question_id == question_id)
+            .unwrap_or_else(|| {
+                self.questions.push(QuestionLikeTimeline {
+                    question_id,
+                    buckets: Vec::new(),
+                });
+                self.questions.len() - 1
+            });
+
+        let Some(timeline) = self.questions.get_mut(index) else {
+            return;
+        };
+
+        match timeline.buckets.last_mut() {
+            Some(bucket) if bucket.minute == minute => {
+                bucket.likes = bucket.likes.saturating_add(delta);
+            }
+            _ => timeline.buckets.push(LikeBucket {
+                minute,
+                likes: delta,
+            }),
+        }
+
+        if timeline.buckets.len() > MAX_LIKE_BUCKETS {
+            timeline.buckets.remove(0);
+        }
+    }
+}
+
+/// likes of a question that were not counted because they came in implausibly fast
+/// from a single browser or address, only reported to moderators
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FlaggedLikes {
+    pub question_id: i64,
+    pub discounted: u32,
+}
+
+/// question text in another language, see `EventResponseFlags::TRANSLATIONS`
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct QuestionTranslation {
+    pub question_id: i64,
+    /// code of one of the `QUESTION_LANGUAGES`
+    pub language: String,
+    pub text: String,
+}
+
+/// tone of a question as told by the sentiment classifier
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Sentiment {
+    #[serde(rename = "positive")]
+    Positive,
+    #[default]
+    #[serde(rename = "neutral")]
+    Neutral,
+    #[serde(rename = "negative")]
+    Negative,
+}
+
+/// classified tone of a question, only reported to moderators
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct QuestionSentiment {
+    pub question_id: i64,
+    pub sentiment: Sentiment,
+}
+
+/// number of questions or likes within one `EVENT_STATS_BUCKET_SECS` window
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct StatsBucket {
+    /// unix timestamp of the start of the window
+    pub start: i64,
+    pub count: i64,
+}
+
+/// moderator statistics of an event, the timelines contain a bucket for every window
+/// between the first and the last activity
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
+pub struct EventStats {
+    pub questions: Vec<StatsBucket>,
+    pub likes: Vec<StatsBucket>,
+    pub peak_viewers: i64,
+    /// visible questions, hidden, unscreened and shadowed ones are not counted
+    pub total: usize,
+    pub answered: usize,
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
@@ -83,6 +380,11 @@ pub struct PaymentCapture {
     pub order_captured: bool,
 }
 
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
+pub struct SubscriptionState {
+    pub active: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 pub struct Tag {
     pub name: String,
@@ -105,21 +407,31 @@ impl EventTags {
 
     /// returns `false` if max tags is reached and a new tag would have to be added
     pub fn set_or_add_tag(&mut self, tag: &str) -> bool {
+        let Some(id) = self.get_or_add_tag(tag) else {
+            return false;
+        };
+
+        self.current_tag = Some(id);
+
+        true
+    }
+
+    /// id of `tag`, added if it is new, `None` if max tags is reached
+    pub fn get_or_add_tag(&mut self, tag: &str) -> Option<TagId> {
         let tag = tag.to_lowercase();
 
         if let Some(i) = self.tags.iter().find(|e| *e.name == tag) {
-            self.current_tag = Some(i.id);
-        } else {
-            if self.tags.len() >= MAX_TAGS {
-                return false;
-            }
+            return Some(i.id);
+        }
 
-            let id = TagId(self.tags.len());
-            self.tags.push(Tag { name: tag, id });
-            self.current_tag = Some(id);
+        if self.tags.len() >= MAX_TAGS {
+            return None;
         }
 
-        true
+        let id = TagId(self.tags.len());
+        self.tags.push(Tag { name: tag, id });
+
+        Some(id)
     }
 }
 
@@ -141,6 +453,46 @@ pub struct EventInfo {
     pub context: Vec<ContextItem>,
     #[serde(default)]
     pub tags: EventTags,
+    #[serde(default)]
+    pub question_names: QuestionNames,
+    #[serde(default)]
+    pub question_length: QuestionLength,
+    #[serde(default)]
+    pub polls: Vec<Poll>,
+    #[serde(default)]
+    pub word_clouds: Vec<WordCloud>,
+    #[serde(default)]
+    pub sessions: Vec<Session>,
+    #[serde(default)]
+    pub speakers: SpeakerQueue,
+    /// countdown currently shown to everyone
+    #[serde(default)]
+    pub timer: Option<EventTimer>,
+    /// minutes a participant has to wait between two questions, `0` for no limit
+    #[serde(default)]
+    pub question_cooldown_mins: u32,
+    #[serde(default)]
+    pub question_limits: QuestionLimits,
+    /// concurrent viewers before newcomers wait for a free slot, `0` for no limit
+    #[serde(default)]
+    pub viewer_capacity: u32,
+    /// languages questions are expected in, empty for any
+    #[serde(default)]
+    pub question_languages: QuestionLanguages,
+    /// set on every occurrence of a recurring series, without the list of occurrences
+    #[serde(default)]
+    pub series: Option<EventSeries>,
+    /// custom short url slug of a premium event, `short_url` points to it
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub theme: EventTheme,
+    /// end of the premium trial once it was started, `PREMIUM` is set while it runs
+    #[serde(default)]
+    pub trial_end_unix: Option<i64>,
+    /// premium features this event unlocked, `PREMIUM` is set as long as there are any
+    #[serde(default)]
+    pub entitlements: Entitlements,
 }
 
 impl EventInfo {
@@ -161,6 +513,10 @@ impl EventInfo {
         self.flags.contains(EventFlags::PREMIUM)
     }
     #[must_use]
+    pub const fn is_entitled(&self, entitlement: Entitlements) -> bool {
+        self.entitlements.contains(entitlement)
+    }
+    #[must_use]
     pub const fn is_deleted(&self) -> bool {
         self.flags.contains(EventFlags::DELETED)
     }
@@ -172,6 +528,24 @@ impl EventInfo {
     pub const fn has_password(&self) -> bool {
         self.flags.contains(EventFlags::PASSWORD)
     }
+    #[must_use]
+    pub const fn downvotes_enabled(&self) -> bool {
+        self.flags.contains(EventFlags::DOWNVOTES)
+    }
+    #[must_use]
+    pub const fn survey_enabled(&self) -> bool {
+        self.flags.contains(EventFlags::SURVEY)
+    }
+    /// only ever set for premium events
+    #[must_use]
+    pub const fn branding_hidden(&self) -> bool {
+        self.flags.contains(EventFlags::HIDE_BRANDING)
+    }
+    /// premium only because of a running trial
+    #[must_use]
+    pub const fn is_trial(&self) -> bool {
+        self.flags.contains(EventFlags::TRIAL)
+    }
 
     #[must_use]
     pub fn timestamp_to_datetime(timestamp: i64) -> Option<DateTime<Utc>> {
@@ -196,11 +570,36 @@ impl EventInfo {
 pub struct ModInfo {
     pub pwd: EventPassword,
     pub private_token: String,
+    #[serde(default)]
+    pub notifications: NotificationSettings,
+    /// questions with suspected like inflation
+    #[serde(default)]
+    pub flagged_likes: Vec<FlaggedLikes>,
+    #[serde(default)]
+    pub blocked_terms: BlockedTerms,
+    /// the server is able to summarize the questions
+    #[serde(default)]
+    pub summaries: bool,
+    #[serde(default)]
+    pub question_summary: Option<QuestionSummary>,
+    /// empty unless the server classifies questions
+    #[serde(default)]
+    pub sentiments: Vec<QuestionSentiment>,
 }
 
+/// generated overview of the questions, only shown to moderators
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct QuestionSummary {
+    pub text: String,
+    /// questions the summary is based on
+    pub questions: usize,
+    #[serde(rename = "createTimeUnix")]
+    pub create_time_unix: i64,
+}
+
+/// what the frontend works with, sent as [`EventPublic`] or [`EventMod`]
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 pub struct GetEventResponse {
-    //TODO: remove mod token from inside here
     pub info: EventInfo,
     pub viewers: i64,
     //TODO: not needed if client becomes aware of its user role via header
@@ -211,9 +610,45 @@ pub struct GetEventResponse {
     #[serde(default)]
     pub flags: EventResponseFlags,
     pub mod_info: Option<ModInfo>,
+    /// bumped with every change, see [`EventUpdates`]
+    #[serde(default)]
+    pub version: usize,
+    /// set if `info.questions` only holds the first page, the rest is loaded via [`QuestionsPage`]
+    #[serde(default)]
+    pub questions_total: Option<usize>,
 }
 
 impl GetEventResponse {
+    /// keeps the first `limit` questions as [`QuestionSort::Score`] orders them
+    pub fn limit_questions(&mut self, limit: usize) {
+        let questions = &mut self.info.questions;
+        if questions.len() <= limit {
+            return;
+        }
+
+        QuestionSort::Score.sort(questions);
+        self.questions_total = Some(questions.len());
+        questions.truncate(limit);
+    }
+
+    /// adds the questions of a following page that are not known yet
+    pub fn append_questions(&mut self, page: QuestionsPage) {
+        for question in page.questions {
+            if !self.info.questions.iter().any(|q| q.id == question.id) {
+                self.info.questions.push(question);
+            }
+        }
+
+        self.questions_total = Some(page.total);
+    }
+
+    /// more questions can be loaded via [`QuestionsPage`]
+    #[must_use]
+    pub fn has_more_questions(&self) -> bool {
+        self.questions_total
+            .is_some_and(|total| total > self.info.questions.len())
+    }
+
     #[must_use]
     pub fn get_question(&self, id: i64) -> Option<QuestionItem> {
         self.info.questions.iter().find(|i| i.id == id).cloned()
@@ -226,7 +661,7 @@ impl GetEventResponse {
 
     #[must_use]
     pub const fn is_closed(&self) -> bool {
-        matches!(self.info.state.state, States::Closed) || self.is_timed_out()
+        matches!(self.info.state.state, States::Closed | States::Archived) || self.is_timed_out()
     }
 
     #[must_use]
@@ -244,6 +679,11 @@ impl GetEventResponse {
         self.flags.contains(EventResponseFlags::WRONG_PASSWORD)
     }
 
+    #[must_use]
+    pub const fn can_translate(&self) -> bool {
+        self.flags.contains(EventResponseFlags::TRANSLATIONS)
+    }
+
     #[must_use]
     pub fn deleted(id: String) -> Self {
         Self {
@@ -256,6 +696,11 @@ impl GetEventResponse {
     pub fn any_questions(&self) -> bool {
         !self.info.questions.is_empty()
     }
+
+    #[must_use]
+    pub fn get_session(&self, id: i64) -> Option<&Session> {
+        self.info.sessions.iter().find(|s| s.id == id)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -264,6 +709,9 @@ pub struct AddEvent {
     pub data: EventData,
     #[serde(rename = "moderatorEmail", default)]
     pub moderator_email: Option<String>,
+    /// account token of an active subscription makes the event premium
+    #[serde(default)]
+    pub subscription: Option<String>,
     pub test: bool,
 }
 
@@ -272,11 +720,39 @@ pub struct EditLike {
     #[serde(rename = "questionid")]
     pub question_id: i64,
     pub like: bool,
+    /// random token of the participant's browser, used to spot implausible like bursts
+    #[serde(default)]
+    pub participant: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EditDownvote {
+    #[serde(rename = "questionid")]
+    pub question_id: i64,
+    pub downvote: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EditReaction {
+    #[serde(rename = "questionid")]
+    pub question_id: i64,
+    pub reaction: Reaction,
+    pub add: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AddQuestion {
     pub text: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub attachment: Option<String>,
+    /// session the question is asked in, `None` for events without sessions
+    #[serde(default)]
+    pub session: Option<i64>,
+    /// random token of the participant's browser, required while a question cooldown is set
+    #[serde(default)]
+    pub participant: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -284,6 +760,70 @@ pub struct ModQuestion {
     pub hide: bool,
     pub answered: bool,
     pub screened: bool,
+    #[serde(default)]
+    pub pinned: bool,
+    /// shadow bans the author of the question or lifts the ban, `None` keeps it as is
+    #[serde(default)]
+    pub shadow_ban: Option<bool>,
+}
+
+/// replaces the private moderator note of a question, an empty one removes it
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct ModQuestionNote {
+    pub note: String,
+}
+
+impl ModQuestionNote {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.note.chars().count() <= QUESTION_NOTE_MAX_LENGTH
+    }
+}
+
+pub const BULK_MODERATION_MAX: usize = 200;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub enum BulkAction {
+    Hide,
+    Answer,
+    /// tag to file the questions under, added to the event if new, `None` removes their tag
+    Tag(Option<String>),
+}
+
+/// applies one action to many questions at once
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct ModQuestionsBulk {
+    pub questions: Vec<i64>,
+    pub action: BulkAction,
+}
+
+impl ModQuestionsBulk {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self.questions.is_empty() && self.questions.len() <= BULK_MODERATION_MAX
+    }
+}
+
+/// questions that look like they ask the same, the most liked one first
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct QuestionCluster {
+    pub questions: Vec<i64>,
+}
+
+/// hides `questions` as duplicates of `target`, which takes over their likes
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct ModMergeQuestions {
+    pub target: i64,
+    pub questions: Vec<i64>,
+}
+
+impl ModMergeQuestions {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self.questions.is_empty()
+            && self.questions.len() <= BULK_MODERATION_MAX
+            && !self.questions.contains(&self.target)
+    }
 }
 
 ///
@@ -294,6 +834,141 @@ pub enum States {
     Open = 0,
     VotingOnly = 1,
     Closed = 2,
+    /// questions stay readable, everything else is off for good
+    Archived = 3,
+}
+
+/// whether participants can or have to attach a name to their questions
+#[derive(Serialize_repr, Deserialize_repr, Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[repr(u8)]
+pub enum QuestionNames {
+    #[default]
+    Anonymous = 0,
+    Optional = 1,
+    Required = 2,
+}
+
+impl QuestionNames {
+    #[must_use]
+    pub const fn allowed(self) -> bool {
+        !matches!(self, Self::Anonymous)
+    }
+
+    #[must_use]
+    pub const fn required(self) -> bool {
+        matches!(self, Self::Required)
+    }
+
+    #[must_use]
+    pub const fn to_value(self) -> u8 {
+        match self {
+            Self::Anonymous => 0,
+            Self::Optional => 1,
+            Self::Required => 2,
+        }
+    }
+
+    #[must_use]
+    pub const fn from_value(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Anonymous),
+            1 => Some(Self::Optional),
+            2 => Some(Self::Required),
+            _ => None,
+        }
+    }
+}
+
+/// look attendees get, `Auto` follows their own light/dark preference
+#[derive(Serialize_repr, Deserialize_repr, Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[repr(u8)]
+pub enum EventTheme {
+    #[default]
+    Auto = 0,
+    Light = 1,
+    Dark = 2,
+    /// driven by the branding color, premium only
+    Custom = 3,
+}
+
+impl EventTheme {
+    #[must_use]
+    pub const fn to_value(self) -> u8 {
+        match self {
+            Self::Auto => 0,
+            Self::Light => 1,
+            Self::Dark => 2,
+            Self::Custom => 3,
+        }
+    }
+
+    #[must_use]
+    pub const fn from_value(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Auto),
+            1 => Some(Self::Light),
+            2 => Some(Self::Dark),
+            3 => Some(Self::Custom),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+pub enum Reaction {
+    ThumbsUp = 0,
+    Heart = 1,
+    Laugh = 2,
+    Thinking = 3,
+}
+
+impl Reaction {
+    pub const ALL: [Self; 4] = [Self::ThumbsUp, Self::Heart, Self::Laugh, Self::Thinking];
+
+    #[must_use]
+    pub const fn emoji(self) -> &'static str {
+        match self {
+            Self::ThumbsUp => "\u{1f44d}",
+            Self::Heart => "\u{2764}\u{fe0f}",
+            Self::Laugh => "\u{1f602}",
+            Self::Thinking => "\u{1f914}",
+        }
+    }
+
+    const fn index(self) -> usize {
+        match self {
+            Self::ThumbsUp => 0,
+            Self::Heart => 1,
+            Self::Laugh => 2,
+            Self::Thinking => 3,
+        }
+    }
+}
+
+/// reaction counts of a question, indexed by `Reaction`
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Reactions(pub [i32; 4]);
+
+impl Reactions {
+    #[must_use]
+    pub const fn get(&self, reaction: Reaction) -> i32 {
+        self.0[reaction.index()]
+    }
+
+    pub fn edit(&mut self, reaction: Reaction, add: bool) {
+        let count = &mut self.0[reaction.index()];
+        *count = if add {
+            count.saturating_add(1)
+        } else {
+            count.saturating_sub(1).max(0)
+        };
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|count| *count == 0)
+    }
 }
 
 impl FromStr for States {
@@ -304,6 +979,7 @@ impl FromStr for States {
             "0" => Ok(Self::Open),
             "1" => Ok(Self::VotingOnly),
             "2" => Ok(Self::Closed),
+            "3" => Ok(Self::Archived),
             _ => Err(()),
         }
     }
@@ -382,6 +1058,29 @@ pub struct ModEvent {
     pub meta: Option<EditMetaData>,
     pub screening: Option<bool>,
     pub context: Option<EditContextLink>,
+    pub question_names: Option<QuestionNames>,
+    pub question_length: Option<QuestionLength>,
+    pub downvotes: Option<bool>,
+    pub survey: Option<bool>,
+    /// an empty branding removes it
+    #[serde(default)]
+    pub branding: Option<EventBranding>,
+    #[serde(default)]
+    pub theme: Option<EventTheme>,
+    #[serde(default)]
+    pub hide_branding: Option<bool>,
+    #[serde(default)]
+    pub notifications: Option<NotificationSettings>,
+    #[serde(default)]
+    pub question_cooldown_mins: Option<u32>,
+    #[serde(default)]
+    pub question_limits: Option<QuestionLimits>,
+    #[serde(default)]
+    pub viewer_capacity: Option<u32>,
+    #[serde(default)]
+    pub blocked_terms: Option<BlockedTerms>,
+    #[serde(default)]
+    pub question_languages: Option<QuestionLanguages>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Default)]
@@ -405,12 +1104,18 @@ impl EventState {
         matches!(self.state, States::Closed)
     }
 
+    #[must_use]
+    pub const fn is_archived(&self) -> bool {
+        matches!(self.state, States::Archived)
+    }
+
     #[must_use]
     pub const fn to_value(&self) -> u8 {
         match self.state {
             States::Open => 0,
             States::VotingOnly => 1,
             States::Closed => 2,
+            States::Archived => 3,
         }
     }
 
@@ -426,6 +1131,9 @@ impl EventState {
             2 => Self {
                 state: States::Closed,
             },
+            3 => Self {
+                state: States::Archived,
+            },
             _ => None?,
         })
     }
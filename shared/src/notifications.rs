@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CreateEventValidation;
+
+/// moderators get notified once a question reaches this many likes unless they chose otherwise
+pub const NOTIFY_LIKES_THRESHOLD_DEFAULT: i32 = 10;
+pub const NOTIFY_LIKES_THRESHOLD_MAX: i32 = 10_000;
+
+/// how the moderators of an event are notified about new and popular questions
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(default)]
+pub struct NotificationSettings {
+    /// receiver of notification mails, none turns them off
+    pub email: Option<String>,
+    /// browsers subscribed via Web Push get notified
+    pub push: bool,
+    /// popular question notification once it reaches this many likes, `0` turns it off
+    #[serde(rename = "likesThreshold")]
+    pub likes_threshold: i32,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            email: None,
+            push: true,
+            likes_threshold: NOTIFY_LIKES_THRESHOLD_DEFAULT,
+        }
+    }
+}
+
+impl NotificationSettings {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        (0..=NOTIFY_LIKES_THRESHOLD_MAX).contains(&self.likes_threshold)
+            && self.email.as_deref().map_or(true, |email| {
+                !email.trim().is_empty() && CreateEventValidation::check_email(email).is_none()
+            })
+    }
+
+    /// `likes` was just reached by a question
+    #[must_use]
+    pub const fn is_popular(&self, likes: i32) -> bool {
+        self.likes_threshold > 0 && likes == self.likes_threshold
+    }
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+pub const ORG_NAME_MIN_LENGTH: usize = 3;
+pub const ORG_NAME_MAX_LENGTH: usize = 50;
+pub const ORG_MEMBER_NAME_MAX_LENGTH: usize = 30;
+pub const ORG_MAX_MEMBERS: usize = 25;
+/// the workspace lists all of them, later events are rejected
+pub const ORG_MAX_EVENTS: usize = 200;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum OrgRole {
+    /// manages members and billing
+    Owner,
+    /// creates and moderates the events of the organization
+    #[default]
+    Moderator,
+}
+
+impl OrgRole {
+    #[must_use]
+    pub const fn is_owner(self) -> bool {
+        matches!(self, Self::Owner)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct AddOrg {
+    pub name: String,
+    /// display name of the creating member who becomes the owner
+    pub owner: String,
+}
+
+impl AddOrg {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        (ORG_NAME_MIN_LENGTH..=ORG_NAME_MAX_LENGTH).contains(&self.name.trim().chars().count())
+            && is_valid_member_name(&self.owner)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct AddOrgMember {
+    pub name: String,
+    pub role: OrgRole,
+}
+
+impl AddOrgMember {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        is_valid_member_name(&self.name)
+    }
+}
+
+fn is_valid_member_name(name: &str) -> bool {
+    (1..=ORG_MEMBER_NAME_MAX_LENGTH).contains(&name.trim().chars().count())
+}
+
+/// organization a subscription checkout was started for, looked up by the checkout session on return
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct OrgCheckout {
+    pub org: String,
+}
+
+/// secret login of a member, the frontend keeps it to open the workspace
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct OrgMembership {
+    pub org: String,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct OrgMember {
+    pub name: String,
+    pub role: OrgRole,
+    /// secret login of the member, only visible to owners so they can share or revoke it
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct OrgEvent {
+    pub name: String,
+    #[serde(rename = "publicToken")]
+    pub public_token: String,
+    #[serde(rename = "moderatorToken")]
+    pub moderator_token: String,
+    #[serde(rename = "createTimeUnix")]
+    pub create_time_unix: i64,
+    pub premium: bool,
+    pub deleted: bool,
+}
+
+/// dashboard of an organization as seen by one of its members
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct OrgWorkspace {
+    pub id: String,
+    pub name: String,
+    /// role of the requesting member
+    pub role: OrgRole,
+    pub members: Vec<OrgMember>,
+    /// newest first
+    pub events: Vec<OrgEvent>,
+    /// team subscription making every event created in the workspace premium
+    pub subscribed: bool,
+}
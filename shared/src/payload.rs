@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{EventInfo, EventResponseFlags, GetEventResponse, ModInfo};
+
+/// response of the public `/api/event/:id`, without anything only moderators may see
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct EventPublic {
+    pub info: EventInfo,
+    pub viewers: i64,
+    #[serde(default)]
+    pub admin: bool,
+    #[serde(default)]
+    pub masked: bool,
+    #[serde(default)]
+    pub flags: EventResponseFlags,
+    #[serde(default)]
+    pub version: usize,
+    #[serde(default)]
+    pub questions_total: Option<usize>,
+}
+
+/// response of `/api/mod/event/:id/:secret`
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct EventMod {
+    pub info: EventInfo,
+    pub viewers: i64,
+    #[serde(default)]
+    pub admin: bool,
+    #[serde(default)]
+    pub masked: bool,
+    #[serde(default)]
+    pub flags: EventResponseFlags,
+    pub mod_info: ModInfo,
+    #[serde(default)]
+    pub version: usize,
+}
+
+impl From<GetEventResponse> for EventPublic {
+    fn from(mut val: GetEventResponse) -> Self {
+        let info = &mut val.info;
+        info.tokens.moderator_token = None;
+        info.trial_end_unix = None;
+        info.delete_time_unix = 0;
+        info.last_edit_unix = 0;
+
+        Self {
+            info: val.info,
+            viewers: val.viewers,
+            admin: val.admin,
+            masked: val.masked,
+            flags: val.flags,
+            version: val.version,
+            questions_total: val.questions_total,
+        }
+    }
+}
+
+impl From<GetEventResponse> for EventMod {
+    fn from(val: GetEventResponse) -> Self {
+        Self {
+            info: val.info,
+            viewers: val.viewers,
+            admin: val.admin,
+            masked: val.masked,
+            flags: val.flags,
+            mod_info: val.mod_info.unwrap_or_default(),
+            version: val.version,
+        }
+    }
+}
+
+impl From<EventPublic> for GetEventResponse {
+    fn from(val: EventPublic) -> Self {
+        Self {
+            info: val.info,
+            viewers: val.viewers,
+            admin: val.admin,
+            masked: val.masked,
+            flags: val.flags,
+            mod_info: None,
+            version: val.version,
+            questions_total: val.questions_total,
+        }
+    }
+}
+
+impl From<EventMod> for GetEventResponse {
+    fn from(val: EventMod) -> Self {
+        Self {
+            info: val.info,
+            viewers: val.viewers,
+            admin: val.admin,
+            masked: val.masked,
+            flags: val.flags,
+            mod_info: Some(val.mod_info),
+            version: val.version,
+            questions_total: None,
+        }
+    }
+}
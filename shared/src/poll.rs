@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+pub const POLL_MIN_OPTIONS: usize = 2;
+pub const POLL_MAX_OPTIONS: usize = 6;
+pub const POLL_MAX_PER_EVENT: usize = 20;
+/// max chars of the poll question and of each option
+pub const POLL_TEXT_MAX_LENGTH: usize = 200;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct PollOption {
+    pub text: String,
+    pub votes: i32,
+}
+
+/// multiple choice poll created by the moderator, closed polls stay visible with their results
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct Poll {
+    pub id: i64,
+    pub question: String,
+    pub options: Vec<PollOption>,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(rename = "createTimeUnix")]
+    pub create_time_unix: i64,
+}
+
+impl Poll {
+    #[must_use]
+    pub fn total_votes(&self) -> i32 {
+        self.options.iter().map(|o| o.votes).sum()
+    }
+
+    #[must_use]
+    pub fn votes(&self) -> Vec<i32> {
+        self.options.iter().map(|o| o.votes).collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct AddPoll {
+    pub question: String,
+    pub options: Vec<String>,
+}
+
+impl AddPoll {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        let text_valid = |text: &str| {
+            let text = text.trim();
+            !text.is_empty() && text.chars().count() <= POLL_TEXT_MAX_LENGTH
+        };
+
+        text_valid(&self.question)
+            && (POLL_MIN_OPTIONS..=POLL_MAX_OPTIONS).contains(&self.options.len())
+            && self.options.iter().all(|o| text_valid(o))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ModPoll {
+    pub closed: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct PollVote {
+    /// index into `Poll::options`
+    pub option: usize,
+    /// random token of the participant's browser, each one votes once per poll
+    #[serde(default)]
+    pub participant: String,
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+pub const PROMO_CODE_MIN_LENGTH: usize = 3;
+pub const PROMO_CODE_MAX_LENGTH: usize = 32;
+pub const PROMO_MAX_PERCENT_OFF: u8 = 100;
+
+/// uppercase ascii letters, digits and dashes (e.g. `RUSTCONF-24`), entered codes get uppercased
+#[must_use]
+pub fn is_valid_promo_code(code: &str) -> bool {
+    (PROMO_CODE_MIN_LENGTH..=PROMO_CODE_MAX_LENGTH).contains(&code.len())
+        && code
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// discount on the premium upgrade, created by the admin
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct PromoCode {
+    pub code: String,
+    #[serde(rename = "percentOff")]
+    pub percent_off: u8,
+    /// number of purchases the code can be used for
+    #[serde(rename = "maxUses")]
+    pub max_uses: u32,
+    /// completed purchases as counted by the webhook, only informational
+    /// since the coupon limit is enforced by stripe. ignored when creating a code
+    #[serde(default)]
+    pub uses: u32,
+}
+
+impl PromoCode {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        is_valid_promo_code(&self.code)
+            && (1..=PROMO_MAX_PERCENT_OFF).contains(&self.percent_off)
+            && self.max_uses > 0
+    }
+
+    #[must_use]
+    pub const fn is_used_up(&self) -> bool {
+        self.uses >= self.max_uses
+    }
+}
+
+/// query of `/api/mod/event/upgrade/:id/:secret?promo=<code>`
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct UpgradeParams {
+    #[serde(default)]
+    pub promo: Option<String>,
+}
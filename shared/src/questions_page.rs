@@ -0,0 +1,98 @@
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+use crate::QuestionItem;
+
+/// page size of `/api/event/:id/questions` if none is requested
+pub const QUESTIONS_PAGE_DEFAULT_LIMIT: usize = 50;
+pub const QUESTIONS_PAGE_MAX_LIMIT: usize = 200;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum QuestionSort {
+    /// pinned first, then by likes minus downvotes like the event page lists them
+    #[default]
+    Score,
+    Newest,
+    Oldest,
+}
+
+impl QuestionSort {
+    pub fn sort(self, questions: &mut [QuestionItem]) {
+        //Note: the id breaks ties so pages do not overlap while nothing changes
+        match self {
+            Self::Score => {
+                questions.sort_by_key(|q| (!q.pinned, Reverse(q.score()), Reverse(q.id)));
+            }
+            Self::Newest => questions.sort_by_key(|q| Reverse((q.create_time_unix, q.id))),
+            Self::Oldest => questions.sort_by_key(|q| (q.create_time_unix, q.id)),
+        }
+    }
+}
+
+/// query of `/api/event/:id/questions?offset=<n>&limit=<n>&sort=<score|newest|oldest>`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct QuestionsParams {
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default)]
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub sort: QuestionSort,
+}
+
+impl QuestionsParams {
+    #[must_use]
+    pub fn limit(&self) -> usize {
+        self.limit
+            .unwrap_or(QUESTIONS_PAGE_DEFAULT_LIMIT)
+            .clamp(1, QUESTIONS_PAGE_MAX_LIMIT)
+    }
+}
+
+/// query of `/api/event/:id?questions=<limit>` to only get the first page of questions
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct EventParams {
+    #[serde(default)]
+    pub questions: Option<usize>,
+}
+
+/// one page of the questions visible to viewers
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct QuestionsPage {
+    pub questions: Vec<QuestionItem>,
+    pub offset: usize,
+    /// visible questions of the event across all pages
+    pub total: usize,
+    /// event version the page was cut from, a newer one can shift questions between pages
+    pub version: usize,
+}
+
+impl QuestionsPage {
+    #[must_use]
+    pub fn new(mut questions: Vec<QuestionItem>, params: &QuestionsParams, version: usize) -> Self {
+        params.sort.sort(&mut questions);
+
+        let total = questions.len();
+        let questions = questions
+            .into_iter()
+            .skip(params.offset)
+            .take(params.limit())
+            .collect();
+
+        Self {
+            questions,
+            offset: params.offset,
+            total,
+            version,
+        }
+    }
+
+    /// offset of the following page, `None` on the last one
+    #[must_use]
+    pub fn next_offset(&self) -> Option<usize> {
+        let next = self.offset.saturating_add(self.questions.len());
+        (next < self.total).then_some(next)
+    }
+}
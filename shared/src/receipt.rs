@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+pub const RECEIPT_FIELD_MAX_LENGTH: usize = 200;
+
+/// buyer details printed on the receipt of a premium purchase, all optional
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct ReceiptDetails {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(rename = "vatId", default)]
+    pub vat_id: Option<String>,
+}
+
+impl ReceiptDetails {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        [&self.name, &self.address, &self.vat_id]
+            .into_iter()
+            .flatten()
+            .all(|field| field.chars().count() <= RECEIPT_FIELD_MAX_LENGTH)
+    }
+}
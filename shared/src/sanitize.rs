@@ -0,0 +1,109 @@
+/// removes complete raw html tags (`<b>`, `</a>`, `<!-- -->`) from user input.
+/// a `<` that is never closed (like in `i<n`) is kept as plain text.
+/// markdown syntax itself is left untouched so the frontend can render the supported subset.
+#[must_use]
+pub fn strip_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        let (before, tag) = rest.split_at(start);
+        result.push_str(before);
+
+        match tag_len(tag) {
+            Some(len) => rest = tag.get(len..).unwrap_or_default(),
+            None => {
+                result.push('<');
+                rest = tag.get(1..).unwrap_or_default();
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// names of html elements, `<` followed by anything else (like in `i<n and m>k`) is no tag
+const ELEMENTS: &str = "\
+    a abbr address area article aside audio b base bdi bdo blockquote body br button canvas \
+    caption center cite code col colgroup data datalist dd del details dfn dialog div dl dt em \
+    embed fieldset figcaption figure font footer form frame frameset h1 h2 h3 h4 h5 h6 head \
+    header hr html i iframe img input ins kbd label legend li link main map mark marquee math \
+    menu meta meter nav noscript object ol optgroup option output p param picture pre progress \
+    q rp rt ruby s samp script section select slot small source span strike strong style sub \
+    summary sup svg table tbody td template textarea tfoot th thead time title tr track u ul \
+    var video wbr";
+
+/// length of the complete tag `text` starts with, a tag names an html element
+/// and must be closed before the next `<` or line break
+fn tag_len(text: &str) -> Option<usize> {
+    let body = text.get(1..)?;
+
+    let is_tag = if body.starts_with(['!', '?']) {
+        true
+    } else {
+        let name = body.strip_prefix('/').unwrap_or(body);
+        let name_len = name
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(name.len());
+
+        //Note: the name has to end the way it does in html, `<br/>` is a tag but `<b,` is not
+        let ends_name = name
+            .get(name_len..)?
+            .starts_with(|c: char| c.is_whitespace() || matches!(c, '/' | '>'));
+
+        ends_name
+            && ELEMENTS
+                .split_whitespace()
+                .any(|element| element.eq_ignore_ascii_case(&name[..name_len]))
+    };
+
+    if !is_tag {
+        return None;
+    }
+
+    let end = body.find(['>', '<', '\n'])?;
+    body.get(end..)?.starts_with('>').then_some(end + 2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_strip_tags() {
+        assert_eq!(strip_html("a <b>bold</b> word"), "a bold word");
+        assert_eq!(strip_html("<a href=\"x\">link</a>"), "link");
+        assert_eq!(strip_html("<script>alert(1)</script>"), "alert(1)");
+        assert_eq!(strip_html("<IMG SRC=x onerror=y>z"), "z");
+        assert_eq!(strip_html("line<br/>break"), "linebreak");
+    }
+
+    #[test]
+    fn test_strip_comments() {
+        assert_eq!(strip_html("before<!-- hidden -->after"), "beforeafter");
+        assert_eq!(strip_html("<?xml version=\"1.0\"?>doc"), "doc");
+    }
+
+    #[test]
+    fn test_keep_unclosed() {
+        assert_eq!(strip_html("Is i<n faster?"), "Is i<n faster?");
+        assert_eq!(strip_html("<b unclosed"), "<b unclosed");
+        assert_eq!(strip_html("trailing <"), "trailing <");
+    }
+
+    #[test]
+    fn test_keep_comparisons() {
+        assert_eq!(strip_html("a < b and b > c"), "a < b and b > c");
+        assert_eq!(strip_html("x<y\nz>w"), "x<y\nz>w");
+        assert_eq!(strip_html("i<n<b>x</b>"), "i<nx");
+        assert_eq!(strip_html("if i<n and m>k"), "if i<n and m>k");
+        assert_eq!(strip_html("if a<b, c>d"), "if a<b, c>d");
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(strip_html(""), "");
+        assert_eq!(strip_html("no tags here"), "no tags here");
+    }
+}
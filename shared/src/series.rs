@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+pub const SERIES_INTERVAL_MAX_DAYS: u32 = 92;
+/// keeps the first event small, older occurrences drop out of the archive
+pub const SERIES_MAX_OCCURRENCES: usize = 200;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct SeriesOccurrence {
+    #[serde(rename = "publicToken")]
+    pub public_token: String,
+    #[serde(rename = "startUnix")]
+    pub start_unix: i64,
+}
+
+/// recurring event series ("weekly all-hands"), every occurrence is an event of its own
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct EventSeries {
+    /// public token of the first event, identifies the series
+    pub id: String,
+    /// `0` once the series was ended
+    #[serde(rename = "intervalDays")]
+    pub interval_days: u32,
+    /// all occurrences oldest first, only kept on the first event
+    #[serde(default)]
+    pub occurrences: Vec<SeriesOccurrence>,
+}
+
+impl EventSeries {
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.interval_days > 0
+    }
+
+    #[must_use]
+    pub fn interval_secs(&self) -> i64 {
+        i64::from(self.interval_days) * 24 * 60 * 60
+    }
+
+    /// latest occurrence, the stable series url leads there
+    #[must_use]
+    pub fn current(&self) -> Option<&SeriesOccurrence> {
+        self.occurrences.last()
+    }
+}
+
+/// starts a series, changes its interval or ends it with an interval of `0`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct EditSeries {
+    #[serde(rename = "intervalDays")]
+    pub interval_days: u32,
+}
+
+impl EditSeries {
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.interval_days <= SERIES_INTERVAL_MAX_DAYS
+    }
+}
+
+/// public response of the stable series url
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct SeriesCurrent {
+    #[serde(rename = "publicToken")]
+    pub public_token: String,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::EventState;
+
+pub const SESSION_MAX_PER_EVENT: usize = 20;
+pub const SESSION_NAME_MAX_LENGTH: usize = 50;
+
+/// agenda track within an event ("Keynote", "Panel 2") with its own questions and state
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct Session {
+    pub id: i64,
+    pub name: String,
+    pub state: EventState,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct AddSession {
+    pub name: String,
+}
+
+impl AddSession {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        let name = self.name.trim();
+        !name.is_empty() && name.chars().count() <= SESSION_NAME_MAX_LENGTH
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct ModSession {
+    pub state: EventState,
+}
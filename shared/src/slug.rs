@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+pub const SLUG_MIN_LENGTH: usize = 3;
+pub const SLUG_MAX_LENGTH: usize = 64;
+
+/// lowercase ascii letters, digits and inner dashes (e.g. `rustconf-keynote`)
+#[must_use]
+pub fn is_valid_slug(slug: &str) -> bool {
+    (SLUG_MIN_LENGTH..=SLUG_MAX_LENGTH).contains(&slug.len())
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !slug.starts_with('-')
+        && !slug.ends_with('-')
+}
+
+/// claims a custom short url slug for a premium event
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct EditSlug {
+    pub slug: String,
+}
+
+impl EditSlug {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        is_valid_slug(&self.slug)
+    }
+}
+
+/// public response of a custom short url
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct SlugTarget {
+    #[serde(rename = "publicToken")]
+    pub public_token: String,
+}
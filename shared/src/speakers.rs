@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+pub const SPEAKER_QUEUE_MAX: usize = 100;
+
+/// attendee who raised their hand to ask live
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct Speaker {
+    pub id: i64,
+    pub name: String,
+    #[serde(rename = "createTimeUnix")]
+    pub create_time_unix: i64,
+}
+
+/// raised hands in the order the moderator is going to call them
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct SpeakerQueue {
+    pub speakers: Vec<Speaker>,
+    /// speaker that got the microphone, stays in the queue until the moderator is done with them
+    #[serde(default)]
+    pub current: Option<i64>,
+}
+
+impl SpeakerQueue {
+    #[must_use]
+    pub fn position(&self, id: i64) -> Option<usize> {
+        self.speakers.iter().position(|s| s.id == id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct RaiseHand {
+    pub name: String,
+}
+
+/// answer to raising a hand, `token` is required to lower it again
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct RaisedHand {
+    pub id: i64,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ModSpeaker {
+    /// hands the microphone to the speaker
+    Call(i64),
+    /// moves the speaker to `index` in the queue
+    Move { id: i64, index: usize },
+    /// takes the speaker off the queue, also once they are done speaking
+    Remove(i64),
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+pub const SURVEY_RATING_MAX: u8 = 5;
+pub const SURVEY_COMMENT_MAX_LENGTH: usize = 200;
+/// responses are stored with the event, these keep its entry small, later responses are rejected
+pub const SURVEY_MAX_RESPONSES: usize = 100;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct SurveyResponse {
+    /// 1 to `SURVEY_RATING_MAX` stars
+    pub rating: u8,
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(rename = "createTimeUnix")]
+    pub create_time_unix: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct AddSurveyResponse {
+    pub rating: u8,
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// random token of the participant's browser, each one responds once
+    #[serde(default)]
+    pub participant: String,
+}
+
+impl AddSurveyResponse {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        (1..=SURVEY_RATING_MAX).contains(&self.rating)
+            && !self
+                .comment
+                .as_ref()
+                .is_some_and(|c| c.chars().count() > SURVEY_COMMENT_MAX_LENGTH)
+    }
+}
+
+/// aggregated survey results, only visible to the moderator
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct SurveyResults {
+    /// number of responses per rating, index 0 counts the 1 star ratings
+    pub ratings: Vec<u32>,
+    /// responses that came with a comment, newest first
+    pub comments: Vec<SurveyResponse>,
+}
+
+impl SurveyResults {
+    #[must_use]
+    pub fn new(responses: &[SurveyResponse]) -> Self {
+        let mut ratings = vec![0; usize::from(SURVEY_RATING_MAX)];
+
+        for r in responses {
+            if let Some(count) = usize::from(r.rating)
+                .checked_sub(1)
+                .and_then(|index| ratings.get_mut(index))
+            {
+                *count += 1;
+            }
+        }
+
+        let mut comments = responses
+            .iter()
+            .filter(|r| r.comment.is_some())
+            .cloned()
+            .collect::<Vec<_>>();
+        comments.reverse();
+
+        Self { ratings, comments }
+    }
+
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.ratings.iter().sum()
+    }
+}
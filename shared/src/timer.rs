@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// longest countdown a moderator can start
+pub const TIMER_MAX_SECS: i64 = 3 * 60 * 60;
+pub const TIMER_LABEL_MAX_LENGTH: usize = 100;
+
+/// countdown started by the moderator, every client counts down to `end_unix` on its own
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct EventTimer {
+    #[serde(rename = "endUnix")]
+    pub end_unix: i64,
+    /// e.g. "left for questions", may be empty
+    #[serde(default)]
+    pub label: String,
+}
+
+impl EventTimer {
+    #[must_use]
+    pub fn seconds_left(&self, now: i64) -> i64 {
+        self.end_unix.saturating_sub(now).max(0)
+    }
+}
+
+/// starts a countdown of `seconds` replacing a running one, `None` stops it
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct ModTimer {
+    pub seconds: Option<i64>,
+    #[serde(default)]
+    pub label: String,
+}
+
+impl ModTimer {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.seconds
+            .map_or(true, |seconds| (1..=TIMER_MAX_SECS).contains(&seconds))
+            && self.label.trim().chars().count() <= TIMER_LABEL_MAX_LENGTH
+    }
+}
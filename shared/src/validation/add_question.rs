@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 use super::ValidationState;
 
 ///
@@ -14,14 +16,75 @@ const TRIMMED_MAX_LEN: usize = 200;
 const WORD_MIN: usize = 3;
 const WORD_LEN_MAX: usize = 30;
 
+/// lowest `min` a moderator can configure
+pub const QUESTION_LENGTH_LOWER_BOUND: usize = 5;
+/// highest `max` a moderator can configure
+pub const QUESTION_LENGTH_UPPER_BOUND: usize = 1000;
+
+/// most questions an event takes, organizers can only lower it
+pub const QUESTIONS_MAX: usize = 500;
+/// highest rate of new questions per minute a moderator can configure
+pub const QUESTIONS_PER_MINUTE_UPPER_BOUND: usize = 100;
+
+/// per event limits of the (trimmed) question length
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct QuestionLength {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Default for QuestionLength {
+    fn default() -> Self {
+        Self {
+            min: TRIMMED_MIN_LEN,
+            max: TRIMMED_MAX_LEN,
+        }
+    }
+}
+
+impl QuestionLength {
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.min >= QUESTION_LENGTH_LOWER_BOUND
+            && self.max <= QUESTION_LENGTH_UPPER_BOUND
+            && self.min <= self.max
+    }
+}
+
+/// per event caps of question submissions set by the organizer, `0` for no cap
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct QuestionLimits {
+    /// questions the event takes in total, never more than [`QUESTIONS_MAX`]
+    pub total: usize,
+    /// new questions the event takes within one minute
+    #[serde(rename = "perMinute")]
+    pub per_minute: usize,
+}
+
+impl QuestionLimits {
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.total <= QUESTIONS_MAX && self.per_minute <= QUESTIONS_PER_MINUTE_UPPER_BOUND
+    }
+
+    #[must_use]
+    pub const fn max_questions(&self) -> usize {
+        if self.total == 0 {
+            QUESTIONS_MAX
+        } else {
+            self.total
+        }
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct AddQuestionValidation {
     pub content: ValidationState<AddQuestionError>,
 }
 
 impl AddQuestionValidation {
-    pub fn check(&mut self, content: &str) {
-        self.content = Self::check_content(content);
+    pub fn check(&mut self, content: &str, limits: QuestionLength) {
+        self.content = Self::check_content(content, limits);
     }
 
     #[must_use]
@@ -29,14 +92,14 @@ impl AddQuestionValidation {
         !self.content.is_valid()
     }
 
-    fn check_content(v: &str) -> ValidationState<AddQuestionError> {
+    fn check_content(v: &str, limits: QuestionLength) -> ValidationState<AddQuestionError> {
         let trimmed_len = v.trim().len();
         let words = v.split_whitespace().count();
 
-        if trimmed_len < TRIMMED_MIN_LEN {
-            ValidationState::Invalid(AddQuestionError::MinLength(trimmed_len, TRIMMED_MIN_LEN))
-        } else if trimmed_len > TRIMMED_MAX_LEN {
-            ValidationState::Invalid(AddQuestionError::MaxLength(trimmed_len, TRIMMED_MAX_LEN))
+        if trimmed_len < limits.min {
+            ValidationState::Invalid(AddQuestionError::MinLength(trimmed_len, limits.min))
+        } else if trimmed_len > limits.max {
+            ValidationState::Invalid(AddQuestionError::MaxLength(trimmed_len, limits.max))
         } else if words < WORD_MIN {
             ValidationState::Invalid(AddQuestionError::MinWordCount(words, WORD_MIN))
         } else if v
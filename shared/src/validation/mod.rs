@@ -1,6 +1,7 @@
 pub mod add_question;
 pub mod context_validation;
 pub mod create_event;
+pub mod name_validation;
 pub mod pwd_validation;
 pub mod tag_validation;
 
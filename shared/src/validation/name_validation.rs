@@ -0,0 +1,46 @@
+use super::ValidationState;
+
+///
+#[derive(Debug)]
+pub enum NameError {
+    Required,
+    MaxLength(usize, usize),
+    MinLength(usize, usize),
+}
+
+const TRIMMED_MIN_LEN: usize = 2;
+const MAX_LEN: usize = 30;
+
+#[derive(Default, Debug)]
+pub struct NameValidation {
+    pub content: ValidationState<NameError>,
+}
+
+impl NameValidation {
+    pub fn check(&mut self, content: &str, required: bool) {
+        self.content = Self::check_content(content, required);
+    }
+
+    #[must_use]
+    pub const fn has_any(&self) -> bool {
+        !self.content.is_valid()
+    }
+
+    fn check_content(v: &str, required: bool) -> ValidationState<NameError> {
+        let trimmed_len = v.trim().len();
+
+        if trimmed_len == 0 {
+            if required {
+                ValidationState::Invalid(NameError::Required)
+            } else {
+                ValidationState::Valid
+            }
+        } else if trimmed_len < TRIMMED_MIN_LEN {
+            ValidationState::Invalid(NameError::MinLength(trimmed_len, TRIMMED_MIN_LEN))
+        } else if v.len() > MAX_LEN {
+            ValidationState::Invalid(NameError::MaxLength(v.len(), MAX_LEN))
+        } else {
+            ValidationState::Valid
+        }
+    }
+}
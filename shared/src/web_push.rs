@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// oldest subscriptions of an event are dropped once more are added
+pub const WEB_PUSH_MAX_SUBSCRIPTIONS: usize = 10;
+
+/// browser push subscription as returned by `PushSubscription.toJSON()`
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct WebPushSubscription {
+    pub endpoint: String,
+    pub keys: WebPushKeys,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct WebPushKeys {
+    pub p256dh: String,
+    pub auth: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct WebPushUnsubscribe {
+    pub endpoint: String,
+}
+
+/// payload handed to the `push` handler of the service worker
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct WebPushNotification {
+    pub title: String,
+    pub body: String,
+    /// opened when the notification is clicked
+    pub url: String,
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::strip_html;
+
+/// attendees answer a word cloud prompt with at most this many words
+pub const WORD_CLOUD_MAX_WORDS: usize = 2;
+pub const WORD_CLOUD_ENTRY_MAX_LENGTH: usize = 30;
+/// distinct entries kept per cloud, later new entries are rejected
+pub const WORD_CLOUD_MAX_ENTRIES: usize = 200;
+/// answers of one participant per cloud
+pub const WORD_CLOUD_MAX_ANSWERS: u64 = 1;
+pub const WORD_CLOUD_MAX_PER_EVENT: usize = 20;
+pub const WORD_CLOUD_PROMPT_MAX_LENGTH: usize = 200;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct WordCount {
+    pub text: String,
+    pub count: i32,
+}
+
+/// prompt created by the moderator, answers are aggregated into a size weighted cloud
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct WordCloud {
+    pub id: i64,
+    pub prompt: String,
+    pub words: Vec<WordCount>,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(rename = "createTimeUnix")]
+    pub create_time_unix: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct AddWordCloud {
+    pub prompt: String,
+}
+
+impl AddWordCloud {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        let prompt = self.prompt.trim();
+        !prompt.is_empty() && prompt.chars().count() <= WORD_CLOUD_PROMPT_MAX_LENGTH
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ModWordCloud {
+    pub closed: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct AddWord {
+    pub text: String,
+    /// random token of the participant's browser, see `WORD_CLOUD_MAX_ANSWERS`
+    #[serde(default)]
+    pub participant: String,
+}
+
+/// lowercases and collapses whitespace so equal answers are counted together.
+/// returns `None` if the answer is empty, too long or has too many words.
+#[must_use]
+pub fn normalize_word(text: &str) -> Option<String> {
+    let text = strip_html(text);
+    let words = text.split_whitespace().collect::<Vec<_>>();
+
+    if words.is_empty() || words.len() > WORD_CLOUD_MAX_WORDS {
+        return None;
+    }
+
+    let text = words.join(" ").to_lowercase();
+
+    (text.chars().count() <= WORD_CLOUD_ENTRY_MAX_LENGTH).then_some(text)
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use crate::EventDelta;
+
+/// messages pushed from the server to clients via websocket text frames,
+/// sent as json tagged with a single letter: `{"t":"q","c":12}`
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(tag = "t", content = "c")]
+pub enum WsMessage {
+    /// event changed, clients refetch it
+    #[serde(rename = "e")]
+    Event,
+    /// question changed, clients refetch the event
+    #[serde(rename = "q")]
+    Question(i64),
+    /// live viewer count
+    #[serde(rename = "v")]
+    Viewers(i64),
+    /// participants currently writing a question
+    #[serde(rename = "w")]
+    Typing(usize),
+    /// whether a moderator is connected to the event
+    #[serde(rename = "m")]
+    ModeratorOnline(bool),
+    /// incremental update
+    #[serde(rename = "d")]
+    Delta(EventDelta),
+    /// answer to [`WsClientMessage::Ping`]
+    #[serde(rename = "p")]
+    Pong,
+    /// event is at its viewer capacity, the socket waits for a free slot
+    #[serde(rename = "r")]
+    WaitingRoom,
+    /// socket got a free slot after waiting, clients refetch the event
+    #[serde(rename = "a")]
+    Admitted,
+}
+
+impl WsMessage {
+    /// wire format of the message.
+    /// a delta that fails to serialize is sent as [`WsMessage::Event`] so clients still refetch.
+    #[must_use]
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self)
+            .or_else(|_| serde_json::to_string(&Self::Event))
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn decode(text: &str) -> Option<Self> {
+        serde_json::from_str(text).ok()
+    }
+}
+
+/// messages sent from clients to the server via websocket text frames: `{"t":"p"}`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(tag = "t")]
+pub enum WsClientMessage {
+    /// app level keepalive
+    #[serde(rename = "p")]
+    Ping,
+    /// participant is writing a question
+    #[serde(rename = "t")]
+    Typing,
+}
+
+impl WsClientMessage {
+    #[must_use]
+    pub fn encode(self) -> String {
+        serde_json::to_string(&self).unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn decode(text: &str) -> Option<Self> {
+        serde_json::from_str(text).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{EventState, QuestionItem, States};
+
+    #[test]
+    fn test_message_roundtrip() {
+        let messages = [
+            WsMessage::Event,
+            WsMessage::Question(12),
+            WsMessage::Viewers(134),
+            WsMessage::Typing(2),
+            WsMessage::ModeratorOnline(true),
+            WsMessage::ModeratorOnline(false),
+            WsMessage::Delta(EventDelta::QuestionAdded(QuestionItem::default())),
+            WsMessage::Delta(EventDelta::EventStateChanged(EventState {
+                state: States::Closed,
+            })),
+            WsMessage::Pong,
+            WsMessage::WaitingRoom,
+            WsMessage::Admitted,
+        ];
+
+        for msg in messages {
+            assert_eq!(WsMessage::decode(&msg.encode()), Some(msg));
+        }
+    }
+
+    #[test]
+    fn test_message_format() {
+        assert_eq!(WsMessage::Event.encode(), r#"{"t":"e"}"#);
+        assert_eq!(WsMessage::Question(12).encode(), r#"{"t":"q","c":12}"#);
+        assert_eq!(
+            WsMessage::ModeratorOnline(true).encode(),
+            r#"{"t":"m","c":true}"#
+        );
+    }
+
+    #[test]
+    fn test_message_invalid() {
+        assert_eq!(WsMessage::decode(""), None);
+        assert_eq!(WsMessage::decode("e"), None);
+        assert_eq!(WsMessage::decode(r#"{"t":"x"}"#), None);
+        assert_eq!(WsMessage::decode(r#"{"t":"q","c":"a"}"#), None);
+    }
+
+    #[test]
+    fn test_client_message_roundtrip() {
+        for msg in [WsClientMessage::Ping, WsClientMessage::Typing] {
+            assert_eq!(WsClientMessage::decode(&msg.encode()), Some(msg));
+        }
+
+        assert_eq!(WsClientMessage::Ping.encode(), r#"{"t":"p"}"#);
+        assert_eq!(WsClientMessage::decode("p"), None);
+    }
+}